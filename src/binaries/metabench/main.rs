@@ -43,6 +43,7 @@ use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::TxnRequest;
 use common_tracing::init_logging;
+use common_tracing::AuditLogConfig;
 use common_tracing::FileConfig;
 use common_tracing::QueryLogConfig;
 use common_tracing::StderrConfig;
@@ -100,6 +101,10 @@ async fn main() {
             on: false,
             dir: "./.databend/logs/query-details".to_string(),
         },
+        audit: AuditLogConfig {
+            on: false,
+            dir: "".to_string(),
+        },
         tracing: TracingConfig {
             on: false,
             capture_log_level: "TRACE".to_string(),
@@ -218,6 +223,7 @@ async fn benchmark_table(client: &Arc<ClientHandle>, prefix: u64, client_num: u6
             if_not_exists: true,
             name_ident: tb_name_ident(),
             table_meta: Default::default(),
+            auto_increment_sequences: vec![],
         })
         .await;
 
@@ -256,6 +262,7 @@ async fn benchmark_table(client: &Arc<ClientHandle>, prefix: u64, client_num: u6
             if_not_exists: true,
             name_ident: tb_name_ident(),
             table_meta: Default::default(),
+            auto_increment_sequences: vec![],
         })
         .await;
 
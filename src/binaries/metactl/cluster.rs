@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cluster subcommands: show the status, change the membership and move the leadership of a running databend-meta cluster.
+
+use common_meta_types::protobuf::raft_service_client::RaftServiceClient;
+use common_meta_types::Endpoint;
+use common_meta_types::NodeId;
+use databend_meta::message::ForwardRequest;
+use databend_meta::message::ForwardRequestBody;
+use databend_meta::message::JoinRequest;
+use databend_meta::message::LeaveRequest;
+
+/// Print the status of the node serving the http admin API, i.e., `/v1/cluster/status`.
+pub async fn status(admin_api_address: &str) -> anyhow::Result<()> {
+    let status = admin_get(admin_api_address, "/v1/cluster/status").await?;
+    println!("{}", serde_json::to_string_pretty(&status)?);
+    Ok(())
+}
+
+/// Transfer the leadership to another voter via the http admin API of any node.
+pub async fn transfer_leader(admin_api_address: &str, to: Option<NodeId>) -> anyhow::Result<()> {
+    let path = match to {
+        Some(to) => format!("/v1/cluster/transfer_leader?to={}", to),
+        None => "/v1/cluster/transfer_leader".to_string(),
+    };
+    let resp = admin_get(admin_api_address, &path).await?;
+    println!("{}", serde_json::to_string_pretty(&resp)?);
+    Ok(())
+}
+
+/// Enter or leave the drain mode of the node serving the http admin API.
+pub async fn drain(admin_api_address: &str, on: bool) -> anyhow::Result<()> {
+    let path = format!("/v1/cluster/drain?on={}", on);
+    let resp = admin_get(admin_api_address, &path).await?;
+    println!("{}", serde_json::to_string_pretty(&resp)?);
+    Ok(())
+}
+
+async fn admin_get(admin_api_address: &str, path: &str) -> anyhow::Result<serde_json::Value> {
+    if admin_api_address.is_empty() {
+        return Err(anyhow::anyhow!("--admin-api-address is required"));
+    }
+    let url = format!("http://{}{}", admin_api_address, path);
+    let resp = reqwest::get(&url).await?;
+    if !resp.status().is_success() {
+        return Err(anyhow::anyhow!(
+            "failed to request {}: {}: {}",
+            url,
+            resp.status(),
+            resp.text().await?
+        ));
+    }
+    Ok(resp.json::<serde_json::Value>().await?)
+}
+
+/// Add a node to the cluster via the leader: it is added as a learner first,
+/// and becomes a voter once its logs catch up.
+pub async fn add_node(
+    raft_api_address: &str,
+    node_id: NodeId,
+    endpoint: &str,
+    grpc_api_advertise_address: Option<String>,
+) -> anyhow::Result<()> {
+    let endpoint = parse_endpoint(endpoint)?;
+    let body = ForwardRequestBody::Join(JoinRequest::new(
+        node_id,
+        endpoint,
+        grpc_api_advertise_address,
+    ));
+    forward_to_leader(raft_api_address, body).await?;
+    println!("node-{} joined", node_id);
+    Ok(())
+}
+
+/// Remove a node from the cluster via the leader.
+pub async fn remove_node(raft_api_address: &str, node_id: NodeId) -> anyhow::Result<()> {
+    let body = ForwardRequestBody::Leave(LeaveRequest { node_id });
+    forward_to_leader(raft_api_address, body).await?;
+    println!("node-{} left", node_id);
+    Ok(())
+}
+
+async fn forward_to_leader(raft_api_address: &str, body: ForwardRequestBody) -> anyhow::Result<()> {
+    if raft_api_address.is_empty() {
+        return Err(anyhow::anyhow!("--raft-api-address is required"));
+    }
+    let mut raft_client =
+        RaftServiceClient::connect(format!("http://{}", raft_api_address)).await?;
+    let req = ForwardRequest {
+        forward_to_leader: 1,
+        body,
+    };
+    let reply = raft_client.forward(req).await?.into_inner();
+    if reply.data.is_empty() {
+        return Err(anyhow::anyhow!(
+            "forward via {} failed: {}",
+            raft_api_address,
+            reply.error
+        ));
+    }
+    Ok(())
+}
+
+fn parse_endpoint(s: &str) -> anyhow::Result<Endpoint> {
+    let (addr, port) = s
+        .rsplit_once(':')
+        .ok_or_else(|| anyhow::anyhow!("invalid endpoint: {}, expect <host>:<port>", s))?;
+    let port = port
+        .parse::<u32>()
+        .map_err(|e| anyhow::anyhow!("invalid port of endpoint {}: {}", s, e))?;
+    Ok(Endpoint::new(addr, port))
+}
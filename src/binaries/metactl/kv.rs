@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Key-value subcommands that talk to the grpc API of a running databend-meta.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_meta_api::deserialize_struct;
+use common_meta_app::background::BackgroundJobId;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::data_mask::DatamaskId;
+use common_meta_app::data_mask::DatamaskMeta;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::CatalogId;
+use common_meta_app::schema::CatalogMeta;
+use common_meta_app::schema::DatabaseId;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::IndexId;
+use common_meta_app::schema::IndexMeta;
+use common_meta_app::schema::TableId;
+use common_meta_app::schema::TableMeta;
+use common_meta_client::ClientHandle;
+use common_meta_client::MetaGrpcClient;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_kvapi::kvapi::RangeKVReq;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::protobuf::watch_request::FilterType;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::MetaError;
+use common_meta_types::SeqV;
+use tokio_stream::StreamExt;
+
+/// The key space of `UserInfo`, it is defined in the user api of query.
+const PREFIX_USER: &str = "__fd_users";
+
+pub fn new_client(addr: &str) -> anyhow::Result<Arc<ClientHandle>> {
+    if addr.is_empty() {
+        return Err(anyhow::anyhow!("--grpc-api-address is required"));
+    }
+    let client = MetaGrpcClient::try_create(
+        vec![addr.to_string()],
+        "root",
+        "xxx",
+        None,
+        None,
+        Duration::from_secs(10),
+        None,
+    )?;
+    Ok(client)
+}
+
+pub async fn get(addr: &str, key: &str) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+    let res = client.get_kv(key).await?;
+    print_seq_v(key, res.as_ref());
+    Ok(())
+}
+
+pub async fn mget(addr: &str, keys: &[String]) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+    let res = client.mget_kv(keys).await?;
+    for (key, seq_v) in keys.iter().zip(res.iter()) {
+        print_seq_v(key, seq_v.as_ref());
+    }
+    Ok(())
+}
+
+/// List the keys with the prefix, the `limit` is applied by the server so that
+/// at most `limit` keys are transferred.
+pub async fn list(addr: &str, prefix: &str, limit: Option<u64>) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+    let mut req = RangeKVReq::prefix(prefix)?;
+    if let Some(limit) = limit {
+        req = req.with_limit(limit);
+    }
+    let res = client.range_kv(req).await?;
+    for (key, seq_v) in res.kvs.iter() {
+        print_seq_v(key, Some(seq_v));
+    }
+    match res.continuation {
+        Some(_) => eprintln!("{} keys listed, there are more keys", res.kvs.len()),
+        None => eprintln!("{} keys listed", res.kvs.len()),
+    }
+    Ok(())
+}
+
+pub async fn upsert(
+    addr: &str,
+    key: &str,
+    value: &str,
+    expire_after: Option<u64>,
+) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+    let mut req = UpsertKVReq::update(key, value.as_bytes());
+    if let Some(expire_after) = expire_after {
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?;
+        req = req.with_expire_sec(now.as_secs() + expire_after);
+    }
+    let res = client.upsert_kv(req).await?;
+    print_seq_v(key, res.result.as_ref());
+    Ok(())
+}
+
+pub async fn delete(addr: &str, key: &str) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+    let res = client.upsert_kv(UpsertKVReq::delete(key)).await?;
+    match res.prev {
+        Some(prev) => println!("deleted {} (seq: {})", key, prev.seq),
+        None => println!("{} does not exist", key),
+    }
+    Ok(())
+}
+
+/// Print the changes of the keys with the prefix, until the stream is closed.
+pub async fn watch(addr: &str, prefix: &str) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+    let (key, key_end) = kvapi::prefix_to_range(prefix)?;
+    let mut stream = client
+        .request::<_, _, MetaError>(WatchRequest {
+            key,
+            key_end: Some(key_end),
+            filter_type: FilterType::All.into(),
+        })
+        .await?;
+
+    eprintln!("watching {}...", prefix);
+    while let Some(resp) = stream.next().await {
+        let event = match resp?.event {
+            Some(event) => event,
+            None => continue,
+        };
+        match event.current {
+            Some(current) => println!(
+                "[update] {} (seq: {}): {}",
+                event.key,
+                current.seq,
+                decode_value(&event.key, &current.data)
+            ),
+            None => println!("[delete] {}", event.key),
+        }
+    }
+    Ok(())
+}
+
+fn print_seq_v(key: &str, seq_v: Option<&SeqV>) {
+    match seq_v {
+        Some(seq_v) => {
+            let expire_at = seq_v.meta.as_ref().and_then(|meta| meta.expire_at);
+            match expire_at {
+                Some(expire_at) => println!(
+                    "{} (seq: {}, expire_at: {}): {}",
+                    key,
+                    seq_v.seq,
+                    expire_at,
+                    decode_value(key, &seq_v.data)
+                ),
+                None => println!(
+                    "{} (seq: {}): {}",
+                    key,
+                    seq_v.seq,
+                    decode_value(key, &seq_v.data)
+                ),
+            }
+        }
+        None => println!("{}: <not found>", key),
+    }
+}
+
+/// Decode the value of the known key spaces that are stored in protobuf,
+/// other values are printed as utf8 string or raw bytes.
+fn decode_value(key: &str, data: &[u8]) -> String {
+    let key_space = key.split('/').next().unwrap_or_default();
+    let decoded = match key_space {
+        TableId::PREFIX => deserialize_struct::<TableMeta>(data).map(|v| format!("{:#?}", v)),
+        DatabaseId::PREFIX => deserialize_struct::<DatabaseMeta>(data).map(|v| format!("{:#?}", v)),
+        CatalogId::PREFIX => deserialize_struct::<CatalogMeta>(data).map(|v| format!("{:#?}", v)),
+        IndexId::PREFIX => deserialize_struct::<IndexMeta>(data).map(|v| format!("{:#?}", v)),
+        DatamaskId::PREFIX => deserialize_struct::<DatamaskMeta>(data).map(|v| format!("{:#?}", v)),
+        BackgroundJobId::PREFIX => {
+            deserialize_struct::<BackgroundJobInfo>(data).map(|v| format!("{:#?}", v))
+        }
+        PREFIX_USER => deserialize_struct::<UserInfo>(data).map(|v| format!("{:#?}", v)),
+        _ => return raw_value(data),
+    };
+
+    // fallback to the raw value if it is not in the expected format.
+    decoded.unwrap_or_else(|_| raw_value(data))
+}
+
+fn raw_value(data: &[u8]) -> String {
+    match std::str::from_utf8(data) {
+        Ok(s) => s.to_string(),
+        Err(_) => format!("{:?}", data),
+    }
+}
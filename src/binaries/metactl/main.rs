@@ -14,7 +14,11 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod cluster;
 mod grpc;
+mod kv;
+mod restore;
+use common_tracing::AuditLogConfig;
 use common_tracing::QueryLogConfig;
 use common_tracing::TracingConfig;
 use grpc::export_meta;
@@ -24,6 +28,7 @@ mod snapshot;
 use std::time::Duration;
 
 use clap::Parser;
+use clap::Subcommand;
 use common_base::base::tokio;
 use common_meta_client::MetaGrpcClient;
 use common_meta_kvapi::kvapi::KVApi;
@@ -32,6 +37,7 @@ use common_tracing::init_logging;
 use common_tracing::Config as LogConfig;
 use common_tracing::FileConfig;
 use common_tracing::StderrConfig;
+use databend_meta::configs::OuterBackupConfig;
 use databend_meta::version::METASRV_COMMIT_VERSION;
 use serde::Deserialize;
 use serde::Serialize;
@@ -58,6 +64,15 @@ pub struct Config {
     #[clap(long, env = "METASRV_GRPC_API_ADDRESS", default_value = "")]
     pub grpc_api_address: String,
 
+    /// The http admin API address of a meta node, used by `status`.
+    #[clap(long, env = "METASRV_ADMIN_API_ADDRESS", default_value = "")]
+    pub admin_api_address: String,
+
+    /// The raft API address of a meta node, used by `add-node` and `remove-node`.
+    /// The membership change is forwarded to the leader by this node.
+    #[clap(long, env = "METASRV_RAFT_API_ADDRESS", default_value = "")]
+    pub raft_api_address: String,
+
     /// When export raft data, this is the name of the save db file.
     /// If `db` is empty, output the exported data as json to stdout instead.
     /// When import raft data, this is the name of the restored db file.
@@ -71,6 +86,119 @@ pub struct Config {
 
     #[clap(flatten)]
     pub raft_config: MetaCtlRaftConfig,
+
+    #[clap(subcommand)]
+    pub subcommand: Option<CtlCommand>,
+}
+
+/// Subcommands to inspect and manage a running databend-meta cluster.
+///
+/// The key-value subcommands require `--grpc-api-address`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Subcommand)]
+pub enum CtlCommand {
+    /// Get the value of a key.
+    Get {
+        #[clap(long)]
+        key: String,
+    },
+
+    /// Get the values of several keys.
+    Mget {
+        #[clap(long, required = true, num_args = 1..)]
+        keys: Vec<String>,
+    },
+
+    /// List the keys that start with a prefix.
+    List {
+        #[clap(long, default_value = "")]
+        prefix: String,
+
+        /// Print at most `limit` keys.
+        #[clap(long)]
+        limit: Option<u64>,
+    },
+
+    /// Insert or update a key with a string value.
+    Upsert {
+        #[clap(long)]
+        key: String,
+
+        #[clap(long)]
+        value: String,
+
+        /// Remove the key after this many seconds.
+        #[clap(long)]
+        expire_after: Option<u64>,
+    },
+
+    /// Delete a key.
+    Delete {
+        #[clap(long)]
+        key: String,
+    },
+
+    /// Print the changes of the keys that start with a prefix, until interrupted.
+    Watch {
+        #[clap(long)]
+        prefix: String,
+    },
+
+    /// Show the status of a meta node, requires `--admin-api-address`.
+    Status,
+
+    /// Add a node to the cluster as a learner, it becomes a voter once it catches up.
+    /// Requires `--raft-api-address` of any node in the cluster.
+    AddNode {
+        #[clap(long)]
+        node_id: u64,
+
+        /// The raft API address of the new node, in form of `<host>:<port>`.
+        #[clap(long)]
+        endpoint: String,
+
+        /// The grpc API address the new node advertises to clients.
+        #[clap(long)]
+        grpc_api_advertise_address: Option<String>,
+    },
+
+    /// Remove a node from the cluster.
+    /// Requires `--raft-api-address` of any node in the cluster.
+    RemoveNode {
+        #[clap(long)]
+        node_id: u64,
+    },
+
+    /// Transfer the leadership to another voter, e.g., before restarting the leader.
+    /// Requires `--admin-api-address` of any node in the cluster.
+    TransferLeader {
+        /// The node to transfer the leadership to. The most up-to-date voter is chosen if absent.
+        #[clap(long)]
+        to: Option<u64>,
+    },
+
+    /// Put a node in drain mode, in which it does not stand for election, and transfer the leadership if it is the leader.
+    /// Requires `--admin-api-address` of the node.
+    Drain {
+        /// Leave the drain mode.
+        #[clap(long)]
+        off: bool,
+    },
+
+    /// Restore the raft dir from the online backup of databend-meta, to the latest or a past state.
+    ///
+    /// Like `--import`, it rebuilds a new cluster if `--initial-cluster` is specified.
+    Restore {
+        #[clap(flatten)]
+        backup: OuterBackupConfig,
+
+        /// Restore to the state after applying the log at this index.
+        #[clap(long)]
+        to_log_index: Option<u64>,
+
+        /// Restore to the state at this time, in RFC 3339 format, e.g. `2023-09-01T08:00:00Z`.
+        #[clap(long)]
+        to_time: Option<String>,
+    },
 }
 
 /// TODO: This is a temp copy of RaftConfig, we will migrate them in the future.
@@ -121,6 +249,8 @@ impl Default for MetaCtlRaftConfig {
 ///   ["raft_log",{"Logs":{"key":0,"value":{"log_id":{"leader_id":{"term":0,"node_id":0},"index":0},"payload":{"Membership":{"configs":[[1]],"nodes":{"1":{}}}}}}}]
 ///   ["raft_log",{"Logs":{"key":1,"value":{"log_id":{"leader_id":{"term":1,"node_id":0},"index":1},"payload":"Blank"}}}]
 ///   ```
+/// - To access the data of a running cluster: `$0 --grpc-api-address 127.0.0.1:9191 get --key <key>`,
+///   see `$0 help` for all the subcommands.
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let config = Config::parse();
@@ -134,6 +264,10 @@ async fn main() -> anyhow::Result<()> {
         },
         stderr: StderrConfig::default(),
         query: QueryLogConfig::default(),
+        audit: AuditLogConfig {
+            on: false,
+            dir: "".to_string(),
+        },
         tracing: TracingConfig::from_env(),
     };
 
@@ -166,6 +300,10 @@ async fn main() -> anyhow::Result<()> {
     eprintln!();
     eprintln!("Config: {}", pretty(&config)?);
 
+    if let Some(subcommand) = &config.subcommand {
+        return run_subcommand(&config, subcommand).await;
+    }
+
     if !config.cmd.is_empty() {
         return match config.cmd.as_str() {
             "bench-client-conn-num" => {
@@ -199,6 +337,52 @@ async fn main() -> anyhow::Result<()> {
     Err(anyhow::anyhow!("Nothing to do"))
 }
 
+async fn run_subcommand(config: &Config, subcommand: &CtlCommand) -> anyhow::Result<()> {
+    let addr = &config.grpc_api_address;
+    match subcommand {
+        CtlCommand::Get { key } => kv::get(addr, key).await,
+        CtlCommand::Mget { keys } => kv::mget(addr, keys).await,
+        CtlCommand::List { prefix, limit } => kv::list(addr, prefix, *limit).await,
+        CtlCommand::Upsert {
+            key,
+            value,
+            expire_after,
+        } => kv::upsert(addr, key, value, *expire_after).await,
+        CtlCommand::Delete { key } => kv::delete(addr, key).await,
+        CtlCommand::Watch { prefix } => kv::watch(addr, prefix).await,
+        CtlCommand::Status => cluster::status(&config.admin_api_address).await,
+        CtlCommand::AddNode {
+            node_id,
+            endpoint,
+            grpc_api_advertise_address,
+        } => {
+            cluster::add_node(
+                &config.raft_api_address,
+                *node_id,
+                endpoint,
+                grpc_api_advertise_address.clone(),
+            )
+            .await
+        }
+        CtlCommand::RemoveNode { node_id } => {
+            cluster::remove_node(&config.raft_api_address, *node_id).await
+        }
+        CtlCommand::TransferLeader { to } => {
+            cluster::transfer_leader(&config.admin_api_address, *to).await
+        }
+        CtlCommand::Drain { off } => cluster::drain(&config.admin_api_address, !*off).await,
+        CtlCommand::Restore {
+            backup,
+            to_log_index,
+            to_time,
+        } => {
+            eprintln!();
+            eprintln!("Restore:");
+            restore::restore(config, backup, *to_log_index, to_time.as_deref()).await
+        }
+    }
+}
+
 fn pretty<T>(v: &T) -> Result<String, serde_json::Error>
 where T: Serialize {
     serde_json::to_string_pretty(v)
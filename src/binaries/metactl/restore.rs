@@ -0,0 +1,220 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Restore a meta node from the online backup uploaded by databend-meta.
+//!
+//! The latest snapshot backup that is not after the target log index is imported,
+//! with the backed up logs up to the target, which are applied when the node starts up.
+
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
+use common_meta_raft_store::state::RaftStateKey;
+use common_meta_raft_store::state::RaftStateValue;
+use common_meta_raft_store::state_machine::StateMachineMetaKey;
+use common_meta_raft_store::state_machine::StateMachineMetaValue;
+use common_meta_types::EntryPayload;
+use common_meta_types::LogId;
+use common_storage::init_operator;
+use databend_meta::backup::list_backups;
+use databend_meta::backup::parse_log_path;
+use databend_meta::backup::parse_snapshot_path;
+use databend_meta::backup::LOG_DIR;
+use databend_meta::backup::SNAPSHOT_DIR;
+use databend_meta::configs::BackupConfig;
+use databend_meta::configs::OuterBackupConfig;
+use opendal::Operator;
+
+use crate::snapshot::import_from_memory;
+use crate::Config;
+
+/// A line of exported data and the entry it contains.
+type Line = (String, RaftStoreEntry);
+
+pub async fn restore(
+    config: &Config,
+    backup: &OuterBackupConfig,
+    to_log_index: Option<u64>,
+    to_time: Option<&str>,
+) -> anyhow::Result<()> {
+    let backup: BackupConfig = backup.clone().into();
+    let params = backup
+        .storage_params()?
+        .ok_or_else(|| anyhow!("--backup-storage-type is required to restore"))?;
+    let op = init_operator(&params).map_err(|e| anyhow!("{}", e))?;
+
+    eprintln!("    From: {:?}", params);
+
+    let snapshots = list_backups(&op, SNAPSHOT_DIR, parse_snapshot_path).await?;
+    let logs = list_backups(&op, LOG_DIR, parse_log_path).await?;
+
+    let target = match (to_log_index, to_time) {
+        (Some(_), Some(_)) => {
+            return Err(anyhow!(
+                "--to-log-index and --to-time can not be specified together"
+            ));
+        }
+        (Some(index), None) => index,
+        (None, Some(t)) => {
+            let time_ms = chrono::DateTime::parse_from_rfc3339(t)
+                .map_err(|e| anyhow!("invalid --to-time {}: {}", t, e))?
+                .timestamp_millis() as u64;
+            log_index_at_time(&op, &logs, time_ms).await?
+        }
+        (None, None) => {
+            let last_log = logs.last().map(|((_, last), _)| *last);
+            let last_snapshot = snapshots.last().map(|(x, _)| *x);
+            std::cmp::max(last_log, last_snapshot).ok_or_else(|| anyhow!("no backup found"))?
+        }
+    };
+
+    let (snapshot_index, snapshot_path) = snapshots
+        .iter()
+        .rev()
+        .find(|(x, _)| *x <= target)
+        .ok_or_else(|| anyhow!("no snapshot backup at or before log index {}", target))?;
+
+    eprintln!("    Snapshot: {}", snapshot_path);
+    eprintln!("    To log index: {}", target);
+
+    let mut head = vec![];
+    let mut sm = vec![];
+    let mut raft_logs = BTreeMap::new();
+    let mut committed_tree = None;
+    let mut snapshot_last_applied = None;
+
+    for line in parse_lines(&op.read(snapshot_path).await?)? {
+        match &line.1 {
+            RaftStoreEntry::Logs { key, .. } => {
+                if *key <= target {
+                    raft_logs.insert(*key, line);
+                }
+            }
+            RaftStoreEntry::RaftStateKV {
+                key: RaftStateKey::Committed,
+                ..
+            } => {
+                committed_tree = Some(line.0);
+            }
+            RaftStoreEntry::StateMachineMeta {
+                key: StateMachineMetaKey::LastApplied,
+                value: StateMachineMetaValue::LogId(log_id),
+            } => {
+                snapshot_last_applied = Some(*log_id);
+                sm.push(line);
+            }
+            _ => {
+                if line.0.starts_with("state_machine/") {
+                    sm.push(line);
+                } else {
+                    head.push(line);
+                }
+            }
+        }
+    }
+
+    // Logs in the log backups override the ones in the snapshot backup,
+    // which may contain logs that are not committed.
+    for ((first, last), path) in logs.iter() {
+        if *last <= *snapshot_index || *first > target {
+            continue;
+        }
+        eprintln!("    Logs: {}", path);
+
+        for line in parse_lines(&op.read(path).await?)? {
+            if let RaftStoreEntry::Logs { key, .. } = &line.1 {
+                if *key <= target {
+                    raft_logs.insert(*key, line);
+                }
+            }
+        }
+    }
+
+    for index in snapshot_index + 1..=target {
+        if !raft_logs.contains_key(&index) {
+            return Err(anyhow!("log {} is not found in backup", index));
+        }
+    }
+
+    let committed: Option<LogId> = match raft_logs.get(&target) {
+        Some((_, RaftStoreEntry::Logs { value, .. })) => Some(value.log_id),
+        _ => snapshot_last_applied,
+    };
+
+    let committed_tree =
+        committed_tree.ok_or_else(|| anyhow!("raft state is not found in snapshot backup"))?;
+
+    let committed_line = (committed_tree, RaftStoreEntry::RaftStateKV {
+        key: RaftStateKey::Committed,
+        value: RaftStateValue::Committed(committed),
+    });
+
+    // Keep the order of an export: header and raft state, logs, then state machine.
+    let mut data = Vec::new();
+    let lines = head
+        .iter()
+        .chain([&committed_line])
+        .chain(raft_logs.values())
+        .chain(sm.iter());
+    for line in lines {
+        data.extend_from_slice(serde_json::to_string(line)?.as_bytes());
+        data.push(b'\n');
+    }
+
+    import_from_memory(config, data).await?;
+
+    eprintln!("Restored to log index {}", target);
+    Ok(())
+}
+
+/// Find the last log that is proposed at or before `time_ms` in the log backups.
+async fn log_index_at_time(
+    op: &Operator,
+    logs: &[((u64, u64), String)],
+    time_ms: u64,
+) -> anyhow::Result<u64> {
+    let mut found = None;
+
+    for (_, path) in logs.iter() {
+        for (_, ent) in parse_lines(&op.read(path).await?)? {
+            let entry = match ent {
+                RaftStoreEntry::Logs { value, .. } => value,
+                _ => continue,
+            };
+
+            // Logs without a time, such as membership changes, belong to the time of the previous log.
+            if let EntryPayload::Normal(log_entry) = &entry.payload {
+                if log_entry.time_ms.unwrap_or_default() > time_ms {
+                    return found.ok_or_else(|| anyhow!("no log backup before the time"));
+                }
+            }
+
+            found = Some(entry.log_id.index);
+        }
+    }
+
+    found.ok_or_else(|| anyhow!("no log backup before the time"))
+}
+
+fn parse_lines(data: &[u8]) -> anyhow::Result<Vec<Line>> {
+    let mut res = vec![];
+    for l in data.split(|b| *b == b'\n') {
+        if l.is_empty() {
+            continue;
+        }
+        res.push(serde_json::from_slice(l)?);
+    }
+    Ok(res)
+}
@@ -19,6 +19,7 @@ use std::fs::File;
 use std::io;
 use std::io::BufRead;
 use std::io::BufReader;
+use std::io::Cursor;
 use std::io::Lines;
 use std::io::Write;
 use std::net::SocketAddr;
@@ -96,6 +97,29 @@ pub async fn import_data(config: &Config) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Import exported data that is built in memory, e.g., by `restore`, into the raft dir.
+///
+/// Like `import_data`, a new cluster is initialized if `--initial-cluster` is specified.
+pub async fn import_from_memory(config: &Config, data: Vec<u8>) -> anyhow::Result<()> {
+    let raft_config = &config.raft_config;
+    eprintln!("    Into Meta Dir: '{}'", raft_config.raft_dir);
+
+    let nodes = build_nodes(config.initial_cluster.clone(), raft_config.id)?;
+
+    init_sled_db(raft_config.raft_dir.clone());
+
+    clear(config)?;
+    let max_log_id = import_lines(config, Cursor::new(data).lines()).await?;
+    upgrade(config).await?;
+
+    if config.initial_cluster.is_empty() {
+        return Ok(());
+    }
+
+    init_new_cluster(config, nodes, max_log_id, config.raft_config.id).await?;
+    Ok(())
+}
+
 /// Import from lines of exported data and Return the max log id that is found.
 async fn import_lines<B: BufRead + 'static>(
     config: &Config,
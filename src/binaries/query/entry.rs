@@ -38,6 +38,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::sessions::TempTblGc;
 use databend_query::GlobalServices;
 use log::info;
 
@@ -227,6 +228,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Temporary table gc, the data of lost sessions and nodes are collected.
+    TempTblGc::start();
+
     // Print information to users.
     println!("Databend Query");
     println!();
@@ -236,6 +240,7 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
     println!("    file: {}", conf.log.file);
     println!("    stderr: {}", conf.log.stderr);
     println!("    query: {}", conf.log.query);
+    println!("    audit: {}", conf.log.audit);
     println!("    tracing: {}", conf.log.tracing);
     println!(
         "Meta: {}",
@@ -149,6 +149,9 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    UnknownSequence(1122),
+    OutOfSequenceRange(1123),
+    DropOwnedSequence(1124),
 
     // Data Related Errors
 
@@ -190,6 +193,14 @@ build_exceptions! {
     // Index related errors.
     UnsupportedIndex(1601),
     RefreshIndexError(1602),
+
+    // Transaction related errors.
+
+    /// InvalidTransactionState is used when a statement is not allowed in the
+    /// current state of the explicit transaction.
+    ///
+    /// For example: run a statement other than ROLLBACK after a failed statement.
+    InvalidTransactionState(1701),
 }
 
 // Meta service errors [2001, 3000].
@@ -249,6 +260,7 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    SequenceAlreadyExists(2322),
 
 
     // Cluster error codes.
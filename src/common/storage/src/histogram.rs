@@ -18,9 +18,11 @@ use std::fmt::Debug;
 
 use common_exception::Result;
 use common_expression::arithmetics_type::ResultTypeOfUnary;
-use common_storage::Datum;
+
+use crate::Datum;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
+pub const DEFAULT_MOST_COMMON_VALUES: usize = 20;
 
 /// A histogram is a representation of the distribution of a column.
 ///
@@ -33,18 +35,23 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
-#[derive(Debug, Clone)]
+/// The histogram is either built from the sampled values of a column by `ANALYZE TABLE`,
+/// or constructed from NDV(number of distinct values) and the total number of rows
+/// if the column has not been analyzed, which brings the assumption that the data
+/// is uniformly distributed.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct Histogram {
+    /// Whether the histogram is built from the real data
+    pub accuracy: bool,
     pub buckets: Vec<HistogramBucket>,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            accuracy: false,
+            buckets,
+        }
     }
 
     /// Get number of buckets
@@ -95,7 +102,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -150,10 +157,114 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
+}
+
+/// Construct an equi-height histogram from the sampled values of a column.
+///
+/// # Arguments
+///  * `samples` - sorted non-null values sampled from the column
+///  * `num_rows` - total number of non-null rows of the column
+///  * `ndv` - number of distinct values of the column
+///  * `num_buckets` - max number of buckets
+///
+/// Same as [`histogram_from_ndv`], the first bucket only records the min value.
+/// The equal values are always put into the same bucket, so the buckets of skewed
+/// values may have more values than the others.
+pub fn histogram_from_samples(
+    samples: &[Datum],
+    num_rows: u64,
+    ndv: u64,
+    num_buckets: usize,
+) -> Result<Histogram, String> {
+    if samples.is_empty() {
+        return Err("Must have at least 1 sample".to_string());
+    }
+
+    if num_buckets < 2 {
+        return Err(format!("Must have at least 2 buckets, got {}", num_buckets));
+    }
+
+    if !samples[0].is_numeric() {
+        return Err(format!("Unsupported datum type: {:?}", samples[0]));
+    }
+
+    let sample_ndv = samples.windows(2).filter(|w| w[0] != w[1]).count() + 1;
+    if sample_ndv < 2 {
+        return Err("Must have at least 2 distinct values".to_string());
+    }
+
+    // Scale the number of values and distinct values in samples to the whole column.
+    let values_scale = num_rows as f64 / samples.len() as f64;
+    let distinct_scale = (ndv as f64 / sample_ndv as f64).max(1.0);
+
+    // The first bucket is a dummy bucket which records the min value of the column.
+    let num_min = samples.iter().take_while(|v| *v == &samples[0]).count();
+    let mut buckets = Vec::with_capacity(num_buckets + 1);
+    buckets.push(HistogramBucket {
+        upper_bound: samples[0].clone(),
+        num_values: num_min as f64 * values_scale,
+        num_distinct: 1.0,
+    });
+
+    let num_buckets = num_buckets.min(sample_ndv - 1);
+    let bucket_size = (samples.len() - num_min + num_buckets - 1) / num_buckets;
+    let mut start = num_min;
+    while start < samples.len() {
+        let mut end = (start + bucket_size).min(samples.len());
+        // Don't split the equal values into different buckets.
+        while end < samples.len() && samples[end] == samples[end - 1] {
+            end += 1;
+        }
+        let bucket = &samples[start..end];
+        let num_distinct = bucket.windows(2).filter(|w| w[0] != w[1]).count() + 1;
+        buckets.push(HistogramBucket {
+            upper_bound: bucket[bucket.len() - 1].clone(),
+            num_values: bucket.len() as f64 * values_scale,
+            num_distinct: num_distinct as f64 * distinct_scale,
+        });
+        start = end;
+    }
+
+    Ok(Histogram {
+        accuracy: true,
+        buckets,
+    })
+}
+
+/// Find the most common values from the sorted sampled values of a column.
+///
+/// Returns at most `max_values` values which appear more often than the average,
+/// along with their fractions in all the `num_samples` sampled rows.
+pub fn most_common_values(
+    samples: &[Datum],
+    num_samples: usize,
+    max_values: usize,
+) -> Vec<(Datum, f64)> {
+    if samples.is_empty() || num_samples == 0 {
+        return vec![];
+    }
+
+    let mut counts = Vec::new();
+    let mut start = 0;
+    for end in 1..=samples.len() {
+        if end == samples.len() || samples[end] != samples[start] {
+            counts.push((&samples[start], end - start));
+            start = end;
+        }
+    }
+
+    let avg_count = samples.len() as f64 / counts.len() as f64;
+    counts.retain(|(_, count)| *count > 1 && *count as f64 > avg_count);
+    counts.sort_by(|a, b| b.1.cmp(&a.1));
+    counts
+        .into_iter()
+        .take(max_values)
+        .map(|(value, count)| (value.clone(), count as f64 / num_samples as f64))
+        .collect()
 }
 
-#[derive(Debug, Clone)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct HistogramBucket {
     /// Upper bound value of the bucket.
     upper_bound: Datum,
@@ -65,10 +65,20 @@ pub use stage::StageFilesInfo;
 pub use stage::STDIN_FD;
 
 mod copy;
+mod histogram;
 mod statistics;
 
 pub use copy::CopyStatus;
 pub use copy::FileParseError;
 pub use copy::FileStatus;
+pub use histogram::histogram_from_ndv;
+pub use histogram::histogram_from_samples;
+pub use histogram::most_common_values;
+pub use histogram::Histogram;
+pub use histogram::HistogramBucket;
+pub use histogram::InterleavedBucket;
+pub use histogram::UniformSampleSet;
+pub use histogram::DEFAULT_HISTOGRAM_BUCKETS;
+pub use histogram::DEFAULT_MOST_COMMON_VALUES;
 pub use statistics::Datum;
 pub use statistics::F64;
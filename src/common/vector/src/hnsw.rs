@@ -0,0 +1,329 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::index::corrupted;
+use crate::index::l2_squared;
+use crate::index::Neighbour;
+use crate::index::Reader;
+use crate::index::Writer;
+use crate::VectorDistance;
+
+const DEFAULT_M: usize = 16;
+const DEFAULT_EF_CONSTRUCTION: usize = 128;
+const MAX_LEVEL: usize = 16;
+const NONE: u32 = u32::MAX;
+
+/// Hierarchical navigable small world graph, see
+/// "Efficient and robust approximate nearest neighbor search using
+/// Hierarchical Navigable Small World graphs" (Malkov & Yashunin).
+#[derive(Clone, Debug, PartialEq)]
+pub struct HnswIndex {
+    pub(crate) distance: VectorDistance,
+    pub(crate) dim: usize,
+    m: usize,
+    ef_construction: usize,
+    entry_point: u32,
+    vectors: Vec<f32>,
+    /// `links[node][level]` are the neighbours of `node` on `level`.
+    links: Vec<Vec<Vec<u32>>>,
+}
+
+impl HnswIndex {
+    pub(crate) fn build(
+        distance: VectorDistance,
+        dim: usize,
+        vectors: Vec<f32>,
+        m: Option<usize>,
+        ef_construction: Option<usize>,
+    ) -> Result<Self> {
+        let m = m.unwrap_or(DEFAULT_M);
+        let ef_construction = ef_construction.unwrap_or(DEFAULT_EF_CONSTRUCTION);
+        if !(2..=256).contains(&m) {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "HNSW m must be between 2 and 256, got {m}"
+            )));
+        }
+        if ef_construction < m {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "HNSW ef_construction must not be less than m ({m}), got {ef_construction}"
+            )));
+        }
+
+        let count = vectors.len() / dim;
+        let mut index = HnswIndex {
+            distance,
+            dim,
+            m,
+            ef_construction,
+            entry_point: NONE,
+            vectors,
+            links: Vec::with_capacity(count),
+        };
+        let mut rng = SmallRng::seed_from_u64(count as u64);
+        let level_mult = 1.0 / (m as f64).ln();
+        for id in 0..count as u32 {
+            let r: f64 = rng.gen_range(f64::EPSILON..1.0);
+            let level = ((-r.ln() * level_mult) as usize).min(MAX_LEVEL);
+            index.insert(id, level);
+        }
+        Ok(index)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    fn vector(&self, id: u32) -> &[f32] {
+        let start = id as usize * self.dim;
+        &self.vectors[start..start + self.dim]
+    }
+
+    fn max_level(&self) -> usize {
+        match self.entry_point {
+            NONE => 0,
+            ep => self.links[ep as usize].len() - 1,
+        }
+    }
+
+    fn max_links(&self, level: usize) -> usize {
+        if level == 0 { self.m * 2 } else { self.m }
+    }
+
+    fn neighbour(&self, query: &[f32], id: u32) -> Neighbour {
+        Neighbour {
+            distance: l2_squared(query, self.vector(id)),
+            id,
+        }
+    }
+
+    fn insert(&mut self, id: u32, level: usize) {
+        self.links.push(vec![vec![]; level + 1]);
+        if self.entry_point == NONE {
+            self.entry_point = id;
+            return;
+        }
+
+        let query = self.vector(id).to_vec();
+        let max_level = self.max_level();
+        let mut entry = self.neighbour(&query, self.entry_point);
+        for l in (level + 1..=max_level).rev() {
+            entry = self.greedy_search(&query, entry, l);
+        }
+
+        for l in (0..=level.min(max_level)).rev() {
+            let candidates = self.search_level(&query, &[entry], self.ef_construction, l);
+            let neighbours = self.select_neighbours(&candidates, self.max_links(l));
+            for n in &neighbours {
+                self.links[n.id as usize][l].push(id);
+                self.shrink_links(n.id, l);
+            }
+            self.links[id as usize][l] = neighbours.iter().map(|n| n.id).collect();
+            entry = candidates[0];
+        }
+
+        if level > max_level {
+            self.entry_point = id;
+        }
+    }
+
+    /// Keep the candidates that are closer to the new node than to any
+    /// neighbour already selected, which keeps the graph navigable across
+    /// clusters. Fill up with the nearest skipped ones.
+    fn select_neighbours(&self, candidates: &[Neighbour], max: usize) -> Vec<Neighbour> {
+        let mut selected: Vec<Neighbour> = Vec::with_capacity(max);
+        let mut skipped = vec![];
+        for c in candidates {
+            if selected.len() == max {
+                break;
+            }
+            let v = self.vector(c.id);
+            if selected
+                .iter()
+                .all(|s| l2_squared(v, self.vector(s.id)) > c.distance)
+            {
+                selected.push(*c);
+            } else {
+                skipped.push(*c);
+            }
+        }
+        for c in skipped {
+            if selected.len() == max {
+                break;
+            }
+            selected.push(c);
+        }
+        selected
+    }
+
+    fn shrink_links(&mut self, id: u32, level: usize) {
+        let max = self.max_links(level);
+        if self.links[id as usize][level].len() <= max {
+            return;
+        }
+        let v = self.vector(id).to_vec();
+        let mut candidates = self.links[id as usize][level]
+            .iter()
+            .map(|n| self.neighbour(&v, *n))
+            .collect::<Vec<_>>();
+        candidates.sort();
+        let selected = self.select_neighbours(&candidates, max);
+        self.links[id as usize][level] = selected.iter().map(|n| n.id).collect();
+    }
+
+    fn greedy_search(&self, query: &[f32], mut entry: Neighbour, level: usize) -> Neighbour {
+        loop {
+            let mut changed = false;
+            for n in &self.links[entry.id as usize][level] {
+                let candidate = self.neighbour(query, *n);
+                if candidate < entry {
+                    entry = candidate;
+                    changed = true;
+                }
+            }
+            if !changed {
+                return entry;
+            }
+        }
+    }
+
+    /// Beam search on one level, returns up to `ef` nodes, nearest first.
+    fn search_level(
+        &self,
+        query: &[f32],
+        entries: &[Neighbour],
+        ef: usize,
+        level: usize,
+    ) -> Vec<Neighbour> {
+        let mut visited = entries.iter().map(|e| e.id).collect::<HashSet<_>>();
+        let mut candidates = entries
+            .iter()
+            .map(|e| Reverse(*e))
+            .collect::<BinaryHeap<_>>();
+        let mut results = entries.iter().copied().collect::<BinaryHeap<_>>();
+
+        while let Some(Reverse(current)) = candidates.pop() {
+            if results.len() >= ef && current > *results.peek().unwrap() {
+                break;
+            }
+            for n in &self.links[current.id as usize][level] {
+                if !visited.insert(*n) {
+                    continue;
+                }
+                let neighbour = self.neighbour(query, *n);
+                if results.len() < ef || neighbour < *results.peek().unwrap() {
+                    candidates.push(Reverse(neighbour));
+                    results.push(neighbour);
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+        results.into_sorted_vec()
+    }
+
+    pub(crate) fn search(&self, query: &[f32], k: usize, ef: usize) -> Vec<(u32, f32)> {
+        if self.entry_point == NONE || k == 0 {
+            return vec![];
+        }
+        let mut entry = self.neighbour(query, self.entry_point);
+        for l in (1..=self.max_level()).rev() {
+            entry = self.greedy_search(query, entry, l);
+        }
+        self.search_level(query, &[entry], ef.max(k), 0)
+            .into_iter()
+            .take(k)
+            .map(|n| (n.id, n.distance))
+            .collect()
+    }
+
+    pub(crate) fn write(&self, w: &mut Writer) {
+        w.header(self.distance, self.dim);
+        w.u32(self.m as u32);
+        w.u32(self.ef_construction as u32);
+        w.u32(self.entry_point);
+        w.f32s(&self.vectors);
+        w.u32(self.links.len() as u32);
+        for levels in &self.links {
+            w.u8(levels.len() as u8);
+            for links in levels {
+                w.u32s(links);
+            }
+        }
+    }
+
+    pub(crate) fn read(r: &mut Reader) -> Result<Self> {
+        let (distance, dim) = r.header()?;
+        let m = r.u32()? as usize;
+        let ef_construction = r.u32()? as usize;
+        let entry_point = r.u32()?;
+        let vectors = r.f32s()?;
+        let count = r.u32()? as usize;
+        if vectors.len() != count * dim {
+            return Err(corrupted("vector count mismatch"));
+        }
+        let mut links = Vec::with_capacity(count);
+        for _ in 0..count {
+            let level_count = r.u8()? as usize;
+            let mut levels = Vec::with_capacity(level_count);
+            for _ in 0..level_count {
+                let level_links = r.u32s()?;
+                if level_links.iter().any(|n| *n as usize >= count) {
+                    return Err(corrupted("link out of range"));
+                }
+                levels.push(level_links);
+            }
+            links.push(levels);
+        }
+        if (entry_point == NONE) != (count == 0)
+            || (entry_point != NONE && entry_point as usize >= count)
+        {
+            return Err(corrupted("bad entry point"));
+        }
+        let index = HnswIndex {
+            distance,
+            dim,
+            m,
+            ef_construction,
+            entry_point,
+            vectors,
+            links,
+        };
+        // Every level a node links on must exist on the linked node.
+        for levels in &index.links {
+            if levels.is_empty() {
+                return Err(corrupted("node without levels"));
+            }
+            for (level, links) in levels.iter().enumerate() {
+                if links
+                    .iter()
+                    .any(|n| index.links[*n as usize].len() <= level)
+                {
+                    return Err(corrupted("link to a missing level"));
+                }
+            }
+        }
+        Ok(index)
+    }
+}
@@ -0,0 +1,326 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::hnsw::HnswIndex;
+use crate::ivf_pq::IvfPqIndex;
+
+const MAGIC: &[u8; 4] = b"DVI1";
+
+/// The distance a vector index is built for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorDistance {
+    Cosine,
+    L2,
+}
+
+impl VectorDistance {
+    /// Vectors are stored prepared for the distance, so that both distances
+    /// can be searched with the squared euclidean distance: for unit vectors
+    /// `|a - b|^2 = 2 * cosine_distance(a, b)`.
+    pub(crate) fn prepare(&self, vector: &[f32]) -> Vec<f32> {
+        match self {
+            VectorDistance::L2 => vector.to_vec(),
+            VectorDistance::Cosine => {
+                let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+                if norm == 0.0 {
+                    vector.to_vec()
+                } else {
+                    vector.iter().map(|v| v / norm).collect()
+                }
+            }
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            VectorDistance::Cosine => 0,
+            VectorDistance::L2 => 1,
+        }
+    }
+
+    fn from_u8(v: u8) -> Result<Self> {
+        match v {
+            0 => Ok(VectorDistance::Cosine),
+            1 => Ok(VectorDistance::L2),
+            _ => Err(corrupted(format!("unknown distance {v}"))),
+        }
+    }
+}
+
+/// Build parameters of a vector index, `None` means the default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorIndexParams {
+    Hnsw {
+        /// The number of neighbours kept for every node on the upper layers,
+        /// the bottom layer keeps twice as many.
+        m: Option<usize>,
+        /// The size of the candidate list while inserting a node.
+        ef_construction: Option<usize>,
+    },
+    IvfPq {
+        /// The number of inverted lists (k-means partitions).
+        lists: Option<usize>,
+        /// The number of sub-vectors each vector is split into, every
+        /// sub-vector is encoded into one byte. It must divide the dimension.
+        sub_vectors: Option<usize>,
+    },
+}
+
+/// An approximate nearest-neighbour index over a set of vectors.
+///
+/// Vectors are identified by the order they are passed to [`VectorIndex::build`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum VectorIndex {
+    Hnsw(HnswIndex),
+    IvfPq(IvfPqIndex),
+}
+
+impl VectorIndex {
+    /// Build an index over `vectors`, which are stored one after another and
+    /// all have `dim` elements. The build is deterministic.
+    pub fn build(
+        params: VectorIndexParams,
+        distance: VectorDistance,
+        dim: usize,
+        vectors: &[f32],
+    ) -> Result<Self> {
+        if dim == 0 || vectors.len() % dim != 0 {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector index expects vectors of dimension {dim}, got {} values",
+                vectors.len()
+            )));
+        }
+        let prepared = vectors
+            .chunks(dim)
+            .flat_map(|v| distance.prepare(v))
+            .collect::<Vec<_>>();
+        match params {
+            VectorIndexParams::Hnsw { m, ef_construction } => Ok(VectorIndex::Hnsw(
+                HnswIndex::build(distance, dim, prepared, m, ef_construction)?,
+            )),
+            VectorIndexParams::IvfPq { lists, sub_vectors } => Ok(VectorIndex::IvfPq(
+                IvfPqIndex::build(distance, dim, &prepared, lists, sub_vectors)?,
+            )),
+        }
+    }
+
+    pub fn distance(&self) -> VectorDistance {
+        match self {
+            VectorIndex::Hnsw(index) => index.distance,
+            VectorIndex::IvfPq(index) => index.distance,
+        }
+    }
+
+    pub fn dim(&self) -> usize {
+        match self {
+            VectorIndex::Hnsw(index) => index.dim,
+            VectorIndex::IvfPq(index) => index.dim,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            VectorIndex::Hnsw(index) => index.len(),
+            VectorIndex::IvfPq(index) => index.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Search the approximate `k` nearest vectors of `query`, nearest first.
+    ///
+    /// `effort` trades speed for recall: it is the candidate list size
+    /// (`ef_search`) of HNSW, and IVF_PQ probes `effort / 8` lists and keeps
+    /// `effort` candidates before cutting the result to `k`.
+    ///
+    /// The returned distances are only meant for ranking.
+    pub fn search(&self, query: &[f32], k: usize, effort: usize) -> Result<Vec<(u32, f32)>> {
+        if query.len() != self.dim() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                query.len(),
+                self.dim(),
+            )));
+        }
+        let query = self.distance().prepare(query);
+        let effort = effort.max(1);
+        Ok(match self {
+            VectorIndex::Hnsw(index) => index.search(&query, k, effort),
+            VectorIndex::IvfPq(index) => index.search(&query, k, effort),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = Writer::default();
+        w.bytes(MAGIC);
+        match self {
+            VectorIndex::Hnsw(index) => {
+                w.u8(0);
+                index.write(&mut w);
+            }
+            VectorIndex::IvfPq(index) => {
+                w.u8(1);
+                index.write(&mut w);
+            }
+        }
+        w.0
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut r = Reader(bytes);
+        if r.bytes(MAGIC.len())? != MAGIC {
+            return Err(corrupted("bad magic"));
+        }
+        let index = match r.u8()? {
+            0 => VectorIndex::Hnsw(HnswIndex::read(&mut r)?),
+            1 => VectorIndex::IvfPq(IvfPqIndex::read(&mut r)?),
+            v => return Err(corrupted(format!("unknown index type {v}"))),
+        };
+        if !r.0.is_empty() {
+            return Err(corrupted("trailing bytes"));
+        }
+        Ok(index)
+    }
+}
+
+/// Squared euclidean distance.
+#[inline]
+pub(crate) fn l2_squared(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+/// A search candidate, ordered by distance then id.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Neighbour {
+    pub(crate) distance: f32,
+    pub(crate) id: u32,
+}
+
+impl PartialEq for Neighbour {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Neighbour {}
+
+impl PartialOrd for Neighbour {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Neighbour {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.id.cmp(&other.id))
+    }
+}
+
+pub(crate) fn corrupted(msg: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::StorageOther(format!("corrupted vector index: {msg}"))
+}
+
+/// Little endian encoder of the index files.
+#[derive(Default)]
+pub(crate) struct Writer(pub(crate) Vec<u8>);
+
+impl Writer {
+    pub(crate) fn bytes(&mut self, v: &[u8]) {
+        self.0.extend_from_slice(v);
+    }
+
+    pub(crate) fn u8(&mut self, v: u8) {
+        self.0.push(v);
+    }
+
+    pub(crate) fn u32(&mut self, v: u32) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    pub(crate) fn header(&mut self, distance: VectorDistance, dim: usize) {
+        self.u8(distance.to_u8());
+        self.u32(dim as u32);
+    }
+
+    pub(crate) fn u32s(&mut self, v: &[u32]) {
+        self.u32(v.len() as u32);
+        for x in v {
+            self.u32(*x);
+        }
+    }
+
+    pub(crate) fn f32s(&mut self, v: &[f32]) {
+        self.u32(v.len() as u32);
+        for x in v {
+            self.0.extend_from_slice(&x.to_le_bytes());
+        }
+    }
+}
+
+pub(crate) struct Reader<'a>(pub(crate) &'a [u8]);
+
+impl<'a> Reader<'a> {
+    pub(crate) fn bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.0.len() < n {
+            return Err(corrupted("unexpected end of file"));
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn header(&mut self) -> Result<(VectorDistance, usize)> {
+        let distance = VectorDistance::from_u8(self.u8()?)?;
+        let dim = self.u32()? as usize;
+        if dim == 0 {
+            return Err(corrupted("zero dimension"));
+        }
+        Ok((distance, dim))
+    }
+
+    pub(crate) fn u32s(&mut self) -> Result<Vec<u32>> {
+        let len = self.u32()? as usize;
+        let bytes = self.bytes(len * 4)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+            .collect())
+    }
+
+    pub(crate) fn f32s(&mut self) -> Result<Vec<f32>> {
+        let len = self.u32()? as usize;
+        let bytes = self.bytes(len * 4)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+            .collect())
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BinaryHeap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use rand::rngs::SmallRng;
+use rand::seq::index::sample;
+use rand::SeedableRng;
+
+use crate::index::corrupted;
+use crate::index::l2_squared;
+use crate::index::Neighbour;
+use crate::index::Reader;
+use crate::index::Writer;
+use crate::VectorDistance;
+
+const MAX_LISTS: usize = 4096;
+/// Every sub-vector is encoded into one byte.
+const MAX_CODEBOOK_SIZE: usize = 256;
+const KMEANS_ITERATIONS: usize = 10;
+/// The number of training vectors per centroid of k-means.
+const TRAINING_SAMPLES_PER_CENTROID: usize = 64;
+
+/// Inverted file index with product quantization: vectors are partitioned by
+/// k-means, and the residual of every vector to its partition centroid is
+/// compressed into one byte per sub-vector.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IvfPqIndex {
+    pub(crate) distance: VectorDistance,
+    pub(crate) dim: usize,
+    sub_vectors: usize,
+    codebook_size: usize,
+    /// `lists` centroids of `dim` elements.
+    centroids: Vec<f32>,
+    /// For every sub-vector, `codebook_size` codes of `dim / sub_vectors` elements.
+    codebooks: Vec<f32>,
+    /// The ids of the vectors in every list.
+    list_ids: Vec<Vec<u32>>,
+    /// The codes of the vectors in every list, `sub_vectors` bytes per vector.
+    list_codes: Vec<Vec<u8>>,
+}
+
+impl IvfPqIndex {
+    pub(crate) fn build(
+        distance: VectorDistance,
+        dim: usize,
+        vectors: &[f32],
+        lists: Option<usize>,
+        sub_vectors: Option<usize>,
+    ) -> Result<Self> {
+        let count = vectors.len() / dim;
+        let sub_vectors = match sub_vectors {
+            Some(m) if m == 0 || dim % m != 0 => {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "IVF_PQ sub_vectors must divide the vector dimension {dim}, got {m}"
+                )));
+            }
+            Some(m) => m,
+            // Two or more elements per sub-vector, at most 16 bytes per vector.
+            None => [16, 8, 4, 2, 1]
+                .into_iter()
+                .find(|m| dim % m == 0 && dim / m >= 2)
+                .unwrap_or(1),
+        };
+        let lists = match lists {
+            Some(l) if l == 0 || l > MAX_LISTS => {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "IVF_PQ lists must be between 1 and {MAX_LISTS}, got {l}"
+                )));
+            }
+            Some(l) => l,
+            None => ((count as f64).sqrt() as usize).clamp(1, MAX_LISTS),
+        }
+        .min(count.max(1));
+
+        let mut rng = SmallRng::seed_from_u64(count as u64);
+        let centroids = kmeans(vectors, dim, lists, &mut rng);
+        let assignments = (0..count)
+            .map(|i| nearest(&centroids, dim, &vectors[i * dim..(i + 1) * dim]))
+            .collect::<Vec<_>>();
+        let mut residuals = Vec::with_capacity(vectors.len());
+        for (i, list) in assignments.iter().enumerate() {
+            let centroid = &centroids[list * dim..(list + 1) * dim];
+            residuals.extend(
+                vectors[i * dim..(i + 1) * dim]
+                    .iter()
+                    .zip(centroid)
+                    .map(|(v, c)| v - c),
+            );
+        }
+
+        let sub_dim = dim / sub_vectors;
+        let codebook_size = count.clamp(1, MAX_CODEBOOK_SIZE);
+        let mut codebooks = Vec::with_capacity(sub_vectors * codebook_size * sub_dim);
+        let mut sub_data = Vec::with_capacity(count * sub_dim);
+        for s in 0..sub_vectors {
+            sub_data.clear();
+            for i in 0..count {
+                let start = i * dim + s * sub_dim;
+                sub_data.extend_from_slice(&residuals[start..start + sub_dim]);
+            }
+            codebooks.extend(kmeans(&sub_data, sub_dim, codebook_size, &mut rng));
+        }
+
+        let mut index = IvfPqIndex {
+            distance,
+            dim,
+            sub_vectors,
+            codebook_size,
+            centroids,
+            codebooks,
+            list_ids: vec![vec![]; lists],
+            list_codes: vec![vec![]; lists],
+        };
+        for (i, list) in assignments.into_iter().enumerate() {
+            let residual = &residuals[i * dim..(i + 1) * dim];
+            for s in 0..sub_vectors {
+                let code = nearest(
+                    index.codebook(s),
+                    sub_dim,
+                    &residual[s * sub_dim..(s + 1) * sub_dim],
+                );
+                index.list_codes[list].push(code as u8);
+            }
+            index.list_ids[list].push(i as u32);
+        }
+        Ok(index)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.list_ids.iter().map(|ids| ids.len()).sum()
+    }
+
+    fn codebook(&self, sub_vector: usize) -> &[f32] {
+        let size = self.codebook_size * self.dim / self.sub_vectors;
+        &self.codebooks[sub_vector * size..(sub_vector + 1) * size]
+    }
+
+    pub(crate) fn search(&self, query: &[f32], k: usize, effort: usize) -> Vec<(u32, f32)> {
+        let lists = self.list_ids.len();
+        if k == 0 || lists == 0 {
+            return vec![];
+        }
+        let probes = (effort / 8).clamp(1, lists);
+        let mut nearest_lists = (0..lists)
+            .map(|l| Neighbour {
+                distance: l2_squared(query, &self.centroids[l * self.dim..(l + 1) * self.dim]),
+                id: l as u32,
+            })
+            .collect::<Vec<_>>();
+        nearest_lists.sort();
+
+        let sub_dim = self.dim / self.sub_vectors;
+        let keep = effort.max(k);
+        let mut results = BinaryHeap::with_capacity(keep + 1);
+        let mut table = vec![0f32; self.sub_vectors * self.codebook_size];
+        for list in nearest_lists.into_iter().take(probes) {
+            let l = list.id as usize;
+            let centroid = &self.centroids[l * self.dim..(l + 1) * self.dim];
+            // Distances between the residual of the query and every code.
+            for s in 0..self.sub_vectors {
+                let residual = query[s * sub_dim..(s + 1) * sub_dim]
+                    .iter()
+                    .zip(&centroid[s * sub_dim..(s + 1) * sub_dim])
+                    .map(|(q, c)| q - c)
+                    .collect::<Vec<_>>();
+                let codebook = self.codebook(s);
+                for c in 0..self.codebook_size {
+                    table[s * self.codebook_size + c] =
+                        l2_squared(&residual, &codebook[c * sub_dim..(c + 1) * sub_dim]);
+                }
+            }
+            let codes = self.list_codes[l].chunks_exact(self.sub_vectors);
+            for (id, code) in self.list_ids[l].iter().zip(codes) {
+                let distance = code
+                    .iter()
+                    .enumerate()
+                    .map(|(s, c)| table[s * self.codebook_size + *c as usize])
+                    .sum();
+                results.push(Neighbour { distance, id: *id });
+                if results.len() > keep {
+                    results.pop();
+                }
+            }
+        }
+        results
+            .into_sorted_vec()
+            .into_iter()
+            .take(k)
+            .map(|n| (n.id, n.distance))
+            .collect()
+    }
+
+    pub(crate) fn write(&self, w: &mut Writer) {
+        w.header(self.distance, self.dim);
+        w.u32(self.sub_vectors as u32);
+        w.u32(self.codebook_size as u32);
+        w.f32s(&self.centroids);
+        w.f32s(&self.codebooks);
+        w.u32(self.list_ids.len() as u32);
+        for (ids, codes) in self.list_ids.iter().zip(&self.list_codes) {
+            w.u32s(ids);
+            w.bytes(codes);
+        }
+    }
+
+    pub(crate) fn read(r: &mut Reader) -> Result<Self> {
+        let (distance, dim) = r.header()?;
+        let sub_vectors = r.u32()? as usize;
+        let codebook_size = r.u32()? as usize;
+        if sub_vectors == 0
+            || dim % sub_vectors != 0
+            || codebook_size == 0
+            || codebook_size > MAX_CODEBOOK_SIZE
+        {
+            return Err(corrupted("bad quantizer"));
+        }
+        let centroids = r.f32s()?;
+        let codebooks = r.f32s()?;
+        let lists = r.u32()? as usize;
+        if centroids.len() != lists * dim || codebooks.len() != codebook_size * dim {
+            return Err(corrupted("bad centroids"));
+        }
+        let mut list_ids = Vec::with_capacity(lists);
+        let mut list_codes = Vec::with_capacity(lists);
+        for _ in 0..lists {
+            let ids = r.u32s()?;
+            let codes = r.bytes(ids.len() * sub_vectors)?;
+            if codes.iter().any(|c| *c as usize >= codebook_size) {
+                return Err(corrupted("code out of range"));
+            }
+            list_ids.push(ids);
+            list_codes.push(codes.to_vec());
+        }
+        Ok(IvfPqIndex {
+            distance,
+            dim,
+            sub_vectors,
+            codebook_size,
+            centroids,
+            codebooks,
+            list_ids,
+            list_codes,
+        })
+    }
+}
+
+/// The index of the centroid nearest to `vector`.
+fn nearest(centroids: &[f32], dim: usize, vector: &[f32]) -> usize {
+    centroids
+        .chunks_exact(dim)
+        .enumerate()
+        .map(|(i, c)| (l2_squared(vector, c), i))
+        .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)))
+        .map(|(_, i)| i)
+        .unwrap_or(0)
+}
+
+/// Lloyd's k-means over a random sample of `data`, initialized with random
+/// distinct points. Returns `k` centroids of `dim` elements.
+fn kmeans(data: &[f32], dim: usize, k: usize, rng: &mut SmallRng) -> Vec<f32> {
+    let count = data.len() / dim;
+    if count == 0 {
+        return vec![0.0; k * dim];
+    }
+    let sample_size = count.min(k * TRAINING_SAMPLES_PER_CENTROID);
+    let training = sample(rng, count, sample_size).into_vec();
+
+    let mut centroids = Vec::with_capacity(k * dim);
+    for i in training.iter().take(k) {
+        centroids.extend_from_slice(&data[i * dim..(i + 1) * dim]);
+    }
+    // Fewer distinct points than centroids: repeat the last one.
+    while centroids.len() < k * dim {
+        centroids.extend_from_within(centroids.len() - dim..);
+    }
+
+    let mut sums = vec![0f32; k * dim];
+    let mut counts = vec![0usize; k];
+    for _ in 0..KMEANS_ITERATIONS {
+        sums.iter_mut().for_each(|s| *s = 0.0);
+        counts.iter_mut().for_each(|c| *c = 0);
+        for i in &training {
+            let vector = &data[i * dim..(i + 1) * dim];
+            let c = nearest(&centroids, dim, vector);
+            counts[c] += 1;
+            for (s, v) in sums[c * dim..(c + 1) * dim].iter_mut().zip(vector) {
+                *s += v;
+            }
+        }
+        for c in 0..k {
+            // An empty cluster keeps its centroid.
+            if counts[c] > 0 {
+                for d in 0..dim {
+                    centroids[c * dim + d] = sums[c * dim + d] / counts[c] as f32;
+                }
+            }
+        }
+    }
+    centroids
+}
@@ -13,6 +13,14 @@
 // limitations under the License.
 
 mod distance;
+mod hnsw;
+mod index;
+mod ivf_pq;
 
 pub use distance::cosine_distance;
 pub use distance::l2_distance;
+pub use hnsw::HnswIndex;
+pub use index::VectorDistance;
+pub use index::VectorIndex;
+pub use index::VectorIndexParams;
+pub use ivf_pq::IvfPqIndex;
@@ -0,0 +1,168 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_vector::cosine_distance;
+use common_vector::l2_distance;
+use common_vector::VectorDistance;
+use common_vector::VectorIndex;
+use common_vector::VectorIndexParams;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+const DIM: usize = 8;
+
+/// Points on a few random planes, like embeddings of related texts which
+/// have a low intrinsic dimension.
+fn clustered_vectors(count: usize, seed: u64) -> Vec<f32> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let planes = (0..8 * 3 * DIM)
+        .map(|_| rng.gen_range(-1.0..1.0))
+        .collect::<Vec<f32>>();
+    (0..count)
+        .flat_map(|i| {
+            let plane = &planes[(i % 8) * 3 * DIM..(i % 8 + 1) * 3 * DIM];
+            let (a, b): (f32, f32) = (rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0));
+            (0..DIM)
+                .map(|d| {
+                    plane[d]
+                        + a * plane[DIM + d]
+                        + b * plane[2 * DIM + d]
+                        + rng.gen_range(-0.01..0.01)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn exact_top_k(distance: VectorDistance, vectors: &[f32], query: &[f32], k: usize) -> Vec<u32> {
+    let mut all = vectors
+        .chunks(DIM)
+        .enumerate()
+        .map(|(i, v)| {
+            let d = match distance {
+                VectorDistance::Cosine => cosine_distance(v, query).unwrap(),
+                VectorDistance::L2 => l2_distance(v, query).unwrap(),
+            };
+            (d, i as u32)
+        })
+        .collect::<Vec<_>>();
+    all.sort_by(|a, b| a.0.total_cmp(&b.0));
+    all.into_iter().take(k).map(|(_, i)| i).collect()
+}
+
+fn recall(index: &VectorIndex, vectors: &[f32], queries: &[f32], k: usize, effort: usize) -> f64 {
+    let mut found = 0;
+    for query in queries.chunks(DIM) {
+        let expected = exact_top_k(index.distance(), vectors, query, k);
+        let result = index.search(query, k, effort).unwrap();
+        assert_eq!(result.len(), k);
+        assert!(result.windows(2).all(|w| w[0].1 <= w[1].1));
+        found += result
+            .iter()
+            .filter(|(id, _)| expected.contains(id))
+            .count();
+    }
+    found as f64 / (queries.len() / DIM * k) as f64
+}
+
+#[test]
+fn test_hnsw_index() {
+    let data = clustered_vectors(1020, 1);
+    let (vectors, queries) = data.split_at(1000 * DIM);
+    for distance in [VectorDistance::Cosine, VectorDistance::L2] {
+        let params = VectorIndexParams::Hnsw {
+            m: None,
+            ef_construction: None,
+        };
+        let index = VectorIndex::build(params, distance, DIM, vectors).unwrap();
+        assert_eq!(index.len(), 1000);
+        assert!(recall(&index, vectors, queries, 10, 64) >= 0.9);
+
+        let bytes = index.to_bytes();
+        assert_eq!(VectorIndex::from_bytes(&bytes).unwrap(), index);
+
+        // The build is deterministic.
+        let small = &vectors[..200 * DIM];
+        assert_eq!(
+            VectorIndex::build(params, distance, DIM, small).unwrap(),
+            VectorIndex::build(params, distance, DIM, small).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn test_ivf_pq_index() {
+    let data = clustered_vectors(1020, 2);
+    let (vectors, queries) = data.split_at(1000 * DIM);
+    for distance in [VectorDistance::Cosine, VectorDistance::L2] {
+        let params = VectorIndexParams::IvfPq {
+            lists: Some(8),
+            sub_vectors: None,
+        };
+        let index = VectorIndex::build(params, distance, DIM, vectors).unwrap();
+        assert_eq!(index.len(), 1000);
+
+        // Probing more lists and keeping more candidates finds more.
+        let low = recall(&index, vectors, queries, 10, 8);
+        let high = recall(&index, vectors, queries, 10, 128);
+        assert!(high >= low);
+        assert!(high >= 0.8);
+
+        let bytes = index.to_bytes();
+        assert_eq!(VectorIndex::from_bytes(&bytes).unwrap(), index);
+    }
+
+    // sub_vectors must divide the dimension.
+    let params = VectorIndexParams::IvfPq {
+        lists: None,
+        sub_vectors: Some(3),
+    };
+    assert!(VectorIndex::build(params, VectorDistance::L2, DIM, vectors).is_err());
+}
+
+#[test]
+fn test_vector_index_edge_cases() {
+    let params = [
+        VectorIndexParams::Hnsw {
+            m: None,
+            ef_construction: None,
+        },
+        VectorIndexParams::IvfPq {
+            lists: None,
+            sub_vectors: None,
+        },
+    ];
+    for params in params {
+        // Empty and tiny indexes.
+        let empty = VectorIndex::build(params, VectorDistance::L2, 2, &[]).unwrap();
+        assert!(empty.is_empty());
+        assert!(empty.search(&[0.0, 0.0], 3, 64).unwrap().is_empty());
+
+        let index =
+            VectorIndex::build(params, VectorDistance::L2, 2, &[0.0, 0.0, 5.0, 5.0]).unwrap();
+        let result = index.search(&[4.0, 4.0], 5, 64).unwrap();
+        assert_eq!(result.iter().map(|r| r.0).collect::<Vec<_>>(), vec![1, 0]);
+
+        // Dimension mismatches.
+        assert!(index.search(&[1.0], 1, 64).is_err());
+        assert!(VectorIndex::build(params, VectorDistance::L2, 2, &[1.0, 2.0, 3.0]).is_err());
+
+        // Corrupted files are rejected instead of panicking.
+        let bytes = index.to_bytes();
+        for len in 0..bytes.len() {
+            assert!(VectorIndex::from_bytes(&bytes[..len]).is_err());
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Audit events of the schema changes committed through the meta API.
+//!
+//! The query interpreters write a richer event with the actor and the object state,
+//! while this one also covers the changes made by other meta API clients.
+
+use std::fmt::Display;
+
+use log::info;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct MetaAuditEvent<'a> {
+    source: &'static str,
+    action: &'a str,
+    object: String,
+    change: String,
+}
+
+/// Emits an audit event after a change is committed to the meta-service.
+///
+/// `change` describes the committed change and must not contain secrets.
+pub fn audit_meta_change(action: &str, object: impl Display, change: String) {
+    let event = MetaAuditEvent {
+        source: "meta_api",
+        action,
+        object: object.to_string(),
+        change,
+    };
+
+    match serde_json::to_string(&event) {
+        Ok(s) => info!(target: "audit", "{}", s),
+        Err(e) => info!(target: "audit", "fail to serialize audit event: {}", e),
+    }
+}
@@ -52,6 +52,7 @@ fn new_background_task(
         message: "".to_string(),
         compaction_task_stats: None,
         vacuum_stats: None,
+        sql_task_stats: None,
         manual_trigger: None,
         creator: None,
         created_at,
@@ -69,6 +70,7 @@ fn new_background_job(state: BackgroundJobState, created_at: DateTime<Utc>) -> B
         }),
         last_updated: None,
         task_type: Default::default(),
+        sql_task: None,
         message: "".to_string(),
         creator: None,
         created_at,
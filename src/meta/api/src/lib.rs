@@ -16,6 +16,7 @@
 #![deny(unused_crate_dependencies)]
 extern crate common_meta_types;
 
+mod audit;
 mod background_api;
 mod background_api_impl;
 mod background_api_keys;
@@ -32,6 +33,8 @@ mod schema_api;
 mod schema_api_impl;
 mod schema_api_keys;
 mod schema_api_test_suite;
+mod sequence_api;
+mod sequence_api_impl;
 mod share_api;
 mod share_api_impl;
 mod share_api_keys;
@@ -47,6 +50,7 @@ pub(crate) use id_generator::IdGenerator;
 pub use schema_api::SchemaApi;
 pub(crate) use schema_api_impl::get_db_or_err;
 pub use schema_api_test_suite::SchemaApiTestSuite;
+pub use sequence_api::SequenceApi;
 pub use share_api::ShareApi;
 pub use share_api_test_suite::ShareApiTestSuite;
 pub use util::assert_table_exist;
@@ -76,4 +80,5 @@ pub use util::txn_op_del;
 pub use util::txn_op_put;
 pub use util::txn_op_put_with_expire;
 pub use util::DEFAULT_MGET_SIZE;
+pub use util::DEFAULT_RANGE_PAGE_SIZE;
 pub use util::TXN_MAX_RETRY_TIMES;
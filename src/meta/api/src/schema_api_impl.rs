@@ -121,6 +121,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceMeta;
+use common_meta_app::schema::SequenceNameIdent;
 use common_meta_app::schema::SetLVTReply;
 use common_meta_app::schema::SetLVTReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyAction;
@@ -146,6 +148,8 @@ use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -185,6 +189,7 @@ use log::error;
 use ConditionResult::Eq;
 
 use crate::assert_table_exist;
+use crate::audit::audit_meta_change;
 use crate::convert_share_meta_to_spec;
 use crate::db_has_to_exist;
 use crate::deserialize_struct;
@@ -216,11 +221,13 @@ use crate::util::get_virtual_column_by_id_or_err;
 use crate::util::list_tables_from_share_db;
 use crate::util::list_tables_from_unshare_db;
 use crate::util::mget_pb_values;
+use crate::util::prefix_range_req;
 use crate::util::remove_table_from_share;
 use crate::util::txn_trials;
 use crate::IdGenerator;
 use crate::SchemaApi;
 use crate::DEFAULT_MGET_SIZE;
+use crate::DEFAULT_RANGE_PAGE_SIZE;
 use crate::TXN_MAX_RETRY_TIMES;
 
 const DEFAULT_DATA_RETENTION_SECONDS: i64 = 24 * 60 * 60;
@@ -326,6 +333,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 );
 
                 if succ {
+                    audit_meta_change("create_database", name_key, req.meta.to_string());
                     return Ok(CreateDatabaseReply { db_id });
                 }
             }
@@ -492,6 +500,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             );
 
             if succ {
+                audit_meta_change("drop_database", tenant_dbname, "".to_string());
                 return Ok(DropDatabaseReply {
                     spec_vec: if spec_vec.is_empty() {
                         None
@@ -598,6 +607,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 );
 
                 if succ {
+                    audit_meta_change("undrop_database", &req.name_ident, "".to_string());
                     return Ok(UndropDatabaseReply {});
                 }
             }
@@ -741,6 +751,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 );
 
                 if succ {
+                    audit_meta_change("rename_database", &req.name_ident, req.to_string());
                     return Ok(RenameDatabaseReply {});
                 }
             }
@@ -1585,7 +1596,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 // append new table_id into list
                 tb_id_list.append(table_id);
 
-                let txn_req = TxnRequest {
+                let mut txn_req = TxnRequest {
                     condition: vec![
                         // db has not to change, i.e., no new table is created.
                         // Renaming db is OK and does not affect the seq of db_meta.
@@ -1611,6 +1622,20 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     else_then: vec![],
                 };
 
+                // The sequences of AUTOINCREMENT columns are owned by the table,
+                // they are created along with it and removed when it is gc-ed.
+                for sequence in req.auto_increment_sequences.iter() {
+                    let mut sequence_meta: SequenceMeta = sequence.clone().into();
+                    sequence_meta.table_id = Some(table_id);
+                    txn_req
+                        .condition
+                        .push(txn_cond_seq(&sequence.name_ident, Eq, 0));
+                    txn_req.if_then.push(txn_op_put(
+                        &sequence.name_ident,
+                        serialize_struct(&sequence_meta)?,
+                    ));
+                }
+
                 let (succ, _responses) = send_txn(self, txn_req).await?;
 
                 debug!(
@@ -1621,6 +1646,11 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 );
 
                 if succ {
+                    audit_meta_change(
+                        "create_table",
+                        tenant_dbname_tbname,
+                        req.table_meta.to_string(),
+                    );
                     return Ok(CreateTableReply {
                         table_id,
                         new_table: true,
@@ -1805,6 +1835,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 );
 
                 if succ {
+                    audit_meta_change("undrop_table", tenant_dbname_tbname, "".to_string());
                     return Ok(UndropTableReply {});
                 }
             }
@@ -2004,6 +2035,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 );
 
                 if succ {
+                    audit_meta_change("rename_table", tenant_dbname_tbname, req.to_string());
                     return Ok(RenameTableReply { table_id });
                 }
             }
@@ -2281,6 +2313,23 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         ))
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn get_table_name_by_id(&self, table_id: MetaId) -> Result<String, KVAppError> {
+        debug!(req = as_debug!(&table_id); "SchemaApi: {}", func_name!());
+
+        let table_id_to_name_key = TableIdToName { table_id };
+        let (_, db_id_table_name): (_, Option<DBIdTableName>) =
+            get_pb_value(self, &table_id_to_name_key).await?;
+
+        match db_id_table_name {
+            Some(db_id_table_name) => Ok(db_id_table_name.table_name),
+            None => Err(KVAppError::AppError(AppError::UnknownTableId(
+                UnknownTableId::new(table_id, "get_table_name_by_id"),
+            ))),
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply, KVAppError> {
@@ -2451,6 +2500,11 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     "drop_table_by_id"
                 );
                 if succ {
+                    audit_meta_change(
+                        "drop_table",
+                        format!("table_id: {}", table_id),
+                        req.to_string(),
+                    );
                     return Ok(DropTableReply {
                         spec_vec: if spec_vec.is_empty() {
                             None
@@ -2539,68 +2593,38 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
 
         let chunk_size = req.batch_size.unwrap_or(DEFAULT_MGET_SIZE as u64);
 
-        // 1. Grab a snapshot view of the copied files of a table.
-        //
-        // If table seq is not changed before and after listing, we can be sure the list of copied
-        // files is consistent to this version of the table.
-
-        let (mut seq_1, _tb_meta) = get_table_by_id_or_err(self, &table_id, ctx).await?;
-
-        let mut trials = txn_trials(None, ctx);
-        let copied_files = loop {
-            trials.next().unwrap()?;
-
-            let copied_files = list_table_copied_files(self, table_id.table_id).await?;
-
-            let (seq_2, _tb_meta) = get_table_by_id_or_err(self, &table_id, ctx).await?;
-
-            if seq_1 == seq_2 {
-                debug!(
-                    "list all copied file of table {}: {:?}",
-                    table_id.table_id, copied_files
-                );
-                break copied_files;
-            } else {
-                seq_1 = seq_2;
-            }
-        };
-
-        // 2. Remove the copied files only when the seq of a copied file has not changed.
+        // Scan the copied files of a table page by page, and remove every page in a transaction.
         //
         // During running this step with several small transaction, other transactions may be
         // modifying the table.
         //
-        // - We assert the table seq is not changed in each transaction.
-        // - We do not assert the seq of each copied file in each transaction, since we only delete
-        //   non-changed ones.
-
-        for chunk in copied_files.chunks(chunk_size as usize) {
-            let str_keys: Vec<_> = chunk.iter().map(|f| f.to_string_key()).collect();
+        // - We assert the table seq is not changed in each transaction: if the table is changed
+        //   after a page is scanned, the page is scanned again.
+        // - We delete a copied file only if its seq is the same as when it was scanned.
 
-            // Load the `seq` of every copied file
-            let seqs = {
-                let seq_infos: Vec<(u64, Option<TableCopiedFileInfo>)> =
-                    mget_pb_values(self, &str_keys).await?;
-
-                seq_infos.into_iter().map(|(seq, _)| seq)
-            };
-
-            let mut if_then = vec![];
-            for (copied_seq, copied_str_key) in seqs.zip(str_keys) {
-                if copied_seq == 0 {
-                    continue;
-                }
-
-                if_then.push(TxnOp::delete_exact(copied_str_key, Some(copied_seq)));
-            }
+        let copied_file_ident = TableCopiedFileNameIdent {
+            table_id: table_id.table_id,
+            file: "".to_string(),
+        };
+        let mut range_req = prefix_range_req(&copied_file_ident.to_string_key(), chunk_size)?;
 
+        loop {
             let mut trials = txn_trials(None, ctx);
-            loop {
+            let page = loop {
                 trials.next().unwrap()?;
 
                 let (tb_meta_seq, tb_meta) = get_table_by_id_or_err(self, &table_id, ctx).await?;
 
-                let mut if_then = if_then.clone();
+                let page = self.range_kv(range_req.clone()).await?;
+                if page.kvs.is_empty() {
+                    break page;
+                }
+
+                let mut if_then = page
+                    .kvs
+                    .iter()
+                    .map(|(k, v)| TxnOp::delete_exact(k, Some(v.seq)))
+                    .collect::<Vec<_>>();
 
                 // Update to increase table meta seq, so that to assert no other process modify the table
                 if_then.push(txn_op_put(&table_id, serialize_struct(&tb_meta)?));
@@ -2622,11 +2646,21 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 );
 
                 if succ {
-                    break;
+                    break page;
                 }
+            };
+
+            match range_req.next_page(&page) {
+                Some(next) => range_req = next,
+                None => break,
             }
         }
 
+        audit_meta_change(
+            "truncate_table",
+            format!("table_id: {}", req.table_id),
+            "".to_string(),
+        );
         Ok(TruncateTableReply {})
     }
 
@@ -2698,6 +2732,11 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             );
 
             if succ {
+                audit_meta_change(
+                    "upsert_table_option",
+                    format!("table_id: {}", req.table_id),
+                    req.to_string(),
+                );
                 return Ok(UpsertTableOptionReply {
                     share_table_info: get_share_table_info_map(self, &table_meta).await?,
                 });
@@ -2839,6 +2878,125 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let mut txn_req = TxnRequest {
+            condition: vec![],
+            if_then: vec![],
+            else_then: vec![],
+        };
+        let mut share_table_info = vec![];
+
+        for req in req.update_table_metas.iter() {
+            let tbid = TableId {
+                table_id: req.table_id,
+            };
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(self, &tbid).await?;
+
+            if tb_meta_seq == 0 || table_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "update_multi_table_meta"),
+                )));
+            }
+            if req.seq.match_seq(tb_meta_seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    TableVersionMismatched::new(
+                        req.table_id,
+                        req.seq,
+                        tb_meta_seq,
+                        "update_multi_table_meta",
+                    ),
+                )));
+            }
+
+            // table is not changed
+            txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+            // tb_id -> tb_meta
+            txn_req
+                .if_then
+                .push(txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?));
+            txn_req.else_then.push(TxnOp {
+                request: Some(Request::Get(TxnGetRequest {
+                    key: tbid.to_string_key(),
+                })),
+            });
+
+            if let Some(copied_files) = &req.copied_files {
+                let (conditions, match_operations) =
+                    build_upsert_table_copied_file_info_conditions(
+                        &tbid,
+                        copied_files,
+                        tb_meta_seq,
+                        copied_files.fail_if_duplicated,
+                    )?;
+                txn_req.condition.extend(conditions);
+                txn_req.if_then.extend(match_operations)
+            }
+
+            if let Some(deduplicated_label) = req.deduplicated_label.clone() {
+                txn_req
+                    .if_then
+                    .push(build_upsert_table_deduplicated_label(deduplicated_label))
+            }
+
+            if let Some(infos) = get_share_table_info_map(self, &table_meta.unwrap()).await? {
+                share_table_info.extend(infos);
+            }
+        }
+
+        let (succ, responses) = send_txn(self, txn_req).await?;
+
+        debug!(
+            table_count = req.update_table_metas.len(),
+            succ = succ;
+            "update_multi_table_meta"
+        );
+
+        if succ {
+            return Ok(UpdateMultiTableMetaReply {
+                share_table_info: if share_table_info.is_empty() {
+                    None
+                } else {
+                    Some(share_table_info)
+                },
+            });
+        }
+
+        // Find out the table that is changed by others since it is read.
+        for (req, resp) in req.update_table_metas.iter().zip(responses.iter()) {
+            if let Some(Response::Get(get_resp)) = &resp.response {
+                let tb_meta_seq = get_resp.value.as_ref().map(|v| v.seq).unwrap_or_default();
+                if req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+            }
+        }
+
+        // All the tables are unchanged, the condition on copied files failed.
+        let table_id = req
+            .update_table_metas
+            .first()
+            .map(|req| req.table_id)
+            .unwrap_or_default();
+        Err(KVAppError::AppError(AppError::from(
+            DuplicatedUpsertFiles::new(table_id, "update_multi_table_meta"),
+        )))
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn set_table_column_mask_policy(
@@ -3389,6 +3547,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 );
 
                 if succ {
+                    audit_meta_change("create_catalog", &req.name_ident, "".to_string());
                     break catalog_id;
                 }
             }
@@ -3483,6 +3642,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 );
 
                 if succ {
+                    audit_meta_change("drop_catalog", name_key, "".to_string());
                     break;
                 }
             }
@@ -3612,54 +3772,35 @@ async fn remove_table_copied_files(
     if_then: &mut Vec<TxnOp>,
 ) -> Result<usize, KVAppError> {
     let mut n = 0;
-    let chunk_size = DEFAULT_MGET_SIZE;
 
-    // `list_keys` list all the `TableCopiedFileNameIdent` of the table.
+    // Scanning lists all the `TableCopiedFileNameIdent` of the table.
     // But if a upsert_table_copied_file_info run concurrently, there is chance that
-    // `list_keys` may lack of some new inserted TableCopiedFileNameIdent.
+    // the scan may lack of some new inserted TableCopiedFileNameIdent.
     // But since TableCopiedFileNameIdent has expire time, they can be purged by expire time.
-    let copied_files = list_table_copied_files(kv_api, table_id).await?;
-
-    for chunk in copied_files.chunks(chunk_size) {
-        // Load the `seq` of every copied file
-        let seqs = {
-            let str_keys: Vec<_> = chunk.iter().map(|f| f.to_string_key()).collect();
-
-            let seq_infos: Vec<(u64, Option<TableCopiedFileInfo>)> =
-                mget_pb_values(kv_api, &str_keys).await?;
-
-            seq_infos.into_iter().map(|(seq, _)| seq)
-        };
+    let copied_file_ident = TableCopiedFileNameIdent {
+        table_id,
+        file: "".to_string(),
+    };
+    let mut req = prefix_range_req(&copied_file_ident.to_string_key(), DEFAULT_RANGE_PAGE_SIZE)?;
 
-        for (copied_seq, copied_ident) in seqs.zip(chunk) {
-            if copied_seq == 0 {
-                continue;
-            }
+    loop {
+        let page = kv_api.range_kv(req.clone()).await?;
 
-            condition.push(txn_cond_seq(copied_ident, Eq, copied_seq));
-            if_then.push(txn_op_del(copied_ident));
+        for (copied_str_key, copied_seq_v) in page.kvs.iter() {
+            condition.push(TxnCondition::eq_seq(copied_str_key, copied_seq_v.seq));
+            if_then.push(TxnOp::delete(copied_str_key));
             n += 1;
         }
+
+        match req.next_page(&page) {
+            Some(next) => req = next,
+            None => break,
+        }
     }
 
     Ok(n)
 }
 
-/// List the copied file identities belonging to a table.
-async fn list_table_copied_files(
-    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
-    table_id: u64,
-) -> Result<Vec<TableCopiedFileNameIdent>, MetaError> {
-    let copied_file_ident = TableCopiedFileNameIdent {
-        table_id,
-        file: "".to_string(),
-    };
-
-    let copied_files = list_keys(kv_api, &copied_file_ident).await?;
-
-    Ok(copied_files)
-}
-
 // Return true if drop time is out of `DATA_RETENTION_TIME_IN_DAYS option,
 // use DEFAULT_DATA_RETENTION_SECONDS by default.
 fn is_drop_time_out_of_retention_time(
@@ -4149,6 +4290,7 @@ async fn gc_dropped_db_by_id(
                 for tb_id in tb_id_list.id_list {
                     gc_dropped_table_data(kv_api, tb_id, &mut condition, &mut if_then).await?;
                     gc_dropped_table_index(kv_api, &tenant, tb_id, &mut if_then).await?;
+                    gc_dropped_table_sequence(kv_api, &tenant, tb_id, &mut if_then).await?;
                 }
 
                 let id_key = iter.next().unwrap();
@@ -4215,6 +4357,7 @@ async fn gc_dropped_table_by_id(
         ];
         gc_dropped_table_data(kv_api, table_id, &mut condition, &mut if_then).await?;
         gc_dropped_table_index(kv_api, &tenant, table_id, &mut if_then).await?;
+        gc_dropped_table_sequence(kv_api, &tenant, table_id, &mut if_then).await?;
 
         let txn_req = TxnRequest {
             condition,
@@ -4268,6 +4411,32 @@ async fn gc_dropped_table_data(
     Ok(())
 }
 
+async fn gc_dropped_table_sequence(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+    table_id: u64,
+    if_then: &mut Vec<TxnOp>,
+) -> Result<(), KVAppError> {
+    // Get sequences by `prefix_list` "<prefix>/<tenant>"
+    let prefix_key = kvapi::KeyBuilder::new_prefixed(SequenceNameIdent::PREFIX)
+        .push_str(tenant)
+        .done();
+
+    let sequences = kv_api.prefix_list_kv(&prefix_key).await?;
+    for (key, seq_v) in sequences.iter() {
+        let sequence_meta: SequenceMeta = deserialize_struct(&seq_v.data)?;
+        if sequence_meta.table_id != Some(table_id) {
+            continue;
+        }
+        let name_ident = SequenceNameIdent::from_str_key(key).map_err(|e| {
+            KVAppError::MetaError(MetaError::from(InvalidReply::new("list_sequences", &e)))
+        })?;
+        if_then.push(txn_op_del(&name_ident)); // (tenant, sequence_name) -> sequence_meta
+    }
+
+    Ok(())
+}
+
 async fn gc_dropped_table_index(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     tenant: &str,
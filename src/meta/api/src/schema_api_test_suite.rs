@@ -38,6 +38,7 @@ use common_meta_app::schema::CreateCatalogReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateSequenceReq;
 use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::CreateVirtualColumnReq;
@@ -54,6 +55,7 @@ use common_meta_app::schema::DeleteTableLockRevReq;
 use common_meta_app::schema::DropCatalogReq;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropSequenceReq;
 use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::DropVirtualColumnReq;
 use common_meta_app::schema::DroppedId;
@@ -61,6 +63,8 @@ use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GcDroppedTableReq;
 use common_meta_app::schema::GetCatalogReq;
 use common_meta_app::schema::GetDatabaseReq;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReq;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::IcebergCatalogOption;
@@ -79,6 +83,7 @@ use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SequenceNameIdent;
 use common_meta_app::schema::SetLVTReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
@@ -97,6 +102,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -126,6 +132,7 @@ use crate::serialize_struct;
 use crate::testing::get_kv_data;
 use crate::DatamaskApi;
 use crate::SchemaApi;
+use crate::SequenceApi;
 use crate::ShareApi;
 
 /// Test suite of `SchemaApi`.
@@ -255,7 +262,7 @@ impl SchemaApiTestSuite {
     pub async fn test_single_node<B, MT>(b: B) -> anyhow::Result<()>
     where
         B: kvapi::ApiBuilder<MT>,
-        MT: ShareApi + kvapi::AsKVApi<Error = MetaError> + SchemaApi + DatamaskApi,
+        MT: ShareApi + kvapi::AsKVApi<Error = MetaError> + SchemaApi + DatamaskApi + SequenceApi,
     {
         let suite = SchemaApiTestSuite {};
 
@@ -283,6 +290,7 @@ impl SchemaApiTestSuite {
             .await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite.table_update_multi_meta(&b.build().await).await?;
         suite.table_update_mask_policy(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
@@ -317,6 +325,8 @@ impl SchemaApiTestSuite {
             .await?;
         suite.catalog_create_get_list_drop(&b.build().await).await?;
         suite.table_least_visible_time(&b.build().await).await?;
+        suite.sequence_create_next_drop(&b.build().await).await?;
+        suite.sequence_owned_by_table(&b.build().await).await?;
 
         Ok(())
     }
@@ -435,6 +445,7 @@ impl SchemaApiTestSuite {
             if_not_exists: false,
             name_ident: db_table_name_ident.clone(),
             table_meta: table_meta(created_on),
+            auto_increment_sequences: vec![],
         };
 
         {
@@ -1444,6 +1455,7 @@ impl SchemaApiTestSuite {
                 if_not_exists: false,
                 name_ident: name_ident.clone(),
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
             let res = mt.create_table(req.clone()).await?;
             table_id = res.table_id;
@@ -1474,6 +1486,206 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn sequence_create_next_drop<MT: SequenceApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let name_ident = SequenceNameIdent::new(tenant, "seq1");
+
+        info!("--- create sequence");
+        {
+            let req = CreateSequenceReq {
+                if_not_exists: false,
+                name_ident: name_ident.clone(),
+                start: 10,
+                step: 5,
+                comment: None,
+                create_on: Utc::now(),
+            };
+            mt.create_sequence(req.clone()).await?;
+
+            let res = mt.create_sequence(req.clone()).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::SequenceAlreadyExists("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let req = CreateSequenceReq {
+                if_not_exists: true,
+                ..req
+            };
+            mt.create_sequence(req).await?;
+        }
+
+        info!("--- allocate ranges");
+        {
+            let req = GetSequenceNextValueReq {
+                name_ident: name_ident.clone(),
+                count: 3,
+            };
+            let res = mt.get_sequence_next_value(req.clone()).await?;
+            assert_eq!((10, 5, 25), (res.start, res.step, res.end));
+
+            let res = mt.get_sequence_next_value(req).await?;
+            assert_eq!((25, 5, 40), (res.start, res.step, res.end));
+
+            let res = mt
+                .get_sequence(GetSequenceReq {
+                    name_ident: name_ident.clone(),
+                })
+                .await?;
+            assert_eq!(40, res.meta.current);
+
+            let res = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    name_ident: name_ident.clone(),
+                    count: u64::MAX,
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::OutOfSequenceRange("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- drop sequence");
+        {
+            let req = DropSequenceReq {
+                if_exists: false,
+                name_ident: name_ident.clone(),
+            };
+            mt.drop_sequence(req.clone()).await?;
+
+            let res = mt.drop_sequence(req.clone()).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownSequence("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let res = mt
+                .get_sequence_next_value(GetSequenceNextValueReq {
+                    name_ident: name_ident.clone(),
+                    count: 1,
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownSequence("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            mt.drop_sequence(DropSequenceReq {
+                if_exists: true,
+                ..req
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[minitrace::trace]
+    async fn sequence_owned_by_table<MT: SchemaApi + SequenceApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1_sequence_owned_by_table";
+        let db_name = "db1";
+        let tbl_name = "tb1";
+        let name_ident = SequenceNameIdent::new(tenant, "_autoincrement_1");
+
+        let db_id = mt
+            .create_database(CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            })
+            .await?
+            .db_id;
+
+        info!("--- create table with an autoincrement sequence");
+        let table_id = {
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+                auto_increment_sequences: vec![CreateSequenceReq {
+                    if_not_exists: false,
+                    name_ident: name_ident.clone(),
+                    start: 1,
+                    step: 1,
+                    comment: None,
+                    create_on: Utc::now(),
+                }],
+            };
+            let table_id = mt.create_table(req).await?.table_id;
+
+            let res = mt
+                .get_sequence(GetSequenceReq {
+                    name_ident: name_ident.clone(),
+                })
+                .await?;
+            assert_eq!(Some(table_id), res.meta.table_id);
+            table_id
+        };
+
+        info!("--- the sequence can not be dropped by itself");
+        {
+            let res = mt
+                .drop_sequence(DropSequenceReq {
+                    if_exists: true,
+                    name_ident: name_ident.clone(),
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::DropOwnedSequence("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- the sequence is kept by the dropped table and removed by gc");
+        {
+            mt.drop_table_by_id(DropTableByIdReq {
+                if_exists: false,
+                tenant: tenant.to_string(),
+                tb_id: table_id,
+            })
+            .await?;
+            mt.get_sequence(GetSequenceReq {
+                name_ident: name_ident.clone(),
+            })
+            .await?;
+
+            mt.gc_drop_tables(GcDroppedTableReq {
+                tenant: tenant.to_string(),
+                drop_ids: vec![DroppedId::Table(db_id, table_id, tbl_name.to_string())],
+            })
+            .await?;
+            let res = mt
+                .get_sequence(GetSequenceReq {
+                    name_ident: name_ident.clone(),
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownSequence("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_create_get_drop<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
@@ -1514,6 +1726,7 @@ impl SchemaApiTestSuite {
                 },
 
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
             // test create table
             {
@@ -1575,6 +1788,7 @@ impl SchemaApiTestSuite {
                 table_name: tbl_name.to_string(),
             },
             table_meta: table_meta(created_on),
+            auto_increment_sequences: vec![],
         };
         let tb_ident_2 = {
             {
@@ -1685,6 +1899,7 @@ impl SchemaApiTestSuite {
                     table_name: "tb3".to_string(),
                 },
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
 
             let old_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -1872,6 +2087,7 @@ impl SchemaApiTestSuite {
                 table_name: tb2_name.to_string(),
             },
             table_meta: table_meta(created_on),
+            auto_increment_sequences: vec![],
         };
 
         info!("--- create table for rename");
@@ -2095,6 +2311,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
 
             let _tb_ident_2 = {
@@ -2296,6 +2513,110 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_multi_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let table_meta = || TableMeta {
+            schema: Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )])),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(),
+                    auto_increment_sequences: vec![],
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_req = |table: &TableInfo, seq: u64, data_bytes: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            }
+        };
+
+        info!("--- update multi table meta, normal case");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![
+                    update_req(tb1.as_ref(), tb1.ident.seq, 1),
+                    update_req(tb2.as_ref(), tb2.ident.seq, 2),
+                ],
+            })
+            .await?;
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(1, tb1.meta.statistics.data_bytes);
+            assert_eq!(2, tb2.meta.statistics.data_bytes);
+        }
+
+        info!("--- update multi table meta: one of the versions mismatches, nothing is updated");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(tb1.as_ref(), tb1.ident.seq, 3),
+                        update_req(tb2.as_ref(), tb2.ident.seq + 1, 4),
+                    ],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(1, tb1.meta.statistics.data_bytes);
+            assert_eq!(2, tb2.meta.statistics.data_bytes);
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_update_mask_policy<
         MT: SchemaApi + DatamaskApi + kvapi::AsKVApi<Error = MetaError>,
@@ -2356,6 +2677,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name_1.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
             let _res = mt.create_table(req.clone()).await?;
 
@@ -2367,6 +2689,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name_2.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
             let _res = mt.create_table(req.clone()).await?;
         }
@@ -2679,6 +3002,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
 
             let _tb_ident_2 = {
@@ -3005,6 +3329,7 @@ impl SchemaApiTestSuite {
             if_not_exists: false,
             name_ident,
             table_meta: create_table_meta.clone(),
+            auto_increment_sequences: vec![],
         };
 
         let res = mt.create_table(req).await?;
@@ -3235,6 +3560,7 @@ impl SchemaApiTestSuite {
             if_not_exists: false,
             name_ident: tbl_name_ident,
             table_meta: create_table_meta.clone(),
+            auto_increment_sequences: vec![],
         };
 
         let res = mt.create_table(req).await?;
@@ -3465,6 +3791,7 @@ impl SchemaApiTestSuite {
                 if_not_exists: false,
                 name_ident: tbl_name_ident.clone(),
                 table_meta: create_table_meta.clone(),
+                auto_increment_sequences: vec![],
             };
 
             let old_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -3556,6 +3883,7 @@ impl SchemaApiTestSuite {
                 },
 
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
             let _resp = mt.create_table(req.clone()).await?;
 
@@ -3599,6 +3927,7 @@ impl SchemaApiTestSuite {
                     if_not_exists: false,
                     name_ident: table_name.clone(),
                     table_meta: table_meta(created_on),
+                    auto_increment_sequences: vec![],
                 };
                 let resp = mt.create_table(req.clone()).await?;
                 drop_ids_1.push(DroppedId::Table(
@@ -3627,6 +3956,7 @@ impl SchemaApiTestSuite {
                     },
 
                     table_meta: table_meta.clone(),
+                    auto_increment_sequences: vec![],
                 };
                 let resp = mt.create_table(req.clone()).await?;
                 mt.drop_table_by_id(DropTableByIdReq {
@@ -3653,6 +3983,7 @@ impl SchemaApiTestSuite {
                     },
 
                     table_meta: table_meta(created_on),
+                    auto_increment_sequences: vec![],
                 };
                 let _resp = mt.create_table(req.clone()).await?;
             }
@@ -3701,6 +4032,7 @@ impl SchemaApiTestSuite {
                     },
 
                     table_meta: table_meta(created_on),
+                    auto_increment_sequences: vec![],
                 };
                 let resp = mt.create_table(req.clone()).await?;
                 drop_ids_1.push(DroppedId::Table(db_id, resp.table_id, "tb1".to_string()));
@@ -3725,6 +4057,7 @@ impl SchemaApiTestSuite {
                     },
 
                     table_meta: table_meta.clone(),
+                    auto_increment_sequences: vec![],
                 };
                 let resp = mt.create_table(req.clone()).await?;
                 drop_ids_2.push(DroppedId::Table(db_id, resp.table_id, "tb2".to_string()));
@@ -3752,6 +4085,7 @@ impl SchemaApiTestSuite {
                     },
 
                     table_meta: table_meta(created_on),
+                    auto_increment_sequences: vec![],
                 };
                 let _resp = mt.create_table(req.clone()).await?;
             }
@@ -3887,6 +4221,7 @@ impl SchemaApiTestSuite {
                 if_not_exists: false,
                 name_ident: tbl_name_ident.clone(),
                 table_meta: create_table_meta.clone(),
+                auto_increment_sequences: vec![],
             };
 
             let old_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -4005,6 +4340,7 @@ impl SchemaApiTestSuite {
                     if_not_exists: false,
                     name_ident: tbl_name_ident.clone(),
                     table_meta: create_table_meta.clone(),
+                    auto_increment_sequences: vec![],
                 })
                 .await?;
             let cur_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -4099,6 +4435,7 @@ impl SchemaApiTestSuite {
                 if_not_exists: false,
                 name_ident: new_tbl_name_ident.clone(),
                 table_meta: create_table_meta.clone(),
+                auto_increment_sequences: vec![],
             };
 
             let old_db = mt.get_database(Self::req_get_db(tenant, db_name)).await?;
@@ -4262,6 +4599,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
 
             let _tb_ident_2 = {
@@ -4364,6 +4702,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
             let resp = mt.create_table(req.clone()).await?;
             table_id = resp.table_id;
@@ -4572,6 +4911,7 @@ impl SchemaApiTestSuite {
                     if_not_exists: false,
                     name_ident: tb_name.clone(),
                     table_meta: table_meta(create_on),
+                    auto_increment_sequences: vec![],
                 };
                 let res = mt.create_table(req).await?;
                 if tb_name == &tb_name1 {
@@ -4698,6 +5038,7 @@ impl SchemaApiTestSuite {
                     options: options.clone(),
                     ..Default::default()
                 },
+                auto_increment_sequences: vec![],
             };
 
             let tb_ids = {
@@ -4767,6 +5108,7 @@ impl SchemaApiTestSuite {
                     options: options.clone(),
                     ..Default::default()
                 },
+                auto_increment_sequences: vec![],
             };
 
             let tb_ids = {
@@ -5564,6 +5906,7 @@ impl SchemaApiTestSuite {
                         options: options.clone(),
                         ..Default::default()
                     },
+                    auto_increment_sequences: vec![],
                 };
                 let old_db = node_a
                     .get_database(Self::req_get_db(tenant, db_name))
@@ -5637,6 +5980,7 @@ impl SchemaApiTestSuite {
                     options: options.clone(),
                     ..Default::default()
                 },
+                auto_increment_sequences: vec![],
             };
 
             let old_db = node_a
@@ -5727,6 +6071,7 @@ impl SchemaApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: table_meta(created_on),
+                auto_increment_sequences: vec![],
             };
             let resp = mt.create_table(req.clone()).await?;
             table_id = resp.table_id;
@@ -5978,6 +6323,7 @@ where MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>
                 table_name: self.tbl_name(),
             },
             table_meta: table_meta.clone(),
+            auto_increment_sequences: vec![],
         };
         let resp = self.mt.create_table(req.clone()).await?;
         let table_id = resp.table_id;
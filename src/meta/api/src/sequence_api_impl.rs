@@ -0,0 +1,249 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+use common_meta_app::app_error::AppError;
+use common_meta_app::app_error::DropOwnedSequence;
+use common_meta_app::app_error::OutOfSequenceRange;
+use common_meta_app::app_error::SequenceAlreadyExists;
+use common_meta_app::app_error::UnknownSequence;
+use common_meta_app::schema::CreateSequenceReply;
+use common_meta_app::schema::CreateSequenceReq;
+use common_meta_app::schema::DropSequenceReply;
+use common_meta_app::schema::DropSequenceReq;
+use common_meta_app::schema::GetSequenceNextValueReply;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::GetSequenceReply;
+use common_meta_app::schema::GetSequenceReq;
+use common_meta_app::schema::SequenceMeta;
+use common_meta_app::schema::SequenceNameIdent;
+use common_meta_kvapi::kvapi;
+use common_meta_types::ConditionResult::Eq;
+use common_meta_types::MetaError;
+use common_meta_types::TxnRequest;
+use common_tracing::func_name;
+use log::as_debug;
+use log::debug;
+
+use crate::get_pb_value;
+use crate::kv_app_error::KVAppError;
+use crate::send_txn;
+use crate::sequence_api::SequenceApi;
+use crate::serialize_struct;
+use crate::txn_cond_seq;
+use crate::txn_op_del;
+use crate::txn_op_put;
+use crate::util::txn_trials;
+
+#[tonic::async_trait]
+impl<KV: kvapi::KVApi<Error = MetaError>> SequenceApi for KV {
+    async fn create_sequence(
+        &self,
+        req: CreateSequenceReq,
+    ) -> Result<CreateSequenceReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SequenceApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (seq, _): (_, Option<SequenceMeta>) = get_pb_value(self, name_key).await?;
+            debug!(seq = seq, name_key = as_debug!(name_key); "create_sequence");
+
+            if seq > 0 {
+                return if req.if_not_exists {
+                    Ok(CreateSequenceReply {})
+                } else {
+                    Err(KVAppError::AppError(AppError::SequenceAlreadyExists(
+                        SequenceAlreadyExists::new(
+                            &name_key.sequence_name,
+                            format!("create sequence: {}", name_key),
+                        ),
+                    )))
+                };
+            }
+
+            let meta: SequenceMeta = req.clone().into();
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(name_key, Eq, 0)],
+                if_then: vec![txn_op_put(name_key, serialize_struct(&meta)?)],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(name = as_debug!(name_key), succ = succ; "create_sequence");
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(CreateSequenceReply {})
+    }
+
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SequenceApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let (_seq, meta) =
+            get_sequence_or_err(self, name_key, format!("get_sequence: {}", name_key)).await?;
+
+        Ok(GetSequenceReply { meta })
+    }
+
+    async fn get_sequence_next_value(
+        &self,
+        req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SequenceApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (seq, mut meta) = get_sequence_or_err(
+                self,
+                name_key,
+                format!("get_sequence_next_value: {}", name_key),
+            )
+            .await?;
+
+            // Move `current` forward by the whole range, so that concurrent callers
+            // always get disjoint ranges.
+            let start = meta.current;
+            let end = req
+                .count
+                .checked_mul(meta.step)
+                .and_then(|n| start.checked_add(n))
+                .ok_or_else(|| {
+                    KVAppError::AppError(AppError::OutOfSequenceRange(OutOfSequenceRange::new(
+                        &name_key.sequence_name,
+                        format!("get_sequence_next_value: {}, count={}", name_key, req.count),
+                    )))
+                })?;
+
+            meta.current = end;
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(name_key, Eq, seq)],
+                if_then: vec![txn_op_put(name_key, serialize_struct(&meta)?)],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = as_debug!(name_key),
+                start = start,
+                end = end,
+                succ = succ;
+                "get_sequence_next_value"
+            );
+
+            if succ {
+                return Ok(GetSequenceNextValueReply {
+                    start,
+                    step: meta.step,
+                    end,
+                });
+            }
+        }
+    }
+
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SequenceApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let (seq, meta): (_, Option<SequenceMeta>) = get_pb_value(self, name_key).await?;
+
+            let meta = match meta {
+                Some(meta) => meta,
+                None => {
+                    return if req.if_exists {
+                        Ok(DropSequenceReply {})
+                    } else {
+                        Err(unknown_sequence(
+                            name_key,
+                            format!("drop_sequence: {}", name_key),
+                        ))
+                    };
+                }
+            };
+
+            // Inserting into the table would fail without the sequence.
+            if let Some(table_id) = meta.table_id {
+                return Err(KVAppError::AppError(AppError::DropOwnedSequence(
+                    DropOwnedSequence::new(
+                        &name_key.sequence_name,
+                        table_id,
+                        format!("drop_sequence: {}", name_key),
+                    ),
+                )));
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(name_key, Eq, seq)],
+                if_then: vec![txn_op_del(name_key)],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(name = as_debug!(name_key), succ = succ; "drop_sequence");
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(DropSequenceReply {})
+    }
+}
+
+async fn get_sequence_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_key: &SequenceNameIdent,
+    msg: impl Display,
+) -> Result<(u64, SequenceMeta), KVAppError> {
+    let (seq, meta) = get_pb_value(kv_api, name_key).await?;
+
+    match meta {
+        Some(meta) => Ok((seq, meta)),
+        None => {
+            debug!(seq = seq, name_ident = as_debug!(name_key); "sequence does not exist");
+            Err(unknown_sequence(name_key, msg))
+        }
+    }
+}
+
+fn unknown_sequence(name_key: &SequenceNameIdent, msg: impl Display) -> KVAppError {
+    KVAppError::AppError(AppError::UnknownSequence(UnknownSequence::new(
+        &name_key.sequence_name,
+        msg.to_string(),
+    )))
+}
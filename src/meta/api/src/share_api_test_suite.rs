@@ -738,6 +738,7 @@ impl ShareApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                auto_increment_sequences: vec![],
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -761,6 +762,7 @@ impl ShareApiTestSuite {
                     table_name: tbl2_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                auto_increment_sequences: vec![],
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -777,6 +779,7 @@ impl ShareApiTestSuite {
                     table_name: tbl2_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                auto_increment_sequences: vec![],
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -1185,6 +1188,7 @@ impl ShareApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                auto_increment_sequences: vec![],
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -1338,6 +1342,7 @@ impl ShareApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                auto_increment_sequences: vec![],
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -1589,6 +1594,7 @@ impl ShareApiTestSuite {
                     table_name: tbl_name.to_string(),
                 },
                 table_meta: TableMeta::default(),
+                auto_increment_sequences: vec![],
             };
 
             let res = mt.create_table(req.clone()).await?;
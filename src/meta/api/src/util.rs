@@ -51,6 +51,7 @@ use common_meta_app::schema::VirtualColumnNameIdent;
 use common_meta_app::share::*;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::Key;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::txn_condition::Target;
 use common_meta_types::txn_op::Request;
@@ -81,6 +82,7 @@ use crate::Id;
 
 pub const TXN_MAX_RETRY_TIMES: u32 = 10;
 pub const DEFAULT_MGET_SIZE: usize = 256;
+pub const DEFAULT_RANGE_PAGE_SIZE: u64 = 1024;
 
 /// Get value that its type is `u64`.
 ///
@@ -149,22 +151,41 @@ where
     Ok(seq_values)
 }
 
+/// Build a request to scan by page all the records with the `prefix`.
+pub fn prefix_range_req(prefix: &str, page_size: u64) -> Result<RangeKVReq, MetaError> {
+    let req = RangeKVReq::prefix(prefix).map_err(|e| {
+        MetaNetworkError::InvalidArgument(InvalidArgument::new(e, "fail to build range request"))
+    })?;
+    Ok(req.with_limit(page_size))
+}
+
 /// Return a vec of structured key(such as `DatabaseNameIdent`), such as:
 /// all the `db_name` with prefix `__fd_database/<tenant>/`.
+///
+/// The keys are scanned page by page so that a single reply never holds all of the records.
 pub async fn list_keys<K: kvapi::Key>(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     key: &K,
 ) -> Result<Vec<K>, MetaError> {
-    let res = kv_api.prefix_list_kv(&key.to_string_key()).await?;
+    let mut req = prefix_range_req(&key.to_string_key(), DEFAULT_RANGE_PAGE_SIZE)?;
 
-    let mut structured_keys = Vec::with_capacity(res.len());
+    let mut structured_keys = vec![];
 
-    for (str_key, _seq_id) in res.iter() {
-        let struct_key = K::from_str_key(str_key).map_err(|e| {
-            let inv = InvalidReply::new("fail to list_keys", &e);
-            MetaNetworkError::InvalidReply(inv)
-        })?;
-        structured_keys.push(struct_key);
+    loop {
+        let page = kv_api.range_kv(req.clone()).await?;
+
+        for (str_key, _seq_id) in page.kvs.iter() {
+            let struct_key = K::from_str_key(str_key).map_err(|e| {
+                let inv = InvalidReply::new("fail to list_keys", &e);
+                MetaNetworkError::InvalidReply(inv)
+            })?;
+            structured_keys.push(struct_key);
+        }
+
+        match req.next_page(&page) {
+            Some(next) => req = next,
+            None => break,
+        }
     }
 
     Ok(structured_keys)
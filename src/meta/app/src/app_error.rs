@@ -74,6 +74,56 @@ impl DatamaskAlreadyExists {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("SequenceAlreadyExists: `{name}` while `{context}`")]
+pub struct SequenceAlreadyExists {
+    name: String,
+    context: String,
+}
+
+impl SequenceAlreadyExists {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("OutOfSequenceRange: `{name}` while `{context}`")]
+pub struct OutOfSequenceRange {
+    name: String,
+    context: String,
+}
+
+impl OutOfSequenceRange {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("DropOwnedSequence: `{name}` owned by table {table_id} while `{context}`")]
+pub struct DropOwnedSequence {
+    name: String,
+    table_id: u64,
+    context: String,
+}
+
+impl DropOwnedSequence {
+    pub fn new(name: impl Into<String>, table_id: u64, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            table_id,
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("BackgroundJobAlreadyExists: `{name}` while `{context}`")]
 pub struct BackgroundJobAlreadyExists {
@@ -316,6 +366,22 @@ impl UnknownDatamask {
     }
 }
 
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("UnknownSequence: `{name}` while `{context}`")]
+pub struct UnknownSequence {
+    name: String,
+    context: String,
+}
+
+impl UnknownSequence {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownBackgroundJob: `{name}` while `{context}`")]
 pub struct UnknownBackgroundJob {
@@ -916,6 +982,18 @@ pub enum AppError {
     #[error(transparent)]
     UnknownDatamask(#[from] UnknownDatamask),
 
+    #[error(transparent)]
+    SequenceAlreadyExists(#[from] SequenceAlreadyExists),
+
+    #[error(transparent)]
+    UnknownSequence(#[from] UnknownSequence),
+
+    #[error(transparent)]
+    OutOfSequenceRange(#[from] OutOfSequenceRange),
+
+    #[error(transparent)]
+    DropOwnedSequence(#[from] DropOwnedSequence),
+
     #[error(transparent)]
     BackgroundJobAlreadyExists(#[from] BackgroundJobAlreadyExists),
 
@@ -1201,6 +1279,33 @@ impl AppErrorMessage for UnknownDatamask {
     }
 }
 
+impl AppErrorMessage for SequenceAlreadyExists {
+    fn message(&self) -> String {
+        format!("Sequence '{}' already exists", self.name)
+    }
+}
+
+impl AppErrorMessage for UnknownSequence {
+    fn message(&self) -> String {
+        format!("Unknown sequence '{}'", self.name)
+    }
+}
+
+impl AppErrorMessage for OutOfSequenceRange {
+    fn message(&self) -> String {
+        format!("Sequence '{}' has run out of values", self.name)
+    }
+}
+
+impl AppErrorMessage for DropOwnedSequence {
+    fn message(&self) -> String {
+        format!(
+            "Sequence '{}' backs an AUTOINCREMENT column of table {}, it is dropped along with the table",
+            self.name, self.table_id
+        )
+    }
+}
+
 impl AppErrorMessage for UnmatchColumnDataType {
     fn message(&self) -> String {
         format!(
@@ -1306,6 +1411,10 @@ impl From<AppError> for ErrorCode {
             AppError::GetIndexWithDropTIme(err) => ErrorCode::GetIndexWithDropTime(err.message()),
             AppError::DatamaskAlreadyExists(err) => ErrorCode::DatamaskAlreadyExists(err.message()),
             AppError::UnknownDatamask(err) => ErrorCode::UnknownDatamask(err.message()),
+            AppError::SequenceAlreadyExists(err) => ErrorCode::SequenceAlreadyExists(err.message()),
+            AppError::UnknownSequence(err) => ErrorCode::UnknownSequence(err.message()),
+            AppError::OutOfSequenceRange(err) => ErrorCode::OutOfSequenceRange(err.message()),
+            AppError::DropOwnedSequence(err) => ErrorCode::DropOwnedSequence(err.message()),
 
             AppError::BackgroundJobAlreadyExists(err) => {
                 ErrorCode::BackgroundJobAlreadyExists(err.message())
@@ -122,6 +122,11 @@ impl BackgroundJobParams {
         }
     }
 
+    /// Check the cron expression before it is stored, the scheduler panics on an invalid one.
+    pub fn is_valid_cron(cron: &str) -> bool {
+        Schedule::from_str(cron).is_ok()
+    }
+
     pub fn get_next_running_time(&self, last_run_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
         match self.job_type {
             BackgroundJobType::ONESHOT => None,
@@ -215,12 +220,25 @@ impl Display for BackgroundJobIdent {
     }
 }
 
+/// The definition of a user-defined SQL task, created by `CREATE TASK`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct SqlTaskDefinition {
+    pub query_text: String,
+    /// The SQL runs in the database which is current when the task is created.
+    pub database: String,
+    pub warehouse: Option<String>,
+    /// The predecessor tasks, this task runs after any of them finished successfully.
+    pub after: Vec<String>,
+    pub comment: Option<String>,
+}
+
 // Info
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
 pub struct BackgroundJobInfo {
     pub job_params: Option<BackgroundJobParams>,
     pub job_status: Option<BackgroundJobStatus>,
     pub task_type: BackgroundTaskType,
+    pub sql_task: Option<SqlTaskDefinition>,
 
     pub last_updated: Option<DateTime<Utc>>,
     pub message: String,
@@ -236,6 +254,40 @@ impl BackgroundJobInfo {
             job_status: Option::from(BackgroundJobStatus::new(&job_params)),
             job_params: Some(job_params),
             task_type: BackgroundTaskType::COMPACTION,
+            sql_task: None,
+            last_updated: Some(Utc::now()),
+            message: "".to_string(),
+            creator: Some(creator),
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn new_vacuum_job(job_params: BackgroundJobParams, creator: UserIdentity) -> Self {
+        Self {
+            job_status: Option::from(BackgroundJobStatus::new(&job_params)),
+            job_params: Some(job_params),
+            task_type: BackgroundTaskType::VACUUM,
+            sql_task: None,
+            last_updated: Some(Utc::now()),
+            message: "".to_string(),
+            creator: Some(creator),
+            created_at: Utc::now(),
+        }
+    }
+
+    /// A SQL task is created in the suspended state, it is scheduled after `ALTER TASK RESUME`.
+    pub fn new_sql_task_job(
+        job_params: BackgroundJobParams,
+        definition: SqlTaskDefinition,
+        creator: UserIdentity,
+    ) -> Self {
+        let mut job_status = BackgroundJobStatus::new(&job_params);
+        job_status.job_state = BackgroundJobState::SUSPENDED;
+        Self {
+            job_status: Some(job_status),
+            job_params: Some(job_params),
+            task_type: BackgroundTaskType::SQL,
+            sql_task: Some(definition),
             last_updated: Some(Utc::now()),
             message: "".to_string(),
             creator: Some(creator),
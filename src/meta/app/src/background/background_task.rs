@@ -62,6 +62,7 @@ pub enum BackgroundTaskType {
     #[default]
     COMPACTION = 0,
     VACUUM = 1,
+    SQL = 2,
 }
 
 impl Display for BackgroundTaskType {
@@ -99,13 +100,45 @@ impl Display for CompactionStats {
     }
 }
 
-// TODO(zhihanz) provide detailed vacuum stats
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
-pub struct VacuumStats {}
+pub struct VacuumStats {
+    pub db_id: u64,
+    pub table_id: u64,
+    /// The number of files removed from the storage.
+    pub removed_files: u64,
+    /// The total size in bytes of the files removed from the storage.
+    pub removed_bytes: u64,
+    pub total_vacuum_time: Option<Duration>,
+}
 
 impl Display for VacuumStats {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "vacuum stats")
+        write!(
+            f,
+            "db_id: {}, table_id: {}, removed_files: {}, removed_bytes: {}, total_vacuum_time: {:?}",
+            self.db_id,
+            self.table_id,
+            self.removed_files,
+            self.removed_bytes,
+            self.total_vacuum_time,
+        )
+    }
+}
+
+/// A single run of the user-defined SQL task.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct SqlTaskStats {
+    pub query_id: String,
+    pub query_text: String,
+}
+
+impl Display for SqlTaskStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "query_id: {}, query_text: {}",
+            self.query_id, self.query_text
+        )
     }
 }
 
@@ -117,6 +150,7 @@ pub struct BackgroundTaskInfo {
     pub message: String,
     pub compaction_task_stats: Option<CompactionStats>,
     pub vacuum_stats: Option<VacuumStats>,
+    pub sql_task_stats: Option<SqlTaskStats>,
 
     pub manual_trigger: Option<ManualTriggerParams>,
     pub creator: Option<BackgroundJobIdent>,
@@ -146,6 +180,57 @@ impl BackgroundTaskInfo {
                 total_compaction_time: None,
             }),
             vacuum_stats: None,
+            sql_task_stats: None,
+            manual_trigger,
+            creator: Some(creator),
+            created_at: now,
+        }
+    }
+
+    pub fn new_vacuum_task(
+        creator: BackgroundJobIdent,
+        db_id: u64,
+        tb_id: u64,
+        manual_trigger: Option<ManualTriggerParams>,
+        message: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            last_updated: Some(now),
+            task_type: BackgroundTaskType::VACUUM,
+            task_state: BackgroundTaskState::STARTED,
+            message,
+            compaction_task_stats: None,
+            vacuum_stats: Some(VacuumStats {
+                db_id,
+                table_id: tb_id,
+                ..Default::default()
+            }),
+            sql_task_stats: None,
+            manual_trigger,
+            creator: Some(creator),
+            created_at: now,
+        }
+    }
+
+    pub fn new_sql_task(
+        creator: BackgroundJobIdent,
+        query_id: String,
+        query_text: String,
+        manual_trigger: Option<ManualTriggerParams>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            last_updated: Some(now),
+            task_type: BackgroundTaskType::SQL,
+            task_state: BackgroundTaskState::STARTED,
+            message: "".to_string(),
+            compaction_task_stats: None,
+            vacuum_stats: None,
+            sql_task_stats: Some(SqlTaskStats {
+                query_id,
+                query_text,
+            }),
             manual_trigger,
             creator: Some(creator),
             created_at: now,
@@ -30,6 +30,7 @@ pub use background_job::GetBackgroundJobReply;
 pub use background_job::GetBackgroundJobReq;
 pub use background_job::ListBackgroundJobsReq;
 pub use background_job::ManualTriggerParams;
+pub use background_job::SqlTaskDefinition;
 pub use background_job::UpdateBackgroundJobParamsReq;
 pub use background_job::UpdateBackgroundJobReply;
 pub use background_job::UpdateBackgroundJobReq;
@@ -42,6 +43,7 @@ pub use background_task::CompactionStats;
 pub use background_task::GetBackgroundTaskReply;
 pub use background_task::GetBackgroundTaskReq;
 pub use background_task::ListBackgroundTasksReq;
+pub use background_task::SqlTaskStats;
 pub use background_task::UpdateBackgroundTaskReply;
 pub use background_task::UpdateBackgroundTaskReq;
 pub use background_task::VacuumStats;
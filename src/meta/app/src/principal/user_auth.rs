@@ -23,6 +23,7 @@ const NO_PASSWORD_STR: &str = "no_password";
 const SHA256_PASSWORD_STR: &str = "sha256_password";
 const DOUBLE_SHA1_PASSWORD_STR: &str = "double_sha1_password";
 const JWT_AUTH_STR: &str = "jwt";
+const KEY_PAIR_AUTH_STR: &str = "key_pair";
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum AuthType {
@@ -30,6 +31,7 @@ pub enum AuthType {
     Sha256Password,
     DoubleSha1Password,
     JWT,
+    KeyPair,
 }
 
 impl std::str::FromStr for AuthType {
@@ -40,6 +42,7 @@ impl std::str::FromStr for AuthType {
             DOUBLE_SHA1_PASSWORD_STR => Ok(AuthType::DoubleSha1Password),
             NO_PASSWORD_STR => Ok(AuthType::NoPassword),
             JWT_AUTH_STR => Ok(AuthType::JWT),
+            KEY_PAIR_AUTH_STR => Ok(AuthType::KeyPair),
             _ => Err(ErrorCode::InvalidAuthInfo(AuthType::bad_auth_types(s))),
         }
     }
@@ -52,6 +55,7 @@ impl AuthType {
             AuthType::Sha256Password => SHA256_PASSWORD_STR,
             AuthType::DoubleSha1Password => DOUBLE_SHA1_PASSWORD_STR,
             AuthType::JWT => JWT_AUTH_STR,
+            AuthType::KeyPair => KEY_PAIR_AUTH_STR,
         }
     }
 
@@ -61,6 +65,7 @@ impl AuthType {
             SHA256_PASSWORD_STR,
             DOUBLE_SHA1_PASSWORD_STR,
             JWT_AUTH_STR,
+            KEY_PAIR_AUTH_STR,
         ];
         let all = all
             .iter()
@@ -90,6 +95,12 @@ pub enum AuthInfo {
         hash_method: PasswordHashMethod,
     },
     JWT,
+    /// Public keys(PEM encoded, RSA or Ed25519) used to verify the JWT signed by the user.
+    /// Two slots are kept so that a key can be rotated without downtime.
+    KeyPair {
+        public_key: Option<String>,
+        public_key_2: Option<String>,
+    },
 }
 
 fn calc_sha1(v: &[u8]) -> [u8; 20] {
@@ -107,6 +118,10 @@ impl AuthInfo {
         match auth_type {
             AuthType::NoPassword => Ok(AuthInfo::None),
             AuthType::JWT => Ok(AuthInfo::JWT),
+            AuthType::KeyPair => Ok(AuthInfo::KeyPair {
+                public_key: auth_string.clone(),
+                public_key_2: None,
+            }),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => match auth_string {
                 Some(p) => {
                     let method = auth_type.get_password_type().unwrap();
@@ -165,6 +180,7 @@ impl AuthInfo {
         match self {
             AuthInfo::None => AuthType::NoPassword,
             AuthInfo::JWT => AuthType::JWT,
+            AuthInfo::KeyPair { .. } => AuthType::KeyPair,
             AuthInfo::Password {
                 hash_value: _,
                 hash_method: t,
@@ -181,7 +197,7 @@ impl AuthInfo {
                 hash_value: p,
                 hash_method: t,
             } => t.to_string(p),
-            AuthInfo::None | AuthInfo::JWT => "".to_string(),
+            AuthInfo::None | AuthInfo::JWT | AuthInfo::KeyPair { .. } => "".to_string(),
         }
     }
 
@@ -205,6 +221,41 @@ impl AuthInfo {
         }
     }
 
+    pub fn get_public_keys(&self) -> Vec<&str> {
+        match self {
+            AuthInfo::KeyPair {
+                public_key,
+                public_key_2,
+            } => public_key
+                .iter()
+                .chain(public_key_2.iter())
+                .map(|k| k.as_str())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Replace the public key in the given slot(1 or 2), a user with another auth type
+    /// is switched to key pair authentication.
+    pub fn with_public_key(&self, slot: usize, key: Option<String>) -> AuthInfo {
+        let (mut public_key, mut public_key_2) = match self {
+            AuthInfo::KeyPair {
+                public_key,
+                public_key_2,
+            } => (public_key.clone(), public_key_2.clone()),
+            _ => (None, None),
+        };
+        if slot == 2 {
+            public_key_2 = key;
+        } else {
+            public_key = key;
+        }
+        AuthInfo::KeyPair {
+            public_key,
+            public_key_2,
+        }
+    }
+
     fn restore_sha1_mysql(salt: &[u8], input: &[u8], user_password_hash: &[u8]) -> Result<Vec<u8>> {
         // SHA1( password ) XOR SHA1( "20-bytes random data from server" <concat> SHA1( SHA1( password ) ) )
         let mut m = sha1::Sha1::new();
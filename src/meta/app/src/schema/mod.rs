@@ -19,6 +19,7 @@ mod database;
 mod index;
 mod least_visible_time;
 mod ownership;
+mod sequence;
 mod table;
 mod virtual_column;
 pub use catalog::*;
@@ -47,6 +48,16 @@ pub use least_visible_time::LeastVisibleTimeKey;
 pub use least_visible_time::SetLVTReply;
 pub use least_visible_time::SetLVTReq;
 pub use ownership::Ownership;
+pub use sequence::CreateSequenceReply;
+pub use sequence::CreateSequenceReq;
+pub use sequence::DropSequenceReply;
+pub use sequence::DropSequenceReq;
+pub use sequence::GetSequenceNextValueReply;
+pub use sequence::GetSequenceNextValueReq;
+pub use sequence::GetSequenceReply;
+pub use sequence::GetSequenceReq;
+pub use sequence::SequenceMeta;
+pub use sequence::SequenceNameIdent;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -94,6 +105,8 @@ pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
@@ -132,3 +145,4 @@ const PREFIX_INDEX_BY_ID: &str = "__fd_index_by_id";
 const PREFIX_TABLE_LOCK: &str = "__fd_table_lock";
 const PREFIX_TABLE_LVT: &str = "__fd_table_lvt";
 const PREFIX_VIRTUAL_COLUMN: &str = "__fd_virtual_column";
+const PREFIX_SEQUENCE: &str = "__fd_sequence";
@@ -32,6 +32,7 @@ use common_meta_types::MetaId;
 use maplit::hashmap;
 
 use crate::schema::database::DatabaseNameIdent;
+use crate::schema::CreateSequenceReq;
 use crate::schema::Ownership;
 use crate::share::ShareNameIdent;
 use crate::share::ShareSpec;
@@ -446,6 +447,9 @@ pub struct CreateTableReq {
     pub if_not_exists: bool,
     pub name_ident: TableNameIdent,
     pub table_meta: TableMeta,
+    /// Sequences backing the AUTOINCREMENT columns, created in the same transaction
+    /// as the table and owned by it.
+    pub auto_increment_sequences: Vec<CreateSequenceReq>,
 }
 
 impl CreateTableReq {
@@ -663,6 +667,19 @@ pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+/// Update the meta of several tables atomically, e.g., when committing an explicit transaction.
+///
+/// Either all the tables are updated or none of them, if any of the table versions mismatches.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
@@ -21,6 +21,8 @@ use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
@@ -28,6 +30,7 @@ use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::LeaseCmd;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use tonic::codegen::InterceptedService;
@@ -52,6 +55,8 @@ pub enum MetaGrpcReq {
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+
+    Lease(LeaseCmd),
 }
 
 impl TryInto<MetaGrpcReq> for Request<RaftRequest> {
@@ -92,6 +97,14 @@ impl RequestFor for ListKVReq {
     type Reply = ListKVReply;
 }
 
+impl RequestFor for RangeKVReq {
+    type Reply = RangeKVReply;
+}
+
+impl RequestFor for LeaseCmd {
+    type Reply = UpsertKVReply;
+}
+
 impl RequestFor for UpsertKVReq {
     type Reply = UpsertKVReply;
 }
@@ -42,6 +42,9 @@ use common_grpc::GrpcConnectionError;
 use common_grpc::RpcClientConf;
 use common_grpc::RpcClientTlsConfig;
 use common_meta_api::reply::reply_to_api_result;
+use common_meta_kvapi::kvapi::ListKVReq;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_types::anyerror::AnyError;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::ClientInfo;
@@ -61,9 +64,11 @@ use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaHandshakeError;
 use common_meta_types::MetaNetworkError;
+use common_meta_types::SeqV;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use futures::stream::StreamExt;
+use futures::stream::TryStreamExt;
 use log::as_debug;
 use log::as_display;
 use log::debug;
@@ -268,6 +273,16 @@ pub struct MetaGrpcClient {
     /// Note that a thread_pool tokio runtime does not help: a scheduled tokio-task resides in `filo_slot` won't be stolen by other tokio-workers.
     #[allow(dead_code)]
     rt: Arc<Runtime>,
+
+    /// The records listed by the last emulated range scan, to serve its following pages.
+    range_list_cache: Mutex<Option<RangeListCache>>,
+}
+
+/// The records of a range listed from a metasrv that does not support `KvRange`.
+struct RangeListCache {
+    start: String,
+    end: Option<String>,
+    listed: Vec<(String, SeqV)>,
 }
 
 impl Debug for MetaGrpcClient {
@@ -345,6 +360,7 @@ impl MetaGrpcClient {
             username: username.to_string(),
             password: password.to_string(),
             rt: rt.clone(),
+            range_list_cache: Mutex::new(None),
         });
 
         rt.spawn(UnlimitedFuture::create(Self::worker_loop(
@@ -413,6 +429,13 @@ impl MetaGrpcClient {
                         .await;
                     message::Response::PrefixList(resp)
                 }
+                message::Request::Range(r) => {
+                    let resp = self
+                        .range_kv(r)
+                        .timed_ge(threshold(), info_spent("MetaGrpcClient::range_kv"))
+                        .await;
+                    message::Response::Range(resp)
+                }
                 message::Request::Upsert(r) => {
                     let resp = self
                         .kv_api(r)
@@ -420,6 +443,13 @@ impl MetaGrpcClient {
                         .await;
                     message::Response::Upsert(resp)
                 }
+                message::Request::Lease(r) => {
+                    let resp = self
+                        .kv_api(r)
+                        .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                        .await;
+                    message::Response::Upsert(resp)
+                }
                 message::Request::Txn(r) => {
                     let resp = self
                         .transaction(r)
@@ -948,6 +978,90 @@ impl MetaGrpcClient {
         Ok(resp)
     }
 
+    /// Scan one page of a key range, by collecting the records streamed back by metasrv.
+    #[minitrace::trace]
+    pub(crate) async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, MetaError> {
+        debug!(
+            req = as_debug!(&req);
+            "MetaGrpcClient::range_kv request"
+        );
+
+        let data = serde_json::to_string(&req).map_err(|e| {
+            MetaNetworkError::InvalidArgument(InvalidArgument::new(e, "fail to encode request"))
+        })?;
+        let raft_req = RaftRequest { data };
+
+        let mut client = self.make_client().await?;
+        let result = client
+            .kv_range(common_tracing::inject_span_to_tonic_request(Request::new(
+                raft_req.clone(),
+            )))
+            .await;
+
+        let strm = match result {
+            Ok(r) => r.into_inner(),
+            Err(s) => {
+                if s.code() == Code::Unimplemented {
+                    // metasrv before range scan was introduced.
+                    return self.range_kv_by_prefix_list(req).await;
+                } else if status_is_retryable(&s) {
+                    self.mark_as_unhealthy().await;
+                    let mut client = self.make_client().await?;
+                    client
+                        .kv_range(common_tracing::inject_span_to_tonic_request(Request::new(
+                            raft_req,
+                        )))
+                        .await?
+                        .into_inner()
+                } else {
+                    return Err(s.into());
+                }
+            }
+        };
+
+        let items = strm.try_collect::<Vec<_>>().await?;
+        let kvs = items.into_iter().map(|x| x.into_pair()).collect();
+
+        Ok(RangeKVReply::new(&req, kvs))
+    }
+
+    /// Emulate a range scan with a prefix list, for a metasrv that does not support `KvRange`.
+    ///
+    /// It lists by the longest common prefix of the range bounds and filters the result locally.
+    /// The listed records are kept for the following pages, which start from the continuation key,
+    /// so that paging through a range lists it only once.
+    async fn range_kv_by_prefix_list(&self, req: RangeKVReq) -> Result<RangeKVReply, MetaError> {
+        let cached = match &req.continuation {
+            Some(_) => self
+                .range_list_cache
+                .lock()
+                .take()
+                .filter(|c| c.start == req.start && c.end == req.end),
+            None => None,
+        };
+
+        let listed = match cached {
+            Some(c) => c.listed,
+            None => {
+                let prefix = req.list_prefix();
+                let mut listed = self.kv_api(ListKVReq { prefix }).await?;
+                listed.sort_by(|a, b| a.0.cmp(&b.0));
+                listed
+            }
+        };
+
+        let reply = RangeKVReply::from_listed(&req, &listed);
+        if reply.continuation.is_some() {
+            *self.range_list_cache.lock() = Some(RangeListCache {
+                start: req.start.clone(),
+                end: req.end.clone(),
+                listed,
+            });
+        }
+
+        Ok(reply)
+    }
+
     #[minitrace::trace]
     pub(crate) async fn transaction(&self, req: TxnRequest) -> Result<TxnReply, MetaError> {
         let txn: TxnRequest = req;
@@ -19,6 +19,8 @@ use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::MetaError;
@@ -60,6 +62,11 @@ impl kvapi::KVApi for ClientHandle {
         Ok(reply)
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, Self::Error> {
+        let reply = self.request(req).await?;
+        Ok(reply)
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         let reply = self.request(txn).await?;
         Ok(reply)
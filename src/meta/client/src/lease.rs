@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_meta_kvapi::kvapi::UpsertKVReply;
+use common_meta_types::LeaseCmd;
+use common_meta_types::MetaError;
+use common_meta_types::SeqV;
+
+use crate::ClientHandle;
+
+/// Lease API of meta-client.
+///
+/// A lease has a time-to-live and must be kept alive periodically, otherwise it expires.
+/// Keys attached to a lease are removed when the lease expires or is revoked.
+impl ClientHandle {
+    /// Create a lease with `ttl`, or reset the ttl of an existing lease.
+    pub async fn grant_lease(
+        &self,
+        lease_id: impl ToString,
+        ttl: Duration,
+    ) -> Result<(), MetaError> {
+        let _reply: UpsertKVReply = self
+            .request(LeaseCmd::Grant {
+                lease_id: lease_id.to_string(),
+                ttl_ms: ttl.as_millis() as u64,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Extend a lease and all its attached keys by another ttl.
+    ///
+    /// It returns `false` if the lease has already expired or been revoked.
+    pub async fn keep_alive_lease(&self, lease_id: impl ToString) -> Result<bool, MetaError> {
+        let reply: UpsertKVReply = self
+            .request(LeaseCmd::KeepAlive {
+                lease_id: lease_id.to_string(),
+            })
+            .await?;
+        Ok(reply.result.is_some())
+    }
+
+    /// Remove a lease and all its attached keys.
+    pub async fn revoke_lease(&self, lease_id: impl ToString) -> Result<(), MetaError> {
+        let _reply: UpsertKVReply = self
+            .request(LeaseCmd::Revoke {
+                lease_id: lease_id.to_string(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Put a key that is attached to a lease.
+    ///
+    /// It returns the record that is written, or `None` if the lease does not exist.
+    pub async fn put_with_lease(
+        &self,
+        lease_id: impl ToString,
+        key: impl ToString,
+        value: Vec<u8>,
+    ) -> Result<Option<SeqV>, MetaError> {
+        let reply: UpsertKVReply = self
+            .request(LeaseCmd::Attach {
+                lease_id: lease_id.to_string(),
+                key: key.to_string(),
+                value,
+            })
+            .await?;
+
+        if reply.is_changed() {
+            Ok(reply.result)
+        } else {
+            Ok(None)
+        }
+    }
+}
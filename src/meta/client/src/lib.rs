@@ -18,7 +18,9 @@ mod grpc_action;
 mod grpc_client;
 mod grpc_metrics;
 mod kv_api_impl;
+mod lease;
 mod message;
+mod semaphore;
 
 pub use common_meta_api::reply::reply_to_api_result;
 pub use common_meta_api::reply::reply_to_meta_result;
@@ -28,6 +30,8 @@ pub use grpc_client::ClientHandle;
 pub use grpc_client::MetaGrpcClient;
 pub use message::ClientWorkerRequest;
 use once_cell::sync::Lazy;
+pub use semaphore::Permit;
+pub use semaphore::Semaphore;
 use semver::BuildMetadata;
 use semver::Prerelease;
 use semver::Version;
@@ -64,6 +68,10 @@ pub static METACLI_COMMIT_SEMVER: Lazy<Version> = Lazy::new(|| {
 ///
 /// - 2023-05-07: since 1.1.32:
 ///   Meta service: add: TxnDeleteRequest provides a `match_seq` field to delete a record if its `seq` matches.
+///
+/// Meta service APIs added without requiring a newer server:
+/// - `KvRange` for paginated range scans: the client falls back to `ListKV` if the server responds with `Unimplemented`;
+/// - `Lease` requests sent through `kv_api`: an older server rejects them as unknown requests.
 pub static MIN_METASRV_SEMVER: Version = Version {
     major: 1,
     minor: 1,
@@ -21,6 +21,8 @@ use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
@@ -28,6 +30,7 @@ use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::LeaseCmd;
 use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
 use common_meta_types::TxnReply;
@@ -69,12 +72,18 @@ pub enum Request {
     /// List KVs by key prefix
     PrefixList(ListKVReq),
 
+    /// Scan one page of KVs in a key range
+    Range(RangeKVReq),
+
     /// Update or insert KV
     Upsert(UpsertKVReq),
 
     /// Run a transaction on remote
     Txn(TxnRequest),
 
+    /// Run a lease operation, which is replied with a `Response::Upsert`
+    Lease(LeaseCmd),
+
     /// Watch KV changes, expecting a Stream that reports KV change events
     Watch(WatchRequest),
 
@@ -97,8 +106,10 @@ impl Request {
             Request::Get(_) => "Get",
             Request::MGet(_) => "MGet",
             Request::PrefixList(_) => "PrefixList",
+            Request::Range(_) => "Range",
             Request::Upsert(_) => "Upsert",
             Request::Txn(_) => "Txn",
+            Request::Lease(_) => "Lease",
             Request::Watch(_) => "Watch",
             Request::Export(_) => "Export",
             Request::MakeClient(_) => "MakeClient",
@@ -114,6 +125,7 @@ pub enum Response {
     Get(Result<GetKVReply, MetaError>),
     MGet(Result<MGetKVReply, MetaError>),
     PrefixList(Result<ListKVReply, MetaError>),
+    Range(Result<RangeKVReply, MetaError>),
     Upsert(Result<UpsertKVReply, MetaError>),
     Txn(Result<TxnReply, MetaError>),
     Watch(Result<tonic::codec::Streaming<WatchResponse>, MetaError>),
@@ -131,6 +143,7 @@ impl Response {
             Response::Get(res) => res.is_err(),
             Response::MGet(res) => res.is_err(),
             Response::PrefixList(res) => res.is_err(),
+            Response::Range(res) => res.is_err(),
             Response::Upsert(res) => res.is_err(),
             Response::Txn(res) => res.is_err(),
             Response::Watch(res) => res.is_err(),
@@ -155,6 +168,10 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::Range(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
             Response::Upsert(res) => res
                 .as_ref()
                 .err()
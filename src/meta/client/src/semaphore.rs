@@ -0,0 +1,254 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A fair distributed semaphore built on leases.
+//!
+//! An acquirer queues itself with a key `__fd_semaphore/<name>/<lease_id>` attached to its own lease.
+//! The seq of the queued key is its ticket: it is allocated by the same raft log entry that writes the key,
+//! thus an acquirer queued later always has a greater ticket.
+//! Keeping the lease alive changes the seq of the key, thus the ticket is stored in the value of the key
+//! before the lease is kept alive.
+//! The acquirers with the smallest `permits` tickets hold the semaphore.
+//! The ticket is also the fencing token of a permit:
+//! a storage that receives writes from permit holders should reject a token smaller than one it has seen.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::tokio::time::timeout;
+use common_base::base::uuid::Uuid;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::RangeKVReq;
+use common_meta_types::anyerror::AnyError;
+use common_meta_types::protobuf::watch_request::FilterType;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::MetaClientError;
+use common_meta_types::MetaError;
+use common_meta_types::SeqV;
+use log::info;
+use log::warn;
+
+use crate::ClientHandle;
+
+/// A distributed semaphore that allows at most `permits` holders at the same time.
+///
+/// Acquirers are granted in the order they arrive.
+pub struct Semaphore {
+    client: Arc<ClientHandle>,
+    name: String,
+    permits: u64,
+    ttl: Duration,
+}
+
+impl Semaphore {
+    const PREFIX: &'static str = "__fd_semaphore";
+
+    /// Create a semaphore `name` with `permits`.
+    ///
+    /// A permit is kept alive by its holder every `ttl / 3`; it is released after `ttl` if the holder is gone.
+    pub fn new(
+        client: Arc<ClientHandle>,
+        name: impl ToString,
+        permits: u64,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            client,
+            name: name.to_string(),
+            permits,
+            ttl,
+        }
+    }
+
+    /// Create a distributed lock, i.e., a semaphore with only one permit.
+    pub fn new_lock(client: Arc<ClientHandle>, name: impl ToString, ttl: Duration) -> Self {
+        Self::new(client, name, 1, ttl)
+    }
+
+    /// Wait until a permit is acquired.
+    pub async fn acquire(&self) -> Result<Permit, MetaError> {
+        let permit = self.enqueue().await?;
+
+        loop {
+            if self.is_granted(&permit).await? {
+                info!(
+                    "semaphore {} acquired, fencing token: {}",
+                    self.name, permit.fencing_token
+                );
+                return Ok(permit);
+            }
+
+            self.wait_for_release().await?;
+        }
+    }
+
+    /// Acquire a permit if one is available at once, otherwise return `None`.
+    pub async fn try_acquire(&self) -> Result<Option<Permit>, MetaError> {
+        let permit = self.enqueue().await?;
+
+        if self.is_granted(&permit).await? {
+            Ok(Some(permit))
+        } else {
+            permit.release().await?;
+            Ok(None)
+        }
+    }
+
+    /// Put an entry attached to a new lease into the queue, the seq of the entry is the ticket.
+    ///
+    /// The ticket is then stored in the entry, since keeping the lease alive changes the seq.
+    async fn enqueue(&self) -> Result<Permit, MetaError> {
+        let lease_id = format!("semaphore/{}/{}", self.name, Uuid::new_v4());
+        self.client.grant_lease(&lease_id, self.ttl).await?;
+
+        let key = format!("{}/{}/{}", Self::PREFIX, self.name, lease_id);
+        let put = self
+            .client
+            .put_with_lease(&lease_id, &key, lease_id.as_bytes().to_vec())
+            .await?;
+        let ticket = match put {
+            Some(entry) => entry.seq,
+            None => return Err(lease_lost(&lease_id)),
+        };
+        let put = self
+            .client
+            .put_with_lease(&lease_id, &key, ticket.to_string().into_bytes())
+            .await?;
+        if put.is_none() {
+            return Err(lease_lost(&lease_id));
+        }
+
+        let keep_alive = spawn_keep_alive(self.client.clone(), lease_id.clone(), self.ttl);
+
+        Ok(Permit {
+            client: self.client.clone(),
+            lease_id,
+            key,
+            fencing_token: ticket,
+            keep_alive,
+        })
+    }
+
+    /// Check if the permit is one of the `permits` entries with the smallest tickets in the queue.
+    async fn is_granted(&self, permit: &Permit) -> Result<bool, MetaError> {
+        let (start, end) = self.queue_range();
+        let reply = self
+            .client
+            .range_kv(RangeKVReq::new(start, Some(end)))
+            .await?;
+
+        // The entry must still be in the queue, or the lease has expired.
+        if !reply.kvs.iter().any(|(k, _)| k == &permit.key) {
+            return Err(lease_lost(&permit.lease_id));
+        }
+
+        let ahead = reply
+            .kvs
+            .iter()
+            .filter(|(_, v)| ticket_of(v) < permit.fencing_token)
+            .count();
+        Ok((ahead as u64) < self.permits)
+    }
+
+    /// Wait for an entry in the queue to be removed, or a ttl to elapse.
+    async fn wait_for_release(&self) -> Result<(), MetaError> {
+        let (start, end) = self.queue_range();
+        let watch = WatchRequest {
+            key: start,
+            key_end: Some(end),
+            filter_type: FilterType::Delete.into(),
+        };
+
+        let mut strm: tonic::codec::Streaming<WatchResponse> = self.client.request(watch).await?;
+
+        // Re-check on timeout in case a deletion happened before the watcher is set up.
+        let _ = timeout(self.ttl, strm.message()).await;
+        Ok(())
+    }
+
+    fn queue_range(&self) -> (String, String) {
+        let prefix = format!("{}/{}/", Self::PREFIX, self.name);
+        let end = format!("{}/{}0", Self::PREFIX, self.name);
+        (prefix, end)
+    }
+}
+
+/// A permit of a [`Semaphore`] that is kept alive until it is released or dropped.
+///
+/// Dropping a permit stops keeping it alive, and it will be released by metasrv after a ttl.
+pub struct Permit {
+    client: Arc<ClientHandle>,
+    lease_id: String,
+    /// The key of the entry in the queue.
+    key: String,
+    fencing_token: u64,
+    keep_alive: JoinHandle<()>,
+}
+
+impl Permit {
+    /// A token that increases every time a permit is acquired.
+    pub fn fencing_token(&self) -> u64 {
+        self.fencing_token
+    }
+
+    /// Release the permit at once.
+    pub async fn release(self) -> Result<(), MetaError> {
+        self.keep_alive.abort();
+        self.client.revoke_lease(&self.lease_id).await
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.keep_alive.abort();
+    }
+}
+
+fn spawn_keep_alive(client: Arc<ClientHandle>, lease_id: String, ttl: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let interval = ttl / 3;
+        loop {
+            tokio::time::sleep(interval).await;
+
+            match client.keep_alive_lease(&lease_id).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    warn!("lease {} is lost, stop keeping alive", lease_id);
+                    return;
+                }
+                Err(e) => {
+                    warn!("fail to keep alive lease {}: {}", lease_id, e);
+                }
+            }
+        }
+    })
+}
+
+/// The ticket stored in a queued entry.
+///
+/// The entry whose ticket is not stored yet has not been kept alive, its seq is the ticket.
+fn ticket_of(entry: &SeqV) -> u64 {
+    std::str::from_utf8(&entry.data)
+        .ok()
+        .and_then(|ticket| ticket.parse().ok())
+        .unwrap_or(entry.seq)
+}
+
+fn lease_lost(lease_id: &str) -> MetaError {
+    MetaClientError::ClientRuntimeError(AnyError::error(format!("lease {} has expired", lease_id)))
+        .into()
+}
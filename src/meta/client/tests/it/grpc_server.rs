@@ -29,6 +29,7 @@ use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::protobuf::RangeItem;
 use common_meta_types::protobuf::TxnReply;
 use common_meta_types::protobuf::TxnRequest;
 use common_meta_types::protobuf::WatchRequest;
@@ -95,6 +96,16 @@ impl MetaService for GrpcServiceForTestImpl {
         todo!()
     }
 
+    type KvRangeStream =
+        Pin<Box<dyn Stream<Item = Result<RangeItem, tonic::Status>> + Send + 'static>>;
+
+    async fn kv_range(
+        &self,
+        _request: Request<RaftRequest>,
+    ) -> Result<Response<Self::KvRangeStream>, Status> {
+        todo!()
+    }
+
     async fn member_list(
         &self,
         _request: Request<MemberListRequest>,
@@ -17,6 +17,8 @@ use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 pub use common_meta_sled_store::init_temp_sled_db;
@@ -49,6 +51,11 @@ impl kvapi::KVApi for MetaEmbedded {
         sm.prefix_list_kv(prefix).await
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, Self::Error> {
+        let sm = self.inner.lock().await;
+        sm.range_kv(req).await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         let sm = self.inner.lock().await;
         sm.transaction(txn).await
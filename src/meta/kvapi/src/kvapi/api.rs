@@ -22,6 +22,8 @@ use crate::kvapi;
 use crate::kvapi::GetKVReply;
 use crate::kvapi::ListKVReply;
 use crate::kvapi::MGetKVReply;
+use crate::kvapi::RangeKVReply;
+use crate::kvapi::RangeKVReq;
 use crate::kvapi::UpsertKVReply;
 use crate::kvapi::UpsertKVReq;
 
@@ -57,6 +59,11 @@ pub trait KVApi: Send + Sync {
     /// List key-value records that are starts with the specified prefix.
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, Self::Error>;
 
+    /// Scan one page of key-value records in a key range.
+    ///
+    /// Use the `continuation` in the reply to fetch the following pages.
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, Self::Error>;
+
     /// Run transaction: update one or more records if specified conditions are met.
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error>;
 }
@@ -81,6 +88,10 @@ impl<U: kvapi::KVApi, T: Deref<Target = U> + Send + Sync> kvapi::KVApi for T {
         self.deref().prefix_list_kv(prefix).await
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, Self::Error> {
+        self.deref().range_kv(req).await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         self.deref().transaction(txn).await
     }
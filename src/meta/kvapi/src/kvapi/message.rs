@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::ops::Bound;
+
 use common_meta_types::Change;
 use common_meta_types::SeqV;
 use common_meta_types::UpsertKV;
 
+use crate::kvapi::prefix_to_range;
+use crate::kvapi::KeyError;
+
 pub type UpsertKVReq = UpsertKV;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -33,7 +38,196 @@ pub struct ListKVReq {
     pub prefix: String,
 }
 
+/// Scan the key range `[start, end)` in key order, one page at a time.
+///
+/// A page contains at most `limit` records. If a page is full, the reply carries a
+/// `continuation` token, which should be set in the next request to fetch the next page.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RangeKVReq {
+    /// The inclusive left bound of the range.
+    pub start: String,
+
+    /// The exclusive right bound of the range. `None` means unbounded.
+    pub end: Option<String>,
+
+    /// Max number of records to return in one page. `None` means no limit.
+    pub limit: Option<u64>,
+
+    /// The token returned by the previous page, i.e., the last key already returned.
+    /// The scan resumes from the key right after it.
+    pub continuation: Option<String>,
+}
+
+impl RangeKVReq {
+    pub fn new(start: impl ToString, end: Option<String>) -> Self {
+        Self {
+            start: start.to_string(),
+            end,
+            limit: None,
+            continuation: None,
+        }
+    }
+
+    /// Build a request to scan all the keys starting with `prefix`.
+    pub fn prefix(prefix: &str) -> Result<Self, KeyError> {
+        let (start, end) = prefix_to_range(prefix)?;
+        Ok(Self::new(start, Some(end)))
+    }
+
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Build the request for the page following the `reply`.
+    ///
+    /// It returns `None` if the `reply` is the last page.
+    pub fn next_page(&self, reply: &RangeKVReply) -> Option<Self> {
+        let continuation = reply.continuation.clone()?;
+        Some(Self {
+            continuation: Some(continuation),
+            ..self.clone()
+        })
+    }
+
+    /// The bounds of the keys to scan for this page, taking `continuation` into account.
+    pub fn bounds(&self) -> (Bound<String>, Bound<String>) {
+        let left = match &self.continuation {
+            Some(c) if c.as_str() >= self.start.as_str() => Bound::Excluded(c.clone()),
+            _ => Bound::Included(self.start.clone()),
+        };
+        let right = match &self.end {
+            Some(e) => Bound::Excluded(e.clone()),
+            None => Bound::Unbounded,
+        };
+        (left, right)
+    }
+
+    /// Returns true if the `key` is in the range of this page.
+    pub fn contains(&self, key: &str) -> bool {
+        let (left, right) = self.bounds();
+        let left_ok = match left {
+            Bound::Included(l) => key >= l.as_str(),
+            Bound::Excluded(l) => key > l.as_str(),
+            Bound::Unbounded => true,
+        };
+        let right_ok = match right {
+            Bound::Excluded(r) => key < r.as_str(),
+            _ => true,
+        };
+        left_ok && right_ok
+    }
+
+    /// Whether a page of `n` records reaches the limit.
+    pub fn is_full(&self, n: usize) -> bool {
+        match self.limit {
+            Some(limit) => n as u64 >= limit,
+            None => false,
+        }
+    }
+
+    /// The prefix to list all the keys in the range, for a server that does not support range scan.
+    ///
+    /// It is the longest common prefix of the range bounds.
+    pub fn list_prefix(&self) -> String {
+        match &self.end {
+            Some(end) => self
+                .start
+                .chars()
+                .zip(end.chars())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a)
+                .collect::<String>(),
+            None => "".to_string(),
+        }
+    }
+}
+
+/// A page of records returned by a [`RangeKVReq`].
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeKVReply {
+    pub kvs: Vec<(String, SeqV<Vec<u8>>)>,
+
+    /// The token to fetch the next page. `None` if there are no more records.
+    pub continuation: Option<String>,
+}
+
+impl RangeKVReply {
+    /// Build a page from the records scanned for `req`, setting the continuation token if the page is full.
+    pub fn new(req: &RangeKVReq, kvs: Vec<(String, SeqV<Vec<u8>>)>) -> Self {
+        let continuation = if req.is_full(kvs.len()) {
+            kvs.last().map(|(k, _)| k.clone())
+        } else {
+            None
+        };
+        Self { kvs, continuation }
+    }
+
+    /// Build a page of `req` from the records listed by [`RangeKVReq::list_prefix`], which are sorted by key.
+    ///
+    /// The start of the page is found with a binary search, so that paging through the listed records costs
+    /// `O(log(n))` per page instead of a full scan.
+    pub fn from_listed(req: &RangeKVReq, listed: &[(String, SeqV<Vec<u8>>)]) -> Self {
+        let (left, _) = req.bounds();
+        let begin = listed.partition_point(|(k, _)| match &left {
+            Bound::Included(l) => k < l,
+            Bound::Excluded(l) => k <= l,
+            Bound::Unbounded => false,
+        });
+
+        let mut kvs = vec![];
+        for (k, v) in &listed[begin..] {
+            if req.is_full(kvs.len()) || !req.contains(k) {
+                break;
+            }
+            kvs.push((k.clone(), v.clone()));
+        }
+
+        Self::new(req, kvs)
+    }
+}
+
 pub type UpsertKVReply = Change<Vec<u8>>;
 pub type GetKVReply = Option<SeqV<Vec<u8>>>;
 pub type MGetKVReply = Vec<Option<SeqV<Vec<u8>>>>;
 pub type ListKVReply = Vec<(String, SeqV<Vec<u8>>)>;
+
+#[cfg(test)]
+mod tests {
+    use crate::kvapi::RangeKVReply;
+    use crate::kvapi::RangeKVReq;
+
+    #[test]
+    fn test_range_kv_req_list_prefix() -> anyhow::Result<()> {
+        assert_eq!("r/", RangeKVReq::prefix("r/")?.list_prefix());
+        assert_eq!(
+            "r/",
+            RangeKVReq::new("r/1", Some("r/4".to_string())).list_prefix()
+        );
+        assert_eq!("", RangeKVReq::new("r/1", None).list_prefix());
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_kv_reply_from_listed() -> anyhow::Result<()> {
+        let listed = ["r/1", "r/2", "r/3", "r/4", "s/1"]
+            .into_iter()
+            .map(|k| (k.to_string(), Default::default()))
+            .collect::<Vec<_>>();
+        let keys_of =
+            |reply: &RangeKVReply| reply.kvs.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>();
+
+        // page through the listed records by the continuation.
+        let req = RangeKVReq::new("r/2", Some("s/".to_string())).with_limit(2);
+        let reply = RangeKVReply::from_listed(&req, &listed);
+        assert_eq!(vec!["r/2", "r/3"], keys_of(&reply));
+        assert_eq!(Some("r/3".to_string()), reply.continuation);
+
+        let req = req.next_page(&reply).unwrap();
+        let reply = RangeKVReply::from_listed(&req, &listed);
+        assert_eq!(vec!["r/4"], keys_of(&reply));
+        assert_eq!(None, reply.continuation);
+
+        Ok(())
+    }
+}
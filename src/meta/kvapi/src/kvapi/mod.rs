@@ -34,6 +34,8 @@ pub use message::ListKVReply;
 pub use message::ListKVReq;
 pub use message::MGetKVReply;
 pub use message::MGetKVReq;
+pub use message::RangeKVReply;
+pub use message::RangeKVReq;
 pub use message::UpsertKVReply;
 pub use message::UpsertKVReq;
 pub use prefix::prefix_to_range;
@@ -42,6 +42,7 @@ use log::debug;
 use log::info;
 
 use crate::kvapi;
+use crate::kvapi::RangeKVReq;
 use crate::kvapi::UpsertKVReq;
 
 pub struct TestSuite {}
@@ -59,6 +60,7 @@ impl kvapi::TestSuite {
         self.kv_timeout(&builder.build().await).await?;
         self.kv_meta(&builder.build().await).await?;
         self.kv_list(&builder.build().await).await?;
+        self.kv_range(&builder.build().await).await?;
         self.kv_mget(&builder.build().await).await?;
         self.kv_txn_absent_seq_0(&builder.build().await).await?;
         self.kv_transaction(&builder.build().await).await?;
@@ -447,6 +449,75 @@ impl kvapi::TestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    pub async fn kv_range<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        info!("--- kvapi::KVApiTestSuite::kv_range() start");
+
+        kv.upsert_kv(UpsertKVReq::update("r", b"")).await?;
+        for i in 0..5 {
+            let key = format!("r/{}", i);
+            kv.upsert_kv(UpsertKVReq::update(&key, key.as_bytes()))
+                .await?;
+        }
+        kv.upsert_kv(UpsertKVReq::update("s", b"")).await?;
+
+        let keys_of = |reply: &kvapi::RangeKVReply| {
+            reply.kvs.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>()
+        };
+
+        info!("--- scan without limit");
+        {
+            let req = RangeKVReq::new("r/1", Some("r/4".to_string()));
+            let reply = kv.range_kv(req).await?;
+            assert_eq!(vec!["r/1", "r/2", "r/3"], keys_of(&reply));
+            assert_eq!(b"r/1".to_vec(), reply.kvs[0].1.data);
+            assert_eq!(None, reply.continuation);
+        }
+
+        info!("--- scan unbounded right");
+        {
+            let req = RangeKVReq::new("r/3", None);
+            let reply = kv.range_kv(req).await?;
+            assert_eq!(vec!["r/3", "r/4", "s"], keys_of(&reply));
+        }
+
+        info!("--- scan by page");
+        {
+            let mut req = RangeKVReq::prefix("r/")?.with_limit(2);
+            let mut pages = vec![];
+            loop {
+                let reply = kv.range_kv(req.clone()).await?;
+                pages.push(keys_of(&reply));
+                match req.next_page(&reply) {
+                    Some(next) => req = next,
+                    None => break,
+                }
+            }
+            assert_eq!(
+                vec![
+                    vec!["r/0".to_string(), "r/1".to_string()],
+                    vec!["r/2".to_string(), "r/3".to_string()],
+                    vec!["r/4".to_string()],
+                ],
+                pages
+            );
+        }
+
+        info!("--- the last full page has a continuation but the next page is empty");
+        {
+            let req = RangeKVReq::prefix("r/")?.with_limit(5);
+            let reply = kv.range_kv(req.clone()).await?;
+            assert_eq!(Some("r/4".to_string()), reply.continuation);
+
+            let next = req.next_page(&reply).unwrap();
+            let reply = kv.range_kv(next).await?;
+            assert!(reply.kvs.is_empty());
+            assert_eq!(None, reply.continuation);
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     pub async fn kv_mget<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         info!("--- kvapi::KVApiTestSuite::kv_mget() start");
@@ -161,6 +161,12 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
                     }),
                 }))
             }
+            // Values attached to a lease are opaque bytes and need no conversion.
+            Cmd::Lease(lease_cmd) => Ok(Some(LogEntry {
+                txid: log_entry.txid,
+                time_ms: log_entry.time_ms,
+                cmd: Cmd::Lease(lease_cmd),
+            })),
         }
     }
 
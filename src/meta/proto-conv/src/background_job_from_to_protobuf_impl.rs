@@ -49,6 +49,10 @@ impl FromToProto for mt::background::BackgroundJobInfo {
             task_type: FromPrimitive::from_i32(p.task_type).ok_or_else(|| Incompatible {
                 reason: format!("invalid TaskType: {}", p.task_type),
             })?,
+            sql_task: p
+                .sql_task
+                .map(mt::background::SqlTaskDefinition::from_pb)
+                .transpose()?,
 
             last_updated: p
                 .last_updated
@@ -69,6 +73,7 @@ impl FromToProto for mt::background::BackgroundJobInfo {
             job_params: self.job_params.clone().and_then(|t| t.to_pb().ok()),
             job_status: self.job_status.clone().and_then(|t| t.to_pb().ok()),
             task_type: self.task_type.clone() as i32,
+            sql_task: self.sql_task.as_ref().map(|t| t.to_pb()).transpose()?,
             last_updated: self.last_updated.and_then(|t| t.to_pb().ok()),
             message: self.message.clone(),
             creator: self.creator.clone().and_then(|c| c.to_pb().ok()),
@@ -78,6 +83,37 @@ impl FromToProto for mt::background::BackgroundJobInfo {
     }
 }
 
+impl FromToProto for mt::background::SqlTaskDefinition {
+    type PB = pb::SqlTaskDefinition;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(Self {
+            query_text: p.query_text,
+            database: p.database,
+            warehouse: p.warehouse,
+            after: p.after,
+            comment: p.comment,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(pb::SqlTaskDefinition {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            query_text: self.query_text.clone(),
+            database: self.database.clone(),
+            warehouse: self.warehouse.clone(),
+            after: self.after.clone(),
+            comment: self.comment.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::background::BackgroundJobParams {
     type PB = pb::BackgroundJobParams;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -55,6 +55,9 @@ impl FromToProto for mt::background::BackgroundTaskInfo {
             vacuum_stats: p
                 .vacuum_stats
                 .and_then(|t| mt::background::VacuumStats::from_pb(t).ok()),
+            sql_task_stats: p
+                .sql_task_stats
+                .and_then(|t| mt::background::SqlTaskStats::from_pb(t).ok()),
             manual_trigger: p
                 .manual_trigger
                 .and_then(|t| ManualTriggerParams::from_pb(t).ok()),
@@ -79,6 +82,7 @@ impl FromToProto for mt::background::BackgroundTaskInfo {
                 .clone()
                 .and_then(|t| t.to_pb().ok()),
             vacuum_stats: self.vacuum_stats.clone().and_then(|t| t.to_pb().ok()),
+            sql_task_stats: self.sql_task_stats.clone().and_then(|t| t.to_pb().ok()),
             manual_trigger: self.manual_trigger.clone().and_then(|t| t.to_pb().ok()),
             creator: self.creator.as_ref().and_then(|c| c.to_pb().ok()),
             created_at: self.created_at.to_pb()?,
@@ -144,18 +148,55 @@ impl FromToProto for mt::background::VacuumStats {
     fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
     where Self: Sized {
         reader_check_msg(p.ver, p.min_reader_ver)?;
-        Ok(Self {})
+        Ok(Self {
+            db_id: p.db_id,
+            table_id: p.table_id,
+            removed_files: p.removed_files,
+            removed_bytes: p.removed_bytes,
+            total_vacuum_time: p.total_vacuum_time_secs.map(Duration::from_secs_f32),
+        })
     }
 
     fn to_pb(&self) -> Result<Self::PB, Incompatible> {
         let p = pb::VacuumStats {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
+            db_id: self.db_id,
+            table_id: self.table_id,
+            removed_files: self.removed_files,
+            removed_bytes: self.removed_bytes,
+            total_vacuum_time_secs: self.total_vacuum_time.map(|t| t.as_secs_f32()),
         };
         Ok(p)
     }
 }
 
+impl FromToProto for mt::background::SqlTaskStats {
+    type PB = pb::SqlTaskStats;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(Self {
+            query_id: p.query_id,
+            query_text: p.query_text,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(pb::SqlTaskStats {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            query_id: self.query_id.clone(),
+            query_text: self.query_text.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::background::BackgroundTaskIdent {
     type PB = pb::BackgroundTaskIdent;
     fn get_pb_ver(p: &Self::PB) -> u64 {
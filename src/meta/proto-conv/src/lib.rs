@@ -73,6 +73,7 @@ mod index_from_to_protobuf_impl;
 mod least_visible_time_from_to_protobuf_impl;
 mod owner_from_to_protobuf_impl;
 mod schema_from_to_protobuf_impl;
+mod sequence_from_to_protobuf_impl;
 mod share_from_to_protobuf_impl;
 mod stage_from_to_protobuf_impl;
 mod table_from_to_protobuf_impl;
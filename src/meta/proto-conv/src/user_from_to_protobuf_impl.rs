@@ -56,6 +56,13 @@ impl FromToProto for mt::principal::AuthInfo {
                     reason: format!("invalid PasswordHashMethod: {}", hash_method),
                 })?,
             }),
+            Some(pb::auth_info::Info::KeyPair(pb::auth_info::KeyPair {
+                public_key,
+                public_key_2,
+            })) => Ok(mt::principal::AuthInfo::KeyPair {
+                public_key,
+                public_key_2,
+            }),
             None => Err(Incompatible {
                 reason: "AuthInfo cannot be None".to_string(),
             }),
@@ -75,6 +82,13 @@ impl FromToProto for mt::principal::AuthInfo {
                 hash_value: hash_value.clone(),
                 hash_method: *hash_method as i32,
             })),
+            mt::principal::AuthInfo::KeyPair {
+                public_key,
+                public_key_2,
+            } => Some(pb::auth_info::Info::KeyPair(pb::auth_info::KeyPair {
+                public_key: public_key.clone(),
+                public_key_2: public_key_2.clone(),
+            })),
         };
         Ok(pb::AuthInfo {
             ver: VER,
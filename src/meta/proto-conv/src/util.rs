@@ -89,6 +89,14 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (57, "2023-09-05: Add: catalog.proto add hdfs config", ),
     (58, "2023-09-06: Add: udf.proto/UserDefinedFunction", ),
     (59, "2023-08-17: Add: user.proto/CsvFileFormatParams add field `allow_column_count_mismatch`", ),
+    (60, "2023-09-12: Add: user.proto/AuthInfo::KeyPair", ),
+    (61, "2023-09-14: Add: background.proto/VacuumStats add removed files and bytes", ),
+    (62, "2023-09-18: Add: background.proto/SqlTaskDefinition and SqlTaskStats", ),
+    (63, "2023-09-25: Add: sequence.proto/SequenceMeta", ),
+    (64, "2023-09-26: Add: metadata.proto/DataType Interval type", ),
+    (65, "2023-09-27: Add: metadata.proto/DataType TimestampTz type", ),
+    (66, "2023-09-28: Add: metadata.proto/DataType Geometry type", ),
+    (67, "2023-09-29: Add: sequence.proto/SequenceMeta::table_id", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -64,3 +64,11 @@ mod v056_least_visible_time;
 mod v057_hdfs_storage;
 mod v058_udf;
 mod v059_csv_format_params;
+mod v060_user_info;
+mod v061_background;
+mod v062_background;
+mod v063_sequence;
+mod v064_schema;
+mod v065_schema;
+mod v066_schema;
+mod v067_sequence;
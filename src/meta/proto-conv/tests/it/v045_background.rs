@@ -54,6 +54,7 @@ fn test_decode_v44_background_task() -> anyhow::Result<()> {
         message: "1123".to_string(),
         compaction_task_stats: None,
         vacuum_stats: None,
+        sql_task_stats: None,
         manual_trigger: None,
         creator: None,
 
@@ -104,7 +105,8 @@ fn test_decode_v40_background_task_case_2() -> anyhow::Result<()> {
             }),
             total_compaction_time: Some(Duration::from_secs(100)),
         }),
-        vacuum_stats: Some(VacuumStats {}),
+        vacuum_stats: Some(VacuumStats::default()),
+        sql_task_stats: None,
         manual_trigger: None,
         creator: Some(BackgroundJobIdent {
             tenant: "test1".to_string(),
@@ -139,6 +141,7 @@ fn test_decode_v44_background_job() -> anyhow::Result<()> {
         }),
         last_updated: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
         task_type: BackgroundTaskType::COMPACTION,
+        sql_task: None,
         message: "".to_string(),
         creator: None,
         created_at: Default::default(),
@@ -82,7 +82,8 @@ fn test_decode_v48_background_task_case_2() -> anyhow::Result<()> {
             }),
             total_compaction_time: Some(Duration::from_secs(100)),
         }),
-        vacuum_stats: Some(VacuumStats {}),
+        vacuum_stats: Some(VacuumStats::default()),
+        sql_task_stats: None,
         manual_trigger: Some(ManualTriggerParams {
             id: "1231".to_string(),
             trigger: Default::default(),
@@ -128,6 +129,7 @@ fn test_decode_v48_background_job() -> anyhow::Result<()> {
         }),
         last_updated: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
         task_type: BackgroundTaskType::COMPACTION,
+        sql_task: None,
         message: "".to_string(),
         creator: None,
         created_at: Default::default(),
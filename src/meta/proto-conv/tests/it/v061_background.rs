@@ -0,0 +1,72 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::background::VacuumStats;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v61_background_vacuum_task() -> anyhow::Result<()> {
+    let bytes = vec![
+        26, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85,
+        84, 67, 32, 1, 40, 1, 50, 24, 100, 97, 116, 97, 98, 101, 110, 100, 32, 98, 97, 99, 107,
+        103, 114, 111, 117, 110, 100, 32, 116, 97, 115, 107, 66, 20, 8, 21, 16, 92, 24, 10, 32,
+        128, 16, 45, 0, 0, 200, 66, 160, 6, 61, 168, 6, 24, 210, 5, 25, 10, 5, 116, 101, 115, 116,
+        49, 18, 10, 118, 97, 99, 117, 117, 109, 95, 106, 111, 98, 160, 6, 61, 168, 6, 24, 218, 5,
+        23, 49, 57, 55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84,
+        67, 160, 6, 61, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::background::BackgroundTaskInfo {
+        last_updated: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        task_type: BackgroundTaskType::VACUUM,
+        task_state: BackgroundTaskState::DONE,
+        message: "databend background task".to_string(),
+        compaction_task_stats: None,
+        vacuum_stats: Some(VacuumStats {
+            db_id: 21,
+            table_id: 92,
+            removed_files: 10,
+            removed_bytes: 2048,
+            total_vacuum_time: Some(Duration::from_secs(100)),
+        }),
+        sql_task_stats: None,
+        manual_trigger: None,
+        creator: Some(BackgroundJobIdent {
+            tenant: "test1".to_string(),
+            name: "vacuum_job".to_string(),
+        }),
+        created_at: Default::default(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 61, want())
+}
@@ -0,0 +1,119 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobState;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::BackgroundJobType;
+use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::background::SqlTaskDefinition;
+use common_meta_app::background::SqlTaskStats;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v62_background_sql_task_job() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 26, 8, 2, 26, 11, 48, 32, 48, 32, 42, 32, 42, 32, 42, 32, 42, 34, 3, 85, 84, 67, 160,
+        6, 62, 168, 6, 24, 18, 8, 16, 2, 160, 6, 62, 168, 6, 24, 24, 2, 42, 23, 50, 48, 49, 52, 45,
+        49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 50, 84, 10, 31, 73,
+        78, 83, 69, 82, 84, 32, 73, 78, 84, 79, 32, 116, 49, 32, 83, 69, 76, 69, 67, 84, 32, 42,
+        32, 70, 82, 79, 77, 32, 116, 50, 18, 3, 100, 98, 49, 26, 3, 119, 104, 49, 34, 5, 116, 97,
+        115, 107, 49, 34, 5, 116, 97, 115, 107, 50, 42, 19, 105, 110, 99, 114, 101, 109, 101, 110,
+        116, 97, 108, 32, 114, 101, 102, 114, 101, 115, 104, 160, 6, 62, 168, 6, 24, 218, 5, 23,
+        49, 57, 55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67,
+        160, 6, 62, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::background::BackgroundJobInfo {
+        job_params: Some(BackgroundJobParams {
+            job_type: BackgroundJobType::CRON,
+            scheduled_job_interval: std::time::Duration::from_secs(0),
+            scheduled_job_cron: "0 0 * * * *".to_string(),
+            scheduled_job_timezone: Some(chrono_tz::UTC),
+            manual_trigger_params: None,
+        }),
+        job_status: Some(BackgroundJobStatus {
+            job_state: BackgroundJobState::SUSPENDED,
+            last_task_id: None,
+            last_task_run_at: None,
+            next_task_scheduled_time: None,
+        }),
+        task_type: BackgroundTaskType::SQL,
+        sql_task: Some(SqlTaskDefinition {
+            query_text: "INSERT INTO t1 SELECT * FROM t2".to_string(),
+            database: "db1".to_string(),
+            warehouse: Some("wh1".to_string()),
+            after: vec!["task1".to_string(), "task2".to_string()],
+            comment: Some("incremental refresh".to_string()),
+        }),
+        last_updated: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        message: "".to_string(),
+        creator: None,
+        created_at: Default::default(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 62, want())
+}
+
+#[test]
+fn test_decode_v62_background_sql_task() -> anyhow::Result<()> {
+    let bytes = vec![
+        26, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85,
+        84, 67, 32, 2, 40, 1, 82, 77, 10, 36, 99, 49, 100, 51, 98, 54, 102, 52, 45, 55, 101, 51,
+        53, 45, 52, 99, 56, 98, 45, 97, 54, 102, 50, 45, 50, 100, 52, 102, 55, 97, 49, 99, 57, 101,
+        48, 49, 18, 31, 73, 78, 83, 69, 82, 84, 32, 73, 78, 84, 79, 32, 116, 49, 32, 83, 69, 76,
+        69, 67, 84, 32, 42, 32, 70, 82, 79, 77, 32, 116, 50, 160, 6, 62, 168, 6, 24, 210, 5, 20,
+        10, 5, 116, 101, 115, 116, 49, 18, 5, 116, 97, 115, 107, 49, 160, 6, 62, 168, 6, 24, 218,
+        5, 23, 49, 57, 55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85,
+        84, 67, 160, 6, 62, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::background::BackgroundTaskInfo {
+        last_updated: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        task_type: BackgroundTaskType::SQL,
+        task_state: BackgroundTaskState::DONE,
+        message: "".to_string(),
+        compaction_task_stats: None,
+        vacuum_stats: None,
+        sql_task_stats: Some(SqlTaskStats {
+            query_id: "c1d3b6f4-7e35-4c8b-a6f2-2d4f7a1c9e01".to_string(),
+            query_text: "INSERT INTO t1 SELECT * FROM t2".to_string(),
+        }),
+        manual_trigger: None,
+        creator: Some(BackgroundJobIdent {
+            tenant: "test1".to_string(),
+            name: "task1".to_string(),
+        }),
+        created_at: Default::default(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 62, want())
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::time::Duration;
 use std::time::SystemTime;
 
@@ -26,8 +27,11 @@ use common_meta_types::ConditionResult;
 use common_meta_types::Entry;
 use common_meta_types::EntryPayload;
 use common_meta_types::KVMeta;
+use common_meta_types::LeaseCmd;
+use common_meta_types::LeaseInfo;
 use common_meta_types::MatchSeq;
 use common_meta_types::Node;
+use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::SeqValue;
 use common_meta_types::StoredMembership;
@@ -62,6 +66,9 @@ pub struct Applier<'a> {
 
     /// The changes has been made by the applying one log entry
     changes: Vec<Change<Vec<u8>, String>>,
+
+    /// The proposing time of the log entry being applied, used to renew leases.
+    log_time_ms: u64,
 }
 
 impl<'a> Applier<'a> {
@@ -69,6 +76,7 @@ impl<'a> Applier<'a> {
         Self {
             sm,
             changes: Vec::new(),
+            log_time_ms: 0,
         }
     }
 
@@ -81,6 +89,7 @@ impl<'a> Applier<'a> {
 
         let log_id = &entry.log_id;
         let log_time_ms = Self::get_log_time(entry);
+        self.log_time_ms = log_time_ms;
 
         self.clean_expired_kvs(log_time_ms).await;
 
@@ -139,6 +148,8 @@ impl<'a> Applier<'a> {
             Cmd::UpsertKV(ref upsert_kv) => self.apply_upsert_kv(upsert_kv).await,
 
             Cmd::Transaction(txn) => self.apply_txn(txn).await,
+
+            Cmd::Lease(lease_cmd) => self.apply_lease(lease_cmd).await,
         };
 
         info!("apply_result: cmd: {}; res: {}", cmd, res);
@@ -219,6 +230,90 @@ impl<'a> Applier<'a> {
         (prev, result)
     }
 
+    /// Execute a lease operation.
+    ///
+    /// A lease and its attached keys are renewed with the time of the log entry,
+    /// thus every node computes the same expiration time.
+    /// It returns the change of the lease record, or the change of the attached key for `Attach`.
+    #[minitrace::trace]
+    async fn apply_lease(&mut self, cmd: &LeaseCmd) -> AppliedState {
+        debug!(cmd = as_display!(cmd); "apply_lease");
+
+        let lease_key = LeaseInfo::key(cmd.lease_id());
+        let curr = self.get_lease(&lease_key).await;
+
+        match cmd {
+            LeaseCmd::Grant { ttl_ms, .. } => {
+                let mut info = curr.unwrap_or_default();
+                info.ttl_ms = *ttl_ms;
+                self.renew_lease(&lease_key, info).await
+            }
+            LeaseCmd::KeepAlive { .. } => match curr {
+                Some(info) => self.renew_lease(&lease_key, info).await,
+                None => Change::new(None, None).into(),
+            },
+            LeaseCmd::Revoke { .. } => {
+                if let Some(info) = curr {
+                    for key in info.keys.iter() {
+                        self.upsert_kv(&UpsertKV::delete(key)).await;
+                    }
+                }
+                let (prev, result) = self.upsert_kv(&UpsertKV::delete(&lease_key)).await;
+                Change::new(prev, result).into()
+            }
+            LeaseCmd::Attach { key, value, .. } => {
+                let mut info = match curr {
+                    Some(info) => info,
+                    None => {
+                        let curr = self.sm.get_kv(key).await;
+                        return Change::new(curr.clone(), curr).into();
+                    }
+                };
+
+                let meta = info.kv_meta(self.log_time_ms);
+                let (prev, result) = self
+                    .upsert_kv(&UpsertKV::update(key, value).with(meta))
+                    .await;
+
+                info.keys.insert(key.clone());
+                self.renew_lease(&lease_key, info).await;
+
+                Change::new(prev, result).into()
+            }
+        }
+    }
+
+    /// Load a non-expired lease.
+    async fn get_lease(&self, lease_key: &str) -> Option<LeaseInfo> {
+        let seq_v = self.sm.get_kv(lease_key).await;
+        if seq_v.is_expired(self.log_time_ms) {
+            return None;
+        }
+        seq_v.and_then(|x| LeaseInfo::decode(&x.data))
+    }
+
+    /// Reset the expiration time of a lease and all of its attached keys.
+    ///
+    /// Keys that are removed since being attached are forgotten.
+    async fn renew_lease(&mut self, lease_key: &str, mut info: LeaseInfo) -> AppliedState {
+        let meta = info.kv_meta(self.log_time_ms);
+
+        let mut alive = BTreeSet::new();
+        for key in std::mem::take(&mut info.keys) {
+            let upsert = UpsertKV::new(&key, MatchSeq::GE(1), Operation::AsIs, Some(meta.clone()));
+            let (_prev, result) = self.upsert_kv(&upsert).await;
+            if result.is_some() {
+                alive.insert(key);
+            }
+        }
+        info.keys = alive;
+
+        let (prev, result) = self
+            .upsert_kv(&UpsertKV::update(lease_key, &info.encode()).with(meta))
+            .await;
+        Change::new(prev, result).into()
+    }
+
     #[minitrace::trace]
     async fn apply_txn(&mut self, req: &TxnRequest) -> AppliedState {
         debug!(txn = as_display!(req); "apply txn cmd");
@@ -22,6 +22,8 @@ use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_stoerr::MetaBytesError;
@@ -113,6 +115,13 @@ impl<'a> kvapi::KVApi for SMV002KVApi<'a> {
         Ok(kvs.collect())
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, Self::Error> {
+        let local_now_ms = SeqV::<()>::now_ms();
+
+        let kvs = self.sm.range_kv(&req, local_now_ms).await;
+        Ok(RangeKVReply::new(&req, kvs))
+    }
+
     async fn transaction(&self, _txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         unreachable!("write operation SM2KVApi::transaction is disabled")
     }
@@ -283,6 +292,34 @@ impl SMV002 {
         res
     }
 
+    /// Scan kv entries in the range specified by `req`, at most `req.limit` entries.
+    ///
+    /// Tombstones and entries expired by `now_ms` are skipped and do not count toward the limit.
+    pub async fn range_kv(&self, req: &RangeKVReq, now_ms: u64) -> Vec<(String, SeqV)> {
+        let mut res = Vec::new();
+        let strm = self.levels.str_map().range(req.bounds()).await;
+
+        {
+            let mut strm = std::pin::pin!(strm);
+
+            while let Some((k, marked)) = strm.next().await {
+                if req.is_full(res.len()) {
+                    break;
+                }
+
+                let seqv = Into::<Option<SeqV>>::into(marked);
+
+                if let Some(x) = seqv {
+                    if !x.is_expired(now_ms) {
+                        res.push((k, x));
+                    }
+                }
+            }
+        }
+
+        res
+    }
+
     pub(crate) fn update_expire_cursor(&mut self, log_time_ms: u64) {
         if log_time_ms < self.expire_cursor.time_ms {
             warn!(
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::convert::TryInto;
 use std::fmt::Debug;
 use std::time::Duration;
@@ -39,6 +40,8 @@ use common_meta_types::ConditionResult;
 use common_meta_types::Entry;
 use common_meta_types::EntryPayload;
 use common_meta_types::KVMeta;
+use common_meta_types::LeaseCmd;
+use common_meta_types::LeaseInfo;
 use common_meta_types::LogId;
 use common_meta_types::MatchSeq;
 use common_meta_types::MatchSeqExt;
@@ -461,6 +464,118 @@ impl StateMachine {
         Ok(Change::new(prev, result).into())
     }
 
+    /// Execute a lease operation.
+    ///
+    /// It returns the change of the lease record, or the change of the attached key for `Attach`.
+    fn apply_lease_cmd(
+        &self,
+        cmd: &LeaseCmd,
+        txn_tree: &mut TransactionSledTree,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        debug!(cmd = as_display!(cmd); "apply_lease_cmd");
+
+        let lease_key = LeaseInfo::key(cmd.lease_id());
+
+        let curr = txn_tree.key_space::<GenericKV>().get(&lease_key)?;
+        let (_expired, curr) = Self::expire_seq_v(curr, log_time_ms);
+        let curr = curr.and_then(|x| LeaseInfo::decode(&x.data));
+
+        match cmd {
+            LeaseCmd::Grant { ttl_ms, .. } => {
+                let mut info = curr.unwrap_or_default();
+                info.ttl_ms = *ttl_ms;
+                Self::txn_renew_lease(txn_tree, &lease_key, info, log_time_ms)
+            }
+            LeaseCmd::KeepAlive { .. } => match curr {
+                Some(info) => Self::txn_renew_lease(txn_tree, &lease_key, info, log_time_ms),
+                None => Ok(Change::new(None, None).into()),
+            },
+            LeaseCmd::Revoke { .. } => {
+                if let Some(info) = curr {
+                    for key in info.keys.iter() {
+                        Self::txn_upsert_kv_with_change(
+                            txn_tree,
+                            &UpsertKV::delete(key),
+                            log_time_ms,
+                        )?;
+                    }
+                }
+                let (prev, result) = Self::txn_upsert_kv_with_change(
+                    txn_tree,
+                    &UpsertKV::delete(&lease_key),
+                    log_time_ms,
+                )?;
+                Ok(Change::new(prev, result).into())
+            }
+            LeaseCmd::Attach { key, value, .. } => {
+                let mut info = match curr {
+                    Some(info) => info,
+                    None => {
+                        let curr = txn_tree.key_space::<GenericKV>().get(key)?;
+                        let (_expired, curr) = Self::expire_seq_v(curr, log_time_ms);
+                        return Ok(Change::new(curr.clone(), curr).into());
+                    }
+                };
+
+                let meta = info.kv_meta(log_time_ms);
+                let (prev, result) = Self::txn_upsert_kv_with_change(
+                    txn_tree,
+                    &UpsertKV::update(key, value).with(meta),
+                    log_time_ms,
+                )?;
+
+                info.keys.insert(key.clone());
+                Self::txn_renew_lease(txn_tree, &lease_key, info, log_time_ms)?;
+
+                Ok(Change::new(prev, result).into())
+            }
+        }
+    }
+
+    /// Reset the expiration time of a lease and all of its attached keys.
+    fn txn_renew_lease(
+        txn_tree: &mut TransactionSledTree,
+        lease_key: &str,
+        mut info: LeaseInfo,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        let meta = info.kv_meta(log_time_ms);
+
+        let mut alive = BTreeSet::new();
+        for key in std::mem::take(&mut info.keys) {
+            let upsert = UpsertKV::new(&key, MatchSeq::GE(1), Operation::AsIs, Some(meta.clone()));
+            let (_prev, result) = Self::txn_upsert_kv_with_change(txn_tree, &upsert, log_time_ms)?;
+            if result.is_some() {
+                alive.insert(key);
+            }
+        }
+        info.keys = alive;
+
+        let (prev, result) = Self::txn_upsert_kv_with_change(
+            txn_tree,
+            &UpsertKV::update(lease_key, &info.encode()).with(meta),
+            log_time_ms,
+        )?;
+        Ok(Change::new(prev, result).into())
+    }
+
+    /// Upsert a kv record and push the change to subscriber.
+    fn txn_upsert_kv_with_change(
+        txn_tree: &mut TransactionSledTree,
+        upsert_kv: &UpsertKV,
+        log_time_ms: u64,
+    ) -> Result<(Option<SeqV>, Option<SeqV>), MetaStorageError> {
+        let (expired, prev, result) = Self::txn_upsert_kv(txn_tree, upsert_kv, log_time_ms)?;
+
+        if expired.is_some() {
+            txn_tree.push_change(&upsert_kv.key, expired, None);
+        }
+        txn_tree.push_change(&upsert_kv.key, prev.clone(), result.clone());
+
+        Ok((prev, result))
+    }
+
     fn return_value_condition_result(
         &self,
         expected: i32,
@@ -800,6 +915,8 @@ impl StateMachine {
             }
 
             Cmd::Transaction(txn) => self.apply_txn_cmd(txn, txn_tree, kv_pairs, log_time_ms),
+
+            Cmd::Lease(lease_cmd) => self.apply_lease_cmd(lease_cmd, txn_tree, log_time_ms),
         };
 
         let elapsed = now.elapsed().as_micros();
@@ -15,8 +15,11 @@
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_stoerr::MetaStorageError;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::MetaError;
@@ -119,4 +122,26 @@ impl kvapi::KVApi for StateMachine {
 
         Ok(x.collect())
     }
+
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, Self::Error> {
+        let kvs = self.kvs();
+        let it = kvs.range(req.bounds())?;
+
+        let local_now_ms = SeqV::<()>::now_ms();
+
+        let mut res = vec![];
+        for item in it {
+            if req.is_full(res.len()) {
+                break;
+            }
+
+            let (k, v) = item?.kv().map_err(MetaStorageError::from)?;
+            let (_expired, v) = Self::expire_seq_v(Some(v), local_now_ms);
+            if let Some(v) = v {
+                res.push((k, v));
+            }
+        }
+
+        Ok(RangeKVReply::new(&req, res))
+    }
 }
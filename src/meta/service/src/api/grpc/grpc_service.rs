@@ -23,6 +23,7 @@ use common_grpc::GrpcClaim;
 use common_grpc::GrpcToken;
 use common_meta_client::MetaGrpcReq;
 use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_types::protobuf::meta_service_server::MetaService;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::Empty;
@@ -33,6 +34,7 @@ use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::protobuf::RangeItem;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::TxnReply;
@@ -190,6 +192,10 @@ impl MetaService for MetaServiceImpl {
                     let res = m.prefix_list_kv(&a.prefix).await;
                     RaftReply::from(res)
                 }
+                MetaGrpcReq::Lease(a) => {
+                    let res = m.lease(a.clone()).await;
+                    RaftReply::from(res)
+                }
             };
 
             let elapsed = t0.elapsed();
@@ -244,6 +250,47 @@ impl MetaService for MetaServiceImpl {
         .await
     }
 
+    type KvRangeStream = Pin<Box<dyn Stream<Item = Result<RangeItem, Status>> + Send + 'static>>;
+
+    /// Scan one page of a key range and stream the records back in key order.
+    async fn kv_range(
+        &self,
+        r: Request<RaftRequest>,
+    ) -> Result<Response<Self::KvRangeStream>, Status> {
+        let root = common_tracing::start_trace_for_remote_request(func_name!(), &r);
+
+        let items = async {
+            self.check_token(r.metadata())?;
+            network_metrics::incr_recv_bytes(r.get_ref().encoded_len() as u64);
+            let _guard = RequestInFlight::guard();
+
+            let req: RangeKVReq = serde_json::from_str(&r.get_ref().data)
+                .map_err(|e| Status::invalid_argument(e.to_string()))?;
+            debug!("{}: Received RangeKVReq: {:?}", func_name!(), req);
+
+            let res = self.meta_node.range_kv(req).await;
+            network_metrics::incr_request_result(res.is_ok());
+
+            let reply = res.map_err(|e| Status::internal(e.to_string()))?;
+
+            let items = reply
+                .kvs
+                .into_iter()
+                .map(|(k, v)| RangeItem::new(k, v))
+                .collect::<Vec<_>>();
+
+            let sent = items.iter().map(|x| x.encoded_len() as u64).sum::<u64>();
+            network_metrics::incr_sent_bytes(sent);
+
+            Ok::<_, Status>(items)
+        }
+        .in_span(root)
+        .await?;
+
+        let strm = tokio_stream::iter(items.into_iter().map(Ok));
+        Ok(Response::new(Box::pin(strm)))
+    }
+
     type ExportStream = Pin<Box<dyn Stream<Item = Result<ExportedChunk, Status>> + Send + 'static>>;
 
     /// Export all meta data.
@@ -14,10 +14,14 @@
 
 use std::sync::Arc;
 
+use common_meta_types::NodeId;
 use poem::http::StatusCode;
 use poem::web::Data;
 use poem::web::IntoResponse;
 use poem::web::Json;
+use poem::web::Query;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::meta_service::MetaNode;
 
@@ -42,3 +46,81 @@ pub async fn status_handler(meta_node: Data<&Arc<MetaNode>>) -> poem::Result<imp
 
     Ok(Json(status))
 }
+
+#[derive(Debug, Deserialize)]
+pub struct TransferLeaderQuery {
+    /// The node to transfer the leadership to. The most up-to-date voter is chosen if absent.
+    pub to: Option<NodeId>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransferLeaderResponse {
+    pub from: NodeId,
+    pub to: NodeId,
+}
+
+/// Transfer the leadership to another voter, e.g., before restarting the leader.
+///
+/// request: `?to=<node_id>`, optional
+/// return: the previous and the new leader
+#[poem::handler]
+pub async fn transfer_leader_handler(
+    meta_node: Data<&Arc<MetaNode>>,
+    query: Query<TransferLeaderQuery>,
+) -> poem::Result<impl IntoResponse> {
+    let from = meta_node
+        .get_leader()
+        .await
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+
+    let to = meta_node.transfer_leader(query.to).await.map_err(|e| {
+        poem::Error::from_string(
+            format!("failed to transfer leader: {}", e),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )
+    })?;
+
+    Ok(Json(TransferLeaderResponse { from, to }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DrainQuery {
+    /// Enter the drain mode if true, leave it if false. Default to true.
+    pub on: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DrainResponse {
+    pub draining: bool,
+
+    /// The new leader if this node was the leader and has transferred the leadership.
+    pub new_leader: Option<NodeId>,
+}
+
+/// Enter or leave the drain mode, in which this node does not stand for election.
+///
+/// A draining leader transfers its leadership to another voter.
+///
+/// request: `?on=true|false`, optional
+/// return: the drain mode of this node and the new leader if the leadership is transferred
+#[poem::handler]
+pub async fn drain_handler(
+    meta_node: Data<&Arc<MetaNode>>,
+    query: Query<DrainQuery>,
+) -> poem::Result<impl IntoResponse> {
+    let draining = query.on.unwrap_or(true);
+
+    let new_leader = meta_node.set_draining(draining).await.map_err(|e| {
+        poem::Error::from_string(
+            format!("failed to set draining: {}", e),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )
+    })?;
+
+    Ok(Json(DrainResponse {
+        draining,
+        new_leader,
+    }))
+}
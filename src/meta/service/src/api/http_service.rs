@@ -78,6 +78,14 @@ impl HttpService {
                 "/v1/cluster/status",
                 get(super::http::v1::cluster_state::status_handler),
             )
+            .at(
+                "/v1/cluster/transfer_leader",
+                get(super::http::v1::cluster_state::transfer_leader_handler),
+            )
+            .at(
+                "/v1/cluster/drain",
+                get(super::http::v1::cluster_state::drain_handler),
+            )
             .at(
                 "/v1/metrics",
                 get(super::http::v1::metrics::metrics_handler),
@@ -0,0 +1,361 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Online backup of meta-service data to an object storage.
+//!
+//! The leader uploads two kinds of files:
+//!
+//! - `snapshots/<last_applied>.json`: the latest raft snapshot of the state machine, with the header and raft state,
+//!   in the same format as `databend-metactl --export` but without raft logs.
+//!   `last_applied` is the last log index included in the snapshot.
+//!
+//! - `logs/<first>-<last>.json`: the raft logs applied since the previous upload,
+//!   one exported `raft_log` line per log entry.
+//!
+//! A node can be restored to any log index after the first snapshot backup,
+//! by importing the latest snapshot before it and the logs up to it. See `databend-metactl restore`.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyerror::AnyError;
+use common_base::base::tokio;
+use common_base::base::tokio::sync::watch;
+use common_base::base::tokio::time::Instant;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
+use common_meta_types::MetaStartupError;
+use common_storage::init_operator;
+use futures::TryStreamExt;
+use log::error;
+use log::info;
+use log::warn;
+use minitrace::prelude::*;
+use opendal::EntryMode;
+use opendal::Metakey;
+use opendal::Operator;
+
+use crate::configs::BackupConfig;
+use crate::meta_service::MetaNode;
+
+pub const SNAPSHOT_DIR: &str = "snapshots/";
+pub const LOG_DIR: &str = "logs/";
+
+/// Size of the chunks in which a snapshot backup is uploaded.
+const UPLOAD_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Max time to wait for a snapshot to be built before uploading it.
+const SNAPSHOT_BUILD_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Path of a snapshot backup that includes logs up to `last_applied`.
+pub fn snapshot_path(last_applied: u64) -> String {
+    // Zero padded so that the files are listed in log order.
+    format!("{}{:020}.json", SNAPSHOT_DIR, last_applied)
+}
+
+/// Path of a backup of logs in range `[first, last]`.
+pub fn log_path(first: u64, last: u64) -> String {
+    format!("{}{:020}-{:020}.json", LOG_DIR, first, last)
+}
+
+/// Parse the `last_applied` from a snapshot backup path.
+pub fn parse_snapshot_path(path: &str) -> Option<u64> {
+    let name = path.rsplit('/').next()?;
+    name.strip_suffix(".json")?.parse().ok()
+}
+
+/// Parse the log range `[first, last]` from a log backup path.
+pub fn parse_log_path(path: &str) -> Option<(u64, u64)> {
+    let name = path.rsplit('/').next()?;
+    let (first, last) = name.strip_suffix(".json")?.split_once('-')?;
+    Some((first.parse().ok()?, last.parse().ok()?))
+}
+
+/// List the files in a backup dir, parsed with `parse`, sorted by the parsed key.
+pub async fn list_backups<T: Ord>(
+    op: &Operator,
+    dir: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<(T, String)>, opendal::Error> {
+    let mut res = vec![];
+
+    let mut lister = op.lister_with(dir).metakey(Metakey::Mode).await?;
+    while let Some(ent) = lister.try_next().await? {
+        if ent.metadata().mode() != EntryMode::FILE {
+            continue;
+        }
+        match parse(ent.path()) {
+            Some(key) => res.push((key, ent.path().to_string())),
+            None => warn!("ignore unknown file in meta backup: {}", ent.path()),
+        }
+    }
+
+    res.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(res)
+}
+
+/// Periodically uploads snapshots and raft logs of a meta node, if it is the leader.
+pub struct BackupWorker {
+    meta_node: Arc<MetaNode>,
+    op: Operator,
+    snapshot_interval: Duration,
+    log_ship_interval: Duration,
+
+    /// The last log index that has been uploaded.
+    ///
+    /// It is `None` if unknown, e.g., when this node just becomes the leader,
+    /// and will be loaded from the backup storage.
+    shipped: Option<u64>,
+}
+
+impl BackupWorker {
+    /// Spawn a backup worker if backup is enabled in config.
+    pub async fn spawn(
+        meta_node: Arc<MetaNode>,
+        config: &BackupConfig,
+    ) -> Result<(), MetaStartupError> {
+        let params = match config.storage_params()? {
+            None => return Ok(()),
+            Some(x) => x,
+        };
+
+        let op = init_operator(&params).map_err(|e| {
+            MetaStartupError::InvalidConfig(format!("fail to init backup storage: {}", e))
+        })?;
+
+        info!("start meta backup to {:?}", params);
+
+        let worker = BackupWorker {
+            meta_node: meta_node.clone(),
+            op,
+            snapshot_interval: Duration::from_secs(config.snapshot_interval),
+            log_ship_interval: Duration::from_secs(config.log_ship_interval),
+            shipped: None,
+        };
+
+        let running_rx = meta_node.running_rx.clone();
+        let fut = worker.run(running_rx);
+        let h = tokio::spawn(fut.in_span(Span::enter_with_local_parent("meta-backup")));
+
+        meta_node.join_handles.lock().await.push(h);
+        Ok(())
+    }
+
+    async fn run(mut self, mut running_rx: watch::Receiver<()>) -> Result<(), AnyError> {
+        // Upload a snapshot as soon as this node becomes the leader.
+        let mut next_snapshot = Instant::now();
+
+        loop {
+            tokio::select! {
+                _ = running_rx.changed() => {
+                    info!("meta backup worker quit");
+                    return Ok(());
+                }
+                _ = tokio::time::sleep(self.log_ship_interval) => {}
+            }
+
+            if !self.is_leader() {
+                // Another node is in charge of the backup; reload the progress when becoming the leader.
+                self.shipped = None;
+                next_snapshot = Instant::now();
+                continue;
+            }
+
+            if Instant::now() >= next_snapshot {
+                match self.backup_snapshot().await {
+                    Ok(()) => next_snapshot = Instant::now() + self.snapshot_interval,
+                    Err(e) => error!("fail to backup meta snapshot: {}", e),
+                }
+            }
+
+            match self.ship_logs().await {
+                Ok(true) => {}
+                Ok(false) => {
+                    // Some logs are purged before being uploaded: take a full backup instead.
+                    next_snapshot = Instant::now();
+                }
+                Err(e) => error!("fail to backup meta logs: {}", e),
+            }
+        }
+    }
+
+    fn is_leader(&self) -> bool {
+        let metrics = self.meta_node.raft.metrics();
+        let leader = metrics.borrow().current_leader;
+        leader == Some(self.meta_node.sto.id)
+    }
+
+    /// Build a raft snapshot and upload it, along with the header and raft state of this node.
+    ///
+    /// The snapshot is streamed to the backup storage in chunks of `UPLOAD_CHUNK_SIZE`,
+    /// and the logs included in it need not to be shipped.
+    async fn backup_snapshot(&mut self) -> Result<(), AnyError> {
+        let want = self.last_applied();
+
+        self.meta_node
+            .raft
+            .trigger()
+            .snapshot()
+            .await
+            .map_err(|e| AnyError::new(&e))?;
+
+        self.meta_node
+            .raft
+            .wait(Some(SNAPSHOT_BUILD_TIMEOUT))
+            .metrics(
+                |m| m.snapshot.map(|x| x.index).unwrap_or_default() >= want,
+                "backup snapshot built",
+            )
+            .await
+            .map_err(|e| AnyError::new(&e))?;
+
+        let snapshot_meta = {
+            let current = self.meta_node.sto.current_snapshot.read().await;
+            current.as_ref().map(|s| s.meta.clone()).ok_or_else(|| {
+                AnyError::error("no snapshot is built for meta backup".to_string())
+            })?
+        };
+
+        let last_applied = snapshot_meta
+            .last_log_id
+            .map(|x| x.index)
+            .unwrap_or_default();
+        let path = snapshot_path(last_applied);
+
+        if self
+            .op
+            .is_exist(&path)
+            .await
+            .map_err(|e| AnyError::new(&e))?
+        {
+            info!("meta snapshot backup already exists: {}", path);
+        } else {
+            let mut lines = self
+                .meta_node
+                .sto
+                .inner()
+                .export_snapshot(snapshot_meta.snapshot_id.clone());
+
+            let mut writer = self.op.writer(&path).await.map_err(|e| AnyError::new(&e))?;
+            let mut buf = Vec::with_capacity(UPLOAD_CHUNK_SIZE);
+
+            while let Some(line) = lines.try_next().await.map_err(|e| AnyError::new(&e))? {
+                buf.extend_from_slice(line.as_bytes());
+                buf.push(b'\n');
+
+                if buf.len() >= UPLOAD_CHUNK_SIZE {
+                    let chunk = std::mem::replace(&mut buf, Vec::with_capacity(UPLOAD_CHUNK_SIZE));
+                    writer.write(chunk).await.map_err(|e| AnyError::new(&e))?;
+                }
+            }
+
+            if !buf.is_empty() {
+                writer.write(buf).await.map_err(|e| AnyError::new(&e))?;
+            }
+            writer.close().await.map_err(|e| AnyError::new(&e))?;
+
+            info!("meta snapshot backup uploaded: {}", path);
+        }
+
+        // Logs included in the snapshot do not need to be shipped.
+        self.shipped = Some(match self.shipped {
+            Some(x) => std::cmp::max(x, last_applied),
+            None => last_applied,
+        });
+
+        Ok(())
+    }
+
+    fn last_applied(&self) -> u64 {
+        let metrics = self.meta_node.raft.metrics();
+        let applied = metrics.borrow().last_applied;
+        applied.map(|x| x.index).unwrap_or_default()
+    }
+
+    /// Upload the logs that are applied but not yet uploaded.
+    ///
+    /// It returns `false` if some logs to upload have been purged.
+    async fn ship_logs(&mut self) -> Result<bool, AnyError> {
+        let shipped = match self.shipped {
+            Some(x) => x,
+            None => {
+                let logs = list_backups(&self.op, LOG_DIR, parse_log_path)
+                    .await
+                    .map_err(|e| AnyError::new(&e))?;
+                let snapshots = list_backups(&self.op, SNAPSHOT_DIR, parse_snapshot_path)
+                    .await
+                    .map_err(|e| AnyError::new(&e))?;
+
+                let last_log = logs.last().map(|((_, last), _)| *last).unwrap_or_default();
+                let last_snapshot = snapshots.last().map(|(x, _)| *x).unwrap_or_default();
+                std::cmp::max(last_log, last_snapshot)
+            }
+        };
+
+        let last_applied = self.last_applied();
+
+        if last_applied <= shipped {
+            self.shipped = Some(shipped);
+            return Ok(true);
+        }
+
+        let (tree_name, entries) = {
+            let log = self.meta_node.sto.log.read().await;
+            let entries = log
+                .range_values(shipped + 1..=last_applied)
+                .map_err(|e| AnyError::new(&e))?;
+            (log.inner.name.clone(), entries)
+        };
+
+        let first = match entries.first() {
+            Some(x) => x.log_id.index,
+            None => {
+                warn!(
+                    "logs since {} are purged before being backed up",
+                    shipped + 1
+                );
+                return Ok(false);
+            }
+        };
+
+        if first != shipped + 1 {
+            warn!(
+                "logs [{}, {}) are purged before being backed up",
+                shipped + 1,
+                first
+            );
+            return Ok(false);
+        }
+
+        let mut buf = Vec::new();
+        for entry in entries {
+            let ent = RaftStoreEntry::Logs {
+                key: entry.log_id.index,
+                value: entry,
+            };
+            let line = serde_json::to_string(&(&tree_name, ent)).map_err(|e| AnyError::new(&e))?;
+            buf.extend_from_slice(line.as_bytes());
+            buf.push(b'\n');
+        }
+
+        let path = log_path(first, last_applied);
+        self.op
+            .write(&path, buf)
+            .await
+            .map_err(|e| AnyError::new(&e))?;
+
+        info!("meta logs backup uploaded: {}", path);
+        self.shipped = Some(last_applied);
+        Ok(true)
+    }
+}
@@ -14,6 +14,9 @@
 
 use std::net::SocketAddr;
 
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
+use common_meta_app::storage::StorageS3Config;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_types::MetaStartupError;
 use common_meta_types::Node;
@@ -41,6 +44,7 @@ pub struct Config {
     pub grpc_tls_server_cert: String,
     pub grpc_tls_server_key: String,
     pub raft_config: RaftConfig,
+    pub backup: BackupConfig,
 }
 
 impl Default for Config {
@@ -63,6 +67,7 @@ impl Default for Config {
             grpc_tls_server_cert: "".to_string(),
             grpc_tls_server_key: "".to_string(),
             raft_config: Default::default(),
+            backup: Default::default(),
         }
     }
 }
@@ -84,6 +89,7 @@ impl Config {
                 e, self.grpc_api_address
             ))
         })?;
+        self.backup.storage_params()?;
         Ok(())
     }
 
@@ -133,3 +139,77 @@ impl Config {
         !self.grpc_tls_server_key.is_empty() && !self.grpc_tls_server_cert.is_empty()
     }
 }
+
+/// Config of the online backup of a meta node.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct BackupConfig {
+    /// `fs` or `s3`; an empty string disables backup.
+    pub storage_type: String,
+    pub fs_root: String,
+    pub s3_endpoint_url: String,
+    pub s3_region: String,
+    pub s3_bucket: String,
+    pub s3_root: String,
+    pub s3_access_key_id: String,
+    pub s3_secret_access_key: String,
+    /// Interval in seconds to upload a full backup.
+    pub snapshot_interval: u64,
+    /// Interval in seconds to upload newly applied raft logs.
+    pub log_ship_interval: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            storage_type: "".to_string(),
+            fs_root: "./.databend/meta_backup".to_string(),
+            s3_endpoint_url: "https://s3.amazonaws.com".to_string(),
+            s3_region: "".to_string(),
+            s3_bucket: "".to_string(),
+            s3_root: "".to_string(),
+            s3_access_key_id: "".to_string(),
+            s3_secret_access_key: "".to_string(),
+            snapshot_interval: 3600,
+            log_ship_interval: 60,
+        }
+    }
+}
+
+impl BackupConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.storage_type.is_empty()
+    }
+
+    /// Build the params to access the backup storage, or `None` if backup is disabled.
+    pub fn storage_params(&self) -> Result<Option<StorageParams>, MetaStartupError> {
+        let params = match self.storage_type.as_str() {
+            "" => return Ok(None),
+            "fs" => StorageParams::Fs(StorageFsConfig {
+                root: self.fs_root.clone(),
+            }),
+            "s3" => StorageParams::S3(StorageS3Config {
+                endpoint_url: self.s3_endpoint_url.clone(),
+                region: self.s3_region.clone(),
+                bucket: self.s3_bucket.clone(),
+                root: self.s3_root.clone(),
+                access_key_id: self.s3_access_key_id.clone(),
+                secret_access_key: self.s3_secret_access_key.clone(),
+                ..Default::default()
+            }),
+            x => {
+                return Err(MetaStartupError::InvalidConfig(format!(
+                    "unsupported backup storage type: {}, expect fs or s3",
+                    x
+                )));
+            }
+        };
+
+        if self.snapshot_interval == 0 || self.log_ship_interval == 0 {
+            return Err(MetaStartupError::InvalidConfig(
+                "backup snapshot_interval and log_ship_interval must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Some(params))
+    }
+}
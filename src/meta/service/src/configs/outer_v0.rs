@@ -20,6 +20,7 @@ use clap::Parser;
 use common_meta_raft_store::config::get_default_raft_advertise_host;
 use common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use common_meta_types::MetaStartupError;
+use common_tracing::AuditLogConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::QueryLogConfig;
@@ -32,6 +33,7 @@ use serfig::collectors::from_file;
 use serfig::collectors::from_self;
 use serfig::parsers::Toml;
 
+use super::inner::BackupConfig as InnerBackupConfig;
 use super::inner::Config as InnerConfig;
 use crate::version::METASRV_COMMIT_VERSION;
 
@@ -127,6 +129,9 @@ pub struct Config {
 
     #[clap(flatten)]
     pub raft_config: RaftConfig,
+
+    #[clap(flatten)]
+    pub backup: BackupConfig,
 }
 
 impl Default for Config {
@@ -163,6 +168,7 @@ impl From<Config> for InnerConfig {
             grpc_tls_server_cert: outer.grpc_tls_server_cert,
             grpc_tls_server_key: outer.grpc_tls_server_key,
             raft_config: outer.raft_config.into(),
+            backup: outer.backup.into(),
         }
     }
 }
@@ -189,6 +195,7 @@ impl From<InnerConfig> for Config {
             grpc_tls_server_cert: inner.grpc_tls_server_cert,
             grpc_tls_server_key: inner.grpc_tls_server_key,
             raft_config: inner.raft_config.into(),
+            backup: inner.backup.into(),
         }
     }
 }
@@ -291,6 +298,17 @@ pub struct ConfigViaEnv {
     pub kvsrv_id: u64,
     pub sled_tree_prefix: String,
     pub cluster_name: String,
+
+    pub metasrv_backup_storage_type: String,
+    pub metasrv_backup_fs_root: String,
+    pub metasrv_backup_s3_endpoint_url: String,
+    pub metasrv_backup_s3_region: String,
+    pub metasrv_backup_s3_bucket: String,
+    pub metasrv_backup_s3_root: String,
+    pub metasrv_backup_s3_access_key_id: String,
+    pub metasrv_backup_s3_secret_access_key: String,
+    pub metasrv_backup_snapshot_interval: u64,
+    pub metasrv_backup_log_ship_interval: u64,
 }
 
 impl Default for ConfigViaEnv {
@@ -335,6 +353,16 @@ impl From<Config> for ConfigViaEnv {
             kvsrv_id: cfg.raft_config.id,
             sled_tree_prefix: cfg.raft_config.sled_tree_prefix,
             cluster_name: cfg.raft_config.cluster_name,
+            metasrv_backup_storage_type: cfg.backup.backup_storage_type,
+            metasrv_backup_fs_root: cfg.backup.backup_fs_root,
+            metasrv_backup_s3_endpoint_url: cfg.backup.backup_s3_endpoint_url,
+            metasrv_backup_s3_region: cfg.backup.backup_s3_region,
+            metasrv_backup_s3_bucket: cfg.backup.backup_s3_bucket,
+            metasrv_backup_s3_root: cfg.backup.backup_s3_root,
+            metasrv_backup_s3_access_key_id: cfg.backup.backup_s3_access_key_id,
+            metasrv_backup_s3_secret_access_key: cfg.backup.backup_s3_secret_access_key,
+            metasrv_backup_snapshot_interval: cfg.backup.backup_snapshot_interval,
+            metasrv_backup_log_ship_interval: cfg.backup.backup_log_ship_interval,
         }
     }
 }
@@ -365,6 +393,18 @@ impl Into<Config> for ConfigViaEnv {
             sled_tree_prefix: self.sled_tree_prefix,
             cluster_name: self.cluster_name,
         };
+        let backup = BackupConfig {
+            backup_storage_type: self.metasrv_backup_storage_type,
+            backup_fs_root: self.metasrv_backup_fs_root,
+            backup_s3_endpoint_url: self.metasrv_backup_s3_endpoint_url,
+            backup_s3_region: self.metasrv_backup_s3_region,
+            backup_s3_bucket: self.metasrv_backup_s3_bucket,
+            backup_s3_root: self.metasrv_backup_s3_root,
+            backup_s3_access_key_id: self.metasrv_backup_s3_access_key_id,
+            backup_s3_secret_access_key: self.metasrv_backup_s3_secret_access_key,
+            backup_snapshot_interval: self.metasrv_backup_snapshot_interval,
+            backup_log_ship_interval: self.metasrv_backup_log_ship_interval,
+        };
         let log_config = LogConfig {
             file: FileLogConfig {
                 file_on: self.metasrv_log_file_on,
@@ -400,6 +440,7 @@ impl Into<Config> for ConfigViaEnv {
             grpc_tls_server_cert: self.grpc_tls_server_cert,
             grpc_tls_server_key: self.grpc_tls_server_key,
             raft_config,
+            backup,
         }
     }
 }
@@ -558,6 +599,98 @@ impl From<InnerRaftConfig> for RaftConfig {
     }
 }
 
+/// Where and how often a meta node backs up its data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackupConfig {
+    /// The storage to back up to: `fs` or `s3`. Backup is disabled if it is empty.
+    #[clap(long = "backup-storage-type", default_value = "")]
+    #[serde(rename = "storage_type")]
+    pub backup_storage_type: String,
+
+    /// The dir to store backups in, when `storage_type` is `fs`.
+    #[clap(long = "backup-fs-root", default_value = "./.databend/meta_backup")]
+    #[serde(rename = "fs_root")]
+    pub backup_fs_root: String,
+
+    #[clap(
+        long = "backup-s3-endpoint-url",
+        default_value = "https://s3.amazonaws.com"
+    )]
+    #[serde(rename = "s3_endpoint_url")]
+    pub backup_s3_endpoint_url: String,
+
+    #[clap(long = "backup-s3-region", default_value = "")]
+    #[serde(rename = "s3_region")]
+    pub backup_s3_region: String,
+
+    #[clap(long = "backup-s3-bucket", default_value = "")]
+    #[serde(rename = "s3_bucket")]
+    pub backup_s3_bucket: String,
+
+    #[clap(long = "backup-s3-root", default_value = "")]
+    #[serde(rename = "s3_root")]
+    pub backup_s3_root: String,
+
+    #[clap(long = "backup-s3-access-key-id", default_value = "")]
+    #[serde(rename = "s3_access_key_id")]
+    pub backup_s3_access_key_id: String,
+
+    #[clap(long = "backup-s3-secret-access-key", default_value = "")]
+    #[serde(rename = "s3_secret_access_key")]
+    pub backup_s3_secret_access_key: String,
+
+    /// Interval in seconds to upload a full backup of the state machine.
+    #[clap(long = "backup-snapshot-interval", default_value = "3600")]
+    #[serde(rename = "snapshot_interval")]
+    pub backup_snapshot_interval: u64,
+
+    /// Interval in seconds to upload the raft logs applied since the last upload.
+    #[clap(long = "backup-log-ship-interval", default_value = "60")]
+    #[serde(rename = "log_ship_interval")]
+    pub backup_log_ship_interval: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        InnerBackupConfig::default().into()
+    }
+}
+
+impl From<BackupConfig> for InnerBackupConfig {
+    fn from(x: BackupConfig) -> InnerBackupConfig {
+        InnerBackupConfig {
+            storage_type: x.backup_storage_type,
+            fs_root: x.backup_fs_root,
+            s3_endpoint_url: x.backup_s3_endpoint_url,
+            s3_region: x.backup_s3_region,
+            s3_bucket: x.backup_s3_bucket,
+            s3_root: x.backup_s3_root,
+            s3_access_key_id: x.backup_s3_access_key_id,
+            s3_secret_access_key: x.backup_s3_secret_access_key,
+            snapshot_interval: x.backup_snapshot_interval,
+            log_ship_interval: x.backup_log_ship_interval,
+        }
+    }
+}
+
+impl From<InnerBackupConfig> for BackupConfig {
+    fn from(inner: InnerBackupConfig) -> Self {
+        Self {
+            backup_storage_type: inner.storage_type,
+            backup_fs_root: inner.fs_root,
+            backup_s3_endpoint_url: inner.s3_endpoint_url,
+            backup_s3_region: inner.s3_region,
+            backup_s3_bucket: inner.s3_bucket,
+            backup_s3_root: inner.s3_root,
+            backup_s3_access_key_id: inner.s3_access_key_id,
+            backup_s3_secret_access_key: inner.s3_secret_access_key,
+            backup_snapshot_interval: inner.snapshot_interval,
+            backup_log_ship_interval: inner.log_ship_interval,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct LogConfig {
@@ -584,6 +717,10 @@ impl Into<InnerLogConfig> for LogConfig {
                 on: false,
                 dir: "".to_string(),
             },
+            audit: AuditLogConfig {
+                on: false,
+                dir: "".to_string(),
+            },
             tracing: TracingConfig::from_env(),
         }
     }
@@ -18,12 +18,30 @@ use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::AppliedState;
 use common_meta_types::Endpoint;
 use common_meta_types::LogEntry;
 use common_meta_types::NodeId;
 
+/// The version of the forward protocol spoken by this node.
+///
+/// A node sends it back in the metadata of a forward reply, so that a peer learns which
+/// [`ForwardRequestBody`] variants it understands. A node that does not send it speaks version 0.
+///
+/// - 1: `RangeKV`.
+/// - 2: `Cmd::Lease` in the raft log.
+/// - 3: `TransferLeader` and `Elect`.
+pub const FORWARD_PROTOCOL_VERSION: u64 = 3;
+
+/// The oldest forward protocol version of a node that can apply a `Cmd::Lease` raft log entry.
+pub const LEASE_PROTOCOL_VERSION: u64 = 2;
+
+/// The key of the forward protocol version in the metadata of a forward reply.
+pub const FORWARD_PROTOCOL_VERSION_KEY: &str = "forward-protocol-version";
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, PartialEq, Eq)]
 pub struct JoinRequest {
     pub node_id: NodeId,
@@ -56,6 +74,15 @@ pub struct LeaveRequest {
     pub node_id: NodeId,
 }
 
+/// Transfer the leadership to another voter.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TransferLeaderRequest {
+    /// The node to transfer the leadership to.
+    ///
+    /// If it is `None`, the voter with the most replicated logs is chosen.
+    pub to: Option<NodeId>,
+}
+
 #[derive(
     serde::Serialize,
     serde::Deserialize,
@@ -71,12 +98,19 @@ pub enum ForwardRequestBody {
 
     Join(JoinRequest),
     Leave(LeaveRequest),
+    TransferLeader(TransferLeaderRequest),
+
+    /// Ask the receiving node to start an election at once, which is not forwarded to the leader.
+    ///
+    /// It is sent by the leader to the node it transfers the leadership to.
+    Elect,
 
     Write(LogEntry),
 
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+    RangeKV(RangeKVReq),
 }
 
 /// A request that is forwarded from one raft node to another
@@ -88,6 +122,17 @@ pub struct ForwardRequest {
     pub body: ForwardRequestBody,
 }
 
+impl ForwardRequestBody {
+    /// The oldest forward protocol version of a peer that understands this request.
+    pub fn min_protocol_version(&self) -> u64 {
+        match self {
+            ForwardRequestBody::RangeKV(_) => 1,
+            ForwardRequestBody::TransferLeader(_) | ForwardRequestBody::Elect => 3,
+            _ => 0,
+        }
+    }
+}
+
 impl ForwardRequest {
     pub fn decr_forward(&mut self) {
         self.forward_to_leader -= 1;
@@ -104,11 +149,15 @@ pub enum ForwardResponse {
 
     Join(()),
     Leave(()),
+    /// The node that becomes the new leader.
+    TransferLeader(NodeId),
+    Elect(()),
     AppliedState(AppliedState),
 
     GetKV(GetKVReply),
     MGetKV(MGetKVReply),
     ListKV(ListKVReply),
+    RangeKV(RangeKVReply),
 }
 
 impl tonic::IntoRequest<RaftRequest> for ForwardRequest {
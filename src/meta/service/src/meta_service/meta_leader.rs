@@ -13,8 +13,12 @@
 // limitations under the License.
 
 use std::collections::BTreeSet;
+use std::time::Duration;
 
+use anyerror::AnyError;
 use common_base::base::tokio::sync::RwLockReadGuard;
+use common_base::base::tokio::time::sleep;
+use common_base::base::tokio::time::Instant;
 use common_meta_kvapi::kvapi::KVApi;
 use common_meta_raft_store::sm_v002::SMV002;
 use common_meta_sled_store::openraft::ChangeMembers;
@@ -26,6 +30,7 @@ use common_meta_types::LogEntry;
 use common_meta_types::MembershipNode;
 use common_meta_types::MetaDataError;
 use common_meta_types::MetaDataReadError;
+use common_meta_types::MetaManagementError;
 use common_meta_types::MetaOperationError;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
@@ -43,6 +48,7 @@ use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::TransferLeaderRequest;
 use crate::meta_service::raftmeta::MetaRaft;
 use crate::meta_service::MetaNode;
 use crate::metrics::server_metrics;
@@ -56,13 +62,18 @@ use crate::store::RaftStore;
 pub struct MetaLeader<'a> {
     sto: &'a RaftStore,
     raft: &'a MetaRaft,
+    meta_node: &'a MetaNode,
 }
 
 impl<'a> MetaLeader<'a> {
+    /// How long to wait for the target of a leader transfer to catch up.
+    const TRANSFER_LEADER_CATCH_UP_TIMEOUT: Duration = Duration::from_secs(5);
+
     pub fn new(meta_node: &'a MetaNode) -> MetaLeader {
         MetaLeader {
             sto: &meta_node.sto,
             raft: &meta_node.raft,
+            meta_node,
         }
     }
 
@@ -84,6 +95,12 @@ impl<'a> MetaLeader<'a> {
                 self.leave(leave_req).await?;
                 Ok(ForwardResponse::Leave(()))
             }
+            ForwardRequestBody::TransferLeader(transfer_req) => {
+                let new_leader = self.transfer_leader(transfer_req).await?;
+                Ok(ForwardResponse::TransferLeader(new_leader))
+            }
+            // This node is already the leader.
+            ForwardRequestBody::Elect => Ok(ForwardResponse::Elect(())),
             ForwardRequestBody::Write(entry) => {
                 let res = self.write(entry.clone()).await?;
                 Ok(ForwardResponse::AppliedState(res))
@@ -104,6 +121,11 @@ impl<'a> MetaLeader<'a> {
                 let res = sm.kv_api().prefix_list_kv(&req.prefix).await.unwrap();
                 Ok(ForwardResponse::ListKV(res))
             }
+            ForwardRequestBody::RangeKV(req) => {
+                let sm = self.get_state_machine().await;
+                let res = sm.kv_api().range_kv(req).await.unwrap();
+                Ok(ForwardResponse::RangeKV(res))
+            }
         }
     }
 
@@ -184,6 +206,105 @@ impl<'a> MetaLeader<'a> {
         Ok(())
     }
 
+    /// Transfer the leadership to another voter and return the new leader.
+    ///
+    /// - Wait for the target to catch up with the logs of the leader.
+    /// - Stop sending heartbeats so that the leader lease on the followers expires,
+    ///   and do not stand for election.
+    /// - Ask the target to start an election at once.
+    ///
+    /// If the target fails to become the leader, this node resumes sending heartbeats and keeps the leadership.
+    #[minitrace::trace]
+    pub async fn transfer_leader(
+        &self,
+        req: TransferLeaderRequest,
+    ) -> Result<NodeId, MetaOperationError> {
+        let to = self.pick_transferee(req.to).await?;
+
+        info!("transfer leader from {} to {}", self.sto.id, to);
+
+        self.raft.enable_heartbeat(false);
+        self.raft.enable_elect(false);
+
+        let res = self
+            .meta_node
+            .forward_to(&to, ForwardRequest {
+                forward_to_leader: 0,
+                body: ForwardRequestBody::Elect,
+            })
+            .await;
+
+        self.raft.enable_heartbeat(true);
+        self.raft.enable_elect(!self.meta_node.is_draining());
+
+        if let Err(e) = res {
+            return Err(transfer_leader_error(format!(
+                "node {} failed to become the leader: {}",
+                to, e
+            )));
+        }
+
+        info!("leader transferred from {} to {}", self.sto.id, to);
+        Ok(to)
+    }
+
+    /// Choose the voter to transfer the leadership to and wait for it to catch up.
+    ///
+    /// If `to` is `None`, the voter with the most replicated logs is chosen.
+    async fn pick_transferee(&self, to: Option<NodeId>) -> Result<NodeId, MetaOperationError> {
+        let deadline = Instant::now() + Self::TRANSFER_LEADER_CATCH_UP_TIMEOUT;
+
+        loop {
+            let metrics = self.raft.metrics().borrow().clone();
+            let voters = metrics
+                .membership_config
+                .membership()
+                .voter_ids()
+                .collect::<BTreeSet<_>>();
+
+            if let Some(to) = to {
+                if to == self.sto.id {
+                    return Err(transfer_leader_error(format!(
+                        "node {} is already the leader",
+                        to
+                    )));
+                }
+                if !voters.contains(&to) {
+                    return Err(transfer_leader_error(format!("node {} is not a voter", to)));
+                }
+            }
+
+            let replication = metrics.replication.unwrap_or_default();
+            let matched = |id: &NodeId| replication.get(id).cloned().flatten().map(|x| x.index);
+
+            let candidate = voters
+                .iter()
+                .filter(|id| **id != self.sto.id)
+                .filter(|id| to.map_or(true, |to| to == **id))
+                .max_by_key(|id| matched(id))
+                .copied();
+
+            let candidate = candidate.ok_or_else(|| {
+                transfer_leader_error("no other voter to transfer the leadership to")
+            })?;
+
+            if matched(&candidate) == metrics.last_log_index {
+                return Ok(candidate);
+            }
+
+            if Instant::now() > deadline {
+                return Err(transfer_leader_error(format!(
+                    "node {} has not caught up: matched: {:?}, last log index: {:?}",
+                    candidate,
+                    matched(&candidate),
+                    metrics.last_log_index
+                )));
+            }
+
+            sleep(Duration::from_millis(100)).await;
+        }
+    }
+
     /// Write a log through local raft node and return the states before and after applying the log.
     ///
     /// If the raft node is not a leader, it returns MetaRaftError::ForwardToLeader.
@@ -243,3 +364,8 @@ impl<'a> MetaLeader<'a> {
         self.sto.state_machine.read().await
     }
 }
+
+fn transfer_leader_error(msg: impl ToString) -> MetaOperationError {
+    let e = MetaManagementError::TransferLeader(AnyError::error(msg.to_string()));
+    MetaDataError::from(e).into()
+}
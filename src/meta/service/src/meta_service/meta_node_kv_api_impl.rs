@@ -20,10 +20,13 @@ use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::ListKVReq;
 use common_meta_kvapi::kvapi::MGetKVReply;
 use common_meta_kvapi::kvapi::MGetKVReq;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
+use common_meta_types::LeaseCmd;
 use common_meta_types::LogEntry;
 use common_meta_types::MetaAPIError;
 use common_meta_types::TxnReply;
@@ -31,6 +34,7 @@ use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKV;
 use log::info;
 
+use crate::message::LEASE_PROTOCOL_VERSION;
 use crate::meta_service::MetaNode;
 
 /// Impl kvapi::KVApi for MetaNode.
@@ -92,6 +96,13 @@ impl kvapi::KVApi for MetaNode {
         Ok(res)
     }
 
+    #[minitrace::trace]
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, Self::Error> {
+        let res = self.consistent_read(req).await?;
+
+        Ok(res)
+    }
+
     #[minitrace::trace]
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         info!("MetaNode::transaction(): {}", txn);
@@ -106,3 +117,26 @@ impl kvapi::KVApi for MetaNode {
         }
     }
 }
+
+impl MetaNode {
+    /// Apply a lease operation through raft-log.
+    ///
+    /// It returns the change of the lease record, or the change of the attached key for `Attach`.
+    #[minitrace::trace]
+    pub async fn lease(&self, cmd: LeaseCmd) -> Result<UpsertKVReply, MetaAPIError> {
+        info!("MetaNode::lease(): {}", cmd);
+        // A node of an older version can not apply a lease log entry.
+        self.ensure_protocol_version(LEASE_PROTOCOL_VERSION, "lease")
+            .await?;
+
+        let ent = LogEntry::new(Cmd::Lease(cmd));
+        let rst = self.write(ent).await?;
+
+        match rst {
+            AppliedState::KV(x) => Ok(x),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::KV")
+            }
+        }
+    }
+}
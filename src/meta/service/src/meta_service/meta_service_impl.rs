@@ -27,6 +27,8 @@ use minitrace::prelude::*;
 use tonic::codegen::futures_core::Stream;
 
 use crate::message::ForwardRequest;
+use crate::message::FORWARD_PROTOCOL_VERSION;
+use crate::message::FORWARD_PROTOCOL_VERSION_KEY;
 use crate::meta_service::MetaNode;
 use crate::metrics::raft_metrics;
 
@@ -68,7 +70,12 @@ impl RaftService for RaftServiceImpl {
 
             let raft_mes: RaftReply = res.into();
 
-            Ok(tonic::Response::new(raft_mes))
+            let mut resp = tonic::Response::new(raft_mes);
+            resp.metadata_mut().insert(
+                FORWARD_PROTOCOL_VERSION_KEY,
+                FORWARD_PROTOCOL_VERSION.into(),
+            );
+            Ok(resp)
         }
         .in_span(root)
         .await
@@ -16,7 +16,9 @@ use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::net::Ipv4Addr;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicI32;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -31,6 +33,9 @@ use common_base::base::tokio::time::Instant;
 use common_grpc::ConnectionFactory;
 use common_grpc::DNSResolver;
 use common_meta_client::reply_to_api_result;
+use common_meta_kvapi::kvapi::ListKVReply;
+use common_meta_kvapi::kvapi::ListKVReq;
+use common_meta_kvapi::kvapi::RangeKVReply;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_raft_store::ondisk::DataVersion;
 use common_meta_raft_store::ondisk::DATA_VERSION;
@@ -54,6 +59,7 @@ use common_meta_types::LogEntry;
 use common_meta_types::LogId;
 use common_meta_types::MembershipNode;
 use common_meta_types::MetaAPIError;
+use common_meta_types::MetaDataError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaManagementError;
 use common_meta_types::MetaNetworkError;
@@ -79,12 +85,15 @@ use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
 
+use crate::backup::BackupWorker;
 use crate::configs::Config as MetaConfig;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::TransferLeaderRequest;
+use crate::message::FORWARD_PROTOCOL_VERSION_KEY;
 use crate::meta_service::errors::grpc_error_to_network_err;
 use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::RaftServiceImpl;
@@ -121,6 +130,9 @@ pub struct MetaNodeStatus {
     /// Is this node a leader.
     pub is_leader: bool,
 
+    /// Is this node in drain mode, in which it does not stand for election.
+    pub draining: bool,
+
     /// Current term.
     pub current_term: u64,
 
@@ -171,6 +183,14 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+
+    /// In drain mode, this node does not stand for election, e.g., before it is restarted for an upgrade.
+    ///
+    /// It is not persisted: a restarted node is not in drain mode.
+    pub draining: AtomicBool,
+
+    /// The forward protocol versions of the peers, learned from their forward replies.
+    pub peer_protocol_versions: std::sync::Mutex<BTreeMap<NodeId, u64>>,
 }
 
 impl Opened for MetaNode {
@@ -228,6 +248,8 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            draining: AtomicBool::new(false),
+            peer_protocol_versions: std::sync::Mutex::new(BTreeMap::new()),
         });
 
         if self.monitor_metrics {
@@ -540,6 +562,7 @@ impl MetaNode {
     pub async fn start(config: &MetaConfig) -> Result<Arc<MetaNode>, MetaStartupError> {
         info!(config = as_debug!(config); "start()");
         let mn = Self::do_start(config).await?;
+        BackupWorker::spawn(mn.clone(), &config.backup).await?;
         info!("Done starting MetaNode: {:?}", config);
         Ok(mn)
     }
@@ -916,6 +939,7 @@ impl MetaNode {
             db_size,
             state: format!("{:?}", metrics.state),
             is_leader: metrics.state == openraft::ServerState::Leader,
+            draining: self.is_draining(),
             current_term: metrics.current_term,
             last_log_index: metrics.last_log_index.unwrap_or(0),
             last_applied: metrics
@@ -1000,6 +1024,11 @@ impl MetaNode {
     ) -> Result<ForwardResponse, MetaAPIError> {
         debug!(target = as_display!(&req.forward_to_leader), req = as_debug!(&req); "handle_forwardable_request");
 
+        // An election request is always handled by the node receiving it.
+        if req.body == ForwardRequestBody::Elect {
+            return self.elect().await;
+        }
+
         let forward = req.forward_to_leader;
 
         let mut n_retry = 20;
@@ -1075,6 +1104,96 @@ impl MetaNode {
         }
     }
 
+    /// Transfer the leadership to another voter via the leader, and return the new leader.
+    ///
+    /// If `to` is `None`, the voter with the most replicated logs is chosen.
+    #[minitrace::trace]
+    pub async fn transfer_leader(&self, to: Option<NodeId>) -> Result<NodeId, MetaAPIError> {
+        let res = self
+            .handle_forwardable_request(ForwardRequest {
+                forward_to_leader: 1,
+                body: ForwardRequestBody::TransferLeader(TransferLeaderRequest { to }),
+            })
+            .await?;
+
+        let new_leader: NodeId = res.try_into().expect("expect NodeId");
+        Ok(new_leader)
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Relaxed)
+    }
+
+    /// Enter or leave the drain mode, in which this node does not stand for election.
+    ///
+    /// If this node is the leader when entering the drain mode, the leadership is transferred to another voter,
+    /// which is returned.
+    #[minitrace::trace]
+    pub async fn set_draining(&self, draining: bool) -> Result<Option<NodeId>, MetaAPIError> {
+        info!("node {} set draining: {}", self.sto.id, draining);
+
+        self.draining.store(draining, Ordering::Relaxed);
+        self.raft.enable_elect(!draining);
+
+        if !draining {
+            return Ok(None);
+        }
+
+        let leader = self.raft.metrics().borrow().current_leader;
+        if leader != Some(self.sto.id) {
+            return Ok(None);
+        }
+
+        let new_leader = self.transfer_leader(None).await?;
+        Ok(Some(new_leader))
+    }
+
+    /// Start an election at once and wait for this node to become the leader.
+    ///
+    /// The other voters reject the election until the lease of the current leader expires,
+    /// which lasts at most the max election timeout since its last heartbeat.
+    /// Thus it retries at a short interval until the lease must have expired,
+    /// instead of waiting for a whole election timeout of this node as a candidate.
+    #[minitrace::trace]
+    async fn elect(&self) -> Result<ForwardResponse, MetaAPIError> {
+        if self.is_draining() {
+            return Err(elect_error(format!("node {} is draining", self.sto.id)));
+        }
+
+        let heartbeat_interval = Duration::from_millis(self.sto.config.heartbeat_interval);
+        let (_, lease) = self.sto.config.election_timeout();
+        let deadline = Instant::now() + Duration::from_millis(lease) + heartbeat_interval;
+        let retry_interval = heartbeat_interval / 5;
+
+        loop {
+            self.raft
+                .trigger()
+                .elect()
+                .await
+                .map_err(MetaDataError::from)?;
+
+            let res = self
+                .raft
+                .wait(Some(retry_interval))
+                .state(ServerState::Leader, "elect")
+                .await;
+
+            if res.is_ok() {
+                info!("node {} becomes the leader by election", self.sto.id);
+                return Ok(ForwardResponse::Elect(()));
+            }
+
+            if Instant::now() >= deadline {
+                break;
+            }
+        }
+
+        Err(elect_error(format!(
+            "node {} did not become the leader before the leader lease expired",
+            self.sto.id
+        )))
+    }
+
     /// Return a MetaLeader if `self` believes it is the leader.
     ///
     /// Otherwise it returns the leader in a ForwardToLeader error.
@@ -1171,11 +1290,133 @@ impl MetaNode {
         }
     }
 
+    /// Forward a request to another node.
+    ///
+    /// If the request is not understood by the forward protocol version of the target node,
+    /// it is downgraded to the requests the target understands.
     #[minitrace::trace]
     pub async fn forward_to(
         &self,
         node_id: &NodeId,
         req: ForwardRequest,
+    ) -> Result<ForwardResponse, ForwardRPCError> {
+        let required = req.body.min_protocol_version();
+        if required > 0 && self.peer_protocol_version(node_id).await? < required {
+            return self.forward_to_old_peer(node_id, req).await;
+        }
+        self.send_forward(node_id, req).await
+    }
+
+    /// Get the forward protocol version of a peer.
+    ///
+    /// The version is learned from the forward replies of the peer,
+    /// a peer that has not replied yet is probed with a `Ping`.
+    async fn peer_protocol_version(&self, node_id: &NodeId) -> Result<u64, ForwardRPCError> {
+        let known = self
+            .peer_protocol_versions
+            .lock()
+            .unwrap()
+            .get(node_id)
+            .copied();
+        if let Some(v) = known {
+            return Ok(v);
+        }
+
+        let ping = ForwardRequest {
+            forward_to_leader: 0,
+            body: ForwardRequestBody::Ping,
+        };
+        match self.send_forward(node_id, ping).await {
+            // A remote error, such as not being the leader, still carries the version.
+            Ok(_) | Err(ForwardRPCError::RemoteError(_)) => {}
+            Err(e) => return Err(e),
+        }
+
+        let versions = self.peer_protocol_versions.lock().unwrap();
+        Ok(versions.get(node_id).copied().unwrap_or_default())
+    }
+
+    /// Ensure every other node in the cluster speaks at least the forward protocol `version`,
+    /// before proposing a raft log entry that a node of an older version can not apply.
+    pub async fn ensure_protocol_version(
+        &self,
+        version: u64,
+        action: &str,
+    ) -> Result<(), MetaAPIError> {
+        let node_ids = {
+            let metrics = self.raft.metrics().borrow().clone();
+            metrics
+                .membership_config
+                .membership()
+                .nodes()
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>()
+        };
+
+        for node_id in node_ids {
+            if node_id == self.sto.id {
+                continue;
+            }
+            if self.peer_protocol_version(&node_id).await? < version {
+                let e = MetaManagementError::Unsupported(AnyError::error(format!(
+                    "{} is not supported by node {}, which runs an older version",
+                    action, node_id
+                )));
+                return Err(MetaDataError::from(e).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Forward a request to a peer of an older forward protocol version,
+    /// by translating it to the requests the peer understands.
+    async fn forward_to_old_peer(
+        &self,
+        node_id: &NodeId,
+        req: ForwardRequest,
+    ) -> Result<ForwardResponse, ForwardRPCError> {
+        match req.body {
+            ForwardRequestBody::RangeKV(range_req) => {
+                let list_req = ForwardRequest {
+                    forward_to_leader: req.forward_to_leader,
+                    body: ForwardRequestBody::ListKV(ListKVReq {
+                        prefix: range_req.list_prefix(),
+                    }),
+                };
+                let resp = self.send_forward(node_id, list_req).await?;
+                let mut listed: ListKVReply = resp.try_into().map_err(|e| {
+                    let invalid_reply = InvalidReply::new(
+                        "expect reply type to be ListKVReply",
+                        &AnyError::error(e),
+                    );
+                    MetaNetworkError::from(invalid_reply)
+                })?;
+                listed.sort_by(|a, b| a.0.cmp(&b.0));
+                Ok(ForwardResponse::RangeKV(RangeKVReply::from_listed(
+                    &range_req, &listed,
+                )))
+            }
+            ForwardRequestBody::TransferLeader(_) | ForwardRequestBody::Elect => {
+                let e = MetaManagementError::Unsupported(AnyError::error(format!(
+                    "transferring the leadership is not supported by node {}, which runs an older version",
+                    node_id
+                )));
+                Err(ForwardRPCError::RemoteError(MetaDataError::from(e).into()))
+            }
+            body => {
+                self.send_forward(node_id, ForwardRequest {
+                    forward_to_leader: req.forward_to_leader,
+                    body,
+                })
+                .await
+            }
+        }
+    }
+
+    async fn send_forward(
+        &self,
+        node_id: &NodeId,
+        req: ForwardRequest,
     ) -> Result<ForwardResponse, ForwardRPCError> {
         debug!("forward_to: {} {:?}", node_id, req);
 
@@ -1199,9 +1440,23 @@ impl MetaNode {
             .max_encoding_message_size(GrpcConfig::MAX_ENCODING_SIZE);
 
         let resp = client.forward(req).await.map_err(|e| {
+            // The peer may be restarted with another version.
+            self.peer_protocol_versions.lock().unwrap().remove(node_id);
             MetaNetworkError::from(e)
                 .add_context(format!("target: {}, endpoint: {}", node_id, endpoint))
         })?;
+
+        let version = resp
+            .metadata()
+            .get(FORWARD_PROTOCOL_VERSION_KEY)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or_default();
+        self.peer_protocol_versions
+            .lock()
+            .unwrap()
+            .insert(*node_id, version);
+
         let raft_mes = resp.into_inner();
 
         let res: Result<ForwardResponse, MetaAPIError> = reply_to_api_result(raft_mes);
@@ -1228,3 +1483,8 @@ impl MetaNode {
         }
     }
 }
+
+fn elect_error(msg: impl ToString) -> MetaAPIError {
+    let e = MetaManagementError::TransferLeader(AnyError::error(msg.to_string()));
+    MetaDataError::from(e).into()
+}
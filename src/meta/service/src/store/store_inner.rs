@@ -57,6 +57,7 @@ use common_meta_types::SnapshotMeta;
 use common_meta_types::StorageError;
 use common_meta_types::StorageIOError;
 use common_meta_types::Vote;
+use futures::stream::BoxStream;
 use futures::Stream;
 use log::as_display;
 use log::debug;
@@ -384,8 +385,23 @@ impl StoreInner {
     /// Export data that can be used to restore a meta-service node.
     ///
     /// Returns a `BoxStream<'a, Result<String, io::Error>>` that yields a series of JSON strings.
+    pub fn export(self: Arc<StoreInner>) -> BoxStream<'static, Result<String, io::Error>> {
+        self.do_export(None)
+    }
+
+    /// Export the header, raft state and the state machine in snapshot `snapshot_id`, without raft logs.
+    ///
+    /// It returns an error if the current snapshot is no longer `snapshot_id`.
+    pub fn export_snapshot(
+        self: Arc<StoreInner>,
+        snapshot_id: String,
+    ) -> BoxStream<'static, Result<String, io::Error>> {
+        self.do_export(Some(snapshot_id))
+    }
+
+    /// Export all data, or only the snapshot `snapshot_id` if it is specified.
     #[futures_async_stream::try_stream(boxed, ok = String, error = io::Error)]
-    pub async fn export(self: Arc<StoreInner>) {
+    async fn do_export(self: Arc<StoreInner>, snapshot_id: Option<String>) {
         // Convert an error occurred during export to `io::Error(InvalidData)`.
         fn invalid_data(e: impl std::error::Error + Send + Sync + 'static) -> io::Error {
             io::Error::new(ErrorKind::InvalidData, e)
@@ -403,6 +419,19 @@ impl StoreInner {
         let raft_state = self.raft_state.read().await;
         let log = self.log.read().await;
 
+        if let Some(snapshot_id) = &snapshot_id {
+            let current_id = current_snapshot.as_ref().map(|s| &s.meta.snapshot_id);
+            if current_id != Some(snapshot_id) {
+                return Err(io::Error::new(
+                    ErrorKind::NotFound,
+                    format!(
+                        "snapshot {} is not the current snapshot: {:?}",
+                        snapshot_id, current_id
+                    ),
+                ));
+            }
+        }
+
         // Export data header first
         {
             let header_tree = SledTree::open(&self.db, TREE_HEADER, false).map_err(invalid_data)?;
@@ -459,7 +488,7 @@ impl StoreInner {
         };
 
         // Export logs that has smaller or equal leader id as `vote`
-        {
+        if snapshot_id.is_none() {
             let tree_name = &log.inner.name;
 
             let log_kvs = log.inner.export()?;
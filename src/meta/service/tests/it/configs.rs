@@ -65,6 +65,11 @@ join = ["j1", "j2"]
 id = 20
 sled_tree_prefix = "sled_foo"
 cluster_name = "foo_cluster"
+
+[backup]
+storage_type = "fs"
+fs_root = "backup dir"
+log_ship_interval = 10
              "#
     )?;
 
@@ -92,6 +97,10 @@ cluster_name = "foo_cluster"
         assert_eq!(cfg.raft_config.id, 20);
         assert_eq!(cfg.raft_config.sled_tree_prefix, "sled_foo");
         assert_eq!(cfg.raft_config.cluster_name, "foo_cluster");
+        assert_eq!(cfg.backup.storage_type, "fs");
+        assert_eq!(cfg.backup.fs_root, "backup dir");
+        assert_eq!(cfg.backup.snapshot_interval, 3600);
+        assert_eq!(cfg.backup.log_ship_interval, 10);
     });
 
     temp_env::with_vars(
@@ -123,5 +132,23 @@ cluster_name = "foo_cluster"
         },
     );
 
+    // Test backup config.
+    temp_env::with_vars(
+        vec![
+            (
+                "METASRV_CONFIG_FILE",
+                Some(file_path.to_str().expect("must be valid str")),
+            ),
+            ("METASRV_BACKUP_STORAGE_TYPE", Some("s3")),
+            ("METASRV_BACKUP_S3_BUCKET", Some("meta-backup")),
+        ],
+        || {
+            let cfg = Config::load_for_test().expect("load must success");
+            assert_eq!(cfg.backup.storage_type, "s3");
+            assert_eq!(cfg.backup.s3_bucket, "meta-backup");
+            assert!(cfg.backup.storage_params().unwrap().is_some());
+        },
+    );
+
     Ok(())
 }
@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::time::Duration;
+
+use common_base::base::tokio::time::sleep;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_meta::backup::parse_log_path;
+use databend_meta::backup::parse_snapshot_path;
+use databend_meta::backup::LOG_DIR;
+use databend_meta::backup::SNAPSHOT_DIR;
+use log::info;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::service::MetaSrvTestContext;
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_backup_snapshot_and_logs() -> anyhow::Result<()> {
+    // - Start a metasrv server with backup enabled.
+    // - Write some data.
+    // - A snapshot backup without raft logs is uploaded, and the logs after it are shipped.
+
+    let backup_dir = tempfile::tempdir()?;
+
+    let mut tc = MetaSrvTestContext::new(0);
+    tc.config.backup.storage_type = "fs".to_string();
+    tc.config.backup.fs_root = backup_dir.path().to_str().unwrap().to_string();
+    tc.config.backup.log_ship_interval = 1;
+    crate::tests::start_metasrv_with_context(&mut tc).await?;
+
+    let client = tc.grpc_client().await?;
+
+    let mn = tc
+        .grpc_srv
+        .as_ref()
+        .map(|grpc_server| grpc_server.get_meta_node())
+        .unwrap();
+
+    info!("--- wait for the first snapshot backup");
+    let snapshot_index = {
+        let mut found = None;
+        for _ in 0..20 {
+            found = list(backup_dir.path(), SNAPSHOT_DIR, parse_snapshot_path)
+                .into_iter()
+                .last();
+            if found.is_some() {
+                break;
+            }
+            sleep(Duration::from_millis(500)).await;
+        }
+        found.expect("snapshot backup is uploaded")
+    };
+
+    let snapshot_data = fs::read_to_string(
+        backup_dir
+            .path()
+            .join(SNAPSHOT_DIR)
+            .join(format!("{:020}.json", snapshot_index)),
+    )?;
+    assert!(snapshot_data.contains(r#""state_machine/0""#));
+    assert!(
+        !snapshot_data.contains(r#"{"Logs":"#),
+        "snapshot backup does not contain raft logs"
+    );
+
+    info!("--- upsert kv");
+    for k in ["foo", "bar", "wow"] {
+        client
+            .upsert_kv(UpsertKVReq::update(k, k.as_bytes()))
+            .await?;
+    }
+
+    let last_applied = mn.raft.metrics().borrow().last_applied.unwrap().index;
+
+    info!("--- wait for the logs to be shipped up to {}", last_applied);
+    let mut logs = vec![];
+    for _ in 0..20 {
+        logs = list(backup_dir.path(), LOG_DIR, parse_log_path);
+        if logs.last().map(|(_, last)| *last) >= Some(last_applied) {
+            break;
+        }
+        sleep(Duration::from_millis(500)).await;
+    }
+
+    // Logs included in the snapshot backup are not shipped again.
+    let (first, _) = logs
+        .iter()
+        .find(|(_, last)| *last > snapshot_index)
+        .copied()
+        .expect("logs after the snapshot are shipped");
+    assert_eq!(snapshot_index + 1, first);
+    assert_eq!(Some(last_applied), logs.last().map(|(_, last)| *last));
+
+    Ok(())
+}
+
+fn list<T: Ord>(root: &std::path::Path, dir: &str, parse: impl Fn(&str) -> Option<T>) -> Vec<T> {
+    let mut res = vec![];
+    if let Ok(entries) = fs::read_dir(root.join(dir)) {
+        for ent in entries {
+            let name = ent.unwrap().file_name().to_string_lossy().to_string();
+            res.extend(parse(&name));
+        }
+    }
+    res.sort();
+    res
+}
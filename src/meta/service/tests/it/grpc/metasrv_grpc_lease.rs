@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio::time::sleep;
+use common_base::base::tokio::time::timeout;
+use common_meta_client::Semaphore;
+use common_meta_kvapi::kvapi::KVApi;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_lease_keep_alive_and_revoke() -> anyhow::Result<()> {
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+    let client = tc.grpc_client().await?;
+
+    // A key can not be attached to a nonexistent lease.
+    let put = client.put_with_lease("l1", "k1", b"v1".to_vec()).await?;
+    assert!(put.is_none());
+    assert!(!client.keep_alive_lease("l1").await?);
+
+    client.grant_lease("l1", Duration::from_secs(3)).await?;
+    let put = client.put_with_lease("l1", "k1", b"v1".to_vec()).await?;
+    assert_eq!(Some(b"v1".to_vec()), put.map(|x| x.data));
+
+    // Kept alive, the key outlives the initial ttl.
+    for _ in 0..3 {
+        sleep(Duration::from_secs(1)).await;
+        assert!(client.keep_alive_lease("l1").await?);
+    }
+    assert!(client.get_kv("k1").await?.is_some());
+
+    client.revoke_lease("l1").await?;
+    assert!(client.get_kv("k1").await?.is_none());
+    assert!(!client.keep_alive_lease("l1").await?);
+
+    // Without keep-alive, the key expires with the lease.
+    client.grant_lease("l2", Duration::from_secs(1)).await?;
+    client.put_with_lease("l2", "k2", b"v2".to_vec()).await?;
+    sleep(Duration::from_secs(3)).await;
+    assert!(client.get_kv("k2").await?.is_none());
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_semaphore_fencing_token() -> anyhow::Result<()> {
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+    let client = tc.grpc_client().await?;
+
+    let lock = Semaphore::new_lock(client.clone(), "foo", Duration::from_secs(3));
+
+    let p1 = lock.acquire().await?;
+    assert!(lock.try_acquire().await?.is_none());
+
+    let fencing_1 = p1.fencing_token();
+    p1.release().await?;
+
+    let p2 = lock.try_acquire().await?.unwrap();
+    assert!(p2.fencing_token() > fencing_1);
+
+    // Two permits are available to a semaphore.
+    let sem = Semaphore::new(client.clone(), "bar", 2, Duration::from_secs(3));
+    let _p1 = sem.acquire().await?;
+    let _p2 = sem.acquire().await?;
+    assert!(sem.try_acquire().await?.is_none());
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_semaphore_keep_alive_holder() -> anyhow::Result<()> {
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+    let client = tc.grpc_client().await?;
+
+    let lock = Semaphore::new_lock(client.clone(), "foo", Duration::from_secs(3));
+    let p1 = lock.acquire().await?;
+
+    // The holder keeps alive its permit several times, the waiter is not granted.
+    let waiter = Semaphore::new_lock(client.clone(), "foo", Duration::from_secs(3));
+    let res = timeout(Duration::from_secs(7), waiter.acquire()).await;
+    assert!(res.is_err());
+
+    let fencing_1 = p1.fencing_token();
+    p1.release().await?;
+
+    let p2 = waiter.acquire().await?;
+    assert!(p2.fencing_token() > fencing_1);
+
+    Ok(())
+}
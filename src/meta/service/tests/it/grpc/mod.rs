@@ -14,11 +14,13 @@
 
 pub mod metasrv_connection_error;
 pub mod metasrv_grpc_api;
+mod metasrv_grpc_backup;
 mod metasrv_grpc_export;
 pub mod metasrv_grpc_get_client_info;
 pub mod metasrv_grpc_handshake;
 pub mod metasrv_grpc_kv_api;
 pub mod metasrv_grpc_kv_api_restart_cluster;
+pub mod metasrv_grpc_lease;
 pub mod metasrv_grpc_schema_api;
 pub mod metasrv_grpc_schema_api_follower_follower;
 pub mod metasrv_grpc_schema_api_leader_follower;
@@ -747,6 +747,105 @@ async fn test_meta_node_restart_single_node() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_transfer_leader() -> anyhow::Result<()> {
+    // - Bring up a cluster of 3 voters.
+    // - Transfer the leadership to node-2 by sending a request to a follower.
+    // - Transfer the leadership to any voter.
+
+    let (_log_index, tcs) = start_meta_node_cluster(btreeset![0, 1, 2], btreeset![]).await?;
+    let all = test_context_nodes(&tcs);
+
+    info!("--- transfer leader to node-2 via node-1");
+    {
+        let new_leader = all[1].transfer_leader(Some(2)).await?;
+        assert_eq!(2, new_leader);
+
+        for mn in all.iter() {
+            mn.raft
+                .wait(timeout())
+                .current_leader(2, "node-2 becomes the leader")
+                .await?;
+        }
+    }
+
+    info!("--- write to the new leader");
+    {
+        assert_upsert_kv_synced(all.clone(), "transfer-leader-key").await?;
+    }
+
+    info!("--- transfer leader to any voter");
+    {
+        let new_leader = all[2].transfer_leader(None).await?;
+        assert_ne!(2, new_leader);
+
+        all[2]
+            .raft
+            .wait(timeout())
+            .current_leader(new_leader, "leader changed")
+            .await?;
+    }
+
+    info!("--- transfer leader to the leader itself is not allowed");
+    {
+        let leader = all[0].get_leader().await?.unwrap();
+        let res = all[0].transfer_leader(Some(leader)).await;
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_drain() -> anyhow::Result<()> {
+    // - Bring up a cluster of 3 voters, node-0 is the leader.
+    // - Drain node-0: the leadership is transferred to another node.
+    // - A draining node refuses to become the leader.
+
+    let (_log_index, tcs) = start_meta_node_cluster(btreeset![0, 1, 2], btreeset![]).await?;
+    let all = test_context_nodes(&tcs);
+
+    info!("--- drain the leader node-0");
+    let new_leader = {
+        let new_leader = all[0].set_draining(true).await?;
+        let new_leader = new_leader.unwrap();
+        assert_ne!(0, new_leader);
+
+        assert!(all[0].is_draining());
+        assert!(all[0].get_status().await?.draining);
+
+        all[0]
+            .raft
+            .wait(timeout())
+            .current_leader(new_leader, "leader transferred")
+            .await?;
+        new_leader
+    };
+
+    info!("--- can not transfer the leadership to a draining node");
+    {
+        let res = all[new_leader as usize].transfer_leader(Some(0)).await;
+        assert!(res.is_err());
+
+        let leader = all[0].get_leader().await?;
+        assert_eq!(Some(new_leader), leader);
+    }
+
+    info!("--- leave the drain mode");
+    {
+        let res = all[0].set_draining(false).await?;
+        assert_eq!(None, res);
+        assert!(!all[0].get_status().await?.draining);
+
+        let new_leader = all[new_leader as usize].transfer_leader(Some(0)).await?;
+        assert_eq!(0, new_leader);
+    }
+
+    Ok(())
+}
+
 fn join_req(
     node_id: NodeId,
     endpoint: Endpoint,
@@ -14,12 +14,23 @@
 
 use std::sync::Arc;
 
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_sled_store::openraft::error::RaftError;
 use common_meta_types::ClientWriteError;
 use common_meta_types::Cmd;
 use common_meta_types::ForwardToLeader;
+use common_meta_types::LeaseCmd;
 use common_meta_types::LogEntry;
+use common_meta_types::MetaAPIError;
+use common_meta_types::MetaDataError;
+use common_meta_types::MetaManagementError;
 use common_meta_types::UpsertKV;
+use databend_meta::message::ForwardRequest;
+use databend_meta::message::ForwardRequestBody;
+use databend_meta::message::ForwardResponse;
+use databend_meta::message::FORWARD_PROTOCOL_VERSION;
+use databend_meta::message::LEASE_PROTOCOL_VERSION;
 use databend_meta::meta_service::meta_leader::MetaLeader;
 use databend_meta::meta_service::MetaNode;
 use maplit::btreeset;
@@ -76,6 +87,123 @@ async fn test_meta_node_forward_to_leader() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_forward_range_kv_to_old_leader() -> anyhow::Result<()> {
+    // - Forward a range scan from a follower to the leader, expect the leader's protocol version is learned.
+    // - Pretend the leader speaks an old protocol, expect the range scan is downgraded to a prefix list.
+
+    let (mut _nlog, tcs) = start_meta_node_cluster(btreeset![0, 1], btreeset![]).await?;
+    let all = test_context_nodes(&tcs);
+
+    let leader_id = all[0].raft.metrics().borrow().current_leader.unwrap();
+    let leader = &all[leader_id as usize];
+    let follower = &all[1 - leader_id as usize];
+
+    for key in ["r/1", "r/2", "r/3"] {
+        MetaLeader::new(leader)
+            .write(LogEntry {
+                txid: None,
+                time_ms: None,
+                cmd: Cmd::UpsertKV(UpsertKV::update(key, key.as_bytes())),
+            })
+            .await?;
+    }
+
+    let range_req = || ForwardRequest {
+        forward_to_leader: 1,
+        body: ForwardRequestBody::RangeKV(RangeKVReq::prefix("r/").unwrap().with_limit(2)),
+    };
+    let keys_of = |resp: ForwardResponse| -> anyhow::Result<Vec<String>> {
+        let reply: RangeKVReply = resp.try_into().map_err(|e| anyhow::anyhow!("{}", e))?;
+        Ok(reply.kvs.into_iter().map(|(k, _)| k).collect())
+    };
+
+    let resp = follower.forward_to(&leader_id, range_req()).await?;
+    assert_eq!(vec!["r/1", "r/2"], keys_of(resp)?);
+    assert_eq!(
+        Some(&FORWARD_PROTOCOL_VERSION),
+        follower
+            .peer_protocol_versions
+            .lock()
+            .unwrap()
+            .get(&leader_id)
+    );
+
+    // The leader is treated as an old node, which does not understand `RangeKV`.
+    follower
+        .peer_protocol_versions
+        .lock()
+        .unwrap()
+        .insert(leader_id, 0);
+    let resp = follower.forward_to(&leader_id, range_req()).await?;
+    assert_eq!(vec!["r/1", "r/2"], keys_of(resp)?);
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_lease_requires_protocol_version() -> anyhow::Result<()> {
+    // - A lease can be granted when all the nodes understand a lease log entry.
+    // - Pretend a node speaks an old protocol, expect a lease is rejected.
+
+    let (mut _nlog, tcs) = start_meta_node_cluster(btreeset![0, 1], btreeset![]).await?;
+    let all = test_context_nodes(&tcs);
+
+    let grant = |lease_id: &str| LeaseCmd::Grant {
+        lease_id: lease_id.to_string(),
+        ttl_ms: 3_000,
+    };
+
+    all[0].lease(grant("l1")).await?;
+
+    all[0]
+        .peer_protocol_versions
+        .lock()
+        .unwrap()
+        .insert(1, LEASE_PROTOCOL_VERSION - 1);
+    let res = all[0].lease(grant("l2")).await;
+    match res {
+        Err(MetaAPIError::DataError(MetaDataError::ManagementError(
+            MetaManagementError::Unsupported(_),
+        ))) => {}
+        _ => panic!("expect Unsupported error, got: {:?}", res),
+    }
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_transfer_leader_requires_protocol_version() -> anyhow::Result<()> {
+    // - Pretend node-1 speaks an old protocol that does not understand `Elect`.
+    // - Expect the leadership is not transferred to it, and the leader is unchanged.
+
+    let (mut _nlog, tcs) = start_meta_node_cluster(btreeset![0, 1], btreeset![]).await?;
+    let all = test_context_nodes(&tcs);
+
+    all[0]
+        .peer_protocol_versions
+        .lock()
+        .unwrap()
+        .insert(1, FORWARD_PROTOCOL_VERSION - 1);
+
+    let res = all[0].transfer_leader(Some(1)).await;
+    match res {
+        Err(MetaAPIError::DataError(MetaDataError::ManagementError(
+            MetaManagementError::TransferLeader(e),
+        ))) => {
+            assert!(e.to_string().contains("older version"), "{}", e);
+        }
+        _ => panic!("expect TransferLeader error, got: {:?}", res),
+    }
+
+    assert_eq!(Some(0), all[0].get_leader().await?);
+
+    Ok(())
+}
+
 fn test_context_nodes(tcs: &[MetaSrvTestContext]) -> Vec<Arc<MetaNode>> {
     tcs.iter().map(|tc| tc.meta_node()).collect::<Vec<_>>()
 }
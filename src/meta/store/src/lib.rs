@@ -27,6 +27,8 @@ use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::protobuf::WatchRequest;
@@ -118,6 +120,13 @@ impl kvapi::KVApi for MetaStore {
         }
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, MetaError> {
+        match self {
+            MetaStore::L(x) => x.range_kv(req).await,
+            MetaStore::R(x) => x.range_kv(req).await,
+        }
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
         match self {
             MetaStore::L(x) => x.transaction(txn).await,
@@ -19,6 +19,7 @@ use serde::Serialize;
 
 use crate::with::With;
 use crate::KVMeta;
+use crate::LeaseCmd;
 use crate::MatchSeq;
 use crate::Node;
 use crate::NodeId;
@@ -46,6 +47,9 @@ pub enum Cmd {
 
     /// Update one or more kv with a transaction.
     Transaction(TxnRequest),
+
+    /// Grant, keep alive or revoke a lease, or put a key attached to a lease.
+    Lease(LeaseCmd),
 }
 
 /// Update or insert a general purpose kv store
@@ -89,6 +93,9 @@ impl fmt::Display for Cmd {
             Cmd::Transaction(txn) => {
                 write!(f, "txn:{}", txn)
             }
+            Cmd::Lease(lease) => {
+                write!(f, "lease:{}", lease)
+            }
         }
     }
 }
@@ -21,6 +21,7 @@ use crate::raft_types::Fatal;
 use crate::raft_types::ForwardToLeader;
 use crate::ClientWriteError;
 use crate::InvalidReply;
+use crate::MetaManagementError;
 use crate::MetaNetworkError;
 use crate::RaftError;
 
@@ -68,6 +69,7 @@ impl MetaAPIError {
                 },
                 MetaDataError::WriteError(_) => false,
                 MetaDataError::ReadError(_) => false,
+                MetaDataError::ManagementError(_) => false,
             },
             MetaAPIError::ForwardToLeader(_) => {
                 // Leader is changing, wait a while and retry
@@ -81,6 +83,7 @@ impl MetaAPIError {
                 MetaDataError::WriteError(_) => false,
                 MetaDataError::ChangeMembershipError(_) => true,
                 MetaDataError::ReadError(_) => false,
+                MetaDataError::ManagementError(_) => false,
             },
         }
     }
@@ -130,6 +133,10 @@ pub enum MetaDataError {
     /// Error occurred when reading.
     #[error(transparent)]
     ReadError(#[from] MetaDataReadError),
+
+    /// Error occurred when managing the cluster.
+    #[error(transparent)]
+    ManagementError(#[from] MetaManagementError),
 }
 
 /// Error occurred when a meta-node reads data.
@@ -24,4 +24,12 @@ pub enum MetaManagementError {
 
     #[error(transparent)]
     Leave(AnyError),
+
+    /// The operation is not supported by some nodes in the cluster, which run an older version.
+    #[error(transparent)]
+    Unsupported(AnyError),
+
+    /// The leadership can not be transferred, or the node receiving it failed to become the leader.
+    #[error(transparent)]
+    TransferLeader(AnyError),
 }
@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Leases: a time-to-live shared by a group of keys.
+//!
+//! A lease is stored as a regular record `__fd_lease/<lease_id>` that expires when the lease expires.
+//! Every key attached to a lease shares the same expiration time,
+//! so that all of them are removed together when the lease expires or is revoked.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::KVMeta;
+
+/// Operations on a lease, which are applied to the state machine through raft-log.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum LeaseCmd {
+    /// Create a lease with a time-to-live, or reset the ttl of an existing one.
+    Grant { lease_id: String, ttl_ms: u64 },
+
+    /// Extend the expiration of a lease and all the keys attached to it by another `ttl_ms`.
+    KeepAlive { lease_id: String },
+
+    /// Remove a lease and all the keys attached to it.
+    Revoke { lease_id: String },
+
+    /// Put a key that is attached to a lease and is removed when the lease is gone.
+    ///
+    /// Nothing is written if the lease does not exist.
+    Attach {
+        lease_id: String,
+        key: String,
+        value: Vec<u8>,
+    },
+}
+
+impl LeaseCmd {
+    pub fn lease_id(&self) -> &str {
+        match self {
+            LeaseCmd::Grant { lease_id, .. } => lease_id,
+            LeaseCmd::KeepAlive { lease_id } => lease_id,
+            LeaseCmd::Revoke { lease_id } => lease_id,
+            LeaseCmd::Attach { lease_id, .. } => lease_id,
+        }
+    }
+}
+
+impl fmt::Display for LeaseCmd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaseCmd::Grant { lease_id, ttl_ms } => {
+                write!(f, "grant:{}(ttl: {} ms)", lease_id, ttl_ms)
+            }
+            LeaseCmd::KeepAlive { lease_id } => write!(f, "keep_alive:{}", lease_id),
+            LeaseCmd::Revoke { lease_id } => write!(f, "revoke:{}", lease_id),
+            LeaseCmd::Attach { lease_id, key, .. } => write!(f, "attach:{}:{}", lease_id, key),
+        }
+    }
+}
+
+/// The value of a lease record.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LeaseInfo {
+    pub ttl_ms: u64,
+
+    /// Keys attached to this lease.
+    ///
+    /// A key may have been removed or overridden without a lease since it is attached.
+    pub keys: BTreeSet<String>,
+}
+
+impl LeaseInfo {
+    pub const PREFIX: &'static str = "__fd_lease";
+
+    pub fn new(ttl_ms: u64) -> Self {
+        Self {
+            ttl_ms,
+            keys: BTreeSet::new(),
+        }
+    }
+
+    /// The key of the record storing the lease.
+    pub fn key(lease_id: &str) -> String {
+        format!("{}/{}", Self::PREFIX, lease_id)
+    }
+
+    /// Build the meta of the lease and its attached keys, for a lease renewed at `now_ms`.
+    pub fn kv_meta(&self, now_ms: u64) -> KVMeta {
+        // Round up to make sure a lease lives at least `ttl_ms`.
+        let expire_at = (now_ms + self.ttl_ms + 999) / 1000;
+        KVMeta {
+            expire_at: Some(expire_at),
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("fail to serialize LeaseInfo")
+    }
+
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        serde_json::from_slice(buf).ok()
+    }
+}
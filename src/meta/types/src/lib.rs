@@ -26,6 +26,7 @@ pub mod config;
 mod endpoint;
 pub mod errors;
 mod grpc_config;
+mod lease;
 mod log_entry;
 mod match_seq;
 mod message;
@@ -74,6 +75,8 @@ pub use errors::meta_network_errors::MetaNetworkResult;
 pub use errors::meta_startup_errors::MetaStartupError;
 pub use errors::rpc_errors::ForwardRPCError;
 pub use grpc_config::GrpcConfig;
+pub use lease::LeaseCmd;
+pub use lease::LeaseInfo;
 pub use log_entry::LogEntry;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
@@ -212,3 +215,26 @@ impl TxnOpResponse {
         }
     }
 }
+
+impl protobuf::RangeItem {
+    /// Build a range scan item from a key and a [`SeqV`].
+    pub fn new(key: impl ToString, seq_v: SeqV) -> Self {
+        Self {
+            key: key.to_string(),
+            value: Some(protobuf::SeqV {
+                seq: seq_v.seq,
+                data: seq_v.data,
+            }),
+            expire_at: seq_v.meta.and_then(|m| m.expire_at),
+        }
+    }
+
+    /// Convert back to a key and a [`SeqV`].
+    pub fn into_pair(self) -> (String, SeqV) {
+        let value = self.value.unwrap_or_default();
+        let meta = self.expire_at.map(|expire_at| KVMeta {
+            expire_at: Some(expire_at),
+        });
+        (self.key, SeqV::with_meta(value.seq, meta, value.data))
+    }
+}
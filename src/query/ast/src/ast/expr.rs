@@ -179,7 +179,11 @@ pub enum Expr {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
-        window: Option<Window>,
+        /// `WITHIN GROUP (ORDER BY ...)` of ordered-set aggregate functions
+        within_group: Vec<OrderByExpr>,
+        /// `FILTER (WHERE ...)` of aggregate functions
+        filter: Option<Box<Expr>>,
+        window: Option<WindowDesc>,
         lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
@@ -298,6 +302,8 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    Interval,
+    TimestampTz,
     String,
     Array(Box<TypeName>),
     Map {
@@ -310,6 +316,7 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Geometry,
     Nullable(Box<TypeName>),
 }
 
@@ -334,6 +341,15 @@ pub enum TrimWhere {
     Trailing,
 }
 
+/// The window of a window function call, with an optional `IGNORE NULLS` or
+/// `RESPECT NULLS` modifier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowDesc {
+    /// `Some(true)` for `IGNORE NULLS`, `Some(false)` for `RESPECT NULLS`.
+    pub ignore_nulls: Option<bool>,
+    pub window: Window,
+}
+
 #[derive(Debug, Clone, PartialEq, EnumAsInner)]
 pub enum Window {
     WindowReference(WindowRef),
@@ -726,6 +742,12 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
+            TypeName::TimestampTz => {
+                write!(f, "TIMESTAMP_TZ")?;
+            }
             TypeName::String => {
                 write!(f, "STRING")?;
             }
@@ -769,6 +791,9 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -1077,6 +1102,8 @@ impl Display for Expr {
                 name,
                 args,
                 params,
+                within_group,
+                filter,
                 window,
                 lambda,
                 ..
@@ -1097,8 +1124,23 @@ impl Display for Expr {
                 }
                 write!(f, ")")?;
 
+                if !within_group.is_empty() {
+                    write!(f, " WITHIN GROUP (ORDER BY ")?;
+                    write_comma_separated_list(f, within_group)?;
+                    write!(f, ")")?;
+                }
+                if let Some(filter) = filter {
+                    write!(f, " FILTER (WHERE {filter})")?;
+                }
                 if let Some(window) = window {
-                    write!(f, " OVER ({window})")?;
+                    if let Some(ignore_nulls) = window.ignore_nulls {
+                        if ignore_nulls {
+                            write!(f, " IGNORE NULLS")?;
+                        } else {
+                            write!(f, " RESPECT NULLS")?;
+                        }
+                    }
+                    write!(f, " OVER ({})", window.window)?;
                 }
             }
             Expr::Case {
@@ -237,6 +237,8 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             name,
             args,
             params,
+            within_group,
+            filter,
             window,
             ..
         } => RcDoc::text(name.to_string())
@@ -259,12 +261,35 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             })
             .append(inline_comma(args.into_iter().map(pretty_expr)))
             .append(RcDoc::text(")"))
-            .append(if let Some(window) = window {
-                RcDoc::text(" OVER (")
-                    .append(RcDoc::text(window.to_string()))
+            .append(if !within_group.is_empty() {
+                RcDoc::text(" WITHIN GROUP (ORDER BY ")
+                    .append(inline_comma(
+                        within_group
+                            .into_iter()
+                            .map(|order_by| RcDoc::text(order_by.to_string())),
+                    ))
+                    .append(")")
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(filter) = filter {
+                RcDoc::text(" FILTER (WHERE ")
+                    .append(pretty_expr(*filter))
                     .append(")")
             } else {
                 RcDoc::nil()
+            })
+            .append(if let Some(window) = window {
+                RcDoc::text(match window.ignore_nulls {
+                    Some(true) => " IGNORE NULLS",
+                    Some(false) => " RESPECT NULLS",
+                    None => "",
+                })
+                .append(RcDoc::text(" OVER ("))
+                .append(RcDoc::text(window.window.to_string()))
+                .append(")")
+            } else {
+                RcDoc::nil()
             }),
         Expr::Case {
             operand,
@@ -87,6 +87,7 @@ fn pretty_body(body: SetExpr) -> RcDoc<'static> {
         .append(pretty_selection(select_stmt.selection))
         .append(pretty_group_by(select_stmt.group_by))
         .append(pretty_having(select_stmt.having))
+        .append(pretty_qualify(select_stmt.qualify))
         .append(pretty_window(select_stmt.window_list)),
         SetExpr::Query(query) => parenthesized(pretty_query(*query)),
         SetExpr::SetOperation(set_operation) => pretty_body(*set_operation.left)
@@ -281,6 +282,16 @@ fn pretty_having(having: Option<Expr>) -> RcDoc<'static> {
     }
 }
 
+fn pretty_qualify(qualify: Option<Expr>) -> RcDoc<'static> {
+    if let Some(qualify) = qualify {
+        RcDoc::line()
+            .append(RcDoc::text("QUALIFY").append(RcDoc::line().nest(NEST_FACTOR)))
+            .append(pretty_expr(qualify))
+    } else {
+        RcDoc::nil()
+    }
+}
+
 fn pretty_window(window: Option<Vec<WindowDefinition>>) -> RcDoc<'static> {
     if let Some(window) = window {
         RcDoc::line()
@@ -314,6 +325,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             travel_point,
             pivot,
             unpivot,
+            sample,
         } => if let Some(catalog) = catalog {
             RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
         } else {
@@ -346,18 +358,31 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::text(format!(" AS {alias}"))
         } else {
             RcDoc::nil()
+        })
+        .append(if let Some(sample) = sample {
+            RcDoc::text(format!(" {sample}"))
+        } else {
+            RcDoc::nil()
         }),
         TableReference::Subquery {
             span: _,
+            lateral,
             subquery,
             alias,
-        } => parenthesized(pretty_query(*subquery)).append(if let Some(alias) = alias {
+        } => (if lateral {
+            RcDoc::text("LATERAL ")
+        } else {
+            RcDoc::nil()
+        })
+        .append(parenthesized(pretty_query(*subquery)))
+        .append(if let Some(alias) = alias {
             RcDoc::text(format!(" AS {alias}"))
         } else {
             RcDoc::nil()
         }),
         TableReference::TableFunction {
             span: _,
+            lateral,
             name,
             params,
             named_params,
@@ -368,7 +393,12 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             };
-            RcDoc::text(name.to_string())
+            let name = if lateral {
+                format!("LATERAL {name}")
+            } else {
+                name.to_string()
+            };
+            RcDoc::text(name)
                 .append(RcDoc::text("("))
                 .append(inline_comma(params.into_iter().map(pretty_expr)))
                 .append(separator)
@@ -401,8 +431,17 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
                 JoinOperator::RightAnti => RcDoc::text("RIGHT ANTI JOIN"),
                 JoinOperator::LeftSemi => RcDoc::text("LEFT SEMI JOIN"),
                 JoinOperator::RightSemi => RcDoc::text("RIGHT SEMI JOIN"),
+                JoinOperator::AsofJoin => RcDoc::text("ASOF JOIN"),
+                JoinOperator::LeftAsofJoin => RcDoc::text("ASOF LEFT JOIN"),
             })
             .append(RcDoc::space().append(pretty_table(*join.right)))
+            .append(match join.match_condition {
+                Some(expr) => RcDoc::space()
+                    .append(RcDoc::text("MATCH_CONDITION ("))
+                    .append(pretty_expr(*expr))
+                    .append(RcDoc::text(")")),
+                None => RcDoc::nil(),
+            })
             .append(match &join.condition {
                 JoinCondition::On(expr) => RcDoc::space()
                     .append(RcDoc::text("ON"))
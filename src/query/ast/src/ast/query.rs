@@ -90,6 +90,8 @@ pub struct SelectStmt {
     pub group_by: Option<GroupBy>,
     // `HAVING` clause
     pub having: Option<Expr>,
+    // `QUALIFY` clause
+    pub qualify: Option<Expr>,
     // `WINDOW` clause
     pub window_list: Option<Vec<WindowDefinition>>,
 }
@@ -231,6 +233,31 @@ pub struct Unpivot {
     pub names: Vec<Identifier>,
 }
 
+/// Sampling level of `SAMPLE` / `TABLESAMPLE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleLevel {
+    // Decide for each row independently
+    Row,
+    // Decide for each storage block as a whole
+    Block,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SampleCriteria {
+    // `(n PERCENT)` or `(n)`
+    Percentage(f64),
+    // `(n ROWS)`
+    Rows(u64),
+}
+
+/// `{SAMPLE | TABLESAMPLE} [ROW | BLOCK] (n [PERCENT | ROWS]) [REPEATABLE (seed)]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleConfig {
+    pub level: SampleLevel,
+    pub criteria: SampleCriteria,
+    pub seed: Option<u64>,
+}
+
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq)]
 pub enum TableReference {
@@ -244,10 +271,13 @@ pub enum TableReference {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<SampleConfig>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
         span: Span,
+        /// Whether the table function is prefixed with `LATERAL`
+        lateral: bool,
         name: Identifier,
         params: Vec<Expr>,
         named_params: Vec<(String, Expr)>,
@@ -256,6 +286,8 @@ pub enum TableReference {
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
         span: Span,
+        /// Whether the subquery is prefixed with `LATERAL`
+        lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>,
     },
@@ -297,6 +329,8 @@ pub struct TableAlias {
 pub struct Join {
     pub op: JoinOperator,
     pub condition: JoinCondition,
+    // `MATCH_CONDITION (expr)` of ASOF join
+    pub match_condition: Option<Box<Expr>>,
     pub left: Box<TableReference>,
     pub right: Box<TableReference>,
 }
@@ -314,6 +348,9 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // Asof joins must work with a match condition
+    AsofJoin,
+    LeftAsofJoin,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -405,6 +442,23 @@ impl Display for Unpivot {
     }
 }
 
+impl Display for SampleConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SAMPLE")?;
+        if self.level == SampleLevel::Block {
+            write!(f, " BLOCK")?;
+        }
+        match &self.criteria {
+            SampleCriteria::Percentage(p) => write!(f, " ({p} PERCENT)")?,
+            SampleCriteria::Rows(n) => write!(f, " ({n} ROWS)")?,
+        }
+        if let Some(seed) = self.seed {
+            write!(f, " REPEATABLE ({seed})")?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for TableReference {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -417,6 +471,7 @@ impl Display for TableReference {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 write_dot_separated_list(
                     f,
@@ -434,6 +489,9 @@ impl Display for TableReference {
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
                 if let Some(pivot) = pivot {
                     write!(f, " {pivot}")?;
                 }
@@ -444,11 +502,15 @@ impl Display for TableReference {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "{name}(")?;
                 write_comma_separated_list(f, params)?;
                 if !params.is_empty() && !named_params.is_empty() {
@@ -467,9 +529,13 @@ impl Display for TableReference {
             }
             TableReference::Subquery {
                 span: _,
+                lateral,
                 subquery,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "({subquery})")?;
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
@@ -508,8 +574,17 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::AsofJoin => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsofJoin => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let Some(match_condition) = &join.match_condition {
+                    write!(f, " MATCH_CONDITION ({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
@@ -643,6 +718,11 @@ impl Display for SelectStmt {
             write!(f, " HAVING {having}")?;
         }
 
+        // QUALIFY clause
+        if let Some(qualify) = &self.qualify {
+            write!(f, " QUALIFY {qualify}")?;
+        }
+
         Ok(())
     }
 }
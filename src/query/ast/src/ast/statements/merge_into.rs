@@ -241,6 +241,7 @@ impl MergeSource {
 
             Self::Select { query } => TableReference::Subquery {
                 span: None,
+                lateral: false,
                 subquery: query.clone(),
                 alias,
             },
@@ -27,11 +27,13 @@ mod merge_into;
 mod network_policy;
 mod presign;
 mod replace;
+mod sequence;
 mod share;
 mod show;
 mod stage;
 mod statement;
 mod table;
+mod task;
 mod udf;
 mod unset;
 mod update;
@@ -54,11 +56,13 @@ pub use merge_into::*;
 pub use network_policy::*;
 pub use presign::*;
 pub use replace::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
 pub use table::*;
+pub use task::*;
 pub use udf::*;
 pub use unset::*;
 pub use update::*;
@@ -132,6 +132,7 @@ pub struct CreateTableStmt {
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
+    pub temporary: bool,
 }
 
 impl Display for CreateTableStmt {
@@ -140,6 +141,9 @@ impl Display for CreateTableStmt {
         if self.transient {
             write!(f, "TRANSIENT ")?;
         }
+        if self.temporary {
+            write!(f, "TEMPORARY ")?;
+        }
         write!(f, "TABLE ")?;
         if self.if_not_exists {
             write!(f, "IF NOT EXISTS ")?;
@@ -684,6 +688,11 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    /// Values are taken from an implicit sequence, `AUTOINCREMENT` or `IDENTITY`.
+    AutoIncrement {
+        start: u64,
+        step: u64,
+    },
 }
 
 impl Display for ColumnExpr {
@@ -698,6 +707,9 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::AutoIncrement { start, step } => {
+                write!(f, " AUTOINCREMENT ({start}, {step})")?;
+            }
         }
         Ok(())
     }
@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskSchedule {
+    /// Run the task every `n` seconds.
+    Interval(u64),
+    /// Run the task by the cron expression, in the timezone of the session.
+    Cron(String),
+}
+
+impl Display for TaskSchedule {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TaskSchedule::Interval(secs) => write!(f, "{} SECOND", secs),
+            TaskSchedule::Cron(expr) => write!(f, "'{}'", expr),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateTaskStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub warehouse: Option<String>,
+    pub schedule: Option<TaskSchedule>,
+    pub after: Vec<String>,
+    pub comment: Option<String>,
+    pub sql: String,
+}
+
+impl Display for CreateTaskStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE TASK ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if let Some(warehouse) = &self.warehouse {
+            write!(f, " WAREHOUSE = '{}'", warehouse)?;
+        }
+        if let Some(schedule) = &self.schedule {
+            write!(f, " SCHEDULE = {}", schedule)?;
+        }
+        if !self.after.is_empty() {
+            write!(f, " AFTER ")?;
+            write_comma_separated_list(f, &self.after)?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+        write!(f, " AS {}", self.sql)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterTaskAction {
+    Resume,
+    Suspend,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterTaskStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub action: AlterTaskAction,
+}
+
+impl Display for AlterTaskStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER TASK ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        match self.action {
+            AlterTaskAction::Resume => write!(f, " RESUME"),
+            AlterTaskAction::Suspend => write!(f, " SUSPEND"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropTaskStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropTaskStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP TASK ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecuteTaskStmt {
+    pub name: String,
+}
+
+impl Display for ExecuteTaskStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "EXECUTE TASK {}", self.name)
+    }
+}
@@ -15,6 +15,8 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use common_io::escape_string_with_quote;
+use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::AuthType;
 use common_meta_app::principal::PrincipalIdentity;
 use common_meta_app::principal::UserIdentity;
@@ -76,6 +78,7 @@ pub struct AlterUserStmt {
     pub user: Option<UserIdentity>,
     // None means no change to make
     pub auth_option: Option<AuthOption>,
+    pub public_key_options: Vec<PublicKeyOption>,
     pub user_options: Vec<UserOptionItem>,
 }
 
@@ -90,6 +93,10 @@ impl Display for AlterUserStmt {
         if let Some(auth_option) = &self.auth_option {
             write!(f, " IDENTIFIED {}", auth_option)?;
         }
+        if !self.public_key_options.is_empty() {
+            write!(f, " ")?;
+            write_comma_separated_list(f, &self.public_key_options)?;
+        }
         if !self.user_options.is_empty() {
             write!(f, " WITH")?;
             for with_option in &self.user_options {
@@ -175,6 +182,39 @@ impl UserOptionItem {
     }
 }
 
+/// Set or unset the public key in slot 1(`RSA_PUBLIC_KEY`) or 2(`RSA_PUBLIC_KEY_2`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicKeyOption {
+    Set { slot: usize, key: String },
+    Unset { slot: usize },
+}
+
+impl PublicKeyOption {
+    pub fn apply(&self, auth_info: &AuthInfo) -> AuthInfo {
+        match self {
+            Self::Set { slot, key } => auth_info.with_public_key(*slot, Some(key.clone())),
+            Self::Unset { slot } => auth_info.with_public_key(*slot, None),
+        }
+    }
+}
+
+impl Display for PublicKeyOption {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let (action, slot) = match self {
+            PublicKeyOption::Set { slot, .. } => ("SET", slot),
+            PublicKeyOption::Unset { slot } => ("UNSET", slot),
+        };
+        write!(f, "{action} RSA_PUBLIC_KEY")?;
+        if *slot == 2 {
+            write!(f, "_2")?;
+        }
+        if let PublicKeyOption::Set { key, .. } = self {
+            write!(f, " = '{}'", escape_string_with_quote(key, Some('\'')))?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for AccountMgrSource {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -249,6 +249,10 @@ pub enum ExprElement {
     PgCast {
         target_type: TypeName,
     },
+    /// `AT TIME ZONE <zone>` expression
+    AtTimeZone {
+        zone: Box<Expr>,
+    },
     /// EXTRACT(IntervalKind FROM <expr>)
     Extract {
         field: IntervalKind,
@@ -292,7 +296,9 @@ pub enum ExprElement {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
-        window: Option<Window>,
+        within_group: Vec<OrderByExpr>,
+        filter: Option<Box<Expr>>,
+        window: Option<WindowDesc>,
         lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
@@ -419,6 +425,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 BinaryOperator::Caret => Affix::Infix(Precedence(40), Associativity::Left),
             },
             ExprElement::PgCast { .. } => Affix::Postfix(Precedence(60)),
+            ExprElement::AtTimeZone { .. } => Affix::Postfix(Precedence(50)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -492,6 +499,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 name,
                 args,
                 params,
+                within_group,
+                filter,
                 window,
                 lambda,
             } => Expr::FunctionCall {
@@ -500,6 +509,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 name,
                 args,
                 params,
+                within_group,
+                filter,
                 window,
                 lambda,
             },
@@ -646,6 +657,21 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 target_type,
                 pg_style: true,
             },
+            ExprElement::AtTimeZone { zone } => Expr::FunctionCall {
+                span: transform_span(elem.span.0),
+                distinct: false,
+                name: Identifier {
+                    span: transform_span(elem.span.0),
+                    name: "convert_timezone".to_string(),
+                    quote: None,
+                },
+                args: vec![*zone, lhs],
+                params: vec![],
+                within_group: vec![],
+                filter: None,
+                window: None,
+                lambda: None,
+            },
             ExprElement::UnaryOp { op } => Expr::UnaryOp {
                 span: transform_span(elem.span.0),
                 op,
@@ -730,6 +756,15 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
         |(_, target_type)| ExprElement::PgCast { target_type },
     );
+    // `<expr> AT TIME ZONE <zone>` is a shorthand for `convert_timezone(<zone>, <expr>)`
+    let at_time_zone = map(
+        rule! {
+            AT ~ TIME ~ ZONE ~ ^#subexpr(50)
+        },
+        |(_, _, _, zone)| ExprElement::AtTimeZone {
+            zone: Box::new(zone),
+        },
+    );
     let extract = map(
         rule! {
             EXTRACT ~ "(" ~ ^#interval_kind ~ ^FROM ~ ^#subexpr(0) ~ ^")"
@@ -804,10 +839,30 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
 
     let count_all_with_window = map(
         rule! {
-        COUNT ~ "(" ~ "*" ~ ")" ~ (OVER ~ #window_spec_ident)?
-        },
-        |(_, _, _, _, window)| ExprElement::CountAll {
-            window: window.map(|w| w.1),
+        COUNT ~ "(" ~ "*" ~ ")" ~ #aggregate_filter? ~ (OVER ~ #window_spec_ident)?
+        },
+        |(count, _, _, _, filter, window)| match filter {
+            // `COUNT(*) FILTER (WHERE ...)` is the same as `COUNT() FILTER (WHERE ...)`
+            Some(filter) => ExprElement::FunctionCall {
+                distinct: false,
+                name: Identifier {
+                    span: transform_span(&[count.clone()]),
+                    name: count.text().to_string(),
+                    quote: None,
+                },
+                args: vec![],
+                params: vec![],
+                within_group: vec![],
+                filter: Some(Box::new(filter)),
+                window: window.map(|w| WindowDesc {
+                    ignore_nulls: None,
+                    window: w.1,
+                }),
+                lambda: None,
+            },
+            None => ExprElement::CountAll {
+                window: window.map(|w| w.1),
+            },
         },
     );
     let tuple = map(
@@ -827,12 +882,15 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule! {
             #function_name
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ #within_group? ~ #aggregate_filter?
         },
-        |(name, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, _, within_group, filter)| ExprElement::FunctionCall {
             distinct: opt_distinct.is_some(),
             name,
             args: opt_args.unwrap_or_default(),
             params: vec![],
+            within_group: within_group.unwrap_or_default(),
+            filter: filter.map(Box::new),
             window: None,
             lambda: None,
         },
@@ -848,6 +906,8 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             name,
             args: vec![arg],
             params: vec![],
+            within_group: vec![],
+            filter: None,
             window: None,
             lambda: Some(Lambda {
                 params: vec![param],
@@ -859,16 +919,35 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     let function_call_with_window = map(
         rule! {
             #function_name
-            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ #null_treatment? ~ ")"
+            ~ #within_group? ~ #aggregate_filter? ~ #null_treatment?
             ~ (OVER ~ #window_spec_ident)
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
-            distinct: opt_distinct.is_some(),
+        |(
             name,
-            args: opt_args.unwrap_or_default(),
-            params: vec![],
-            window: Some(window.1),
-            lambda: None,
+            _,
+            opt_distinct,
+            opt_args,
+            inner_nulls,
+            _,
+            within_group,
+            filter,
+            outer_nulls,
+            window,
+        )| {
+            ExprElement::FunctionCall {
+                distinct: opt_distinct.is_some(),
+                name,
+                args: opt_args.unwrap_or_default(),
+                params: vec![],
+                within_group: within_group.unwrap_or_default(),
+                filter: filter.map(Box::new),
+                window: Some(WindowDesc {
+                    ignore_nulls: inner_nulls.or(outer_nulls),
+                    window: window.1,
+                }),
+                lambda: None,
+            }
         },
     );
 
@@ -877,14 +956,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             #function_name
             ~ ("(" ~ #comma_separated_list1(literal) ~ ")")?
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ #within_group? ~ #aggregate_filter?
         },
-        |(name, params, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
-            distinct: opt_distinct.is_some(),
-            name,
-            args: opt_args.unwrap_or_default(),
-            params: params.map(|x| x.1).unwrap_or_default(),
-            window: None,
-            lambda: None,
+        |(name, params, _, opt_distinct, opt_args, _, within_group, filter)| {
+            ExprElement::FunctionCall {
+                distinct: opt_distinct.is_some(),
+                name,
+                args: opt_args.unwrap_or_default(),
+                params: params.map(|x| x.1).unwrap_or_default(),
+                within_group: within_group.unwrap_or_default(),
+                filter: filter.map(Box::new),
+                window: None,
+                lambda: None,
+            }
         },
     );
 
@@ -986,12 +1070,34 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             unit,
         },
     );
-    let date_trunc = map(
-        rule! {
-            DATE_TRUNC ~ "(" ~ #interval_kind ~ "," ~ #subexpr(0) ~ ")"
-        },
-        |(_, _, unit, _, date, _)| ExprElement::DateTrunc { unit, date },
-    );
+    let date_trunc = alt((
+        map(
+            rule! {
+                DATE_TRUNC ~ "(" ~ #interval_kind ~ "," ~ #subexpr(0) ~ ")"
+            },
+            |(_, _, unit, _, date, _)| ExprElement::DateTrunc { unit, date },
+        ),
+        // `DATE_TRUNC(<interval>, <timestamp>)` truncates by an arbitrary interval
+        map(
+            rule! {
+                DATE_TRUNC ~ "(" ~ #subexpr(0) ~ "," ~ #subexpr(0) ~ ")"
+            },
+            |(date_trunc, _, interval, _, date, _)| ExprElement::FunctionCall {
+                distinct: false,
+                name: Identifier {
+                    span: transform_span(&[date_trunc.clone()]),
+                    name: date_trunc.text().to_lowercase(),
+                    quote: None,
+                },
+                args: vec![interval, date],
+                params: vec![],
+                within_group: vec![],
+                filter: None,
+                window: None,
+                lambda: None,
+            },
+        ),
+    ));
 
     let date_expr = map(
         rule! {
@@ -1019,6 +1125,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.0),
+                lit: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
+
     let is_distinct_from = map(
         rule! {
             IS ~ NOT? ~ DISTINCT ~ FROM
@@ -1039,11 +1158,13 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #cast : "`CAST(... AS ...)`"
             | #date_add: "`DATE_ADD(..., ..., (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW))`"
             | #date_sub: "`DATE_SUB(..., ..., (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW))`"
-            | #date_trunc: "`DATE_TRUNC((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND), ...)`"
+            | #date_trunc: "`DATE_TRUNC((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | <interval>), ...)`"
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+            | #interval_expr: "`INTERVAL <str_literal>`"
             | #pg_cast : "`::<type_name>`"
+            | #at_time_zone : "`... AT TIME ZONE ...`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND) FROM ...)`"
         ),
         rule!(
@@ -1338,15 +1459,24 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         },
     );
     let ty_date = value(TypeName::Date, rule! { DATE });
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
+    let ty_timestamp_tz = value(TypeName::TimestampTz, rule! { TIMESTAMP_TZ });
     let ty_datetime = map(
-        rule! { ( DATETIME | TIMESTAMP ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
-        |(_, _)| TypeName::Timestamp,
+        rule! { ( DATETIME | TIMESTAMP ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? ~ ( WITH ~ TIME ~ ZONE )? },
+        |(_, _, with_tz)| {
+            if with_tz.is_some() {
+                TypeName::TimestampTz
+            } else {
+                TypeName::Timestamp
+            }
+        },
     );
     let ty_string = value(
         TypeName::String,
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT | BINARY | VARBINARY ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     map(
         alt((
             rule! {
@@ -1372,8 +1502,11 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             rule! {
             ( #ty_date
             | #ty_datetime
+            | #ty_interval
+            | #ty_timestamp_tz
             | #ty_string
             | #ty_variant
+            | #ty_geometry
             | #ty_nullable
             ) ~ NULL? : "type name" },
         )),
@@ -57,6 +57,7 @@ pub enum SetOperationElement {
         selection: Box<Option<Expr>>,
         group_by: Option<GroupBy>,
         having: Box<Option<Expr>>,
+        qualify: Box<Option<Expr>>,
         window_list: Option<Vec<WindowDefinition>>,
     },
     SetOperation {
@@ -103,6 +104,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 ~ ( WHERE ~ ^#expr )?
                 ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
                 ~ ( HAVING ~ ^#expr )?
+                ~ ( QUALIFY ~ ^#expr )?
                 ~ ( WINDOW ~ ^#comma_separated_list1(window_clause) )?
         },
         |(
@@ -114,6 +116,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             opt_where_block,
             opt_group_by_block,
             opt_having_block,
+            opt_qualify_block,
             opt_window_block,
         )| {
             SetOperationElement::SelectStmt {
@@ -128,6 +131,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 selection: Box::new(opt_where_block.map(|(_, selection)| selection)),
                 group_by: opt_group_by_block.map(|(_, _, group_by)| group_by),
                 having: Box::new(opt_having_block.map(|(_, having)| having)),
+                qualify: Box::new(opt_qualify_block.map(|(_, qualify)| qualify)),
                 window_list: opt_window_block.map(|(_, windows)| windows),
             }
         },
@@ -221,6 +225,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 selection,
                 group_by,
                 having,
+                qualify,
                 window_list,
             } => SetExpr::Select(Box::new(SelectStmt {
                 span: transform_span(input.span.0),
@@ -231,6 +236,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 selection: *selection,
                 group_by,
                 having: *having,
+                qualify: *qualify,
                 window_list,
             })),
             SetOperationElement::Values(values) => SetExpr::Values {
@@ -457,6 +463,8 @@ pub fn table_alias(i: Input) -> IResult<TableAlias> {
 pub fn join_operator(i: Input) -> IResult<JoinOperator> {
     alt((
         value(JoinOperator::Inner, rule! { INNER }),
+        value(JoinOperator::LeftAsofJoin, rule! { ASOF ~ LEFT }),
+        value(JoinOperator::AsofJoin, rule! { ASOF }),
         value(JoinOperator::LeftSemi, rule! { LEFT? ~ SEMI }),
         value(JoinOperator::RightSemi, rule! { RIGHT ~ SEMI }),
         value(JoinOperator::LeftAnti, rule! { LEFT? ~ ANTI }),
@@ -516,6 +524,39 @@ pub fn table_function_param(i: Input) -> IResult<TableFunctionParam> {
     )(i)
 }
 
+// {SAMPLE | TABLESAMPLE} [ROW | BLOCK] (n [PERCENT | ROWS]) [REPEATABLE (seed)]
+pub fn table_sample(i: Input) -> IResult<SampleConfig> {
+    let level = alt((
+        value(SampleLevel::Row, rule! { ROW }),
+        value(SampleLevel::Block, rule! { BLOCK }),
+    ));
+    let percentage = map_res(
+        rule! {
+            ( LiteralInteger | LiteralFloat ) ~ PERCENT?
+        },
+        |(token, _)| Ok(SampleCriteria::Percentage(fast_float::parse(token.text())?)),
+    );
+    let rows = map(
+        rule! {
+            #literal_u64 ~ ROWS
+        },
+        |(rows, _)| SampleCriteria::Rows(rows),
+    );
+
+    map(
+        rule! {
+            ( SAMPLE | TABLESAMPLE ) ~ #level?
+            ~ "(" ~ ^( #rows | #percentage ) ~ ^")"
+            ~ ( REPEATABLE ~ ^"(" ~ ^#literal_u64 ~ ^")" )?
+        },
+        |(_, level, _, criteria, _, seed)| SampleConfig {
+            level: level.unwrap_or(SampleLevel::Row),
+            criteria,
+            seed: seed.map(|(_, _, seed, _)| seed),
+        },
+    )(i)
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TableReferenceElement {
     Table {
@@ -526,15 +567,18 @@ pub enum TableReferenceElement {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<SampleConfig>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
+        lateral: bool,
         name: Identifier,
         params: Vec<TableFunctionParam>,
         alias: Option<TableAlias>,
     },
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
+        lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>,
     },
@@ -545,6 +589,8 @@ pub enum TableReferenceElement {
     },
     // ON expr | USING (ident, ...)
     JoinCondition(JoinCondition),
+    // MATCH_CONDITION (expr)
+    JoinMatchCondition(Expr),
     Group(TableReference),
     Stage {
         location: FileLocation,
@@ -578,9 +624,9 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_table = map(
         rule! {
-            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)? ~ #table_alias? ~ #pivot? ~ #unpivot?
+            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)? ~ #table_alias? ~ #table_sample? ~ #pivot? ~ #unpivot?
         },
-        |((catalog, database, table), travel_point_opt, alias, pivot, unpivot)| {
+        |((catalog, database, table), travel_point_opt, alias, sample, pivot, unpivot)| {
             TableReferenceElement::Table {
                 catalog,
                 database,
@@ -589,6 +635,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 travel_point: travel_point_opt.map(|p| p.1),
                 pivot: pivot.map(Box::new),
                 unpivot: unpivot.map(Box::new),
+                sample,
             }
         },
     );
@@ -613,11 +660,18 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
         |(_, _, idents, _)| TableReferenceElement::JoinCondition(JoinCondition::Using(idents)),
     );
+    let join_match_condition = map(
+        rule! {
+            MATCH_CONDITION ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableReferenceElement::JoinMatchCondition(expr),
+    );
     let table_function = map(
         rule! {
-            #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
+            LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
         },
-        |(name, _, params, _, alias)| TableReferenceElement::TableFunction {
+        |(lateral, name, _, params, _, alias)| TableReferenceElement::TableFunction {
+            lateral: lateral.is_some(),
             name,
             params,
             alias,
@@ -625,9 +679,10 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let subquery = map(
         rule! {
-            "(" ~ #query ~ ")" ~ #table_alias?
+            LATERAL? ~ "(" ~ #query ~ ")" ~ #table_alias?
         },
-        |(_, subquery, _, alias)| TableReferenceElement::Subquery {
+        |(lateral, _, subquery, _, alias)| TableReferenceElement::Subquery {
+            lateral: lateral.is_some(),
             subquery: Box::new(subquery),
             alias,
         },
@@ -664,6 +719,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         | #join
         | #join_condition_on
         | #join_condition_using
+        | #join_match_condition
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
 }
@@ -680,7 +736,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
     fn query(&mut self, input: &Self::Input) -> Result<Affix, &'static str> {
         let affix = match &input.elem {
             TableReferenceElement::Join { .. } => Affix::Infix(Precedence(10), Associativity::Left),
-            TableReferenceElement::JoinCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::JoinCondition(..)
+            | TableReferenceElement::JoinMatchCondition(..) => Affix::Postfix(Precedence(5)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -697,6 +754,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => TableReference::Table {
                 span: transform_span(input.span.0),
                 catalog,
@@ -706,8 +764,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             },
             TableReferenceElement::TableFunction {
+                lateral,
                 name,
                 params,
                 alias,
@@ -728,14 +788,20 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     .collect();
                 TableReference::TableFunction {
                     span: transform_span(input.span.0),
+                    lateral,
                     name,
                     params: normal_params,
                     named_params,
                     alias,
                 }
             }
-            TableReferenceElement::Subquery { subquery, alias } => TableReference::Subquery {
+            TableReferenceElement::Subquery {
+                lateral,
+                subquery,
+                alias,
+            } => TableReference::Subquery {
                 span: transform_span(input.span.0),
+                lateral,
                 subquery,
                 alias,
             },
@@ -775,6 +841,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     join: Join {
                         op,
                         condition,
+                        match_condition: None,
                         left: Box::new(lhs),
                         right: Box::new(rhs),
                     },
@@ -813,6 +880,33 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 },
                 _ => Err("join condition must apply to a join"),
             },
+            TableReferenceElement::JoinMatchCondition(expr) => match &mut lhs {
+                TableReference::Join {
+                    join:
+                        Join {
+                            op: JoinOperator::AsofJoin | JoinOperator::LeftAsofJoin,
+                            condition: JoinCondition::None,
+                            match_condition,
+                            ..
+                        },
+                    ..
+                } => match *match_condition {
+                    None => {
+                        *match_condition = Some(Box::new(expr));
+                        Ok(lhs)
+                    }
+                    Some(_) => Err("match condition already set"),
+                },
+                TableReference::Join {
+                    join:
+                        Join {
+                            op: JoinOperator::AsofJoin | JoinOperator::LeftAsofJoin,
+                            ..
+                        },
+                    ..
+                } => Err("MATCH_CONDITION must precede the join condition"),
+                _ => Err("MATCH_CONDITION must apply to an ASOF join"),
+            },
             _ => unreachable!(),
         }
     }
@@ -923,6 +1017,34 @@ pub fn window_spec_ident(i: Input) -> IResult<Window> {
     ))(i)
 }
 
+/// `IGNORE NULLS` or `RESPECT NULLS`, returns whether nulls are ignored.
+pub fn null_treatment(i: Input) -> IResult<bool> {
+    alt((
+        value(true, rule! { IGNORE ~ NULLS }),
+        value(false, rule! { RESPECT ~ NULLS }),
+    ))(i)
+}
+
+/// `WITHIN GROUP (ORDER BY ...)` of ordered-set aggregate functions.
+pub fn within_group(i: Input) -> IResult<Vec<OrderByExpr>> {
+    map(
+        rule! {
+            WITHIN ~ GROUP ~ "(" ~ ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) ~ ^")"
+        },
+        |(_, _, _, _, _, order_by, _)| order_by,
+    )(i)
+}
+
+/// `FILTER (WHERE ...)` of aggregate functions.
+pub fn aggregate_filter(i: Input) -> IResult<Expr> {
+    map(
+        rule! {
+            FILTER ~ "(" ~ ^WHERE ~ ^#expr ~ ^")"
+        },
+        |(_, _, _, filter, _)| filter,
+    )(i)
+}
+
 pub fn window_clause(i: Input) -> IResult<WindowDefinition> {
     map(
         rule! {
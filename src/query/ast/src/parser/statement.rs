@@ -536,7 +536,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let create_table = map(
         rule! {
-            CREATE ~ TRANSIENT? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            CREATE ~ ( TRANSIENT | TEMPORARY | TEMP )? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #dot_separated_idents_1_to_3
             ~ #create_table_source?
             ~ ( #engine )?
@@ -547,7 +547,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
         |(
             _,
-            opt_transient,
+            opt_table_type,
             _,
             opt_if_not_exists,
             (catalog, database, table),
@@ -571,7 +571,8 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
-                transient: opt_transient.is_some(),
+                transient: opt_table_type.map(|t| t.kind) == Some(TRANSIENT),
+                temporary: matches!(opt_table_type.map(|t| t.kind), Some(TEMPORARY | TEMP)),
             })
         },
     );
@@ -766,6 +767,47 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
 
     let create_index = map(
         rule! {
@@ -808,6 +850,73 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_vector_index = map(
+        rule! {
+            CREATE ~ VECTOR ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#ident ~ ^")"
+            ~ USING ~ #vector_index_type
+            ~ ( WITH ~ ^"(" ~ ^#set_table_option ~ ^")" )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            column,
+            _,
+            _,
+            index_type,
+            opt_options,
+        )| {
+            Statement::CreateVectorIndex(CreateVectorIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                column,
+                index_type,
+                options: opt_options
+                    .map(|(_, _, options, _)| options)
+                    .unwrap_or_default(),
+            })
+        },
+    );
+
+    let drop_vector_index = map(
+        rule! {
+            DROP ~ VECTOR ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index, _, (catalog, database, table))| {
+            Statement::DropVectorIndex(DropVectorIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
+    let refresh_vector_index = map(
+        rule! {
+            REFRESH ~ VECTOR ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, index, _, (catalog, database, table))| {
+            Statement::RefreshVectorIndex(RefreshVectorIndexStmt {
+                index,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let create_virtual_column = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMN ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #dot_separated_idents_1_to_3
@@ -888,15 +997,17 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! {
             ALTER ~ USER ~ ( #map(rule! { USER ~ "(" ~ ")" }, |_| None) | #map(user_identity, Some) )
             ~ ( IDENTIFIED ~ ( WITH ~ ^#auth_type )? ~ ( BY ~ ^#literal_string )? )?
+            ~ ( #comma_separated_list1(public_key_option) )?
             ~ ( WITH ~ ^#comma_separated_list1(user_option) )?
         },
-        |(_, _, user, opt_auth_option, opt_user_option)| {
+        |(_, _, user, opt_auth_option, opt_public_key_options, opt_user_option)| {
             Statement::AlterUser(AlterUserStmt {
                 user,
                 auth_option: opt_auth_option.map(|(_, opt_auth_type, opt_password)| AuthOption {
                     auth_type: opt_auth_type.map(|(_, auth_type)| auth_type),
                     password: opt_password.map(|(_, password)| password),
                 }),
+                public_key_options: opt_public_key_options.unwrap_or_default(),
                 user_options: opt_user_option
                     .map(|(_, user_options)| user_options)
                     .unwrap_or_default(),
@@ -1337,6 +1448,35 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( START ~ WITH? ~ Eq? ~ ^#literal_u64 )?
+            ~ ( INCREMENT ~ BY? ~ Eq? ~ ^#literal_u64 )?
+            ~ ( COMMENT ~ Eq? ~ ^#literal_string )?
+        },
+        |(_, _, opt_if_not_exists, sequence, opt_start, opt_increment, opt_comment)| {
+            Statement::CreateSequence(CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                sequence,
+                start: opt_start.map(|(_, _, _, start)| start),
+                increment: opt_increment.map(|(_, _, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, sequence)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                sequence,
+            })
+        },
+    );
+
     let create_network_policy = map(
         rule! {
             CREATE ~ NETWORK ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
@@ -1438,6 +1578,85 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! { SHOW ~ NETWORK ~ POLICIES },
     );
 
+    let create_task = map_res(
+        rule! {
+            CREATE ~ TASK ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( WAREHOUSE ~ ^Eq ~ ^#literal_string )?
+            ~ ( SCHEDULE ~ ^Eq ~ ^#task_schedule )?
+            ~ ( AFTER ~ ^#comma_separated_list1(ident) )?
+            ~ ( COMMENT ~ ^Eq ~ ^#literal_string )?
+            ~ AS ~ #rest_str
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            opt_warehouse,
+            opt_schedule,
+            opt_after,
+            opt_comment,
+            _,
+            (sql, _),
+        )| {
+            let sql = sql.trim().trim_end_matches(';').trim_end();
+            if sql.is_empty() {
+                return Err(ErrorKind::Other("the SQL of task should not be empty"));
+            }
+            Ok(Statement::CreateTask(CreateTaskStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                warehouse: opt_warehouse.map(|(_, _, warehouse)| warehouse),
+                schedule: opt_schedule.map(|(_, _, schedule)| schedule),
+                after: opt_after
+                    .map(|(_, after)| after.iter().map(|t| t.to_string()).collect())
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                sql: sql.to_string(),
+            }))
+        },
+    );
+    let alter_task = map(
+        rule! {
+            ALTER ~ TASK ~ ( IF ~ ^EXISTS )? ~ #ident ~ ( RESUME | SUSPEND )
+        },
+        |(_, _, opt_if_exists, name, action)| {
+            Statement::AlterTask(AlterTaskStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                action: match action.kind {
+                    TokenKind::RESUME => AlterTaskAction::Resume,
+                    _ => AlterTaskAction::Suspend,
+                },
+            })
+        },
+    );
+    let drop_task = map(
+        rule! {
+            DROP ~ TASK ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropTask(DropTaskStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let execute_task = map(
+        rule! {
+            EXECUTE ~ TASK ~ #ident
+        },
+        |(_, _, name)| {
+            Statement::ExecuteTask(ExecuteTaskStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let abort = value(Statement::Abort, rule! { ABORT | ROLLBACK });
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1462,6 +1681,19 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
         ),
+        // transaction
+        rule!(
+            #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT`"
+            | #abort : "`ABORT | ROLLBACK`"
+        ),
+        // task
+        rule!(
+            #create_task : "`CREATE TASK [IF NOT EXISTS] <name> [WAREHOUSE = '<warehouse>'] [SCHEDULE = { <num> MINUTE | <num> SECOND | '<cron>' }] [AFTER <task>, ...] [COMMENT = '<string_literal>'] AS <sql>`"
+            | #alter_task : "`ALTER TASK [IF EXISTS] <name> { RESUME | SUSPEND }`"
+            | #drop_task : "`DROP TASK [IF EXISTS] <name>`"
+            | #execute_task : "`EXECUTE TASK <name>`"
+        ),
         // network policy
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
@@ -1505,11 +1737,17 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
         ),
         rule!(
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_vector_index: "`CREATE VECTOR INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>) USING {HNSW | IVF_PQ} [WITH (<option> = <value>, ...)]`"
+            | #drop_vector_index: "`DROP VECTOR INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_vector_index: "`REFRESH VECTOR INDEX <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1520,7 +1758,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #show_users : "`SHOW USERS`"
             | #create_user : "`CREATE USER [IF NOT EXISTS] '<username>'@'hostname' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
-            | #alter_user : "`ALTER USER ('<username>'@'hostname' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ...]`"
+            | #alter_user : "`ALTER USER ('<username>'@'hostname' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [(SET | UNSET) RSA_PUBLIC_KEY[_2] [= '<public_key>'], ...] [WITH <user_option>, ...]`"
             | #drop_user : "`DROP USER [IF EXISTS] '<username>'@'hostname'`"
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
@@ -1571,6 +1809,11 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_data_mask_policy: "`DROP MASKING POLICY [IF EXISTS] mask_name`"
             | #describe_data_mask_policy: "`DESC MASKING POLICY mask_name`"
         ),
+        // sequence
+        rule!(
+            #create_sequence: "`CREATE SEQUENCE [IF NOT EXISTS] <sequence> [START [WITH] <start>] [INCREMENT [BY] <step>] [COMMENT = '<string_literal>']`"
+            | #drop_sequence: "`DROP SEQUENCE [IF EXISTS] <sequence>`"
+        ),
         // share
         rule!(
             #create_share_endpoint: "`CREATE SHARE ENDPOINT [IF NOT EXISTS] <endpoint_name> URL=endpoint_location tenant=tenant_name ARGS=(arg=..) [ COMMENT = '<string_literal>' ]`"
@@ -1715,6 +1958,20 @@ pub fn hint(i: Input) -> IResult<Hint> {
     rule!(#hint|#invalid_hint)(i)
 }
 
+pub fn task_schedule(i: Input) -> IResult<TaskSchedule> {
+    let interval = map(
+        rule! {
+            #literal_u64 ~ ( MINUTE | SECOND )
+        },
+        |(num, unit)| match unit.kind {
+            TokenKind::MINUTE => TaskSchedule::Interval(num * 60),
+            _ => TaskSchedule::Interval(num),
+        },
+    );
+    let cron = map(literal_string, TaskSchedule::Cron);
+    rule!(#interval | #cron)(i)
+}
+
 pub fn rest_str(i: Input) -> IResult<(String, usize)> {
     // It's safe to unwrap because input must contain EOI.
     let first_token = i.0.first().unwrap();
@@ -1735,12 +1992,27 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        AutoIncrement { start: u64, step: u64 },
     }
 
     let nullable = alt((
         value(ColumnConstraint::Nullable(true), rule! { NULL }),
         value(ColumnConstraint::Nullable(false), rule! { NOT ~ ^NULL }),
     ));
+    let auto_increment_range = alt((
+        map(
+            rule! {
+                "(" ~ ^#literal_u64 ~ ^"," ~ ^#literal_u64 ~ ^")"
+            },
+            |(_, start, _, step, _)| (start, step),
+        ),
+        map(
+            rule! {
+                START ~ ^#literal_u64 ~ ^INCREMENT ~ ^#literal_u64
+            },
+            |(_, start, _, step)| (start, step),
+        ),
+    ));
     let expr = alt((
         map(
             rule! {
@@ -1760,6 +2032,15 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             },
             |(_, _, _, stored_expr, _, _)| ColumnConstraint::StoredExpr(Box::new(stored_expr)),
         ),
+        map(
+            rule! {
+                ( AUTOINCREMENT | IDENTITY ) ~ #auto_increment_range?
+            },
+            |(_, opt_range)| {
+                let (start, step) = opt_range.unwrap_or((1, 1));
+                ColumnConstraint::AutoIncrement { start, step }
+            },
+        ),
     ));
 
     let comment = map(
@@ -1775,7 +2056,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ~ #type_name
             ~ ( #nullable | #expr )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [AUTOINCREMENT [(<start>, <step>)]] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let def = ColumnDefinition {
@@ -1813,6 +2094,9 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::AutoIncrement { start, step } => {
+                def.expr = Some(ColumnExpr::AutoIncrement { start, step })
+            }
         }
     }
 
@@ -2483,6 +2767,13 @@ pub fn set_table_option(i: Input) -> IResult<BTreeMap<String, String>> {
     )(i)
 }
 
+pub fn vector_index_type(i: Input) -> IResult<VectorIndexType> {
+    alt((
+        value(VectorIndexType::Hnsw, rule! { HNSW }),
+        value(VectorIndexType::IvfPq, rule! { IVF_PQ }),
+    ))(i)
+}
+
 pub fn engine(i: Input) -> IResult<Engine> {
     let engine = alt((
         value(Engine::Null, rule! { NULL }),
@@ -2580,6 +2871,33 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
     ))(i)
 }
 
+pub fn public_key_option(i: Input) -> IResult<PublicKeyOption> {
+    let set_public_key = map(
+        rule! {
+            SET ~ RSA_PUBLIC_KEY ~ "=" ~ #literal_string
+        },
+        |(_, _, _, key)| PublicKeyOption::Set { slot: 1, key },
+    );
+    let set_public_key_2 = map(
+        rule! {
+            SET ~ RSA_PUBLIC_KEY_2 ~ "=" ~ #literal_string
+        },
+        |(_, _, _, key)| PublicKeyOption::Set { slot: 2, key },
+    );
+    alt((
+        set_public_key,
+        set_public_key_2,
+        value(
+            PublicKeyOption::Unset { slot: 1 },
+            rule! { UNSET ~ RSA_PUBLIC_KEY },
+        ),
+        value(
+            PublicKeyOption::Unset { slot: 2 },
+            rule! { UNSET ~ RSA_PUBLIC_KEY_2 },
+        ),
+    ))(i)
+}
+
 pub fn user_identity(i: Input) -> IResult<UserIdentity> {
     map(
         rule! {
@@ -2598,6 +2916,7 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
         value(AuthType::Sha256Password, rule! { SHA256_PASSWORD }),
         value(AuthType::DoubleSha1Password, rule! { DOUBLE_SHA1_PASSWORD }),
         value(AuthType::JWT, rule! { JWT }),
+        value(AuthType::KeyPair, rule! { KEY_PAIR }),
     ))(i)
 }
 
@@ -2695,6 +3014,7 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
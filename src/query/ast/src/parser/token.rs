@@ -303,6 +303,8 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -323,10 +325,16 @@ pub enum TokenKind {
     AT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
+    #[token("ABORT", ignore(ascii_case))]
+    ABORT,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -335,6 +343,8 @@ pub enum TokenKind {
     BINARY,
     #[token("BITMAP", ignore(ascii_case))]
     BITMAP,
+    #[token("BLOCK", ignore(ascii_case))]
+    BLOCK,
     #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
     BLOCKED_IP_LIST,
     #[token("BOOL", ignore(ascii_case))]
@@ -367,6 +377,8 @@ pub enum TokenKind {
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
@@ -479,6 +491,8 @@ pub enum TokenKind {
     ERROR_ON_COLUMN_COUNT_MISMATCH,
     #[token("ESCAPE", ignore(ascii_case))]
     ESCAPE,
+    #[token("EXECUTE", ignore(ascii_case))]
+    EXECUTE,
     #[token("EXISTS", ignore(ascii_case))]
     EXISTS,
     #[token("EXPLAIN", ignore(ascii_case))]
@@ -501,6 +515,8 @@ pub enum TokenKind {
     FILE_FORMAT,
     #[token("FILE", ignore(ascii_case))]
     FILE,
+    #[token("FILTER", ignore(ascii_case))]
+    FILTER,
     #[token("FILES", ignore(ascii_case))]
     FILES,
     #[token("FINAL", ignore(ascii_case))]
@@ -543,6 +559,8 @@ pub enum TokenKind {
     FUSE,
     #[token("GENERATED", ignore(ascii_case))]
     GENERATED,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
@@ -557,6 +575,8 @@ pub enum TokenKind {
     HISTORY,
     #[token("HIVE", ignore(ascii_case))]
     HIVE,
+    #[token("HNSW", ignore(ascii_case))]
+    HNSW,
     #[token("HOUR", ignore(ascii_case))]
     HOUR,
     #[token("HOURS", ignore(ascii_case))]
@@ -567,12 +587,18 @@ pub enum TokenKind {
     INTERSECT,
     #[token("IDENTIFIED", ignore(ascii_case))]
     IDENTIFIED,
+    #[token("IDENTITY", ignore(ascii_case))]
+    IDENTITY,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("IGNORE", ignore(ascii_case))]
+    IGNORE,
     #[token("IN", ignore(ascii_case))]
     IN,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -599,6 +625,8 @@ pub enum TokenKind {
     ISODOW,
     #[token("ISOYEAR", ignore(ascii_case))]
     ISOYEAR,
+    #[token("IVF_PQ", ignore(ascii_case))]
+    IVF_PQ,
     #[token("JOIN", ignore(ascii_case))]
     JOIN,
     #[token("JSON", ignore(ascii_case))]
@@ -609,6 +637,8 @@ pub enum TokenKind {
     JWT,
     #[token("KEY", ignore(ascii_case))]
     KEY,
+    #[token("KEY_PAIR", ignore(ascii_case))]
+    KEY_PAIR,
     #[token("KILL", ignore(ascii_case))]
     KILL,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
@@ -708,6 +738,8 @@ pub enum TokenKind {
     PARQUET,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PERCENT", ignore(ascii_case))]
+    PERCENT,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
@@ -722,6 +754,8 @@ pub enum TokenKind {
     PROCESSLIST,
     #[token("PURGE", ignore(ascii_case))]
     PURGE,
+    #[token("QUALIFY", ignore(ascii_case))]
+    QUALIFY,
     #[token("QUARTER", ignore(ascii_case))]
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
@@ -744,12 +778,18 @@ pub enum TokenKind {
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
+    #[token("REPEATABLE", ignore(ascii_case))]
+    REPEATABLE,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("RESPECT", ignore(ascii_case))]
+    RESPECT,
     #[token("MERGE", ignore(ascii_case))]
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
+    #[token("MATCH_CONDITION", ignore(ascii_case))]
+    MATCH_CONDITION,
     #[token("UNMATCHED", ignore(ascii_case))]
     UNMATCHED,
     #[token("ROW", ignore(ascii_case))]
@@ -762,6 +802,12 @@ pub enum TokenKind {
     GRANT,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
+    #[token("RSA_PUBLIC_KEY", ignore(ascii_case))]
+    RSA_PUBLIC_KEY,
+    #[token("RSA_PUBLIC_KEY_2", ignore(ascii_case))]
+    RSA_PUBLIC_KEY_2,
     #[token("PRECEDING", ignore(ascii_case))]
     PRECEDING,
     #[token("PRECISION", ignore(ascii_case))]
@@ -772,6 +818,8 @@ pub enum TokenKind {
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RESUME", ignore(ascii_case))]
+    RESUME,
     #[token("RETAIN", ignore(ascii_case))]
     RETAIN,
     #[token("REVOKE", ignore(ascii_case))]
@@ -792,6 +840,10 @@ pub enum TokenKind {
     RLIKE,
     #[token("RAW", ignore(ascii_case))]
     RAW,
+    #[token("SAMPLE", ignore(ascii_case))]
+    SAMPLE,
+    #[token("SCHEDULE", ignore(ascii_case))]
+    SCHEDULE,
     #[token("SCHEMA", ignore(ascii_case))]
     SCHEMA,
     #[token("SCHEMAS", ignore(ascii_case))]
@@ -800,6 +852,8 @@ pub enum TokenKind {
     SECOND,
     #[token("SELECT", ignore(ascii_case))]
     SELECT,
+    #[token("SEQUENCE", ignore(ascii_case))]
+    SEQUENCE,
     #[token("PIVOT", ignore(ascii_case))]
     PIVOT,
     #[token("UNPIVOT", ignore(ascii_case))]
@@ -840,6 +894,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -852,6 +908,8 @@ pub enum TokenKind {
     SHARE,
     #[token("SHARES", ignore(ascii_case))]
     SHARES,
+    #[token("SUSPEND", ignore(ascii_case))]
+    SUSPEND,
     #[token("SUPER", ignore(ascii_case))]
     SUPER,
     #[token("STATUS", ignore(ascii_case))]
@@ -874,6 +932,14 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TABLESAMPLE", ignore(ascii_case))]
+    TABLESAMPLE,
+    #[token("TASK", ignore(ascii_case))]
+    TASK,
+    #[token("TEMP", ignore(ascii_case))]
+    TEMP,
+    #[token("TEMPORARY", ignore(ascii_case))]
+    TEMPORARY,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("TENANTSETTING", ignore(ascii_case))]
@@ -884,8 +950,12 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIME", ignore(ascii_case))]
+    TIME,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
+    #[token("TIMESTAMP_TZ", ignore(ascii_case))]
+    TIMESTAMP_TZ,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
     TIMEZONE_HOUR,
     #[token("TIMEZONE_MINUTE", ignore(ascii_case))]
@@ -900,6 +970,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -954,10 +1026,14 @@ pub enum TokenKind {
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
     VIRTUAL,
+    #[token("WAREHOUSE", ignore(ascii_case))]
+    WAREHOUSE,
     #[token("WEEK", ignore(ascii_case))]
     WEEK,
     #[token("WHEN", ignore(ascii_case))]
@@ -968,6 +1044,8 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WITHIN", ignore(ascii_case))]
+    WITHIN,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -976,6 +1054,8 @@ pub enum TokenKind {
     XZ,
     #[token("YEAR", ignore(ascii_case))]
     YEAR,
+    #[token("ZONE", ignore(ascii_case))]
+    ZONE,
     #[token("ZSTD", ignore(ascii_case))]
     ZSTD,
     #[token("NULLIF", ignore(ascii_case))]
@@ -992,6 +1072,8 @@ pub enum TokenKind {
     FIRST,
     #[token("LAST", ignore(ascii_case))]
     LAST,
+    #[token("LATERAL", ignore(ascii_case))]
+    LATERAL,
     #[token("IGNORE_RESULT", ignore(ascii_case))]
     IGNORE_RESULT,
     #[token("GROUPING", ignore(ascii_case))]
@@ -1137,7 +1219,7 @@ impl TokenKind {
             | TokenKind::INT
             | TokenKind::INTEGER
             | TokenKind::INTERVAL
-            // | TokenKind::LATERAL
+            | TokenKind::LATERAL
             | TokenKind::LEADING
             // | TokenKind::LEAST
             // | TokenKind::LOCALTIME
@@ -1242,6 +1324,7 @@ impl TokenKind {
             | TokenKind::ANY
             | TokenKind::ASC
             | TokenKind::ANTI
+            | TokenKind::ASOF
             // | TokenKind::ASYMMETRIC
             // | TokenKind::AUTHORIZATION
             // | TokenKind::BINARY
@@ -1278,12 +1361,13 @@ impl TokenKind {
             | TokenKind::INNER
             | TokenKind::IS
             | TokenKind::JOIN
-            // | TokenKind::LATERAL
+            | TokenKind::LATERAL
             | TokenKind::LEADING
             | TokenKind::LEFT
             | TokenKind::LIKE
             // | TokenKind::LOCALTIME
             // | TokenKind::LOCALTIMESTAMP
+            | TokenKind::MATCH_CONDITION
             | TokenKind::NATURAL
             | TokenKind::NOT
             | TokenKind::NULL
@@ -1303,7 +1387,7 @@ impl TokenKind {
             | TokenKind::SEMI
             // | TokenKind::SYMMETRIC
             // | TokenKind::TABLE
-            // | TokenKind::TABLESAMPLE
+            | TokenKind::TABLESAMPLE
             | TokenKind::THEN
             | TokenKind::TRAILING
             | TokenKind::TRUE
@@ -1337,6 +1421,8 @@ impl TokenKind {
             | TokenKind::ORDER
             | TokenKind::OVER
             | TokenKind::PARTITION
+            | TokenKind::QUALIFY
+            | TokenKind::SAMPLE
             | TokenKind::ROWS
             | TokenKind::RANGE
             // | TokenKind::OVERLAPS
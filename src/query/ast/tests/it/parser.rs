@@ -78,6 +78,11 @@ fn test_statement() {
         r#"show columns in t from ctl.db"#,
         r#"show full columns from t from db like 'id%'"#,
         r#"show processlist;"#,
+        r#"begin;"#,
+        r#"begin transaction;"#,
+        r#"commit;"#,
+        r#"rollback;"#,
+        r#"abort;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
@@ -93,6 +98,10 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create temporary table t (a int) engine = memory;"#,
+        r#"create table t (id int autoincrement, b bigint identity (10, 5));"#,
+        r#"create sequence if not exists seq start with 10 increment by 5 comment = 'order ids';"#,
+        r#"drop sequence seq;"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
@@ -115,6 +124,12 @@ fn test_statement() {
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create materialized view if not exists db.mv as select a, count(*) as c from t group by a;"#,
+        r#"refresh materialized view mv;"#,
+        r#"drop materialized view if exists db.mv;"#,
+        r#"create vector index if not exists idx on db.t(emb) using hnsw with (m = 16, distance = 'l2');"#,
+        r#"refresh vector index idx on t;"#,
+        r#"drop vector index if exists idx on db.t;"#,
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
@@ -130,6 +145,8 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1, TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 SET RSA_PUBLIC_KEY = 'key1', UNSET RSA_PUBLIC_KEY_2;"#,
+        r#"ALTER USER u1 SET RSA_PUBLIC_KEY_2 = 'it''s\nkey';"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
         r#"DROP database if exists db1;"#,
@@ -461,6 +478,12 @@ fn test_statement() {
         r#"REFRESH VIRTUAL COLUMN FOR t"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        r#"CREATE TASK IF NOT EXISTS t1 WAREHOUSE = 'wh1' SCHEDULE = '0 * * * *' COMMENT = 'hourly' AS INSERT INTO t2 SELECT * FROM t1;"#,
+        r#"CREATE TASK t2 SCHEDULE = 5 MINUTE AFTER t1, t0 AS DELETE FROM t3 WHERE a > 1"#,
+        r#"ALTER TASK t1 RESUME"#,
+        r#"ALTER TASK IF EXISTS t1 SUSPEND"#,
+        r#"EXECUTE TASK t1"#,
+        r#"DROP TASK IF EXISTS t1"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- 96477300355	31379974136	3.074486292973661\nselect 34343",
         "-- xxxxx\n  select 34343;",
@@ -571,6 +594,8 @@ fn test_query() {
         r#"select * from customer inner join orders on a = b limit 2 offset 3"#,
         r#"select * from customer natural full join orders"#,
         r#"select * from customer natural join orders left outer join detail using (id)"#,
+        r#"select * from t1 asof join t2 match_condition (t1.a >= t2.a) on t1.b = t2.b"#,
+        r#"select * from t1 asof left join t2 match_condition (t1.a < t2.a) using (b)"#,
         r#"with t2(tt) as (select a from t) select t2.tt from t2  where t2.tt > 1"#,
         r#"with t2(tt) as materialized (select a from t) select t2.tt from t2  where t2.tt > 1"#,
         r#"with t2 as (select a from t) select t2.a from t2  where t2.a > 1"#,
@@ -608,6 +633,9 @@ fn test_query() {
         r#"select * from range(1, 2)"#,
         r#"select sum(a) over w from customer window w as (partition by a order by b)"#,
         r#"select a, sum(a) over w, sum(a) over w1, sum(a) over w2 from t1 window w as (partition by a), w2 as (w1 rows current row), w1 as (w order by a) order by a"#,
+        r#"select a from t qualify row_number() over w = 1 window w as (order by b)"#,
+        r#"select a from t sample block (10.5 percent) repeatable (42)"#,
+        r#"select * from t, lateral unnest(t.a) as u"#,
         r#"SELECT * FROM ((SELECT * FROM xyu ORDER BY x, y)) AS xyu"#,
         r#"SELECT * FROM (VALUES(1,1),(2,null),(null,5)) AS t(a,b)"#,
         r#"VALUES(1,'a'),(2,'b'),(null,'c') order by col0 limit 2"#,
@@ -626,6 +654,7 @@ fn test_query_error() {
         r#"select * from customer join where a = b"#,
         r#"select * from join customer"#,
         r#"select * from customer natural inner join orders on a = b"#,
+        r#"select * from t1 join t2 match_condition (t1.a >= t2.a)"#,
         r#"select * order a"#,
         r#"select * order"#,
         r#"select number + 5 as a, cast(number as float(255))"#,
@@ -719,6 +748,9 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS CURRENT ROW)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
+        r#"LAST_VALUE(salary IGNORE NULLS) OVER (ORDER BY hire_date)"#,
+        r#"COUNT(*) FILTER (WHERE status = 'ok')"#,
+        r#"percentile_cont(0.9) WITHIN GROUP (ORDER BY latency)"#,
         r#"ARRAY_APPLY([1,2,3], x -> x + 1)"#,
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
     ];
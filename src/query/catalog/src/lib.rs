@@ -25,3 +25,5 @@ pub mod table;
 pub mod table_args;
 pub mod table_context;
 pub mod table_function;
+pub mod temp_table;
+pub mod txn;
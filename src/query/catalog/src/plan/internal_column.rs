@@ -29,10 +29,13 @@ use common_expression::TableDataType;
 use common_expression::Value;
 use common_expression::BLOCK_NAME_COLUMN_ID;
 use common_expression::ROW_ID_COLUMN_ID;
+use common_expression::SAMPLE_HASH_COLUMN_ID;
 use common_expression::SEGMENT_NAME_COLUMN_ID;
 use common_expression::SNAPSHOT_NAME_COLUMN_ID;
 use storages_common_table_meta::meta::NUM_BLOCK_ID_BITS;
 
+use crate::plan::SampleInfo;
+
 // Segment and Block id Bits when generate internal column `_row_id`
 // Assumes that the max block count of a segment is 2 ^ NUM_BLOCK_ID_BITS
 // Since `DEFAULT_BLOCK_PER_SEGMENT` is 1000, so `block_id` 10 bits is enough.
@@ -90,6 +93,9 @@ pub struct InternalColumnMeta {
     pub snapshot_location: Option<String>,
     /// The row offsets in the block.
     pub offsets: Option<Vec<usize>>,
+    /// The seed of row level sampling mixed with the block location,
+    /// see [`SampleInfo::block_seed`].
+    pub sample_block_seed: Option<u64>,
 }
 
 #[typetag::serde(name = "internal_column_meta")]
@@ -117,6 +123,8 @@ pub enum InternalColumnType {
     BlockName,
     SegmentName,
     SnapshotName,
+    /// The hash a row is sampled by, only used to bind `SAMPLE (n ROWS)`.
+    SampleHash,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -143,6 +151,7 @@ impl InternalColumn {
             InternalColumnType::BlockName => TableDataType::String,
             InternalColumnType::SegmentName => TableDataType::String,
             InternalColumnType::SnapshotName => TableDataType::String,
+            InternalColumnType::SampleHash => TableDataType::Number(NumberDataType::UInt64),
         }
     }
 
@@ -161,6 +170,7 @@ impl InternalColumn {
             InternalColumnType::BlockName => BLOCK_NAME_COLUMN_ID,
             InternalColumnType::SegmentName => SEGMENT_NAME_COLUMN_ID,
             InternalColumnType::SnapshotName => SNAPSHOT_NAME_COLUMN_ID,
+            InternalColumnType::SampleHash => SAMPLE_HASH_COLUMN_ID,
         }
     }
 
@@ -222,6 +232,23 @@ impl InternalColumn {
                     Value::Scalar(Scalar::String(builder.build_scalar())),
                 )
             }
+            InternalColumnType::SampleHash => {
+                let block_seed = meta.sample_block_seed.unwrap_or_default();
+                let hashes: Vec<u64> = match &meta.offsets {
+                    Some(offsets) => offsets
+                        .iter()
+                        .map(|i| SampleInfo::row_hash(block_seed, *i))
+                        .collect(),
+                    None => (0..num_rows)
+                        .map(|i| SampleInfo::row_hash(block_seed, i))
+                        .collect(),
+                };
+
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Column(UInt64Type::from_data(hashes)),
+                )
+            }
         }
     }
 }
@@ -15,6 +15,8 @@
 use std::fmt::Debug;
 
 use common_expression::types::DataType;
+use common_expression::types::F32;
+use common_expression::types::F64;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
@@ -67,6 +69,85 @@ pub struct PrewhereInfo {
     pub virtual_columns: Option<Vec<VirtualColumnInfo>>,
 }
 
+/// Granularity of table sampling.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SampleLevel {
+    Row,
+    Block,
+}
+
+/// Information about table sampling, i.e. `SAMPLE` / `TABLESAMPLE`.
+///
+/// Whether a row or a block is kept only depends on `seed` and its position
+/// (block location and row offset), so scanning unchanged data twice with the
+/// same seed yields the same sample regardless of how the scan is scheduled.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SampleInfo {
+    pub level: SampleLevel,
+    /// Probability of keeping a row (or a block), in the range `[0, 1]`.
+    pub probability: F64,
+    pub seed: u64,
+}
+
+impl SampleInfo {
+    /// Mix `seed` with the location of a block, the result is used to sample
+    /// the block itself or the rows in it.
+    pub fn block_seed(&self, location: &str) -> u64 {
+        // FNV-1a, stable across versions and platforms.
+        let mut hash = self.seed ^ 0xcbf2_9ce4_8422_2325;
+        for byte in location.as_bytes() {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        splitmix64(hash)
+    }
+
+    pub fn keep_block(&self, location: &str) -> bool {
+        self.accept(self.block_seed(location))
+    }
+
+    /// `block_seed` must be computed by [`SampleInfo::block_seed`],
+    /// `offset` is the row offset inside the block.
+    pub fn keep_row(&self, block_seed: u64, offset: usize) -> bool {
+        self.accept(Self::row_hash(block_seed, offset))
+    }
+
+    /// The hash a row is sampled by. A row with a smaller hash is kept by any
+    /// probability that keeps a row with a larger one, so the rows with the
+    /// `n` smallest hashes are a sample of `n` rows.
+    pub fn row_hash(block_seed: u64, offset: usize) -> u64 {
+        splitmix64(block_seed ^ splitmix64(offset as u64))
+    }
+
+    fn accept(&self, hash: u64) -> bool {
+        // Use the 53 high bits to get a uniform value in [0, 1).
+        ((hash >> 11) as f64 / (1u64 << 53) as f64) < self.probability.0
+    }
+}
+
+fn splitmix64(v: u64) -> u64 {
+    let mut z = v.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// An approximate nearest-neighbour search with a vector index, pushed down for
+/// `ORDER BY cosine_distance(<column>, <constant>) LIMIT <k>` and its `l2_distance`
+/// counterpart.
+///
+/// It only prunes blocks: the blocks that hold none of the candidates found by
+/// the index are skipped, and the distances of the remaining rows are still
+/// evaluated and sorted exactly by the query.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VectorTopK {
+    /// The id of the vector index, which locates its files.
+    pub index_id: String,
+    /// The constant vector the distances are computed to.
+    pub query: Vec<F32>,
+    pub limit: usize,
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -94,6 +175,10 @@ pub struct PushDownInfo {
     pub lazy_materialization: bool,
     /// Aggregating index information.
     pub agg_index: Option<AggIndexInfo>,
+    /// Optional table sampling.
+    pub sample: Option<SampleInfo>,
+    /// Optional vector index search.
+    pub vector_top_k: Option<VectorTopK>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
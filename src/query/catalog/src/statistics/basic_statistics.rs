@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_storage::Datum;
+use common_storage::Histogram;
 use storages_common_table_meta::meta::ColumnStatistics;
 
 // #[derive(Debug, Clone)]
@@ -27,6 +28,10 @@ pub struct BasicColumnStatistics {
     pub ndv: Option<u64>,
     // Count of null values
     pub null_count: u64,
+    /// Histogram built from the sampled values by `ANALYZE TABLE`
+    pub histogram: Option<Histogram>,
+    /// Most common values and their fractions of all the rows
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 impl From<ColumnStatistics> for BasicColumnStatistics {
@@ -36,6 +41,8 @@ impl From<ColumnStatistics> for BasicColumnStatistics {
             max: Datum::from_scalar(value.max),
             ndv: value.distinct_of_values,
             null_count: value.null_count,
+            histogram: None,
+            most_common_values: vec![],
         }
     }
 }
@@ -47,6 +54,8 @@ impl BasicColumnStatistics {
             max: None,
             ndv: None,
             null_count: 0,
+            histogram: None,
+            most_common_values: vec![],
         }
     }
 
@@ -59,6 +68,9 @@ impl BasicColumnStatistics {
             _ => None,
         };
         self.null_count += other.null_count;
+        // The distributions can't be merged, fall back to estimate by min, max and ndv.
+        self.histogram = None;
+        self.most_common_values.clear();
     }
 
     // If the data type is int and max - min + 1 < ndv, then adjust ndv to max - min + 1.
@@ -123,6 +135,8 @@ impl BasicColumnStatistics {
             max: self.max.clone(),
             ndv,
             null_count: self.null_count,
+            histogram: self.histogram.clone(),
+            most_common_values: self.most_common_values.clone(),
         })
     }
 }
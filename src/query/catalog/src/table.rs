@@ -384,6 +384,16 @@ pub trait TableExt: Table {
         let name = table_info.name.clone();
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        // read the uncommitted changes made by the explicit transaction of the session
+        let buffered_table_info = ctx.txn_mgr().lock().get_table_from_buffer(tid);
+        if let Some(table_info) = buffered_table_info {
+            return catalog.get_table_by_info(&table_info);
+        }
+        // temporary tables are not known by the meta service
+        let temp_table_info = ctx.temp_tbl_mgr().lock().get_table_by_id(tid);
+        if let Some(table_info) = temp_table_info {
+            return catalog.get_table_by_info(&table_info);
+        }
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
         let table_info: TableInfo = TableInfo {
             ident,
@@ -51,6 +51,8 @@ use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::query_kind::QueryKind;
 use crate::table::Table;
+use crate::temp_table::TempTblMgrRef;
+use crate::txn::TxnManagerRef;
 
 pub type MaterializedCtesBlocks = Arc<RwLock<HashMap<(usize, usize), Arc<RwLock<Vec<DataBlock>>>>>>;
 
@@ -160,6 +162,8 @@ pub trait TableContext: Send + Sync {
     fn get_connection_id(&self) -> String;
     fn get_settings(&self) -> Arc<Settings>;
     fn get_shard_settings(&self) -> Arc<Settings>;
+    fn txn_mgr(&self) -> TxnManagerRef;
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef;
     fn get_cluster(&self) -> Arc<Cluster>;
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
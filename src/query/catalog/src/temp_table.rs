@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use parking_lot::Mutex;
+
+pub type TempTblMgrRef = Arc<Mutex<TempTblMgr>>;
+
+/// Ids of temporary tables are allocated by the query node itself, from a range
+/// that the meta service never reaches, so they never collide with persistent tables.
+const TEMP_TBL_ID_BEGIN: u64 = 1 << 62;
+
+static NEXT_TEMP_TBL_ID: AtomicU64 = AtomicU64::new(TEMP_TBL_ID_BEGIN);
+
+/// Data of the temporary tables of a session are stored under
+/// `_tmp/<cluster_id>/<node_id>/<session_id>/`, the data left by crashed
+/// nodes or lost sessions could be told apart and collected.
+pub const TEMP_TBL_STORAGE_PREFIX: &str = "_tmp";
+
+pub fn temp_tbl_cluster_storage_prefix(cluster_id: &str) -> String {
+    format!("{}/{}", TEMP_TBL_STORAGE_PREFIX, cluster_id)
+}
+
+pub fn temp_tbl_node_storage_prefix(cluster_id: &str, node_id: &str) -> String {
+    format!(
+        "{}/{}",
+        temp_tbl_cluster_storage_prefix(cluster_id),
+        node_id
+    )
+}
+
+pub fn temp_tbl_storage_prefix(cluster_id: &str, node_id: &str, session_id: &str) -> String {
+    format!(
+        "{}/{}",
+        temp_tbl_node_storage_prefix(cluster_id, node_id),
+        session_id
+    )
+}
+
+pub fn is_temp_table_id(table_id: u64) -> bool {
+    table_id >= TEMP_TBL_ID_BEGIN
+}
+
+/// Per session registry of temporary tables.
+///
+/// The meta of temporary tables is kept here instead of the meta service,
+/// it is only visible to the owning session and discarded together with it.
+#[derive(Default)]
+pub struct TempTblMgr {
+    /// (database, table) -> table id
+    name_to_id: BTreeMap<(String, String), u64>,
+    tables: BTreeMap<u64, TableInfo>,
+}
+
+impl TempTblMgr {
+    pub fn init() -> TempTblMgrRef {
+        Arc::new(Mutex::new(TempTblMgr::default()))
+    }
+
+    pub fn next_table_id() -> u64 {
+        NEXT_TEMP_TBL_ID.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Register a temporary table, returns false if it already exists and `if_not_exists` is set.
+    pub fn create_table(
+        &mut self,
+        database: &str,
+        if_not_exists: bool,
+        table_info: TableInfo,
+    ) -> Result<bool> {
+        let key = (database.to_string(), table_info.name.clone());
+        if self.name_to_id.contains_key(&key) {
+            return if if_not_exists {
+                Ok(false)
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "Temporary table '{}' already exists",
+                    table_info.name
+                )))
+            };
+        }
+        let table_id = table_info.ident.table_id;
+        self.name_to_id.insert(key, table_id);
+        self.tables.insert(table_id, table_info);
+        Ok(true)
+    }
+
+    pub fn get_table(&self, database: &str, table: &str) -> Option<TableInfo> {
+        self.name_to_id
+            .get(&(database.to_string(), table.to_string()))
+            .and_then(|table_id| self.tables.get(table_id))
+            .cloned()
+    }
+
+    pub fn get_table_by_id(&self, table_id: u64) -> Option<TableInfo> {
+        self.tables.get(&table_id).cloned()
+    }
+
+    pub fn has_tables(&self) -> bool {
+        !self.tables.is_empty()
+    }
+
+    /// Apply the new meta of a temporary table, the same way as the meta service does.
+    pub fn update_table_meta(&mut self, req: UpdateTableMetaReq) -> Result<()> {
+        let table_info = self.tables.get_mut(&req.table_id).ok_or_else(|| {
+            ErrorCode::UnknownTableId(format!("Unknown temporary table id {}", req.table_id))
+        })?;
+        if let MatchSeq::Exact(seq) = req.seq {
+            if seq != table_info.ident.seq {
+                return Err(ErrorCode::TableVersionMismatched(format!(
+                    "Table version mismatched of temporary table {}, expect {} but {}",
+                    table_info.name, seq, table_info.ident.seq
+                )));
+            }
+        }
+        table_info.ident.seq += 1;
+        table_info.meta = req.new_table_meta;
+        Ok(())
+    }
+
+    pub fn drop_table(&mut self, database: &str, table: &str) -> Option<TableInfo> {
+        self.name_to_id
+            .remove(&(database.to_string(), table.to_string()))
+            .and_then(|table_id| self.tables.remove(&table_id))
+    }
+
+    /// Drop all the temporary tables, called when the session is going away.
+    pub fn drain(&mut self) -> Vec<TableInfo> {
+        self.name_to_id.clear();
+        std::mem::take(&mut self.tables).into_values().collect()
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxnState {
+    /// Every statement commits its own changes.
+    AutoCommit,
+    /// Inside an explicit transaction, the changes are buffered until `COMMIT`.
+    Active,
+    /// A statement of the explicit transaction failed, only `ROLLBACK` is accepted.
+    Fail,
+}
+
+/// The changes made to a table by the statements of an explicit transaction.
+#[derive(Clone, Debug)]
+pub struct TxnTableMutation {
+    /// The table info when the table is mutated by the transaction for the first time.
+    pub base_table_info: TableInfo,
+    /// The table info with all the buffered changes applied.
+    pub table_info: TableInfo,
+    /// Snapshots written by the transaction, in the order they are generated.
+    pub uncommitted_snapshots: Vec<String>,
+    pub update_table_meta_req: UpdateTableMetaReq,
+}
+
+/// Per session state of explicit transactions.
+///
+/// Instead of committing to the meta service, the table meta generated by each statement
+/// is buffered here, and all of them are committed in a single meta transaction by `COMMIT`.
+pub struct TxnManager {
+    state: TxnState,
+    txn_id: String,
+    mutated_tables: BTreeMap<u64, TxnTableMutation>,
+    // The number of table metas buffered by the transaction.
+    mutation_count: u64,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            txn_id: String::new(),
+            mutated_tables: BTreeMap::new(),
+            mutation_count: 0,
+        }))
+    }
+
+    pub fn begin(&mut self, txn_id: String) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+            self.txn_id = txn_id;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    pub fn mutation_count(&self) -> u64 {
+        self.mutation_count
+    }
+
+    /// Fail the transaction if a failed statement has buffered some of its changes, which
+    /// can't be undone alone. A statement failed before buffering anything leaves the
+    /// transaction as it was.
+    pub fn set_fail_if_mutated_since(&mut self, mutation_count: u64) {
+        if self.mutation_count != mutation_count {
+            self.set_fail();
+        }
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    pub fn txn_id(&self) -> &str {
+        &self.txn_id
+    }
+
+    /// Buffer the new meta of a table instead of committing it.
+    pub fn update_table_meta(
+        &mut self,
+        table_info: &TableInfo,
+        req: UpdateTableMetaReq,
+        snapshot_location: String,
+    ) {
+        self.mutation_count += 1;
+        let table_id = table_info.ident.table_id;
+        let mut new_table_info = table_info.clone();
+        new_table_info.meta = req.new_table_meta.clone();
+
+        match self.mutated_tables.get_mut(&table_id) {
+            Some(mutation) => {
+                let prev_req = &mut mutation.update_table_meta_req;
+                prev_req.new_table_meta = req.new_table_meta;
+                prev_req.copied_files =
+                    merge_copied_files(prev_req.copied_files.take(), req.copied_files);
+                if req.deduplicated_label.is_some() {
+                    prev_req.deduplicated_label = req.deduplicated_label;
+                }
+                mutation.table_info = new_table_info;
+                mutation.uncommitted_snapshots.push(snapshot_location);
+            }
+            None => {
+                self.mutated_tables.insert(table_id, TxnTableMutation {
+                    base_table_info: table_info.clone(),
+                    table_info: new_table_info,
+                    uncommitted_snapshots: vec![snapshot_location],
+                    update_table_meta_req: req,
+                });
+            }
+        }
+    }
+
+    /// Get the table info with the buffered changes, so that the statements in the
+    /// transaction could read their own writes.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.mutated_tables
+            .get(&table_id)
+            .map(|mutation| mutation.table_info.clone())
+    }
+
+    pub fn mutated_tables(&self) -> Vec<TxnTableMutation> {
+        self.mutated_tables.values().cloned().collect()
+    }
+
+    /// End the transaction, either committed or aborted.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.txn_id = String::new();
+        self.mutated_tables.clear();
+        self.mutation_count = 0;
+    }
+}
+
+fn merge_copied_files(
+    prev: Option<UpsertTableCopiedFileReq>,
+    new: Option<UpsertTableCopiedFileReq>,
+) -> Option<UpsertTableCopiedFileReq> {
+    match (prev, new) {
+        (Some(mut prev), Some(new)) => {
+            prev.file_info.extend(new.file_info);
+            prev.expire_at = prev.expire_at.max(new.expire_at);
+            prev.fail_if_duplicated |= new.fail_if_duplicated;
+            Some(prev)
+        }
+        (prev, new) => prev.or(new),
+    }
+}
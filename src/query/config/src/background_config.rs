@@ -32,6 +32,9 @@ pub struct BackgroundConfig {
     // Fs compaction related background config.
     #[clap(flatten)]
     pub compaction: BackgroundCompactionConfig,
+    // Fuse vacuum related background config.
+    #[clap(flatten)]
+    pub vacuum: BackgroundVacuumConfig,
 }
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
@@ -67,6 +70,37 @@ pub struct BackgroundCompactionConfig {
     pub scheduled_config: BackgroundScheduledConfig,
 }
 
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackgroundVacuumConfig {
+    // only wake up background job if it is enabled.
+    #[clap(long)]
+    pub enable_vacuum: bool,
+    #[clap(long, default_value = "one_shot")]
+    pub vacuum_mode: String,
+
+    // Retain the snapshots and orphan files generated in the last `vacuum_retain_hours` hours,
+    // None represent the `retention_period` setting is used.
+    #[clap(long)]
+    pub vacuum_retain_hours: Option<u64>,
+
+    // Also vacuum the dropped tables which are out of the retention period.
+    #[clap(long)]
+    pub vacuum_drop_tables: bool,
+
+    // the fixed interval for vacuum on each table.
+    #[clap(long, default_value = "86400")]
+    pub vacuum_duration_secs: u64,
+
+    // the cron expression for scheduled vacuum job,
+    // by default it is scheduled with UTC timezone
+    #[clap(long, default_value = "")]
+    pub vacuum_cron: String,
+
+    #[clap(long)]
+    pub vacuum_time_zone: Option<String>,
+}
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct BackgroundScheduledConfig {
@@ -106,6 +140,7 @@ impl BackgroundScheduledConfig {
 pub struct InnerBackgroundConfig {
     pub enable: bool,
     pub compaction: InnerBackgroundCompactionConfig,
+    pub vacuum: InnerBackgroundVacuumConfig,
 }
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -117,6 +152,14 @@ pub struct InnerBackgroundCompactionConfig {
     pub params: BackgroundJobParams,
 }
 
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InnerBackgroundVacuumConfig {
+    pub enable: bool,
+    pub retain_hours: Option<u64>,
+    pub vacuum_drop_tables: bool,
+    pub params: BackgroundJobParams,
+}
+
 impl InnerBackgroundCompactionConfig {
     pub fn has_target_tables(&self) -> bool {
         self.target_tables.is_some() && !self.target_tables.as_ref().unwrap().is_empty()
@@ -130,6 +173,7 @@ impl TryInto<InnerBackgroundConfig> for BackgroundConfig {
         Ok(InnerBackgroundConfig {
             enable: self.enable,
             compaction: self.compaction.try_into()?,
+            vacuum: self.vacuum.try_into()?,
         })
     }
 }
@@ -139,6 +183,7 @@ impl From<InnerBackgroundConfig> for BackgroundConfig {
         Self {
             enable: inner.enable,
             compaction: BackgroundCompactionConfig::from(inner.compaction),
+            vacuum: BackgroundVacuumConfig::from(inner.vacuum),
         }
     }
 }
@@ -152,71 +197,104 @@ impl TryInto<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
             block_limit: self.block_limit,
             enable: self.enable_compaction,
             target_tables: self.target_tables,
-            params: {
-                match self.compact_mode.as_str() {
-                    "one_shot" => BackgroundJobParams::new_one_shot_job(),
-                    "interval" => BackgroundJobParams::new_interval_job(
-                        std::time::Duration::from_secs(self.scheduled_config.duration_secs),
-                    ),
-                    "cron" => {
-                        if self.scheduled_config.cron.is_empty() {
-                            return Err(ErrorCode::InvalidArgument(
-                                "cron expression is empty".to_string(),
-                            ));
-                        }
-                        let tz = self
-                            .scheduled_config
-                            .time_zone
-                            .clone()
-                            .map(|x| chrono_tz::Tz::from_str(&x))
-                            .transpose()
-                            .map_err(|e| {
-                                ErrorCode::InvalidArgument(format!("invalid time_zone: {}", e))
-                            })?;
-                        BackgroundJobParams::new_cron_job(self.scheduled_config.cron, tz)
-                    }
-
-                    _ => {
-                        return Err(ErrorCode::InvalidArgument(format!(
-                            "invalid compact_mode: {}",
-                            self.compact_mode
-                        )));
-                    }
-                }
-            },
+            params: parse_job_params("compact_mode", &self.compact_mode, self.scheduled_config)?,
         })
     }
 }
 
+fn parse_job_params(
+    name: &str,
+    mode: &str,
+    scheduled_config: BackgroundScheduledConfig,
+) -> Result<BackgroundJobParams> {
+    match mode {
+        "one_shot" => Ok(BackgroundJobParams::new_one_shot_job()),
+        "interval" => Ok(BackgroundJobParams::new_interval_job(
+            std::time::Duration::from_secs(scheduled_config.duration_secs),
+        )),
+        "cron" => {
+            if scheduled_config.cron.is_empty() {
+                return Err(ErrorCode::InvalidArgument(
+                    "cron expression is empty".to_string(),
+                ));
+            }
+            let tz = scheduled_config
+                .time_zone
+                .clone()
+                .map(|x| chrono_tz::Tz::from_str(&x))
+                .transpose()
+                .map_err(|e| ErrorCode::InvalidArgument(format!("invalid time_zone: {}", e)))?;
+            Ok(BackgroundJobParams::new_cron_job(scheduled_config.cron, tz))
+        }
+        _ => Err(ErrorCode::InvalidArgument(format!(
+            "invalid {}: {}",
+            name, mode
+        ))),
+    }
+}
+
+// return the mode and the scheduled config of the job params
+fn format_job_params(params: BackgroundJobParams) -> (String, BackgroundScheduledConfig) {
+    match params.job_type {
+        BackgroundJobType::ONESHOT => ("one_shot".to_string(), Default::default()),
+        BackgroundJobType::INTERVAL => (
+            "interval".to_string(),
+            BackgroundScheduledConfig::new_interval_job(params.scheduled_job_interval.as_secs()),
+        ),
+        BackgroundJobType::CRON => (
+            "cron".to_string(),
+            BackgroundScheduledConfig::new_cron_job(
+                params.scheduled_job_cron,
+                params.scheduled_job_timezone.map(|x| x.to_string()),
+            ),
+        ),
+    }
+}
+
+impl TryInto<InnerBackgroundVacuumConfig> for BackgroundVacuumConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerBackgroundVacuumConfig> {
+        let scheduled_config = BackgroundScheduledConfig {
+            duration_secs: self.vacuum_duration_secs,
+            cron: self.vacuum_cron,
+            time_zone: self.vacuum_time_zone,
+        };
+        Ok(InnerBackgroundVacuumConfig {
+            enable: self.enable_vacuum,
+            retain_hours: self.vacuum_retain_hours,
+            vacuum_drop_tables: self.vacuum_drop_tables,
+            params: parse_job_params("vacuum_mode", &self.vacuum_mode, scheduled_config)?,
+        })
+    }
+}
+
+impl From<InnerBackgroundVacuumConfig> for BackgroundVacuumConfig {
+    fn from(inner: InnerBackgroundVacuumConfig) -> Self {
+        let (vacuum_mode, scheduled_config) = format_job_params(inner.params);
+        Self {
+            enable_vacuum: inner.enable,
+            vacuum_mode,
+            vacuum_retain_hours: inner.retain_hours,
+            vacuum_drop_tables: inner.vacuum_drop_tables,
+            vacuum_duration_secs: scheduled_config.duration_secs,
+            vacuum_cron: scheduled_config.cron,
+            vacuum_time_zone: scheduled_config.time_zone,
+        }
+    }
+}
+
 impl From<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
     fn from(inner: InnerBackgroundCompactionConfig) -> Self {
-        let mut cfg = Self {
+        let (compact_mode, scheduled_config) = format_job_params(inner.params);
+        Self {
             enable_compaction: inner.enable,
-            compact_mode: "".to_string(), // it would be set later
+            compact_mode,
             target_tables: inner.target_tables,
             segment_limit: inner.segment_limit,
             block_limit: inner.block_limit,
-            scheduled_config: Default::default(), // it would be set later
-        };
-        match inner.params.job_type {
-            BackgroundJobType::ONESHOT => {
-                cfg.compact_mode = "one_shot".to_string();
-            }
-            BackgroundJobType::INTERVAL => {
-                cfg.compact_mode = "interval".to_string();
-                cfg.scheduled_config = BackgroundScheduledConfig::new_interval_job(
-                    inner.params.scheduled_job_interval.as_secs(),
-                );
-            }
-            BackgroundJobType::CRON => {
-                cfg.compact_mode = "cron".to_string();
-                cfg.scheduled_config = BackgroundScheduledConfig::new_cron_job(
-                    inner.params.scheduled_job_cron,
-                    inner.params.scheduled_job_timezone.map(|x| x.to_string()),
-                );
-            }
+            scheduled_config,
         }
-        cfg
     }
 }
 
@@ -254,6 +332,32 @@ impl Debug for BackgroundCompactionConfig {
     }
 }
 
+impl Default for BackgroundVacuumConfig {
+    fn default() -> Self {
+        Self {
+            enable_vacuum: false,
+            vacuum_mode: "one_shot".to_string(),
+            vacuum_retain_hours: None,
+            vacuum_drop_tables: false,
+            vacuum_duration_secs: 86400,
+            vacuum_cron: "".to_string(),
+            vacuum_time_zone: None,
+        }
+    }
+}
+
+impl Debug for BackgroundVacuumConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackgroundVacuumConfig")
+            .field("mode", &self.vacuum_mode)
+            .field("retain_hours", &self.vacuum_retain_hours)
+            .field("vacuum_drop_tables", &self.vacuum_drop_tables)
+            .field("duration_secs", &self.vacuum_duration_secs)
+            .field("cron", &self.vacuum_cron)
+            .finish()
+    }
+}
+
 impl Default for BackgroundScheduledConfig {
     fn default() -> Self {
         Self {
@@ -283,6 +387,12 @@ impl Default for InnerBackgroundConfig {
                 block_limit: None,
                 params: Default::default(),
             },
+            vacuum: InnerBackgroundVacuumConfig {
+                enable: false,
+                retain_hours: None,
+                vacuum_drop_tables: false,
+                params: Default::default(),
+            },
         }
     }
 }
@@ -291,6 +401,7 @@ impl Debug for InnerBackgroundConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("InnerBackgroundConfig")
             .field("compaction", &self.compaction)
+            .field("vacuum", &self.vacuum)
             .finish()
     }
 }
@@ -304,3 +415,13 @@ impl Debug for InnerBackgroundCompactionConfig {
             .finish()
     }
 }
+
+impl Debug for InnerBackgroundVacuumConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InnerBackgroundVacuumConfig")
+            .field("retain_hours", &self.retain_hours)
+            .field("vacuum_drop_tables", &self.vacuum_drop_tables)
+            .field("params", &self.params)
+            .finish()
+    }
+}
@@ -43,6 +43,7 @@ use common_meta_app::storage::StorageS3Config as InnerStorageS3Config;
 use common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhdfsConfig;
 use common_meta_app::tenant::TenantQuota;
 use common_storage::StorageConfig as InnerStorageConfig;
+use common_tracing::AuditLogConfig as InnerAuditLogConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::QueryLogConfig as InnerQueryLogConfig;
@@ -1536,6 +1537,11 @@ pub struct QueryConfig {
     /// A list of allowed udf server addresses.
     #[clap(long)]
     pub udf_server_allow_list: Vec<String>,
+
+    /// The database of the append-only fuse table `audit_log`, which stores the audit events of DDL and DCL statements.
+    /// An empty string disables the audit log table.
+    #[clap(long, default_value = "system_audit")]
+    pub audit_log_database: String,
 }
 
 impl Default for QueryConfig {
@@ -1611,6 +1617,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             openai_api_version: self.openai_api_version,
             enable_udf_server: self.enable_udf_server,
             udf_server_allow_list: self.udf_server_allow_list,
+            audit_log_database: self.audit_log_database,
         })
     }
 }
@@ -1697,6 +1704,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             openai_api_embedding_model: inner.openai_api_embedding_model,
             enable_udf_server: inner.enable_udf_server,
             udf_server_allow_list: inner.udf_server_allow_list,
+            audit_log_database: inner.audit_log_database,
         }
     }
 }
@@ -1736,6 +1744,9 @@ pub struct LogConfig {
 
     #[clap(flatten)]
     pub query: QueryLogConfig,
+
+    #[clap(flatten)]
+    pub audit: AuditLogConfig,
 }
 
 impl Default for LogConfig {
@@ -1787,12 +1798,23 @@ impl TryInto<InnerLogConfig> for LogConfig {
             query.dir = format!("{}/query-details", &file.dir);
         }
 
+        let mut audit: InnerAuditLogConfig = self.audit.try_into()?;
+        if audit.dir.is_empty() {
+            if file.dir.is_empty() {
+                return Err(ErrorCode::InvalidConfig(
+                    "`dir` or `file.dir` must be set when `audit.dir` is empty".to_string(),
+                ));
+            }
+            audit.dir = format!("{}/audit", &file.dir);
+        }
+
         let tracing = TracingConfig::from_env();
 
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
             query,
+            audit,
             tracing,
         })
     }
@@ -1806,6 +1828,7 @@ impl From<InnerLogConfig> for LogConfig {
             file: inner.file.into(),
             stderr: inner.stderr.into(),
             query: inner.query.into(),
+            audit: inner.audit.into(),
 
             // Deprecated fields
             log_dir: None,
@@ -1957,6 +1980,49 @@ impl From<InnerQueryLogConfig> for QueryLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct AuditLogConfig {
+    #[clap(long = "log-audit-on", default_value = "true", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    #[serde(rename = "on")]
+    pub log_audit_on: bool,
+
+    /// Audit Log file dir
+    #[clap(
+        long = "log-audit-dir",
+        default_value = "",
+        help = "Default to <log-file-dir>/audit"
+    )]
+    #[serde(rename = "dir")]
+    pub log_audit_dir: String,
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        InnerAuditLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerAuditLogConfig> for AuditLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerAuditLogConfig> {
+        Ok(InnerAuditLogConfig {
+            on: self.log_audit_on,
+            dir: self.log_audit_dir,
+        })
+    }
+}
+
+impl From<InnerAuditLogConfig> for AuditLogConfig {
+    fn from(inner: InnerAuditLogConfig) -> Self {
+        Self {
+            log_audit_on: inner.on,
+            log_audit_dir: inner.dir,
+        }
+    }
+}
+
 /// Meta config group.
 /// deny_unknown_fields to check unknown field, like the deprecated `address`.
 /// TODO(xuanwo): All meta_xxx should be rename to xxx.
@@ -2160,6 +2226,13 @@ impl TryInto<AuthInfo> for UserAuthConfig {
         match auth_type {
             AuthType::NoPassword => check_no_auth_string(self.auth_string, AuthInfo::None),
             AuthType::JWT => check_no_auth_string(self.auth_string, AuthInfo::JWT),
+            AuthType::KeyPair => match self.auth_string {
+                None => Err(ErrorCode::InvalidConfig("must set auth_string")),
+                Some(s) => Ok(AuthInfo::KeyPair {
+                    public_key: Some(s),
+                    public_key_2: None,
+                }),
+            },
             AuthType::Sha256Password | AuthType::DoubleSha1Password => {
                 let password_type = auth_type.get_password_type().expect("must success");
                 match self.auth_string {
@@ -2283,6 +2356,10 @@ pub struct CacheConfig {
     #[clap(long = "cache-table-prune-partitions-count", default_value = "256")]
     pub table_prune_partitions_count: u64,
 
+    /// Max bytes of cached segment vector indexes. Set it to 0 to disable it.
+    #[clap(long = "cache-table-vector-index-bytes", default_value = "1073741824")]
+    pub table_vector_index_bytes: u64,
+
     /// Type of data cache storage
     #[clap(long = "cache-data-cache-storage", value_enum, default_value_t)]
     pub data_cache_storage: CacheStorageTypeConfig,
@@ -2436,6 +2513,7 @@ mod cache_config_converters {
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
                 table_bloom_index_filter_size: value.table_bloom_index_filter_size,
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                table_vector_index_bytes: value.table_vector_index_bytes,
                 data_cache_storage: value.data_cache_storage.try_into()?,
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
@@ -2457,6 +2535,7 @@ mod cache_config_converters {
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
                 table_bloom_index_filter_size: value.table_bloom_index_filter_size,
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                table_vector_index_bytes: value.table_vector_index_bytes,
                 data_cache_storage: value.data_cache_storage.into(),
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
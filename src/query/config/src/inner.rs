@@ -214,6 +214,9 @@ pub struct QueryConfig {
 
     pub enable_udf_server: bool,
     pub udf_server_allow_list: Vec<String>,
+
+    /// The database of the audit log table, empty if the audit log is disabled.
+    pub audit_log_database: String,
 }
 
 impl Default for QueryConfig {
@@ -279,6 +282,7 @@ impl Default for QueryConfig {
             openai_api_embedding_model: "text-embedding-ada-002".to_string(),
             enable_udf_server: false,
             udf_server_allow_list: Vec::new(),
+            audit_log_database: "system_audit".to_string(),
         }
     }
 }
@@ -517,6 +521,9 @@ pub struct CacheConfig {
     // One bloom index filter per column of data block being indexed will be generated if necessary.
     pub table_bloom_index_filter_size: u64,
 
+    /// Max bytes of cached segment vector indexes. Set it to 0 to disable it.
+    pub table_vector_index_bytes: u64,
+
     pub data_cache_storage: CacheStorageTypeConfig,
 
     /// Max size of external cache population queue length
@@ -596,6 +603,7 @@ impl Default for CacheConfig {
             table_bloom_index_filter_count: 0,
             table_bloom_index_filter_size: 2147483648,
             table_prune_partitions_count: 256,
+            table_vector_index_bytes: 1073741824,
             data_cache_storage: Default::default(),
             table_data_cache_population_queue_size: 65536,
             disk_cache_config: Default::default(),
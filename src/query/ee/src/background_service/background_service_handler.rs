@@ -49,6 +49,7 @@ use crate::background_service::session::create_session;
 use crate::background_service::session::get_background_service_user;
 use crate::background_service::CompactionJob;
 use crate::background_service::JobScheduler;
+use crate::background_service::VacuumJob;
 
 pub struct RealBackgroundService {
     conf: InnerConfig,
@@ -134,6 +135,13 @@ impl RealBackgroundService {
                 user.identity(),
             )
             .await?;
+            Self::create_vacuum_job(
+                meta_api.clone(),
+                conf,
+                BackgroundJobParams::new_one_shot_job(),
+                user.identity(),
+            )
+            .await?;
             return Ok(None);
         }
         let meta_api = UserApiProvider::instance().get_meta_store_client();
@@ -148,6 +156,19 @@ impl RealBackgroundService {
             .await?;
             scheduler.add_job(compactor_job).await?;
         }
+        if conf.background.vacuum.enable {
+            let vacuum_job = RealBackgroundService::get_vacuum_job(
+                meta_api.clone(),
+                conf,
+                &user.identity(),
+                scheduler.finish_tx.clone(),
+            )
+            .await?;
+            scheduler.add_job(vacuum_job).await?;
+        }
+
+        // the SQL tasks are always scheduled, they are suspended until `ALTER TASK RESUME`.
+        scheduler.enable_sql_tasks(conf.query.tenant_id.clone(), conf.query.cluster_id.clone());
 
         let rm = RealBackgroundService {
             conf: conf.clone(),
@@ -194,17 +215,63 @@ impl RealBackgroundService {
             creator.clone(),
         )
         .await?;
-        Self::update_compaction_job_params(meta.clone(), &id, conf).await?;
+        Self::update_job_params(meta.clone(), &id, conf.background.compaction.params.clone())
+            .await?;
         Self::suspend_job(meta.clone(), &id, false).await?;
 
         let job = CompactionJob::create(conf, id.name, finish_tx).await;
         Ok(job)
     }
 
-    async fn update_compaction_job_params(
+    pub fn get_vacuum_job_name(tenant: String) -> String {
+        format!("{}-vacuum-job", tenant)
+    }
+
+    pub async fn create_vacuum_job(
+        meta: Arc<MetaStore>,
+        conf: &InnerConfig,
+        params: BackgroundJobParams,
+        creator: UserIdentity,
+    ) -> Result<BackgroundJobIdent> {
+        let name = RealBackgroundService::get_vacuum_job_name(conf.query.tenant_id.clone());
+        let id = BackgroundJobIdent {
+            tenant: conf.query.tenant_id.clone(),
+            name,
+        };
+        let info = BackgroundJobInfo::new_vacuum_job(params, creator);
+        meta.create_background_job(CreateBackgroundJobReq {
+            if_not_exists: true,
+            job_name: id.clone(),
+            job_info: info,
+        })
+        .await?;
+        Ok(id)
+    }
+
+    async fn get_vacuum_job(
         meta: Arc<MetaStore>,
-        id: &BackgroundJobIdent,
         conf: &InnerConfig,
+        creator: &UserIdentity,
+        finish_tx: Arc<Mutex<Sender<u64>>>,
+    ) -> Result<VacuumJob> {
+        let id = RealBackgroundService::create_vacuum_job(
+            meta.clone(),
+            conf,
+            conf.background.vacuum.params.clone(),
+            creator.clone(),
+        )
+        .await?;
+        Self::update_job_params(meta.clone(), &id, conf.background.vacuum.params.clone()).await?;
+        Self::suspend_job(meta.clone(), &id, false).await?;
+
+        let job = VacuumJob::create(conf, id.name, finish_tx).await;
+        Ok(job)
+    }
+
+    async fn update_job_params(
+        meta: Arc<MetaStore>,
+        id: &BackgroundJobIdent,
+        params: BackgroundJobParams,
     ) -> Result<()> {
         // create job if not exist
         let info = meta
@@ -214,7 +281,7 @@ impl RealBackgroundService {
         if info.job_params.is_some() {
             meta.update_background_job_params(UpdateBackgroundJobParamsReq {
                 job_name: id.clone(),
-                params: conf.background.compaction.params.clone(),
+                params,
             })
             .await?;
         }
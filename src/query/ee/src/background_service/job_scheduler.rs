@@ -23,19 +23,43 @@ use common_base::base::tokio::sync::mpsc::Receiver;
 use common_base::base::tokio::sync::mpsc::Sender;
 use common_base::base::tokio::sync::Mutex;
 use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
 use common_meta_app::background::BackgroundJobInfo;
 use common_meta_app::background::BackgroundJobState;
 use common_meta_app::background::BackgroundJobType;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::background::ListBackgroundJobsReq;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKV;
+use common_users::UserApiProvider;
 use dashmap::DashMap;
 use log::as_debug;
 use log::info;
+use log::warn;
 
 use crate::background_service::job::BoxedJob;
 use crate::background_service::job::Job;
+use crate::background_service::SqlTaskJob;
+
+/// The nodes running the same scheduled SQL task compete for a key under the prefix, so that
+/// each scheduled run of the task happens on a single node.
+const SQL_TASK_RUNNER_PREFIX: &str = "__fd_background_job_runner";
+/// The key of a scheduled run is kept long enough for all the nodes to see the run.
+const SQL_TASK_RUNNER_TTL_SEC: u64 = 60 * 60;
 
 pub struct JobScheduler {
     one_shot_jobs: DashMap<String, BoxedJob>,
     scheduled_jobs: DashMap<String, BoxedJob>,
+    // the scheduled SQL tasks of the tenant are reloaded from the meta store on each tick.
+    sql_task_tenant: Option<String>,
+    // the SQL tasks with a warehouse only run on the nodes of the warehouse (cluster).
+    sql_task_warehouse: String,
+    sql_task_jobs: DashMap<String, BoxedJob>,
     pub job_tick_interval: Duration,
     pub finish_tx: Arc<Mutex<Sender<u64>>>,
     pub finish_rx: Arc<Mutex<Receiver<u64>>>,
@@ -57,6 +81,9 @@ impl JobScheduler {
         Self {
             one_shot_jobs: DashMap::new(),
             scheduled_jobs: DashMap::new(),
+            sql_task_tenant: None,
+            sql_task_warehouse: String::new(),
+            sql_task_jobs: DashMap::new(),
             job_tick_interval: Duration::from_secs(5),
             finish_tx: Arc::new(Mutex::new(finish_tx)),
             finish_rx: Arc::new(Mutex::new(finish_rx)),
@@ -90,11 +117,18 @@ impl JobScheduler {
         Ok(())
     }
 
+    /// Schedules the SQL tasks of the tenant, which are created and dropped at any time by
+    /// the task statements.
+    pub fn enable_sql_tasks(&mut self, tenant: String, warehouse: String) {
+        self.sql_task_tenant = Some(tenant);
+        self.sql_task_warehouse = warehouse;
+    }
+
     pub async fn start(&self) -> Result<()> {
         let one_shot_jobs = &self.one_shot_jobs;
         if !one_shot_jobs.is_empty() {
             info!(background = true; "start one_shot jobs");
-            Self::check_and_run_jobs(one_shot_jobs).await;
+            Self::check_and_run_jobs(one_shot_jobs, false).await;
             let mut finished_one_shot_jobs = vec![];
             while let Some(i) = self.finish_rx.clone().lock().await.recv().await {
                 finished_one_shot_jobs.push(i);
@@ -111,7 +145,7 @@ impl JobScheduler {
 
     pub async fn start_scheduled_jobs(&self, tick_duration: std::time::Duration) -> Result<()> {
         let scheduled_jobs = &self.scheduled_jobs;
-        if scheduled_jobs.is_empty() {
+        if scheduled_jobs.is_empty() && self.sql_task_tenant.is_none() {
             return Ok(());
         }
         let mut job_interval = tokio::time::interval(tick_duration);
@@ -131,28 +165,87 @@ impl JobScheduler {
                 _ => {}
             }
             job_interval.tick().await;
-            Self::check_and_run_jobs(scheduled_jobs).await;
+            if let Err(e) = self.sync_sql_tasks().await {
+                warn!(background = true; "failed to sync sql tasks: {}", e);
+            }
+            Self::check_and_run_jobs(scheduled_jobs, false).await;
+            Self::check_and_run_jobs(&self.sql_task_jobs, true).await;
+        }
+        Ok(())
+    }
+    // Adds the created SQL tasks with a schedule, and removes the dropped ones.
+    async fn sync_sql_tasks(&self) -> Result<()> {
+        let tenant = match &self.sql_task_tenant {
+            Some(tenant) => tenant.clone(),
+            None => return Ok(()),
+        };
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let jobs = meta_api
+            .list_background_jobs(ListBackgroundJobsReq {
+                tenant: tenant.clone(),
+            })
+            .await?;
+        let mut sql_tasks = vec![];
+        for (_, name, info) in jobs {
+            if info.task_type != BackgroundTaskType::SQL
+                || !Self::runs_in_warehouse(&info, &self.sql_task_warehouse)
+            {
+                continue;
+            }
+            // the tasks without a schedule only run on EXECUTE TASK or after other tasks.
+            if let Some(BackgroundJobType::CRON | BackgroundJobType::INTERVAL) =
+                info.job_params.as_ref().map(|params| &params.job_type)
+            {
+                sql_tasks.push(name);
+            }
+        }
+
+        self.sql_task_jobs
+            .retain(|name, _| sql_tasks.contains(name));
+        for name in sql_tasks {
+            if !self.sql_task_jobs.contains_key(&name) {
+                let job = SqlTaskJob::create(BackgroundJobIdent {
+                    tenant: tenant.clone(),
+                    name: name.clone(),
+                });
+                self.sql_task_jobs.insert(name, Box::new(job) as BoxedJob);
+            }
         }
         Ok(())
     }
-    async fn check_and_run_jobs(jobs: &DashMap<String, BoxedJob>) {
+
+    async fn check_and_run_jobs(jobs: &DashMap<String, BoxedJob>, elect_runner: bool) {
         let job_futures = jobs
             .iter()
             .map(|job| {
                 let j = job.value().box_clone();
-                Self::check_and_run_job(j, false)
+                Self::do_check_and_run_job(j, false, elect_runner)
             })
             .collect::<Vec<_>>();
         for job in job_futures {
-            job.await.unwrap();
+            // the SQL tasks may be dropped at any time.
+            if let Err(e) = job.await {
+                warn!(background = true; "failed to run job: {}", e);
+            }
         }
     }
     // Checks and runs a single [Job](crate::Job)
-    pub async fn check_and_run_job(mut job: BoxedJob, force_execute: bool) -> Result<()> {
+    pub async fn check_and_run_job(job: BoxedJob, force_execute: bool) -> Result<()> {
+        Self::do_check_and_run_job(job, force_execute, false).await
+    }
+
+    async fn do_check_and_run_job(
+        mut job: BoxedJob,
+        force_execute: bool,
+        elect_runner: bool,
+    ) -> Result<()> {
         let info = &job.get_info().await?;
         if !Self::should_run_job(info, Utc::now(), force_execute) {
             return Ok(());
         }
+        if elect_runner && !force_execute && !Self::elect_runner(&job.get_name(), info).await? {
+            return Ok(());
+        }
 
         // update job status only if it is not forced to run
         if !force_execute {
@@ -169,6 +262,52 @@ impl JobScheduler {
         Ok(())
     }
 
+    // returns true if the SQL task can run on the nodes of the warehouse
+    pub fn runs_in_warehouse(job_info: &BackgroundJobInfo, warehouse: &str) -> bool {
+        match job_info
+            .sql_task
+            .as_ref()
+            .and_then(|task| task.warehouse.as_ref())
+        {
+            Some(task_warehouse) => task_warehouse == warehouse,
+            None => true,
+        }
+    }
+
+    /// Elect the node to run the job at its scheduled time, returns true if the current
+    /// node is elected.
+    ///
+    /// All the nodes see the same scheduled time until the elected node updates it, so the
+    /// time identifies a single run of the job.
+    pub async fn elect_runner(
+        name: &BackgroundJobIdent,
+        job_info: &BackgroundJobInfo,
+    ) -> Result<bool> {
+        let scheduled_time = job_info
+            .job_status
+            .as_ref()
+            .and_then(|status| status.next_task_scheduled_time)
+            .map(|time| time.timestamp_millis())
+            .unwrap_or_default();
+        let key = format!(
+            "{}/{}/{}/{}",
+            SQL_TASK_RUNNER_PREFIX, name.tenant, name.name, scheduled_time
+        );
+        let expire_at = SeqV::<()>::now_ms() / 1000 + SQL_TASK_RUNNER_TTL_SEC;
+        let reply = UserApiProvider::instance()
+            .get_meta_store_client()
+            .upsert_kv(UpsertKV {
+                key,
+                seq: MatchSeq::Exact(0),
+                value: Operation::Update(vec![]),
+                value_meta: Some(KVMeta {
+                    expire_at: Some(expire_at),
+                }),
+            })
+            .await?;
+        Ok(reply.is_changed())
+    }
+
     // returns true if the job should be run
     pub fn should_run_job(
         job_info: &BackgroundJobInfo,
@@ -0,0 +1,92 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::GetBackgroundJobReq;
+use common_meta_app::background::UpdateBackgroundJobParamsReq;
+use common_meta_app::background::UpdateBackgroundJobStatusReq;
+use common_meta_store::MetaStore;
+use common_users::UserApiProvider;
+use databend_query::interpreters::execute_sql_task;
+use log::as_debug;
+use log::error;
+use log::info;
+
+use crate::background_service::job::Job;
+
+/// Runs a scheduled SQL task created by `CREATE TASK`, the tasks which run after it
+/// are triggered once it succeeds.
+#[derive(Clone)]
+pub struct SqlTaskJob {
+    meta_api: Arc<MetaStore>,
+    creator: BackgroundJobIdent,
+}
+
+#[async_trait::async_trait]
+impl Job for SqlTaskJob {
+    async fn run(&mut self) {
+        info!(background = true, job_name = as_debug!(&self.creator.clone()); "SQL task started");
+        if let Err(e) = execute_sql_task(self.creator.clone(), None).await {
+            error!(background = true, job_name = as_debug!(&self.creator.clone()); "SQL task failed: {}", e);
+        }
+    }
+
+    async fn get_info(&self) -> Result<BackgroundJobInfo> {
+        let job = self
+            .meta_api
+            .get_background_job(GetBackgroundJobReq {
+                name: self.creator.clone(),
+            })
+            .await?;
+        Ok(job.info)
+    }
+
+    fn get_name(&self) -> BackgroundJobIdent {
+        self.creator.clone()
+    }
+
+    async fn update_job_status(&mut self, status: BackgroundJobStatus) -> Result<()> {
+        self.meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: self.creator.clone(),
+                status: status.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()> {
+        self.meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: self.creator.clone(),
+                params: param.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl SqlTaskJob {
+    pub fn create(creator: BackgroundJobIdent) -> Self {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        Self { meta_api, creator }
+    }
+}
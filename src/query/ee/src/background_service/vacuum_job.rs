@@ -0,0 +1,384 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::LargeBinaryArray;
+use arrow_array::UInt64Array;
+use chrono::Utc;
+use common_base::base::tokio::sync::mpsc::Sender;
+use common_base::base::tokio::sync::Mutex;
+use common_base::base::tokio::time::Instant;
+use common_base::base::uuid::Uuid;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_license::license::Feature;
+use common_license::license_manager::get_license_manager;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::BackgroundJobType::ONESHOT;
+use common_meta_app::background::BackgroundTaskIdent;
+use common_meta_app::background::BackgroundTaskInfo;
+use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::GetBackgroundJobReq;
+use common_meta_app::background::ManualTriggerParams;
+use common_meta_app::background::UpdateBackgroundJobParamsReq;
+use common_meta_app::background::UpdateBackgroundJobStatusReq;
+use common_meta_app::background::UpdateBackgroundTaskReq;
+use common_meta_store::MetaStore;
+use common_storages_fuse::operations::PurgeStats;
+use common_storages_fuse::FuseTable;
+use common_users::UserApiProvider;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::Session;
+use databend_query::table_functions::SuggestedBackgroundTasksSource;
+use log::as_debug;
+use log::debug;
+use log::error;
+use log::info;
+
+use crate::background_service::job::Job;
+use crate::background_service::session::create_session;
+use crate::storages::fuse::do_vacuum_and_count;
+
+const EXPIRE_SEC: u64 = 60 * 60 * 24 * 7; // 7 days
+
+/// Purges the snapshots and orphan files out of the retention period for all the fuse tables,
+/// and optionally removes the data of the dropped tables, like `VACUUM TABLE` and
+/// `VACUUM DROP TABLE` do.
+#[derive(Clone)]
+pub struct VacuumJob {
+    conf: InnerConfig,
+    meta_api: Arc<MetaStore>,
+    creator: BackgroundJobIdent,
+
+    finish_tx: Arc<Mutex<Sender<u64>>>,
+}
+
+#[async_trait::async_trait]
+impl Job for VacuumJob {
+    async fn run(&mut self) {
+        info!(background = true, job_name = as_debug!(&self.creator.clone()); "Vacuum job started");
+        // the job is retried in the next round, the scheduler is kept running.
+        if let Err(e) = self.do_vacuum_job().await {
+            error!(background = true, job_name = as_debug!(&self.creator.clone()); "Vacuum job failed: {}", e);
+        }
+        if self.conf.background.vacuum.params.job_type == ONESHOT {
+            let finish_tx = self.finish_tx.clone();
+            let _ = finish_tx.lock().await.send(1).await;
+        }
+    }
+
+    async fn get_info(&self) -> Result<BackgroundJobInfo> {
+        let job = self
+            .meta_api
+            .get_background_job(GetBackgroundJobReq {
+                name: self.creator.clone(),
+            })
+            .await?;
+        Ok(job.info)
+    }
+
+    fn get_name(&self) -> BackgroundJobIdent {
+        self.creator.clone()
+    }
+
+    async fn update_job_status(&mut self, status: BackgroundJobStatus) -> Result<()> {
+        self.meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: self.creator.clone(),
+                status: status.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()> {
+        self.meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: self.creator.clone(),
+                params: param.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl VacuumJob {
+    pub async fn create(
+        config: &InnerConfig,
+        name: String,
+        finish_tx: Arc<Mutex<Sender<u64>>>,
+    ) -> Self {
+        let tenant = config.query.tenant_id.clone();
+        let creator = BackgroundJobIdent { tenant, name };
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        Self {
+            conf: config.clone(),
+            meta_api,
+            creator,
+            finish_tx,
+        }
+    }
+
+    async fn do_vacuum_job(&mut self) -> Result<()> {
+        let session = create_session(&self.conf).await?;
+        let ctx = session.create_query_context().await?;
+        get_license_manager().manager.check_enterprise_enabled(
+            &ctx.get_settings(),
+            self.conf.query.tenant_id.clone(),
+            Feature::Vacuum,
+        )?;
+        let job_info = self.get_info().await?;
+
+        let (params, manual) = Self::sync_vacuum_params(&job_info);
+        // guarantee at least once for manual job
+        self.update_job_params(params).await?;
+
+        for records in Self::do_get_target_tables(ctx.clone()).await? {
+            debug!(records = as_debug!(&records); "target_tables");
+            let db_names = records
+                .column(0)
+                .as_any()
+                .downcast_ref::<LargeBinaryArray>()
+                .unwrap();
+            let db_ids = records
+                .column(1)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .unwrap();
+            let tb_names = records
+                .column(2)
+                .as_any()
+                .downcast_ref::<LargeBinaryArray>()
+                .unwrap();
+            let tb_ids = records
+                .column(3)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .unwrap();
+            for i in 0..records.num_rows() {
+                let db_name = String::from_utf8_lossy(db_names.value(i)).to_string();
+                let db_id = db_ids.value(i);
+                let tb_name = String::from_utf8_lossy(tb_names.value(i)).to_string();
+                let tb_id = tb_ids.value(i);
+                match self
+                    .vacuum_table(
+                        session.clone(),
+                        db_name.clone(),
+                        tb_name.clone(),
+                        db_id,
+                        tb_id,
+                        manual.clone(),
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        info!("vacuum job success, db: {}, table: {}", db_name, tb_name);
+                    }
+                    Err(e) => {
+                        error!(
+                            "vacuum job failed, db: {}, table: {}, err: {}",
+                            db_name, tb_name, e
+                        );
+                    }
+                }
+            }
+        }
+
+        if self.conf.background.vacuum.vacuum_drop_tables {
+            let sql = Self::get_vacuum_drop_tables_sql(self.conf.background.vacuum.retain_hours);
+            info!(job = "vacuum", background = true, sql = sql.as_str(); "vacuum drop tables");
+            let ctx = session.create_query_context().await?;
+            if let Err(e) = SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await {
+                error!("vacuum job failed to vacuum dropped tables, err: {}", e);
+            }
+        }
+
+        info!(
+            job = "vacuum",
+            background = true;
+            "vacuum task is done"
+        );
+        Ok(())
+    }
+
+    fn sync_vacuum_params(
+        job_info: &BackgroundJobInfo,
+    ) -> (BackgroundJobParams, Option<ManualTriggerParams>) {
+        let mut job_params = job_info.job_params.clone().unwrap();
+        let manual = job_params.manual_trigger_params.clone();
+        job_params.manual_trigger_params = None;
+        (job_params, manual)
+    }
+
+    fn sync_vacuum_status(id: String, job_info: &BackgroundJobInfo) -> BackgroundJobStatus {
+        let mut job_status = job_info.job_status.clone().unwrap();
+        job_status.last_task_id = Some(id);
+        job_status.last_task_run_at = Some(Utc::now());
+        job_status.next_task_scheduled_time = job_info
+            .job_params
+            .as_ref()
+            .unwrap()
+            .get_next_running_time(job_status.last_task_run_at.unwrap());
+        job_status
+    }
+
+    async fn vacuum_table(
+        &mut self,
+        session: Arc<Session>,
+        database: String,
+        table: String,
+        db_id: u64,
+        tb_id: u64,
+        manual: Option<ManualTriggerParams>,
+    ) -> Result<()> {
+        let ctx = session.create_query_context().await?;
+        let tbl = ctx.get_table(CATALOG_DEFAULT, &database, &table).await?;
+        let fuse_table = FuseTable::try_from_table(tbl.as_ref())?;
+        if !Self::has_purgeable_data(fuse_table).await? {
+            debug!(job = "vacuum", background = true, database = database.clone(), table = table.clone(); "skip vacuum, no purgeable data");
+            return Ok(());
+        }
+
+        let job_info = self.get_info().await?;
+        let id = Uuid::new_v4().to_string();
+        let status = Self::sync_vacuum_status(id.clone(), &job_info);
+        self.update_job_status(status).await?;
+
+        info!(job = "vacuum", background = true, id = id.clone(), database = database.clone(), table = table.clone(); "start vacuum");
+        let task_name = BackgroundTaskIdent {
+            tenant: self.creator.tenant.clone(),
+            task_id: id.clone(),
+        };
+        let mut info = BackgroundTaskInfo::new_vacuum_task(
+            self.creator.clone(),
+            db_id,
+            tb_id,
+            manual,
+            format!("vacuum table {}.{}", database, table),
+        );
+        self.meta_api
+            .update_background_task(UpdateBackgroundTaskReq {
+                task_name: task_name.clone(),
+                task_info: info.clone(),
+                expire_at: Utc::now().timestamp() as u64 + EXPIRE_SEC,
+            })
+            .await?;
+
+        let start = Instant::now();
+        match self.do_vacuum_table(ctx, fuse_table).await {
+            Ok(removed) => {
+                if let Some(stats) = info.vacuum_stats.as_mut() {
+                    stats.removed_files = removed.files;
+                    stats.removed_bytes = removed.bytes;
+                    stats.total_vacuum_time = Some(start.elapsed());
+                }
+                Self::set_task_status(&mut info, BackgroundTaskState::DONE);
+                info!(job = "vacuum", background = true, id = id.clone(), database = database.clone(), table = table.clone(), removed_files = removed.files, removed_bytes = removed.bytes; "finish vacuum");
+            }
+            Err(e) => {
+                info.message = format!("vacuum failed: {:?}", e);
+                Self::set_task_status(&mut info, BackgroundTaskState::FAILED);
+            }
+        }
+        self.meta_api
+            .update_background_task(UpdateBackgroundTaskReq {
+                task_name,
+                task_info: info,
+                expire_at: Utc::now().timestamp() as u64 + EXPIRE_SEC,
+            })
+            .await?;
+        Ok(())
+    }
+
+    // vacuum the table and return the number and the size of removed files
+    async fn do_vacuum_table(
+        &self,
+        ctx: Arc<QueryContext>,
+        fuse_table: &FuseTable,
+    ) -> Result<PurgeStats> {
+        let hours = match self.conf.background.vacuum.retain_hours {
+            Some(hours) => hours as i64,
+            None => ctx.get_settings().get_retention_period()? as i64,
+        };
+        let retention_time = Utc::now() - chrono::Duration::hours(hours);
+        do_vacuum_and_count(fuse_table, ctx, retention_time).await
+    }
+
+    /// A table has purgeable data if any snapshot other than the current one is left.
+    ///
+    /// Orphan files left by failed writes of a table without history are purged once the
+    /// table has history.
+    pub async fn has_purgeable_data(fuse_table: &FuseTable) -> Result<bool> {
+        let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
+            return Ok(false);
+        };
+        let Some((prev_id, prev_version)) = snapshot.prev_snapshot_id else {
+            return Ok(false);
+        };
+        let prev_location = fuse_table
+            .meta_location_generator()
+            .snapshot_location_from_uuid(&prev_id, prev_version)?;
+        Ok(fuse_table
+            .get_operator_ref()
+            .is_exist(&prev_location)
+            .await?)
+    }
+
+    fn set_task_status(info: &mut BackgroundTaskInfo, state: BackgroundTaskState) {
+        info.task_state = state;
+        info.last_updated = Some(Utc::now());
+    }
+
+    pub async fn do_get_target_tables(
+        ctx: Arc<QueryContext>,
+    ) -> Result<Vec<arrow_array::RecordBatch>> {
+        let sql = Self::get_target_tables_sql();
+        info!(
+            job = "vacuum",
+            background = true,
+            sql = sql.as_str();
+            "get target tables"
+        );
+        let res = SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        Ok(res.into_iter().collect())
+    }
+
+    pub fn get_target_tables_sql() -> String {
+        "
+        SELECT t.database as database, d.database_id as database_id, t.name as table, t.table_id as table_id
+        FROM system.tables as t
+        JOIN system.databases as d
+        ON t.database = d.name
+        WHERE t.database != 'system'
+            AND t.database != 'information_schema'
+            AND t.engine = 'FUSE'
+            ;
+        "
+        .to_string()
+    }
+
+    pub fn get_vacuum_drop_tables_sql(retain_hours: Option<u64>) -> String {
+        match retain_hours {
+            Some(hours) => format!("VACUUM DROP TABLE RETAIN {} HOURS;", hours),
+            None => "VACUUM DROP TABLE;".to_string(),
+        }
+    }
+}
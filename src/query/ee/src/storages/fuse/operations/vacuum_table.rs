@@ -28,6 +28,7 @@ use common_storages_fuse::io::MetaReaders;
 use common_storages_fuse::io::SnapshotLiteExtended;
 use common_storages_fuse::io::SnapshotsIO;
 use common_storages_fuse::io::TableMetaLocationGenerator;
+use common_storages_fuse::operations::PurgeStats;
 use common_storages_fuse::FuseTable;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::CompactSegmentInfo;
@@ -170,11 +171,11 @@ pub async fn do_gc_orphan_files(
     ctx: &Arc<dyn TableContext>,
     retention_time: DateTime<Utc>,
     start: Instant,
-) -> Result<()> {
+) -> Result<PurgeStats> {
     // 1. Get all the files referenced by the current snapshot
     let referenced_files = match get_snapshot_referenced_files(fuse_table, ctx).await? {
         Some(referenced_files) => referenced_files,
-        None => return Ok(()),
+        None => return Ok(PurgeStats::default()),
     };
     let status = format!(
         "gc orphan: read referenced files:{},{},{}, cost:{} sec",
@@ -199,11 +200,17 @@ pub async fn do_gc_orphan_files(
     ctx.set_status_info(&status);
 
     // 2.2 Delete all the orphan segment files to be purged
+    let mut purged = PurgeStats::default();
     let purged_file_num = segment_locations_to_be_purged.len();
+    let segment_locations_to_be_purged = HashSet::from_iter(segment_locations_to_be_purged);
+    purged.files += purged_file_num as u64;
+    purged.bytes += fuse_table
+        .get_location_files_size(ctx.clone(), &segment_locations_to_be_purged)
+        .await?;
     fuse_table
         .try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
             ctx.clone(),
-            HashSet::from_iter(segment_locations_to_be_purged.into_iter()),
+            segment_locations_to_be_purged,
         )
         .await?;
     let status = format!(
@@ -226,11 +233,13 @@ pub async fn do_gc_orphan_files(
 
     // 3.2 Delete all the orphan block files to be purged
     let purged_file_num = block_locations_to_be_purged.len();
+    let block_locations_to_be_purged = HashSet::from_iter(block_locations_to_be_purged);
+    purged.files += purged_file_num as u64;
+    purged.bytes += fuse_table
+        .get_location_files_size(ctx.clone(), &block_locations_to_be_purged)
+        .await?;
     fuse_table
-        .try_purge_location_files(
-            ctx.clone(),
-            HashSet::from_iter(block_locations_to_be_purged.into_iter()),
-        )
+        .try_purge_location_files(ctx.clone(), block_locations_to_be_purged)
         .await?;
     let status = format!(
         "gc orphan: purged block files:{}, cost:{} sec",
@@ -253,11 +262,13 @@ pub async fn do_gc_orphan_files(
 
     // 4.2 Delete all the orphan block index files to be purged
     let purged_file_num = index_locations_to_be_purged.len();
+    let index_locations_to_be_purged = HashSet::from_iter(index_locations_to_be_purged);
+    purged.files += purged_file_num as u64;
+    purged.bytes += fuse_table
+        .get_location_files_size(ctx.clone(), &index_locations_to_be_purged)
+        .await?;
     fuse_table
-        .try_purge_location_files(
-            ctx.clone(),
-            HashSet::from_iter(index_locations_to_be_purged.into_iter()),
-        )
+        .try_purge_location_files(ctx.clone(), index_locations_to_be_purged)
         .await?;
     let status = format!(
         "gc orphan: purged block index files:{}, cost:{} sec",
@@ -266,7 +277,7 @@ pub async fn do_gc_orphan_files(
     );
     ctx.set_status_info(&status);
 
-    Ok(())
+    Ok(purged)
 }
 
 #[async_backtrace::framed]
@@ -345,43 +356,77 @@ pub async fn do_vacuum(
     retention_time: DateTime<Utc>,
     dry_run: bool,
 ) -> Result<Option<Vec<String>>> {
+    if !dry_run {
+        do_vacuum_and_count(fuse_table, ctx, retention_time).await?;
+        return Ok(None);
+    }
+
     let start = Instant::now();
     // First, do purge
     let instant = Some(NavigationPoint::TimePoint(retention_time));
-    let dry_run_limit = if dry_run { Some(DRY_RUN_LIMIT) } else { None };
-    let purge_files_opt = fuse_table
-        .purge(ctx.clone(), instant, dry_run_limit, true, dry_run)
-        .await?;
+    let dry_run_limit = DRY_RUN_LIMIT;
+    let mut purge_files = fuse_table
+        .purge(ctx.clone(), instant, Some(dry_run_limit), true, true)
+        .await?
+        .unwrap_or_default();
     let status = format!(
         "do_vacuum: purged table, cost:{} sec",
         start.elapsed().as_secs()
     );
     ctx.set_status_info(&status);
-    let retention = Duration::hours(ctx.get_settings().get_retention_period()? as i64);
-    // use min(now - get_retention_period(), retention_time) as gc orphan files retention time
-    // to protect files that generated by txn which has not been committed being gc.
-    let retention_time = std::cmp::min(chrono::Utc::now() - retention, retention_time);
-    if let Some(mut purge_files) = purge_files_opt {
-        let dry_run_limit = dry_run_limit.unwrap();
-        if purge_files.len() < dry_run_limit {
-            do_dry_run_orphan_files(
-                fuse_table,
-                &ctx,
-                retention_time,
-                start,
-                &mut purge_files,
-                dry_run_limit,
-            )
-            .await?;
-        }
+    let retention_time = orphan_retention_time(&ctx, retention_time)?;
+    if purge_files.len() < dry_run_limit {
+        do_dry_run_orphan_files(
+            fuse_table,
+            &ctx,
+            retention_time,
+            start,
+            &mut purge_files,
+            dry_run_limit,
+        )
+        .await?;
+    }
 
-        if purge_files.len() > dry_run_limit {
-            purge_files = purge_files.into_iter().take(dry_run_limit).collect();
-        }
-        Ok(Some(purge_files))
-    } else {
-        debug_assert!(dry_run_limit.is_none());
-        do_gc_orphan_files(fuse_table, &ctx, retention_time, start).await?;
-        Ok(None)
+    if purge_files.len() > dry_run_limit {
+        purge_files = purge_files.into_iter().take(dry_run_limit).collect();
     }
+    Ok(Some(purge_files))
+}
+
+/// Vacuum the table like `do_vacuum` without dry run, returns the number and the total size
+/// of the files removed.
+#[async_backtrace::framed]
+pub async fn do_vacuum_and_count(
+    fuse_table: &FuseTable,
+    ctx: Arc<dyn TableContext>,
+    retention_time: DateTime<Utc>,
+) -> Result<PurgeStats> {
+    let start = Instant::now();
+    // First, do purge
+    let instant = Some(NavigationPoint::TimePoint(retention_time));
+    let purged = fuse_table.purge_and_count(&ctx, instant).await?;
+    let status = format!(
+        "do_vacuum: purged table, cost:{} sec",
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+    let retention_time = orphan_retention_time(&ctx, retention_time)?;
+    let orphan = do_gc_orphan_files(fuse_table, &ctx, retention_time, start).await?;
+    Ok(PurgeStats {
+        files: purged.files + orphan.files,
+        bytes: purged.bytes + orphan.bytes,
+    })
+}
+
+// use min(now - get_retention_period(), retention_time) as gc orphan files retention time
+// to protect files that generated by txn which has not been committed being gc.
+fn orphan_retention_time(
+    ctx: &Arc<dyn TableContext>,
+    retention_time: DateTime<Utc>,
+) -> Result<DateTime<Utc>> {
+    let retention = Duration::hours(ctx.get_settings().get_retention_period()? as i64);
+    Ok(std::cmp::min(
+        chrono::Utc::now() - retention,
+        retention_time,
+    ))
 }
@@ -22,12 +22,16 @@ use chrono_tz::Tz;
 use common_base::base::tokio;
 use common_base::base::tokio::sync::mpsc::Sender;
 use common_base::base::tokio::sync::Mutex;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::background::BackgroundJobIdent;
 use common_meta_app::background::BackgroundJobInfo;
 use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobState;
 use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::SqlTaskDefinition;
 use common_meta_app::principal::UserIdentity;
+use databend_query::test_kits::table_test_fixture::execute_command;
 use databend_query::test_kits::TestFixture;
 use enterprise_query::background_service::Job;
 use enterprise_query::background_service::JobScheduler;
@@ -44,6 +48,7 @@ fn new_info(params: BackgroundJobParams, status: BackgroundJobStatus) -> Backgro
         job_params: Some(params),
         job_status: Some(status),
         task_type: Default::default(),
+        sql_task: None,
         last_updated: None,
         message: "".to_string(),
         creator: None,
@@ -219,3 +224,132 @@ async fn test_should_run_job_cron() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_should_run_sql_task_job() -> Result<()> {
+    let current_time = chrono::Utc::now();
+    let definition = SqlTaskDefinition {
+        query_text: "INSERT INTO t SELECT 1".to_string(),
+        database: "default".to_string(),
+        warehouse: None,
+        after: vec![],
+        comment: None,
+    };
+    let mut sql_task = BackgroundJobInfo::new_sql_task_job(
+        BackgroundJobParams::new_interval_job(std::time::Duration::from_secs(60)),
+        definition,
+        UserIdentity::new("root", "%"),
+    );
+
+    // a created task is suspended until it is resumed.
+    assert!(!JobScheduler::should_run_job(
+        &sql_task,
+        current_time,
+        false
+    ));
+
+    sql_task.job_status.as_mut().unwrap().job_state = BackgroundJobState::RUNNING;
+    assert!(JobScheduler::should_run_job(
+        &sql_task,
+        current_time + chrono::Duration::seconds(1),
+        false
+    ));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sql_task_runs_in_warehouse() -> Result<()> {
+    let new_task = |warehouse: Option<&str>| {
+        BackgroundJobInfo::new_sql_task_job(
+            BackgroundJobParams::new_interval_job(std::time::Duration::from_secs(60)),
+            SqlTaskDefinition {
+                query_text: "INSERT INTO t SELECT 1".to_string(),
+                database: "default".to_string(),
+                warehouse: warehouse.map(|w| w.to_string()),
+                after: vec![],
+                comment: None,
+            },
+            UserIdentity::new("root", "%"),
+        )
+    };
+
+    assert!(JobScheduler::runs_in_warehouse(&new_task(None), "wh1"));
+    assert!(JobScheduler::runs_in_warehouse(
+        &new_task(Some("wh1")),
+        "wh1"
+    ));
+    assert!(!JobScheduler::runs_in_warehouse(
+        &new_task(Some("wh2")),
+        "wh1"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_elect_sql_task_runner() -> Result<()> {
+    let _fixture = TestFixture::new().await;
+
+    let name = BackgroundJobIdent {
+        tenant: "test".to_string(),
+        name: "elect_task".to_string(),
+    };
+    let scheduled_time = Utc.with_ymd_and_hms(2023, 6, 15, 11, 0, 0).unwrap();
+    let new_task = |time| {
+        new_info(
+            BackgroundJobParams::new_interval_job(std::time::Duration::from_secs(60)),
+            BackgroundJobStatus {
+                job_state: BackgroundJobState::RUNNING,
+                last_task_id: None,
+                last_task_run_at: None,
+                next_task_scheduled_time: Some(time),
+            },
+        )
+    };
+
+    // only one node runs the task at the scheduled time.
+    let task = new_task(scheduled_time);
+    assert!(JobScheduler::elect_runner(&name, &task).await?);
+    assert!(!JobScheduler::elect_runner(&name, &task).await?);
+
+    // the next scheduled run is elected again.
+    let task = new_task(scheduled_time + chrono::Duration::seconds(60));
+    assert!(JobScheduler::elect_runner(&name, &task).await?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sql_task_runs_after_task_of_same_warehouse() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    execute_command(
+        ctx.clone(),
+        "CREATE TASK wh_t1 WAREHOUSE = 'wh1' SCHEDULE = 5 MINUTE AS SELECT 1",
+    )
+    .await?;
+
+    // the successor runs on the node which runs the predecessor.
+    let res = execute_command(
+        ctx.clone(),
+        "CREATE TASK wh_t2 WAREHOUSE = 'wh2' SCHEDULE = 5 MINUTE AFTER wh_t1 AS SELECT 1",
+    )
+    .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::INVALID_ARGUMENT);
+    let res = execute_command(
+        ctx.clone(),
+        "CREATE TASK wh_t2 SCHEDULE = 5 MINUTE AFTER wh_t1 AS SELECT 1",
+    )
+    .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::INVALID_ARGUMENT);
+
+    execute_command(
+        ctx,
+        "CREATE TASK wh_t2 WAREHOUSE = 'wh1' SCHEDULE = 5 MINUTE AFTER wh_t1 AS SELECT 1",
+    )
+    .await?;
+
+    Ok(())
+}
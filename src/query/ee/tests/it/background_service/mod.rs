@@ -14,3 +14,4 @@
 
 mod compaction_job;
 mod job_scheduler;
+mod vacuum_job;
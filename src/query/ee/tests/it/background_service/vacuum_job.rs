@@ -0,0 +1,70 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_storages_fuse::FuseTable;
+use databend_query::test_kits::table_test_fixture::append_sample_data;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use enterprise_query::background_service::VacuumJob;
+use enterprise_query::storages::fuse::do_vacuum_and_count;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_vacuum_drop_tables_sql() -> Result<()> {
+    let sql = VacuumJob::get_vacuum_drop_tables_sql(None);
+    assert_eq!(sql.trim(), "VACUUM DROP TABLE;");
+    let sql = VacuumJob::get_vacuum_drop_tables_sql(Some(24));
+    assert_eq!(sql.trim(), "VACUUM DROP TABLE RETAIN 24 HOURS;");
+    Ok(())
+}
+
+async fn has_purgeable_data(fixture: &TestFixture) -> Result<bool> {
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    VacuumJob::has_purgeable_data(fuse_table).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_vacuum_purgeable_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx: Arc<dyn TableContext> = fixture.ctx();
+    ctx.get_settings().set_retention_period(0)?;
+    fixture.create_default_table().await?;
+
+    // a table without history has nothing to purge
+    assert!(!has_purgeable_data(&fixture).await?);
+    append_sample_data(1, &fixture).await?;
+    assert!(!has_purgeable_data(&fixture).await?);
+
+    append_sample_data(1, &fixture).await?;
+    assert!(has_purgeable_data(&fixture).await?);
+
+    // the first snapshot is removed, the segments are referenced by the current one
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let removed = do_vacuum_and_count(fuse_table, ctx.clone(), Utc::now()).await?;
+    assert!(removed.files >= 1);
+    assert!(removed.bytes > 0);
+    assert!(!has_purgeable_data(&fixture).await?);
+
+    // nothing is removed twice
+    let removed = do_vacuum_and_count(fuse_table, ctx, Utc::now()).await?;
+    assert_eq!(removed.files, 0);
+    assert_eq!(removed.bytes, 0);
+    Ok(())
+}
@@ -27,6 +27,9 @@ use crate::DataSchema;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INTERVAL;
+use crate::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -48,6 +51,9 @@ impl From<&DataField> for ArrowField {
             DataType::EmptyMap => Some(ARROW_EXT_TYPE_EMPTY_MAP.to_string()),
             DataType::Variant => Some(ARROW_EXT_TYPE_VARIANT.to_string()),
             DataType::Bitmap => Some(ARROW_EXT_TYPE_BITMAP.to_string()),
+            DataType::Interval => Some(ARROW_EXT_TYPE_INTERVAL.to_string()),
+            DataType::TimestampTz => Some(ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string()),
+            DataType::Geometry => Some(ARROW_EXT_TYPE_GEOMETRY.to_string()),
             _ => None,
         };
 
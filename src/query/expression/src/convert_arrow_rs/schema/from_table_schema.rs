@@ -29,6 +29,9 @@ use crate::TableField;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INTERVAL;
+use crate::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -40,6 +43,9 @@ impl From<&TableField> for ArrowField {
             TableDataType::EmptyMap => Some(ARROW_EXT_TYPE_EMPTY_MAP.to_string()),
             TableDataType::Variant => Some(ARROW_EXT_TYPE_VARIANT.to_string()),
             TableDataType::Bitmap => Some(ARROW_EXT_TYPE_BITMAP.to_string()),
+            TableDataType::Interval => Some(ARROW_EXT_TYPE_INTERVAL.to_string()),
+            TableDataType::TimestampTz => Some(ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string()),
+            TableDataType::Geometry => Some(ARROW_EXT_TYPE_GEOMETRY.to_string()),
             _ => None,
         };
 
@@ -78,6 +84,8 @@ impl From<&TableDataType> for ArrowDataType {
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             TableDataType::Date => ArrowDataType::Date32,
+            TableDataType::Interval => ArrowDataType::Decimal128(38, 0),
+            TableDataType::TimestampTz => ArrowDataType::Decimal128(38, 0),
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -122,6 +130,7 @@ impl From<&TableDataType> for ArrowDataType {
 
             TableDataType::Bitmap => ArrowDataType::LargeBinary,
             TableDataType::Variant => ArrowDataType::LargeBinary,
+            TableDataType::Geometry => ArrowDataType::LargeBinary,
         }
     }
 }
@@ -26,6 +26,9 @@ use crate::TableSchema;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INTERVAL;
+use crate::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -66,6 +69,9 @@ impl TryFrom<&ArrowField> for TableDataType {
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(TableDataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(TableDataType::Variant),
             Some(ARROW_EXT_TYPE_BITMAP) => Some(TableDataType::Bitmap),
+            Some(ARROW_EXT_TYPE_INTERVAL) => Some(TableDataType::Interval),
+            Some(ARROW_EXT_TYPE_TIMESTAMP_TZ) => Some(TableDataType::TimestampTz),
+            Some(ARROW_EXT_TYPE_GEOMETRY) => Some(TableDataType::Geometry),
             _ => None,
         };
 
@@ -93,7 +99,8 @@ impl TryFrom<&ArrowField> for TableDataType {
                 TableDataType::Number(NumberDataType::Float32)
             }
             ArrowDataType::Float64 => TableDataType::Number(NumberDataType::Float64),
-            ArrowDataType::Timestamp(_unit, _tz) => TableDataType::Timestamp,
+            ArrowDataType::Timestamp(_unit, Some(_tz)) => TableDataType::TimestampTz,
+            ArrowDataType::Timestamp(_unit, None) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
             ArrowDataType::Utf8
             | ArrowDataType::LargeUtf8
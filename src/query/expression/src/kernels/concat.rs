@@ -35,11 +35,14 @@ use crate::types::ArrayType;
 use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DateType;
+use crate::types::GeometryType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NullableType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -182,6 +185,22 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| IntervalType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                let builder = Self::concat_primitive_types(&columns, capacity);
+                Column::Interval(builder.into())
+            }
+            Column::TimestampTz(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| TimestampTzType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                let builder = Self::concat_primitive_types(&columns, capacity);
+                Column::TimestampTz(builder.into())
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -251,6 +270,13 @@ impl Column {
                     .collect_vec();
                 VariantType::upcast_column(Self::concat_string_types(&columns, capacity))
             }
+            Column::Geometry(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| GeometryType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                GeometryType::upcast_column(Self::concat_string_types(&columns, capacity))
+            }
         }
     }
 
@@ -132,6 +132,14 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::filter_primitive_types(column, filter);
+                Column::Interval(i)
+            }
+            Column::TimestampTz(column) => {
+                let t = Self::filter_primitive_types(column, filter);
+                Column::TimestampTz(t)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -182,6 +190,10 @@ impl Column {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::Variant(column)
             }
+            Column::Geometry(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Geometry(column)
+            }
         }
     }
 
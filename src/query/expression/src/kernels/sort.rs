@@ -223,6 +223,22 @@ fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCompar
     }))
 }
 
+fn compare_geometry(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Geometry)
+        .as_geometry()
+        .cloned()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Geometry)
+        .as_geometry()
+        .cloned()
+        .unwrap();
+    Ok(Box::new(move |i, j| {
+        let l = unsafe { left.index_unchecked(i) };
+        let r = unsafe { right.index_unchecked(j) };
+        l.cmp(r)
+    }))
+}
+
 fn compare_null() -> ArrowResult<DynComparator> {
     Ok(Box::new(move |_, _| Ordering::Equal))
 }
@@ -246,6 +262,7 @@ fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparat
     match left.data_type() {
         ArrowType::Extension(name, _, _) => match name.as_str() {
             "Variant" => compare_variant(left, right),
+            "Geometry" => compare_geometry(left, right),
             "EmptyArray" | "EmptyMap" => compare_null(),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Sort not supported for data type {:?}",
@@ -30,6 +30,7 @@ use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -130,6 +131,14 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_compacted_primitive_types(column, indices, num_rows);
+                Column::Interval(builder.into())
+            }
+            Column::TimestampTz(column) => {
+                let builder = Self::take_compacted_primitive_types(column, indices, num_rows);
+                Column::TimestampTz(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(num_rows + 1);
                 offsets.push(0);
@@ -179,6 +188,9 @@ impl Column {
             Column::Variant(column) => VariantType::upcast_column(Self::take_compact_string_types(
                 column, indices, num_rows,
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(
+                Self::take_compact_string_types(column, indices, num_rows),
+            ),
         }
     }
 
@@ -16,7 +16,9 @@ use common_arrow::arrow::bitmap::Bitmap;
 use ethnum::i256;
 
 use super::row_converter::null_sentinel;
+use crate::types::interval::months_days_micros;
 use crate::types::string::StringColumnBuilder;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::F32;
 use crate::types::F64;
 
@@ -98,6 +100,28 @@ impl FixedLengthEncoding for F64 {
     }
 }
 
+impl FixedLengthEncoding for months_days_micros {
+    type Encoded = [u8; 32];
+
+    fn encode(self) -> [u8; 32] {
+        // Order by the normalized length first, and by the raw fields to break ties,
+        // which matches the `Ord` implementation of `months_days_micros`.
+        let mut b = [0; 32];
+        b[..16].copy_from_slice(&self.total_micros().encode());
+        b[16..].copy_from_slice(&self.0.encode());
+        b
+    }
+}
+
+impl FixedLengthEncoding for timestamp_tz {
+    type Encoded = [u8; 8];
+
+    fn encode(self) -> [u8; 8] {
+        // Only the instant, which matches the `Ord` implementation of `timestamp_tz`.
+        self.timestamp().encode()
+    }
+}
+
 pub fn encode<T, I>(
     out: &mut StringColumnBuilder,
     iter: I,
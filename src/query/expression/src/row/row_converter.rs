@@ -22,8 +22,10 @@ use super::fixed;
 use super::fixed::FixedLengthEncoding;
 use super::variable;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
@@ -36,7 +38,7 @@ use crate::SortField;
 
 /// Convert column-oriented data into comparable row-oriented data.
 ///
-/// **NOTE**: currently, Variant is treat as String.
+/// **NOTE**: currently, Variant and Geometry are treat as String.
 pub struct RowConverter {
     fields: Arc<[SortField]>,
 }
@@ -123,6 +125,12 @@ impl RowConverter {
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
+                DataType::Interval => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += months_days_micros::ENCODED_LEN as u64),
+                DataType::TimestampTz => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += timestamp_tz::ENCODED_LEN as u64),
                 DataType::String => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -169,6 +177,29 @@ impl RowConverter {
                             })
                     }
                 }
+                DataType::Geometry => {
+                    let col = col.remove_nullable();
+                    if all_null {
+                        lengths.iter_mut().for_each(|x| *x += 1)
+                    } else if let Some(validity) = validity {
+                        col.as_geometry()
+                            .unwrap()
+                            .iter()
+                            .zip(validity.iter())
+                            .zip(lengths.iter_mut())
+                            .for_each(|((bytes, v), length)| {
+                                *length += variable::encoded_len(bytes, !v) as u64
+                            })
+                    } else {
+                        col.as_geometry()
+                            .unwrap()
+                            .iter()
+                            .zip(lengths.iter_mut())
+                            .for_each(|(bytes, length)| {
+                                *length += variable::encoded_len(bytes, false) as u64
+                            })
+                    }
+                }
                 _ => unimplemented!(),
             }
         }
@@ -231,8 +262,11 @@ fn encode_column(out: &mut StringColumnBuilder, column: &Column, asc: bool, null
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Interval(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::TimestampTz(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::String(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::Variant(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
+        Column::Geometry(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         _ => unimplemented!(),
     }
 }
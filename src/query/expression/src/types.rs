@@ -21,6 +21,8 @@ pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
+pub mod geometry;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -28,6 +30,7 @@ pub mod number;
 pub mod number_class;
 pub mod string;
 pub mod timestamp;
+pub mod timestamp_tz;
 pub mod variant;
 
 use std::fmt::Debug;
@@ -48,6 +51,8 @@ pub use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::geometry::GeometryType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -55,6 +60,7 @@ pub use self::number::*;
 pub use self::number_class::*;
 pub use self::string::StringType;
 pub use self::timestamp::TimestampType;
+pub use self::timestamp_tz::TimestampTzType;
 pub use self::variant::VariantType;
 use crate::property::Domain;
 use crate::values::Column;
@@ -75,12 +81,15 @@ pub enum DataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
+    TimestampTz,
     Nullable(Box<DataType>),
     Array(Box<DataType>),
     Map(Box<DataType>),
     Bitmap,
     Tuple(Vec<DataType>),
     Variant,
+    Geometry,
     Generic(usize),
 }
 
@@ -191,7 +200,7 @@ impl DataType {
     #[inline]
     pub fn is_string_column(&self) -> bool {
         match self {
-            DataType::String | DataType::Bitmap | DataType::Variant => true,
+            DataType::String | DataType::Bitmap | DataType::Variant | DataType::Geometry => true,
             DataType::Nullable(ty) => ty.is_string_column(),
             _ => false,
         }
@@ -246,6 +255,7 @@ impl DataType {
                 NumberDataType::Float64 => "DOUBLE".to_string(),
             },
             DataType::String => "VARCHAR".to_string(),
+            DataType::TimestampTz => "TIMESTAMP_TZ".to_string(),
             DataType::Nullable(inner_ty) => format!("{} NULL", inner_ty.sql_name()),
             _ => self.to_string().to_uppercase(),
         }
@@ -0,0 +1,996 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::ops::Range;
+
+use geo_types::Coord;
+use geo_types::Geometry;
+use geo_types::GeometryCollection;
+use geo_types::LineString;
+use geo_types::MultiLineString;
+use geo_types::MultiPoint;
+use geo_types::MultiPolygon;
+use geo_types::Point;
+use geo_types::Polygon;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::property::Domain;
+use crate::types::number::F64;
+use crate::types::string::StringColumn;
+use crate::types::string::StringColumnBuilder;
+use crate::types::string::StringIterator;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+/// The EWKB flag announcing an embedded SRID, as written by PostGIS.
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+
+/// Geometries are stored as little-endian, two-dimensional ISO WKB.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryType;
+
+/// The bounding box of the geometries in a column.
+///
+/// A box with `min > max` contains no point, it is the domain of empty
+/// geometries such as `GEOMETRYCOLLECTION EMPTY`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeometryDomain {
+    pub min_x: F64,
+    pub min_y: F64,
+    pub max_x: F64,
+    pub max_y: F64,
+}
+
+impl GeometryDomain {
+    pub fn empty() -> Self {
+        GeometryDomain {
+            min_x: F64::from(f64::INFINITY),
+            min_y: F64::from(f64::INFINITY),
+            max_x: F64::from(f64::NEG_INFINITY),
+            max_y: F64::from(f64::NEG_INFINITY),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min_x > self.max_x || self.min_y > self.max_y
+    }
+
+    pub fn merge(&self, other: &GeometryDomain) -> GeometryDomain {
+        GeometryDomain {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    fn add_coord(&mut self, coord: &Coord<f64>) {
+        self.min_x = self.min_x.min(F64::from(coord.x));
+        self.min_y = self.min_y.min(F64::from(coord.y));
+        self.max_x = self.max_x.max(F64::from(coord.x));
+        self.max_y = self.max_y.max(F64::from(coord.y));
+    }
+
+    /// Whether some geometry in `self` may share a point with some geometry in `other`.
+    pub fn intersects(&self, other: &GeometryDomain) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    /// Whether the box of `other` lies inside the box of `self`.
+    pub fn contains(&self, other: &GeometryDomain) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.min_x <= other.min_x
+            && self.min_y <= other.min_y
+            && other.max_x <= self.max_x
+            && other.max_y <= self.max_y
+    }
+}
+
+impl ValueType for GeometryType {
+    type Scalar = Vec<u8>;
+    type ScalarRef<'a> = &'a [u8];
+    type Column = StringColumn;
+    type Domain = GeometryDomain;
+    type ColumnIterator<'a> = StringIterator<'a>;
+    type ColumnBuilder = StringColumnBuilder;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: Self::ScalarRef<'long>) -> Self::ScalarRef<'short> {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar.to_vec()
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_geometry().cloned()
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        col.as_geometry().cloned()
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            crate::ColumnBuilder::Geometry(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        domain.as_geometry().cloned()
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Geometry(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Geometry(col)
+    }
+
+    fn upcast_domain(domain: Self::Domain) -> Domain {
+        Domain::Geometry(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.index(index)
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        col.index_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.slice(range)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        StringColumnBuilder::from_column(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.put_slice(item);
+        builder.commit_row();
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.put_slice(&default_geometry());
+        builder.commit_row();
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.append_column(other)
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.build()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        builder.build_scalar()
+    }
+
+    fn scalar_memory_size<'a>(scalar: &Self::ScalarRef<'a>) -> usize {
+        scalar.len()
+    }
+
+    fn column_memory_size(col: &Self::Column) -> usize {
+        col.data().len() + col.offsets().len() * 8
+    }
+}
+
+impl ArgType for GeometryType {
+    fn data_type() -> DataType {
+        DataType::Geometry
+    }
+
+    fn full_domain() -> Self::Domain {
+        GeometryDomain {
+            min_x: F64::from(f64::NEG_INFINITY),
+            min_y: F64::from(f64::NEG_INFINITY),
+            max_x: F64::from(f64::INFINITY),
+            max_y: F64::from(f64::INFINITY),
+        }
+    }
+
+    fn create_builder(capacity: usize, _: &GenericMap) -> Self::ColumnBuilder {
+        StringColumnBuilder::with_capacity(capacity, 0)
+    }
+}
+
+/// The default value of the type, `GEOMETRYCOLLECTION EMPTY`.
+pub fn default_geometry() -> Vec<u8> {
+    geometry_to_wkb(&Geometry::GeometryCollection(GeometryCollection(vec![])))
+}
+
+/// Parse the text form of a geometry into WKB. WKT, EWKT (the SRID is
+/// discarded), GeoJSON and hex-encoded WKB are accepted.
+pub fn parse_geometry(buf: &[u8]) -> Result<Vec<u8>, String> {
+    // Raw WKB, e.g. the binary geometry columns of GeoParquet files, starts
+    // with its byte order marker which never begins a text form.
+    if matches!(buf.first(), Some(0) | Some(1)) {
+        return Ok(geometry_to_wkb(&wkb_to_geometry(buf)?));
+    }
+    let text = std::str::from_utf8(buf)
+        .map_err(|_| "geometry must be a valid utf-8 string".to_string())?
+        .trim();
+    let geometry = if text.starts_with('{') {
+        geojson_to_geometry(text)?
+    } else if !text.is_empty() && text.bytes().all(|b| b.is_ascii_hexdigit()) {
+        let wkb = hex::decode(text).map_err(|e| format!("invalid hex WKB: {e}"))?;
+        wkb_to_geometry(&wkb)?
+    } else {
+        wkt_to_geometry(text)?
+    };
+    Ok(geometry_to_wkb(&geometry))
+}
+
+/// The bounding box of a WKB value, `None` if the value cannot be decoded.
+pub fn geometry_bbox(wkb: &[u8]) -> Option<GeometryDomain> {
+    let geometry = wkb_to_geometry(wkb).ok()?;
+    let mut domain = GeometryDomain::empty();
+    visit_coords(&geometry, &mut |coord| domain.add_coord(coord));
+    Some(domain)
+}
+
+fn visit_coords(geometry: &Geometry<f64>, f: &mut impl FnMut(&Coord<f64>)) {
+    match geometry {
+        Geometry::Point(p) => f(&p.0),
+        Geometry::Line(l) => {
+            f(&l.start);
+            f(&l.end);
+        }
+        Geometry::LineString(ls) => ls.0.iter().for_each(f),
+        Geometry::Polygon(p) => {
+            p.exterior().0.iter().for_each(&mut *f);
+            for ring in p.interiors() {
+                ring.0.iter().for_each(&mut *f);
+            }
+        }
+        Geometry::MultiPoint(mp) => mp.0.iter().for_each(|p| f(&p.0)),
+        Geometry::MultiLineString(mls) => {
+            for ls in &mls.0 {
+                ls.0.iter().for_each(&mut *f);
+            }
+        }
+        Geometry::MultiPolygon(mp) => {
+            for p in &mp.0 {
+                visit_coords(&Geometry::Polygon(p.clone()), f);
+            }
+        }
+        Geometry::GeometryCollection(gc) => {
+            for g in &gc.0 {
+                visit_coords(g, f);
+            }
+        }
+        Geometry::Rect(r) => {
+            f(&r.min());
+            f(&r.max());
+        }
+        Geometry::Triangle(t) => {
+            f(&t.0);
+            f(&t.1);
+            f(&t.2);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// WKB
+// ---------------------------------------------------------------------------
+
+pub fn geometry_to_wkb(geometry: &Geometry<f64>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_wkb(geometry, &mut buf);
+    buf
+}
+
+fn write_header(kind: u32, buf: &mut Vec<u8>) {
+    buf.push(1);
+    buf.extend_from_slice(&kind.to_le_bytes());
+}
+
+fn write_coord(coord: &Coord<f64>, buf: &mut Vec<u8>) {
+    buf.extend_from_slice(&coord.x.to_le_bytes());
+    buf.extend_from_slice(&coord.y.to_le_bytes());
+}
+
+fn write_coords(coords: &[Coord<f64>], buf: &mut Vec<u8>) {
+    buf.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+    coords.iter().for_each(|c| write_coord(c, buf));
+}
+
+fn write_polygon_rings(polygon: &Polygon<f64>, buf: &mut Vec<u8>) {
+    if polygon.exterior().0.is_empty() {
+        buf.extend_from_slice(&0u32.to_le_bytes());
+        return;
+    }
+    buf.extend_from_slice(&(polygon.interiors().len() as u32 + 1).to_le_bytes());
+    write_coords(&polygon.exterior().0, buf);
+    for ring in polygon.interiors() {
+        write_coords(&ring.0, buf);
+    }
+}
+
+fn write_wkb(geometry: &Geometry<f64>, buf: &mut Vec<u8>) {
+    match geometry {
+        Geometry::Point(p) => {
+            write_header(WKB_POINT, buf);
+            write_coord(&p.0, buf);
+        }
+        Geometry::Line(l) => {
+            write_header(WKB_LINESTRING, buf);
+            write_coords(&[l.start, l.end], buf);
+        }
+        Geometry::LineString(ls) => {
+            write_header(WKB_LINESTRING, buf);
+            write_coords(&ls.0, buf);
+        }
+        Geometry::Polygon(p) => {
+            write_header(WKB_POLYGON, buf);
+            write_polygon_rings(p, buf);
+        }
+        Geometry::MultiPoint(mp) => {
+            write_header(WKB_MULTIPOINT, buf);
+            buf.extend_from_slice(&(mp.0.len() as u32).to_le_bytes());
+            for p in &mp.0 {
+                write_wkb(&Geometry::Point(*p), buf);
+            }
+        }
+        Geometry::MultiLineString(mls) => {
+            write_header(WKB_MULTILINESTRING, buf);
+            buf.extend_from_slice(&(mls.0.len() as u32).to_le_bytes());
+            for ls in &mls.0 {
+                write_header(WKB_LINESTRING, buf);
+                write_coords(&ls.0, buf);
+            }
+        }
+        Geometry::MultiPolygon(mp) => {
+            write_header(WKB_MULTIPOLYGON, buf);
+            buf.extend_from_slice(&(mp.0.len() as u32).to_le_bytes());
+            for p in &mp.0 {
+                write_header(WKB_POLYGON, buf);
+                write_polygon_rings(p, buf);
+            }
+        }
+        Geometry::GeometryCollection(gc) => {
+            write_header(WKB_GEOMETRYCOLLECTION, buf);
+            buf.extend_from_slice(&(gc.0.len() as u32).to_le_bytes());
+            for g in &gc.0 {
+                write_wkb(g, buf);
+            }
+        }
+        Geometry::Rect(r) => write_wkb(&Geometry::Polygon(r.to_polygon()), buf),
+        Geometry::Triangle(t) => write_wkb(&Geometry::Polygon(t.to_polygon()), buf),
+    }
+}
+
+/// Decode ISO WKB, or PostGIS EWKB whose SRID is discarded. Only
+/// two-dimensional geometries are supported.
+pub fn wkb_to_geometry(wkb: &[u8]) -> Result<Geometry<f64>, String> {
+    let mut reader = WkbReader { buf: wkb, pos: 0 };
+    let geometry = reader.read_geometry()?;
+    if reader.pos != wkb.len() {
+        return Err(format!(
+            "unexpected {} trailing bytes in WKB",
+            wkb.len() - reader.pos
+        ));
+    }
+    Ok(geometry)
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WkbReader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| "unexpected end of WKB".to_string())?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Result<u32, String> {
+        let bytes = self.take::<4>()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_len(&mut self, little_endian: bool) -> Result<usize, String> {
+        let len = self.read_u32(little_endian)? as usize;
+        // Every element takes at least one byte, reject lengths that can't fit.
+        if len > self.buf.len() - self.pos {
+            return Err("invalid element count in WKB".to_string());
+        }
+        Ok(len)
+    }
+
+    fn read_coord(&mut self, little_endian: bool) -> Result<Coord<f64>, String> {
+        let mut read_f64 = || -> Result<f64, String> {
+            let bytes = self.take::<8>()?;
+            Ok(if little_endian {
+                f64::from_le_bytes(bytes)
+            } else {
+                f64::from_be_bytes(bytes)
+            })
+        };
+        let x = read_f64()?;
+        let y = read_f64()?;
+        if !x.is_finite() || !y.is_finite() {
+            return Err("coordinates must be finite, POINT EMPTY is not supported".to_string());
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn read_coords(&mut self, little_endian: bool) -> Result<Vec<Coord<f64>>, String> {
+        let len = self.read_len(little_endian)?;
+        (0..len).map(|_| self.read_coord(little_endian)).collect()
+    }
+
+    fn read_polygon(&mut self, little_endian: bool) -> Result<Polygon<f64>, String> {
+        let len = self.read_len(little_endian)?;
+        let mut rings = (0..len)
+            .map(|_| self.read_coords(little_endian).map(LineString::new))
+            .collect::<Result<Vec<_>, _>>()?;
+        if rings.is_empty() {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn read_header(&mut self) -> Result<(bool, u32), String> {
+        let little_endian = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => return Err(format!("invalid WKB byte order {b}")),
+        };
+        let mut kind = self.read_u32(little_endian)?;
+        if kind & EWKB_SRID_FLAG != 0 {
+            self.read_u32(little_endian)?;
+            kind &= !EWKB_SRID_FLAG;
+        }
+        // ISO WKB adds 1000, 2000 or 3000 to the type for Z, M and ZM.
+        if kind & (EWKB_Z_FLAG | EWKB_M_FLAG) != 0 || kind >= 1000 {
+            return Err("only 2D geometries are supported".to_string());
+        }
+        Ok((little_endian, kind))
+    }
+
+    /// Read a member of a multi-geometry, which must be of the `expected` type.
+    fn read_member(&mut self, expected: u32) -> Result<Geometry<f64>, String> {
+        let pos = self.pos;
+        let (_, kind) = self.read_header()?;
+        if kind != expected {
+            return Err(format!("unexpected WKB geometry type {kind} in collection"));
+        }
+        self.pos = pos;
+        self.read_geometry()
+    }
+
+    fn read_geometry(&mut self) -> Result<Geometry<f64>, String> {
+        let (le, kind) = self.read_header()?;
+        let geometry = match kind {
+            WKB_POINT => Geometry::Point(Point(self.read_coord(le)?)),
+            WKB_LINESTRING => Geometry::LineString(LineString::new(self.read_coords(le)?)),
+            WKB_POLYGON => Geometry::Polygon(self.read_polygon(le)?),
+            WKB_MULTIPOINT => {
+                let len = self.read_len(le)?;
+                let points = (0..len)
+                    .map(|_| match self.read_member(WKB_POINT)? {
+                        Geometry::Point(p) => Ok(p),
+                        _ => unreachable!(),
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            WKB_MULTILINESTRING => {
+                let len = self.read_len(le)?;
+                let lines = (0..len)
+                    .map(|_| match self.read_member(WKB_LINESTRING)? {
+                        Geometry::LineString(ls) => Ok(ls),
+                        _ => unreachable!(),
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            WKB_MULTIPOLYGON => {
+                let len = self.read_len(le)?;
+                let polygons = (0..len)
+                    .map(|_| match self.read_member(WKB_POLYGON)? {
+                        Geometry::Polygon(p) => Ok(p),
+                        _ => unreachable!(),
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            WKB_GEOMETRYCOLLECTION => {
+                let len = self.read_len(le)?;
+                let geometries = (0..len)
+                    .map(|_| self.read_geometry())
+                    .collect::<Result<Vec<_>, String>>()?;
+                Geometry::GeometryCollection(GeometryCollection(geometries))
+            }
+            _ => return Err(format!("unknown WKB geometry type {kind}")),
+        };
+        Ok(geometry)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// WKT
+// ---------------------------------------------------------------------------
+
+pub fn geometry_to_wkt(geometry: &Geometry<f64>) -> String {
+    let mut buf = String::new();
+    write_wkt(geometry, &mut buf);
+    buf
+}
+
+fn write_wkt_coords(coords: &[Coord<f64>], buf: &mut String) {
+    buf.push('(');
+    for (i, c) in coords.iter().enumerate() {
+        if i > 0 {
+            buf.push(',');
+        }
+        write!(buf, "{} {}", c.x, c.y).unwrap();
+    }
+    buf.push(')');
+}
+
+fn write_wkt_polygon(polygon: &Polygon<f64>, buf: &mut String) {
+    buf.push('(');
+    write_wkt_coords(&polygon.exterior().0, buf);
+    for ring in polygon.interiors() {
+        buf.push(',');
+        write_wkt_coords(&ring.0, buf);
+    }
+    buf.push(')');
+}
+
+fn write_wkt_list<T>(tag: &str, items: &[T], buf: &mut String, mut f: impl FnMut(&T, &mut String)) {
+    buf.push_str(tag);
+    if items.is_empty() {
+        buf.push_str(" EMPTY");
+        return;
+    }
+    buf.push('(');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            buf.push(',');
+        }
+        f(item, buf);
+    }
+    buf.push(')');
+}
+
+fn write_wkt(geometry: &Geometry<f64>, buf: &mut String) {
+    match geometry {
+        Geometry::Point(p) => {
+            buf.push_str("POINT");
+            write_wkt_coords(&[p.0], buf);
+        }
+        Geometry::Line(l) => write_wkt(
+            &Geometry::LineString(LineString::new(vec![l.start, l.end])),
+            buf,
+        ),
+        Geometry::LineString(ls) => write_wkt_list("LINESTRING", &ls.0, buf, |c, buf| {
+            write!(buf, "{} {}", c.x, c.y).unwrap()
+        }),
+        Geometry::Polygon(p) => {
+            buf.push_str("POLYGON");
+            if p.exterior().0.is_empty() {
+                buf.push_str(" EMPTY");
+            } else {
+                write_wkt_polygon(p, buf);
+            }
+        }
+        Geometry::MultiPoint(mp) => write_wkt_list("MULTIPOINT", &mp.0, buf, |p, buf| {
+            write!(buf, "{} {}", p.x(), p.y()).unwrap()
+        }),
+        Geometry::MultiLineString(mls) => {
+            write_wkt_list("MULTILINESTRING", &mls.0, buf, |ls, buf| {
+                write_wkt_coords(&ls.0, buf)
+            })
+        }
+        Geometry::MultiPolygon(mp) => write_wkt_list("MULTIPOLYGON", &mp.0, buf, |p, buf| {
+            write_wkt_polygon(p, buf)
+        }),
+        Geometry::GeometryCollection(gc) => {
+            write_wkt_list("GEOMETRYCOLLECTION", &gc.0, buf, write_wkt)
+        }
+        Geometry::Rect(r) => write_wkt(&Geometry::Polygon(r.to_polygon()), buf),
+        Geometry::Triangle(t) => write_wkt(&Geometry::Polygon(t.to_polygon()), buf),
+    }
+}
+
+/// Parse WKT, or EWKT whose `SRID=<n>;` prefix is discarded.
+pub fn wkt_to_geometry(text: &str) -> Result<Geometry<f64>, String> {
+    let mut text = text.trim();
+    if text.len() >= 5 && text[..5].eq_ignore_ascii_case("SRID=") {
+        text = match text.find(';') {
+            Some(pos) => &text[pos + 1..],
+            None => return Err("invalid EWKT, missing ';' after SRID".to_string()),
+        };
+    }
+    let mut parser = WktParser { text, pos: 0 };
+    let geometry = parser.parse_geometry()?;
+    parser.skip_whitespace();
+    if parser.pos != text.len() {
+        return Err(format!(
+            "unexpected '{}' at position {} in WKT",
+            &text[parser.pos..],
+            parser.pos
+        ));
+    }
+    Ok(geometry)
+}
+
+struct WktParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> WktParser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.text.len() && self.text.as_bytes()[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.text.as_bytes().get(self.pos).cloned()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), String> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected '{}' at position {} in WKT",
+                c as char, self.pos
+            ))
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(u8) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self.pos < self.text.len() && f(self.text.as_bytes()[self.pos]) {
+            self.pos += 1;
+        }
+        let text = self.text;
+        &text[start..self.pos]
+    }
+
+    fn word(&mut self) -> String {
+        self.take_while(|b| b.is_ascii_alphabetic())
+            .to_ascii_uppercase()
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        let pos = self.pos;
+        let text = self.take_while(|b| b.is_ascii_digit() || b"+-.eE".contains(&b));
+        text.parse::<f64>()
+            .map_err(|_| format!("expected a number at position {pos} in WKT"))
+    }
+
+    fn coord(&mut self) -> Result<Coord<f64>, String> {
+        let x = self.number()?;
+        let y = self.number()?;
+        if !matches!(self.peek(), Some(b',') | Some(b')')) {
+            return Err("only 2D geometries are supported".to_string());
+        }
+        Ok(Coord { x, y })
+    }
+
+    /// `EMPTY` or a parenthesized, comma separated list of items.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let pos = self.pos;
+        if self.word() == "EMPTY" {
+            return Ok(vec![]);
+        }
+        self.pos = pos;
+        self.expect(b'(')?;
+        let mut items = vec![item(self)?];
+        while self.eat(b',') {
+            items.push(item(self)?);
+        }
+        self.expect(b')')?;
+        Ok(items)
+    }
+
+    fn coords(&mut self) -> Result<Vec<Coord<f64>>, String> {
+        self.list(|p| p.coord())
+    }
+
+    fn polygon(&mut self) -> Result<Polygon<f64>, String> {
+        let mut rings = self.list(|p| p.coords().map(LineString::new))?;
+        if rings.is_empty() {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn parse_geometry(&mut self) -> Result<Geometry<f64>, String> {
+        let tag = self.word();
+        let pos = self.pos;
+        if matches!(self.word().as_str(), "Z" | "M" | "ZM") {
+            return Err("only 2D geometries are supported".to_string());
+        }
+        self.pos = pos;
+        let geometry = match tag.as_str() {
+            "POINT" => {
+                let mut coords = self.coords()?;
+                if coords.len() != 1 {
+                    return Err("POINT must have exactly one coordinate".to_string());
+                }
+                Geometry::Point(Point(coords.remove(0)))
+            }
+            "LINESTRING" => Geometry::LineString(LineString::new(self.coords()?)),
+            "POLYGON" => Geometry::Polygon(self.polygon()?),
+            "MULTIPOINT" => {
+                let points = self.list(|p| {
+                    // Both `MULTIPOINT(0 0,1 1)` and `MULTIPOINT((0 0),(1 1))` are valid.
+                    if p.eat(b'(') {
+                        let coord = p.coord()?;
+                        p.expect(b')')?;
+                        Ok(Point(coord))
+                    } else {
+                        Ok(Point(p.coord()?))
+                    }
+                })?;
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            "MULTILINESTRING" => {
+                let lines = self.list(|p| p.coords().map(LineString::new))?;
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            "MULTIPOLYGON" => Geometry::MultiPolygon(MultiPolygon(self.list(|p| p.polygon())?)),
+            "GEOMETRYCOLLECTION" => {
+                Geometry::GeometryCollection(GeometryCollection(self.list(|p| p.parse_geometry())?))
+            }
+            "" => return Err(format!("expected a geometry type at position {pos} in WKT")),
+            _ => return Err(format!("unknown geometry type '{tag}'")),
+        };
+        Ok(geometry)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// GeoJSON
+// ---------------------------------------------------------------------------
+
+pub fn geometry_to_geojson(geometry: &Geometry<f64>) -> String {
+    let mut buf = String::new();
+    write_geojson(geometry, &mut buf);
+    buf
+}
+
+fn write_geojson_coord(coord: &Coord<f64>, buf: &mut String) {
+    write!(buf, "[{},{}]", coord.x, coord.y).unwrap();
+}
+
+fn write_geojson_array<T>(items: &[T], buf: &mut String, mut f: impl FnMut(&T, &mut String)) {
+    buf.push('[');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            buf.push(',');
+        }
+        f(item, buf);
+    }
+    buf.push(']');
+}
+
+fn write_geojson_polygon(polygon: &Polygon<f64>, buf: &mut String) {
+    if polygon.exterior().0.is_empty() {
+        buf.push_str("[]");
+        return;
+    }
+    let rings = std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .collect::<Vec<_>>();
+    write_geojson_array(&rings, buf, |ring, buf| {
+        write_geojson_array(&ring.0, buf, write_geojson_coord)
+    });
+}
+
+fn write_geojson(geometry: &Geometry<f64>, buf: &mut String) {
+    let (kind, member) = match geometry {
+        Geometry::Point(_) => ("Point", "coordinates"),
+        Geometry::Line(_) | Geometry::LineString(_) => ("LineString", "coordinates"),
+        Geometry::Polygon(_) | Geometry::Rect(_) | Geometry::Triangle(_) => {
+            ("Polygon", "coordinates")
+        }
+        Geometry::MultiPoint(_) => ("MultiPoint", "coordinates"),
+        Geometry::MultiLineString(_) => ("MultiLineString", "coordinates"),
+        Geometry::MultiPolygon(_) => ("MultiPolygon", "coordinates"),
+        Geometry::GeometryCollection(_) => ("GeometryCollection", "geometries"),
+    };
+    write!(buf, "{{\"type\":\"{kind}\",\"{member}\":").unwrap();
+    match geometry {
+        Geometry::Point(p) => write_geojson_coord(&p.0, buf),
+        Geometry::Line(l) => write_geojson_array(&[l.start, l.end], buf, write_geojson_coord),
+        Geometry::LineString(ls) => write_geojson_array(&ls.0, buf, write_geojson_coord),
+        Geometry::Polygon(p) => write_geojson_polygon(p, buf),
+        Geometry::Rect(r) => write_geojson_polygon(&r.to_polygon(), buf),
+        Geometry::Triangle(t) => write_geojson_polygon(&t.to_polygon(), buf),
+        Geometry::MultiPoint(mp) => {
+            write_geojson_array(&mp.0, buf, |p, buf| write_geojson_coord(&p.0, buf))
+        }
+        Geometry::MultiLineString(mls) => write_geojson_array(&mls.0, buf, |ls, buf| {
+            write_geojson_array(&ls.0, buf, write_geojson_coord)
+        }),
+        Geometry::MultiPolygon(mp) => write_geojson_array(&mp.0, buf, write_geojson_polygon),
+        Geometry::GeometryCollection(gc) => write_geojson_array(&gc.0, buf, write_geojson),
+    }
+    buf.push('}');
+}
+
+/// Parse a GeoJSON geometry, or the geometry of a GeoJSON `Feature`.
+pub fn geojson_to_geometry(text: &str) -> Result<Geometry<f64>, String> {
+    let value: JsonValue =
+        serde_json::from_str(text).map_err(|e| format!("invalid GeoJSON: {e}"))?;
+    json_to_geometry(&value)
+}
+
+fn json_to_coord(value: &JsonValue) -> Result<Coord<f64>, String> {
+    match value.as_array().map(|v| v.as_slice()) {
+        Some([x, y]) => match (x.as_f64(), y.as_f64()) {
+            (Some(x), Some(y)) => Ok(Coord { x, y }),
+            _ => Err("GeoJSON coordinates must be numbers".to_string()),
+        },
+        Some(v) if v.len() > 2 => Err("only 2D geometries are supported".to_string()),
+        _ => Err("GeoJSON position must be an array of two numbers".to_string()),
+    }
+}
+
+fn json_to_array<T>(
+    value: &JsonValue,
+    f: impl Fn(&JsonValue) -> Result<T, String>,
+) -> Result<Vec<T>, String> {
+    value
+        .as_array()
+        .ok_or_else(|| "GeoJSON coordinates must be an array".to_string())?
+        .iter()
+        .map(f)
+        .collect()
+}
+
+fn json_to_coords(value: &JsonValue) -> Result<Vec<Coord<f64>>, String> {
+    json_to_array(value, json_to_coord)
+}
+
+fn json_to_polygon(value: &JsonValue) -> Result<Polygon<f64>, String> {
+    let mut rings = json_to_array(value, |ring| json_to_coords(ring).map(LineString::new))?;
+    if rings.is_empty() {
+        return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+    }
+    let exterior = rings.remove(0);
+    Ok(Polygon::new(exterior, rings))
+}
+
+fn json_to_geometry(value: &JsonValue) -> Result<Geometry<f64>, String> {
+    let kind = value
+        .get("type")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "GeoJSON object must have a string member 'type'".to_string())?;
+    if kind == "Feature" {
+        return match value.get("geometry") {
+            Some(geometry) if !geometry.is_null() => json_to_geometry(geometry),
+            _ => Err("GeoJSON Feature has no geometry".to_string()),
+        };
+    }
+    if kind == "GeometryCollection" {
+        let geometries = value
+            .get("geometries")
+            .ok_or_else(|| "GeoJSON GeometryCollection must have 'geometries'".to_string())?;
+        return Ok(Geometry::GeometryCollection(GeometryCollection(
+            json_to_array(geometries, json_to_geometry)?,
+        )));
+    }
+    let coordinates = value
+        .get("coordinates")
+        .ok_or_else(|| format!("GeoJSON {kind} must have 'coordinates'"))?;
+    let geometry = match kind {
+        "Point" => Geometry::Point(Point(json_to_coord(coordinates)?)),
+        "LineString" => Geometry::LineString(LineString::new(json_to_coords(coordinates)?)),
+        "Polygon" => Geometry::Polygon(json_to_polygon(coordinates)?),
+        "MultiPoint" => Geometry::MultiPoint(MultiPoint(
+            json_to_coords(coordinates)?
+                .into_iter()
+                .map(Point)
+                .collect(),
+        )),
+        "MultiLineString" => {
+            Geometry::MultiLineString(MultiLineString(json_to_array(coordinates, |v| {
+                json_to_coords(v).map(LineString::new)
+            })?))
+        }
+        "MultiPolygon" => {
+            Geometry::MultiPolygon(MultiPolygon(json_to_array(coordinates, json_to_polygon)?))
+        }
+        _ => return Err(format!("unsupported GeoJSON type '{kind}'")),
+    };
+    Ok(geometry)
+}
@@ -0,0 +1,503 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+/// Number of days in a month, used when an interval has to be reduced to a
+/// single duration (ordering, `date_bin` strides), the same way PostgreSQL does.
+pub const DAYS_IN_A_MONTH: i64 = 30;
+
+/// An interval stored as three independent fields packed into an `i128`:
+/// the upper 32 bits hold the months, the next 32 bits the days and the lower
+/// 64 bits the microseconds.
+///
+/// Months and days are kept apart from the microseconds because their length
+/// depends on the calendar and the time zone of the value they are added to.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct months_days_micros(pub i128);
+
+impl months_days_micros {
+    pub fn new(months: i32, days: i32, micros: i64) -> Self {
+        let months_bits = (months as i128) << 96;
+        let days_bits = ((days as u32) as i128) << 64;
+        let micros_bits = (micros as u64) as i128;
+        Self(months_bits | days_bits | micros_bits)
+    }
+
+    #[inline]
+    pub fn months(&self) -> i32 {
+        (self.0 >> 96) as i32
+    }
+
+    #[inline]
+    pub fn days(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    #[inline]
+    pub fn microseconds(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// The length of the interval in microseconds, assuming 30-day months.
+    pub fn total_micros(&self) -> i128 {
+        (self.months() as i128 * DAYS_IN_A_MONTH as i128 + self.days() as i128)
+            * MICROS_IN_A_DAY as i128
+            + self.microseconds() as i128
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_add(other.months())?,
+            self.days().checked_add(other.days())?,
+            self.microseconds().checked_add(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_sub(other.months())?,
+            self.days().checked_sub(other.days())?,
+            self.microseconds().checked_sub(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_neg()?,
+            self.days().checked_neg()?,
+            self.microseconds().checked_neg()?,
+        ))
+    }
+
+    /// Multiply every field by `factor`, cascading the fractional months into
+    /// days and the fractional days into microseconds.
+    pub fn checked_mul(&self, factor: f64) -> Option<Self> {
+        let months = self.months() as f64 * factor;
+        let days = self.days() as f64 * factor + months.fract() * DAYS_IN_A_MONTH as f64;
+        let micros = self.microseconds() as f64 * factor + days.fract() * MICROS_IN_A_DAY as f64;
+        let (months, days, micros) = (months.trunc(), days.trunc(), micros.round());
+        if !(i32::MIN as f64..=i32::MAX as f64).contains(&months)
+            || !(i32::MIN as f64..=i32::MAX as f64).contains(&days)
+            || !(i64::MIN as f64..=i64::MAX as f64).contains(&micros)
+        {
+            return None;
+        }
+        Some(Self::new(months as i32, days as i32, micros as i64))
+    }
+}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for months_days_micros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then(self.0.cmp(&other.0))
+    }
+}
+
+impl Display for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (months, days, micros) = (self.months(), self.days(), self.microseconds());
+        let mut parts = Vec::with_capacity(4);
+        let years = months / 12;
+        let months = months % 12;
+        if years != 0 {
+            parts.push(format!(
+                "{years} {}",
+                if years.abs() == 1 { "year" } else { "years" }
+            ));
+        }
+        if months != 0 {
+            parts.push(format!(
+                "{months} {}",
+                if months.abs() == 1 { "mon" } else { "mons" }
+            ));
+        }
+        if days != 0 {
+            parts.push(format!(
+                "{days} {}",
+                if days.abs() == 1 { "day" } else { "days" }
+            ));
+        }
+        if micros != 0 || parts.is_empty() {
+            let sign = if micros < 0 { "-" } else { "" };
+            let abs = micros.unsigned_abs();
+            let secs = abs / MICROS_IN_A_SEC as u64;
+            let frac = abs % MICROS_IN_A_SEC as u64;
+            let mut time = format!(
+                "{sign}{:02}:{:02}:{:02}",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            );
+            if frac != 0 {
+                let frac = format!("{frac:06}");
+                time.push('.');
+                time.push_str(frac.trim_end_matches('0'));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// Parse an interval from its textual form. Both the PostgreSQL verbose format
+/// (`'1 year 2 months 3 days 04:05:06.7'`, `'@ 1 hour ago'`) and the ISO 8601
+/// duration format (`'P1Y2M3DT4H5M6.7S'`) are accepted.
+pub fn string_to_interval(buf: &[u8]) -> Result<months_days_micros, String> {
+    let text = std::str::from_utf8(buf)
+        .map_err(|_| "interval must be a valid utf-8 string".to_string())?
+        .trim()
+        .to_ascii_lowercase();
+    let invalid = || format!("invalid interval format '{}'", String::from_utf8_lossy(buf));
+    if text.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut acc = IntervalAccumulator::default();
+    if let Some(rest) = text.strip_prefix('p') {
+        parse_iso8601(rest, &mut acc).ok_or_else(invalid)?;
+    } else {
+        parse_verbose(&text, &mut acc).ok_or_else(invalid)?;
+    }
+    acc.finish()
+        .ok_or_else(|| "interval is out of range".to_string())
+}
+
+#[derive(Default)]
+struct IntervalAccumulator {
+    months: f64,
+    days: f64,
+    micros: f64,
+}
+
+impl IntervalAccumulator {
+    fn add(&mut self, value: f64, unit: &str) -> Option<()> {
+        match unit {
+            "microsecond" | "microseconds" | "us" | "usec" | "usecs" => self.micros += value,
+            "millisecond" | "milliseconds" | "ms" | "msec" | "msecs" => {
+                self.micros += value * 1_000.0
+            }
+            "second" | "seconds" | "s" | "sec" | "secs" => {
+                self.micros += value * MICROS_IN_A_SEC as f64
+            }
+            "minute" | "minutes" | "m" | "min" | "mins" => {
+                self.micros += value * MICROS_IN_A_MINUTE as f64
+            }
+            "hour" | "hours" | "h" | "hr" | "hrs" => {
+                self.micros += value * MICROS_IN_AN_HOUR as f64
+            }
+            "day" | "days" | "d" => self.days += value,
+            "week" | "weeks" | "w" => self.days += value * 7.0,
+            "month" | "months" | "mon" | "mons" => self.months += value,
+            "quarter" | "quarters" | "q" => self.months += value * 3.0,
+            "year" | "years" | "y" | "yr" | "yrs" => self.months += value * 12.0,
+            "decade" | "decades" => self.months += value * 120.0,
+            "century" | "centuries" => self.months += value * 1_200.0,
+            "millennium" | "millennia" | "millenniums" => self.months += value * 12_000.0,
+            _ => return None,
+        }
+        Some(())
+    }
+
+    fn negate(&mut self) {
+        self.months = -self.months;
+        self.days = -self.days;
+        self.micros = -self.micros;
+    }
+
+    fn finish(self) -> Option<months_days_micros> {
+        let months = self.months.trunc();
+        let days = self.days + self.months.fract() * DAYS_IN_A_MONTH as f64;
+        let micros = self.micros + days.fract() * MICROS_IN_A_DAY as f64;
+        let (days, micros) = (days.trunc(), micros.round());
+        if !(i32::MIN as f64..=i32::MAX as f64).contains(&months)
+            || !(i32::MIN as f64..=i32::MAX as f64).contains(&days)
+            || !(i64::MIN as f64..=i64::MAX as f64).contains(&micros)
+        {
+            return None;
+        }
+        Some(months_days_micros::new(
+            months as i32,
+            days as i32,
+            micros as i64,
+        ))
+    }
+}
+
+fn parse_verbose(text: &str, acc: &mut IntervalAccumulator) -> Option<()> {
+    let text = text.strip_prefix('@').unwrap_or(text);
+    let mut tokens = text.split_whitespace().peekable();
+    let mut parsed_any = false;
+    while let Some(token) = tokens.next() {
+        if token == "ago" {
+            if tokens.peek().is_some() || !parsed_any {
+                return None;
+            }
+            acc.negate();
+            break;
+        }
+        if token.contains(':') {
+            acc.micros += parse_time(token)? as f64;
+            parsed_any = true;
+            continue;
+        }
+        // The unit may be attached to the number (`3h`) or follow it (`3 hours`).
+        let split = token
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+            .unwrap_or(token.len());
+        let value: f64 = token[..split].parse().ok()?;
+        let unit = if split < token.len() {
+            &token[split..]
+        } else {
+            match tokens.peek() {
+                Some(next) if *next != "ago" && !next.contains(':') => tokens.next()?,
+                // A bare number is a number of seconds.
+                _ => "second",
+            }
+        };
+        acc.add(value, unit.trim_end_matches(','))?;
+        parsed_any = true;
+    }
+    parsed_any.then_some(())
+}
+
+/// Parse `[-]HH:MM[:SS[.ffffff]]` into microseconds.
+fn parse_time(token: &str) -> Option<i64> {
+    let (negative, token) = match token.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+    let mut parts = token.split(':');
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+    let seconds: f64 = match parts.next() {
+        Some(s) => s.parse().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some() || !(0..60).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    let micros = hours.checked_mul(MICROS_IN_AN_HOUR)?
+        + minutes * MICROS_IN_A_MINUTE
+        + (seconds * MICROS_IN_A_SEC as f64).round() as i64;
+    Some(if negative { -micros } else { micros })
+}
+
+/// Parse the part of an ISO 8601 duration that follows the leading `P`.
+fn parse_iso8601(text: &str, acc: &mut IntervalAccumulator) -> Option<()> {
+    let mut in_time = false;
+    let mut number = String::new();
+    let mut parsed_any = false;
+    for c in text.chars() {
+        match c {
+            't' if !in_time && number.is_empty() => in_time = true,
+            '0'..='9' | '.' | '-' | '+' => number.push(c),
+            _ => {
+                let value: f64 = number.parse().ok()?;
+                number.clear();
+                let unit = match (c, in_time) {
+                    ('y', false) => "year",
+                    ('m', false) => "month",
+                    ('w', false) => "week",
+                    ('d', false) => "day",
+                    ('h', true) => "hour",
+                    ('m', true) => "minute",
+                    ('s', true) => "second",
+                    _ => return None,
+                };
+                acc.add(value, unit)?;
+                parsed_any = true;
+            }
+        }
+    }
+    (number.is_empty() && parsed_any).then_some(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
@@ -0,0 +1,385 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Range;
+
+use chrono::Offset;
+use chrono_tz::Tz;
+use common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::check_timestamp;
+use crate::types::timestamp::string_to_timestamp;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::timestamp::TIMESTAMP_FORMAT;
+use crate::types::timestamp::TIMESTAMP_MAX;
+use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::utils::date_helper::DateConverter;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// The largest accepted UTC offset, `+18:00` or `-18:00`, in seconds.
+pub const MAX_TZ_OFFSET_SECONDS: i32 = 18 * 3600;
+
+/// A timestamp with its UTC offset, packed into an `i128`: the upper 96 bits
+/// hold the UTC instant in microseconds and the lower 32 bits hold the offset
+/// in seconds east of UTC.
+///
+/// Values are equal, ordered and hashed by their instant only, so values at
+/// different offsets which denote the same point in time are equal.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct timestamp_tz(pub i128);
+
+impl PartialEq for timestamp_tz {
+    fn eq(&self, other: &Self) -> bool {
+        self.timestamp() == other.timestamp()
+    }
+}
+
+impl Eq for timestamp_tz {}
+
+impl PartialOrd for timestamp_tz {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for timestamp_tz {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp().cmp(&other.timestamp())
+    }
+}
+
+impl Hash for timestamp_tz {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.timestamp().hash(state)
+    }
+}
+
+impl timestamp_tz {
+    pub fn new(timestamp: i64, offset: i32) -> Self {
+        Self(((timestamp as i128) << 32) | ((offset as u32) as i128))
+    }
+
+    /// The UTC instant in microseconds since the epoch.
+    #[inline]
+    pub fn timestamp(&self) -> i64 {
+        (self.0 >> 32) as i64
+    }
+
+    /// The UTC offset in seconds east of UTC.
+    #[inline]
+    pub fn offset(&self) -> i32 {
+        self.0 as i32
+    }
+
+    /// The wall-clock time at the stored offset, in microseconds since the epoch.
+    #[inline]
+    pub fn local_timestamp(&self) -> i64 {
+        self.timestamp() + self.offset() as i64 * MICROS_IN_A_SEC
+    }
+
+    /// Keep the instant and switch the offset to the one `tz` observes at that instant.
+    pub fn with_timezone(&self, tz: Tz) -> Self {
+        Self::from_timestamp(self.timestamp(), tz)
+    }
+
+    /// Attach to `timestamp` the offset that `tz` observes at that instant.
+    pub fn from_timestamp(timestamp: i64, tz: Tz) -> Self {
+        let offset = timestamp.to_timestamp(tz).offset().fix().local_minus_utc();
+        Self::new(timestamp, offset)
+    }
+}
+
+impl Display for timestamp_tz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let local = self.local_timestamp().to_timestamp(Tz::UTC);
+        write!(
+            f,
+            "{} {}",
+            local.format(TIMESTAMP_FORMAT),
+            offset_to_string(self.offset())
+        )
+    }
+}
+
+/// Format an offset in seconds as `+HH:MM`, with a `:SS` suffix if needed.
+pub fn offset_to_string(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let abs = offset.unsigned_abs();
+    let mut s = format!("{sign}{:02}:{:02}", abs / 3600, abs / 60 % 60);
+    if abs % 60 != 0 {
+        s.push_str(&format!(":{:02}", abs % 60));
+    }
+    s
+}
+
+/// Parse a timestamp with an optional trailing ISO 8601 UTC offset
+/// (`Z`, `+HH`, `+HH:MM` or `+HHMM`). Values without an offset are
+/// interpreted in `tz` and keep the offset `tz` observes at that time.
+pub fn string_to_timestamp_tz(buf: &[u8], tz: Tz) -> Result<timestamp_tz, String> {
+    let text = std::str::from_utf8(buf)
+        .map_err(|_| "timestamp must be a valid utf-8 string".to_string())?
+        .trim();
+    let invalid = || format!("invalid timestamp with time zone '{text}'");
+    let (local, offset) = split_offset(text).ok_or_else(invalid)?;
+    match offset {
+        Some(offset) => {
+            // Read the wall-clock part as if it were UTC, then shift it by the offset.
+            let local = string_to_timestamp(local, Tz::UTC).ok_or_else(invalid)?;
+            let timestamp = local.timestamp_micros() - offset as i64 * MICROS_IN_A_SEC;
+            Ok(timestamp_tz::new(check_timestamp(timestamp)?, offset))
+        }
+        None => {
+            let dt = string_to_timestamp(local, tz).ok_or_else(invalid)?;
+            let offset = dt.offset().fix().local_minus_utc();
+            Ok(timestamp_tz::new(
+                check_timestamp(dt.timestamp_micros())?,
+                offset,
+            ))
+        }
+    }
+}
+
+/// Split `text` into the timestamp part and the trailing offset in seconds, if any.
+fn split_offset(text: &str) -> Option<(&str, Option<i32>)> {
+    if let Some(rest) = text.strip_suffix(['Z', 'z']) {
+        return Some((rest.trim_end(), Some(0)));
+    }
+    // The sign must come after the `YYYY-MM-DD` date part.
+    match text.rfind(['+', '-']) {
+        Some(pos) if pos >= 10 => {
+            let offset = parse_offset(&text[pos..])?;
+            Some((text[..pos].trim_end(), Some(offset)))
+        }
+        _ => Some((text, None)),
+    }
+}
+
+/// Parse an ISO 8601 UTC offset (`Z`, `+HH`, `+HH:MM` or `+HHMM`) into seconds east of UTC.
+pub fn parse_offset(text: &str) -> Option<i32> {
+    if text.eq_ignore_ascii_case("z") {
+        return Some(0);
+    }
+    let (sign, digits) = match text.as_bytes().first()? {
+        b'+' => (1, &text[1..]),
+        b'-' => (-1, &text[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((h, m)) if h.len() == 2 && m.len() == 2 => (h, m),
+        None if digits.len() == 4 => (&digits[..2], &digits[2..]),
+        None if digits.len() == 2 => (digits, "00"),
+        _ => return None,
+    };
+    if !hours
+        .bytes()
+        .chain(minutes.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    let offset = hours * 3600 + minutes * 60;
+    if minutes >= 60 || offset > MAX_TZ_OFFSET_SECONDS {
+        return None;
+    }
+    Some(sign * offset)
+}
+
+/// Attach the offsets of an arrow time zone to UTC instants in microseconds.
+/// The time zone is either a fixed offset such as `+08:00`, or a name such as
+/// `Asia/Shanghai`. An unknown time zone is read as UTC.
+pub fn timestamps_with_arrow_timezone(timestamps: &[i64], tz: &str) -> Buffer<timestamp_tz> {
+    let values = match parse_offset(tz) {
+        Some(offset) => timestamps
+            .iter()
+            .map(|ts| timestamp_tz::new(*ts, offset))
+            .collect::<Vec<_>>(),
+        None => {
+            let tz = tz.parse::<Tz>().unwrap_or(Tz::UTC);
+            timestamps
+                .iter()
+                .map(|ts| timestamp_tz::from_timestamp(*ts, tz))
+                .collect()
+        }
+    };
+    values.into()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampTzType;
+
+impl ValueType for TimestampTzType {
+    type Scalar = timestamp_tz;
+    type ScalarRef<'a> = timestamp_tz;
+    type Column = Buffer<timestamp_tz>;
+    type Domain = SimpleDomain<timestamp_tz>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, timestamp_tz>>;
+    type ColumnBuilder = Vec<timestamp_tz>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: timestamp_tz) -> timestamp_tz {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::TimestampTz(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::TimestampTz(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<timestamp_tz>> {
+        domain.as_timestamp_tz().cloned()
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::TimestampTz(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::TimestampTz(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<timestamp_tz>) -> Domain {
+        Domain::TimestampTz(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for TimestampTzType {
+    fn data_type() -> DataType {
+        DataType::TimestampTz
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: timestamp_tz::new(TIMESTAMP_MIN, 0),
+            max: timestamp_tz::new(TIMESTAMP_MAX, -1),
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
@@ -15,6 +15,8 @@
 use std::ops::Range;
 
 use super::date::date_to_string;
+use super::geometry::geometry_to_geojson;
+use super::geometry::wkb_to_geometry;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
 use crate::date_helper::TzLUT;
@@ -194,6 +196,8 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::String(s) => jsonb::Value::String(String::from_utf8_lossy(s)),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => i.to_string().into(),
+        ScalarRef::TimestampTz(t) => t.to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -242,6 +246,20 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             buf.extend_from_slice(bytes);
             return;
         }
+        ScalarRef::Geometry(wkb) => {
+            // Geometries are converted into GeoJSON objects.
+            match wkb_to_geometry(wkb) {
+                Ok(geometry) => {
+                    let json = geometry_to_geojson(&geometry);
+                    match jsonb::parse_value(json.as_bytes()) {
+                        Ok(value) => value.write_to_vec(buf),
+                        Err(_) => jsonb::Value::Null.write_to_vec(buf),
+                    }
+                }
+                Err(_) => jsonb::Value::Null.write_to_vec(buf),
+            }
+            return;
+        }
     };
     value.write_to_vec(buf);
 }
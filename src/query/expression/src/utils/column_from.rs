@@ -57,6 +57,8 @@ macro_rules! for_common_scalar_values {
             { StringType },
             { DateType },
             { TimestampType },
+            { IntervalType },
+            { TimestampTzType },
             { VariantType },
             { BitmapType }
         }
@@ -0,0 +1,128 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::geometry::geojson_to_geometry;
+use common_expression::types::geometry::geometry_bbox;
+use common_expression::types::geometry::geometry_to_geojson;
+use common_expression::types::geometry::geometry_to_wkt;
+use common_expression::types::geometry::parse_geometry;
+use common_expression::types::geometry::wkb_to_geometry;
+use common_expression::types::geometry::GeometryDomain;
+use common_expression::types::number::F64;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_geometry_text() {
+    let cases = vec![
+        (
+            "POINT(1 2)",
+            "POINT(1 2)",
+            r#"{"type":"Point","coordinates":[1,2]}"#,
+        ),
+        (
+            "linestring (0 0, 1.5 1, 2 -3)",
+            "LINESTRING(0 0,1.5 1,2 -3)",
+            r#"{"type":"LineString","coordinates":[[0,0],[1.5,1],[2,-3]]}"#,
+        ),
+        (
+            "POLYGON((0 0,4 0,4 4,0 4,0 0),(1 1,2 1,2 2,1 1))",
+            "POLYGON((0 0,4 0,4 4,0 4,0 0),(1 1,2 1,2 2,1 1))",
+            r#"{"type":"Polygon","coordinates":[[[0,0],[4,0],[4,4],[0,4],[0,0]],[[1,1],[2,1],[2,2],[1,1]]]}"#,
+        ),
+        (
+            "MULTIPOINT((1 2),(3 4))",
+            "MULTIPOINT(1 2,3 4)",
+            r#"{"type":"MultiPoint","coordinates":[[1,2],[3,4]]}"#,
+        ),
+        (
+            "SRID=4326;GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))",
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))",
+            r#"{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[1,2]},{"type":"LineString","coordinates":[[0,0],[1,1]]}]}"#,
+        ),
+        (
+            "GEOMETRYCOLLECTION EMPTY",
+            "GEOMETRYCOLLECTION EMPTY",
+            r#"{"type":"GeometryCollection","geometries":[]}"#,
+        ),
+    ];
+
+    for (text, wkt, geojson) in cases {
+        let wkb = parse_geometry(text.as_bytes()).unwrap();
+        let geometry = wkb_to_geometry(&wkb).unwrap();
+        assert_eq!(geometry_to_wkt(&geometry), wkt, "{text}");
+        assert_eq!(geometry_to_geojson(&geometry), geojson, "{text}");
+
+        // The WKT, GeoJSON and hex-encoded WKB forms all round-trip.
+        assert_eq!(parse_geometry(wkt.as_bytes()).unwrap(), wkb, "{text}");
+        assert_eq!(parse_geometry(geojson.as_bytes()).unwrap(), wkb, "{text}");
+        assert_eq!(geojson_to_geometry(geojson).unwrap(), geometry, "{text}");
+        let hex = hex::encode_upper(&wkb);
+        assert_eq!(parse_geometry(hex.as_bytes()).unwrap(), wkb, "{text}");
+    }
+
+    for text in [
+        "",
+        "POINT",
+        "POINT(1)",
+        "POINT(1 2 3)",
+        "LINESTRING(0 0,1 1",
+        "CIRCLE(0 0)",
+        r#"{"type":"Point"}"#,
+        "0101000000",
+    ] {
+        let r = parse_geometry(text.as_bytes());
+        assert!(r.is_err(), "{text}: {r:?}");
+    }
+}
+
+#[test]
+fn test_geometry_wkb_byte_order() {
+    // POINT(1 2) in big endian, and as PostGIS EWKB with SRID 4326.
+    let big_endian = hex::decode("00000000013FF00000000000004000000000000000").unwrap();
+    let ewkb = hex::decode("0101000020E6100000000000000000F03F0000000000000040").unwrap();
+    let little_endian = parse_geometry(b"POINT(1 2)").unwrap();
+    for wkb in [big_endian, ewkb] {
+        let geometry = wkb_to_geometry(&wkb).unwrap();
+        assert_eq!(geometry_to_wkt(&geometry), "POINT(1 2)");
+        assert_eq!(
+            parse_geometry(hex::encode(&wkb).as_bytes()).unwrap(),
+            little_endian
+        );
+        assert_eq!(parse_geometry(&wkb).unwrap(), little_endian);
+    }
+}
+
+#[test]
+fn test_geometry_bbox() {
+    let domain = |min_x: f64, min_y: f64, max_x: f64, max_y: f64| GeometryDomain {
+        min_x: F64::from(min_x),
+        min_y: F64::from(min_y),
+        max_x: F64::from(max_x),
+        max_y: F64::from(max_y),
+    };
+
+    let line = parse_geometry(b"LINESTRING(0 5,3 -1,2 2)").unwrap();
+    let bbox = geometry_bbox(&line).unwrap();
+    assert_eq!(bbox, domain(0.0, -1.0, 3.0, 5.0));
+
+    let empty = parse_geometry(b"GEOMETRYCOLLECTION EMPTY").unwrap();
+    assert!(geometry_bbox(&empty).unwrap().is_empty());
+
+    let point = geometry_bbox(&parse_geometry(b"POINT(1 1)").unwrap()).unwrap();
+    let far = geometry_bbox(&parse_geometry(b"POINT(10 10)").unwrap()).unwrap();
+    assert!(bbox.intersects(&point) && bbox.contains(&point));
+    assert!(!bbox.intersects(&far) && !bbox.contains(&far));
+    assert!(!bbox.intersects(&GeometryDomain::empty()));
+    assert_eq!(bbox.merge(&far), domain(0.0, -1.0, 10.0, 10.0));
+}
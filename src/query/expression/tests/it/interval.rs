@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_interval_text() {
+    let cases = vec![
+        ("1 day", months_days_micros::new(0, 1, 0), "1 day"),
+        (
+            "1 day 3 hours",
+            months_days_micros::new(0, 1, 3 * 3_600_000_000),
+            "1 day 03:00:00",
+        ),
+        (
+            "1 year 2 months 3 days 04:05:06.7",
+            months_days_micros::new(14, 3, 14_706_700_000),
+            "1 year 2 mons 3 days 04:05:06.7",
+        ),
+        (
+            "P1Y2M3DT4H5M6S",
+            months_days_micros::new(14, 3, 14_706_000_000),
+            "1 year 2 mons 3 days 04:05:06",
+        ),
+        (
+            "@ 1 hour ago",
+            months_days_micros::new(0, 0, -3_600_000_000),
+            "-01:00:00",
+        ),
+        (
+            "1.5 months",
+            months_days_micros::new(1, 15, 0),
+            "1 mon 15 days",
+        ),
+        ("2 weeks", months_days_micros::new(0, 14, 0), "14 days"),
+        ("90", months_days_micros::new(0, 0, 90_000_000), "00:01:30"),
+        ("0 seconds", months_days_micros::new(0, 0, 0), "00:00:00"),
+    ];
+
+    for (text, expected, display) in cases {
+        let interval = string_to_interval(text.as_bytes()).unwrap();
+        assert_eq!(interval, expected, "{text}");
+        assert_eq!(interval.to_string(), display, "{text}");
+        assert_eq!(string_to_interval(display.as_bytes()).unwrap(), expected);
+    }
+
+    for text in ["", "abc", "1 fortnight", "ago", "P1H", "10:61"] {
+        let r = string_to_interval(text.as_bytes());
+        assert!(r.is_err(), "{text}: {r:?}");
+    }
+}
+
+#[test]
+fn test_interval_ordering() {
+    let day = string_to_interval(b"1 day").unwrap();
+    let hours = string_to_interval(b"23 hours").unwrap();
+    let month = string_to_interval(b"1 month").unwrap();
+    let days = string_to_interval(b"30 days").unwrap();
+
+    assert!(day > hours);
+    assert!(month > day);
+    assert_ne!(month, days);
+    assert_eq!(
+        month.checked_sub(&days).unwrap(),
+        months_days_micros::new(1, -30, 0)
+    );
+    assert_eq!(
+        day.checked_mul(1.5).unwrap(),
+        months_days_micros::new(0, 1, 12 * 3_600_000_000)
+    );
+}
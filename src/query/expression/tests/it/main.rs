@@ -20,9 +20,12 @@ extern crate core;
 mod block;
 mod common;
 mod decimal;
+mod geometry;
 mod group_by;
+mod interval;
 mod kernel;
 mod row;
 mod schema;
 mod serde;
 mod sort;
+mod timestamp_tz;
@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono_tz::Tz;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_tz;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_timestamp_tz_text() {
+    let instant = 1_683_360_000_000_000;
+    let cases = vec![
+        (
+            "2023-05-06 10:00:00+02:00",
+            timestamp_tz::new(instant, 7200),
+            "2023-05-06 10:00:00.000000 +02:00",
+        ),
+        (
+            "2023-05-06T08:00:00Z",
+            timestamp_tz::new(instant, 0),
+            "2023-05-06 08:00:00.000000 +00:00",
+        ),
+        (
+            "2023-05-06 03:00:00-0500",
+            timestamp_tz::new(instant, -18000),
+            "2023-05-06 03:00:00.000000 -05:00",
+        ),
+        (
+            "2023-05-06 13:30:00 +05:30",
+            timestamp_tz::new(instant, 19800),
+            "2023-05-06 13:30:00.000000 +05:30",
+        ),
+    ];
+
+    for (text, expected, display) in cases {
+        let ts = string_to_timestamp_tz(text.as_bytes(), Tz::UTC).unwrap();
+        assert_eq!(ts, expected, "{text}");
+        assert_eq!(ts.timestamp(), instant, "{text}");
+        assert_eq!(ts.to_string(), display, "{text}");
+        assert_eq!(
+            string_to_timestamp_tz(display.as_bytes(), Tz::UTC).unwrap(),
+            expected
+        );
+    }
+
+    // Values without an offset take the one observed by the given time zone.
+    let ts = string_to_timestamp_tz(b"2023-01-01 12:00:00", Tz::Asia__Shanghai).unwrap();
+    assert_eq!(ts, timestamp_tz::new(1_672_545_600_000_000, 28800));
+    assert_eq!(ts.to_string(), "2023-01-01 12:00:00.000000 +08:00");
+
+    for text in [
+        "",
+        "abc",
+        "2023-05-06 10:00:00+25:00",
+        "2023-05-06 10:00:00+02:0",
+    ] {
+        let r = string_to_timestamp_tz(text.as_bytes(), Tz::UTC);
+        assert!(r.is_err(), "{text}: {r:?}");
+    }
+}
+
+#[test]
+fn test_timestamp_tz_ordering() {
+    let utc = string_to_timestamp_tz(b"2023-05-06 08:00:00Z", Tz::UTC).unwrap();
+    let cest = string_to_timestamp_tz(b"2023-05-06 10:00:00+02:00", Tz::UTC).unwrap();
+    let later = string_to_timestamp_tz(b"2023-05-06 08:00:01-12:00", Tz::UTC).unwrap();
+    let earlier = string_to_timestamp_tz(b"2023-05-06 21:59:59+14:00", Tz::UTC).unwrap();
+
+    // The same instant at different offsets is a different value...
+    assert_ne!(utc, cest);
+    assert_eq!(utc.timestamp(), cest.timestamp());
+    // ...but values are always ordered by instant first.
+    assert!(earlier < utc && earlier < cest);
+    assert!(later > utc && later > cest);
+    assert_eq!(cest.with_timezone(Tz::UTC), utc);
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::geometry::geometry_to_geojson;
+use common_expression::types::geometry::wkb_to_geometry;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
@@ -96,6 +98,8 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => JsonValue::String(v.to_string()),
+        ScalarRef::TimestampTz(v) => JsonValue::String(v.to_string()),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
@@ -140,6 +144,10 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let b = jsonb::from_slice(x).unwrap();
             b.into()
         }
+        ScalarRef::Geometry(x) => match wkb_to_geometry(x) {
+            Ok(geometry) => serde_json::from_str(&geometry_to_geojson(&geometry)).unwrap(),
+            Err(_) => JsonValue::Null,
+        },
     }
 }
 
@@ -72,6 +72,10 @@ pub const GENERAL_CAST_RULES: AutoCastRules = &[
     (DataType::String, DataType::Date),
     (DataType::String, DataType::Boolean),
     (DataType::Date, DataType::Timestamp),
+    (DataType::String, DataType::TimestampTz),
+    (DataType::Date, DataType::TimestampTz),
+    (DataType::Timestamp, DataType::TimestampTz),
+    (DataType::String, DataType::Geometry),
     (
         DataType::Number(NumberDataType::UInt8),
         DataType::Number(NumberDataType::UInt16),
@@ -25,6 +25,8 @@ use common_expression::types::date::date_to_string;
 use common_expression::types::date::string_to_date;
 use common_expression::types::date::DATE_MAX;
 use common_expression::types::date::DATE_MIN;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::MICROS_IN_A_DAY;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::Int64Type;
@@ -41,6 +43,7 @@ use common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use common_expression::types::timestamp::MICROS_IN_A_SEC;
 use common_expression::types::DateType;
 use common_expression::types::Int32Type;
+use common_expression::types::IntervalType;
 use common_expression::types::NullableType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -244,21 +247,21 @@ fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
             output.push(calc_date_to_timestamp(val, tz));
         })(val, ctx)
     }
+}
 
-    fn calc_date_to_timestamp(val: i32, tz: Tz) -> i64 {
-        let ts = (val as i64) * 24 * 3600 * MICROS_IN_A_SEC;
-        let epoch_time_with_ltz = tz
-            .from_utc_datetime(
-                &NaiveDate::from_ymd_opt(1970, 1, 1)
-                    .unwrap()
-                    .and_hms_micro_opt(0, 0, 0, 0)
-                    .unwrap(),
-            )
-            .naive_local()
-            .timestamp_micros();
-
-        ts - epoch_time_with_ltz
-    }
+pub(crate) fn calc_date_to_timestamp(val: i32, tz: Tz) -> i64 {
+    let ts = (val as i64) * 24 * 3600 * MICROS_IN_A_SEC;
+    let epoch_time_with_ltz = tz
+        .from_utc_datetime(
+            &NaiveDate::from_ymd_opt(1970, 1, 1)
+                .unwrap()
+                .and_hms_micro_opt(0, 0, 0, 0)
+                .unwrap(),
+        )
+        .naive_local()
+        .timestamp_micros();
+
+    ts - epoch_time_with_ltz
 }
 
 fn register_number_to_timestamp(registry: &mut FunctionRegistry) {
@@ -1203,23 +1206,13 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         ),
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
         "minus",
-        |_, lhs, rhs| {
-            (|| {
-                let lm = lhs.max;
-                let ln = lhs.min;
-                let rm = rhs.max;
-                let rn = rhs.min;
-
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
-                }))
-            })()
-            .unwrap_or(FunctionDomain::Full)
+        |_, _, _| FunctionDomain::Full,
+        |a, b, _| {
+            let diff = a - b;
+            months_days_micros::new(0, (diff / MICROS_IN_A_DAY) as i32, diff % MICROS_IN_A_DAY)
         },
-        |a, b, _| a - b,
     );
 }
 
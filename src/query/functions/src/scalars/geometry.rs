@@ -0,0 +1,573 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::error_to_null;
+use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::geometry::default_geometry;
+use common_expression::types::geometry::geojson_to_geometry;
+use common_expression::types::geometry::geometry_bbox;
+use common_expression::types::geometry::geometry_to_geojson;
+use common_expression::types::geometry::geometry_to_wkb;
+use common_expression::types::geometry::geometry_to_wkt;
+use common_expression::types::geometry::parse_geometry;
+use common_expression::types::geometry::wkb_to_geometry;
+use common_expression::types::geometry::wkt_to_geometry;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::F64;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::string::StringDomain;
+use common_expression::types::BooleanType;
+use common_expression::types::GeometryType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+use geo::relate::IntersectionMatrix;
+use geo::Area;
+use geo::Coord;
+use geo::EuclideanDistance;
+use geo::Geometry;
+use geo::Line;
+use geo::LineString;
+use geo::Point;
+use geo::Polygon;
+use geo::Relate;
+
+/// The number of segments used by `st_buffer` to approximate a circle.
+const BUFFER_SEGMENTS: usize = 32;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS geometry)
+    // to_geometry(xx)
+    register_to_geometry(registry);
+
+    // cast(geometry AS string)
+    // st_astext(geometry), st_aswkb(geometry), st_asgeojson(geometry)
+    register_geometry_to_other(registry);
+
+    // st_point(x, y), st_x(geometry), st_y(geometry), st_envelope(geometry), ...
+    register_geometry_constructors(registry);
+
+    // st_contains(a, b), st_intersects(a, b), st_distance(a, b), st_area(a)
+    register_spatial_relationships(registry);
+}
+
+fn push_geometry(output: &mut StringColumnBuilder, wkb: &[u8]) {
+    output.put_slice(wkb);
+    output.commit_row();
+}
+
+fn register_to_geometry(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_geometry,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_geometry),
+    );
+
+    fn eval_string_to_geometry(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<GeometryType> {
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            match parse_geometry(val) {
+                Ok(wkb) => push_geometry(output, &wkb),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `GEOMETRY`: {e}"),
+                    );
+                    push_geometry(output, &default_geometry());
+                }
+            }
+        })(val, ctx)
+    }
+
+    // Unlike `to_geometry`, which accepts any of the text forms, these only accept
+    // the format in their names.
+    for name in ["st_geomfromtext", "st_geometryfromtext", "st_geomfromwkt"] {
+        registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+                let geometry = std::str::from_utf8(val)
+                    .map_err(|e| e.to_string())
+                    .and_then(wkt_to_geometry);
+                match geometry {
+                    Ok(geometry) => push_geometry(output, &geometry_to_wkb(&geometry)),
+                    Err(e) => {
+                        ctx.set_error(output.len(), format!("invalid WKT: {e}"));
+                        push_geometry(output, &default_geometry());
+                    }
+                }
+            }),
+        );
+    }
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromwkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            let geometry = hex::decode(val)
+                .map_err(|e| e.to_string())
+                .and_then(|wkb| wkb_to_geometry(&wkb));
+            match geometry {
+                Ok(geometry) => push_geometry(output, &geometry_to_wkb(&geometry)),
+                Err(e) => {
+                    ctx.set_error(output.len(), format!("invalid WKB: {e}"));
+                    push_geometry(output, &default_geometry());
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            let geometry = std::str::from_utf8(val)
+                .map_err(|e| e.to_string())
+                .and_then(geojson_to_geometry);
+            match geometry {
+                Ok(geometry) => push_geometry(output, &geometry_to_wkb(&geometry)),
+                Err(e) => {
+                    ctx.set_error(output.len(), format!("invalid GeoJSON: {e}"));
+                    push_geometry(output, &default_geometry());
+                }
+            }
+        }),
+    );
+}
+
+fn register_geometry_to_other(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::MayThrow,
+        eval_geometry_to_wkt,
+    );
+    registry.register_combine_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: true,
+                value: Some(Box::new(StringDomain {
+                    min: vec![],
+                    max: None,
+                })),
+            })
+        },
+        error_to_null(eval_geometry_to_wkt),
+    );
+    for name in ["st_astext", "st_aswkt"] {
+        registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            eval_geometry_to_wkt,
+        );
+    }
+
+    fn eval_geometry_to_wkt(
+        val: ValueRef<GeometryType>,
+        ctx: &mut EvalContext,
+    ) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, ctx| {
+            match wkb_to_geometry(val) {
+                Ok(geometry) => output.put_str(&geometry_to_wkt(&geometry)),
+                Err(e) => ctx.set_error(output.len(), e),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_aswkb",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, _| {
+            output.put_str(&hex::encode_upper(val));
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_asgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, ctx| {
+            match wkb_to_geometry(val) {
+                Ok(geometry) => output.put_str(&geometry_to_geojson(&geometry)),
+                Err(e) => ctx.set_error(output.len(), e),
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_geometrytype",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, ctx| {
+            match wkb_to_geometry(val) {
+                Ok(geometry) => output.put_str(geometry_type_name(&geometry)),
+                Err(e) => ctx.set_error(output.len(), e),
+            }
+            output.commit_row();
+        }),
+    );
+}
+
+fn geometry_type_name(geometry: &Geometry<f64>) -> &'static str {
+    match geometry {
+        Geometry::Point(_) => "ST_Point",
+        Geometry::Line(_) | Geometry::LineString(_) => "ST_LineString",
+        Geometry::Polygon(_) | Geometry::Rect(_) | Geometry::Triangle(_) => "ST_Polygon",
+        Geometry::MultiPoint(_) => "ST_MultiPoint",
+        Geometry::MultiLineString(_) => "ST_MultiLineString",
+        Geometry::MultiPolygon(_) => "ST_MultiPolygon",
+        Geometry::GeometryCollection(_) => "ST_GeometryCollection",
+    }
+}
+
+fn register_geometry_constructors(registry: &mut FunctionRegistry) {
+    for name in ["st_point", "st_makepoint"] {
+        registry
+            .register_passthrough_nullable_2_arg::<Float64Type, Float64Type, GeometryType, _, _>(
+                name,
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<Float64Type, Float64Type, GeometryType>(
+                    |x, y, output, ctx| {
+                        if !x.0.is_finite() || !y.0.is_finite() {
+                            ctx.set_error(output.len(), "coordinates of a point must be finite");
+                            push_geometry(output, &default_geometry());
+                        } else {
+                            let point = Geometry::Point(Point::new(x.0, y.0));
+                            push_geometry(output, &geometry_to_wkb(&point));
+                        }
+                    },
+                ),
+            );
+    }
+
+    for (name, get_coord) in [
+        ("st_x", (|p: &Point<f64>| p.x()) as fn(&Point<f64>) -> f64),
+        ("st_y", |p: &Point<f64>| p.y()),
+    ] {
+        registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<GeometryType, Float64Type>(move |val, output, ctx| {
+                match wkb_to_geometry(val) {
+                    Ok(Geometry::Point(p)) => output.push(F64::from(get_coord(&p))),
+                    Ok(_) => {
+                        ctx.set_error(output.len(), "argument must be a POINT");
+                        output.push(F64::default());
+                    }
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(F64::default());
+                    }
+                }
+            }),
+        );
+    }
+
+    // The bounding box of a geometry, degenerated to a point or a line if it has no area.
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_envelope",
+        |_, domain| FunctionDomain::Domain(*domain),
+        vectorize_with_builder_1_arg::<GeometryType, GeometryType>(|val, output, ctx| {
+            match geometry_bbox(val) {
+                Some(bbox) if bbox.is_empty() => push_geometry(output, &default_geometry()),
+                Some(bbox) => {
+                    let min = Coord {
+                        x: bbox.min_x.0,
+                        y: bbox.min_y.0,
+                    };
+                    let max = Coord {
+                        x: bbox.max_x.0,
+                        y: bbox.max_y.0,
+                    };
+                    let envelope = if min == max {
+                        Geometry::Point(Point(min))
+                    } else if min.x == max.x || min.y == max.y {
+                        Geometry::LineString(LineString(vec![min, max]))
+                    } else {
+                        Geometry::Polygon(Polygon::new(
+                            LineString(vec![
+                                min,
+                                Coord { x: max.x, y: min.y },
+                                max,
+                                Coord { x: min.x, y: max.y },
+                                min,
+                            ]),
+                            vec![],
+                        ))
+                    };
+                    push_geometry(output, &geometry_to_wkb(&envelope));
+                }
+                None => {
+                    ctx.set_error(output.len(), "invalid geometry");
+                    push_geometry(output, &default_geometry());
+                }
+            }
+        }),
+    );
+
+    // Only points are supported, the buffer is approximated by a regular polygon.
+    registry.register_passthrough_nullable_2_arg::<GeometryType, Float64Type, GeometryType, _, _>(
+        "st_buffer",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, Float64Type, GeometryType>(
+            |val, radius, output, ctx| match wkb_to_geometry(val) {
+                Ok(Geometry::Point(center)) if radius.0.is_finite() && radius.0 > 0.0 => {
+                    let mut ring = (0..BUFFER_SEGMENTS)
+                        .map(|i| {
+                            let angle =
+                                2.0 * std::f64::consts::PI * i as f64 / BUFFER_SEGMENTS as f64;
+                            Coord {
+                                x: center.x() + radius.0 * angle.cos(),
+                                y: center.y() + radius.0 * angle.sin(),
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    ring.push(ring[0]);
+                    let polygon = Geometry::Polygon(Polygon::new(LineString(ring), vec![]));
+                    push_geometry(output, &geometry_to_wkb(&polygon));
+                }
+                Ok(Geometry::Point(_)) => {
+                    ctx.set_error(output.len(), "radius of buffer must be a positive number");
+                    push_geometry(output, &default_geometry());
+                }
+                Ok(_) => {
+                    ctx.set_error(output.len(), "st_buffer only supports POINT");
+                    push_geometry(output, &default_geometry());
+                }
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    push_geometry(output, &default_geometry());
+                }
+            },
+        ),
+    );
+}
+
+fn register_spatial_relationships(registry: &mut FunctionRegistry) {
+    // The bounding boxes kept in block statistics are enough to tell that no
+    // geometry of a block can satisfy the predicate.
+    const ALL_FALSE: BooleanDomain = BooleanDomain {
+        has_false: true,
+        has_true: false,
+    };
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        |_, a, b| {
+            if a.contains(b) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(ALL_FALSE)
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, output, ctx| match relate(a, b, |m| m.is_contains()) {
+                Ok(v) => output.push(v),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_within",
+        |_, a, b| {
+            if b.contains(a) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(ALL_FALSE)
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, output, ctx| match relate(a, b, |m| m.is_within()) {
+                Ok(v) => output.push(v),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_intersects",
+        |_, a, b| {
+            if a.intersects(b) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(ALL_FALSE)
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, output, ctx| match relate(a, b, |m| m.is_intersects()) {
+                Ok(v) => output.push(v),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    // The distance to an empty geometry is NULL.
+    registry.register_combine_nullable_2_arg::<GeometryType, GeometryType, Float64Type, _, _>(
+        "st_distance",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, NullableType<Float64Type>>(
+            |a, b, output, ctx| match (wkb_to_geometry(a), wkb_to_geometry(b)) {
+                (Ok(a), Ok(b)) => match distance(&a, &b) {
+                    Some(d) => output.push(F64::from(d)),
+                    None => output.push_null(),
+                },
+                (Err(e), _) | (_, Err(e)) => {
+                    ctx.set_error(output.len(), e);
+                    output.push_null();
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_area",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|val, output, ctx| {
+            match wkb_to_geometry(val) {
+                Ok(geometry) => output.push(F64::from(geometry.unsigned_area())),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(F64::default());
+                }
+            }
+        }),
+    );
+}
+
+fn is_empty_geometry(geometry: &Geometry<f64>) -> bool {
+    let (points, lines) = decompose(geometry);
+    points.is_empty() && lines.is_empty()
+}
+
+/// Evaluate a DE-9IM predicate. Empty geometries are related to nothing.
+fn relate(
+    a: &[u8],
+    b: &[u8],
+    predicate: impl Fn(&IntersectionMatrix) -> bool,
+) -> Result<bool, String> {
+    let a = wkb_to_geometry(a)?;
+    let b = wkb_to_geometry(b)?;
+    if is_empty_geometry(&a) || is_empty_geometry(&b) {
+        return Ok(false);
+    }
+    Ok(predicate(&a.relate(&b)))
+}
+
+/// The minimum euclidean distance between two geometries, `None` if either is empty.
+fn distance(a: &Geometry<f64>, b: &Geometry<f64>) -> Option<f64> {
+    let (points_a, lines_a) = decompose(a);
+    let (points_b, lines_b) = decompose(b);
+    if (points_a.is_empty() && lines_a.is_empty()) || (points_b.is_empty() && lines_b.is_empty()) {
+        return None;
+    }
+    if a.relate(b).is_intersects() {
+        return Some(0.0);
+    }
+    // Disjoint geometries are closest at their boundaries.
+    let mut min = f64::INFINITY;
+    for p in &points_a {
+        for q in &points_b {
+            min = min.min(p.euclidean_distance(q));
+        }
+        for l in &lines_b {
+            min = min.min(p.euclidean_distance(l));
+        }
+    }
+    for l in &lines_a {
+        for q in &points_b {
+            min = min.min(q.euclidean_distance(l));
+        }
+        for m in &lines_b {
+            min = min.min(l.euclidean_distance(m));
+        }
+    }
+    Some(min)
+}
+
+/// Split a geometry into its isolated points and the segments of its lines and rings.
+fn decompose(geometry: &Geometry<f64>) -> (Vec<Point<f64>>, Vec<Line<f64>>) {
+    fn add_line_string(
+        ls: &LineString<f64>,
+        points: &mut Vec<Point<f64>>,
+        lines: &mut Vec<Line<f64>>,
+    ) {
+        match ls.0.len() {
+            0 => {}
+            1 => points.push(Point(ls.0[0])),
+            _ => lines.extend(ls.lines()),
+        }
+    }
+
+    fn visit(geometry: &Geometry<f64>, points: &mut Vec<Point<f64>>, lines: &mut Vec<Line<f64>>) {
+        match geometry {
+            Geometry::Point(p) => points.push(*p),
+            Geometry::Line(l) => lines.push(*l),
+            Geometry::LineString(ls) => add_line_string(ls, points, lines),
+            Geometry::Polygon(p) => {
+                add_line_string(p.exterior(), points, lines);
+                for ring in p.interiors() {
+                    add_line_string(ring, points, lines);
+                }
+            }
+            Geometry::MultiPoint(mp) => points.extend(mp.0.iter().copied()),
+            Geometry::MultiLineString(mls) => {
+                for ls in &mls.0 {
+                    add_line_string(ls, points, lines);
+                }
+            }
+            Geometry::MultiPolygon(mp) => {
+                for p in &mp.0 {
+                    visit(&Geometry::Polygon(p.clone()), points, lines);
+                }
+            }
+            Geometry::GeometryCollection(gc) => {
+                for g in &gc.0 {
+                    visit(g, points, lines);
+                }
+            }
+            Geometry::Rect(r) => visit(&Geometry::Polygon(r.to_polygon()), points, lines),
+            Geometry::Triangle(t) => visit(&Geometry::Polygon(t.to_polygon()), points, lines),
+        }
+    }
+
+    let mut points = vec![];
+    let mut lines = vec![];
+    visit(geometry, &mut points, &mut lines);
+    (points, lines)
+}
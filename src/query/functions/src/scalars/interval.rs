@@ -0,0 +1,466 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_expression::error_to_null;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
+use common_expression::types::interval::MICROS_IN_AN_HOUR;
+use common_expression::types::interval::MICROS_IN_A_DAY;
+use common_expression::types::interval::MICROS_IN_A_MINUTE;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::Int64Type;
+use common_expression::types::string::StringDomain;
+use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::DateType;
+use common_expression::types::IntervalType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::date_helper::*;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+use crate::scalars::datetime::calc_date_to_timestamp;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast(interval AS string)
+    // to_string(interval)
+    register_interval_to_string(registry);
+
+    // to_[years | quarters | months | days | hours | minutes | seconds](number)
+    // interval number [year | quarter | month | day | hour | minute | second]
+    register_number_to_interval(registry);
+
+    // [date | timestamp | interval] +/- interval
+    // interval [* | /] number
+    register_interval_arith(registry);
+
+    // date_bin(interval, timestamp [, origin])
+    // date_trunc(interval, timestamp)
+    register_date_bin(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `INTERVAL`: {e}"),
+                    );
+                    output.push(months_days_micros::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{val}").unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: vec![],
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{val}").unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_number_to_interval(registry: &mut FunctionRegistry) {
+    register_interval_constructor(registry, "to_years", |n| {
+        let months = i32::try_from(n.checked_mul(12)?).ok()?;
+        Some(months_days_micros::new(months, 0, 0))
+    });
+    register_interval_constructor(registry, "to_quarters", |n| {
+        let months = i32::try_from(n.checked_mul(3)?).ok()?;
+        Some(months_days_micros::new(months, 0, 0))
+    });
+    register_interval_constructor(registry, "to_months", |n| {
+        let months = i32::try_from(n).ok()?;
+        Some(months_days_micros::new(months, 0, 0))
+    });
+    register_interval_constructor(registry, "to_days", |n| {
+        let days = i32::try_from(n).ok()?;
+        Some(months_days_micros::new(0, days, 0))
+    });
+    register_interval_constructor(registry, "to_hours", |n| {
+        Some(months_days_micros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_AN_HOUR)?,
+        ))
+    });
+    register_interval_constructor(registry, "to_minutes", |n| {
+        Some(months_days_micros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_A_MINUTE)?,
+        ))
+    });
+    register_interval_constructor(registry, "to_seconds", |n| {
+        Some(months_days_micros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_A_SEC)?,
+        ))
+    });
+}
+
+fn register_interval_constructor(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    constructor: fn(i64) -> Option<months_days_micros>,
+) {
+    registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<Int64Type, IntervalType>(move |val, output, ctx| {
+            match constructor(val) {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            }
+        }),
+    );
+}
+
+/// Add an interval to a timestamp: the months are added on the calendar of
+/// the session time zone first, then the days, then the microseconds.
+fn add_interval_to_timestamp(
+    ts: i64,
+    interval: months_days_micros,
+    tz: TzLUT,
+) -> Result<i64, String> {
+    let mut ts = ts;
+    if interval.months() != 0 {
+        ts = AddMonthsImpl::eval_timestamp(ts, tz, interval.months())?;
+    }
+    if interval.days() != 0 {
+        ts = AddDaysImpl::eval_timestamp(ts, interval.days())?;
+    }
+    match ts.checked_add(interval.microseconds()) {
+        Some(ts) => check_timestamp(ts),
+        None => Err("timestamp is out of range".to_string()),
+    }
+}
+
+fn register_interval_arith(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_add(&b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_sub(&b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|a, output, ctx| {
+            match a.checked_neg() {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Float64Type, IntervalType, _, _>(
+        "multiply",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Float64Type, IntervalType>(
+            |a, b, output, ctx| match a.checked_mul(b.0) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Float64Type, IntervalType, IntervalType, _, _>(
+        "multiply",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Float64Type, IntervalType, IntervalType>(
+            |a, b, output, ctx| match b.checked_mul(a.0) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Float64Type, IntervalType, _, _>(
+        "divide",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Float64Type, IntervalType>(
+            |a, b, output, ctx| {
+                if b.0 == 0.0 {
+                    ctx.set_error(output.len(), "divided by zero");
+                    output.push(months_days_micros::default());
+                    return;
+                }
+                match a.checked_mul(1.0 / b.0) {
+                    Some(v) => output.push(v),
+                    None => {
+                        ctx.set_error(output.len(), "interval out of range");
+                        output.push(months_days_micros::default());
+                    }
+                }
+            },
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |a, b, output, ctx| match add_interval_to_timestamp(a, b, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |a, b, output, ctx| match add_interval_to_timestamp(b, a, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |a, b, output, ctx| {
+                    let res = match b.checked_neg() {
+                        Some(b) => add_interval_to_timestamp(a, b, ctx.func_ctx.tz),
+                        None => Err("interval out of range".to_string()),
+                    };
+                    match res {
+                        Ok(v) => output.push(v),
+                        Err(err) => {
+                            ctx.set_error(output.len(), err);
+                            output.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |a, b, output, ctx| {
+                let tz = ctx.func_ctx.tz;
+                match add_interval_to_timestamp(calc_date_to_timestamp(a, tz.tz), b, tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |a, b, output, ctx| {
+                let tz = ctx.func_ctx.tz;
+                match add_interval_to_timestamp(calc_date_to_timestamp(b, tz.tz), a, tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |a, b, output, ctx| {
+                let tz = ctx.func_ctx.tz;
+                let res = match b.checked_neg() {
+                    Some(b) => add_interval_to_timestamp(calc_date_to_timestamp(a, tz.tz), b, tz),
+                    None => Err("interval out of range".to_string()),
+                };
+                match res {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+}
+
+/// Truncate `ts` to the start of the `stride`-wide bin it falls into, with the
+/// bins aligned to `origin`.
+fn date_bin(stride: months_days_micros, ts: i64, origin: i64) -> Result<i64, String> {
+    if stride.months() != 0 {
+        return Err(
+            "timestamps cannot be binned into intervals containing months or years".to_string(),
+        );
+    }
+    let stride = (stride.days() as i64)
+        .checked_mul(MICROS_IN_A_DAY)
+        .and_then(|days| days.checked_add(stride.microseconds()))
+        .ok_or_else(|| "interval out of range".to_string())?;
+    if stride <= 0 {
+        return Err("stride must be greater than zero".to_string());
+    }
+    let delta = ts
+        .checked_sub(origin)
+        .ok_or_else(|| "timestamp is out of range".to_string())?;
+    check_timestamp(origin + delta.div_euclid(stride) * stride)
+}
+
+fn register_date_bin(registry: &mut FunctionRegistry) {
+    registry.register_aliases("date_bin", &["date_trunc"]);
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "date_bin",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |stride, ts, output, ctx| match date_bin(stride, ts, 0) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_3_arg::<
+        IntervalType,
+        TimestampType,
+        TimestampType,
+        TimestampType,
+        _,
+        _,
+    >(
+        "date_bin",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<IntervalType, TimestampType, TimestampType, TimestampType>(
+            |stride, ts, origin, output, ctx| match date_bin(stride, ts, origin) {
+                Ok(v) => output.push(v),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+}
@@ -25,12 +25,15 @@ mod datetime;
 mod decimal;
 mod geo;
 mod geo_h3;
+mod geometry;
 mod hash;
+mod interval;
 mod map;
 mod math;
 mod other;
 mod string;
 mod string_multi_args;
+mod timestamp_tz;
 mod tuple;
 mod variant;
 mod vector;
@@ -48,6 +51,9 @@ pub fn register(registry: &mut FunctionRegistry) {
     control::register(registry);
     comparison::register(registry);
     datetime::register(registry);
+    interval::register(registry);
+    timestamp_tz::register(registry);
+    geometry::register(registry);
     math::register(registry);
     map::register(registry);
     string::register(registry);
@@ -78,6 +78,10 @@ pub fn register(registry: &mut FunctionRegistry) {
         "rand".to_string(),
         FunctionProperty::default().non_deterministic(),
     );
+    registry.properties.insert(
+        "nextval".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
 
     registry.register_passthrough_nullable_1_arg::<Float64Type, StringType, _, _>(
         "humanize_size",
@@ -130,6 +134,18 @@ pub fn register(registry: &mut FunctionRegistry) {
         },
     );
 
+    // The values of a sequence are allocated by the meta service, so `nextval` is
+    // rewritten into a column fed by the pipeline before evaluation. Reaching here
+    // means it is used somewhere the rewrite is not supported.
+    registry.register_1_arg_core::<StringType, NumberType<u64>, _, _>(
+        "nextval",
+        |_, _| FunctionDomain::MayThrow,
+        |_, ctx| {
+            ctx.set_error(0, "nextval() is not supported in this context");
+            Value::Scalar(0_u64)
+        },
+    );
+
     registry.register_0_arg_core::<NumberType<F64>, _, _>(
         "rand",
         |_| {
@@ -0,0 +1,234 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use chrono_tz::Tz;
+use common_expression::error_to_null;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::SimpleDomain;
+use common_expression::types::string::StringDomain;
+use common_expression::types::timestamp_tz::parse_offset;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_tz;
+use common_expression::types::DateType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+use crate::scalars::datetime::calc_date_to_timestamp;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS timestamp_tz)
+    // to_timestamp_tz(xx)
+    register_to_timestamp_tz(registry);
+
+    // cast(timestamp_tz AS [string | timestamp])
+    // to_[string | timestamp](timestamp_tz)
+    register_timestamp_tz_to_other(registry);
+
+    // convert_timezone(zone, [timestamp | timestamp_tz])
+    // [timestamp | timestamp_tz] AT TIME ZONE zone
+    register_convert_timezone(registry);
+}
+
+fn register_to_timestamp_tz(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_timestamp_tz),
+    );
+
+    fn eval_string_to_timestamp_tz(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<StringType, TimestampTzType>(|val, output, ctx| {
+            match string_to_timestamp_tz(val, ctx.func_ctx.tz.tz) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `TIMESTAMP_TZ`: {e}"),
+                    );
+                    output.push(timestamp_tz::default());
+                }
+            }
+        })(val, ctx)
+    }
+
+    // A naive timestamp is read in the session time zone and keeps its offset.
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampType, TimestampTzType>(|val, output, ctx| {
+            output.push(timestamp_tz::from_timestamp(val, ctx.func_ctx.tz.tz));
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampType, NullableType<TimestampTzType>>(
+            |val, output, ctx| {
+                output.push(timestamp_tz::from_timestamp(val, ctx.func_ctx.tz.tz));
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<DateType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<DateType, TimestampTzType>(|val, output, ctx| {
+            let tz = ctx.func_ctx.tz.tz;
+            output.push(timestamp_tz::from_timestamp(
+                calc_date_to_timestamp(val, tz),
+                tz,
+            ));
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<DateType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<DateType, NullableType<TimestampTzType>>(
+            |val, output, ctx| {
+                let tz = ctx.func_ctx.tz.tz;
+                output.push(timestamp_tz::from_timestamp(
+                    calc_date_to_timestamp(val, tz),
+                    tz,
+                ));
+            },
+        ),
+    );
+}
+
+fn register_timestamp_tz_to_other(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, StringType>(|val, output, _| {
+            write!(output.data, "{val}").unwrap();
+            output.commit_row();
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: vec![],
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<TimestampTzType, NullableType<StringType>>(
+            |val, output, _| {
+                write!(output.builder.data, "{val}").unwrap();
+                output.builder.commit_row();
+                output.validity.push(true);
+            },
+        ),
+    );
+
+    // Dropping the offset keeps the instant.
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "to_timestamp",
+        |_, domain| {
+            FunctionDomain::Domain(SimpleDomain {
+                min: domain.min.timestamp(),
+                max: domain.max.timestamp(),
+            })
+        },
+        vectorize_with_builder_1_arg::<TimestampTzType, TimestampType>(|val, output, _| {
+            output.push(val.timestamp());
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "try_to_timestamp",
+        |_, domain| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(SimpleDomain {
+                    min: domain.min.timestamp(),
+                    max: domain.max.timestamp(),
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<TimestampTzType, NullableType<TimestampType>>(
+            |val, output, _| {
+                output.push(val.timestamp());
+            },
+        ),
+    );
+}
+
+/// Resolve a time zone name such as `Asia/Shanghai`, or a fixed offset such as
+/// `+08:00`, and attach the offset it observes at `timestamp`.
+fn convert_timezone(zone: &[u8], timestamp: i64) -> Result<timestamp_tz, String> {
+    let zone = std::str::from_utf8(zone)
+        .map_err(|_| "time zone must be a valid utf-8 string".to_string())?
+        .trim();
+    if let Some(offset) = parse_offset(zone) {
+        return Ok(timestamp_tz::new(timestamp, offset));
+    }
+    let tz = zone
+        .parse::<Tz>()
+        .map_err(|_| format!("invalid time zone '{zone}'"))?;
+    Ok(timestamp_tz::from_timestamp(timestamp, tz))
+}
+
+fn register_convert_timezone(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampTzType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampTzType, TimestampTzType>(
+                |zone, ts, output, ctx| match convert_timezone(zone, ts.timestamp()) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(timestamp_tz::default());
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampType, TimestampTzType>(
+                |zone, ts, output, ctx| match convert_timezone(zone, ts) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(timestamp_tz::default());
+                    }
+                },
+            ),
+        );
+}
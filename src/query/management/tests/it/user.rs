@@ -26,6 +26,8 @@ use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::GetKVReply;
 use common_meta_kvapi::kvapi::ListKVReply;
 use common_meta_kvapi::kvapi::MGetKVReply;
+use common_meta_kvapi::kvapi::RangeKVReply;
+use common_meta_kvapi::kvapi::RangeKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::MatchSeq;
@@ -58,6 +60,8 @@ mock! {
 
         async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, MetaError>;
 
+        async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, MetaError>;
+
         async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError>;
 
         }
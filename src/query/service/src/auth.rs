@@ -69,6 +69,14 @@ impl AuthMgr {
                 token: t,
                 client_ip,
             } => {
+                if let Some((user, role)) = self
+                    .auth_key_pair(&session, t.as_str(), client_ip.as_deref())
+                    .await?
+                {
+                    session.set_authed_user(user, role).await?;
+                    return Ok(());
+                }
+
                 let jwt_auth = self
                     .jwt_auth
                     .as_ref()
@@ -155,4 +163,42 @@ impl AuthMgr {
         };
         Ok(())
     }
+
+    /// Authenticate a JWT signed by the private key of a key pair user.
+    ///
+    /// Returns `None` if the subject of the token is not a key pair user, the token is then
+    /// verified by the configured jwt key stores.
+    #[async_backtrace::framed]
+    async fn auth_key_pair(
+        &self,
+        session: &Arc<Session>,
+        token: &str,
+        client_ip: Option<&str>,
+    ) -> Result<Option<(UserInfo, Option<String>)>> {
+        let claims = JwtAuthenticator::decode_unverified_claims(token)?;
+        let user_name = match claims.subject {
+            Some(user_name) => user_name,
+            None => return Ok(None),
+        };
+
+        let tenant = session.get_current_tenant();
+        let identity = UserIdentity::new(&user_name, "%");
+        let user = match UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, identity, client_ip)
+            .await
+        {
+            Ok(user) => user,
+            Err(e) if e.code() == ErrorCode::META_SERVICE_ERROR => return Err(e),
+            Err(_) => return Ok(None),
+        };
+        if !matches!(user.auth_info, AuthInfo::KeyPair { .. }) {
+            return Ok(None);
+        }
+
+        let jwt = JwtAuthenticator::parse_jwt_claims_with_public_keys(
+            token,
+            &user.auth_info.get_public_keys(),
+        )?;
+        Ok(Some((user, jwt.custom.role)))
+    }
 }
@@ -196,6 +196,20 @@ impl ClusterDiscovery {
         Ok((lift_time, Arc::new(cluster_manager)))
     }
 
+    pub fn local_id(&self) -> &str {
+        &self.local_id
+    }
+
+    pub fn cluster_id(&self) -> &str {
+        &self.cluster_id
+    }
+
+    /// The nodes registered to the meta service, whether they are reachable or not.
+    #[async_backtrace::framed]
+    pub async fn get_nodes(&self) -> Result<Vec<NodeInfo>> {
+        self.api_provider.get_nodes().await
+    }
+
     #[async_backtrace::framed]
     pub async fn discover(&self, config: &InnerConfig) -> Result<Arc<Cluster>> {
         match self.api_provider.get_nodes().await {
@@ -20,6 +20,7 @@ use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_storages_system::AuditLogTable;
 use common_storages_system::BackgroundJobTable;
 use common_storages_system::BackgroundTaskTable;
 use common_storages_system::BacktraceTable;
@@ -51,6 +52,7 @@ use common_storages_system::StagesTable;
 use common_storages_system::TableFunctionsTable;
 use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
+use common_storages_system::TaskHistoryTable;
 use common_storages_system::TempFilesTable;
 use common_storages_system::TracingTable;
 use common_storages_system::UsersTable;
@@ -117,6 +119,11 @@ impl SystemDatabase {
             BacktraceTable::create(sys_db_meta.next_table_id()),
             TempFilesTable::create(sys_db_meta.next_table_id()),
             QuerySummaryTable::create(sys_db_meta.next_table_id()),
+            TaskHistoryTable::create(sys_db_meta.next_table_id()),
+            Arc::new(AuditLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
         ];
 
         let disable_tables = Self::disable_system_tables();
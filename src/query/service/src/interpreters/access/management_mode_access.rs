@@ -76,6 +76,12 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::DropTable(_)
                 | Plan::DropView(_)
                 | Plan::CreateView(_)
+                | Plan::CreateMaterializedView(_)
+                | Plan::RefreshMaterializedView(_)
+
+                // Sequence.
+                | Plan::CreateSequence(_)
+                | Plan::DropSequence(_)
 
                 // User.
                 | Plan::AlterUser(_)
@@ -99,12 +105,21 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateNetworkPolicy(_)
                 | Plan::AlterNetworkPolicy(_)
                 | Plan::DropNetworkPolicy(_)
+                // Task.
+                | Plan::CreateTask(_)
+                | Plan::AlterTask(_)
+                | Plan::DropTask(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
                 | Plan::AlterUDF(_)
                 | Plan::DropUDF(_)
-                | Plan::UseDatabase(_) => true,
+                | Plan::UseDatabase(_)
+
+                // Transaction
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Abort => true,
                 Plan::DescribeTable(plan) => {
                     let catalog = &plan.catalog;
                     let database = &plan.database;
@@ -86,7 +86,9 @@ impl AccessChecker for PrivilegeAccess {
                 };
                 let metadata = metadata.read().clone();
                 for table in metadata.tables() {
-                    if table.is_source_of_view() {
+                    // The tables added for aggregating indexes and materialized views are
+                    // only read on behalf of the queried tables.
+                    if table.is_source_of_view() || table.is_source_of_index() {
                         continue;
                     }
                     session
@@ -199,6 +201,47 @@ impl AccessChecker for PrivilegeAccess {
                     .await?;
             }
 
+            // Vector Index.
+            Plan::CreateVectorIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                        false,
+                    )
+                    .await?;
+            }
+            Plan::DropVectorIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                        false,
+                    )
+                    .await?;
+            }
+            Plan::RefreshVectorIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                        false,
+                    )
+                    .await?;
+            }
+
             // Table.
             Plan::ShowCreateTable(plan) => {
                 session
@@ -519,6 +562,39 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                        true,
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.source_database.clone(),
+                            plan.source_table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                        true,
+                    )
+                    .await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.view_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Insert],
+                        true,
+                    )
+                    .await?;
+            }
             Plan::AlterView(plan) => {
                 session
                     .validate_privilege(
@@ -636,6 +712,8 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::ShowCreateCatalog(_)
             | Plan::CreateCatalog(_)
             | Plan::DropCatalog(_)
+            | Plan::CreateSequence(_)
+            | Plan::DropSequence(_)
             | Plan::CreateStage(_)
             | Plan::DropStage(_)
             | Plan::RemoveStage(_)
@@ -646,7 +724,11 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterNetworkPolicy(_)
             | Plan::DropNetworkPolicy(_)
             | Plan::DescNetworkPolicy(_)
-            | Plan::ShowNetworkPolicies(_) => {
+            | Plan::ShowNetworkPolicies(_)
+            | Plan::CreateTask(_)
+            | Plan::AlterTask(_)
+            | Plan::DropTask(_)
+            | Plan::ExecuteTask(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
                     .await?;
@@ -663,6 +745,8 @@ impl AccessChecker for PrivilegeAccess {
             // Note: No need to check privileges
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
+            // the privileges are checked by the statements inside the transaction
+            Plan::Begin | Plan::Commit | Plan::Abort => {}
             Plan::ShowRoles(_) => {}
             Plan::Presign(_) => {}
             Plan::ExplainAst { .. } => {}
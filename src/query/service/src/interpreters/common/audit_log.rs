@@ -0,0 +1,728 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::SendableDataBlockStream;
+use common_meta_app::principal::PrincipalIdentity;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::OneBlockSource;
+use common_storages_system::AuditLogElement;
+use common_storages_system::AuditLogQueue;
+use common_storages_system::SystemLogElement;
+use common_users::UserApiProvider;
+use log::error;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use serde_json::json;
+use serde_json::Value;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+
+/// The meta object changed by an audited statement.
+#[derive(Clone, Debug)]
+pub enum AuditObject {
+    Catalog(String),
+    Database {
+        catalog: String,
+        database: String,
+    },
+    Table {
+        catalog: String,
+        database: String,
+        table: String,
+    },
+    User(UserIdentity),
+    Role(String),
+    Other {
+        kind: &'static str,
+        name: String,
+    },
+}
+
+impl AuditObject {
+    fn database(catalog: &str, database: &str) -> Self {
+        AuditObject::Database {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+        }
+    }
+
+    fn table(catalog: &str, database: &str, table: &str) -> Self {
+        AuditObject::Table {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+        }
+    }
+
+    fn principal(principal: &PrincipalIdentity) -> Self {
+        match principal {
+            PrincipalIdentity::User(user) => AuditObject::User(user.clone()),
+            PrincipalIdentity::Role(role) => AuditObject::Role(role.clone()),
+        }
+    }
+
+    fn other(kind: &'static str, name: &str) -> Self {
+        AuditObject::Other {
+            kind,
+            name: name.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AuditObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuditObject::Catalog(catalog) => write!(f, "CATALOG {}", catalog),
+            AuditObject::Database { catalog, database } => {
+                write!(f, "DATABASE {}.{}", catalog, database)
+            }
+            AuditObject::Table {
+                catalog,
+                database,
+                table,
+            } => write!(f, "TABLE {}.{}.{}", catalog, database, table),
+            AuditObject::User(user) => write!(f, "USER {}", user),
+            AuditObject::Role(role) => write!(f, "ROLE '{}'", role),
+            AuditObject::Other { kind, name } => write!(f, "{} {}", kind, name),
+        }
+    }
+}
+
+/// What an audited statement does: the action, the object it changes,
+/// and for renames, the object after the change.
+pub struct AuditTarget {
+    pub action: &'static str,
+    pub object: AuditObject,
+    pub new_object: Option<AuditObject>,
+}
+
+impl AuditTarget {
+    fn new(action: &'static str, object: AuditObject) -> Self {
+        AuditTarget {
+            action,
+            object,
+            new_object: None,
+        }
+    }
+
+    /// Returns the audit target of the DDL and DCL plans, `None` for the others.
+    pub fn from_plan(plan: &Plan) -> Option<AuditTarget> {
+        let target = match plan {
+            // Catalog.
+            Plan::CreateCatalog(p) => {
+                Self::new("CREATE CATALOG", AuditObject::Catalog(p.catalog.clone()))
+            }
+            Plan::DropCatalog(p) => {
+                Self::new("DROP CATALOG", AuditObject::Catalog(p.catalog.clone()))
+            }
+
+            // Database.
+            Plan::CreateDatabase(p) => Self::new(
+                "CREATE DATABASE",
+                AuditObject::database(&p.catalog, &p.database),
+            ),
+            Plan::DropDatabase(p) => Self::new(
+                "DROP DATABASE",
+                AuditObject::database(&p.catalog, &p.database),
+            ),
+            Plan::UndropDatabase(p) => Self::new(
+                "UNDROP DATABASE",
+                AuditObject::database(&p.catalog, &p.database),
+            ),
+            Plan::RenameDatabase(p) => {
+                let entity = p.entities.first()?;
+                AuditTarget {
+                    action: "RENAME DATABASE",
+                    object: AuditObject::database(&entity.catalog, &entity.database),
+                    new_object: Some(AuditObject::database(&entity.catalog, &entity.new_database)),
+                }
+            }
+
+            // Table.
+            Plan::CreateTable(p) => Self::new(
+                "CREATE TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTable(p) => Self::new(
+                "DROP TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::UndropTable(p) => Self::new(
+                "UNDROP TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::RenameTable(p) => AuditTarget {
+                action: "RENAME TABLE",
+                object: AuditObject::table(&p.catalog, &p.database, &p.table),
+                new_object: Some(AuditObject::table(
+                    &p.catalog,
+                    &p.new_database,
+                    &p.new_table,
+                )),
+            },
+            Plan::TruncateTable(p) => Self::new(
+                "TRUNCATE TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::RevertTable(p) => Self::new(
+                "REVERT TABLE",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::SetOptions(p) => Self::new(
+                "ALTER TABLE SET OPTIONS",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::AddTableColumn(p) => Self::new(
+                "ALTER TABLE ADD COLUMN",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTableColumn(p) => Self::new(
+                "ALTER TABLE DROP COLUMN",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::ModifyTableColumn(p) => Self::new(
+                "ALTER TABLE MODIFY COLUMN",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::RenameTableColumn(p) => Self::new(
+                "ALTER TABLE RENAME COLUMN",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::AlterTableClusterKey(p) => Self::new(
+                "ALTER TABLE CLUSTER BY",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropTableClusterKey(p) => Self::new(
+                "ALTER TABLE DROP CLUSTER KEY",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+
+            // View.
+            Plan::CreateView(p) => Self::new(
+                "CREATE VIEW",
+                AuditObject::table(&p.catalog, &p.database, &p.view_name),
+            ),
+            Plan::AlterView(p) => Self::new(
+                "ALTER VIEW",
+                AuditObject::table(&p.catalog, &p.database, &p.view_name),
+            ),
+            Plan::DropView(p) => Self::new(
+                "DROP VIEW",
+                AuditObject::table(&p.catalog, &p.database, &p.view_name),
+            ),
+            Plan::CreateMaterializedView(p) => Self::new(
+                "CREATE MATERIALIZED VIEW",
+                AuditObject::table(&p.catalog, &p.database, &p.view_name),
+            ),
+            Plan::RefreshMaterializedView(p) => Self::new(
+                "REFRESH MATERIALIZED VIEW",
+                AuditObject::table(&p.catalog, &p.database, &p.view_name),
+            ),
+
+            // User and role.
+            Plan::CreateUser(p) => Self::new("CREATE USER", AuditObject::User(p.user.clone())),
+            Plan::AlterUser(p) => Self::new("ALTER USER", AuditObject::User(p.user.clone())),
+            Plan::DropUser(p) => Self::new("DROP USER", AuditObject::User(p.user.clone())),
+            Plan::CreateRole(p) => Self::new("CREATE ROLE", AuditObject::Role(p.role_name.clone())),
+            Plan::DropRole(p) => Self::new("DROP ROLE", AuditObject::Role(p.role_name.clone())),
+
+            // Grant.
+            Plan::GrantRole(p) => Self::new("GRANT ROLE", AuditObject::principal(&p.principal)),
+            Plan::RevokeRole(p) => Self::new("REVOKE ROLE", AuditObject::principal(&p.principal)),
+            Plan::GrantPriv(p) => {
+                Self::new("GRANT PRIVILEGE", AuditObject::principal(&p.principal))
+            }
+            Plan::RevokePriv(p) => {
+                Self::new("REVOKE PRIVILEGE", AuditObject::principal(&p.principal))
+            }
+
+            // Others.
+            Plan::CreateUDF(p) => Self::new(
+                "CREATE FUNCTION",
+                AuditObject::other("FUNCTION", &p.udf.name),
+            ),
+            Plan::AlterUDF(p) => Self::new(
+                "ALTER FUNCTION",
+                AuditObject::other("FUNCTION", &p.udf.name),
+            ),
+            Plan::DropUDF(p) => Self::new("DROP FUNCTION", AuditObject::other("FUNCTION", &p.name)),
+            Plan::CreateStage(p) => Self::new(
+                "CREATE STAGE",
+                AuditObject::other("STAGE", &p.stage_info.stage_name),
+            ),
+            Plan::DropStage(p) => Self::new("DROP STAGE", AuditObject::other("STAGE", &p.name)),
+            Plan::CreateFileFormat(p) => Self::new(
+                "CREATE FILE FORMAT",
+                AuditObject::other("FILE FORMAT", &p.name),
+            ),
+            Plan::DropFileFormat(p) => Self::new(
+                "DROP FILE FORMAT",
+                AuditObject::other("FILE FORMAT", &p.name),
+            ),
+            Plan::CreateShare(p) => {
+                Self::new("CREATE SHARE", AuditObject::other("SHARE", &p.share))
+            }
+            Plan::DropShare(p) => Self::new("DROP SHARE", AuditObject::other("SHARE", &p.share)),
+            Plan::CreateIndex(p) => {
+                Self::new("CREATE INDEX", AuditObject::other("INDEX", &p.index_name))
+            }
+            Plan::DropIndex(p) => Self::new("DROP INDEX", AuditObject::other("INDEX", &p.index)),
+            Plan::CreateVectorIndex(p) => Self::new(
+                "CREATE VECTOR INDEX",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::DropVectorIndex(p) => Self::new(
+                "DROP VECTOR INDEX",
+                AuditObject::table(&p.catalog, &p.database, &p.table),
+            ),
+            Plan::CreateNetworkPolicy(p) => Self::new(
+                "CREATE NETWORK POLICY",
+                AuditObject::other("NETWORK POLICY", &p.name),
+            ),
+            Plan::AlterNetworkPolicy(p) => Self::new(
+                "ALTER NETWORK POLICY",
+                AuditObject::other("NETWORK POLICY", &p.name),
+            ),
+            Plan::DropNetworkPolicy(p) => Self::new(
+                "DROP NETWORK POLICY",
+                AuditObject::other("NETWORK POLICY", &p.name),
+            ),
+            Plan::CreateTask(p) => Self::new("CREATE TASK", AuditObject::other("TASK", &p.name)),
+            Plan::AlterTask(p) => Self::new("ALTER TASK", AuditObject::other("TASK", &p.name)),
+            Plan::DropTask(p) => Self::new("DROP TASK", AuditObject::other("TASK", &p.name)),
+            Plan::CreateSequence(p) => Self::new(
+                "CREATE SEQUENCE",
+                AuditObject::other("SEQUENCE", &p.sequence),
+            ),
+            Plan::DropSequence(p) => {
+                Self::new("DROP SEQUENCE", AuditObject::other("SEQUENCE", &p.sequence))
+            }
+
+            _ => return None,
+        };
+        Some(target)
+    }
+}
+
+/// Name of the fuse table in `query.audit_log_database` to which the audit events are appended.
+pub const AUDIT_LOG_TABLE: &str = "audit_log";
+
+/// The buffered audit events are appended to the audit log table at this interval.
+const AUDIT_LOG_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The oldest buffered events are dropped if the audit log table can not keep up.
+const AUDIT_LOG_MAX_BUFFERED: usize = 100_000;
+
+pub struct InterpreterAuditLog;
+
+impl InterpreterAuditLog {
+    /// Write an audit event to the `audit` log target and `system.audit_log`,
+    /// and buffer it for the audit log table if it is enabled.
+    ///
+    /// A failure to write the event is logged, and does not fail the audited statement.
+    pub fn log(
+        ctx: &QueryContext,
+        target: &AuditTarget,
+        before: String,
+        after: String,
+        err: Option<&ErrorCode>,
+    ) {
+        Self::write_log(ctx, target, before, after, err)
+            .unwrap_or_else(|e| error!("fail to write audit_log {:?}", e));
+    }
+
+    /// Whether `database` is the database of the audit log, which can not be changed by statements.
+    pub fn is_audit_log_database(database: &str) -> bool {
+        let audit_log_database = &GlobalConfig::instance().query.audit_log_database;
+        !audit_log_database.is_empty() && audit_log_database == database
+    }
+
+    fn write_log(
+        ctx: &QueryContext,
+        target: &AuditTarget,
+        before: String,
+        after: String,
+        err: Option<&ErrorCode>,
+    ) -> Result<()> {
+        let event_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::new(0, 0))
+            .as_micros() as i64;
+
+        let actor = match ctx.get_current_user() {
+            Ok(user) => user.identity().to_string(),
+            Err(_) => "".to_string(),
+        };
+        let role = ctx.get_current_role().map(|r| r.name).unwrap_or_default();
+        let client_address = match ctx.get_client_address() {
+            Some(addr) => format!("{:?}", addr),
+            None => "".to_string(),
+        };
+
+        let (error_code, error_text) = match err {
+            None => (0, "".to_string()),
+            Some(e) => (e.code().into(), e.message()),
+        };
+
+        let event = AuditLogElement {
+            event_time,
+            tenant_id: ctx.get_tenant(),
+            cluster_id: GlobalConfig::instance().query.cluster_id.clone(),
+            node_id: ctx.get_cluster().local_id.clone(),
+            query_id: ctx.get_id(),
+            actor,
+            role,
+            client_address,
+            action: target.action.to_string(),
+            object: target.object.to_string(),
+            before,
+            after,
+            error_code,
+            error_text,
+        };
+
+        let event_str = serde_json::to_string(&event)?;
+        info!(target: "audit", "{}", event_str);
+        if !GlobalConfig::instance().query.audit_log_database.is_empty() {
+            AuditLogWriter::instance().push(event.clone());
+        }
+        AuditLogQueue::instance()?.append_data(event)
+    }
+}
+
+/// Buffers the audit events of this node, and appends them to the audit log table in batches.
+///
+/// The events are appended in a new session, so that they are committed even if the audited
+/// session is in an explicit transaction. The events buffered when the node stops are lost,
+/// but they are kept in the `audit` log files.
+pub struct AuditLogWriter {
+    events: Mutex<VecDeque<AuditLogElement>>,
+}
+
+impl AuditLogWriter {
+    pub fn init() -> Result<()> {
+        let writer = Arc::new(AuditLogWriter {
+            events: Mutex::new(VecDeque::new()),
+        });
+        GlobalInstance::set(writer.clone());
+
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                sleep(AUDIT_LOG_FLUSH_INTERVAL).await;
+                writer.flush().await;
+            }
+        });
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<AuditLogWriter> {
+        GlobalInstance::get()
+    }
+
+    fn push(&self, event: AuditLogElement) {
+        let mut events = self.events.lock();
+        if events.len() >= AUDIT_LOG_MAX_BUFFERED {
+            warn!("too many buffered audit events, drop the oldest one");
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    /// Append the buffered events to the audit log tables of their tenants.
+    #[async_backtrace::framed]
+    pub async fn flush(&self) {
+        let database = GlobalConfig::instance().query.audit_log_database.clone();
+        let events = std::mem::take(&mut *self.events.lock());
+        let mut tenant_events: BTreeMap<String, Vec<AuditLogElement>> = BTreeMap::new();
+        for event in events {
+            tenant_events
+                .entry(event.tenant_id.clone())
+                .or_default()
+                .push(event);
+        }
+
+        for (tenant, events) in tenant_events {
+            let num_events = events.len();
+            if let Err(e) = Self::append(&tenant, &database, events).await {
+                error!(
+                    "fail to append {} events to audit_log of tenant {}: {:?}",
+                    num_events, tenant, e
+                );
+            }
+        }
+    }
+
+    async fn append(tenant: &str, database: &str, events: Vec<AuditLogElement>) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session.set_current_tenant(tenant.to_string());
+        let ctx = session.create_query_context().await?;
+
+        let table = Self::get_or_create_table(&ctx, database).await?;
+
+        let schema = AuditLogElement::schema();
+        let mut columns = schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::with_capacity(&f.data_type().into(), events.len()))
+            .collect::<Vec<_>>();
+        for event in events {
+            event.fill_to_data_block(&mut columns)?;
+        }
+        let block = DataBlock::new_from_columns(columns.into_iter().map(|c| c.build()).collect());
+
+        let mut pipeline = Pipeline::create();
+        pipeline.add_source(|output| OneBlockSource::create(output, block.clone()), 1)?;
+        table.append_data(ctx.clone(), &mut pipeline, AppendMode::Normal)?;
+        table.commit_insertion(ctx.clone(), &mut pipeline, None, false, None)?;
+
+        // the complete executor blocks the thread until the pipeline finishes.
+        let settings = ExecutorSettings::try_create(&ctx.get_settings(), ctx.get_id())?;
+        GlobalIORuntime::instance()
+            .spawn_blocking(move || {
+                let executor = PipelineCompleteExecutor::try_create(pipeline, settings)?;
+                ctx.set_executor(executor.get_inner())?;
+                executor.execute()
+            })
+            .await
+    }
+
+    /// Get the audit log table, create it and its database on the first event.
+    async fn get_or_create_table(ctx: &QueryContext, database: &str) -> Result<Arc<dyn Table>> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+
+        match catalog.get_table(&tenant, database, AUDIT_LOG_TABLE).await {
+            Ok(table) => return Ok(table),
+            Err(e) if e.code() == ErrorCode::UNKNOWN_DATABASE => {
+                catalog
+                    .create_database(CreateDatabaseReq {
+                        if_not_exists: true,
+                        name_ident: DatabaseNameIdent {
+                            tenant: tenant.clone(),
+                            db_name: database.to_string(),
+                        },
+                        meta: DatabaseMeta {
+                            engine: "".to_string(),
+                            comment: "audit log of DDL and DCL statements".to_string(),
+                            ..Default::default()
+                        },
+                    })
+                    .await?;
+            }
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => {}
+            Err(e) => return Err(e),
+        }
+
+        // The fuse engine keeps the database id in the table options.
+        let db_id = catalog
+            .get_database(&tenant, database)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+
+        catalog
+            .create_table(CreateTableReq {
+                if_not_exists: true,
+                name_ident: TableNameIdent {
+                    tenant: tenant.clone(),
+                    db_name: database.to_string(),
+                    table_name: AUDIT_LOG_TABLE.to_string(),
+                },
+                table_meta: TableMeta {
+                    schema: AuditLogElement::schema(),
+                    engine: "FUSE".to_string(),
+                    options: BTreeMap::from([(OPT_KEY_DATABASE_ID.to_string(), db_id.to_string())]),
+                    ..Default::default()
+                },
+                auto_increment_sequences: vec![],
+            })
+            .await?;
+
+        catalog.get_table(&tenant, database, AUDIT_LOG_TABLE).await
+    }
+}
+
+impl InterpreterAuditLog {
+    /// A json snapshot of the current state of an object, or an empty string if it does not exist.
+    ///
+    /// Secrets such as passwords are never included.
+    pub async fn snapshot(ctx: &QueryContext, object: &AuditObject) -> String {
+        match Self::snapshot_value(ctx, object).await {
+            Ok(Some(v)) => v.to_string(),
+            _ => "".to_string(),
+        }
+    }
+
+    async fn snapshot_value(ctx: &QueryContext, object: &AuditObject) -> Result<Option<Value>> {
+        let tenant = ctx.get_tenant();
+
+        let value = match object {
+            AuditObject::Database { catalog, database } => {
+                let catalog = ctx.get_catalog(catalog).await?;
+                let db = catalog.get_database(&tenant, database).await?;
+                let info = db.get_db_info();
+                json!({
+                    "id": info.ident.db_id,
+                    "engine": info.meta.engine,
+                    "options": info.meta.options,
+                    "comment": info.meta.comment,
+                })
+            }
+            AuditObject::Table {
+                catalog,
+                database,
+                table,
+            } => {
+                // Bypass the table cache of the context to see the latest version.
+                let catalog = ctx.get_catalog(catalog).await?;
+                let table = catalog.get_table(&tenant, database, table).await?;
+                let info = table.get_table_info();
+                let columns = info
+                    .meta
+                    .schema
+                    .fields()
+                    .iter()
+                    .map(|f| format!("{} {}", f.name(), f.data_type()))
+                    .collect::<Vec<_>>();
+                json!({
+                    "id": info.ident.table_id,
+                    "version": info.ident.seq,
+                    "engine": table.engine(),
+                    "schema": columns,
+                    "options": info.meta.options,
+                    "cluster_key": info.meta.default_cluster_key,
+                })
+            }
+            AuditObject::User(user) => {
+                let user = UserApiProvider::instance()
+                    .get_user(&tenant, user.clone())
+                    .await?;
+                json!({
+                    "name": user.identity().to_string(),
+                    "auth_type": user.auth_info.get_type().to_str(),
+                    "grants": user.grants.to_string(),
+                    "roles": user.grants.roles(),
+                    "default_role": user.option.default_role(),
+                })
+            }
+            AuditObject::Role(role) => {
+                let role = UserApiProvider::instance()
+                    .get_role(&tenant, role.clone())
+                    .await?;
+                json!({
+                    "name": role.name,
+                    "grants": role.grants.to_string(),
+                    "roles": role.grants.roles(),
+                })
+            }
+            AuditObject::Catalog(_) | AuditObject::Other { .. } => return Ok(None),
+        };
+
+        Ok(Some(value))
+    }
+}
+
+/// Wraps the interpreter of a DDL or DCL statement to write an audit event after it is executed.
+pub struct AuditedInterpreter {
+    ctx: Arc<QueryContext>,
+    inner: InterpreterPtr,
+    target: AuditTarget,
+}
+
+impl AuditedInterpreter {
+    pub fn create(ctx: Arc<QueryContext>, inner: InterpreterPtr, target: AuditTarget) -> Self {
+        AuditedInterpreter { ctx, inner, target }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AuditedInterpreter {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let before = InterpreterAuditLog::snapshot(&self.ctx, &self.target.object).await;
+
+        let res = self.inner.execute2().await;
+
+        match &res {
+            Ok(_) => {
+                let new_object = self.target.new_object.as_ref();
+                let after = InterpreterAuditLog::snapshot(
+                    &self.ctx,
+                    new_object.unwrap_or(&self.target.object),
+                )
+                .await;
+                InterpreterAuditLog::log(&self.ctx, &self.target, before, after, None);
+            }
+            Err(e) => {
+                InterpreterAuditLog::log(&self.ctx, &self.target, before, "".to_string(), Some(e));
+            }
+        }
+
+        res
+    }
+
+    fn set_source_pipe_builder(&self, builder: Option<SourcePipeBuilder>) -> Result<()> {
+        self.inner.set_source_pipe_builder(builder)
+    }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        self.inner.inject_result()
+    }
+}
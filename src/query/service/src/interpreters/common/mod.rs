@@ -12,20 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit_log;
 mod compact_hook;
 mod grant;
 mod metrics;
 mod query_log;
 mod refresh_aggregating_index;
 mod table;
+mod task;
+mod txn;
 mod util;
 
+pub use audit_log::AuditLogWriter;
+pub use audit_log::AuditObject;
+pub use audit_log::AuditTarget;
+pub use audit_log::AuditedInterpreter;
+pub use audit_log::InterpreterAuditLog;
 pub use compact_hook::*;
 pub use grant::validate_grant_object_exists;
 pub use query_log::InterpreterQueryLog;
 pub use refresh_aggregating_index::hook_refresh_agg_index;
 pub use refresh_aggregating_index::RefreshAggIndexDesc;
 pub use table::check_referenced_computed_columns;
+pub use task::check_task_predecessors;
+pub use task::execute_sql_task;
+pub use task::get_sql_task;
+pub use txn::abort_txn;
+pub use txn::abort_txn_on_session_drop;
+pub use txn::begin_txn;
+pub use txn::commit_txn;
 pub use util::check_deduplicate_label;
 pub use util::create_push_down_filters;
 
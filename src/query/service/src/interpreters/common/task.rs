@@ -0,0 +1,287 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::uuid::Uuid;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundJobState;
+use common_meta_app::background::BackgroundTaskIdent;
+use common_meta_app::background::BackgroundTaskInfo;
+use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::background::GetBackgroundJobReq;
+use common_meta_app::background::ListBackgroundJobsReq;
+use common_meta_app::background::ManualTriggerParams;
+use common_meta_app::background::SqlTaskDefinition;
+use common_meta_app::background::UpdateBackgroundJobStatusReq;
+use common_meta_app::background::UpdateBackgroundTaskReq;
+use common_meta_store::MetaStore;
+use common_sql::Planner;
+use common_users::UserApiProvider;
+use futures::StreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// The run history of the SQL tasks is kept for 7 days.
+const TASK_HISTORY_EXPIRE_SEC: u64 = 60 * 60 * 24 * 7;
+
+/// Get the SQL task, the background jobs of other types are invisible to the task statements.
+#[async_backtrace::framed]
+pub async fn get_sql_task(
+    meta: &Arc<MetaStore>,
+    name: &BackgroundJobIdent,
+) -> Result<BackgroundJobInfo> {
+    let unknown_task =
+        || ErrorCode::UnknownBackgroundJob(format!("task {} does not exist", name.name));
+    let info = match meta
+        .get_background_job(GetBackgroundJobReq { name: name.clone() })
+        .await
+    {
+        Ok(reply) => reply.info,
+        Err(e) => {
+            let e = ErrorCode::from(e);
+            if e.code() == ErrorCode::UNKNOWN_BACKGROUND_JOB {
+                return Err(unknown_task());
+            }
+            return Err(e);
+        }
+    };
+    if info.task_type != BackgroundTaskType::SQL || info.sql_task.is_none() {
+        return Err(unknown_task());
+    }
+    Ok(info)
+}
+
+/// Run the SQL task once, then the resumed tasks which run after it, level by level.
+///
+/// Each task of the DAG runs at most once, a failed task does not trigger its successors.
+#[async_backtrace::framed]
+pub async fn execute_sql_task(
+    name: BackgroundJobIdent,
+    manual_trigger: Option<ManualTriggerParams>,
+) -> Result<()> {
+    let meta = UserApiProvider::instance().get_meta_store_client();
+    let info = get_sql_task(&meta, &name).await?;
+    run_sql_task(&meta, &name, info, manual_trigger).await?;
+
+    let mut finished = HashSet::from([name.name.clone()]);
+    let mut predecessors = VecDeque::from([name.name.clone()]);
+    while let Some(predecessor) = predecessors.pop_front() {
+        let jobs = meta
+            .list_background_jobs(ListBackgroundJobsReq {
+                tenant: name.tenant.clone(),
+            })
+            .await?;
+        for (_, job_name, info) in jobs {
+            let runs_after = match &info.sql_task {
+                Some(definition) => definition.after.contains(&predecessor),
+                None => false,
+            };
+            let resumed = match &info.job_status {
+                Some(status) => status.job_state == BackgroundJobState::RUNNING,
+                None => false,
+            };
+            if !runs_after || !resumed || finished.contains(&job_name) {
+                continue;
+            }
+
+            finished.insert(job_name.clone());
+            let successor = BackgroundJobIdent {
+                tenant: name.tenant.clone(),
+                name: job_name,
+            };
+            match run_sql_task(&meta, &successor, info, None).await {
+                Ok(_) => predecessors.push_back(successor.name),
+                Err(e) => warn!(
+                    "task {} which runs after {} failed: {}",
+                    successor.name, predecessor, e
+                ),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check the predecessors of the task exist and run in the same warehouse as the task, since
+/// the successors of a task run on the node which runs the task.
+///
+/// The dropped predecessors are ignored if `ignore_unknown` is set.
+#[async_backtrace::framed]
+pub async fn check_task_predecessors(
+    meta: &Arc<MetaStore>,
+    name: &BackgroundJobIdent,
+    definition: &SqlTaskDefinition,
+    ignore_unknown: bool,
+) -> Result<()> {
+    for predecessor in &definition.after {
+        let predecessor_name = BackgroundJobIdent {
+            tenant: name.tenant.clone(),
+            name: predecessor.clone(),
+        };
+        let info = match get_sql_task(meta, &predecessor_name).await {
+            Ok(info) => info,
+            Err(e) if ignore_unknown && e.code() == ErrorCode::UNKNOWN_BACKGROUND_JOB => continue,
+            Err(e) => {
+                return Err(e.add_message_back(format!(" (the predecessor of task {})", name.name)));
+            }
+        };
+        let warehouse = info.sql_task.and_then(|definition| definition.warehouse);
+        if warehouse != definition.warehouse {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "task {} runs in warehouse {}, but its predecessor {} runs in warehouse {}",
+                name.name,
+                definition.warehouse.as_deref().unwrap_or("<any>"),
+                predecessor,
+                warehouse.as_deref().unwrap_or("<any>"),
+            )));
+        }
+    }
+    Ok(())
+}
+
+async fn run_sql_task(
+    meta: &Arc<MetaStore>,
+    name: &BackgroundJobIdent,
+    info: BackgroundJobInfo,
+    manual_trigger: Option<ManualTriggerParams>,
+) -> Result<()> {
+    let definition = info.sql_task.clone().unwrap_or_default();
+    let task_name = BackgroundTaskIdent {
+        tenant: name.tenant.clone(),
+        task_id: Uuid::new_v4().to_string(),
+    };
+    let mut task_info = BackgroundTaskInfo::new_sql_task(
+        name.clone(),
+        "".to_string(),
+        definition.query_text.clone(),
+        manual_trigger,
+    );
+
+    // the task which fails to start is recorded in the run history as well.
+    let ctx = create_task_context(name, &info, &definition).await;
+    if let (Ok(ctx), Some(stats)) = (&ctx, task_info.sql_task_stats.as_mut()) {
+        stats.query_id = ctx.get_id();
+    }
+    update_task(meta, &task_name, &task_info).await?;
+    if let Some(mut status) = info.job_status {
+        status.last_task_id = Some(task_name.task_id.clone());
+        status.last_task_run_at = Some(Utc::now());
+        meta.update_background_job_status(UpdateBackgroundJobStatusReq {
+            job_name: name.clone(),
+            status,
+        })
+        .await?;
+    }
+
+    let res = match ctx {
+        Ok(ctx) => {
+            info!(task = name.name, query_id = ctx.get_id(); "run sql task");
+            execute_sql(ctx, &definition.query_text).await
+        }
+        Err(e) => Err(e),
+    };
+    task_info.last_updated = Some(Utc::now());
+    match &res {
+        Ok(_) => task_info.task_state = BackgroundTaskState::DONE,
+        Err(e) => {
+            task_info.task_state = BackgroundTaskState::FAILED;
+            task_info.message = e.to_string();
+        }
+    }
+    update_task(meta, &task_name, &task_info).await?;
+    res
+}
+
+async fn create_task_context(
+    name: &BackgroundJobIdent,
+    info: &BackgroundJobInfo,
+    definition: &SqlTaskDefinition,
+) -> Result<Arc<QueryContext>> {
+    // the task with a warehouse only runs on the nodes of the warehouse.
+    if let Some(warehouse) = &definition.warehouse {
+        let cluster_id = &GlobalConfig::instance().query.cluster_id;
+        if warehouse != cluster_id {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "task {} runs in warehouse {}, but the current warehouse is {}",
+                name.name, warehouse, cluster_id
+            )));
+        }
+    }
+    let creator = info.creator.clone().ok_or_else(|| {
+        ErrorCode::Internal(format!("the owner of task {} is unknown", name.name))
+    })?;
+
+    // the task runs with the privileges of its owner.
+    let user = UserApiProvider::instance()
+        .get_user(&name.tenant, creator)
+        .await?;
+    let session = SessionManager::instance()
+        .create_session(SessionType::Dummy)
+        .await?;
+    session.set_authed_user(user, None).await?;
+    session.set_current_database(definition.database.clone());
+    session.create_query_context().await
+}
+
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<()> {
+    let res = async {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, extras) = planner.plan_sql(sql).await?;
+        ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        interpreter.execute(ctx.clone()).await
+    }
+    .await;
+    let mut stream = match res {
+        Ok(stream) => stream,
+        Err(e) => {
+            InterpreterQueryLog::fail_to_start(ctx, e.clone());
+            return Err(e);
+        }
+    };
+    while let Some(block) = stream.next().await {
+        block?;
+    }
+    Ok(())
+}
+
+async fn update_task(
+    meta: &Arc<MetaStore>,
+    task_name: &BackgroundTaskIdent,
+    task_info: &BackgroundTaskInfo,
+) -> Result<()> {
+    meta.update_background_task(UpdateBackgroundTaskReq {
+        task_name: task_name.clone(),
+        task_info: task_info.clone(),
+        expire_at: Utc::now().timestamp() as u64 + TASK_HISTORY_EXPIRE_SEC,
+    })
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,247 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::catalog::Catalog;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
+use common_catalog::temp_table::is_temp_table_id;
+use common_catalog::txn::TxnManagerRef;
+use common_catalog::txn::TxnState;
+use common_catalog::txn::TxnTableMutation;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_storages_fuse::FuseTable;
+use log::info;
+use log::warn;
+
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+const MAX_TXN_COMMIT_RETRIES: usize = 3;
+
+/// Start an explicit transaction in the current session, nested `BEGIN` is ignored.
+pub fn begin_txn(ctx: &Arc<QueryContext>, txn_id: String) {
+    let txn_mgr = ctx.txn_mgr();
+    let mut txn_mgr = txn_mgr.lock();
+    if txn_mgr.state() == TxnState::AutoCommit {
+        info!("begin transaction {}", txn_id);
+        txn_mgr.begin(txn_id);
+    }
+}
+
+/// Commit all the buffered table metas of the explicit transaction in a single meta transaction.
+///
+/// If a new snapshot of any of the tables is committed by others since it is mutated by the
+/// transaction for the first time, the whole transaction is rolled back.
+#[async_backtrace::framed]
+pub async fn commit_txn(ctx: &Arc<QueryContext>) -> Result<()> {
+    let txn_mgr = ctx.txn_mgr();
+    let (state, txn_id, mutated_tables) = {
+        let txn_mgr = txn_mgr.lock();
+        (
+            txn_mgr.state(),
+            txn_mgr.txn_id().to_string(),
+            txn_mgr.mutated_tables(),
+        )
+    };
+
+    match state {
+        TxnState::AutoCommit => return Ok(()),
+        TxnState::Fail => {
+            abort_mutations(ctx, &mutated_tables).await;
+            txn_mgr.lock().clear();
+            return Err(ErrorCode::InvalidTransactionState(format!(
+                "transaction {} is rolled back, because one of its statements failed",
+                txn_id
+            )));
+        }
+        TxnState::Active => {}
+    }
+
+    if mutated_tables.is_empty() {
+        txn_mgr.lock().clear();
+        return Ok(());
+    }
+
+    info!(
+        "commit transaction {}, {} tables mutated",
+        txn_id,
+        mutated_tables.len()
+    );
+    // the meta of temporary tables is kept by the session, they are committed after the others
+    let (temp_tables, tables): (Vec<_>, Vec<_>) = mutated_tables
+        .iter()
+        .cloned()
+        .partition(|mutation| is_temp_table_id(mutation.base_table_info.ident.table_id));
+    let res = match tables.is_empty() {
+        true => Ok(()),
+        false => commit_mutations(ctx, &tables).await,
+    };
+    let res = res.and_then(|_| {
+        let temp_tbl_mgr = ctx.temp_tbl_mgr();
+        let mut temp_tbl_mgr = temp_tbl_mgr.lock();
+        for mutation in temp_tables.iter() {
+            temp_tbl_mgr.update_table_meta(mutation.update_table_meta_req.clone())?;
+        }
+        Ok(())
+    });
+    if res.is_err() && !temp_tables.is_empty() {
+        abort_mutations(ctx, &temp_tables).await;
+    }
+    txn_mgr.lock().clear();
+    res
+}
+
+/// Commit the buffered table metas of the tables kept by the meta service.
+async fn commit_mutations(
+    ctx: &Arc<QueryContext>,
+    mutated_tables: &[TxnTableMutation],
+) -> Result<()> {
+    let req = UpdateMultiTableMetaReq {
+        update_table_metas: mutated_tables
+            .iter()
+            .map(|mutation| mutation.update_table_meta_req.clone())
+            .collect(),
+    };
+    let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+    let mut retries = 0;
+    let res = loop {
+        // a conflict of snapshot is not retried
+        let req = match rebase_txn_req(&catalog, mutated_tables, req.clone()).await {
+            Ok(req) => req,
+            Err(e) => break Err(e),
+        };
+        match catalog.update_multi_table_meta(req).await {
+            // the table meta is changed by others since rebased
+            Err(e)
+                if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                    && retries < MAX_TXN_COMMIT_RETRIES =>
+            {
+                retries += 1;
+                continue;
+            }
+            res => break res,
+        }
+    };
+    match &res {
+        Ok(_) => {}
+        Err(e) => {
+            // only remove the files if we are SURE that nothing is committed
+            if FuseTable::no_side_effects_in_meta_store(e)
+                || e.code() == ErrorCode::DUPLICATED_UPSERT_FILES
+            {
+                abort_mutations(ctx, mutated_tables).await;
+            }
+        }
+    }
+    res.map(|_| ())
+}
+
+/// Rebase the buffered table metas onto the latest ones, conflicts are detected by snapshot.
+async fn rebase_txn_req(
+    catalog: &Arc<dyn Catalog>,
+    mutated_tables: &[TxnTableMutation],
+    mut req: UpdateMultiTableMetaReq,
+) -> Result<UpdateMultiTableMetaReq> {
+    for (mutation, req) in mutated_tables.iter().zip(req.update_table_metas.iter_mut()) {
+        let base = &mutation.base_table_info;
+        let (ident, latest) = catalog.get_table_meta_by_id(req.table_id).await?;
+        req.new_table_meta =
+            FuseTable::rebase_txn_table_meta(&base.desc, &base.meta, &latest, &req.new_table_meta)?;
+        req.seq = MatchSeq::Exact(ident.seq);
+    }
+    Ok(req)
+}
+
+/// Discard the buffered table metas of the explicit transaction, and remove the files
+/// written by it.
+#[async_backtrace::framed]
+pub async fn abort_txn(ctx: &Arc<QueryContext>) -> Result<()> {
+    let txn_mgr = ctx.txn_mgr();
+    let (txn_id, mutated_tables) = {
+        let txn_mgr = txn_mgr.lock();
+        if txn_mgr.state() == TxnState::AutoCommit {
+            return Ok(());
+        }
+        (txn_mgr.txn_id().to_string(), txn_mgr.mutated_tables())
+    };
+
+    info!("rollback transaction {}", txn_id);
+    abort_mutations(ctx, &mutated_tables).await;
+    txn_mgr.lock().clear();
+    Ok(())
+}
+
+/// Remove the files written by the unfinished explicit transaction of a dropped session.
+pub fn abort_txn_on_session_drop(txn_mgr: TxnManagerRef) {
+    let (txn_id, mutated_tables) = {
+        let mut txn_mgr = txn_mgr.lock();
+        let mutated_tables = txn_mgr.mutated_tables();
+        if txn_mgr.state() == TxnState::AutoCommit || mutated_tables.is_empty() {
+            return;
+        }
+        let txn_id = txn_mgr.txn_id().to_string();
+        txn_mgr.clear();
+        (txn_id, mutated_tables)
+    };
+
+    info!("rollback transaction {} of dropped session", txn_id);
+    GlobalIORuntime::instance().spawn(async move {
+        let res = async {
+            let session = SessionManager::instance()
+                .create_session(SessionType::Dummy)
+                .await?;
+            let ctx = session.create_query_context().await?;
+            abort_mutations(&ctx, &mutated_tables).await;
+            Ok::<_, ErrorCode>(())
+        }
+        .await;
+        if let Err(e) = res {
+            warn!(
+                "failed to rollback transaction {} of dropped session: {}",
+                txn_id, e
+            );
+        }
+    });
+}
+
+async fn abort_mutations(ctx: &Arc<QueryContext>, mutated_tables: &[TxnTableMutation]) {
+    for mutation in mutated_tables {
+        let base_table_info = &mutation.base_table_info;
+        let res = async {
+            let catalog = ctx.get_catalog(base_table_info.catalog()).await?;
+            let table = catalog.get_table_by_info(base_table_info)?;
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            fuse_table
+                .abort_txn_mutation(ctx.clone(), &mutation.uncommitted_snapshots)
+                .await
+        }
+        .await;
+
+        // the orphan files are left to VACUUM if the cleanup fails.
+        if let Err(e) = res {
+            warn!(
+                "failed to remove the uncommitted files of table {}: {}",
+                base_table_info.desc, e
+            );
+        }
+    }
+}
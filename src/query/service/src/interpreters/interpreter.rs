@@ -52,9 +52,13 @@ pub trait Interpreter: Sync + Send {
             log_query_finished(&ctx, Some(err.clone()));
             return Err(err);
         }
+        let txn_mutation_count = ctx.txn_mgr().lock().mutation_count();
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
+                ctx.txn_mgr()
+                    .lock()
+                    .set_fail_if_mutated_since(txn_mutation_count);
                 InterpreterMetrics::record_query_error(&ctx);
                 log_query_finished(&ctx, Some(build_error.clone()));
                 return Err(build_error);
@@ -75,7 +79,13 @@ pub trait Interpreter: Sync + Send {
 
             match may_error {
                 None => Ok(()),
-                Some(error) => Err(error.clone()),
+                Some(error) => {
+                    query_ctx
+                        .txn_mgr()
+                        .lock()
+                        .set_fail_if_mutated_since(txn_mutation_count);
+                    Err(error.clone())
+                }
             }
         });
 
@@ -21,6 +21,7 @@ use common_catalog::plan::Filters;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::Projection;
+use common_catalog::table::TableExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
@@ -122,9 +123,7 @@ impl Interpreter for DeleteInterpreter {
         let catalog_info = catalog.info();
 
         // refresh table.
-        let tbl = catalog
-            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
-            .await?;
+        let tbl = tbl.as_ref().refresh(self.ctx.as_ref()).await?;
 
         let selection = if !self.plan.subquery_desc.is_empty() {
             let support_row_id = tbl.support_row_id_column();
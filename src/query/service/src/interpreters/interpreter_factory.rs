@@ -15,6 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnState;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use log::error;
 
@@ -28,6 +31,10 @@ use super::interpreter_table_set_options::SetOptionsInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
 use crate::interpreters::access::Accessor;
+use crate::interpreters::common::AuditObject;
+use crate::interpreters::common::AuditTarget;
+use crate::interpreters::common::AuditedInterpreter;
+use crate::interpreters::common::InterpreterAuditLog;
 use crate::interpreters::interpreter_catalog_drop::DropCatalogInterpreter;
 use crate::interpreters::interpreter_copy::CopyInterpreter;
 use crate::interpreters::interpreter_file_format_create::CreateFileFormatInterpreter;
@@ -45,6 +52,7 @@ use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
 use crate::sessions::QueryContext;
+use crate::sql::plans::CopyPlan;
 use crate::sql::plans::Plan;
 
 /// InterpreterFactory is the entry of Interpreter.
@@ -56,12 +64,142 @@ impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         // Check the access permission.
+        let audit_target = AuditTarget::from_plan(plan);
         let access_checker = Accessor::create(ctx.clone());
-        access_checker.check(plan).await.map_err(|e| {
-            error!("Access.denied(v2): {:?}", e);
-            e
-        })?;
-        Self::get_inner(ctx, plan)
+        let checked = match access_checker.check(plan).await {
+            Ok(()) => Self::check_audit_log(plan, audit_target.as_ref()),
+            Err(e) => {
+                error!("Access.denied(v2): {:?}", e);
+                Err(e)
+            }
+        };
+        if let Err(e) = checked {
+            if let Some(target) = &audit_target {
+                InterpreterAuditLog::log(&ctx, target, "".to_string(), "".to_string(), Some(&e));
+            }
+            return Err(e);
+        }
+        Self::check_txn_state(&ctx, plan)?;
+        Self::check_txn_table(&ctx, plan).await?;
+        let interpreter = Self::get_inner(ctx.clone(), plan)?;
+
+        match audit_target {
+            Some(target) => Ok(Arc::new(AuditedInterpreter::create(
+                ctx,
+                interpreter,
+                target,
+            ))),
+            None => Ok(interpreter),
+        }
+    }
+
+    /// The audit log is append-only: only the server appends to it, statements can not change it.
+    fn check_audit_log(plan: &Plan, audit_target: Option<&AuditTarget>) -> Result<()> {
+        let mut databases = vec![];
+        if let Some((_, database, _)) = Self::dml_target(plan) {
+            databases.push(database);
+        }
+        if let Some(target) = audit_target {
+            for object in std::iter::once(&target.object).chain(target.new_object.as_ref()) {
+                match object {
+                    AuditObject::Database { database, .. }
+                    | AuditObject::Table { database, .. } => databases.push(database),
+                    _ => {}
+                }
+            }
+        }
+
+        match databases
+            .into_iter()
+            .find(|db| InterpreterAuditLog::is_audit_log_database(db))
+        {
+            Some(database) => Err(ErrorCode::PermissionDenied(format!(
+                "database {} of the audit log is append-only and can not be modified",
+                database
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// The catalog, database and table modified by a DML plan.
+    fn dml_target(plan: &Plan) -> Option<(&str, &String, &String)> {
+        let target = match plan {
+            Plan::Insert(p) => (p.catalog.as_str(), &p.database, &p.table),
+            Plan::Replace(p) => (p.catalog.as_str(), &p.database, &p.table),
+            Plan::Delete(p) => (p.catalog_name.as_str(), &p.database_name, &p.table_name),
+            Plan::Update(p) => (p.catalog.as_str(), &p.database, &p.table),
+            Plan::MergeInto(p) => (p.catalog.as_str(), &p.database, &p.table),
+            Plan::Copy(p) => match p.as_ref() {
+                CopyPlan::IntoTable(p) => (
+                    p.catalog_info.catalog_name(),
+                    &p.database_name,
+                    &p.table_name,
+                ),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(target)
+    }
+
+    /// Only queries and DML are allowed inside an explicit transaction, and only the end of the
+    /// transaction is allowed once one of its statements failed.
+    fn check_txn_state(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let txn_mgr = ctx.txn_mgr();
+        let state = txn_mgr.lock().state();
+        match state {
+            TxnState::AutoCommit => Ok(()),
+            TxnState::Active => match plan {
+                Plan::Query { .. }
+                | Plan::Explain { .. }
+                | Plan::ExplainAst { .. }
+                | Plan::ExplainSyntax { .. }
+                | Plan::ExplainAnalyze { .. }
+                | Plan::Insert(_)
+                | Plan::Replace(_)
+                | Plan::Delete(_)
+                | Plan::Update(_)
+                | Plan::MergeInto(_)
+                | Plan::Copy(_)
+                | Plan::SetVariable(_)
+                | Plan::UnSetVariable(_)
+                | Plan::UseDatabase(_)
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Abort => Ok(()),
+                _ => Err(ErrorCode::InvalidTransactionState(
+                    "only queries and DML statements are allowed in an explicit transaction",
+                )),
+            },
+            TxnState::Fail => match plan {
+                Plan::Commit | Plan::Abort => Ok(()),
+                _ => Err(ErrorCode::InvalidTransactionState(
+                    "current transaction is aborted, commands ignored until end of transaction block",
+                )),
+            },
+        }
+    }
+
+    /// The changes of an explicit transaction are buffered by the fuse engine, writing to
+    /// tables of other engines would be committed immediately.
+    async fn check_txn_table(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        if !ctx.txn_mgr().lock().is_active() {
+            return Ok(());
+        }
+        let (catalog, database, table) = match Self::dml_target(plan) {
+            Some(target) => target,
+            None => return Ok(()),
+        };
+        let table = ctx.get_table(catalog, database, table).await?;
+        if !table.engine().eq_ignore_ascii_case("FUSE") {
+            return Err(ErrorCode::InvalidTransactionState(format!(
+                "table {}.{} of engine {} can't be modified in an explicit transaction",
+                database,
+                table.name(),
+                table.engine()
+            )));
+        }
+        Ok(())
     }
 
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
@@ -222,6 +360,20 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(Arc::new(CreateMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *create_materialized_view.clone(),
+                )?))
+            }
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(Arc::new(RefreshMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *refresh_materialized_view.clone(),
+                )?))
+            }
+
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
                 ctx,
@@ -236,6 +388,16 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateVectorIndex(index) => Ok(Arc::new(
+                CreateVectorIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropVectorIndex(index) => Ok(Arc::new(DropVectorIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
+            Plan::RefreshVectorIndex(index) => Ok(Arc::new(
+                RefreshVectorIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -369,6 +531,11 @@ impl InterpreterFactory {
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
 
+            // transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
                 CreateShareEndpointInterpreter::try_create(ctx, *p.clone())?,
@@ -423,6 +590,15 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
 
+            Plan::CreateSequence(p) => Ok(Arc::new(CreateSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSequence(p) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
             )),
@@ -441,6 +617,17 @@ impl InterpreterFactory {
             Plan::ShowNetworkPolicies(_) => {
                 Ok(Arc::new(ShowNetworkPoliciesInterpreter::try_create(ctx)?))
             }
+
+            Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterTask(p) => Ok(Arc::new(AlterTaskInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropTask(p) => Ok(Arc::new(DropTaskInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::ExecuteTask(p) => Ok(Arc::new(ExecuteTaskInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
         }
     }
 }
@@ -0,0 +1,182 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Engine;
+use common_catalog::catalog::Catalog;
+use common_catalog::catalog::CatalogManager;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateMaterializedViewPlan;
+use common_sql::plans::CreateTablePlan;
+use common_sql::Binder;
+use common_sql::Metadata;
+use common_sql::NameResolutionContext;
+use itertools::Itertools;
+use parking_lot::RwLock;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_IDS;
+
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+const MAX_UPDATE_SOURCE_TABLE_RETRIES: u32 = 10;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+
+    /// Append the id of the materialized view to the option of source table,
+    /// the ids of the dropped materialized views are removed along the way.
+    #[async_backtrace::framed]
+    async fn add_to_source_table(&self, catalog: &Arc<dyn Catalog>, view_id: u64) -> Result<()> {
+        let mut retries = 0;
+        loop {
+            let (ident, meta) = catalog
+                .get_table_meta_by_id(self.plan.source_table_id)
+                .await?;
+            let mut view_ids = vec![];
+            for id in meta
+                .options
+                .get(OPT_KEY_MATERIALIZED_VIEW_IDS)
+                .map(|ids| ids.split(',').filter_map(|id| id.parse::<u64>().ok()))
+                .into_iter()
+                .flatten()
+            {
+                let dropped = match catalog.get_table_meta_by_id(id).await {
+                    Ok((_, view_meta)) => view_meta.drop_on.is_some(),
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => true,
+                    Err(e) => return Err(e),
+                };
+                if id != view_id && !dropped {
+                    view_ids.push(id);
+                }
+            }
+            view_ids.push(view_id);
+
+            let req = UpsertTableOptionReq {
+                table_id: self.plan.source_table_id,
+                seq: MatchSeq::Exact(ident.seq),
+                options: HashMap::from([(
+                    OPT_KEY_MATERIALIZED_VIEW_IDS.to_string(),
+                    Some(view_ids.iter().map(|id| id.to_string()).join(",")),
+                )]),
+            };
+            match catalog
+                .upsert_table_option(self.plan.tenant.as_str(), &self.plan.source_database, req)
+                .await
+            {
+                Err(e)
+                    if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                        && retries < MAX_UPDATE_SOURCE_TABLE_RETRIES =>
+                {
+                    retries += 1;
+                }
+                res => return res.map(|_| ()),
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        if self.plan.if_not_exists
+            && catalog
+                .exists_table(
+                    self.plan.tenant.as_str(),
+                    &self.plan.database,
+                    &self.plan.view_name,
+                )
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // 1. create the fuse table which stores the rows of materialized view.
+        let create_table_plan = CreateTablePlan {
+            if_not_exists: self.plan.if_not_exists,
+            temporary: false,
+            tenant: self.plan.tenant.clone(),
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.view_name.clone(),
+            schema: self.plan.schema.clone(),
+            engine: Engine::Fuse,
+            storage_params: None,
+            part_prefix: "".to_string(),
+            options: self.plan.options.clone(),
+            field_comments: vec![],
+            cluster_key: None,
+            auto_increment_sequences: vec![],
+            as_select: None,
+        };
+        CreateTableInterpreter::try_create(self.ctx.clone(), create_table_plan)?
+            .execute2()
+            .await?;
+
+        // 2. keep the id of materialized view on the source table, queries on the source table
+        // find it by the id. Only the materialized views in the same database are used.
+        if self.plan.database == self.plan.source_database {
+            let view = catalog
+                .get_table(
+                    self.plan.tenant.as_str(),
+                    &self.plan.database,
+                    &self.plan.view_name,
+                )
+                .await?;
+            self.add_to_source_table(&catalog, view.get_id()).await?;
+        }
+
+        // 3. fill the materialized view with the current rows of source table.
+        let settings = self.ctx.get_settings();
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata,
+        );
+        let refresh_plan = binder
+            .build_refresh_materialized_view_plan(
+                self.plan.catalog.clone(),
+                self.plan.database.clone(),
+                self.plan.view_name.clone(),
+            )
+            .await?;
+        RefreshMaterializedViewInterpreter::try_create(self.ctx.clone(), refresh_plan)?
+            .execute2()
+            .await
+    }
+}
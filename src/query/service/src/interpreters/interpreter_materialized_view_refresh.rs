@@ -0,0 +1,248 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::executor::DistributedInsertSelect;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::PhysicalPlanBuilder;
+use common_sql::executor::PhysicalPlanReplacer;
+use common_sql::executor::TableScan;
+use common_sql::plans::Plan;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use common_sql::Planner;
+use common_storages_fuse::pruning::create_segment_location_vector;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline_without_render_result_set;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+
+    /// Returns the data source of the scan on source table, which only reads the appended segments.
+    #[async_backtrace::framed]
+    async fn get_read_source(
+        &self,
+        query_plan: &PhysicalPlan,
+        source_table: &FuseTable,
+        segments: Vec<Location>,
+    ) -> Result<Option<DataSourcePlan>> {
+        let mut source = None;
+        let source_table_id = self.plan.source_table_id;
+        let mut collect_read_source = |plan: &PhysicalPlan| {
+            if let PhysicalPlan::TableScan(scan) = plan {
+                if let DataSourceInfo::TableSource(table_info) = &scan.source.source_info {
+                    if table_info.ident.table_id == source_table_id {
+                        source = Some(*scan.source.clone());
+                    }
+                }
+            }
+        };
+        PhysicalPlan::traverse(
+            query_plan,
+            &mut |_| true,
+            &mut collect_read_source,
+            &mut |_| {},
+        );
+
+        let Some(mut source) = source else {
+            return Ok(None);
+        };
+        let dal = self.ctx.get_data_operator()?.operator();
+        let (statistics, partitions) = source_table
+            .prune_snapshot_blocks(
+                self.ctx.clone(),
+                dal,
+                source.push_downs.clone(),
+                source_table.get_table_info().clone(),
+                create_segment_location_vector(segments, None),
+                0,
+            )
+            .await?;
+        source.parts = partitions;
+        source.statistics = statistics;
+        Ok(Some(source))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // 1. plan the query of materialized view to find out the source table.
+        // The source table must be read directly instead of other indexes or materialized views.
+        self.ctx.set_can_scan_from_agg_index(false);
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&self.plan.query).await?;
+        let source_table = match &plan {
+            Plan::Query { metadata, .. } => metadata
+                .read()
+                .tables()
+                .iter()
+                .find(|t| t.table().get_id() == self.plan.source_table_id)
+                .map(|t| t.table()),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            ErrorCode::UnknownTable(format!(
+                "The source table of materialized view `{}`.`{}` has been changed, please recreate it",
+                self.plan.database, self.plan.view_name
+            ))
+        })?;
+        let source_table = FuseTable::try_from_table(source_table.as_ref())?;
+
+        // 2. find out what has been changed since last refresh.
+        // An empty location means the source table had no snapshot at last refresh.
+        let snapshot_loc = source_table.snapshot_loc().await?.unwrap_or_default();
+        let appended_segments = match &self.plan.refreshed_snapshot {
+            Some(refreshed) if refreshed == &snapshot_loc => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Some(refreshed) if refreshed.is_empty() => source_table
+                .read_table_snapshot()
+                .await?
+                .map(|snapshot| snapshot.segments.clone()),
+            Some(refreshed) => source_table.appended_segments_since(refreshed).await?,
+            None => None,
+        };
+
+        // 3. build the query, only read the appended segments if it can be refreshed incrementally.
+        let incremental = appended_segments.is_some();
+        let plan = match &self.plan.merge_query {
+            Some(merge_query) if incremental => planner.plan_sql(merge_query).await?.0,
+            _ => plan,
+        };
+        let (mut query_plan, select_schema, select_column_bindings) = match plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => {
+                let mut builder = PhysicalPlanBuilder::new(metadata, self.ctx.clone(), false);
+                (
+                    builder.build(&s_expr, bind_context.column_set()).await?,
+                    bind_context.output_schema(),
+                    bind_context.columns.clone(),
+                )
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "Refresh materialized view encounter Non-Query Plan",
+                ));
+            }
+        };
+        if let Some(segments) = appended_segments {
+            // The scan may have been eliminated by optimizer, there is nothing to read then.
+            if let Some(source) = self
+                .get_read_source(&query_plan, source_table, segments)
+                .await?
+            {
+                let mut replace_read_source = ReplaceSourceTableScan {
+                    table_id: self.plan.source_table_id,
+                    source,
+                };
+                query_plan = replace_read_source.replace(&query_plan)?;
+            }
+        }
+
+        // 4. write the result into materialized view, and record the refreshed snapshot
+        // along with the new snapshot of materialized view.
+        let mut table_info = self.plan.table_info.clone();
+        table_info.meta.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT.to_string(),
+            snapshot_loc,
+        );
+        let view_table = FuseTable::do_create(table_info)?;
+        let prev_snapshot_id = view_table
+            .read_table_snapshot()
+            .await?
+            .map(|snapshot| snapshot.snapshot_id);
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let insert_plan =
+            PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
+                plan_id: query_plan.get_id(),
+                input: Box::new(query_plan),
+                catalog_info: catalog.info(),
+                table_info: view_table.get_table_info().clone(),
+                select_schema,
+                select_column_bindings,
+                insert_schema: Arc::new(view_table.schema().into()),
+                cast_needed: true,
+            }));
+        let mut build_res =
+            build_query_pipeline_without_render_result_set(&self.ctx, &insert_plan, false).await?;
+
+        // The rows of non-aggregating materialized view can be appended directly,
+        // otherwise the whole materialized view is overwritten by the new result.
+        let overwrite = !incremental || self.plan.merge_query.is_some();
+        view_table.commit_insertion(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            None,
+            overwrite,
+            prev_snapshot_id,
+        )?;
+
+        Ok(build_res)
+    }
+}
+
+/// Replaces the scan on the source table of materialized view, other table scans are kept.
+struct ReplaceSourceTableScan {
+    table_id: u64,
+    source: DataSourcePlan,
+}
+
+impl PhysicalPlanReplacer for ReplaceSourceTableScan {
+    fn replace_table_scan(&mut self, plan: &TableScan) -> Result<PhysicalPlan> {
+        let is_source_table = matches!(
+            &plan.source.source_info,
+            DataSourceInfo::TableSource(table_info) if table_info.ident.table_id == self.table_id
+        );
+        if !is_source_table {
+            return Ok(PhysicalPlan::TableScan(plan.clone()));
+        }
+        Ok(PhysicalPlan::TableScan(TableScan {
+            plan_id: plan.plan_id,
+            source: Box::new(self.source.clone()),
+            name_mapping: plan.name_mapping.clone(),
+            table_index: plan.table_index,
+            stat_info: plan.stat_info.clone(),
+            internal_column: plan.internal_column.clone(),
+        }))
+    }
+}
@@ -19,6 +19,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
+use common_expression::TableDataType;
 use common_meta_app::principal::StageInfo;
 use common_sql::executor::AsyncSourcerPlan;
 use common_sql::executor::CommitSink;
@@ -263,6 +264,18 @@ impl ReplaceInterpreter {
             Err(ErrorCode::BadArguments(
                 "at least one column must be specified in the replace into .. on [conflict] statement",
             ))
+        } else if let Some(field) = self
+            .plan
+            .on_conflict_fields
+            .iter()
+            .find(|f| f.data_type().remove_nullable() == TableDataType::Geometry)
+        {
+            // Geometry columns keep a bounding box instead of min/max statistics,
+            // which can not be used to locate conflicting rows.
+            Err(ErrorCode::BadArguments(format!(
+                "column {} of type GEOMETRY can not be used as a conflict key of replace into",
+                field.name()
+            )))
         } else {
             Ok(())
         }
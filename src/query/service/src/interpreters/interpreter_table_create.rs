@@ -16,6 +16,8 @@ use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_ast::ast::Engine;
+use common_catalog::temp_table::TempTblMgr;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -23,6 +25,7 @@ use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_expression::BLOCK_NAME_COL_NAME;
 use common_expression::ROW_ID_COL_NAME;
+use common_expression::SAMPLE_HASH_COL_NAME;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_expression::SNAPSHOT_NAME_COL_NAME;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
@@ -30,6 +33,8 @@ use common_license::license::Feature::ComputedColumn;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::Ownership;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
@@ -57,6 +62,9 @@ use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -65,6 +73,8 @@ use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
+use crate::sessions::session_temp_tbl_storage_prefix;
+use crate::sessions::QueryAffect;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::insert::Insert;
@@ -111,7 +121,8 @@ impl Interpreter for CreateTableInterpreter {
         let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
         let engine = self.plan.engine;
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
-        if quota.max_tables_per_database > 0 {
+        // temporary tables are not counted, they are not kept by the meta service
+        if quota.max_tables_per_database > 0 && !self.plan.temporary {
             // Note:
             // max_tables_per_database is a config quota. Default is 0.
             // If a database has lot of tables, list_tables will be slow.
@@ -157,14 +168,21 @@ impl CreateTableInterpreter {
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
 
-        // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        let reply = catalog.create_table(self.build_request(None)?).await?;
-        if !reply.new_table {
-            return Ok(PipelineBuildResult::create());
-        }
-        let table = catalog
-            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
-            .await?;
+        let table = if self.plan.temporary {
+            match self.create_temp_table()? {
+                Some(table_info) => catalog.get_table_by_info(&table_info)?,
+                None => return Ok(PipelineBuildResult::create()),
+            }
+        } else {
+            // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
+            let reply = catalog.create_table(self.build_request(None)?).await?;
+            if !reply.new_table {
+                return Ok(PipelineBuildResult::create());
+            }
+            catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?
+        };
 
         // If the table creation query contains column definitions, like 'CREATE TABLE t1(a int) AS SELECT * from t2',
         // we use the definitions to create the table schema. It may happen that the "AS SELECT" query's schema doesn't
@@ -192,6 +210,11 @@ impl CreateTableInterpreter {
 
     #[async_backtrace::framed]
     async fn create_table(&self) -> Result<PipelineBuildResult> {
+        if self.plan.temporary {
+            self.create_temp_table()?;
+            return Ok(PipelineBuildResult::create());
+        }
+
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
         let mut stat = None;
         if !GlobalConfig::instance().query.management_mode {
@@ -231,6 +254,54 @@ impl CreateTableInterpreter {
         Ok(PipelineBuildResult::create())
     }
 
+    /// Register the table in the session rather than the meta service.
+    ///
+    /// Returns None if the temporary table already exists and `IF NOT EXISTS` is specified.
+    fn create_temp_table(&self) -> Result<Option<TableInfo>> {
+        // the sequences live in the meta service, while the temporary table does not
+        if !self.plan.auto_increment_sequences.is_empty() {
+            return Err(ErrorCode::TableOptionInvalid(
+                "AUTOINCREMENT columns are not supported by temporary tables",
+            ));
+        }
+        let mut req = self.build_request(None)?;
+        if let Some(current_role) = self.ctx.get_current_role() {
+            req.table_meta.owner = Some(Ownership::new(current_role.name));
+        }
+
+        let table_id = TempTblMgr::next_table_id();
+        if self.plan.engine == Engine::Fuse {
+            // keep the data under the prefix of the session, so that it could be removed along with the session
+            let storage_prefix = format!(
+                "{}/{}",
+                session_temp_tbl_storage_prefix(&self.ctx.get_connection_id()),
+                table_id
+            );
+            req.table_meta
+                .options
+                .insert(OPT_KEY_STORAGE_PREFIX.to_string(), storage_prefix);
+        }
+
+        let table_info = TableInfo::new(
+            &self.plan.database,
+            &self.plan.table,
+            TableIdent::new(table_id, 0),
+            req.table_meta,
+        );
+        let created = self.ctx.temp_tbl_mgr().lock().create_table(
+            &self.plan.database,
+            self.plan.if_not_exists,
+            table_info.clone(),
+        )?;
+        // let the http handler keep the session for the following queries
+        self.ctx.set_affect(QueryAffect::Create {
+            kind: "TEMPORARY TABLE".to_string(),
+            name: self.plan.table.clone(),
+            success: created,
+        });
+        Ok(created.then_some(table_info))
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -294,6 +365,12 @@ impl CreateTableInterpreter {
                 table_name: self.plan.table.to_string(),
             },
             table_meta,
+            auto_increment_sequences: self
+                .plan
+                .auto_increment_sequences
+                .iter()
+                .map(|sequence| sequence.clone().into())
+                .collect(),
         };
 
         Ok(req)
@@ -352,6 +429,7 @@ impl CreateTableInterpreter {
                 table_name: self.plan.table.to_string(),
             },
             table_meta,
+            auto_increment_sequences: vec![],
         };
 
         Ok(req)
@@ -375,6 +453,10 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
 
     r.insert(OPT_KEY_ENGINE);
 
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT);
+
     r.insert("transient");
     r
 });
@@ -392,6 +474,7 @@ pub static INTERNAL_COLUMN_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(SNAPSHOT_NAME_COL_NAME);
     r.insert(SEGMENT_NAME_COL_NAME);
     r.insert(BLOCK_NAME_COL_NAME);
+    r.insert(SAMPLE_HASH_COL_NAME);
 
     r.insert(PREDICATE_COLUMN_NAME);
 
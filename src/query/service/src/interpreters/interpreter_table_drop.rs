@@ -14,10 +14,13 @@
 
 use std::sync::Arc;
 
+use common_catalog::temp_table::is_temp_table_id;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::DropTableByIdReq;
 use common_sql::plans::DropTablePlan;
+use common_storages_fuse::FuseTable;
+use common_storages_memory::MemoryTable;
 use common_storages_share::save_share_spec;
 use common_storages_view::view_table::VIEW_ENGINE;
 
@@ -67,6 +70,20 @@ impl Interpreter for DropTableInterpreter {
                     &self.plan.database, &self.plan.table, &self.plan.database, &self.plan.table
                 )));
             }
+            let table_id = tbl.get_table_info().ident.table_id;
+            if is_temp_table_id(table_id) {
+                // temporary tables only live in the session, there is nothing to undrop
+                self.ctx.temp_tbl_mgr().lock().drop_table(db_name, tbl_name);
+                match FuseTable::try_from_table(tbl.as_ref()) {
+                    Ok(fuse_table) => {
+                        let prefix = format!("{}/", fuse_table.meta_location_generator().prefix());
+                        fuse_table.get_operator().remove_all(&prefix).await?;
+                    }
+                    Err(_) => MemoryTable::release(table_id),
+                }
+                return Ok(PipelineBuildResult::create());
+            }
+
             let catalog = self.ctx.get_catalog(catalog_name).await?;
 
             let resp = catalog
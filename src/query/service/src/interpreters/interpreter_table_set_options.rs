@@ -22,6 +22,7 @@ use common_meta_types::MatchSeq;
 use common_sql::plans::SetOptionsPlan;
 use common_storages_fuse::TableContext;
 use log::error;
+use storages_common_table_meta::table::is_reserved_opt_key;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 
@@ -76,6 +77,12 @@ impl Interpreter for SetOptionsInterpreter {
         }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
+            if is_reserved_opt_key(&key) {
+                error!("{}", &error_str);
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "can't change {key} for alter table statement",
+                )));
+            }
             if !is_valid_create_opt(&key) {
                 error!("{}", &error_str);
                 return Err(ErrorCode::TableOptionInvalid(format!(
@@ -27,6 +27,7 @@ use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use storages_common_table_meta::table::is_internal_opt_key;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -89,6 +90,28 @@ impl Interpreter for ShowCreateTableInterpreter {
                 ));
             }
         }
+        if let Some(query) = table.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+            let view_create_sql = format!(
+                "CREATE MATERIALIZED VIEW `{}`.`{}` AS {}",
+                &self.plan.database, name, query
+            );
+            let block = DataBlock::new(
+                vec![
+                    BlockEntry::new(
+                        DataType::String,
+                        Value::Scalar(Scalar::String(name.as_bytes().to_vec())),
+                    ),
+                    BlockEntry::new(
+                        DataType::String,
+                        Value::Scalar(Scalar::String(view_create_sql.into_bytes())),
+                    ),
+                ],
+                1,
+            );
+            debug!("Show create materialized view executor result: {:?}", block);
+
+            return PipelineBuildResult::from_blocks(vec![block]);
+        }
         let schema = table.schema();
         let field_comments = table.field_comments();
         let n_fields = schema.fields().len();
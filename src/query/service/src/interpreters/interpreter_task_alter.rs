@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_ast::ast::AlterTaskAction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobState;
+use common_meta_app::background::UpdateBackgroundJobStatusReq;
+use common_sql::plans::AlterTaskPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::common::check_task_predecessors;
+use crate::interpreters::common::get_sql_task;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTaskPlan,
+}
+
+impl AlterTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTaskPlan) -> Result<Self> {
+        Ok(AlterTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTaskInterpreter {
+    fn name(&self) -> &str {
+        "AlterTaskInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_task_execute");
+
+        let plan = self.plan.clone();
+        let meta = UserApiProvider::instance().get_meta_store_client();
+        let name = BackgroundJobIdent {
+            tenant: plan.tenant,
+            name: plan.name,
+        };
+        let info = match get_sql_task(&meta, &name).await {
+            Ok(info) => info,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_BACKGROUND_JOB => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut status = info.job_status.unwrap_or_default();
+        match plan.action {
+            AlterTaskAction::Resume => {
+                // the predecessors may be recreated in another warehouse since the task is created.
+                if let Some(definition) = &info.sql_task {
+                    check_task_predecessors(&meta, &name, definition, true).await?;
+                }
+                // the schedule restarts from now on, the missed runs are skipped.
+                status.job_state = BackgroundJobState::RUNNING;
+                status.next_task_scheduled_time = info
+                    .job_params
+                    .and_then(|params| params.get_next_running_time(Utc::now()));
+            }
+            AlterTaskAction::Suspend => status.job_state = BackgroundJobState::SUSPENDED,
+        }
+        meta.update_background_job_status(UpdateBackgroundJobStatusReq {
+            job_name: name,
+            status,
+        })
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
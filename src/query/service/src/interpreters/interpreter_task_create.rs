@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::CreateBackgroundJobReq;
+use common_sql::plans::CreateTaskPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::common::check_task_predecessors;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTaskPlan,
+}
+
+impl CreateTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTaskPlan) -> Result<Self> {
+        Ok(CreateTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTaskInterpreter {
+    fn name(&self) -> &str {
+        "CreateTaskInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_task_execute");
+
+        let plan = self.plan.clone();
+        let meta = UserApiProvider::instance().get_meta_store_client();
+        let job_name = BackgroundJobIdent {
+            tenant: plan.tenant,
+            name: plan.name,
+        };
+        // the successors run on the node which runs the predecessor, so a task can not run
+        // after the tasks of another warehouse.
+        check_task_predecessors(&meta, &job_name, &plan.definition, false).await?;
+
+        let creator = self.ctx.get_current_user()?.identity();
+        let job_info =
+            BackgroundJobInfo::new_sql_task_job(plan.job_params, plan.definition, creator);
+        meta.create_background_job(CreateBackgroundJobReq {
+            if_not_exists: plan.if_not_exists,
+            job_name,
+            job_info,
+        })
+        .await
+        .map_err(ErrorCode::from)?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::DeleteBackgroundJobReq;
+use common_sql::plans::DropTaskPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::common::get_sql_task;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTaskPlan,
+}
+
+impl DropTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTaskPlan) -> Result<Self> {
+        Ok(DropTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTaskInterpreter {
+    fn name(&self) -> &str {
+        "DropTaskInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_task_execute");
+
+        let plan = self.plan.clone();
+        let meta = UserApiProvider::instance().get_meta_store_client();
+        let name = BackgroundJobIdent {
+            tenant: plan.tenant,
+            name: plan.name,
+        };
+        match get_sql_task(&meta, &name).await {
+            Ok(_) => {}
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_BACKGROUND_JOB => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        }
+        meta.drop_background_job(DeleteBackgroundJobReq { name })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
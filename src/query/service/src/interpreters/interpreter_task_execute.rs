@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::uuid::Uuid;
+use common_exception::Result;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::ManualTriggerParams;
+use common_sql::plans::ExecuteTaskPlan;
+use log::debug;
+
+use crate::interpreters::execute_sql_task;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ExecuteTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExecuteTaskPlan,
+}
+
+impl ExecuteTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExecuteTaskPlan) -> Result<Self> {
+        Ok(ExecuteTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExecuteTaskInterpreter {
+    fn name(&self) -> &str {
+        "ExecuteTaskInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "execute_task_execute");
+
+        let plan = self.plan.clone();
+        let trigger = ManualTriggerParams::new(
+            Uuid::new_v4().to_string(),
+            self.ctx.get_current_user()?.identity(),
+        );
+        // the task runs on the current node in the foreground, which must be in the warehouse
+        // of the task if it has one.
+        execute_sql_task(
+            BackgroundJobIdent {
+                tenant: plan.tenant,
+                name: plan.name,
+            },
+            Some(trigger),
+        )
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
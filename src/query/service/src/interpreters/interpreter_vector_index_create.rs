@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateVectorIndexPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::TableContext;
+use storages_common_index::SegmentVectorIndex;
+use storages_common_table_meta::table::get_vector_indexes;
+use storages_common_table_meta::table::set_vector_indexes;
+use storages_common_table_meta::table::VectorIndexMeta;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEXES;
+use uuid::Uuid;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CreateVectorIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateVectorIndexPlan,
+}
+
+impl CreateVectorIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateVectorIndexPlan) -> Result<Self> {
+        Ok(CreateVectorIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateVectorIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateVectorIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let tenant = self.ctx.get_tenant();
+        let table = catalog
+            .get_table(&tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        let table_info = table.get_table_info();
+
+        let mut indexes = get_vector_indexes(&table_info.meta.options)?;
+        if indexes
+            .iter()
+            .any(|index| index.name == self.plan.index_name)
+        {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::IndexAlreadyExists(format!(
+                    "Vector index '{}' already exists on table {}.{}",
+                    self.plan.index_name, self.plan.database, self.plan.table
+                )))
+            };
+        }
+
+        let index = VectorIndexMeta {
+            id: Uuid::new_v4().simple().to_string(),
+            name: self.plan.index_name.clone(),
+            column_id: self.plan.column_id,
+            index_type: self.plan.index_type,
+            distance: self.plan.distance,
+            options: self.plan.options.clone(),
+        };
+        SegmentVectorIndex::validate(&index)?;
+        indexes.push(index.clone());
+
+        let mut options = table_info.meta.options.clone();
+        set_vector_indexes(&mut options, &indexes)?;
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_VECTOR_INDEXES.to_string(),
+                options.get(OPT_KEY_VECTOR_INDEXES).cloned(),
+            )]),
+        };
+        catalog
+            .upsert_table_option(&tenant, &self.plan.database, req)
+            .await?;
+
+        // Build the index files of the existing segments, the new segments are
+        // indexed when they are written.
+        let table = table.refresh(self.ctx.as_ref()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        fuse_table
+            .do_refresh_vector_index(self.ctx.clone(), &index)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
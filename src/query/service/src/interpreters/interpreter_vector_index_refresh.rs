@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::RefreshVectorIndexPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::TableContext;
+use storages_common_table_meta::table::get_vector_indexes;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct RefreshVectorIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshVectorIndexPlan,
+}
+
+impl RefreshVectorIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshVectorIndexPlan) -> Result<Self> {
+        Ok(RefreshVectorIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshVectorIndexInterpreter {
+    fn name(&self) -> &str {
+        "RefreshVectorIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+
+        let indexes = get_vector_indexes(&table.get_table_info().meta.options)?;
+        let Some(index) = indexes
+            .iter()
+            .find(|index| index.name == self.plan.index_name)
+        else {
+            return Err(ErrorCode::UnknownIndex(format!(
+                "Unknown vector index '{}' on table {}.{}",
+                self.plan.index_name, self.plan.database, self.plan.table
+            )));
+        };
+
+        // Builds the index files of the segments written before the index was created,
+        // or by compactions and mutations.
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        fuse_table
+            .do_refresh_vector_index(self.ctx.clone(), index)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -98,6 +98,7 @@ impl Interpreter for AlterViewInterpreter {
                     options,
                     ..Default::default()
                 },
+                auto_increment_sequences: vec![],
             };
             catalog.create_table(plan).await?;
 
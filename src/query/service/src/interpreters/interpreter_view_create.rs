@@ -123,6 +123,7 @@ impl Interpreter for CreateViewInterpreter {
                 options,
                 ..Default::default()
             },
+            auto_increment_sequences: vec![],
         };
         catalog.create_table(plan).await?;
 
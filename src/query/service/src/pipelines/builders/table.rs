@@ -21,7 +21,9 @@ use common_expression::DataSchemaRef;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_pipeline_core::Pipeline;
 
+use crate::pipelines::processors::transforms::build_expression_transform;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformSequenceNextval;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::sessions::QueryContext;
 
@@ -38,6 +40,24 @@ pub fn build_fill_missing_columns_pipeline(
 
     // Fill missing default columns and resort the columns.
     if source_schema != default_schema {
+        // Allocate values for the sequences used by the default expressions.
+        let (_, sequences) = build_expression_transform(
+            source_schema.clone(),
+            default_schema.clone(),
+            table.clone(),
+            ctx.clone(),
+        )?;
+        if !sequences.is_empty() {
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                TransformSequenceNextval::try_create(
+                    ctx.clone(),
+                    transform_input_port,
+                    transform_output_port,
+                    sequences.clone(),
+                )
+            })?;
+        }
+
         pipeline.add_transform(|transform_input_port, transform_output_port| {
             TransformResortAddOn::try_create(
                 ctx.clone(),
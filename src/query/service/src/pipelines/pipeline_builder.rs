@@ -69,6 +69,7 @@ use common_pipeline_transforms::processors::transforms::create_dummy_item;
 use common_pipeline_transforms::processors::transforms::Transformer;
 use common_profile::SharedProcessorProfiles;
 use common_settings::Settings;
+use common_sql::evaluator::extract_nextval_calls;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::executor::AggregateExpand;
@@ -166,7 +167,9 @@ use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
 use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
+use crate::pipelines::processors::transforms::TransformSequenceNextval;
 use crate::pipelines::processors::transforms::TransformWindow;
+use crate::pipelines::processors::transforms::TransformWindowPartitionTopN;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::HashJoinState;
 use crate::pipelines::processors::SinkRuntimeFilterSource;
@@ -969,7 +972,7 @@ impl PipelineBuilder {
     }
 
     fn build_range_join(&mut self, range_join: &RangeJoin) -> Result<()> {
-        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join));
+        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join)?);
         self.expand_right_side_pipeline(range_join, state.clone())?;
         self.build_left_side(range_join, state)?;
         if self.enable_profiling {
@@ -1335,7 +1338,7 @@ impl PipelineBuilder {
         self.build_pipeline(&eval_scalar.input)?;
 
         let input_schema = eval_scalar.input.output_schema()?;
-        let exprs = eval_scalar
+        let mut exprs = eval_scalar
             .exprs
             .iter()
             .map(|(scalar, _)| scalar.as_expr(&BUILTIN_FUNCTIONS))
@@ -1345,13 +1348,39 @@ impl PipelineBuilder {
             return Ok(());
         }
 
+        let mut num_input_columns = input_schema.num_fields();
+        let mut projections = eval_scalar.projections.clone();
+
+        // The values of sequences are appended after the input columns,
+        // the evaluated columns are shifted by the number of sequences.
+        let sequences = extract_nextval_calls(&mut exprs, num_input_columns)?;
+        if !sequences.is_empty() {
+            self.main_pipeline.add_transform(|input, output| {
+                TransformSequenceNextval::try_create(
+                    self.ctx.clone(),
+                    input,
+                    output,
+                    sequences.clone(),
+                )
+            })?;
+            projections = projections
+                .into_iter()
+                .map(|index| {
+                    if index < num_input_columns {
+                        index
+                    } else {
+                        index + sequences.len()
+                    }
+                })
+                .collect();
+            num_input_columns += sequences.len();
+        }
+
         let op = BlockOperator::Map {
             exprs,
-            projections: Some(eval_scalar.projections.clone()),
+            projections: Some(projections),
         };
 
-        let num_input_columns = input_schema.num_fields();
-
         self.main_pipeline.add_transform(|input, output| {
             let transform = CompoundBlockOperator::new(
                 vec![op.clone()],
@@ -1809,7 +1838,31 @@ impl PipelineBuilder {
 
             sort_desc.extend(order_by.clone());
 
-            self.build_sort_pipeline(input_schema.clone(), sort_desc, window.plan_id, None, false)?;
+            // Drop the rows which can't be in the top n rows of their partitions before sorting.
+            // Without partitions, the top n rows can be taken by the sort directly.
+            let limit = match window.limit {
+                Some(limit) if !partition_by.is_empty() => {
+                    self.main_pipeline.add_transform(|input, output| {
+                        TransformWindowPartitionTopN::try_create(
+                            input,
+                            output,
+                            partition_by.clone(),
+                            sort_desc.clone(),
+                            limit,
+                        )
+                    })?;
+                    None
+                }
+                limit => limit,
+            };
+
+            self.build_sort_pipeline(
+                input_schema.clone(),
+                sort_desc,
+                window.plan_id,
+                limit,
+                false,
+            )?;
         }
         // `TransformWindow` is a pipeline breaker.
         self.main_pipeline.try_resize(1)?;
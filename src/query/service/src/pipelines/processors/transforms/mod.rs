@@ -29,6 +29,7 @@ mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sequence_nextval;
 mod window;
 
 pub use aggregator::build_partition_bucket;
@@ -74,11 +75,14 @@ pub use transform_materialized_cte::MaterializedCteSource;
 pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
 pub use transform_resort_addon::TransformResortAddOn;
+pub use transform_resort_addon_without_source_schema::build_expression_transform;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
+pub use transform_sequence_nextval::TransformSequenceNextval;
 pub use transform_sort_partial::TransformSortPartial;
 pub use window::FrameBound;
 pub use window::TransformWindow;
+pub use window::TransformWindowPartitionTopN;
 pub use window::WindowFunctionInfo;
@@ -0,0 +1,305 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::executor::RangeJoin;
+use common_sql::plans::JoinType;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::transforms::RangeJoinState;
+
+pub struct AsofJoinState {
+    pub(crate) left_keys: Vec<Expr>,
+    pub(crate) right_keys: Vec<Expr>,
+    pub(crate) join_type: JoinType,
+    // Data types of the right table, used to pad unmatched left rows with nulls
+    pub(crate) right_types: Vec<DataType>,
+    // All blocks of the right table are concatenated into one block
+    pub(crate) right_block: RwLock<Option<DataBlock>>,
+    // Evaluated equi-keys and match key (the last one) of the right table
+    pub(crate) right_key_columns: RwLock<Vec<Column>>,
+    // Row indexes of the right table sorted by (equi-keys, match key), rows with null keys are excluded
+    pub(crate) right_sorted_rows: RwLock<Vec<u32>>,
+}
+
+impl AsofJoinState {
+    pub fn create(range_join: &RangeJoin) -> Result<Self> {
+        let right_types = range_join
+            .right
+            .output_schema()?
+            .fields()
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect();
+        Ok(Self {
+            left_keys: range_join
+                .left_keys
+                .iter()
+                .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+                .collect(),
+            right_keys: range_join
+                .right_keys
+                .iter()
+                .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+                .collect(),
+            join_type: range_join.join_type.clone(),
+            right_types,
+            right_block: RwLock::new(None),
+            right_key_columns: RwLock::new(vec![]),
+            right_sorted_rows: RwLock::new(vec![]),
+        })
+    }
+}
+
+impl RangeJoinState {
+    // Sort the right table once, then every left block is a task which is merged with it.
+    pub(crate) fn asof_partition(&self) -> Result<()> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let left_table = self.left_table.read();
+        let right_table = self.right_table.read();
+
+        if !right_table.is_empty() {
+            let right_block = DataBlock::concat(&right_table)?;
+            let match_key = self.conditions[0].right_expr.as_expr(&BUILTIN_FUNCTIONS);
+            let key_columns = evaluate_keys(&right_block, &asof_join_state.right_keys, &match_key)?;
+            *asof_join_state.right_sorted_rows.write() = sort_rows(&key_columns);
+            *asof_join_state.right_key_columns.write() = key_columns;
+            *asof_join_state.right_block.write() = Some(right_block);
+        }
+
+        let mut tasks = self.tasks.write();
+        for left_idx in 0..left_table.len() {
+            tasks.push((left_idx, 0));
+        }
+        Ok(())
+    }
+
+    pub fn asof_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let (left_idx, _) = self.tasks.read()[task_id];
+        let left_table = self.left_table.read();
+        let left_block = &left_table[left_idx];
+
+        let match_key = self.conditions[0].left_expr.as_expr(&BUILTIN_FUNCTIONS);
+        let left_key_columns = evaluate_keys(left_block, &asof_join_state.left_keys, &match_key)?;
+        let left_sorted_rows = sort_rows(&left_key_columns);
+
+        let right_key_columns = asof_join_state.right_key_columns.read();
+        let right_sorted_rows = asof_join_state.right_sorted_rows.read();
+        let op = self.conditions[0].operator.as_str();
+        let keys_len = asof_join_state.left_keys.len();
+
+        // Both sides are sorted by (equi-keys, match key) in ascending order,
+        // so the position of the closest right row only moves forward.
+        let mut matched = Vec::with_capacity(left_sorted_rows.len());
+        let mut j = 0;
+        for &left_row in left_sorted_rows.iter() {
+            let compare = |right_row: u32| {
+                compare_rows(
+                    &left_key_columns[..keys_len],
+                    left_row,
+                    &right_key_columns[..keys_len],
+                    right_row,
+                )
+            };
+            let left_match_key =
+                unsafe { left_key_columns[keys_len].index_unchecked(left_row as usize) };
+            let satisfied = |right_row: u32| {
+                let right_match_key =
+                    unsafe { right_key_columns[keys_len].index_unchecked(right_row as usize) };
+                compare_scalar(&left_match_key, &right_match_key, op)
+            };
+            let right_row = match op {
+                // The closest one is the last right row satisfying the match condition
+                "gt" | "gte" => {
+                    while j < right_sorted_rows.len() {
+                        let right_row = right_sorted_rows[j];
+                        match compare(right_row) {
+                            Ordering::Greater => j += 1,
+                            Ordering::Equal if satisfied(right_row) => j += 1,
+                            _ => break,
+                        }
+                    }
+                    if j > 0 && compare(right_sorted_rows[j - 1]) == Ordering::Equal {
+                        Some(right_sorted_rows[j - 1])
+                    } else {
+                        None
+                    }
+                }
+                // The closest one is the first right row satisfying the match condition
+                _ => {
+                    while j < right_sorted_rows.len() {
+                        let right_row = right_sorted_rows[j];
+                        match compare(right_row) {
+                            Ordering::Greater => j += 1,
+                            Ordering::Equal if !satisfied(right_row) => j += 1,
+                            _ => break,
+                        }
+                    }
+                    if j < right_sorted_rows.len()
+                        && compare(right_sorted_rows[j]) == Ordering::Equal
+                    {
+                        Some(right_sorted_rows[j])
+                    } else {
+                        None
+                    }
+                }
+            };
+            matched.push((left_row, right_row));
+        }
+
+        let right_block = asof_join_state.right_block.read();
+        let result_block = match asof_join_state.join_type {
+            JoinType::LeftAsof => {
+                // Rows with null keys never match, but they are still kept by left asof join
+                let mut left_indices = Vec::with_capacity(left_block.num_rows());
+                let mut right_indices = Vec::with_capacity(left_block.num_rows());
+                let mut validity = MutableBitmap::with_capacity(left_block.num_rows());
+                let mut is_matched = vec![false; left_block.num_rows()];
+                for (left_row, right_row) in matched.iter() {
+                    if let Some(right_row) = right_row {
+                        left_indices.push(*left_row);
+                        right_indices.push(*right_row);
+                        validity.push(true);
+                        is_matched[*left_row as usize] = true;
+                    }
+                }
+                for (left_row, is_matched) in is_matched.iter().enumerate() {
+                    if !is_matched {
+                        left_indices.push(left_row as u32);
+                        right_indices.push(0);
+                        validity.push(false);
+                    }
+                }
+                let mut result_block = left_block.take(&left_indices, &mut None)?;
+                match right_block.as_ref() {
+                    Some(right_block) if !right_block.is_empty() => {
+                        let right_result_block = right_block.take(&right_indices, &mut None)?;
+                        let validity: Bitmap = validity.into();
+                        for entry in right_result_block.columns() {
+                            let column = entry
+                                .value
+                                .convert_to_full_column(&entry.data_type, left_indices.len())
+                                .wrap_nullable(Some(validity.clone()));
+                            result_block.add_column(BlockEntry::new(
+                                entry.data_type.wrap_nullable(),
+                                Value::Column(column),
+                            ));
+                        }
+                    }
+                    _ => {
+                        for data_type in asof_join_state.right_types.iter() {
+                            result_block.add_column(BlockEntry::new(
+                                data_type.wrap_nullable(),
+                                Value::Scalar(Scalar::Null),
+                            ));
+                        }
+                    }
+                }
+                result_block
+            }
+            _ => {
+                let (left_indices, right_indices): (Vec<u32>, Vec<u32>) = matched
+                    .iter()
+                    .filter_map(|(left_row, right_row)| right_row.map(|r| (*left_row, r)))
+                    .unzip();
+                if left_indices.is_empty() {
+                    return Ok(vec![]);
+                }
+                let mut result_block = left_block.take(&left_indices, &mut None)?;
+                let right_result_block = right_block
+                    .as_ref()
+                    .unwrap()
+                    .take(&right_indices, &mut None)?;
+                for entry in right_result_block.columns() {
+                    result_block.add_column(entry.clone());
+                }
+                result_block
+            }
+        };
+        Ok(vec![result_block])
+    }
+}
+
+// Evaluate equi-keys and the match key, the match key is the last column.
+fn evaluate_keys(block: &DataBlock, keys: &[Expr], match_key: &Expr) -> Result<Vec<Column>> {
+    let func_ctx = FunctionContext::default();
+    let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+    keys.iter()
+        .chain(std::iter::once(match_key))
+        .map(|expr| {
+            Ok(evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), block.num_rows()))
+        })
+        .collect()
+}
+
+// Sort row indexes by all key columns, rows containing null keys can't match and are skipped.
+fn sort_rows(key_columns: &[Column]) -> Vec<u32> {
+    let num_rows = key_columns.first().map(|column| column.len()).unwrap_or(0);
+    let mut rows = (0..num_rows as u32)
+        .filter(|row| {
+            key_columns.iter().all(|column| {
+                !matches!(
+                    unsafe { column.index_unchecked(*row as usize) },
+                    ScalarRef::Null
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    rows.sort_by(|a, b| compare_rows(key_columns, *a, key_columns, *b));
+    rows
+}
+
+fn compare_rows(left: &[Column], left_row: u32, right: &[Column], right_row: u32) -> Ordering {
+    for (left_column, right_column) in left.iter().zip(right.iter()) {
+        let (left_scalar, right_scalar) = unsafe {
+            (
+                left_column.index_unchecked(left_row as usize),
+                right_column.index_unchecked(right_row as usize),
+            )
+        };
+        match left_scalar.cmp(&right_scalar) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+    Ordering::Equal
+}
+
+fn compare_scalar(left: &ScalarRef, right: &ScalarRef, op: &str) -> bool {
+    match op {
+        "gte" => left.cmp(right) != Ordering::Less,
+        "gt" => left.cmp(right) == Ordering::Greater,
+        "lte" => left.cmp(right) != Ordering::Greater,
+        "lt" => left.cmp(right) == Ordering::Less,
+        _ => unreachable!(),
+    }
+}
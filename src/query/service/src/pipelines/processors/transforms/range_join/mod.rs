@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod asof_join_state;
 mod ie_join_state;
 mod ie_join_util;
 mod merge_join_state;
@@ -67,6 +67,8 @@ impl Processor for TransformRangeJoinLeft {
     fn name(&self) -> String {
         if self.state.ie_join_state.is_some() {
             "TransformIEJoinLeft".to_string()
+        } else if self.state.asof_join_state.is_some() {
+            "TransformAsofJoinLeft".to_string()
         } else {
             "TransformMergeJoinLeft".to_string()
         }
@@ -134,9 +136,10 @@ impl Processor for TransformRangeJoinLeft {
             RangeJoinStep::Execute => {
                 let task_id = self.state.task_id();
                 if let Some(task_id) = task_id {
-                    let res = match self.state.ie_join_state {
-                        Some(ref _ie_join_state) => self.state.ie_join(task_id)?,
-                        None => self.state.merge_join(task_id)?,
+                    let res = match (&self.state.ie_join_state, &self.state.asof_join_state) {
+                        (Some(_), _) => self.state.ie_join(task_id)?,
+                        (_, Some(_)) => self.state.asof_join(task_id)?,
+                        _ => self.state.merge_join(task_id)?,
                     };
                     for block in res {
                         if !block.is_empty() {
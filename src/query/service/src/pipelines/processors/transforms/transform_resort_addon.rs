@@ -44,14 +44,16 @@ where Self: Transform
         output_schema: DataSchemaRef,
         table: Arc<dyn Table>,
     ) -> Result<ProcessorPtr> {
-        let expression_transform =
+        // The values of the sequences are appended after the input columns by
+        // `TransformSequenceNextval` ahead of this transform.
+        let (expression_transform, sequences) =
             build_expression_transform(input_schema.clone(), output_schema, table, ctx)?;
         Ok(ProcessorPtr::create(Transformer::create(
             input,
             output,
             Self {
                 expression_transform,
-                input_len: input_schema.num_fields(),
+                input_len: input_schema.num_fields() + sequences.len(),
             },
         )))
     }
@@ -21,16 +21,19 @@ use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::Expr;
 use common_expression::Scalar;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+use common_sql::evaluator::extract_nextval_calls;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::parse_exprs;
 use common_storages_factory::Table;
 
+use super::transform_sequence_nextval::append_sequence_values;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::ProcessorPtr;
 use crate::pipelines::processors::transforms::transform::Transform;
-use crate::pipelines::processors::transforms::transform::Transformer;
 use crate::sessions::QueryContext;
 
 pub struct TransformResortAddOnWithoutSourceSchema {
@@ -39,12 +42,16 @@ pub struct TransformResortAddOnWithoutSourceSchema {
     table: Arc<dyn Table>,
 }
 
+/// Build the transform to fill the missing columns with default values.
+///
+/// The `nextval` calls in the default expressions refer to extra columns after the input
+/// columns, the sequences of them are returned in order.
 pub fn build_expression_transform(
     input_schema: DataSchemaRef,
     output_schema: DataSchemaRef,
     table: Arc<dyn Table>,
     ctx: Arc<QueryContext>,
-) -> Result<CompoundBlockOperator> {
+) -> Result<(CompoundBlockOperator, Vec<String>)> {
     let mut exprs = Vec::with_capacity(output_schema.fields().len());
     for f in output_schema.fields().iter() {
         let expr = if !input_schema.has_field(f.name()) {
@@ -81,19 +88,22 @@ pub fn build_expression_transform(
         exprs.push(expr);
     }
 
+    let sequences = extract_nextval_calls(&mut exprs, input_schema.num_fields())?;
+
     let func_ctx = ctx.get_function_context()?;
-    Ok(CompoundBlockOperator {
-        ctx: func_ctx,
-        operators: vec![BlockOperator::Map {
-            exprs,
-            projections: None,
-        }],
-    })
+    Ok((
+        CompoundBlockOperator {
+            ctx: func_ctx,
+            operators: vec![BlockOperator::Map {
+                exprs,
+                projections: None,
+            }],
+        },
+        sequences,
+    ))
 }
 
-impl TransformResortAddOnWithoutSourceSchema
-where Self: Transform
-{
+impl TransformResortAddOnWithoutSourceSchema {
     pub fn try_create(
         ctx: Arc<QueryContext>,
         input: Arc<InputPort>,
@@ -101,7 +111,7 @@ where Self: Transform
         output_schema: DataSchemaRef,
         table: Arc<dyn Table>,
     ) -> Result<ProcessorPtr> {
-        Ok(ProcessorPtr::create(Transformer::create(
+        Ok(ProcessorPtr::create(AsyncTransformer::create(
             input,
             output,
             Self {
@@ -113,20 +123,23 @@ where Self: Transform
     }
 }
 
-impl Transform for TransformResortAddOnWithoutSourceSchema {
+#[async_trait::async_trait]
+impl AsyncTransform for TransformResortAddOnWithoutSourceSchema {
     const NAME: &'static str = "AddOnWithoutSourceSchemaTransform";
 
-    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+    async fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
         let input_schema =
             DataSchemaRef::downcast_from(block.clone().get_owned_meta().unwrap()).unwrap();
-        block = build_expression_transform(
+        let (mut expression_transform, sequences) = build_expression_transform(
             input_schema.clone(),
             self.output_schema.clone(),
             self.table.clone(),
             self.ctx.clone(),
-        )?
-        .transform(block)?;
-        let columns = block.columns()[input_schema.num_fields()..].to_owned();
+        )?;
+        block = append_sequence_values(self.ctx.clone(), block, &sequences).await?;
+        block = expression_transform.transform(block)?;
+        let input_len = input_schema.num_fields() + sequences.len();
+        let columns = block.columns()[input_len..].to_owned();
         Ok(DataBlock::new(columns, block.num_rows()))
     }
 }
@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Value;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+use common_sql::evaluator::get_sequence_next_values;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::sessions::QueryContext;
+
+/// Append one column of values for each sequence to the input block,
+/// the values are allocated from the meta service per block.
+pub struct TransformSequenceNextval {
+    ctx: Arc<QueryContext>,
+    sequences: Vec<String>,
+}
+
+impl TransformSequenceNextval {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        sequences: Vec<String>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncTransformer::create(
+            input,
+            output,
+            Self { ctx, sequences },
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformSequenceNextval {
+    const NAME: &'static str = "SequenceNextvalTransform";
+
+    async fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        append_sequence_values(self.ctx.clone(), block, &self.sequences).await
+    }
+}
+
+#[async_backtrace::framed]
+pub async fn append_sequence_values(
+    ctx: Arc<QueryContext>,
+    mut block: DataBlock,
+    sequences: &[String],
+) -> Result<DataBlock> {
+    let num_rows = block.num_rows() as u64;
+    for sequence in sequences {
+        let values = if num_rows == 0 {
+            vec![]
+        } else {
+            let reply = get_sequence_next_values(ctx.clone(), sequence, num_rows).await?;
+            (0..num_rows)
+                .map(|i| reply.start + i * reply.step)
+                .collect::<Vec<_>>()
+        };
+        block.add_column(BlockEntry::new(
+            DataType::Number(NumberDataType::UInt64),
+            Value::Column(UInt64Type::from_data(values)),
+        ));
+    }
+    Ok(block)
+}
@@ -137,6 +137,11 @@ pub struct TransformWindow<T: Number> {
 
     // If `is_empty_frame`, the window function result of non-NULL rows will be NULL.
     is_empty_frame: bool,
+
+    // Used for lag, lead and nth_value with IGNORE NULLS.
+    // The non-null rows found in the frame (at most `n` rows), and the next row to scan.
+    non_null_rows: VecDeque<RowPtr>,
+    non_null_scanned: RowPtr,
 }
 
 impl<T: Number> TransformWindow<T> {
@@ -495,6 +500,20 @@ impl<T: Number> TransformWindow<T> {
 
     #[inline]
     fn merge_result_of_current_row(&mut self) -> Result<()> {
+        let ignore_null = match &self.func {
+            WindowFunctionImpl::LagLead(ll) if ll.ignore_null && ll.offset > 0 => {
+                Some((ll.arg, ll.offset, ll.is_lag))
+            }
+            // last_value counts the first non-null row backward from the frame end.
+            WindowFunctionImpl::NthValue(func) if func.ignore_null => match func.n {
+                Some(n) => Some((func.arg, n, false)),
+                None => Some((func.arg, 1, true)),
+            },
+            _ => None,
+        };
+        let non_null_row = ignore_null
+            .and_then(|(arg, n, backward)| self.nth_non_null_row_in_frame(arg, n, backward));
+
         match &self.func {
             WindowFunctionImpl::Aggregate(agg) => {
                 let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
@@ -528,7 +547,18 @@ impl<T: Number> TransformWindow<T> {
                 builder.push(ScalarRef::Number(NumberScalar::Float64(percent.into())));
             }
             WindowFunctionImpl::LagLead(ll) => {
-                let value = if self.frame_start == self.frame_end {
+                let row = if ll.ignore_null && ll.offset > 0 {
+                    non_null_row
+                } else if self.frame_start == self.frame_end {
+                    None
+                } else {
+                    Some(self.frame_start)
+                };
+                let value = if let Some(row) = row {
+                    let block = &self.blocks.get(row.block - self.first_block).unwrap().block;
+                    let value = &block.get_by_offset(ll.arg).value;
+                    value.index(row.row).unwrap().to_owned()
+                } else {
                     let default_value = match ll.default.clone() {
                         LagLeadDefault::Null => Scalar::Null,
                         LagLeadDefault::Index(col) => {
@@ -539,14 +569,6 @@ impl<T: Number> TransformWindow<T> {
                         }
                     };
                     default_value
-                } else {
-                    let block = &self
-                        .blocks
-                        .get(self.frame_start.block - self.first_block)
-                        .unwrap()
-                        .block;
-                    let value = &block.get_by_offset(ll.arg).value;
-                    value.index(self.frame_start.row).unwrap().to_owned()
                 };
 
                 let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
@@ -555,6 +577,14 @@ impl<T: Number> TransformWindow<T> {
             WindowFunctionImpl::NthValue(func) => {
                 let value = if self.frame_start == self.frame_end {
                     Scalar::Null
+                } else if func.ignore_null {
+                    match non_null_row {
+                        Some(cur) => {
+                            let value = &self.block_at(&cur).get_by_offset(func.arg).value;
+                            value.index(cur.row).unwrap().to_owned()
+                        }
+                        None => Scalar::Null,
+                    }
                 } else if let Some(mut n) = func.n {
                     let mut cur = self.frame_start;
                     // n is counting from 1
@@ -621,6 +651,47 @@ impl<T: Number> TransformWindow<T> {
         Ok(())
     }
 
+    /// Find the `n`-th (counting from 1) row whose `arg` column is not null in the current frame,
+    /// counting from the frame start, or from the frame end if `backward` is true.
+    ///
+    /// The frame start and end only move forward within a partition, so the non-null rows are
+    /// tracked incrementally as the frame slides instead of scanning the frame for every row.
+    fn nth_non_null_row_in_frame(&mut self, arg: usize, n: u64, backward: bool) -> Option<RowPtr> {
+        debug_assert!(n > 0);
+        let n = n as usize;
+        while self
+            .non_null_rows
+            .front()
+            .map_or(false, |row| *row < self.frame_start)
+        {
+            self.non_null_rows.pop_front();
+        }
+        if self.non_null_scanned < self.frame_start {
+            self.non_null_scanned = self.frame_start;
+        }
+
+        // Backward needs the last `n` non-null rows of the frame, forward needs the first ones.
+        while self.non_null_scanned < self.frame_end && (backward || self.non_null_rows.len() < n) {
+            let row = self.non_null_scanned;
+            let value = &self.block_at(&row).get_by_offset(arg).value;
+            if !value.index(row.row).unwrap().is_null() {
+                self.non_null_rows.push_back(row);
+                if self.non_null_rows.len() > n {
+                    self.non_null_rows.pop_front();
+                }
+            }
+            self.non_null_scanned = self.advance_row(row);
+        }
+
+        if self.non_null_rows.len() < n {
+            None
+        } else if backward {
+            self.non_null_rows.front().cloned()
+        } else {
+            self.non_null_rows.get(n - 1).cloned()
+        }
+    }
+
     #[inline]
     fn if_need_check_null_frame(&self) -> bool {
         self.frame_unit.is_range() && self.order_by.len() == 1 && self.order_by[0].is_nullable
@@ -696,6 +767,8 @@ impl TransformWindow<u64> {
             current_rank: 1,
             current_rank_count: 1,
             current_dense_rank: 1,
+            non_null_rows: VecDeque::new(),
+            non_null_scanned: RowPtr::default(),
             input_is_finished: false,
             is_empty_frame,
         })
@@ -766,6 +839,8 @@ where T: Number + ResultTypeOfUnary
             current_rank: 1,
             current_rank_count: 1,
             current_dense_rank: 1,
+            non_null_rows: VecDeque::new(),
+            non_null_scanned: RowPtr::default(),
             input_is_finished: false,
             is_empty_frame,
         })
@@ -1047,6 +1122,10 @@ where T: Number + ResultTypeOfUnary
                 self.current_rank = 1;
                 self.current_rank_count = 1;
                 self.current_dense_rank = 1;
+
+                // reset lag, lead and nth_value with IGNORE NULLS
+                self.non_null_rows.clear();
+                self.non_null_scanned = self.partition_start;
             }
         }
 
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::SortColumnDescription;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::transform::Transform;
+use crate::pipelines::processors::transforms::transform::Transformer;
+
+/// Keep the first `limit` rows of each partition in every block.
+///
+/// A row in the top `limit` rows of its partition must also be in the top `limit` rows
+/// of the same partition within its block, so the rows dropped here can never reach the
+/// result of the window. This reduces the rows to be sorted by the window.
+pub struct TransformWindowPartitionTopN {
+    partition_by: Vec<usize>,
+    // Sort by partition columns first, then by the order by columns of the window.
+    sort_desc: Vec<SortColumnDescription>,
+    limit: usize,
+}
+
+impl TransformWindowPartitionTopN {
+    pub fn try_create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        partition_by: Vec<usize>,
+        sort_desc: Vec<SortColumnDescription>,
+        limit: usize,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Transformer::create(
+            input_port,
+            output_port,
+            Self {
+                partition_by,
+                sort_desc,
+                limit,
+            },
+        )))
+    }
+}
+
+impl Transform for TransformWindowPartitionTopN {
+    const NAME: &'static str = "WindowPartitionTopNTransform";
+
+    fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        if num_rows <= self.limit {
+            return Ok(data_block);
+        }
+
+        let data_block = DataBlock::sort(&data_block, &self.sort_desc, None)?;
+        let partition_columns = self
+            .partition_by
+            .iter()
+            .map(|offset| {
+                let entry = data_block.get_by_offset(*offset);
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect::<Vec<_>>();
+
+        let mut indices = Vec::with_capacity(num_rows);
+        let mut rows_in_partition = 0;
+        for row in 0..num_rows {
+            let is_new_partition = row == 0
+                || partition_columns.iter().any(|column| unsafe {
+                    column.index_unchecked(row) != column.index_unchecked(row - 1)
+                });
+            if is_new_partition {
+                rows_in_partition = 0;
+            }
+            if rows_in_partition < self.limit {
+                indices.push(row as u32);
+            }
+            rows_in_partition += 1;
+        }
+
+        if indices.len() == num_rows {
+            return Ok(data_block);
+        }
+        data_block.take(&indices, &mut None)
+    }
+}
@@ -98,6 +98,9 @@ pub struct WindowFuncLagLeadImpl {
     pub arg: usize,
     pub default: LagLeadDefault,
     pub return_type: DataType,
+    pub is_lag: bool,
+    pub offset: u64,
+    pub ignore_null: bool,
 }
 
 #[derive(Clone)]
@@ -105,6 +108,7 @@ pub struct WindowFuncNthValueImpl {
     pub n: Option<u64>,
     pub arg: usize,
     pub return_type: DataType,
+    pub ignore_null: bool,
 }
 
 #[derive(Clone)]
@@ -214,6 +218,9 @@ impl WindowFunctionInfo {
                     arg: new_arg,
                     default: new_default,
                     return_type: ll.return_type.clone(),
+                    is_lag: ll.is_lag,
+                    offset: ll.offset,
+                    ignore_null: ll.ignore_null,
                 })
             }
             WindowFunction::NthValue(func) => {
@@ -222,6 +229,7 @@ impl WindowFunctionInfo {
                     n: func.n,
                     arg: new_arg,
                     return_type: func.return_type.clone(),
+                    ignore_null: func.ignore_null,
                 })
             }
             WindowFunction::Ntile(func) => Self::Ntile(WindowFuncNtileImpl {
@@ -36,6 +36,7 @@ use crate::sql::executor::ExchangeSource;
 use crate::sql::executor::HashJoin;
 use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalPlanReplacer;
+use crate::sql::executor::RangeJoin;
 use crate::sql::executor::TableScan;
 
 /// Visitor to split a `PhysicalPlan` into fragments.
@@ -229,6 +230,31 @@ impl PhysicalPlanReplacer for Fragmenter {
         }))
     }
 
+    fn replace_range_join(&mut self, plan: &RangeJoin) -> Result<PhysicalPlan> {
+        let mut fragments = vec![];
+        let left_input = self.replace(plan.left.as_ref())?;
+
+        // Consume current fragments to prevent them being consumed by `right_input`.
+        fragments.append(&mut self.fragments);
+        let right_input = self.replace(plan.right.as_ref())?;
+
+        fragments.append(&mut self.fragments);
+        self.fragments = fragments;
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: plan.plan_id,
+            left: Box::new(left_input),
+            right: Box::new(right_input),
+            conditions: plan.conditions.clone(),
+            other_conditions: plan.other_conditions.clone(),
+            left_keys: plan.left_keys.clone(),
+            right_keys: plan.right_keys.clone(),
+            join_type: plan.join_type.clone(),
+            range_join_type: plan.range_join_type.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_exchange(&mut self, plan: &Exchange) -> Result<PhysicalPlan> {
         // Recursively rewrite input
         let input = self.replace(plan.input.as_ref())?;
@@ -48,6 +48,7 @@ use arrow_flight::sql::CommandStatementQuery;
 use arrow_flight::sql::CommandStatementSubstraitPlan;
 use arrow_flight::sql::CommandStatementUpdate;
 use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::sql::EndTransaction;
 use arrow_flight::sql::ProstMessageExt;
 use arrow_flight::sql::SqlInfo;
 use arrow_flight::sql::TicketStatementQuery;
@@ -65,6 +66,7 @@ use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
 use common_base::base::uuid::Uuid;
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::DataSchema;
 use futures::Stream;
@@ -78,6 +80,9 @@ use tonic::Status;
 use tonic::Streaming;
 
 use super::status;
+use crate::interpreters::abort_txn;
+use crate::interpreters::begin_txn;
+use crate::interpreters::commit_txn;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 
 fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, Status> {
@@ -138,11 +143,16 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
         Status,
     > {
-        let (user, password) = FlightSqlServiceImpl::get_user_password(request.metadata())
-            .map_err(Status::invalid_argument)?;
         let client_ip = request.remote_addr().map(|a| a.ip().to_string());
-        let session =
-            FlightSqlServiceImpl::auth_user_password(user, password, client_ip.as_deref()).await?;
+        let session = match FlightSqlServiceImpl::get_bearer_token(request.metadata()) {
+            Some(token) => FlightSqlServiceImpl::auth_jwt(token, client_ip).await?,
+            None => {
+                let (user, password) = FlightSqlServiceImpl::get_user_password(request.metadata())
+                    .map_err(Status::invalid_argument)?;
+                FlightSqlServiceImpl::auth_user_password(user, password, client_ip.as_deref())
+                    .await?
+            }
+        };
         let token = Uuid::new_v4().to_string();
         let result = HandshakeResponse {
             protocol_version: 0,
@@ -678,20 +688,58 @@ impl FlightSqlService for FlightSqlServiceImpl {
         unimplemented!()
     }
 
+    #[async_backtrace::framed]
     async fn do_action_begin_transaction(
         &self,
         _query: ActionBeginTransactionRequest,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> std::result::Result<ActionBeginTransactionResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        begin_txn(&context, Uuid::new_v4().simple().to_string());
+        let txn_id = context.txn_mgr().lock().txn_id().to_string();
+        info!("do_action_begin_transaction with transaction_id={txn_id}");
+        Ok(ActionBeginTransactionResult {
+            transaction_id: txn_id.into_bytes().into(),
+        })
     }
 
+    #[async_backtrace::framed]
     async fn do_action_end_transaction(
         &self,
-        _query: ActionEndTransactionRequest,
-        _request: Request<Action>,
+        query: ActionEndTransactionRequest,
+        request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        let txn_id = std::str::from_utf8(&query.transaction_id)
+            .map_err(|e| status!("Error decoding transaction_id", e))?;
+        if context.txn_mgr().lock().txn_id() != txn_id {
+            return Err(Status::invalid_argument(format!(
+                "unknown transaction_id {txn_id:?}"
+            )));
+        }
+        info!(
+            "do_action_end_transaction with transaction_id={txn_id}, action={:?}",
+            query.action()
+        );
+        match query.action() {
+            EndTransaction::Commit => commit_txn(&context)
+                .await
+                .map_err(|e| status!("Could not commit transaction", e)),
+            EndTransaction::Rollback => abort_txn(&context)
+                .await
+                .map_err(|e| status!("Could not rollback transaction", e)),
+            EndTransaction::Unspecified => Err(Status::invalid_argument(
+                "end transaction action is unspecified",
+            )),
+        }
     }
 
     async fn do_action_begin_savepoint(
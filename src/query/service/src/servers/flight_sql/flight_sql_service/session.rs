@@ -24,6 +24,8 @@ use tonic::Request;
 use tonic::Status;
 
 use super::status;
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
@@ -61,6 +63,13 @@ impl FlightSqlServiceImpl {
             .map(|v| v.to_string())
     }
 
+    pub(super) fn get_bearer_token(metadata: &MetadataMap) -> Option<String> {
+        let bearer = "Bearer ";
+        Self::get_header_value(metadata, "authorization")
+            .filter(|authorization| authorization.starts_with(bearer))
+            .map(|authorization| authorization[bearer.len()..].to_string())
+    }
+
     pub(super) fn get_user_password(metadata: &MetadataMap) -> Result<(String, String), String> {
         let basic = "Basic ";
         let authorization = Self::get_header_value(metadata, "authorization")
@@ -126,4 +135,22 @@ impl FlightSqlServiceImpl {
             .map_err(|e| status!("set_authed_user fail {}", e))?;
         Ok(session)
     }
+
+    /// Authenticate with a JWT, e.g. the one signed by a key pair user.
+    #[async_backtrace::framed]
+    pub(super) async fn auth_jwt(
+        token: String,
+        client_ip: Option<String>,
+    ) -> Result<Arc<Session>, Status> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::FlightSQL)
+            .await
+            .map_err(|e| status!("Could not create session", e))?;
+        let credential = Credential::Jwt { token, client_ip };
+        AuthMgr::instance()
+            .auth(session.clone(), &credential)
+            .await
+            .map_err(|e| Status::unauthenticated(e.message()))?;
+        Ok(session)
+    }
 }
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_exception::ErrorCode;
 use common_expression::DataSchemaRef;
+use common_storages_result_cache::AsyncQueryState;
+use common_storages_result_cache::AsyncQueryStatus;
 use log::error;
 use log::info;
 use poem::error::Error as PoemError;
@@ -30,6 +34,10 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value as JsonValue;
 
+use super::query::get_async_query_page;
+use super::query::get_async_query_status;
+use super::query::kill_async_query;
+use super::query::start_async_query;
 use super::query::ExecuteStateKind;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
@@ -62,6 +70,18 @@ pub fn make_kill_uri(query_id: &str) -> String {
     format!("/v1/query/{}/kill", query_id)
 }
 
+pub fn make_async_state_uri(query_id: &str) -> String {
+    format!("/v1/query/async/{}", query_id)
+}
+
+pub fn make_async_page_uri(query_id: &str, page_no: usize) -> String {
+    format!("/v1/query/async/{}/page/{}", query_id, page_no)
+}
+
+pub fn make_async_kill_uri(query_id: &str) -> String {
+    format!("/v1/query/async/{}/kill", query_id)
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct QueryError {
     pub code: u16,
@@ -191,6 +211,61 @@ impl QueryResponse {
         .with_header(HEADER_QUERY_PAGE_ROWS, rows)
     }
 
+    /// Build the response of a query submitted in async mode, `data` is the page `page_no`
+    /// of the result, which is only available after the query succeeded.
+    pub(crate) fn from_async_status(
+        status: AsyncQueryStatus,
+        data: JsonBlock,
+        page_no: Option<usize>,
+    ) -> impl IntoResponse {
+        let id = status.query_id.clone();
+        let kill_uri = match status.state {
+            AsyncQueryState::Running => Some(make_async_kill_uri(&id)),
+            _ => None,
+        };
+        let (state, next_uri) = match status.state {
+            AsyncQueryState::Running => {
+                (ExecuteStateKind::Running, Some(make_async_state_uri(&id)))
+            }
+            AsyncQueryState::Failed => (ExecuteStateKind::Failed, None),
+            AsyncQueryState::Succeeded => {
+                let next_page_no = page_no.map(|n| n + 1).unwrap_or_default();
+                let uri = if next_page_no < status.pages.len() {
+                    Some(make_async_page_uri(&id, next_page_no))
+                } else {
+                    None
+                };
+                (ExecuteStateKind::Succeeded, uri)
+            }
+        };
+        let error = status.error.map(|e| QueryError {
+            code: e.code,
+            message: e.message,
+            detail: "".to_string(),
+        });
+        let schema = status.schema.map(Arc::new).unwrap_or_default();
+        let rows = data.num_rows();
+
+        Json(QueryResponse {
+            id: id.clone(),
+            session_id: None,
+            session: None,
+            schema: QueryResponseField::from_schema(schema),
+            data: data.into(),
+            state,
+            error,
+            stats: QueryStats::default(),
+            affect: None,
+            stats_uri: Some(make_async_state_uri(&id)),
+            final_uri: None,
+            next_uri,
+            kill_uri,
+        })
+        .with_header(HEADER_QUERY_ID, id)
+        .with_header(HEADER_QUERY_STATE, state.to_string())
+        .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+    }
+
     pub(crate) fn fail_to_start_sql(err: &ErrorCode) -> impl IntoResponse {
         metrics_incr_http_response_errors_count(err.name(), err.code());
         Json(QueryResponse {
@@ -286,6 +361,66 @@ async fn query_page_handler(
     }
 }
 
+#[poem::handler]
+async fn async_query_state_handler(
+    ctx: &HttpQueryContext,
+    Path(query_id): Path<String>,
+) -> PoemResult<impl IntoResponse> {
+    let status = get_async_query_status(ctx, &query_id)
+        .await
+        .map_err(|err| PoemError::from_string(err.message(), StatusCode::INTERNAL_SERVER_ERROR))?
+        .ok_or_else(|| query_id_not_found(query_id))?;
+    Ok(QueryResponse::from_async_status(
+        status,
+        JsonBlock::empty(),
+        None,
+    ))
+}
+
+#[poem::handler]
+async fn async_query_page_handler(
+    ctx: &HttpQueryContext,
+    Path((query_id, page_no)): Path<(String, usize)>,
+) -> PoemResult<impl IntoResponse> {
+    let status = get_async_query_status(ctx, &query_id)
+        .await
+        .map_err(|err| PoemError::from_string(err.message(), StatusCode::INTERNAL_SERVER_ERROR))?
+        .ok_or_else(|| query_id_not_found(query_id.clone()))?;
+    if status.state != AsyncQueryState::Succeeded {
+        return Err(PoemError::from_string(
+            format!(
+                "query {} is not succeeded, can not fetch its result",
+                query_id
+            ),
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+    let data = get_async_query_page(ctx, &status, page_no)
+        .await
+        .map_err(|err| PoemError::from_string(err.message(), StatusCode::NOT_FOUND))?;
+    Ok(QueryResponse::from_async_status(
+        status,
+        data,
+        Some(page_no),
+    ))
+}
+
+#[poem::handler]
+async fn async_query_kill_handler(
+    ctx: &HttpQueryContext,
+    Path(query_id): Path<String>,
+) -> PoemResult<impl IntoResponse> {
+    info!("kill async query: {}", query_id);
+    match kill_async_query(ctx, &query_id).await {
+        Ok(true) => Ok(StatusCode::OK),
+        Ok(false) => Err(query_id_not_found(query_id)),
+        Err(err) => Err(PoemError::from_string(
+            err.message(),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )),
+    }
+}
+
 #[poem::handler]
 #[async_backtrace::framed]
 pub(crate) async fn query_handler(
@@ -296,6 +431,23 @@ pub(crate) async fn query_handler(
     let http_query_manager = HttpQueryManager::instance();
     let sql = req.sql.clone();
 
+    if req.async_mode {
+        return match start_async_query(ctx, req).await {
+            Ok(status) => {
+                info!("async query submitted, query_id={}", status.query_id);
+                Ok(
+                    QueryResponse::from_async_status(status, JsonBlock::empty(), None)
+                        .into_response(),
+                )
+            }
+            Err(e) => {
+                let e = e.display_with_sql(&sql);
+                error!("Fail to submit async sql, Error: {:?}", e);
+                Ok(QueryResponse::fail_to_start_sql(&e).into_response())
+            }
+        };
+    }
+
     let query = http_query_manager
         .try_create_query(ctx, req)
         .await
@@ -330,6 +482,12 @@ pub fn query_route() -> Route {
     // Note: endpoints except /v1/query may change without notice, use uris in response instead
     let rules = [
         ("/", post(query_handler)),
+        ("/async/:id", get(async_query_state_handler)),
+        ("/async/:id/page/:page_no", get(async_query_page_handler)),
+        (
+            "/async/:id/kill",
+            get(async_query_kill_handler).post(async_query_kill_handler),
+        ),
         ("/:id", get(query_state_handler)),
         ("/:id/page/:page_no", get(query_page_handler)),
         (
@@ -19,6 +19,9 @@ mod query;
 mod stage;
 mod suggestions;
 
+pub use http_query_handlers::make_async_kill_uri;
+pub use http_query_handlers::make_async_page_uri;
+pub use http_query_handlers::make_async_state_uri;
 pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
@@ -0,0 +1,313 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::runtime::GlobalQueryRuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_meta_types::SeqV;
+use common_sql::plans::KillPlan;
+use common_storages_result_cache::AsyncQueryManager;
+use common_storages_result_cache::AsyncQueryState;
+use common_storages_result_cache::AsyncQueryStatus;
+use common_users::UserApiProvider;
+use futures::future::Either;
+use futures::StreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::KillInterpreter;
+use crate::servers::http::v1::query::ExecuteState;
+use crate::servers::http::v1::query::HttpQueryRequest;
+use crate::servers::http::v1::HttpQueryContext;
+use crate::servers::http::v1::JsonBlock;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// The minimal interval (seconds) to vacuum the result of the expired async queries.
+const VACUUM_INTERVAL_SECS: u64 = 600;
+
+static LAST_VACUUM_TIME: AtomicU64 = AtomicU64::new(0);
+
+fn async_query_manager(session: &Arc<Session>) -> Result<AsyncQueryManager> {
+    let retention = session
+        .get_settings()
+        .get_async_query_result_retention_secs()?;
+    Ok(AsyncQueryManager::create(
+        &session.get_current_tenant(),
+        UserApiProvider::instance().get_meta_store_client(),
+        retention,
+    ))
+}
+
+/// Submit the query in async mode, the query is executed in background and the result
+/// is spooled to the storage page by page.
+#[async_backtrace::framed]
+pub(crate) async fn start_async_query(
+    ctx: &HttpQueryContext,
+    request: HttpQueryRequest,
+) -> Result<AsyncQueryStatus> {
+    if request.session_id.is_some() {
+        return Err(ErrorCode::BadArguments(
+            "async query can not be attached to a server side session",
+        ));
+    }
+    if request.stage_attachment.is_some() {
+        return Err(ErrorCode::BadArguments(
+            "async query does not support stage attachment",
+        ));
+    }
+
+    let session = ctx.get_session(SessionType::HTTPQuery);
+    if let Some(session_conf) = &request.session {
+        session_conf.apply_to_session(&session)?;
+    }
+    let query_ctx = session.create_query_context().await?;
+    if let Some(ua) = &ctx.user_agent {
+        query_ctx.set_ua(ua.clone());
+    }
+    if let Some(query_id) = &ctx.query_id {
+        query_ctx.set_id(query_id.clone());
+    }
+
+    let manager = async_query_manager(&session)?;
+    let user = session.get_current_user()?.identity().to_string();
+    let status = manager
+        .submit(&query_ctx.get_id(), &request.sql, &user, &session.get_id())
+        .await?;
+    info!(query_id = status.data.query_id, sql = request.sql; "submit async query");
+
+    let status_clone = status.data.clone();
+    let max_rows_per_page = request.pagination.max_rows_per_page.max(1);
+    GlobalQueryRuntime::instance()
+        .runtime()
+        .try_spawn(async move {
+            let query_id = status.data.query_id.clone();
+            let mut status = status;
+            let execution = execute(&manager, query_ctx, &request.sql, max_rows_per_page);
+            futures::pin_mut!(execution);
+            // keep the status alive while running, so that the result is not vacuumed.
+            let res = loop {
+                let interval = tokio::time::sleep(manager.keep_alive_interval());
+                futures::pin_mut!(interval);
+                match futures::future::select(execution.as_mut(), interval).await {
+                    Either::Left((res, _)) => break res,
+                    Either::Right(_) => match manager.keep_alive(&status).await {
+                        Ok(Some(new_status)) => status = new_status,
+                        // killed, the execution is aborted soon.
+                        Ok(None) => {}
+                        Err(e) => warn!(
+                            "failed to keep alive the status of async query {}: {}",
+                            query_id, e
+                        ),
+                    },
+                }
+            };
+            let res = match res {
+                Ok((schema, pages, num_rows)) => {
+                    info!(
+                        "async query {} succeeded, {} rows in {} pages",
+                        query_id,
+                        num_rows,
+                        pages.len()
+                    );
+                    manager.succeed(status, schema, pages, num_rows).await
+                }
+                Err(e) => {
+                    info!("async query {} failed: {:?}", query_id, e);
+                    manager.fail(status, &e).await
+                }
+            };
+            if let Err(e) = res {
+                warn!(
+                    "failed to update the status of async query {}: {}",
+                    query_id, e
+                );
+            }
+            // the session is released after the query finished.
+            drop(session);
+            vacuum_expired_results(&manager).await;
+        })?;
+
+    Ok(status_clone)
+}
+
+#[async_backtrace::framed]
+async fn execute(
+    manager: &AsyncQueryManager,
+    ctx: Arc<QueryContext>,
+    sql: &str,
+    max_rows_per_page: usize,
+) -> Result<(DataSchema, Vec<String>, usize)> {
+    let query_id = ctx.get_id();
+    let res = async {
+        let (plan, extras) = ExecuteState::plan_sql(sql, ctx.clone()).await?;
+        ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        Ok((plan.schema(), stream))
+    }
+    .await;
+    let (schema, mut stream) = match res {
+        Ok(v) => v,
+        Err(e) => {
+            InterpreterQueryLog::fail_to_start(ctx.clone(), e.clone());
+            return Err(e);
+        }
+    };
+
+    // each page holds at least `max_rows_per_page` rows, except the last one.
+    let table_schema = infer_table_schema(&schema)?;
+    let mut pages = vec![];
+    let mut num_rows = 0;
+    let mut buffer: Vec<DataBlock> = vec![];
+    let mut buffer_rows = 0;
+    while let Some(block) = stream.next().await {
+        let block = block?;
+        buffer_rows += block.num_rows();
+        buffer.push(block);
+        if buffer_rows >= max_rows_per_page {
+            let blocks = std::mem::take(&mut buffer);
+            pages.push(
+                manager
+                    .write_page(&query_id, table_schema.clone(), blocks)
+                    .await?,
+            );
+            num_rows += buffer_rows;
+            buffer_rows = 0;
+        }
+    }
+    if buffer_rows > 0 {
+        pages.push(manager.write_page(&query_id, table_schema, buffer).await?);
+        num_rows += buffer_rows;
+    }
+
+    Ok((schema.as_ref().clone(), pages, num_rows))
+}
+
+/// The result pages are kept after the status expired, remove them from time to time.
+async fn vacuum_expired_results(manager: &AsyncQueryManager) {
+    let now = SeqV::<()>::now_ms() / 1000;
+    let last = LAST_VACUUM_TIME.load(Ordering::Relaxed);
+    if now < last + VACUUM_INTERVAL_SECS
+        || LAST_VACUUM_TIME
+            .compare_exchange(last, now, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+    {
+        return;
+    }
+    match manager.vacuum_expired_results().await {
+        Ok(n) => info!("removed the result of {} expired async queries", n),
+        Err(e) => warn!(
+            "failed to remove the result of expired async queries: {}",
+            e
+        ),
+    }
+}
+
+/// Get the status of an async query submitted by the current user.
+#[async_backtrace::framed]
+pub(crate) async fn get_async_query_status(
+    ctx: &HttpQueryContext,
+    query_id: &str,
+) -> Result<Option<AsyncQueryStatus>> {
+    let session = ctx.get_session(SessionType::HTTPQuery);
+    let user = session.get_current_user()?.identity().to_string();
+    Ok(async_query_manager(&session)?
+        .get_status(query_id, &user)
+        .await?
+        .map(|status| status.data))
+}
+
+/// Kill an async query submitted by the current user, returns false if the query is not found.
+///
+/// The query is killed on the node executing it, which then records it as failed. If the
+/// node is gone, the query is recorded as failed here.
+#[async_backtrace::framed]
+pub(crate) async fn kill_async_query(ctx: &HttpQueryContext, query_id: &str) -> Result<bool> {
+    let session = ctx.get_session(SessionType::HTTPQuery);
+    let user = session.get_current_user()?.identity().to_string();
+    let manager = async_query_manager(&session)?;
+    let Some(status) = manager.get_status(query_id, &user).await? else {
+        return Ok(false);
+    };
+    if status.data.state != AsyncQueryState::Running {
+        return Ok(true);
+    }
+
+    let query_ctx = session.create_query_context().await?;
+    let interpreter = KillInterpreter::try_create(query_ctx.clone(), KillPlan {
+        id: status.data.session_id.clone(),
+        kill_connection: false,
+    })?;
+    match interpreter.execute(query_ctx).await {
+        Ok(_) => {}
+        Err(e) if e.code() == ErrorCode::UNKNOWN_SESSION => {
+            // the query may have just finished.
+            if let Some(status) = manager.get_status(query_id, &user).await? {
+                if status.data.state == AsyncQueryState::Running {
+                    let error =
+                        ErrorCode::AbortedQuery("Aborted query, because the query was killed");
+                    manager.fail(status, &error).await?;
+                }
+            }
+        }
+        Err(e) => return Err(e),
+    }
+    info!("async query {} killed", query_id);
+    Ok(true)
+}
+
+/// Read a page of the spooled result, the caller should make sure the query succeeded.
+#[async_backtrace::framed]
+pub(crate) async fn get_async_query_page(
+    ctx: &HttpQueryContext,
+    status: &AsyncQueryStatus,
+    page_no: usize,
+) -> Result<JsonBlock> {
+    let location = status.pages.get(page_no).ok_or_else(|| {
+        ErrorCode::BadArguments(format!(
+            "page {} of async query {} not found, the result has {} pages",
+            page_no,
+            status.query_id,
+            status.pages.len()
+        ))
+    })?;
+    let schema = Arc::new(status.schema.clone().unwrap_or_default());
+
+    let session = ctx.get_session(SessionType::HTTPQuery);
+    let format_settings = session
+        .create_query_context()
+        .await?
+        .get_format_settings()?;
+    let blocks = async_query_manager(&session)?.read_page(location).await?;
+    let blocks = blocks
+        .iter()
+        .map(|block| JsonBlock::new(schema.clone(), block, &format_settings))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(JsonBlock::concat(blocks))
+}
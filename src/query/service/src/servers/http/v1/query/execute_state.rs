@@ -33,6 +33,7 @@ use common_sql::Planner;
 use futures::StreamExt;
 use log::error;
 use log::info;
+use log::warn;
 use serde::Deserialize;
 use serde::Serialize;
 use ExecuteState::*;
@@ -41,6 +42,7 @@ use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelSender;
+use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueryAffect;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
@@ -195,6 +197,19 @@ impl Executor {
                     }
                 }
 
+                // temporary tables live in the session, keep it for the following queries
+                let has_temp_tables = r.session.temp_tbl_mgr().lock().has_tables();
+                if has_temp_tables {
+                    if let Err(cause) =
+                        HttpQueryManager::instance().keep_temp_table_session(r.session.clone())
+                    {
+                        warn!(
+                            "http query {}: fail to keep the session of temporary tables: {:?}",
+                            &guard.query_id, cause
+                        );
+                    }
+                }
+
                 guard.state = Stopped(Box::new(ExecuteStopped {
                     stats: Progresses::from_context(&r.ctx),
                     reason,
@@ -49,6 +49,7 @@ use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::short_sql;
 use crate::sessions::QueryAffect;
+use crate::sessions::Session;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
 
@@ -66,6 +67,10 @@ pub struct HttpQueryRequest {
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    /// Run the query in background and spool the result to the storage, the result
+    /// could be fetched from any node of the cluster by the query id.
+    #[serde(default, rename = "async")]
+    pub async_mode: bool,
 }
 
 impl Debug for HttpQueryRequest {
@@ -77,6 +82,7 @@ impl Debug for HttpQueryRequest {
             .field("pagination", &self.pagination)
             .field("string_fields", &self.string_fields)
             .field("stage_attachment", &self.stage_attachment)
+            .field("async_mode", &self.async_mode)
             .finish()
     }
 }
@@ -136,12 +142,42 @@ pub struct HttpSessionConf {
     pub keep_server_session_secs: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub settings: Option<BTreeMap<String, String>>,
+    /// The server side session holding the temporary tables, the following queries
+    /// carrying it are attached to that session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
 }
 
 impl HttpSessionConf {
-    fn apply_affect(&self, affect: &QueryAffect) -> HttpSessionConf {
+    /// Set the current database and the session-level settings to the session.
+    pub(crate) fn apply_to_session(&self, session: &Arc<Session>) -> Result<()> {
+        if let Some(db) = &self.database {
+            session.set_current_database(db.clone());
+        }
+        if let Some(conf_settings) = &self.settings {
+            let settings = session.get_settings();
+            for (k, v) in conf_settings {
+                settings
+                    .set_setting(k.to_string(), v.to_string())
+                    .or_else(|e| {
+                        if e.code() == ErrorCode::UNKNOWN_VARIABLE {
+                            warn!("unknown session setting: {}", k);
+                            Ok(())
+                        } else {
+                            Err(e)
+                        }
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_affect(&self, affect: &QueryAffect, session_id: &str) -> HttpSessionConf {
         let mut ret = self.clone();
         match affect {
+            QueryAffect::Create { kind, .. } if kind == "TEMPORARY TABLE" => {
+                ret.session_id = Some(session_id.to_string());
+            }
             QueryAffect::UseDB { name } => {
                 ret.database = Some(name.to_string());
             }
@@ -212,16 +248,41 @@ impl HttpQuery {
     #[async_backtrace::framed]
     pub(crate) async fn try_create(
         ctx: &HttpQueryContext,
-        request: HttpQueryRequest,
+        mut request: HttpQueryRequest,
         config: HttpQueryConfig,
     ) -> Result<Arc<HttpQuery>> {
         let http_query_manager = HttpQueryManager::instance();
 
+        // The session conf carries the id of the session holding temporary tables, the
+        // temporary tables are gone together with the session if it is expired.
+        if request.session_id.is_none() {
+            if let Some(session_conf) = &mut request.session {
+                if let Some(id) = &session_conf.session_id {
+                    let attachable = match http_query_manager.get_session(id).await {
+                        Some(session) => ctx.is_session_owner(&session)?,
+                        None => false,
+                    };
+                    if attachable {
+                        request.session_id = Some(id.clone());
+                    } else {
+                        session_conf.session_id = None;
+                    }
+                }
+            }
+        }
+
         // If session_id is specified, the new query will be attached in the same session.
         let session = if let Some(id) = &request.session_id {
             let session = http_query_manager.get_session(id).await.ok_or_else(|| {
                 ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
             })?;
+            // the session may hold temporary tables, only its owner can attach to it
+            if !ctx.is_session_owner(&session)? {
+                return Err(ErrorCode::UnknownSession(format!(
+                    "unknown session-id {}, maybe expired",
+                    id
+                )));
+            }
             let mut n = 1;
             while let ExpiringState::InUse(query_id) = session.expire_state() {
                 if let Some(last_query) = &http_query_manager.get_query(&query_id).await {
@@ -251,24 +312,7 @@ impl HttpQuery {
         // - the current database
         // - the session-level settings, like max_threads
         if let Some(session_conf) = &request.session {
-            if let Some(db) = &session_conf.database {
-                session.set_current_database(db.clone());
-            }
-            if let Some(conf_settings) = &session_conf.settings {
-                let settings = session.get_settings();
-                for (k, v) in conf_settings {
-                    settings
-                        .set_setting(k.to_string(), v.to_string())
-                        .or_else(|e| {
-                            if e.code() == ErrorCode::UNKNOWN_VARIABLE {
-                                warn!("unknown session setting: {}", k);
-                                Ok(())
-                            } else {
-                                Err(e)
-                            }
-                        })?;
-                }
-            }
+            session_conf.apply_to_session(&session)?;
             if let Some(secs) = session_conf.keep_server_session_secs {
                 if secs > 0 && request.session_id.is_none() {
                     http_query_manager
@@ -389,7 +433,7 @@ impl HttpQuery {
         let state = self.get_state().await;
         let session = self.request.session.clone().unwrap_or_default();
         let session = if let Some(affect) = &state.affect {
-            Some(session.apply_affect(affect))
+            Some(session.apply_affect(affect, &self.session_id))
         } else {
             Some(session)
         };
@@ -407,7 +451,7 @@ impl HttpQuery {
         let state = self.get_state().await;
         let session = self.request.session.clone().unwrap_or_default();
         let session = if let Some(affect) = &state.affect {
-            Some(session.apply_affect(affect))
+            Some(session.apply_affect(affect, &self.session_id))
         } else {
             Some(session)
         };
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_exception::Result;
 use poem::FromRequest;
 use poem::Request;
 use poem::RequestBody;
@@ -48,6 +49,11 @@ impl HttpQueryContext {
         self.session.set_type(session_type);
         self.session.clone()
     }
+
+    /// Check if the session is created by the user authenticated by this request.
+    pub fn is_session_owner(&self, session: &Arc<Session>) -> Result<bool> {
+        Ok(self.session.get_current_user()?.identity() == session.get_current_user()?.identity())
+    }
 }
 
 #[async_trait::async_trait]
@@ -140,6 +140,23 @@ impl HttpQueryManager {
         sessions.insert(session.get_id(), session, Some(timeout));
     }
 
+    /// Keep the session holding temporary tables after the query, until it is idle for
+    /// `temporary_table_session_idle_secs`.
+    pub(crate) fn keep_temp_table_session(self: &Arc<Self>, session: Arc<Session>) -> Result<()> {
+        let idle_secs = session
+            .get_settings()
+            .get_temporary_table_session_idle_secs()?;
+        let mut sessions = self.sessions.lock();
+        if sessions.get(&session.get_id()).is_none() {
+            sessions.insert(
+                session.get_id(),
+                session,
+                Some(Duration::from_secs(idle_secs)),
+            );
+        }
+        Ok(())
+    }
+
     pub(crate) fn kill_session(self: &Arc<Self>, session_id: &str) {
         let mut sessions = self.sessions.lock();
         sessions.remove(session_id);
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod async_query;
 pub mod execute_state;
 pub mod expirable;
 pub mod expiring_map;
@@ -21,6 +22,10 @@ mod http_query_manager;
 mod page_manager;
 pub mod sized_spsc;
 
+pub(crate) use async_query::get_async_query_page;
+pub(crate) use async_query::get_async_query_status;
+pub(crate) use async_query::kill_async_query;
+pub(crate) use async_query::start_async_query;
 pub(crate) use execute_state::ExecuteState;
 pub use execute_state::ExecuteStateKind;
 pub(crate) use execute_state::Executor;
@@ -23,6 +23,7 @@ mod session_mgr;
 mod session_mgr_status;
 mod session_status;
 mod session_type;
+mod temp_tbl_gc;
 
 pub use common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -36,3 +37,5 @@ pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use temp_tbl_gc::session_temp_tbl_storage_prefix;
+pub use temp_tbl_gc::TempTblGc;
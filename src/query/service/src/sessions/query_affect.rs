@@ -18,7 +18,6 @@ use serde::Serialize;
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "type")]
 pub enum QueryAffect {
-    #[allow(unused)]
     Create {
         kind: String,
         name: String,
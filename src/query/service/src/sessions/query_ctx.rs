@@ -40,6 +40,8 @@ use common_catalog::query_kind::QueryKind;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::temp_table::TempTblMgrRef;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
@@ -554,6 +556,14 @@ impl TableContext for QueryContext {
         self.shared.get_settings()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
+
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.shared.session.temp_tbl_mgr()
+    }
+
     fn get_cluster(&self) -> Arc<Cluster> {
         self.shared.get_cluster()
     }
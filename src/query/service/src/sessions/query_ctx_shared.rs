@@ -23,6 +23,7 @@ use std::time::SystemTime;
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::query_kind::QueryKind;
 use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
@@ -293,8 +294,30 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+        let temp_table_info = match catalog == CATALOG_DEFAULT {
+            true => self
+                .session
+                .temp_tbl_mgr()
+                .lock()
+                .get_table(database, table),
+            false => None,
+        };
         let catalog = self.catalog_manager.get_catalog(&tenant, catalog).await?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // the temporary tables of the session shadow the tables with the same name
+        let cache_table = match temp_table_info {
+            Some(table_info) => catalog.get_table_by_info(&table_info)?,
+            None => catalog.get_table(tenant.as_str(), database, table).await?,
+        };
+        // read the uncommitted changes made by the explicit transaction of the session
+        let buffered_table_info = {
+            let txn_mgr = self.session.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            txn_mgr.get_table_from_buffer(cache_table.get_id())
+        };
+        let cache_table = match buffered_table_info {
+            Some(table_info) => catalog.get_table_by_info(&table_info)?,
+            None => cache_table,
+        };
 
         let mut tables_refs = self.tables_refs.lock();
 
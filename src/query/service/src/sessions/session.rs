@@ -16,6 +16,10 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::temp_table::TempTblMgrRef;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,13 +30,18 @@ use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_storage::DataOperator;
+use common_storages_memory::MemoryTable;
 use common_users::RoleCacheManager;
 use common_users::BUILTIN_ROLE_PUBLIC;
 use log::debug;
+use log::warn;
 use parking_lot::RwLock;
 
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::abort_txn_on_session_drop;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::sessions::session_temp_tbl_storage_prefix;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryContextShared;
 use crate::sessions::SessionContext;
@@ -369,6 +378,14 @@ impl Session {
         self.session_ctx.get_settings()
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.session_ctx.txn_mgr()
+    }
+
+    pub fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.session_ctx.temp_tbl_mgr()
+    }
+
     pub fn get_changed_settings(&self) -> HashMap<String, ChangeValue> {
         self.session_ctx.get_changed_settings()
     }
@@ -394,11 +411,39 @@ impl Session {
         self.session_ctx
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
+
+    // The meta of temporary tables only lives in the session, drop their data as well.
+    fn drop_temp_tables(&self) {
+        let tables = self.session_ctx.temp_tbl_mgr().lock().drain();
+        let mut has_fuse_table = false;
+        for table_info in tables {
+            if table_info.engine().eq_ignore_ascii_case("memory") {
+                MemoryTable::release(table_info.ident.table_id);
+            } else {
+                has_fuse_table = true;
+            }
+        }
+
+        if has_fuse_table {
+            let prefix = format!("{}/", session_temp_tbl_storage_prefix(&self.id));
+            let operator = DataOperator::instance().operator();
+            GlobalIORuntime::instance().spawn(async move {
+                if let Err(cause) = operator.remove_all(&prefix).await {
+                    warn!(
+                        "Failed to remove temporary tables under {}: {}",
+                        prefix, cause
+                    );
+                }
+            });
+        }
+    }
 }
 
 impl Drop for Session {
     fn drop(&mut self) {
         debug!("Drop session {}", self.id.clone());
+        self.drop_temp_tables();
+        abort_txn_on_session_drop(self.txn_mgr());
         SessionManager::instance().destroy_session(&self.id.clone());
     }
 }
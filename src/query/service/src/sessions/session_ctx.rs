@@ -20,6 +20,10 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::temp_table::TempTblMgr;
+use common_catalog::temp_table::TempTblMgrRef;
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
@@ -57,6 +61,10 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The state and the buffered changes of the explicit transaction(BEGIN ... COMMIT).
+    txn_mgr: TxnManagerRef,
+    // The temporary tables created by the session.
+    temp_tbl_mgr: TempTblMgrRef,
     typ: SessionType,
 }
 
@@ -75,10 +83,20 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            txn_mgr: TxnManager::init(),
+            temp_tbl_mgr: TempTblMgr::init(),
             typ,
         }))
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
+    pub fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.temp_tbl_mgr.clone()
+    }
+
     // Get abort status.
     pub fn get_abort(&self) -> bool {
         self.abort.load(Ordering::Relaxed)
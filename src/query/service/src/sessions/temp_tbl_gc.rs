@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::temp_table::temp_tbl_cluster_storage_prefix;
+use common_catalog::temp_table::temp_tbl_node_storage_prefix;
+use common_catalog::temp_table::temp_tbl_storage_prefix;
+use common_exception::Result;
+use common_storage::DataOperator;
+use futures::StreamExt;
+use log::info;
+use log::warn;
+use opendal::EntryMode;
+use opendal::Metakey;
+use opendal::Operator;
+
+use crate::clusters::ClusterDiscovery;
+use crate::sessions::SessionManager;
+
+const TEMP_TBL_GC_INTERVAL: Duration = Duration::from_secs(600);
+
+/// Storage prefix of the temporary tables of a session on this node.
+pub fn session_temp_tbl_storage_prefix(session_id: &str) -> String {
+    let discovery = ClusterDiscovery::instance();
+    temp_tbl_storage_prefix(discovery.cluster_id(), discovery.local_id(), session_id)
+}
+
+/// Removes the data of temporary tables that no session owns any more.
+///
+/// The data is normally removed when the session is dropped, but it is left
+/// behind if the node crashes or is killed. Sessions of this node which are not
+/// alive are collected, and so are the nodes that left the cluster. A node id is
+/// generated on each start, so a node never comes back with the same id.
+pub struct TempTblGc;
+
+impl TempTblGc {
+    pub fn start() {
+        GlobalIORuntime::instance().spawn(async move {
+            // nodes missing in the last round, a node is only collected if it is
+            // missing twice in a row, in case it is not registered yet.
+            let mut missing_nodes = HashSet::new();
+            loop {
+                match Self::gc(&missing_nodes).await {
+                    Ok(nodes) => missing_nodes = nodes,
+                    Err(cause) => warn!("Failed to gc temporary tables: {}", cause),
+                }
+                sleep(TEMP_TBL_GC_INTERVAL).await;
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn gc(missing_nodes: &HashSet<String>) -> Result<HashSet<String>> {
+        let discovery = ClusterDiscovery::instance();
+        let cluster_id = discovery.cluster_id();
+        let local_id = discovery.local_id();
+        let operator = DataOperator::instance().operator();
+
+        let local_prefix = format!("{}/", temp_tbl_node_storage_prefix(cluster_id, local_id));
+        for session_id in list_dirs(&operator, &local_prefix).await? {
+            if SessionManager::instance()
+                .get_session_by_id(&session_id)
+                .is_none()
+            {
+                let prefix = format!("{}{}/", local_prefix, session_id);
+                info!("Remove temporary tables of lost session under {}", prefix);
+                operator.remove_all(&prefix).await?;
+            }
+        }
+
+        let live_nodes = discovery
+            .get_nodes()
+            .await?
+            .into_iter()
+            .map(|node| node.id)
+            .collect::<HashSet<_>>();
+        let cluster_prefix = format!("{}/", temp_tbl_cluster_storage_prefix(cluster_id));
+        let mut missing = HashSet::new();
+        for node_id in list_dirs(&operator, &cluster_prefix).await? {
+            if node_id == local_id || live_nodes.contains(&node_id) {
+                continue;
+            }
+            if missing_nodes.contains(&node_id) {
+                let prefix = format!("{}{}/", cluster_prefix, node_id);
+                info!("Remove temporary tables of lost node under {}", prefix);
+                operator.remove_all(&prefix).await?;
+            } else {
+                missing.insert(node_id);
+            }
+        }
+        Ok(missing)
+    }
+}
+
+async fn list_dirs(operator: &Operator, prefix: &str) -> Result<Vec<String>> {
+    let mut dirs = vec![];
+    let mut lister = operator.lister_with(prefix).metakey(Metakey::Mode).await?;
+    while let Some(entry) = lister.next().await.transpose()? {
+        if entry.metadata().mode() == EntryMode::DIR {
+            let name = entry.name().trim_end_matches('/');
+            if !name.is_empty() {
+                dirs.push(name.to_string());
+            }
+        }
+    }
+    Ok(dirs)
+}
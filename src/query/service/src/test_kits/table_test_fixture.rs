@@ -173,6 +173,7 @@ impl TestFixture {
     pub fn default_create_table_plan(&self) -> CreateTablePlan {
         CreateTablePlan {
             if_not_exists: false,
+            temporary: false,
             tenant: self.default_tenant(),
             catalog: self.default_catalog_name(),
             database: self.default_db_name(),
@@ -189,6 +190,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            auto_increment_sequences: vec![],
         }
     }
 
@@ -196,6 +198,7 @@ impl TestFixture {
     pub fn normal_create_table_plan(&self) -> CreateTablePlan {
         CreateTablePlan {
             if_not_exists: false,
+            temporary: false,
             tenant: self.default_tenant(),
             catalog: self.default_catalog_name(),
             database: self.default_db_name(),
@@ -212,6 +215,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: None,
+            auto_increment_sequences: vec![],
         }
     }
 
@@ -230,6 +234,7 @@ impl TestFixture {
     pub fn variant_create_table_plan(&self) -> CreateTablePlan {
         CreateTablePlan {
             if_not_exists: false,
+            temporary: false,
             tenant: self.default_tenant(),
             catalog: self.default_catalog_name(),
             database: self.default_db_name(),
@@ -246,6 +251,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            auto_increment_sequences: vec![],
         }
     }
 
@@ -273,6 +279,7 @@ impl TestFixture {
     pub fn computed_create_table_plan(&self) -> CreateTablePlan {
         CreateTablePlan {
             if_not_exists: false,
+            temporary: false,
             tenant: self.default_tenant(),
             catalog: self.default_catalog_name(),
             database: self.default_db_name(),
@@ -289,6 +296,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            auto_increment_sequences: vec![],
         }
     }
 
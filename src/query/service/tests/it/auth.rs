@@ -647,3 +647,59 @@ async fn test_jwt_auth_mgr_with_management() -> Result<()> {
         Ok(())
     }
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_key_pair() -> Result<()> {
+    let user_name = "key_pair_user";
+    let old_key_pair = RS256KeyPair::generate(2048)?;
+    let new_key_pair = RS256KeyPair::generate(2048)?;
+    let other_key_pair = RS256KeyPair::generate(2048)?;
+
+    let (_guard, ctx) = databend_query::test_kits::create_query_context().await?;
+    let auth_mgr = AuthMgr::instance();
+    let tenant = ctx.get_tenant();
+    let auth_info = AuthInfo::KeyPair {
+        public_key: Some(old_key_pair.public_key().to_pem()?),
+        public_key_2: Some(new_key_pair.public_key().to_pem()?),
+    };
+    UserApiProvider::instance()
+        .add_user(&tenant, UserInfo::new(user_name, "%", auth_info), false)
+        .await?;
+
+    // both keys are accepted during rotation
+    for key_pair in [&old_key_pair, &new_key_pair] {
+        let claims = Claims::with_custom_claims(CustomClaims::new(), Duration::from_mins(5))
+            .with_subject(user_name.to_string());
+        let token = key_pair.sign(claims)?;
+        auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
+            .await?;
+        let user_info = ctx.get_current_user()?;
+        assert_eq!(user_info.name, user_name);
+    }
+
+    // signed by a key that does not belong to the user
+    {
+        let claims = Claims::with_custom_claims(CustomClaims::new(), Duration::from_mins(5))
+            .with_subject(user_name.to_string());
+        let token = other_key_pair.sign(claims)?;
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
+            .await;
+        assert!(res.is_err());
+        assert!(
+            res.err()
+                .unwrap()
+                .message()
+                .contains("could not verify token with the user public keys")
+        );
+    }
+
+    Ok(())
+}
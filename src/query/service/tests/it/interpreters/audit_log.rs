@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::AuditLogWriter;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::expects_ok;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_audit_log_batched_append() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    execute_command(ctx.clone(), "create table t_audit_1(a int)").await?;
+    execute_command(ctx.clone(), "create table t_audit_2(a int)").await?;
+
+    // the events are recorded in system.audit_log at once.
+    expects_ok(
+        "system_audit_log",
+        execute_query(
+            ctx.clone(),
+            "select action, object from system.audit_log where object like '%t_audit_%'",
+        )
+        .await,
+        vec![
+            "+----------------+-----------------------------------+",
+            "| Column 0       | Column 1                          |",
+            "+----------------+-----------------------------------+",
+            "| 'CREATE TABLE' | 'TABLE default.default.t_audit_1' |",
+            "| 'CREATE TABLE' | 'TABLE default.default.t_audit_2' |",
+            "+----------------+-----------------------------------+",
+        ],
+    )
+    .await?;
+
+    // the buffered events are appended to the audit log table in one block.
+    AuditLogWriter::instance().flush().await;
+    expects_ok(
+        "audit_log_table",
+        execute_query(
+            ctx.clone(),
+            "select count(*) from system_audit.audit_log where object like '%t_audit_%'",
+        )
+        .await,
+        vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 2        |",
+            "+----------+",
+        ],
+    )
+    .await?;
+    expects_ok(
+        "audit_log_blocks",
+        execute_query(
+            ctx,
+            "select block_count from fuse_snapshot('system_audit', 'audit_log')",
+        )
+        .await,
+        vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 1        |",
+            "+----------+",
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
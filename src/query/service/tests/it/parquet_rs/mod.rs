@@ -15,4 +15,5 @@
 mod data;
 mod prune_pages;
 mod prune_row_groups;
+mod timestamp_tz;
 mod utils;
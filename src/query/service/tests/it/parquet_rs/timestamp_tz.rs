@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use arrow_array::Int32Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use arrow_array::TimestampMicrosecondArray;
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::expects_ok;
+use parquet::arrow::ArrowWriter;
+use tempfile::TempDir;
+
+use crate::parquet_rs::utils::create_test_fixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_copy_into_timestamp_tz() -> Result<()> {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("t.parquet");
+
+    // `ts` is stored as an instant adjusted to UTC, `s` keeps the offsets in the strings.
+    let id = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+    let ts = Arc::new(
+        TimestampMicrosecondArray::from(vec![1672531200000000, 1685593800000000])
+            .with_timezone("+08:00"),
+    ) as ArrayRef;
+    let s = Arc::new(StringArray::from(vec![
+        "2023-01-01 10:00:00+05:30",
+        "2023-06-01 12:30:00-04:00",
+    ])) as ArrayRef;
+    let batch = RecordBatch::try_from_iter(vec![("id", id), ("ts", ts), ("s", s)]).unwrap();
+    let file = std::fs::File::create(&path).unwrap();
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let fixture = create_test_fixture().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    execute_command(
+        ctx.clone(),
+        &format!("create table {db}.t(id int, ts timestamp_tz, s timestamp_tz)"),
+    )
+    .await?;
+    execute_command(
+        ctx.clone(),
+        &format!(
+            "copy into {db}.t from 'fs://{}/' pattern = '.*[.]parquet' file_format = (type = parquet)",
+            dir.path().to_string_lossy()
+        ),
+    )
+    .await?;
+
+    expects_ok(
+        "copy_into_timestamp_tz",
+        execute_query(
+            ctx.clone(),
+            &format!("select id, ts::string, s::string from {db}.t order by id"),
+        )
+        .await,
+        vec![
+            "+----------+-------------------------------------+-------------------------------------+",
+            "| Column 0 | Column 1                            | Column 2                            |",
+            "+----------+-------------------------------------+-------------------------------------+",
+            "| 1        | '2023-01-01 00:00:00.000000 +00:00' | '2023-01-01 10:00:00.000000 +05:30' |",
+            "| 2        | '2023-06-01 04:30:00.000000 +00:00' | '2023-06-01 12:30:00.000000 -04:00' |",
+            "+----------+-------------------------------------+-------------------------------------+",
+        ],
+    )
+    .await
+}
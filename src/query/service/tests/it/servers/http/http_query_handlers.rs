@@ -30,6 +30,9 @@ use common_users::EnsureUser;
 use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::make_async_kill_uri;
+use databend_query::servers::http::v1::make_async_page_uri;
+use databend_query::servers::http::v1::make_async_state_uri;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
@@ -415,6 +418,125 @@ async fn test_pagination() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_async_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select * from numbers(10)";
+    let json = serde_json::json!({"sql": sql.to_string(), "async": true, "pagination": {"max_rows_per_page": 4}});
+
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.state, ExecuteStateKind::Running, "{:?}", result);
+    let query_id = result.id.clone();
+    assert_eq!(
+        result.next_uri,
+        Some(make_async_state_uri(&query_id)),
+        "{:?}",
+        result
+    );
+
+    // poll the status until the query finished
+    let mut result = result;
+    for _ in 0..100 {
+        let (status, r) = get_uri_checked(&ep, &make_async_state_uri(&query_id)).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", r);
+        result = r;
+        if result.state != ExecuteStateKind::Running {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+    assert_eq!(result.schema.len(), 1, "{:?}", result);
+    assert!(result.data.is_empty(), "{:?}", result);
+
+    // fetch the spooled result page by page
+    let mut next_uri = result.next_uri.clone();
+    assert_eq!(next_uri, Some(make_async_page_uri(&query_id, 0)));
+    let mut num_rows = 0;
+    let mut num_pages = 0;
+    while let Some(uri) = next_uri {
+        let (status, result) = get_uri_checked(&ep, &uri).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        num_rows += result.data.len();
+        num_pages += 1;
+        next_uri = result.next_uri;
+    }
+    assert_eq!(num_rows, 10);
+    assert!(num_pages >= 1);
+
+    // an unknown query id
+    let response = get_uri(&ep, &make_async_state_uri("not_exist")).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_async_query_kill() -> Result<()> {
+    let user_name = "conf_user";
+    let pass_word = "conf_user_pwd";
+    let hash_method = PasswordHashMethod::DoubleSha1;
+    let hash_value = hash_method.hash(pass_word.as_bytes());
+    let auth_info = AuthInfo::Password {
+        hash_value,
+        hash_method,
+    };
+    let config = ConfigBuilder::create()
+        .add_user(user_name, auth_info)
+        .build();
+    let _guard = TestGlobalServices::setup(config).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select sleep(1) from numbers(60)";
+    let json = serde_json::json!({"sql": sql.to_string(), "async": true});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.state, ExecuteStateKind::Running, "{:?}", result);
+    let query_id = result.id.clone();
+    assert_eq!(result.kill_uri, Some(make_async_kill_uri(&query_id)));
+
+    // the query can only be accessed by the user who submitted it
+    let basic = headers::Authorization::basic(user_name, pass_word);
+    for uri in [
+        make_async_state_uri(&query_id),
+        make_async_page_uri(&query_id, 0),
+        make_async_kill_uri(&query_id),
+    ] {
+        let response = ep
+            .call(
+                Request::builder()
+                    .uri(uri.parse().unwrap())
+                    .method(Method::GET)
+                    .typed_header(basic.clone())
+                    .finish(),
+            )
+            .await
+            .unwrap_or_else(|err| err.into_response());
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{}", uri);
+    }
+
+    let response = get_uri(&ep, &make_async_kill_uri(&query_id)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let mut result = result;
+    for _ in 0..100 {
+        let (status, r) = get_uri_checked(&ep, &make_async_state_uri(&query_id)).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", r);
+        result = r;
+        if result.state != ExecuteStateKind::Running {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(result.state, ExecuteStateKind::Failed, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+    assert!(result.kill_uri.is_none(), "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_http_session() -> Result<()> {
     let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
@@ -452,6 +574,80 @@ async fn test_http_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_session_temp_table() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "create temporary table t(a int) engine = memory"});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let session = result.session.unwrap();
+    assert_eq!(session.session_id, result.session_id, "{:?}", session);
+
+    // the session conf returned carries the session holding the temporary table
+    let json = serde_json::json!({"sql": "insert into t values (1), (2)", "session": session});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let json = serde_json::json!({"sql": "select count(*) from t", "session": session});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data[0][0], "2", "{:?}", result);
+
+    // invisible to other sessions
+    let json = serde_json::json!({"sql": "select count(*) from t"});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_session_temp_table_other_user() -> Result<()> {
+    let user_name = "conf_user";
+    let pass_word = "conf_user_pwd";
+    let hash_method = PasswordHashMethod::DoubleSha1;
+    let hash_value = hash_method.hash(pass_word.as_bytes());
+    let auth_info = AuthInfo::Password {
+        hash_value,
+        hash_method,
+    };
+    let config = ConfigBuilder::create()
+        .add_user(user_name, auth_info)
+        .build();
+    let _guard = TestGlobalServices::setup(config).await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "create temporary table t(a int) engine = memory"});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let session = result.session.unwrap();
+
+    // another user can't attach to the session holding the temporary table
+    let json = serde_json::json!({"sql": "select count(*) from t", "session": session});
+    let response = ep
+        .call(
+            Request::builder()
+                .uri("/v1/query".parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .typed_header(headers::Authorization::basic(user_name, pass_word))
+                .body(serde_json::to_vec(&json)?),
+        )
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    let (status, result) = check_response(response).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+    assert_ne!(result.session_id, session.session_id, "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_result_timeout() -> Result<()> {
     let config = ConfigBuilder::create()
@@ -1206,6 +1402,7 @@ async fn test_affect() -> Result<()> {
                     ("max_threads".to_string(), "1".to_string()),
                     ("timezone".to_string(), "Asia/Shanghai".to_string()),
                 ])),
+                session_id: None,
             }),
         ),
         (
@@ -1218,6 +1415,7 @@ async fn test_affect() -> Result<()> {
                     "max_threads".to_string(),
                     "6".to_string(),
                 )])),
+                session_id: None,
             }),
         ),
         (
@@ -1232,6 +1430,7 @@ async fn test_affect() -> Result<()> {
                     "max_threads".to_string(),
                     "6".to_string(),
                 )])),
+                session_id: None,
             }),
         ),
     ];
@@ -37,6 +37,7 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
 
     let create_table_plan = CreateTablePlan {
         if_not_exists: false,
+        temporary: false,
         tenant: fixture.default_tenant(),
         catalog: fixture.default_catalog_name(),
         database: fixture.default_db_name(),
@@ -53,6 +54,7 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        auto_increment_sequences: vec![],
     };
 
     // create test table
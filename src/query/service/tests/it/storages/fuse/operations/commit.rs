@@ -32,6 +32,8 @@ use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::temp_table::TempTblMgrRef;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -530,6 +532,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.ctx.temp_tbl_mgr()
+    }
+
     fn get_cluster(&self) -> Arc<Cluster> {
         todo!()
     }
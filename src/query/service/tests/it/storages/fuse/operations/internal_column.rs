@@ -52,6 +52,7 @@ fn expected_data_block(
             segment_location: block_meta.segment_location.clone(),
             snapshot_location: block_meta.snapshot_location.clone(),
             offsets: None,
+            sample_block_seed: None,
         };
         for internal_column in internal_columns {
             let column = internal_column.generate_column_values(&internal_column_meta, num_rows);
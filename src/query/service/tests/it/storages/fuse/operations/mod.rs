@@ -27,3 +27,4 @@ mod read_plan;
 mod replace_into;
 mod table_analyze;
 mod truncate;
+mod txn;
@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_txn_rollback() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let session = fixture.ctx().get_current_session();
+    fixture.create_default_table().await?;
+
+    execute_command(session.create_query_context().await?, "begin").await?;
+    let qry = format!("insert into {}.{} values (1, (2, 3)), (2, (4, 6))", db, tbl);
+    execute_command(session.create_query_context().await?, &qry).await?;
+
+    // the uncommitted changes are visible inside the transaction
+    let ctx = session.create_query_context().await?;
+    let table = ctx.get_table("default", &db, &tbl).await?;
+    assert_eq!(table.get_table_info().meta.statistics.number_of_rows, 2);
+    let uncommitted_snapshot = FuseTable::try_from_table(table.as_ref())?
+        .snapshot_loc()
+        .await?
+        .unwrap();
+
+    // but not visible outside
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.get_table_info().meta.statistics.number_of_rows, 0);
+
+    execute_command(session.create_query_context().await?, "rollback").await?;
+    assert!(!session.txn_mgr().lock().is_active());
+
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.get_table_info().meta.statistics.number_of_rows, 0);
+
+    // the orphan snapshot is removed
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert!(
+        !fuse_table
+            .get_operator()
+            .is_exist(&uncommitted_snapshot)
+            .await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_txn_commit() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let session = fixture.ctx().get_current_session();
+    fixture.create_default_table().await?;
+    let base_version = fixture
+        .latest_default_table()
+        .await?
+        .get_table_info()
+        .ident
+        .seq;
+
+    execute_command(session.create_query_context().await?, "begin").await?;
+    let qry = format!("insert into {}.{} values (1, (2, 3)), (2, (4, 6))", db, tbl);
+    execute_command(session.create_query_context().await?, &qry).await?;
+    let qry = format!("delete from {}.{} where id = 1", db, tbl);
+    execute_command(session.create_query_context().await?, &qry).await?;
+
+    // DDL is not allowed inside a transaction
+    let qry = format!("truncate table {}.{}", db, tbl);
+    let res = execute_command(session.create_query_context().await?, &qry).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::INVALID_TRANSACTION_STATE
+    );
+
+    execute_command(session.create_query_context().await?, "commit").await?;
+    assert!(!session.txn_mgr().lock().is_active());
+
+    // all the changes are committed at once
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.get_table_info().meta.statistics.number_of_rows, 1);
+    assert_eq!(table.get_table_info().ident.seq, base_version + 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_txn_conflict() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let session = fixture.ctx().get_current_session();
+    fixture.create_default_table().await?;
+
+    execute_command(session.create_query_context().await?, "begin").await?;
+    let qry = format!("insert into {}.{} values (1, (2, 3))", db, tbl);
+    execute_command(session.create_query_context().await?, &qry).await?;
+
+    // the table is changed by another session
+    let other_session = SessionManager::instance()
+        .create_session(SessionType::Dummy)
+        .await?;
+    other_session
+        .set_authed_user(fixture.ctx().get_current_user()?, None)
+        .await?;
+    let qry = format!("insert into {}.{} values (2, (4, 6))", db, tbl);
+    execute_command(other_session.create_query_context().await?, &qry).await?;
+
+    let res = execute_command(session.create_query_context().await?, "commit").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::TABLE_VERSION_MISMATCHED);
+    assert!(!session.txn_mgr().lock().is_active());
+
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.get_table_info().meta.statistics.number_of_rows, 1);
+
+    Ok(())
+}
@@ -87,6 +87,7 @@ async fn test_block_pruner() -> Result<()> {
     let create_table_plan = CreateTablePlan {
         catalog: "default".to_owned(),
         if_not_exists: false,
+        temporary: false,
         tenant: fixture.default_tenant(),
         database: fixture.default_db_name(),
         table: test_tbl_name.to_string(),
@@ -103,6 +104,7 @@ async fn test_block_pruner() -> Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        auto_increment_sequences: vec![],
     };
 
     let interpreter = CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
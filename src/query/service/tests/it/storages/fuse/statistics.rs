@@ -18,11 +18,15 @@ use std::sync::Arc;
 use chrono::Utc;
 use common_base::base::tokio;
 use common_expression::type_check::check;
+use common_expression::types::geometry::geometry_bbox;
+use common_expression::types::geometry::parse_geometry;
 use common_expression::types::number::Int32Type;
 use common_expression::types::number::NumberScalar;
 use common_expression::types::DataType;
+use common_expression::types::GeometryType;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
+use common_expression::types::ValueType;
 use common_expression::BlockThresholds;
 use common_expression::Column;
 use common_expression::DataBlock;
@@ -219,6 +223,33 @@ fn test_reduce_block_statistics_in_memory_size() -> common_exception::Result<()>
     Ok(())
 }
 
+#[test]
+fn test_reduce_block_statistics_geometry_bbox() -> common_exception::Result<()> {
+    let stats = |wkt: Option<&str>| {
+        let bbox = wkt.map(|wkt| geometry_bbox(&parse_geometry(wkt.as_bytes()).unwrap()).unwrap());
+        HashMap::from([(1, ColumnStatistics {
+            bbox,
+            ..ColumnStatistics::new(Scalar::Null, Scalar::Null, 0, 1, Some(1))
+        })])
+    };
+
+    let r = reducers::reduce_block_statistics(&[
+        stats(Some("POINT(1 2)")),
+        stats(Some("LINESTRING(-1 0,0 5)")),
+    ]);
+    let col_stats = r.get(&1).unwrap();
+    assert!(col_stats.min().is_null() && col_stats.max().is_null());
+    assert_eq!(
+        col_stats.bbox,
+        geometry_bbox(&parse_geometry(b"LINESTRING(-1 0,1 5)").unwrap())
+    );
+
+    // a block without a box may hold any geometry
+    let r = reducers::reduce_block_statistics(&[stats(Some("POINT(1 2)")), stats(None)]);
+    assert_eq!(r.get(&1).unwrap().bbox, Some(GeometryType::full_domain()));
+    Ok(())
+}
+
 #[test]
 fn test_reduce_cluster_statistics() -> common_exception::Result<()> {
     let default_cluster_key_id = Some(0);
@@ -251,6 +251,18 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("async_query_result_retention_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(86400), // seconds
+                    desc: "Sets the time in seconds to keep the status and the result of the queries submitted in async mode over HTTP.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("temporary_table_session_idle_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3600), // seconds
+                    desc: "Sets the seconds to keep an idle HTTP session that holds temporary tables, the temporary tables are dropped along with the session.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("query_result_cache_allow_inconsistent", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend will return cached query results that are inconsistent with the underlying data.",
@@ -362,6 +374,18 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("enable_materialized_view_rewrite", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enable rewriting queries to read from up-to-date materialized views.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("vector_index_search_effort", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64),
+                    desc: "Sets the search effort of vector indexes, a higher value improves the recall of top-k queries at the cost of speed.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("enable_recluster_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables re-clustering after write(copy/replace-into).",
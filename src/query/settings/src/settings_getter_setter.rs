@@ -255,6 +255,14 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_allow_inconsistent")? != 0)
     }
 
+    pub fn get_async_query_result_retention_secs(&self) -> Result<u64> {
+        self.try_get_u64("async_query_result_retention_secs")
+    }
+
+    pub fn get_temporary_table_session_idle_secs(&self) -> Result<u64> {
+        self.try_get_u64("temporary_table_session_idle_secs")
+    }
+
     pub fn get_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         Ok(self.try_get_u64("spilling_bytes_threshold_per_proc")? as usize)
     }
@@ -332,6 +340,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
+    pub fn get_vector_index_search_effort(&self) -> Result<u64> {
+        self.try_get_u64("vector_index_search_effort")
+    }
+
     pub fn get_enable_recluster_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_recluster_after_write")? != 0)
     }
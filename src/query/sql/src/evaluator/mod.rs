@@ -16,7 +16,12 @@
 
 mod block_operator;
 mod cse;
+mod sequence;
 
 pub use block_operator::BlockOperator;
 pub use block_operator::CompoundBlockOperator;
 pub use cse::apply_cse;
+pub use sequence::contains_nextval_call;
+pub use sequence::extract_nextval_calls;
+pub use sequence::get_sequence_next_values;
+pub use sequence::NEXTVAL_FUNC_NAME;
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_meta_app::schema::GetSequenceNextValueReply;
+use common_meta_app::schema::GetSequenceNextValueReq;
+use common_meta_app::schema::SequenceNameIdent;
+
+pub const NEXTVAL_FUNC_NAME: &str = "nextval";
+
+/// Replace the `nextval('<sequence>')` calls in `exprs` with references to extra columns,
+/// which are expected to be appended after the `num_input_columns` input columns.
+///
+/// Returns the sequences in the order of the extra columns.
+pub fn extract_nextval_calls(exprs: &mut [Expr], num_input_columns: usize) -> Result<Vec<String>> {
+    let mut sequences = vec![];
+    for expr in exprs.iter_mut() {
+        replace_nextval_calls(expr, num_input_columns, &mut sequences)?;
+    }
+    Ok(sequences)
+}
+
+/// Whether the expression allocates values from a sequence.
+pub fn contains_nextval_call(expr: &Expr) -> bool {
+    match expr {
+        Expr::FunctionCall { function, args, .. } => {
+            function.signature.name == NEXTVAL_FUNC_NAME || args.iter().any(contains_nextval_call)
+        }
+        Expr::UDFServerCall { args, .. } => args.iter().any(contains_nextval_call),
+        Expr::Cast { expr, .. } => contains_nextval_call(expr),
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => false,
+    }
+}
+
+fn replace_nextval_calls(
+    expr: &mut Expr,
+    num_input_columns: usize,
+    sequences: &mut Vec<String>,
+) -> Result<()> {
+    match expr {
+        Expr::FunctionCall {
+            span,
+            function,
+            args,
+            return_type,
+            ..
+        } if function.signature.name == NEXTVAL_FUNC_NAME => {
+            let sequence = match &args[0] {
+                Expr::Constant {
+                    scalar: Scalar::String(name),
+                    ..
+                } => String::from_utf8_lossy(name).to_string(),
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "the argument of nextval() must be a constant sequence name".to_string(),
+                    )
+                    .set_span(*span));
+                }
+            };
+            *expr = Expr::ColumnRef {
+                span: *span,
+                id: num_input_columns + sequences.len(),
+                data_type: return_type.clone(),
+                display_name: format!("nextval('{sequence}')"),
+            };
+            sequences.push(sequence);
+        }
+        Expr::FunctionCall { args, .. } | Expr::UDFServerCall { args, .. } => {
+            for arg in args.iter_mut() {
+                replace_nextval_calls(arg, num_input_columns, sequences)?;
+            }
+        }
+        Expr::Cast {
+            expr: inner_expr, ..
+        } => {
+            replace_nextval_calls(inner_expr.as_mut(), num_input_columns, sequences)?;
+        }
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => {}
+    }
+    Ok(())
+}
+
+/// Allocate `count` values from the sequence, the values are
+/// `start, start + step, ...` until `end` (exclusive).
+#[async_backtrace::framed]
+pub async fn get_sequence_next_values(
+    ctx: Arc<dyn TableContext>,
+    sequence: &str,
+    count: u64,
+) -> Result<GetSequenceNextValueReply> {
+    let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+    let req = GetSequenceNextValueReq {
+        name_ident: SequenceNameIdent::new(ctx.get_tenant(), sequence),
+        count,
+    };
+    catalog.get_sequence_next_value(req).await
+}
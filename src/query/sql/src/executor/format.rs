@@ -19,6 +19,7 @@ use common_expression::DataSchemaRef;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_profile::SharedProcessorProfiles;
 use itertools::Itertools;
+use storages_common_table_meta::table::get_vector_indexes;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::AggregateExpand;
@@ -321,6 +322,34 @@ fn table_scan_to_format_tree(
         }
     };
     children.push(FormatTreeNode::new(push_downs));
+    // Table sample
+    if let Some(sample) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.sample.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!(
+            "sample: [level: {:?}, probability: {}]",
+            sample.level, sample.probability
+        )));
+    }
+    // Vector index
+    if let Some(vector_top_k) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_top_k.as_ref())
+    {
+        let index_name = get_vector_indexes(table.table().options())?
+            .into_iter()
+            .find(|index| index.id == vector_top_k.index_id)
+            .map_or(vector_top_k.index_id.clone(), |index| index.name);
+        children.push(FormatTreeNode::new(format!(
+            "vector index: [name: {index_name}, limit: {}]",
+            vector_top_k.limit
+        )));
+    }
     // Aggregating index
     if let Some(agg_index) = agg_index {
         let (_, agg_index_sql, _) = metadata
@@ -677,6 +706,10 @@ fn window_to_format_tree(
         FormatTreeNode::new(format!("frame: [{frame}]")),
     ];
 
+    if let Some(limit) = plan.limit {
+        children.push(FormatTreeNode::new(format!("limit: [{limit}]")));
+    }
+
     append_profile_info(&mut children, prof_span_set, plan.plan_id);
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
@@ -836,9 +869,33 @@ fn range_join_to_format_tree(
             format_output_columns(plan.output_schema()?, metadata, true)
         )),
         FormatTreeNode::new(format!("join type: {}", plan.join_type)),
-        FormatTreeNode::new(format!("range join conditions: [{range_join_conditions}]")),
-        FormatTreeNode::new(format!("other conditions: [{other_conditions}]")),
     ];
+    if matches!(plan.range_join_type, RangeJoinType::Asof) {
+        let left_keys = plan
+            .left_keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let right_keys = plan
+            .right_keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .collect::<Vec<_>>()
+            .join(", ");
+        children.push(FormatTreeNode::new(format!("left keys: [{left_keys}]")));
+        children.push(FormatTreeNode::new(format!("right keys: [{right_keys}]")));
+        children.push(FormatTreeNode::new(format!(
+            "match condition: [{range_join_conditions}]"
+        )));
+    } else {
+        children.push(FormatTreeNode::new(format!(
+            "range join conditions: [{range_join_conditions}]"
+        )));
+        children.push(FormatTreeNode::new(format!(
+            "other conditions: [{other_conditions}]"
+        )));
+    }
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -854,6 +911,7 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Asof => "AsofJoin".to_string(),
         },
         children,
     ))
@@ -300,6 +300,11 @@ impl PhysicalPlanBuilder {
                 ));
                 probe_fields
             }
+            JoinType::Asof | JoinType::LeftAsof => {
+                return Err(ErrorCode::Internal(
+                    "Asof join should be executed by asof merge join",
+                ));
+            }
         };
         let mut projections = ColumnSet::new();
         let projected_schema = DataSchemaRefExt::create(merged_fields.clone());
@@ -14,6 +14,7 @@
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::type_check::check_cast;
 use common_expression::type_check::common_super_type;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
@@ -31,6 +32,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ScalarExpr;
 use crate::TypeCheck;
@@ -96,11 +98,99 @@ impl PhysicalPlanBuilder {
                 .iter()
                 .map(|scalar| resolve_scalar(scalar, &merged_schema))
                 .collect::<Result<_>>()?,
+            left_keys: vec![],
+            right_keys: vec![],
             join_type: JoinType::Inner,
             range_join_type,
             stat_info: Some(self.build_plan_stat_info(s_expr)?),
         }))
     }
+
+    pub async fn build_asof_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        // Unlike the other range joins, the left side of asof join must be the preserved side.
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        debug_assert_eq!(join.non_equi_conditions.len(), 1);
+
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        let mut left_keys = Vec::with_capacity(join.left_conditions.len());
+        let mut right_keys = Vec::with_capacity(join.right_conditions.len());
+        for (left_condition, right_condition) in join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+        {
+            let left_expr = left_condition
+                .resolve_and_check(left_schema.as_ref())?
+                .project_column_ref(|index| left_schema.index_of(&index.to_string()).unwrap());
+            let right_expr = right_condition
+                .resolve_and_check(right_schema.as_ref())?
+                .project_column_ref(|index| right_schema.index_of(&index.to_string()).unwrap());
+            // Unify the data types of the left and right keys, so they can be compared directly.
+            let left_type = left_expr.data_type();
+            let right_type = right_expr.data_type();
+            let common_ty = common_super_type(
+                left_type.clone(),
+                right_type.clone(),
+                &BUILTIN_FUNCTIONS.default_cast_rules,
+            )
+            .ok_or_else(|| {
+                ErrorCode::IllegalDataType(format!(
+                    "Cannot find common type for {:?} and {:?}",
+                    left_type, right_type
+                ))
+            })?;
+            let left_expr = check_cast(
+                left_expr.span(),
+                false,
+                left_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let right_expr = check_cast(
+                right_expr.span(),
+                false,
+                right_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            left_keys.push(left_expr.as_remote_expr());
+            right_keys.push(right_expr.as_remote_expr());
+        }
+
+        let match_condition = resolve_range_condition(
+            &join.non_equi_conditions[0],
+            &left_schema,
+            &right_schema,
+            &left_prop,
+            &right_prop,
+        )?;
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: self.next_plan_id(),
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions: vec![match_condition],
+            other_conditions: vec![],
+            left_keys,
+            right_keys,
+            join_type: join.join_type.clone(),
+            range_join_type: RangeJoinType::Asof,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
 }
 
 fn resolve_range_condition(
@@ -468,6 +468,8 @@ pub struct Window {
     pub partition_by: Vec<IndexType>,
     pub order_by: Vec<SortDesc>,
     pub window_frame: WindowFuncFrame,
+    // Only the first `limit` rows of each partition are needed.
+    pub limit: Option<usize>,
 }
 
 impl Window {
@@ -648,6 +650,8 @@ impl HashJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    // Sorted merge join which only keeps the closest match for each left row
+    Asof,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -662,7 +666,10 @@ pub struct RangeJoin {
     pub conditions: Vec<RangeJoinCondition>,
     /// The other conditions
     pub other_conditions: Vec<RemoteExpr>,
-    /// Now only support inner join, will support left/right join later
+    /// The equi-keys of asof join, the match condition is the only one in `conditions`
+    pub left_keys: Vec<RemoteExpr>,
+    pub right_keys: Vec<RemoteExpr>,
+    /// Inner join for IEJoin/Merge, asof/left asof join for Asof
     pub join_type: JoinType,
     pub range_join_type: RangeJoinType,
 
@@ -673,7 +680,18 @@ pub struct RangeJoin {
 impl RangeJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.left.output_schema()?.fields().clone();
-        fields.extend(self.right.output_schema()?.fields().clone());
+        if self.join_type == JoinType::LeftAsof {
+            // Unmatched left rows are padded with nulls
+            fields.extend(
+                self.right
+                    .output_schema()?
+                    .fields()
+                    .iter()
+                    .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable())),
+            );
+        } else {
+            fields.extend(self.right.output_schema()?.fields().clone());
+        }
         Ok(DataSchemaRefExt::create(fields))
     }
 }
@@ -1292,6 +1310,7 @@ pub struct LagLeadFunctionDesc {
     pub arg: usize,
     pub return_type: DataType,
     pub default: LagLeadDefault,
+    pub ignore_null: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -1299,6 +1318,7 @@ pub struct NthValueFunctionDesc {
     pub n: Option<u64>,
     pub arg: usize,
     pub return_type: DataType,
+    pub ignore_null: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
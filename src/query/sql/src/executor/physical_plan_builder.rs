@@ -229,6 +229,10 @@ impl PhysicalPlanBuilder {
                         self.build_range_join(s_expr, left_required, right_required, range, other)
                             .await
                     }
+                    PhysicalJoinType::AsofJoin => {
+                        self.build_asof_join(join, s_expr, left_required, right_required)
+                            .await
+                    }
                 }
             }
 
@@ -1320,6 +1324,7 @@ impl PhysicalPlanBuilder {
                         ))
                     }?,
                     default: new_default,
+                    ignore_null: lag_lead.ignore_null,
                 })
             }
 
@@ -1333,6 +1338,7 @@ impl PhysicalPlanBuilder {
                         "Window's nth_value function argument must be a BoundColumnRef".to_string(),
                     ))
                 }?,
+                ignore_null: func.ignore_null,
             }),
             WindowFuncType::Ntile(func) => WindowFunction::Ntile(NtileFunctionDesc {
                 n: func.n,
@@ -1353,6 +1359,7 @@ impl PhysicalPlanBuilder {
             partition_by: partition_items,
             order_by: order_by_items,
             window_frame: w.frame.clone(),
+            limit: w.limit,
         }))
     }
 
@@ -2047,6 +2054,8 @@ impl PhysicalPlanBuilder {
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
+            sample: scan.sample.clone(),
+            vector_top_k: scan.vector_top_k.clone(),
         })
     }
 
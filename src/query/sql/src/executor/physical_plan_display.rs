@@ -45,6 +45,7 @@ use crate::executor::PhysicalPlan;
 use crate::executor::Project;
 use crate::executor::ProjectSet;
 use crate::executor::RangeJoin;
+use crate::executor::RangeJoinType;
 use crate::executor::ReplaceInto;
 use crate::executor::RowFetch;
 use crate::executor::RuntimeFilterSource;
@@ -353,7 +354,10 @@ impl Display for HashJoin {
 
 impl Display for RangeJoin {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "IEJoin: {}", &self.join_type)
+        match self.range_join_type {
+            RangeJoinType::Asof => write!(f, "AsofJoin: {}", &self.join_type),
+            _ => write!(f, "IEJoin: {}", &self.join_type),
+        }
     }
 }
 
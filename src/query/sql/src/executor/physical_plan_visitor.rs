@@ -187,6 +187,7 @@ pub trait PhysicalPlanReplacer {
             partition_by: plan.partition_by.clone(),
             order_by: plan.order_by.clone(),
             window_frame: plan.window_frame.clone(),
+            limit: plan.limit,
         }))
     }
 
@@ -237,6 +238,8 @@ pub trait PhysicalPlanReplacer {
             right: Box::new(right),
             conditions: plan.conditions.clone(),
             other_conditions: plan.other_conditions.clone(),
+            left_keys: plan.left_keys.clone(),
+            right_keys: plan.right_keys.clone(),
             join_type: plan.join_type.clone(),
             range_join_type: plan.range_join_type.clone(),
             stat_info: plan.stat_info.clone(),
@@ -253,6 +253,7 @@ impl<'a> AggregateRewriter<'a> {
                             offset: ll.offset,
                             default: new_default,
                             return_type: ll.return_type.clone(),
+                            ignore_null: ll.ignore_null,
                         })
                     }
                     WindowFuncType::NthValue(func) => {
@@ -261,6 +262,7 @@ impl<'a> AggregateRewriter<'a> {
                             n: func.n,
                             arg: Box::new(new_arg),
                             return_type: func.return_type.clone(),
+                            ignore_null: func.ignore_null,
                         })
                     }
                     func => func.clone(),
@@ -23,6 +23,7 @@ use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_users::PubKey;
 use common_users::UserApiProvider;
 
 use crate::plans::AlterUserPlan;
@@ -159,9 +160,11 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        let auth_info = AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?;
+        check_public_keys(&auth_info)?;
         let plan = CreateUserPlan {
             user: user.clone(),
-            auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
+            auth_info,
             user_option,
             if_not_exists: *if_not_exists,
         };
@@ -176,6 +179,7 @@ impl Binder {
         let AlterUserStmt {
             user,
             auth_option,
+            public_key_options,
             user_options,
         } = stmt;
         // None means current user
@@ -187,18 +191,23 @@ impl Binder {
                 .await?
         };
 
-        // None means no change to make
-        let new_auth_info = if let Some(auth_option) = &auth_option {
-            let auth_info = user_info
+        let mut auth_info = if let Some(auth_option) = &auth_option {
+            user_info
                 .auth_info
-                .alter2(&auth_option.auth_type, &auth_option.password)?;
-            if user_info.auth_info == auth_info {
-                None
-            } else {
-                Some(auth_info)
-            }
+                .alter2(&auth_option.auth_type, &auth_option.password)?
         } else {
+            user_info.auth_info.clone()
+        };
+        for option in public_key_options {
+            auth_info = option.apply(&auth_info);
+        }
+        check_public_keys(&auth_info)?;
+
+        // None means no change to make
+        let new_auth_info = if user_info.auth_info == auth_info {
             None
+        } else {
+            Some(auth_info)
         };
 
         let mut user_option = user_info.option.clone();
@@ -219,3 +228,11 @@ impl Binder {
         Ok(Plan::AlterUser(Box::new(plan)))
     }
 }
+
+/// Make sure the public keys of a key pair user can be used to verify the JWT.
+fn check_public_keys(auth_info: &AuthInfo) -> Result<()> {
+    for public_key in auth_info.get_public_keys() {
+        PubKey::from_pem(public_key)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,305 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use common_ast::ast::CreateMaterializedViewStmt;
+use common_ast::ast::DropMaterializedViewStmt;
+use common_ast::ast::RefreshMaterializedViewStmt;
+use common_ast::ast::Statement;
+use common_ast::parser::parse_sql;
+use common_ast::parser::quote::quote_ident;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_ast::Visitor;
+use common_ast::VisitorMut;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+
+use crate::binder::Binder;
+use crate::plans::CreateMaterializedViewPlan;
+use crate::plans::DropTablePlan;
+use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
+use crate::MaterializedViewChecker;
+use crate::ViewRewriter;
+use crate::SUPPORTED_MATERIALIZED_VIEW_FUNCTIONS;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+
+        let mut checker = MaterializedViewChecker::default();
+        checker.visit_query(query);
+        if !checker.is_supported() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Currently materialized view just support simple query, like: {}, \
+                and these aggregate funcs: {}, each aggregate function must be a whole select item, \
+                all the group by items must be selected, \
+                and non-deterministic functions are not support like: NOW()",
+                "SELECT ... FROM ... WHERE ... GROUP BY ...",
+                SUPPORTED_MATERIALIZED_VIEW_FUNCTIONS.join(",")
+            )));
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        let mut query = *query.clone();
+        let mut visitor = ViewRewriter {
+            current_database: database.clone(),
+        };
+        visitor.visit_query(&mut query);
+
+        // Bind the query as an aggregating index query, so that it is not rewritten by
+        // other indexes or materialized views.
+        bind_context.planning_agg_index = true;
+        let (_, query_context) = self.bind_query(bind_context, &query).await?;
+        bind_context.planning_agg_index = false;
+
+        let tables = self.metadata.read().tables().to_vec();
+        if tables.len() != 1 {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view currently only support single table",
+            ));
+        }
+        let table_entry = &tables[0];
+        let table = table_entry.table();
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(format!(
+                "Materialized view only supports FUSE engine, but table `{}`.`{}` is {}",
+                table_entry.database(),
+                table_entry.name(),
+                table.engine()
+            )));
+        }
+
+        let mut names = HashSet::with_capacity(query_context.columns.len());
+        let mut fields = Vec::with_capacity(query_context.columns.len());
+        for column in query_context.columns.iter() {
+            if !names.insert(column.column_name.as_str()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Duplicated column name `{}` in materialized view, please add an alias for it",
+                    column.column_name
+                )));
+            }
+            fields.push(TableField::new(
+                &column.column_name,
+                infer_schema_type(&column.data_type)?,
+            ));
+        }
+
+        let subquery = query.to_string();
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            subquery.clone(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID.to_string(),
+            table.get_id().to_string(),
+        );
+        self.fill_fuse_table_options(&catalog, &database, &None, &mut options)
+            .await?;
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+            schema: TableSchemaRefExt::create(fields),
+            options,
+            subquery,
+            source_database: table_entry.database().to_string(),
+            source_table: table_entry.name().to_string(),
+            source_table_id: table.get_id(),
+        };
+        Ok(Plan::CreateMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        // Materialized view is stored as a table, make sure we don't drop a normal table.
+        if let Ok(table) = self.ctx.get_table(&catalog, &database, &view_name).await {
+            if !table
+                .options()
+                .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "`{}`.`{}` is not a materialized view",
+                    database, view_name
+                )));
+            }
+        }
+
+        let plan = DropTablePlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            table: view_name,
+        };
+        Ok(Plan::DropTable(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = self
+            .build_refresh_materialized_view_plan(catalog, database, view_name)
+            .await?;
+        Ok(Plan::RefreshMaterializedView(Box::new(plan)))
+    }
+
+    pub async fn build_refresh_materialized_view_plan(
+        &self,
+        catalog: String,
+        database: String,
+        view_name: String,
+    ) -> Result<RefreshMaterializedViewPlan> {
+        let table = self.ctx.get_table(&catalog, &database, &view_name).await?;
+        let options = table.options();
+        let query = options
+            .get(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "`{}`.`{}` is not a materialized view",
+                    database, view_name
+                ))
+            })?
+            .clone();
+        let source_table_id = options
+            .get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID)
+            .and_then(|id| id.parse::<u64>().ok())
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Invalid source table id of materialized view `{}`.`{}`",
+                    database, view_name
+                ))
+            })?;
+        let refreshed_snapshot = options
+            .get(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT)
+            .cloned();
+
+        let tokens = tokenize_sql(&query)?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        let mut checker = MaterializedViewChecker::default();
+        if let Statement::Query(query) = &stmt {
+            checker.visit_query(query);
+        }
+        if !checker.is_supported() {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid query of materialized view `{}`.`{}`",
+                database, view_name
+            )));
+        }
+
+        // The rows of an aggregating materialized view are the final aggregated values,
+        // the new rows are merged into them by aggregating again with the merge functions,
+        // e.g. `COUNT` is merged by `SUM`.
+        let merge_query = if checker.is_aggregating() {
+            let schema = table.schema();
+            let names = schema
+                .fields()
+                .iter()
+                .map(|field| quote_ident(field.name(), '`', true))
+                .collect::<Vec<_>>();
+            let select_list = names
+                .iter()
+                .zip(checker.merge_functions.iter())
+                .map(|(name, merge_function)| match merge_function {
+                    Some(func) => format!("{func}({name}) AS {name}"),
+                    None => name.clone(),
+                })
+                .collect::<Vec<_>>();
+            let group_items = names
+                .iter()
+                .zip(checker.merge_functions.iter())
+                .filter(|(_, merge_function)| merge_function.is_none())
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>();
+            let mut merge_query = format!(
+                "SELECT {} FROM (SELECT * FROM `{}`.`{}`.`{}` UNION ALL {}) AS _mv({})",
+                select_list.join(", "),
+                catalog,
+                database,
+                view_name,
+                query,
+                names.join(", ")
+            );
+            if !group_items.is_empty() {
+                merge_query.push_str(&format!(" GROUP BY {}", group_items.join(", ")));
+            }
+            Some(merge_query)
+        } else {
+            None
+        };
+
+        Ok(RefreshMaterializedViewPlan {
+            catalog,
+            database,
+            view_name,
+            table_info: table.get_table_info().clone(),
+            source_table_id,
+            refreshed_snapshot,
+            query,
+            merge_query,
+        })
+    }
+}
@@ -18,10 +18,14 @@ mod column;
 mod data_mask;
 mod database;
 mod index;
+mod materialized_view;
 mod network_policy;
 mod role;
+mod sequence;
 mod share;
 mod stage;
 mod table;
+mod task;
+mod vector_index;
 mod view;
 mod virtual_column;
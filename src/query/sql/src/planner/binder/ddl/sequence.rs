@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_ast::ast::CreateSequenceStmt;
+use common_ast::ast::DropSequenceStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::CreateSequencePlan;
+use crate::plans::DropSequencePlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_sequence(
+        &mut self,
+        stmt: &CreateSequenceStmt,
+    ) -> Result<Plan> {
+        let CreateSequenceStmt {
+            if_not_exists,
+            sequence,
+            start,
+            increment,
+            comment,
+        } = stmt;
+
+        let step = increment.unwrap_or(1);
+        if step == 0 {
+            return Err(ErrorCode::SemanticError(
+                "INCREMENT BY of a sequence must be greater than 0".to_string(),
+            ));
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateSequencePlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            sequence: normalize_identifier(sequence, &self.name_resolution_ctx).name,
+            start: start.unwrap_or(1),
+            step,
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateSequence(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_sequence(
+        &mut self,
+        stmt: &DropSequenceStmt,
+    ) -> Result<Plan> {
+        let DropSequenceStmt {
+            if_exists,
+            sequence,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropSequencePlan {
+            if_exists: *if_exists,
+            tenant,
+            sequence: normalize_identifier(sequence, &self.name_resolution_ctx).name,
+        };
+        Ok(Plan::DropSequence(Box::new(plan)))
+    }
+}
@@ -54,6 +54,8 @@ use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Dialect;
+use common_base::base::GlobalUniqName;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -68,6 +70,8 @@ use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::GetSequenceReq;
+use common_meta_app::schema::SequenceNameIdent;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
 use common_storages_view::view_table::QUERY;
@@ -97,6 +101,7 @@ use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
@@ -124,6 +129,9 @@ use crate::BindContext;
 use crate::Planner;
 use crate::SelectBuilder;
 
+/// Name prefix of the sequences backing the AUTOINCREMENT columns.
+const AUTO_INCREMENT_SEQUENCE_PREFIX: &str = "_autoincrement_";
+
 impl Binder {
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_show_tables(
@@ -388,6 +396,7 @@ impl Binder {
             cluster_by,
             as_query,
             transient,
+            temporary,
             engine,
             uri_location,
         } = stmt;
@@ -397,6 +406,30 @@ impl Binder {
 
         // Take FUSE engine AS default engine
         let engine = engine.unwrap_or(Engine::Fuse);
+        if *temporary {
+            // temporary tables are stored under the session private prefix of the default storage
+            if !matches!(engine, Engine::Fuse | Engine::Memory) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Temporary table only supports FUSE and MEMORY engine, but got {engine}"
+                )));
+            }
+            if uri_location.is_some() {
+                return Err(ErrorCode::SemanticError(
+                    "Temporary table can not be created with an external location",
+                ));
+            }
+            if !catalog.eq_ignore_ascii_case(CATALOG_DEFAULT) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Temporary table can only be created in the default catalog, but got {catalog}"
+                )));
+            }
+            // make sure the database exists, as the temporary table is not created by the meta service
+            self.ctx
+                .get_catalog(&catalog)
+                .await?
+                .get_database(&self.ctx.get_tenant(), &database)
+                .await?;
+        }
         let mut options: BTreeMap<String, String> = BTreeMap::new();
         for table_option in table_options.iter() {
             self.insert_table_option_with_validation(
@@ -438,7 +471,7 @@ impl Binder {
         }
 
         // Build table schema
-        let (schema, field_comments) = match (&source, &as_query) {
+        let (schema, field_comments, auto_increment_sequences) = match (&source, &as_query) {
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 self.analyze_create_table_schema(source).await?
@@ -459,11 +492,11 @@ impl Binder {
                     .collect::<Result<Vec<_>>>()?;
                 let schema = TableSchemaRefExt::create(fields);
                 Self::validate_create_table_schema(&schema)?;
-                (schema, vec![])
+                (schema, vec![], vec![])
             }
             (Some(source), Some(query)) => {
                 // e.g. `CREATE TABLE t (i INT) AS SELECT * from old_t` with columns specified
-                let (source_schema, source_comments) =
+                let (source_schema, source_comments, source_sequences) =
                     self.analyze_create_table_schema(source).await?;
                 let mut init_bind_context = BindContext::new();
                 let (_, bind_context) = self.bind_query(&mut init_bind_context, query).await?;
@@ -481,7 +514,7 @@ impl Binder {
                     return Err(ErrorCode::BadArguments("Number of columns does not match"));
                 }
                 Self::validate_create_table_schema(&source_schema)?;
-                (source_schema, source_comments)
+                (source_schema, source_comments, source_sequences)
             }
             _ => Err(ErrorCode::BadArguments(
                 "Incorrect CREATE query: required list of column descriptions or AS section or SELECT..",
@@ -499,61 +532,8 @@ impl Binder {
         }
 
         if engine == Engine::Fuse {
-            // Currently, [Table] can not accesses its database id yet, thus
-            // here we keep the db id AS an entry of `table_meta.options`.
-            //
-            // To make the unit/stateless test cases (`show create ..`) easier,
-            // here we care about the FUSE engine only.
-            //
-            // Later, when database id is kept, let say in `TableInfo`, we can
-            // safely eliminate this "FUSE" constant and the table meta option entry.
-            let catalog = self.ctx.get_catalog(&catalog).await?;
-            let db = catalog
-                .get_database(&self.ctx.get_tenant(), &database)
+            self.fill_fuse_table_options(&catalog, &database, &storage_params, &mut options)
                 .await?;
-            let db_id = db.get_db_info().ident.db_id;
-            options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
-
-            let config = GlobalConfig::instance();
-            let is_blocking_fs = matches!(
-                storage_params.as_ref().unwrap_or(&config.storage.params),
-                StorageParams::Fs(_)
-            );
-
-            // we should persist the storage format and compression type instead of using the default value in fuse table
-            if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
-                let default_storage_format = match config.query.default_storage_format.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "native"
-                        } else {
-                            "parquet"
-                        }
-                    }
-                    _ => config.query.default_storage_format.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_STORAGE_FORMAT.to_owned(),
-                    default_storage_format.to_owned(),
-                );
-            }
-
-            if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
-                let default_compression = match config.query.default_compression.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "lz4"
-                        } else {
-                            "zstd"
-                        }
-                    }
-                    _ => config.query.default_compression.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_TABLE_COMPRESSION.to_owned(),
-                    default_compression.to_owned(),
-                );
-            }
         }
 
         let cluster_key = {
@@ -569,6 +549,7 @@ impl Binder {
 
         let plan = CreateTablePlan {
             if_not_exists: *if_not_exists,
+            temporary: *temporary,
             tenant: self.ctx.get_tenant(),
             catalog: catalog.clone(),
             database: database.clone(),
@@ -580,6 +561,7 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
+            auto_increment_sequences,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -595,6 +577,75 @@ impl Binder {
         Ok(Plan::CreateTable(Box::new(plan)))
     }
 
+    /// Fills the options which are kept in the table meta of a new FUSE table,
+    /// e.g. the database id, storage format and compression.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn fill_fuse_table_options(
+        &self,
+        catalog: &str,
+        database: &str,
+        storage_params: &Option<StorageParams>,
+        options: &mut BTreeMap<String, String>,
+    ) -> Result<()> {
+        // Currently, [Table] can not accesses its database id yet, thus
+        // here we keep the db id AS an entry of `table_meta.options`.
+        //
+        // To make the unit/stateless test cases (`show create ..`) easier,
+        // here we care about the FUSE engine only.
+        //
+        // Later, when database id is kept, let say in `TableInfo`, we can
+        // safely eliminate this "FUSE" constant and the table meta option entry.
+        let catalog = self.ctx.get_catalog(catalog).await?;
+        let db = catalog
+            .get_database(&self.ctx.get_tenant(), database)
+            .await?;
+        let db_id = db.get_db_info().ident.db_id;
+        options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+
+        let config = GlobalConfig::instance();
+        let is_blocking_fs = matches!(
+            storage_params.as_ref().unwrap_or(&config.storage.params),
+            StorageParams::Fs(_)
+        );
+
+        // we should persist the storage format and compression type instead of using the default value in fuse table
+        if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
+            let default_storage_format = match config.query.default_storage_format.as_str() {
+                "" | "auto" => {
+                    if is_blocking_fs {
+                        "native"
+                    } else {
+                        "parquet"
+                    }
+                }
+                _ => config.query.default_storage_format.as_str(),
+            };
+            options.insert(
+                OPT_KEY_STORAGE_FORMAT.to_owned(),
+                default_storage_format.to_owned(),
+            );
+        }
+
+        if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
+            let default_compression = match config.query.default_compression.as_str() {
+                "" | "auto" => {
+                    if is_blocking_fs {
+                        "lz4"
+                    } else {
+                        "zstd"
+                    }
+                }
+                _ => config.query.default_compression.as_str(),
+            };
+            options.insert(
+                OPT_KEY_TABLE_COMPRESSION.to_owned(),
+                default_compression.to_owned(),
+            );
+        }
+
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_attach_table(
         &mut self,
@@ -641,6 +692,7 @@ impl Binder {
 
         Ok(Plan::CreateTable(Box::new(CreateTablePlan {
             if_not_exists: false,
+            temporary: false,
             tenant: self.ctx.get_tenant(),
             catalog,
             database,
@@ -653,6 +705,7 @@ impl Binder {
             field_comments: vec![],
             storage_params: Some(sp),
             part_prefix,
+            auto_increment_sequences: vec![],
         })))
     }
 
@@ -1219,6 +1272,11 @@ impl Binder {
                         "can't add a stored computed column".to_string(),
                     ));
                 }
+                ColumnExpr::AutoIncrement { .. } => {
+                    return Err(ErrorCode::SemanticError(
+                        "can't add an AUTOINCREMENT column to an existing table".to_string(),
+                    ));
+                }
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -1229,10 +1287,11 @@ impl Binder {
     async fn analyze_create_table_schema_by_columns(
         &self,
         columns: &[ColumnDefinition],
-    ) -> Result<(TableSchemaRef, Vec<String>)> {
+    ) -> Result<(TableSchemaRef, Vec<String>, Vec<CreateSequencePlan>)> {
         let mut has_computed = false;
         let mut fields = Vec::with_capacity(columns.len());
         let mut fields_comments = Vec::with_capacity(columns.len());
+        let mut auto_increment_sequences = vec![];
         for column in columns.iter() {
             let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
             let not_null = self.is_column_not_null(column)?;
@@ -1251,6 +1310,27 @@ impl Binder {
                         )?;
                         field = field.with_default_expr(Some(expr));
                     }
+                    ColumnExpr::AutoIncrement { start, step } => {
+                        if !DataType::from(&schema_data_type)
+                            .remove_nullable()
+                            .is_integer()
+                        {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "AUTOINCREMENT column '{}' must be of an integer type, but got {}",
+                                name, schema_data_type
+                            )));
+                        }
+                        if *step == 0 {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "the increment of AUTOINCREMENT column '{}' must be greater than 0",
+                                name
+                            )));
+                        }
+                        let sequence = self.auto_increment_sequence(*start, *step);
+                        field = field
+                            .with_default_expr(Some(format!("nextval('{}')", sequence.sequence)));
+                        auto_increment_sequences.push(sequence);
+                    }
                     _ => has_computed = true,
                 }
             }
@@ -1303,14 +1383,14 @@ impl Binder {
 
         let schema = TableSchemaRefExt::create(fields);
         Self::validate_create_table_schema(&schema)?;
-        Ok((schema, fields_comments))
+        Ok((schema, fields_comments, auto_increment_sequences))
     }
 
     #[async_backtrace::framed]
     async fn analyze_create_table_schema(
         &self,
         source: &CreateTableSource,
-    ) -> Result<(TableSchemaRef, Vec<String>)> {
+    ) -> Result<(TableSchemaRef, Vec<String>, Vec<CreateSequencePlan>)> {
         match source {
             CreateTableSource::Columns(columns) => {
                 self.analyze_create_table_schema_by_columns(columns).await
@@ -1328,19 +1408,73 @@ impl Binder {
                     if let Some(query) = table.get_table_info().options().get(QUERY) {
                         let mut planner = Planner::new(self.ctx.clone());
                         let (plan, _) = planner.plan_sql(query).await?;
-                        Ok((infer_table_schema(&plan.schema())?, vec![]))
+                        Ok((infer_table_schema(&plan.schema())?, vec![], vec![]))
                     } else {
                         Err(ErrorCode::Internal(
                             "Logical error, View Table must have a SelectQuery inside.",
                         ))
                     }
                 } else {
-                    Ok((table.schema(), table.field_comments().clone()))
+                    // the AUTOINCREMENT columns get sequences of their own
+                    let mut fields = Vec::with_capacity(table.schema().num_fields());
+                    let mut auto_increment_sequences = vec![];
+                    for field in table.schema().fields() {
+                        let sequence_name = field
+                            .default_expr()
+                            .and_then(|expr| expr.strip_prefix("nextval('")?.strip_suffix("')"))
+                            .filter(|name| name.starts_with(AUTO_INCREMENT_SEQUENCE_PREFIX));
+                        match sequence_name {
+                            Some(sequence_name) => {
+                                let catalog = self.ctx.get_catalog(CATALOG_DEFAULT).await?;
+                                let meta = catalog
+                                    .get_sequence(GetSequenceReq {
+                                        name_ident: SequenceNameIdent::new(
+                                            self.ctx.get_tenant(),
+                                            sequence_name,
+                                        ),
+                                    })
+                                    .await?
+                                    .meta;
+                                let sequence = self.auto_increment_sequence(meta.start, meta.step);
+                                fields.push(field.clone().with_default_expr(Some(format!(
+                                    "nextval('{}')",
+                                    sequence.sequence
+                                ))));
+                                auto_increment_sequences.push(sequence);
+                            }
+                            None => fields.push(field.clone()),
+                        }
+                    }
+                    let schema = match auto_increment_sequences.is_empty() {
+                        true => table.schema(),
+                        false => TableSchemaRefExt::create(fields),
+                    };
+                    Ok((
+                        schema,
+                        table.field_comments().clone(),
+                        auto_increment_sequences,
+                    ))
                 }
             }
         }
     }
 
+    /// Each AUTOINCREMENT column is backed by a sequence of its own, the values are
+    /// filled in by the default expression of the column.
+    fn auto_increment_sequence(&self, start: u64, step: u64) -> CreateSequencePlan {
+        CreateSequencePlan {
+            if_not_exists: false,
+            tenant: self.ctx.get_tenant(),
+            sequence: format!(
+                "{AUTO_INCREMENT_SEQUENCE_PREFIX}{}",
+                GlobalUniqName::unique()
+            ),
+            start,
+            step,
+            comment: None,
+        }
+    }
+
     /// Validate the schema of the table to be created.
     fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
         // Check if there are duplicated column names
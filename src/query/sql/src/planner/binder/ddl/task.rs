@@ -0,0 +1,162 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use common_ast::ast::*;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::SqlTaskDefinition;
+
+use crate::binder::Binder;
+use crate::plans::AlterTaskPlan;
+use crate::plans::CreateTaskPlan;
+use crate::plans::DropTaskPlan;
+use crate::plans::ExecuteTaskPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_task(
+        &mut self,
+        stmt: &CreateTaskStmt,
+    ) -> Result<Plan> {
+        let CreateTaskStmt {
+            if_not_exists,
+            name,
+            warehouse,
+            schedule,
+            after,
+            comment,
+            sql,
+        } = stmt;
+
+        if after.contains(name) {
+            return Err(ErrorCode::SemanticError(format!(
+                "task {} can not run after itself",
+                name
+            )));
+        }
+
+        // only check the syntax here, the objects referenced by the task may not exist yet.
+        let tokens = tokenize_sql(sql)?;
+        let (task_stmt, _) = parse_sql(&tokens, self.ctx.get_settings().get_sql_dialect()?)?;
+        if matches!(
+            task_stmt,
+            Statement::CreateTask(_)
+                | Statement::AlterTask(_)
+                | Statement::DropTask(_)
+                | Statement::ExecuteTask(_)
+        ) {
+            return Err(ErrorCode::SemanticError(
+                "task statements are not allowed in the SQL of task",
+            ));
+        }
+
+        let job_params = match schedule {
+            None => BackgroundJobParams::new_one_shot_job(),
+            Some(TaskSchedule::Interval(secs)) => {
+                if *secs == 0 {
+                    return Err(ErrorCode::SemanticError(
+                        "the schedule interval of task must be greater than 0",
+                    ));
+                }
+                BackgroundJobParams::new_interval_job(std::time::Duration::from_secs(*secs))
+            }
+            Some(TaskSchedule::Cron(expr)) => {
+                // the standard cron expression has no seconds field, run at the 0th second.
+                let expr = match expr.split_whitespace().count() {
+                    5 => format!("0 {}", expr.trim()),
+                    _ => expr.trim().to_string(),
+                };
+                if !BackgroundJobParams::is_valid_cron(&expr) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "invalid cron expression '{}'",
+                        expr
+                    )));
+                }
+                let tz = self.ctx.get_settings().get_timezone()?;
+                let tz = chrono_tz::Tz::from_str(&tz).map_err(|e| {
+                    ErrorCode::InvalidTimezone(format!("invalid timezone {}: {}", tz, e))
+                })?;
+                BackgroundJobParams::new_cron_job(expr, Some(tz))
+            }
+        };
+
+        let plan = CreateTaskPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            job_params,
+            definition: SqlTaskDefinition {
+                query_text: sql.clone(),
+                database: self.ctx.get_current_database(),
+                warehouse: warehouse.clone(),
+                after: after.clone(),
+                comment: comment.clone(),
+            },
+        };
+        Ok(Plan::CreateTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_task(
+        &mut self,
+        stmt: &AlterTaskStmt,
+    ) -> Result<Plan> {
+        let AlterTaskStmt {
+            if_exists,
+            name,
+            action,
+        } = stmt;
+
+        let plan = AlterTaskPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            action: action.clone(),
+        };
+        Ok(Plan::AlterTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_task(
+        &mut self,
+        stmt: &DropTaskStmt,
+    ) -> Result<Plan> {
+        let DropTaskStmt { if_exists, name } = stmt;
+
+        let plan = DropTaskPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+        };
+        Ok(Plan::DropTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_execute_task(
+        &mut self,
+        stmt: &ExecuteTaskStmt,
+    ) -> Result<Plan> {
+        let plan = ExecuteTaskPlan {
+            tenant: self.ctx.get_tenant(),
+            name: stmt.name.to_string(),
+        };
+        Ok(Plan::ExecuteTask(Box::new(plan)))
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_ast::ast::CreateVectorIndexStmt;
+use common_ast::ast::DropVectorIndexStmt;
+use common_ast::ast::RefreshVectorIndexStmt;
+use common_ast::ast::VectorIndexType as AstVectorIndexType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use storages_common_table_meta::table::VectorIndexDistance;
+use storages_common_table_meta::table::VectorIndexType;
+
+use crate::binder::Binder;
+use crate::plans::CreateVectorIndexPlan;
+use crate::plans::DropVectorIndexPlan;
+use crate::plans::Plan;
+use crate::plans::RefreshVectorIndexPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_vector_index(
+        &mut self,
+        stmt: &CreateVectorIndexStmt,
+    ) -> Result<Plan> {
+        let CreateVectorIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            column,
+            index_type,
+            options,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+        let column_name = self.normalize_object_identifier(column);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Vector index only support FUSE engine",
+            ));
+        }
+        let schema = table_info.schema();
+        let field = schema.field_with_name(&column_name)?;
+        let expected =
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32)));
+        if field.data_type().remove_nullable() != expected {
+            return Err(ErrorCode::SemanticError(format!(
+                "Vector index only support Array(Float32) column, but column {} is {}",
+                column_name,
+                field.data_type()
+            )));
+        }
+
+        let index_type = match index_type {
+            AstVectorIndexType::Hnsw => VectorIndexType::Hnsw,
+            AstVectorIndexType::IvfPq => VectorIndexType::IvfPq,
+        };
+        let mut distance = VectorIndexDistance::Cosine;
+        let mut index_options = BTreeMap::new();
+        for (key, value) in options {
+            if key == "distance" {
+                distance = VectorIndexDistance::try_from(value.as_str())?;
+            } else if index_type.option_names().contains(&key.as_str()) {
+                let value = value.parse::<u64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "vector index option {key} expects a positive integer, got {value}"
+                    ))
+                })?;
+                index_options.insert(key.clone(), value);
+            } else {
+                return Err(ErrorCode::BadArguments(format!(
+                    "unknown {index_type} index option {key}, expect one of: distance, {}",
+                    index_type.option_names().join(", ")
+                )));
+            }
+        }
+
+        Ok(Plan::CreateVectorIndex(Box::new(CreateVectorIndexPlan {
+            if_not_exists: *if_not_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+            column_id: field.column_id(),
+            index_type,
+            distance,
+            options: index_options,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_vector_index(
+        &mut self,
+        stmt: &DropVectorIndexStmt,
+    ) -> Result<Plan> {
+        let DropVectorIndexStmt {
+            if_exists,
+            index,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index);
+
+        Ok(Plan::DropVectorIndex(Box::new(DropVectorIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_vector_index(
+        &mut self,
+        stmt: &RefreshVectorIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshVectorIndexStmt {
+            index,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index);
+
+        Ok(Plan::RefreshVectorIndex(Box::new(RefreshVectorIndexPlan {
+            catalog,
+            database,
+            table,
+            index_name,
+        })))
+    }
+}
@@ -82,6 +82,20 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::AsofJoin | JoinOperator::LeftAsofJoin
+                if join.match_condition.is_none() =>
+            {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain match condition".to_string(),
+                ));
+            }
+            JoinOperator::AsofJoin | JoinOperator::LeftAsofJoin
+                if join.condition == JoinCondition::Natural =>
+            {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should not be natural join".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -110,6 +124,17 @@ impl Binder {
                 &join.op,
             )
             .await?;
+        if let Some(match_condition) = &join.match_condition {
+            if !non_equi_conditions.is_empty() {
+                return Err(ErrorCode::SemanticError(
+                    "join condition of asof join should only contain equi-conditions".to_string(),
+                ));
+            }
+            let match_condition = join_condition_resolver
+                .resolve_match_condition(match_condition)
+                .await?;
+            non_equi_conditions.push(match_condition);
+        }
 
         let join_conditions = JoinConditions {
             left_conditions: left_join_conditions,
@@ -170,6 +195,15 @@ impl Binder {
                     right_child,
                 )
             }
+            JoinOperator::AsofJoin => {
+                self.bind_join_with_type(JoinType::Asof, join_conditions, left_child, right_child)
+            }
+            JoinOperator::LeftAsofJoin => self.bind_join_with_type(
+                JoinType::LeftAsof,
+                join_conditions,
+                left_child,
+                right_child,
+            ),
         }?;
         Ok((s_expr, bind_context))
     }
@@ -293,7 +327,7 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter | JoinOperator::LeftAsofJoin => {
             for column in left_context.all_column_bindings() {
                 bind_context.add_column_binding(column.clone());
             }
@@ -514,8 +548,13 @@ impl<'a> JoinConditionResolver<'a> {
         other_join_conditions: &mut Vec<ScalarExpr>,
     ) -> Result<()> {
         let mut join_context = (*self.join_context).clone();
+        // Conditions of asof join are evaluated before the unmatched rows are padded with nulls.
+        let join_op = match self.join_op {
+            JoinOperator::LeftAsofJoin => JoinOperator::AsofJoin,
+            ref join_op => join_op.clone(),
+        };
         wrap_nullable_for_column(
-            &self.join_op,
+            &join_op,
             self.left_context,
             self.right_context,
             &mut join_context,
@@ -715,6 +754,49 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(false)
     }
 
+    // The match condition of asof join must be a comparison (>, >=, <, <=),
+    // whose one side only contains left columns and the other side only contains right columns.
+    #[async_backtrace::framed]
+    async fn resolve_match_condition(&self, match_condition: &Expr) -> Result<ScalarExpr> {
+        let mut join_context = (*self.join_context).clone();
+        wrap_nullable_for_column(
+            &JoinOperator::AsofJoin,
+            self.left_context,
+            self.right_context,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (scalar, _) = scalar_binder.bind(match_condition).await?;
+        let (left_columns, right_columns) = self.left_right_columns()?;
+        if let ScalarExpr::FunctionCall(func) = &scalar {
+            if func.arguments.len() == 2
+                && matches!(func.func_name.as_str(), "gt" | "lt" | "gte" | "lte")
+            {
+                let first = func.arguments[0].used_columns();
+                let second = func.arguments[1].used_columns();
+                if !first.is_empty()
+                    && !second.is_empty()
+                    && ((first.is_subset(&left_columns) && second.is_subset(&right_columns))
+                        || (first.is_subset(&right_columns) && second.is_subset(&left_columns)))
+                {
+                    return Ok(scalar);
+                }
+            }
+        }
+        Err(ErrorCode::SemanticError(
+            "match condition of asof join should be a comparison (>, >=, <, <=) between left table and right table".to_string(),
+        )
+        .set_span(match_condition.span()))
+    }
+
     fn left_right_columns(&self) -> Result<(ColumnSet, ColumnSet)> {
         let left_columns: ColumnSet =
             self.left_context
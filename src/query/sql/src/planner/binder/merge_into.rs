@@ -118,6 +118,7 @@ impl Binder {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         // get_source_table_reference
@@ -225,6 +226,7 @@ impl Binder {
         let join = Join {
             op: LeftOuter,
             condition: JoinCondition::On(Box::new(join_expr.clone())),
+            match_condition: None,
             left: Box::new(source_data.clone()),
             right: Box::new(target_table),
         };
@@ -36,7 +36,9 @@ mod merge_into;
 mod presign;
 mod project;
 mod project_set;
+mod qualify;
 mod replace;
+mod sample;
 mod scalar;
 mod scalar_common;
 mod scalar_visitor;
@@ -18,7 +18,8 @@ use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
-use common_ast::ast::Window;
+use common_ast::ast::OrderByExpr;
+use common_ast::ast::WindowDesc;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -52,7 +53,9 @@ impl<'a> Visitor<'a> for SrfCollector {
         name: &'a Identifier,
         args: &'a [Expr],
         params: &'a [Literal],
-        over: &'a Option<Window>,
+        within_group: &'a [OrderByExpr],
+        filter: &'a Option<Box<Expr>>,
+        over: &'a Option<WindowDesc>,
         lambda: &'a Option<Lambda>,
     ) {
         if BUILTIN_FUNCTIONS
@@ -67,6 +70,8 @@ impl<'a> Visitor<'a> for SrfCollector {
                 name: name.clone(),
                 args: args.to_vec(),
                 params: params.to_vec(),
+                within_group: within_group.to_vec(),
+                filter: filter.clone(),
                 window: over.clone(),
                 lambda: lambda.clone(),
             });
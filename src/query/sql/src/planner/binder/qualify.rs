@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+
+use crate::binder::aggregate::AggregateRewriter;
+use crate::binder::split_conjunctions;
+use crate::binder::window::WindowRewriter;
+use crate::binder::ExprContext;
+use crate::binder::ScalarBinder;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::GroupingChecker;
+use crate::plans::Filter;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+use crate::Binder;
+use crate::WindowChecker;
+
+impl Binder {
+    /// Analyze window functions in qualify clause, this will rewrite aggregate functions
+    /// and window functions. See `AggregateRewriter` and `WindowRewriter` for more details.
+    #[async_backtrace::framed]
+    pub async fn analyze_window_qualify(
+        &mut self,
+        bind_context: &mut BindContext,
+        aliases: &[(String, ScalarExpr)],
+        qualify: &Expr,
+    ) -> Result<(ScalarExpr, Span)> {
+        bind_context.set_expr_context(ExprContext::QualifyClause);
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            aliases,
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (scalar, _) = scalar_binder.bind(qualify).await?;
+        let mut rewriter = AggregateRewriter::new(bind_context, self.metadata.clone());
+        let scalar = rewriter.visit(&scalar)?;
+        let mut rewriter = WindowRewriter::new(bind_context, self.metadata.clone());
+        let scalar = rewriter.visit(&scalar)?;
+
+        // Same as Snowflake, a window function is required in either
+        // the select list or the qualify clause.
+        if bind_context.windows.window_functions.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "Qualify clause must refer to window functions".to_string(),
+            )
+            .set_span(qualify.span()));
+        }
+
+        Ok((scalar, qualify.span()))
+    }
+
+    /// Qualify is evaluated after window functions, it filters the rows
+    /// just like `HAVING` does for aggregate functions.
+    #[async_backtrace::framed]
+    pub async fn bind_qualify(
+        &mut self,
+        bind_context: &mut BindContext,
+        qualify: ScalarExpr,
+        span: Span,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        bind_context.set_expr_context(ExprContext::QualifyClause);
+
+        let scalar = if bind_context.in_grouping {
+            let grouping_checker = GroupingChecker::new(bind_context);
+            grouping_checker.resolve(&qualify, span)?
+        } else {
+            let window_checker = WindowChecker::new(bind_context);
+            window_checker.resolve(&qualify)?
+        };
+
+        let predicates = split_conjunctions(&scalar);
+
+        let filter = Filter { predicates };
+
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(child),
+        ))
+    }
+}
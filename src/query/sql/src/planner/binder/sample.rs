@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_ast::ast::SampleConfig;
+use common_ast::ast::SampleCriteria;
+use common_ast::ast::SampleLevel as AstSampleLevel;
+use common_catalog::plan::InternalColumn;
+use common_catalog::plan::InternalColumnType;
+use common_catalog::plan::SampleInfo;
+use common_catalog::plan::SampleLevel;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::SAMPLE_HASH_COL_NAME;
+use ordered_float::OrderedFloat;
+
+use crate::optimizer::SExpr;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::Binder;
+use crate::IndexType;
+
+impl Binder {
+    /// Attach `SAMPLE` / `TABLESAMPLE` of a base table to its `Scan`.
+    ///
+    /// `n ROWS` is converted into a probability by the number of rows in
+    /// table statistics. The probability is raised by a margin so that the
+    /// sample rarely falls short, and the sampled rows are cut down to the `n`
+    /// rows with the smallest sample hashes by a sort with limit. Rows are
+    /// sampled by the same hash, so the result only depends on the seed and
+    /// the data, not on how the scan is split among threads.
+    pub(super) fn bind_table_sample(
+        &self,
+        span: Span,
+        s_expr: SExpr,
+        table_index: IndexType,
+        sample: &SampleConfig,
+    ) -> Result<SExpr> {
+        let mut scan: Scan = s_expr.plan().clone().try_into()?;
+
+        let level = match sample.level {
+            AstSampleLevel::Row => SampleLevel::Row,
+            AstSampleLevel::Block => SampleLevel::Block,
+        };
+        let mut limit = None;
+        let probability = match sample.criteria {
+            SampleCriteria::Percentage(percent) => {
+                if !(0.0..=100.0).contains(&percent) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Sample percentage must be between 0 and 100, but got {percent}"
+                    ))
+                    .set_span(span));
+                }
+                percent / 100.0
+            }
+            SampleCriteria::Rows(rows) => {
+                if level == SampleLevel::Block {
+                    return Err(ErrorCode::SemanticError(
+                        "Block sampling only supports a percentage",
+                    )
+                    .set_span(span));
+                }
+                let num_rows = scan
+                    .statistics
+                    .statistics
+                    .as_ref()
+                    .and_then(|s| s.num_rows)
+                    .unwrap_or(0);
+                limit = Some(rows as usize);
+                if num_rows == 0 {
+                    1.0
+                } else {
+                    // Oversample by a few standard deviations of the binomial
+                    // distribution, the surplus is cut off by the limit.
+                    let wanted = rows as f64 + 4.0 * (rows as f64).sqrt() + 16.0;
+                    (wanted / num_rows as f64).min(1.0)
+                }
+            }
+        };
+
+        // Without `REPEATABLE`, every query gets its own sample.
+        let seed = match sample.seed {
+            Some(seed) => seed,
+            None => {
+                let mut hasher = DefaultHasher::new();
+                self.ctx.get_id().hash(&mut hasher);
+                table_index.hash(&mut hasher);
+                hasher.finish()
+            }
+        };
+
+        scan.sample = Some(SampleInfo {
+            level,
+            probability: OrderedFloat(probability),
+            seed,
+        });
+        let Some(limit) = limit else {
+            return Ok(SExpr::create_leaf(Arc::new(scan.into())));
+        };
+
+        let hash_index = self.metadata.write().add_internal_column(
+            table_index,
+            InternalColumn::new(SAMPLE_HASH_COL_NAME, InternalColumnType::SampleHash),
+        );
+        scan.columns.insert(hash_index);
+        let sort_plan = Sort {
+            items: vec![SortItem {
+                index: hash_index,
+                asc: true,
+                nulls_first: false,
+            }],
+            limit: Some(limit),
+            after_exchange: false,
+            pre_projection: None,
+        };
+        let s_expr = SExpr::create_unary(
+            Arc::new(sort_plan.into()),
+            Arc::new(SExpr::create_leaf(Arc::new(scan.into()))),
+        );
+        Ok(Self::bind_limit(s_expr, Some(limit), 0))
+    }
+}
@@ -233,7 +233,10 @@ pub fn wrap_cast_scalar(
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Date
+            | DataType::Interval
+            | DataType::TimestampTz
             | DataType::Bitmap
+            | DataType::Geometry
             | DataType::Variant => wrap_cast(scalar, target_type),
             DataType::String => {
                 // parse string to JSON value
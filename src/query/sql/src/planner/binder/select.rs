@@ -126,6 +126,7 @@ impl Binder {
                     join: Join {
                         op: JoinOperator::CrossJoin,
                         condition: JoinCondition::None,
+                        match_condition: None,
                         left: Box::new(left),
                         right: Box::new(right),
                     },
@@ -213,6 +214,16 @@ impl Binder {
             None
         };
 
+        // Window functions in `QUALIFY` clause should be analyzed before binding windows.
+        let qualify = if let Some(qualify) = &stmt.qualify {
+            Some(
+                self.analyze_window_qualify(&mut from_context, &aliases, qualify)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
         let order_items = self
             .analyze_order_items(
                 &mut from_context,
@@ -260,6 +271,12 @@ impl Binder {
             s_expr = self.bind_window_function(window_info, s_expr).await?;
         }
 
+        if let Some((qualify, span)) = qualify {
+            s_expr = self
+                .bind_qualify(&mut from_context, qualify, span, s_expr)
+                .await?;
+        }
+
         if stmt.distinct {
             s_expr = self.bind_distinct(
                 stmt.span,
@@ -796,6 +813,7 @@ impl Binder {
         // SELECT ... FROM t WHERE ... ORDER BY ... LIMIT ...
         if stmt.group_by.is_some()
             || stmt.having.is_some()
+            || stmt.qualify.is_some()
             || stmt.distinct
             || !bind_context.aggregate_info.group_items.is_empty()
             || !bind_context.aggregate_info.aggregate_functions.is_empty()
@@ -951,6 +969,8 @@ impl<'a> SelectRewriter<'a> {
                 name,
                 args,
                 params: vec![],
+                within_group: vec![],
+                filter: None,
                 window: None,
                 lambda: None,
             }),
@@ -38,6 +38,8 @@ use indexmap::IndexMap;
 
 use crate::binder::wrap_cast;
 use crate::binder::wrap_cast_scalar;
+use crate::evaluator::extract_nextval_calls;
+use crate::evaluator::get_sequence_next_values;
 use crate::evaluator::BlockOperator;
 use crate::evaluator::CompoundBlockOperator;
 use crate::BindContext;
@@ -92,18 +94,28 @@ impl BindContext {
             map_exprs.push(expr);
         }
 
+        let mut entries = vec![BlockEntry::new(
+            DataType::Number(NumberDataType::UInt8),
+            Value::Scalar(Scalar::Number(NumberScalar::UInt8(1))),
+        )];
+        // Values of sequences are fetched from the meta service and
+        // appended to the input as constant columns.
+        let sequences = extract_nextval_calls(&mut map_exprs, entries.len())?;
+        for sequence in sequences.iter() {
+            let reply = get_sequence_next_values(ctx.clone(), sequence, 1).await?;
+            entries.push(BlockEntry::new(
+                DataType::Number(NumberDataType::UInt64),
+                Value::Scalar(Scalar::Number(NumberScalar::UInt64(reply.start))),
+            ));
+        }
+        let num_input_columns = entries.len();
+
         let operators = vec![BlockOperator::Map {
             exprs: map_exprs,
             projections: None,
         }];
 
-        let one_row_chunk = DataBlock::new(
-            vec![BlockEntry::new(
-                DataType::Number(NumberDataType::UInt8),
-                Value::Scalar(Scalar::Number(NumberScalar::UInt8(1))),
-            )],
-            1,
-        );
+        let one_row_chunk = DataBlock::new(entries, 1);
         let func_ctx = ctx.get_function_context()?;
         let mut expression_transform = CompoundBlockOperator {
             operators,
@@ -113,7 +125,7 @@ impl BindContext {
         let scalars: Vec<Scalar> = res
             .columns()
             .iter()
-            .skip(1)
+            .skip(num_input_columns)
             .map(|col| unsafe { col.value.as_ref().index_unchecked(0).to_owned() })
             .collect();
         Ok(scalars)
@@ -64,6 +64,7 @@ use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::TableInfo;
 use common_meta_types::MetaId;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
@@ -78,6 +79,11 @@ use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
 use parking_lot::RwLock;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_IDS;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::binder::copy::resolve_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -176,6 +182,7 @@ impl Binder {
                 travel_point,
                 pivot: _,
                 unpivot: _,
+                sample,
             } => {
                 let (catalog, database, table_name) =
                     self.normalize_object_identifier_triple(catalog, database, table);
@@ -196,6 +203,12 @@ impl Binder {
                 let ctes_map = self.ctes_map.clone();
                 if let Some(cte_info) = ctes_map.get(&table_name) {
                     if bind_cte {
+                        if sample.is_some() {
+                            return Err(ErrorCode::SemanticError(
+                                "SAMPLE is not supported for common table expressions",
+                            )
+                            .set_span(*span));
+                        }
                         return if !cte_info.materialized {
                             self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
                                 .await
@@ -234,6 +247,12 @@ impl Binder {
                             let bind_context = parent.unwrap().as_mut();
                             let ctes_map = self.ctes_map.clone();
                             if let Some(cte_info) = ctes_map.get(&table_name) {
+                                if sample.is_some() {
+                                    return Err(ErrorCode::SemanticError(
+                                        "SAMPLE is not supported for common table expressions",
+                                    )
+                                    .set_span(*span));
+                                }
                                 return if !cte_info.materialized {
                                     self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
                                         .await
@@ -303,6 +322,60 @@ impl Binder {
                     }
                 }
 
+                let mut materialized_views = vec![];
+                if self.ctx.get_can_scan_from_agg_index()
+                    && self
+                        .ctx
+                        .get_settings()
+                        .get_enable_materialized_view_rewrite()?
+                    && !bind_context.planning_agg_index
+                    && navigation_point.is_none()
+                    && table_meta.engine() == "FUSE"
+                {
+                    let views = self
+                        .resolve_materialized_views(
+                            tenant.as_str(),
+                            catalog.as_str(),
+                            database.as_str(),
+                            table_meta.as_ref(),
+                        )
+                        .await?;
+                    for view in views {
+                        let query = view.options()[OPT_KEY_MATERIALIZED_VIEW_QUERY].clone();
+                        let view_index = self.metadata.write().add_table(
+                            catalog.clone(),
+                            database.clone(),
+                            view,
+                            None,
+                            false,
+                            true,
+                        );
+                        let tokens = tokenize_sql(&query)?;
+                        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+                        let mut new_bind_context =
+                            BindContext::with_parent(Box::new(bind_context.clone()));
+                        new_bind_context.planning_agg_index = true;
+                        if let Statement::Query(query_stmt) = &stmt {
+                            let (s_expr, view_bind_context) =
+                                self.bind_query(&mut new_bind_context, query_stmt).await?;
+                            let output_columns = view_bind_context
+                                .columns
+                                .iter()
+                                .map(|column| column.index)
+                                .collect();
+                            materialized_views.push((view_index, query, s_expr, output_columns));
+                        }
+                    }
+                }
+
+                if sample.is_some() && table_meta.engine() != "FUSE" {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "SAMPLE only supports FUSE engine, but table `{database}`.`{table_name}` is {}",
+                        table_meta.engine()
+                    ))
+                    .set_span(*span));
+                }
+
                 match table_meta.engine() {
                     "VIEW" => {
                         Self::check_view_dep(bind_context, &database, &table_name)?;
@@ -366,12 +439,23 @@ impl Binder {
                             // Should use bound table id.
                             self.metadata
                                 .write()
-                                .add_agg_indexes(full_table_name, agg_indexes);
+                                .add_agg_indexes(full_table_name.clone(), agg_indexes);
+                        }
+                        if !materialized_views.is_empty() {
+                            self.metadata
+                                .write()
+                                .add_materialized_views(full_table_name, materialized_views);
                         }
 
                         let (s_expr, mut bind_context) = self
                             .bind_base_table(bind_context, database.as_str(), table_index)
                             .await?;
+                        let s_expr = match sample {
+                            Some(sample) => {
+                                self.bind_table_sample(*span, s_expr, table_index, sample)?
+                            }
+                            None => s_expr,
+                        };
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                         }
@@ -381,11 +465,56 @@ impl Binder {
             }
             TableReference::TableFunction {
                 span,
+                lateral: _,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
+
+                if BUILTIN_FUNCTIONS
+                    .get_property(&func_name.name)
+                    .map(|p| p.kind == FunctionKind::SRF)
+                    .unwrap_or(false)
+                {
+                    // If it is a set-returning function, we bind it as a subquery,
+                    // so its arguments can refer to the columns of `LATERAL` joins.
+                    let mut bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+                    let stmt = SelectStmt {
+                        span: *span,
+                        hints: None,
+                        distinct: false,
+                        select_list: vec![SelectTarget::AliasedExpr {
+                            expr: Box::new(common_ast::ast::Expr::FunctionCall {
+                                span: *span,
+                                distinct: false,
+                                name: common_ast::ast::Identifier {
+                                    span: *span,
+                                    name: func_name.name.clone(),
+                                    quote: None,
+                                },
+                                params: vec![],
+                                args: params.clone(),
+                                within_group: vec![],
+                                filter: None,
+                                window: None,
+                                lambda: None,
+                            }),
+                            alias: None,
+                        }],
+                        from: vec![],
+                        selection: None,
+                        group_by: None,
+                        having: None,
+                        qualify: None,
+                        window_list: None,
+                    };
+                    return self
+                        .bind_select_stmt(&mut bind_context, &stmt, &[], 0)
+                        .await;
+                }
+
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
                     self.ctx.clone(),
@@ -397,8 +526,6 @@ impl Binder {
                 );
                 let table_args = bind_table_args(&mut scalar_binder, params, named_params).await?;
 
-                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
-
                 if func_name.name.eq_ignore_ascii_case("result_scan") {
                     let query_id = parse_result_scan_args(&table_args)?;
                     if query_id.is_empty() {
@@ -460,75 +587,37 @@ impl Binder {
                     return Ok((s_expr, bind_context));
                 }
 
-                if BUILTIN_FUNCTIONS
-                    .get_property(&func_name.name)
-                    .map(|p| p.kind == FunctionKind::SRF)
-                    .unwrap_or(false)
-                {
-                    // If it is a set-returning function, we bind it as a subquery.
-                    let mut bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
-                    let stmt = SelectStmt {
-                        span: *span,
-                        hints: None,
-                        distinct: false,
-                        select_list: vec![SelectTarget::AliasedExpr {
-                            expr: Box::new(common_ast::ast::Expr::FunctionCall {
-                                span: *span,
-                                distinct: false,
-                                name: common_ast::ast::Identifier {
-                                    span: *span,
-                                    name: func_name.name.clone(),
-                                    quote: None,
-                                },
-                                params: vec![],
-                                args: params.clone(),
-                                window: None,
-                                lambda: None,
-                            }),
-                            alias: None,
-                        }],
-                        from: vec![],
-                        selection: None,
-                        group_by: None,
-                        having: None,
-                        window_list: None,
-                    };
-                    self.bind_select_stmt(&mut bind_context, &stmt, &[], 0)
-                        .await
+                // Other table functions always reside is default catalog
+                let table_meta: Arc<dyn TableFunction> = self
+                    .catalogs
+                    .get_default_catalog()?
+                    .get_table_function(&func_name.name, table_args)?;
+                let table = table_meta.as_table();
+                let table_alias_name = if let Some(table_alias) = alias {
+                    Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
                 } else {
-                    // Other table functions always reside is default catalog
-                    let table_meta: Arc<dyn TableFunction> =
-                        self.catalogs
-                            .get_default_catalog()?
-                            .get_table_function(&func_name.name, table_args)?;
-                    let table = table_meta.as_table();
-                    let table_alias_name = if let Some(table_alias) = alias {
-                        Some(
-                            normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name,
-                        )
-                    } else {
-                        None
-                    };
-                    let table_index = self.metadata.write().add_table(
-                        CATALOG_DEFAULT.to_string(),
-                        "system".to_string(),
-                        table.clone(),
-                        table_alias_name,
-                        false,
-                        false,
-                    );
+                    None
+                };
+                let table_index = self.metadata.write().add_table(
+                    CATALOG_DEFAULT.to_string(),
+                    "system".to_string(),
+                    table.clone(),
+                    table_alias_name,
+                    false,
+                    false,
+                );
 
-                    let (s_expr, mut bind_context) = self
-                        .bind_base_table(bind_context, "system", table_index)
-                        .await?;
-                    if let Some(alias) = alias {
-                        bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
-                    }
-                    Ok((s_expr, bind_context))
+                let (s_expr, mut bind_context) = self
+                    .bind_base_table(bind_context, "system", table_index)
+                    .await?;
+                if let Some(alias) = alias {
+                    bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
+                Ok((s_expr, bind_context))
             }
             TableReference::Subquery {
                 span: _,
+                lateral: _,
                 subquery,
                 alias,
             } => {
@@ -580,8 +669,9 @@ impl Binder {
             TableReference::Join { join, .. } => {
                 let (left_expr, left_bind_ctx) =
                     self.bind_table_reference(bind_context, &join.left).await?;
-                let (right_expr, right_bind_ctx) =
-                    self.bind_table_reference(bind_context, &join.right).await?;
+                let (right_expr, right_bind_ctx) = self
+                    .bind_join_right_table(bind_context, &left_bind_ctx, join)
+                    .await?;
                 self.bind_join(
                     bind_context,
                     left_bind_ctx,
@@ -753,8 +843,9 @@ impl Binder {
                     result_ctx = ctx;
                 }
                 _ => {
-                    let (right_expr, right_ctx) =
-                        self.bind_single_table(current_ctx, &join.right).await?;
+                    let (right_expr, right_ctx) = self
+                        .bind_join_right_table(current_ctx, &result_ctx, join)
+                        .await?;
                     let (join_expr, ctx) = self
                         .bind_join(
                             current_ctx,
@@ -774,6 +865,28 @@ impl Binder {
         Ok((result_expr, result_ctx))
     }
 
+    /// Bind the right side of a join. A `LATERAL` subquery or table function is
+    /// bound with the context of the left side as its parent, so it can refer to
+    /// the columns of the tables before it as outer columns.
+    #[async_backtrace::framed]
+    async fn bind_join_right_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        left_context: &BindContext,
+        join: &Join,
+    ) -> Result<(SExpr, BindContext)> {
+        if !matches!(
+            &*join.right,
+            TableReference::Subquery { lateral: true, .. }
+                | TableReference::TableFunction { lateral: true, .. }
+        ) {
+            return self.bind_table_reference(bind_context, &join.right).await;
+        }
+        let mut lateral_context = left_context.clone();
+        self.bind_single_table(&mut lateral_context, &join.right)
+            .await
+    }
+
     fn bind_cte_scan(&mut self, cte_info: &CteInfo) -> Result<SExpr> {
         let blocks = Arc::new(RwLock::new(vec![]));
         self.ctx
@@ -1069,6 +1182,57 @@ impl Binder {
 
         Ok(index_metas)
     }
+
+    /// Returns the materialized views built on the table which are up-to-date,
+    /// they are found by the ids kept in the options of the table.
+    #[async_backtrace::framed]
+    pub(crate) async fn resolve_materialized_views(
+        &self,
+        tenant: &str,
+        catalog_name: &str,
+        database_name: &str,
+        table: &dyn Table,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        let view_ids = match table.options().get(OPT_KEY_MATERIALIZED_VIEW_IDS) {
+            Some(ids) => ids
+                .split(',')
+                .filter_map(|id| id.parse::<MetaId>().ok())
+                .collect::<Vec<_>>(),
+            None => return Ok(vec![]),
+        };
+        let table_id = table.get_id().to_string();
+        let snapshot_loc = table
+            .options()
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .cloned()
+            .unwrap_or_default();
+        let catalog = self.catalogs.get_catalog(tenant, catalog_name).await?;
+        let mut views = Vec::with_capacity(view_ids.len());
+        for view_id in view_ids {
+            // the materialized view may have been dropped
+            let (ident, meta) = match catalog.get_table_meta_by_id(view_id).await {
+                Ok(res) => res,
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                Err(e) => return Err(e),
+            };
+            let options = &meta.options;
+            if meta.drop_on.is_some()
+                || options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID) != Some(&table_id)
+                || options.get(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT) != Some(&snapshot_loc)
+            {
+                continue;
+            }
+            let view_name = match catalog.get_table_name_by_id(view_id).await {
+                Ok(name) => name,
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                Err(e) => return Err(e),
+            };
+            let view_info = TableInfo::new(database_name, &view_name, ident, meta.as_ref().clone());
+            views.push(catalog.get_table_by_info(&view_info)?);
+        }
+
+        Ok(views)
+    }
 }
 
 // copy from common-storages-fuse to avoid cyclic dependency.
@@ -66,6 +66,7 @@ impl Dataframe {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         let settings = query_ctx.get_settings();
@@ -177,6 +178,8 @@ impl Dataframe {
                 name: Identifier::from_name("count"),
                 args: vec![],
                 params: vec![],
+                within_group: vec![],
+                filter: None,
                 window: None,
                 lambda: None,
             }),
@@ -469,6 +472,7 @@ impl Dataframe {
                 travel_point: None,
                 pivot: None,
                 unpivot: None,
+                sample: None,
             };
             table_ref.push(table);
         }
@@ -480,6 +484,7 @@ impl Dataframe {
                 join: Join {
                     op: op.clone(),
                     condition: condition.clone(),
+                    match_condition: None,
                     left: Box::new(left),
                     right: Box::new(right),
                 },
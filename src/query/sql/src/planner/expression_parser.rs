@@ -48,6 +48,7 @@ use parking_lot::RwLock;
 
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::ExprContext;
+use crate::evaluator::contains_nextval_call;
 use crate::planner::binder::BindContext;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
@@ -424,6 +425,13 @@ pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Re
             let mut expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
             let mut expr = expr.remove(0);
 
+            // Values of sequences are only allocated for the inserted rows. Columns
+            // backed by a sequence can't be added to existing tables, so there are
+            // no existing rows that need the default value.
+            if contains_nextval_call(&expr) {
+                return Ok(Scalar::default_value(&data_type));
+            }
+
             if expr.data_type() != &data_type {
                 expr = Expr::Cast {
                     span: None,
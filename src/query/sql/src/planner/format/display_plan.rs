@@ -99,10 +99,21 @@ impl Plan {
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(format!("{:?}", create_materialized_view))
+            }
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(format!("{:?}", refresh_materialized_view))
+            }
+
             // Indexes
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
             Plan::RefreshIndex(index) => Ok(format!("{index:?}")),
+            Plan::CreateVectorIndex(index) => Ok(format!("{index:?}")),
+            Plan::DropVectorIndex(index) => Ok(format!("{index:?}")),
+            Plan::RefreshVectorIndex(index) => Ok(format!("{index:?}")),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(create_virtual_column) => {
@@ -157,6 +168,10 @@ impl Plan {
             Plan::UseDatabase(p) => Ok(format!("{:?}", p)),
             Plan::Kill(p) => Ok(format!("{:?}", p)),
 
+            Plan::Begin => Ok("BEGIN".to_string()),
+            Plan::Commit => Ok("COMMIT".to_string()),
+            Plan::Abort => Ok("ABORT".to_string()),
+
             Plan::CreateShareEndpoint(p) => Ok(format!("{:?}", p)),
             Plan::ShowShareEndpoint(p) => Ok(format!("{:?}", p)),
             Plan::DropShareEndpoint(p) => Ok(format!("{:?}", p)),
@@ -177,12 +192,22 @@ impl Plan {
             Plan::DropDatamaskPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescDatamaskPolicy(p) => Ok(format!("{:?}", p)),
 
+            // sequence
+            Plan::CreateSequence(p) => Ok(format!("{:?}", p)),
+            Plan::DropSequence(p) => Ok(format!("{:?}", p)),
+
             // network policy
             Plan::CreateNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::AlterNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DropNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::ShowNetworkPolicies(p) => Ok(format!("{:?}", p)),
+
+            // task
+            Plan::CreateTask(p) => Ok(format!("{:?}", p)),
+            Plan::AlterTask(p) => Ok(format!("{:?}", p)),
+            Plan::DropTask(p) => Ok(format!("{:?}", p)),
+            Plan::ExecuteTask(p) => Ok(format!("{:?}", p)),
         }
     }
 }
@@ -228,6 +253,8 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             order_by: None,
             prewhere: None,
             agg_index: None,
+            sample: None,
+            vector_top_k: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
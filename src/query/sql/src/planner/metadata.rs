@@ -61,6 +61,9 @@ pub struct Metadata {
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Up-to-date materialized views of tables, each one is
+    /// (table index of materialized view, query, plan of query, output columns of query).
+    materialized_views: HashMap<String, Vec<(IndexType, String, SExpr, Vec<IndexType>)>>,
     max_column_position: usize, // for CSV
 }
 
@@ -259,6 +262,24 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_materialized_views(
+        &mut self,
+        table: String,
+        views: Vec<(IndexType, String, SExpr, Vec<IndexType>)>,
+    ) {
+        self.materialized_views
+            .entry(table)
+            .and_modify(|v| v.extend_from_slice(&views))
+            .or_insert(views);
+    }
+
+    pub fn get_materialized_views(
+        &self,
+        table: &str,
+    ) -> Option<&[(IndexType, String, SExpr, Vec<IndexType>)]> {
+        self.materialized_views.get(table).map(|v| v.as_slice())
+    }
+
     pub fn add_table(
         &mut self,
         catalog: String,
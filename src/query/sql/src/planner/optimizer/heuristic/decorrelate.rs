@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -19,10 +20,14 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
 
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::JoinPredicate;
 use crate::binder::Visibility;
+use crate::binder::WindowOrderByInfo;
 use crate::optimizer::heuristic::subquery_rewriter::FlattenInfo;
 use crate::optimizer::heuristic::subquery_rewriter::SubqueryRewriter;
 use crate::optimizer::heuristic::subquery_rewriter::UnnestResult;
@@ -35,20 +40,29 @@ use crate::plans::AggregateMode;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonOp;
+use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::Limit;
 use crate::plans::PatternPlan;
+use crate::plans::ProjectSet;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
+use crate::plans::SrfItem;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
 use crate::plans::UDFServerCall;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
@@ -422,6 +436,274 @@ impl SubqueryRewriter {
         }
     }
 
+    /// Decorrelate a join whose right child refers to the columns of its left child,
+    /// which is bound from a `LATERAL` subquery or table function.
+    ///
+    /// Set-returning functions in inner joins are evaluated on the left child directly.
+    /// Other plans are evaluated like a nested-loop apply: the right child is flattened
+    /// on the distinct values of the correlated columns of the left child, and then
+    /// joined with the left child by the correlated columns.
+    pub fn try_decorrelate_lateral_join(
+        &mut self,
+        join: &Join,
+        left: SExpr,
+        right: SExpr,
+        correlated_columns: &ColumnSet,
+    ) -> Result<SExpr> {
+        if let Some(s_expr) = self.try_decorrelate_lateral_srf(join, &left, &right)? {
+            return Ok(s_expr);
+        }
+
+        // An aggregation without group by returns exactly one row for each row
+        // of the left child, even if there is no matched rows.
+        let is_scalar_aggregate = is_scalar_aggregate(&right)?;
+        let join_type = match join.join_type {
+            JoinType::Cross | JoinType::Inner if is_scalar_aggregate => JoinType::Left,
+            JoinType::Cross => JoinType::Inner,
+            _ => join.join_type.clone(),
+        };
+        // The rows of the right child that are not produced by any left row must not
+        // be kept, so the correlated predicates can't be turned into join conditions.
+        let need_cross_join = !matches!(
+            join_type,
+            JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+        );
+
+        let mut flatten_info = FlattenInfo {
+            from_count_func: false,
+        };
+        self.derived_columns.clear();
+        self.outer_plan = Some(left.clone());
+        let flatten_plan = self.flatten(
+            &right,
+            correlated_columns,
+            &mut flatten_info,
+            need_cross_join,
+        );
+        self.outer_plan = None;
+        let flatten_plan = flatten_plan?;
+        let flatten_plan = self.rewrite_lateral_limit(flatten_plan)?;
+
+        let mut left_conditions = join.left_conditions.clone();
+        let mut right_conditions = join.right_conditions.clone();
+        self.add_equi_conditions(
+            None,
+            correlated_columns,
+            &mut right_conditions,
+            &mut left_conditions,
+        )?;
+        let join_plan = Join {
+            left_conditions,
+            right_conditions,
+            non_equi_conditions: join.non_equi_conditions.clone(),
+            join_type,
+            marker_index: None,
+            from_correlated_subquery: true,
+            contain_runtime_filter: false,
+        };
+        Ok(SExpr::create_binary(
+            Arc::new(join_plan.into()),
+            Arc::new(left),
+            Arc::new(flatten_plan),
+        ))
+    }
+
+    // Rewrite `LATERAL unnest(t.a)` or `LATERAL (SELECT unnest(t.a))` to a `ProjectSet`
+    // on top of the left child.
+    fn try_decorrelate_lateral_srf(
+        &self,
+        join: &Join,
+        left: &SExpr,
+        right: &SExpr,
+    ) -> Result<Option<SExpr>> {
+        let (eval_scalar, project_set) = match right.plan() {
+            RelOperator::EvalScalar(eval_scalar)
+                if matches!(right.child(0)?.plan(), RelOperator::ProjectSet(_)) =>
+            {
+                (Some(eval_scalar.clone()), right.child(0)?)
+            }
+            RelOperator::ProjectSet(_) => (None, right),
+            _ => return Ok(None),
+        };
+        let input = project_set.child(0)?;
+        let input_prop = RelExpr::with_s_expr(input).derive_relational_prop()?;
+        if !input_prop.outer_columns.is_empty() {
+            return Ok(None);
+        }
+        // Other joins need to keep the rows of the left child without results,
+        // which are evaluated by apply.
+        if !matches!(join.join_type, JoinType::Cross | JoinType::Inner) {
+            return Ok(None);
+        }
+
+        let input = if self.is_one_row_scan(input) {
+            left.clone()
+        } else {
+            SExpr::create_binary(
+                Arc::new(Join::default().into()),
+                Arc::new(left.clone()),
+                Arc::new(input.clone()),
+            )
+        };
+        let mut s_expr = SExpr::create_unary(Arc::new(project_set.plan().clone()), Arc::new(input));
+        if let Some(eval_scalar) = eval_scalar {
+            s_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr));
+        }
+
+        let mut predicates = join.non_equi_conditions.clone();
+        for (left_condition, right_condition) in join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+        {
+            predicates.push(ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "eq".to_string(),
+                params: vec![],
+                arguments: vec![left_condition.clone(), right_condition.clone()],
+            }));
+        }
+        if !predicates.is_empty() {
+            s_expr = SExpr::create_unary(Arc::new(Filter { predicates }.into()), Arc::new(s_expr));
+        }
+        Ok(Some(s_expr))
+    }
+
+    fn is_one_row_scan(&self, s_expr: &SExpr) -> bool {
+        match s_expr.plan() {
+            RelOperator::DummyTableScan(_) => true,
+            RelOperator::Scan(scan) => {
+                let metadata = self.metadata.read();
+                let table = metadata.table(scan.table_index);
+                table.database() == "system" && table.name() == "one"
+            }
+            _ => false,
+        }
+    }
+
+    // `LIMIT` in a lateral subquery applies to the rows of each left row, so rewrite it to
+    // `row_number() OVER (PARTITION BY <correlated columns> ORDER BY <sort items>)`.
+    fn rewrite_lateral_limit(&mut self, plan: SExpr) -> Result<SExpr> {
+        let limit: Limit = match plan.plan() {
+            RelOperator::Limit(limit) => limit.clone(),
+            _ => return Ok(plan),
+        };
+        let child = plan.child(0)?;
+        let (projection, sort, input) = match child.plan() {
+            RelOperator::EvalScalar(eval_scalar)
+                if matches!(child.child(0)?.plan(), RelOperator::Sort(_)) =>
+            {
+                let sort = child.child(0)?;
+                (
+                    Some(eval_scalar.clone()),
+                    Some(sort.plan().clone()),
+                    sort.child(0)?,
+                )
+            }
+            RelOperator::Sort(_) => (None, Some(child.plan().clone()), child.child(0)?),
+            _ => (None, None, child),
+        };
+
+        let (partition_by, order_by) = {
+            let metadata = self.metadata.read();
+            let column_ref = |index: IndexType| {
+                let column_entry = metadata.column(index);
+                ScalarItem {
+                    scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: None,
+                        column: ColumnBindingBuilder::new(
+                            column_entry.name(),
+                            index,
+                            Box::new(column_entry.data_type()),
+                            Visibility::Visible,
+                        )
+                        .build(),
+                    }),
+                    index,
+                }
+            };
+            let partition_by = self
+                .derived_columns
+                .values()
+                .map(|index| column_ref(*index))
+                .collect::<Vec<_>>();
+            let order_by = match &sort {
+                Some(RelOperator::Sort(sort)) => sort
+                    .items
+                    .iter()
+                    .map(|item| WindowOrderByInfo {
+                        order_by_item: column_ref(item.index),
+                        asc: Some(item.asc),
+                        nulls_first: Some(item.nulls_first),
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+            (partition_by, order_by)
+        };
+
+        let row_number_index = self.metadata.write().add_derived_column(
+            "row_number".to_string(),
+            DataType::Number(NumberDataType::UInt64),
+        );
+        let top_n = limit.limit.map(|n| n + limit.offset);
+        let window = Window {
+            span: None,
+            index: row_number_index,
+            function: WindowFuncType::RowNumber,
+            arguments: vec![],
+            partition_by,
+            order_by,
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Rows,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::CurrentRow,
+            },
+            limit: top_n,
+        };
+
+        let row_number = ScalarExpr::BoundColumnRef(BoundColumnRef {
+            span: None,
+            column: ColumnBindingBuilder::new(
+                "row_number".to_string(),
+                row_number_index,
+                Box::new(DataType::Number(NumberDataType::UInt64)),
+                Visibility::Visible,
+            )
+            .build(),
+        });
+        let compare = |func_name: &str, n: usize| {
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: func_name.to_string(),
+                params: vec![],
+                arguments: vec![
+                    row_number.clone(),
+                    ScalarExpr::ConstantExpr(ConstantExpr {
+                        span: None,
+                        value: Scalar::Number(NumberScalar::UInt64(n as u64)),
+                    }),
+                ],
+            })
+        };
+        let mut predicates = vec![];
+        if let Some(top_n) = top_n {
+            predicates.push(compare("lte", top_n));
+        }
+        if limit.offset > 0 {
+            predicates.push(compare("gt", limit.offset));
+        }
+
+        let mut s_expr = SExpr::create_unary(Arc::new(window.into()), Arc::new(input.clone()));
+        if !predicates.is_empty() {
+            s_expr = SExpr::create_unary(Arc::new(Filter { predicates }.into()), Arc::new(s_expr));
+        }
+        if let Some(projection) = projection {
+            s_expr = SExpr::create_unary(Arc::new(projection.into()), Arc::new(s_expr));
+        }
+        Ok(s_expr)
+    }
+
     fn flatten(
         &mut self,
         plan: &SExpr,
@@ -435,6 +717,17 @@ impl SubqueryRewriter {
             if !need_cross_join {
                 return Ok(plan.clone());
             }
+            if let Some(outer_plan) = self.outer_plan.clone() {
+                // Apply the plan to the distinct correlated values of the outer plan.
+                let (domain, derived_columns) =
+                    self.build_apply_domain(&outer_plan, correlated_columns)?;
+                self.derived_columns.extend(derived_columns);
+                return Ok(SExpr::create_binary(
+                    Arc::new(Join::default().into()),
+                    Arc::new(domain),
+                    Arc::new(plan.clone()),
+                ));
+            }
             // Construct a LogicalGet plan by correlated columns.
             // Finally generate a cross join, so we finish flattening the subquery.
             let mut metadata = self.metadata.write();
@@ -442,7 +735,11 @@ impl SubqueryRewriter {
             // Such as: select t2.a from (select a + 1 as a from t) as t2 where (select sum(a) from t as t1 where t1.a < t2.a) = 1;
             let table_index = metadata
                 .table_index_by_column_indexes(correlated_columns)
-                .unwrap();
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(
+                        "correlated columns must come from a base table".to_string(),
+                    )
+                })?;
             let mut data_types = Vec::with_capacity(correlated_columns.len());
             for correlated_column in correlated_columns.iter() {
                 let column_entry = metadata.column(*correlated_column).clone();
@@ -692,6 +989,17 @@ impl SubqueryRewriter {
                         index: item.index,
                     })
                 }
+                if aggregate.group_items.is_empty() {
+                    if let Some(outer_plan) = self.outer_plan.clone() {
+                        return self.flatten_scalar_aggregate(
+                            aggregate,
+                            &outer_plan,
+                            flatten_plan,
+                            agg_items,
+                            correlated_columns,
+                        );
+                    }
+                }
                 Ok(SExpr::create_unary(
                     Arc::new(
                         Aggregate {
@@ -735,6 +1043,34 @@ impl SubqueryRewriter {
                 ))
             }
 
+            RelOperator::ProjectSet(project_set) => {
+                if project_set.srfs.iter().any(|srf| {
+                    srf.scalar
+                        .used_columns()
+                        .iter()
+                        .any(|index| correlated_columns.contains(index))
+                }) {
+                    need_cross_join = true;
+                }
+                let flatten_plan = self.flatten(
+                    plan.child(0)?,
+                    correlated_columns,
+                    flatten_info,
+                    need_cross_join,
+                )?;
+                let mut srfs = Vec::with_capacity(project_set.srfs.len());
+                for srf in project_set.srfs.iter() {
+                    srfs.push(SrfItem {
+                        scalar: self.flatten_scalar(&srf.scalar, correlated_columns)?,
+                        index: srf.index,
+                    });
+                }
+                Ok(SExpr::create_unary(
+                    Arc::new(ProjectSet { srfs }.into()),
+                    Arc::new(flatten_plan),
+                ))
+            }
+
             RelOperator::UnionAll(op) => {
                 if op
                     .used_columns()?
@@ -768,6 +1104,161 @@ impl SubqueryRewriter {
         }
     }
 
+    // Build the distinct values of the correlated columns of the outer plan, which is
+    // the domain the right child of a `LATERAL` join is applied to. The correlated
+    // columns are mapped to new derived columns.
+    fn build_apply_domain(
+        &self,
+        outer_plan: &SExpr,
+        correlated_columns: &ColumnSet,
+    ) -> Result<(SExpr, HashMap<IndexType, IndexType>)> {
+        let mut derived_columns = HashMap::with_capacity(correlated_columns.len());
+        let mut items = Vec::with_capacity(correlated_columns.len());
+        let mut group_items = Vec::with_capacity(correlated_columns.len());
+        for correlated_column in correlated_columns.iter() {
+            let (name, data_type) = {
+                let metadata = self.metadata.read();
+                let column_entry = metadata.column(*correlated_column);
+                (column_entry.name(), column_entry.data_type())
+            };
+            let derived_column = self.metadata.write().add_derived_column(name, data_type);
+            items.push(ScalarItem {
+                scalar: self.column_ref(*correlated_column),
+                index: derived_column,
+            });
+            group_items.push(ScalarItem {
+                scalar: self.column_ref(derived_column),
+                index: derived_column,
+            });
+            derived_columns.insert(*correlated_column, derived_column);
+        }
+        let eval_scalar = SExpr::create_unary(
+            Arc::new(EvalScalar { items }.into()),
+            Arc::new(outer_plan.clone()),
+        );
+        let distinct = Aggregate {
+            mode: AggregateMode::Initial,
+            group_items,
+            aggregate_functions: vec![],
+            from_distinct: false,
+            limit: None,
+            grouping_sets: None,
+        };
+        Ok((
+            SExpr::create_unary(Arc::new(distinct.into()), Arc::new(eval_scalar)),
+            derived_columns,
+        ))
+    }
+
+    // An aggregation without group by returns one row even if its input is empty. When
+    // it's applied to the outer plan, its input is left joined to the apply domain and
+    // grouped by the domain, so every outer row gets a row. `count(*)` counts a marker
+    // column of the input, which is NULL for the domain values without matched rows.
+    fn flatten_scalar_aggregate(
+        &mut self,
+        aggregate: &Aggregate,
+        outer_plan: &SExpr,
+        input: SExpr,
+        aggregate_functions: Vec<ScalarItem>,
+        correlated_columns: &ColumnSet,
+    ) -> Result<SExpr> {
+        let (domain, domain_columns) = self.build_apply_domain(outer_plan, correlated_columns)?;
+
+        let marker_index = self
+            .metadata
+            .write()
+            .add_derived_column("marker".to_string(), DataType::Boolean);
+        let input = SExpr::create_unary(
+            Arc::new(
+                EvalScalar {
+                    items: vec![ScalarItem {
+                        scalar: ScalarExpr::ConstantExpr(ConstantExpr {
+                            span: None,
+                            value: Scalar::Boolean(true),
+                        }),
+                        index: marker_index,
+                    }],
+                }
+                .into(),
+            ),
+            Arc::new(input),
+        );
+
+        let mut left_conditions = Vec::with_capacity(domain_columns.len());
+        let mut right_conditions = Vec::with_capacity(domain_columns.len());
+        for (correlated_column, domain_column) in domain_columns.iter() {
+            let derived_column = self.derived_columns.get(correlated_column).ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Correlated column {correlated_column} is not flattened"
+                ))
+            })?;
+            left_conditions.push(self.column_ref(*domain_column));
+            right_conditions.push(self.column_ref(*derived_column));
+        }
+        let join = Join {
+            left_conditions,
+            right_conditions,
+            non_equi_conditions: vec![],
+            join_type: JoinType::Left,
+            marker_index: None,
+            from_correlated_subquery: false,
+            contain_runtime_filter: false,
+        };
+        let input = SExpr::create_binary(Arc::new(join.into()), Arc::new(domain), Arc::new(input));
+
+        let marker = self.column_ref(marker_index);
+        let aggregate_functions = aggregate_functions
+            .into_iter()
+            .map(|mut item| {
+                if let ScalarExpr::AggregateFunction(agg) = &mut item.scalar {
+                    if agg.func_name.eq_ignore_ascii_case("count") && agg.args.is_empty() {
+                        agg.args.push(marker.clone());
+                    }
+                }
+                item
+            })
+            .collect();
+        let group_items = domain_columns
+            .values()
+            .map(|index| ScalarItem {
+                scalar: self.column_ref(*index),
+                index: *index,
+            })
+            .collect();
+        // The plans above the aggregation refer to the correlated columns by the domain.
+        self.derived_columns = domain_columns;
+
+        Ok(SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    mode: AggregateMode::Initial,
+                    group_items,
+                    aggregate_functions,
+                    from_distinct: aggregate.from_distinct,
+                    limit: aggregate.limit,
+                    grouping_sets: None,
+                }
+                .into(),
+            ),
+            Arc::new(input),
+        ))
+    }
+
+    fn column_ref(&self, index: IndexType) -> ScalarExpr {
+        let metadata = self.metadata.read();
+        let column_entry = metadata.column(index);
+        ScalarExpr::BoundColumnRef(BoundColumnRef {
+            span: None,
+            column: ColumnBindingBuilder::new(
+                column_entry.name(),
+                index,
+                Box::new(column_entry.data_type()),
+                Visibility::Visible,
+            )
+            .build(),
+        })
+    }
+
     fn flatten_scalar(
         &mut self,
         scalar: &ScalarExpr,
@@ -932,3 +1423,16 @@ impl SubqueryRewriter {
         }))
     }
 }
+
+// Check if the plan is an aggregation without group by, which may be under
+// projections, filters (HAVING) and sorts.
+fn is_scalar_aggregate(plan: &SExpr) -> Result<bool> {
+    match plan.plan() {
+        RelOperator::Aggregate(aggregate) => Ok(aggregate.group_items.is_empty()),
+        RelOperator::EvalScalar(_)
+        | RelOperator::Filter(_)
+        | RelOperator::Sort(_)
+        | RelOperator::Limit(_) => is_scalar_aggregate(plan.child(0)?),
+        _ => Ok(false),
+    }
+}
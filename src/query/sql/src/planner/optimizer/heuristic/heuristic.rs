@@ -45,12 +45,15 @@ pub static DEFAULT_REWRITE_RULES: Lazy<Vec<RuleID>> = Lazy::new(|| {
         RuleID::PushDownFilterEvalScalar,
         RuleID::PushDownFilterJoin,
         RuleID::PushDownFilterProjectSet,
+        RuleID::PushDownFilterWindowTopN,
         RuleID::FoldCountAggregate,
+        RuleID::TryApplyMaterializedView,
         RuleID::TryApplyAggIndex,
         RuleID::SplitAggregate,
         RuleID::PushDownFilterScan,
         RuleID::PushDownPrewhere, /* PushDownPrwhere should be after all rules except PushDownFilterScan */
         RuleID::PushDownSortScan, // PushDownSortScan should be after PushDownPrewhere
+        RuleID::TryApplyVectorIndex,
     ]
 });
 
@@ -74,7 +77,7 @@ impl HeuristicOptimizer {
 
     pub fn pre_optimize(&self, s_expr: SExpr) -> Result<SExpr> {
         let mut s_expr = s_expr;
-        if s_expr.contain_subquery() {
+        if s_expr.contain_subquery() || s_expr.contain_lateral_join()? {
             s_expr = decorrelate_subquery(self.metadata.clone(), s_expr)?;
         }
         Ok(s_expr)
@@ -27,6 +27,7 @@ use common_functions::aggregates::AggregateCountFunction;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
+use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::Aggregate;
@@ -68,6 +69,9 @@ pub struct FlattenInfo {
 pub struct SubqueryRewriter {
     pub(crate) metadata: MetadataRef,
     pub(crate) derived_columns: HashMap<IndexType, IndexType>,
+    // The left child of the `LATERAL` join being decorrelated. The right child is
+    // applied to the distinct values of its correlated columns.
+    pub(crate) outer_plan: Option<SExpr>,
 }
 
 impl SubqueryRewriter {
@@ -75,6 +79,7 @@ impl SubqueryRewriter {
         Self {
             metadata,
             derived_columns: Default::default(),
+            outer_plan: None,
         }
     }
 
@@ -156,14 +161,34 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) | RelOperator::MaterializedCte(_) => {
-                Ok(SExpr::create_binary(
-                    Arc::new(s_expr.plan().clone()),
-                    Arc::new(self.rewrite(s_expr.child(0)?)?),
-                    Arc::new(self.rewrite(s_expr.child(1)?)?),
-                ))
+            RelOperator::Join(join) => {
+                let left = self.rewrite(s_expr.child(0)?)?;
+                let right = self.rewrite(s_expr.child(1)?)?;
+
+                // The right child of a `LATERAL` join refers to the columns of the left child.
+                let left_prop = RelExpr::with_s_expr(&left).derive_relational_prop()?;
+                let right_prop = RelExpr::with_s_expr(&right).derive_relational_prop()?;
+                let correlated_columns: ColumnSet = right_prop
+                    .outer_columns
+                    .intersection(&left_prop.output_columns)
+                    .cloned()
+                    .collect();
+                if correlated_columns.is_empty() {
+                    return Ok(SExpr::create_binary(
+                        Arc::new(join.into()),
+                        Arc::new(left),
+                        Arc::new(right),
+                    ));
+                }
+                self.try_decorrelate_lateral_join(&join, left, right, &correlated_columns)
             }
 
+            RelOperator::UnionAll(_) | RelOperator::MaterializedCte(_) => Ok(SExpr::create_binary(
+                Arc::new(s_expr.plan().clone()),
+                Arc::new(self.rewrite(s_expr.child(0)?)?),
+                Arc::new(self.rewrite(s_expr.child(1)?)?),
+            )),
+
             RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
                 Arc::new(s_expr.plan().clone()),
                 Arc::new(self.rewrite(s_expr.child(0)?)?),
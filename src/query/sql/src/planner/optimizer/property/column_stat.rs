@@ -15,8 +15,8 @@
 use std::collections::HashMap;
 
 use common_storage::Datum;
+use common_storage::Histogram;
 
-use crate::optimizer::property::histogram::Histogram;
 use crate::IndexType;
 
 pub type ColumnStatSet = HashMap<IndexType, ColumnStat>;
@@ -38,6 +38,9 @@ pub struct ColumnStat {
 
     /// Histogram of column
     pub histogram: Option<Histogram>,
+
+    /// Most common values of column and their fractions
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 #[derive(Debug, Clone)]
@@ -19,7 +19,6 @@ mod column_stat;
 mod constraint;
 
 mod enforcer;
-mod histogram;
 #[allow(clippy::module_inception)]
 mod property;
 mod selectivity;
@@ -28,15 +27,15 @@ pub use builder::RelExpr;
 pub use column_stat::ColumnStat;
 pub use column_stat::ColumnStatSet;
 pub use column_stat::NewStatistic;
+pub use common_storage::histogram_from_ndv;
+pub use common_storage::Histogram;
+pub use common_storage::HistogramBucket;
+pub use common_storage::InterleavedBucket;
+pub use common_storage::UniformSampleSet;
+pub use common_storage::DEFAULT_HISTOGRAM_BUCKETS;
 #[cfg(feature = "z3-prove")]
 pub use constraint::ConstraintSet;
 pub use enforcer::require_property;
-pub use histogram::histogram_from_ndv;
-pub use histogram::Histogram;
-pub use histogram::HistogramBucket;
-pub use histogram::InterleavedBucket;
-pub use histogram::UniformSampleSet;
-pub use histogram::DEFAULT_HISTOGRAM_BUCKETS;
 pub use property::ColumnSet;
 pub use property::Distribution;
 pub use property::PhysicalProperty;
@@ -132,9 +132,9 @@ impl<'a> SelectivityEstimator<'a> {
 
             return match op {
                 ComparisonOp::Equal => {
-                    // For equal predicate, we just use cardinality of a single
-                    // value to estimate the selectivity. This assumes that
-                    // the column is in a uniform distribution.
+                    // For equal predicate, we use the fraction of the value if it's
+                    // one of the most common values, otherwise we use cardinality of
+                    // a single value in the histogram bucket containing it.
                     let selectivity = evaluate_equal(column_stat, constant);
                     if update {
                         update_statistic(
@@ -354,6 +354,10 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
                 return 0.0;
             }
         }
+
+        if let Some(selectivity) = evaluate_equal_by_distribution(constant_datum, column_stat) {
+            return selectivity;
+        }
     }
 
     if column_stat.ndv == 0.0 {
@@ -363,6 +367,34 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
     }
 }
 
+// Estimate the selectivity of equal predicate by the most common values and histogram.
+fn evaluate_equal_by_distribution(datum: &Datum, column_stat: &ColumnStat) -> Option<f64> {
+    for (value, fraction) in column_stat.most_common_values.iter() {
+        if value.type_comparable(datum) && value.compare(datum).ok()? == Ordering::Equal {
+            return Some(*fraction);
+        }
+    }
+
+    // The histogram constructed from ndv assumes uniform distribution, which is same as `1 / ndv`.
+    let histogram = column_stat.histogram.as_ref().filter(|h| h.accuracy)?;
+    let num_values = histogram.num_values();
+    if num_values == 0.0 {
+        return None;
+    }
+    for bucket in histogram.buckets_iter() {
+        if !bucket.upper_bound().type_comparable(datum) {
+            return None;
+        }
+        if bucket.upper_bound().compare(datum).ok()? != Ordering::Less {
+            if bucket.num_distinct() == 0.0 {
+                return Some(0.0);
+            }
+            return Some(bucket.num_values() / bucket.num_distinct() / num_values);
+        }
+    }
+    None
+}
+
 fn update_statistic(
     column_stat: &mut ColumnStat,
     mut new_min: Datum,
@@ -380,6 +412,8 @@ fn update_statistic(
     }
     column_stat.min = new_min.clone();
     column_stat.max = new_max.clone();
+    // The most common values are invalid after filtering.
+    column_stat.most_common_values.clear();
     if let Some(histogram) = &column_stat.histogram {
         let num_values = histogram.num_values();
         let new_num_values = (num_values * selectivity).ceil() as u64;
@@ -45,12 +45,95 @@ use crate::IndexType;
 use crate::ScalarExpr;
 use crate::Visibility;
 
+/// An output of the index plan.
+pub struct IndexOutput {
+    /// The position of the output in the selection of index plan.
+    pub position: usize,
+    /// The column index of the output in the index plan.
+    pub index: IndexType,
+    pub data_type: DataType,
+    /// If the output is an aggregate function.
+    pub is_agg_func: bool,
+}
+
+/// The result of matching a query with an index plan.
+pub struct IndexMatch {
+    /// The position of the matched plan in the given index plans.
+    pub position: usize,
+    /// The new selection of the query. The column refs in it are the outputs of the index,
+    /// the column index of which is the position of the output in the index selection.
+    pub selection: Vec<ScalarItem>,
+    /// The predicates to be applied on the outputs of the index.
+    pub predicates: Vec<ScalarExpr>,
+    pub is_agg: bool,
+    pub num_agg_funcs: usize,
+    /// The outputs of the index, ordered by position.
+    pub outputs: Vec<IndexOutput>,
+}
+
 pub fn try_rewrite(
     table_index: IndexType,
     base_columns: &[ColumnEntry],
     s_expr: &SExpr,
     index_plans: &[(u64, String, SExpr)],
 ) -> Result<Option<SExpr>> {
+    let plans = index_plans
+        .iter()
+        .map(|(_, _, plan)| plan)
+        .collect::<Vec<_>>();
+    let Some(matched) = try_match(table_index, base_columns, s_expr, &plans, true)? else {
+        return Ok(None);
+    };
+    let (index_id, sql, _) = &index_plans[matched.position];
+
+    // Construct the index output schema
+    let index_fields = matched
+        .outputs
+        .iter()
+        .map(|output| {
+            if output.is_agg_func {
+                // If the item is an aggregation function,
+                // the actual data in the index is the temp state of the function.
+                // (E.g. `sum` function will store serialized `sum_state` in index data.)
+                // So the data type will be `String`.
+                return Ok(TableField::new(
+                    &output.position.to_string(),
+                    TableDataType::String,
+                ));
+            }
+            Ok(TableField::new(
+                &output.position.to_string(),
+                infer_schema_type(&output.data_type)?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let result = push_down_index_scan(s_expr, AggIndexInfo {
+        index_id: *index_id,
+        selection: matched.selection,
+        predicates: matched.predicates,
+        schema: TableSchemaRefExt::create(index_fields),
+        is_agg: matched.is_agg,
+        num_agg_funcs: matched.num_agg_funcs,
+    })?;
+
+    info!("Use aggregating index: {sql}");
+
+    Ok(Some(result))
+}
+
+/// Tries to match the query with the plans of aggregating indexes or materialized views,
+/// the first matched plan is used to rewrite the query.
+///
+/// If `agg_state` is true, the outputs of aggregate functions in the index are the
+/// serialized states of the functions rather than the final values.
+pub fn try_match(
+    table_index: IndexType,
+    base_columns: &[ColumnEntry],
+    s_expr: &SExpr,
+    index_plans: &[&SExpr],
+    agg_state: bool,
+) -> Result<Option<IndexMatch>> {
     if index_plans.is_empty() {
         return Ok(None);
     }
@@ -69,7 +152,7 @@ pub fn try_rewrite(
     let query_group_items = query_info.formatted_group_items();
 
     // Search all index plans, find the first matched index to rewrite the query.
-    for (index_id, sql, plan) in index_plans.iter() {
+    for (position, plan) in index_plans.iter().enumerate() {
         let plan = rewrite_index_plan(table_index, &col_index_map, plan);
 
         let index_info = collect_information(&plan)?;
@@ -123,7 +206,9 @@ pub fn try_rewrite(
                             &index_selection,
                             &query_info.format_scalar(&agg.scalar),
                         ) {
-                            rewritten.column.data_type = Box::new(DataType::String);
+                            if agg_state {
+                                rewritten.column.data_type = Box::new(DataType::String);
+                            }
                             new_selection.push(ScalarItem {
                                 index: agg.index,
                                 scalar: rewritten.into(),
@@ -223,7 +308,7 @@ pub fn try_rewrite(
             (None, None) => { /* Matched */ }
         }
 
-        // 3. Construct the index output schema
+        // 3. Collect the outputs of the index.
         let agg_func_indices = index_info
             .aggregation
             .as_ref()
@@ -234,38 +319,34 @@ pub fn try_rewrite(
                     .collect::<HashSet<_>>()
             })
             .unwrap_or_default();
-        let index_fields = index_selection
-            .iter()
-            .sorted_by_key(|(_, (idx, _))| *idx)
-            .map(|(_, (idx, ty))| {
-                if let ScalarExpr::BoundColumnRef(col) = &index_info.selection.items[*idx].scalar {
-                    if agg_func_indices.contains(&col.column.index) {
-                        // If the item is an aggregation function,
-                        // the actual data in the index is the temp state of the function.
-                        // (E.g. `sum` function will store serialized `sum_state` in index data.)
-                        // So the data type will be `String`.
-                        return Ok(TableField::new(&idx.to_string(), TableDataType::String));
-                    }
+        let outputs = index_selection
+            .values()
+            .sorted_by_key(|(idx, _)| *idx)
+            .map(|(idx, ty)| {
+                let item = &index_info.selection.items[*idx];
+                let is_agg_func = matches!(
+                    &item.scalar,
+                    ScalarExpr::BoundColumnRef(col) if agg_func_indices.contains(&col.column.index)
+                );
+                IndexOutput {
+                    position: *idx,
+                    index: item.index,
+                    data_type: ty.clone(),
+                    is_agg_func,
                 }
-
-                Ok(TableField::new(&idx.to_string(), infer_schema_type(ty)?))
             })
-            .collect::<Result<Vec<_>>>()?;
+            .collect();
 
         new_selection.sort_by_key(|i| i.index);
 
-        let result = push_down_index_scan(s_expr, AggIndexInfo {
-            index_id: *index_id,
+        return Ok(Some(IndexMatch {
+            position,
             selection: new_selection,
             predicates: new_predicates,
-            schema: TableSchemaRefExt::create(index_fields),
             is_agg,
             num_agg_funcs,
-        })?;
-
-        info!("Use aggregating index: {sql}");
-
-        return Ok(Some(result));
+            outputs,
+        }));
     }
 
     Ok(None)
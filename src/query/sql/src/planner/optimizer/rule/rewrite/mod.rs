@@ -30,6 +30,7 @@ mod rule_push_down_filter_project_set;
 mod rule_push_down_filter_scan;
 mod rule_push_down_filter_sort;
 mod rule_push_down_filter_union;
+mod rule_push_down_filter_window_top_n;
 mod rule_push_down_limit_aggregate;
 mod rule_push_down_limit_expression;
 mod rule_push_down_limit_join;
@@ -40,6 +41,8 @@ mod rule_push_down_prewhere;
 mod rule_push_down_sort_scan;
 mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
+mod rule_try_apply_materialized_view;
+mod rule_try_apply_vector_index;
 
 pub use rule_commute_join::RuleCommuteJoin;
 pub use rule_eliminate_eval_scalar::RuleEliminateEvalScalar;
@@ -58,6 +61,7 @@ pub use rule_push_down_filter_project_set::RulePushDownFilterProjectSet;
 pub use rule_push_down_filter_scan::RulePushDownFilterScan;
 pub use rule_push_down_filter_sort::RulePushDownFilterSort;
 pub use rule_push_down_filter_union::RulePushDownFilterUnion;
+pub use rule_push_down_filter_window_top_n::RulePushDownFilterWindowTopN;
 pub use rule_push_down_limit_aggregate::RulePushDownLimitAggregate;
 pub use rule_push_down_limit_expression::RulePushDownLimitExpression;
 pub use rule_push_down_limit_join::RulePushDownLimitOuterJoin;
@@ -68,3 +72,5 @@ pub use rule_push_down_prewhere::RulePushDownPrewhere;
 pub use rule_push_down_sort_scan::RulePushDownSortScan;
 pub use rule_split_aggregate::RuleSplitAggregate;
 pub use rule_try_apply_agg_index::RuleTryApplyAggIndex;
+pub use rule_try_apply_materialized_view::RuleTryApplyMaterializedView;
+pub use rule_try_apply_vector_index::RuleTryApplyVectorIndex;
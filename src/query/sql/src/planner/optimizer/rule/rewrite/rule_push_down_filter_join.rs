@@ -156,7 +156,11 @@ pub fn try_push_down_filter_join(
                 left_push_down.push(predicate);
             }
             JoinPredicate::Right(_) => {
-                if matches!(join.join_type, JoinType::Left) {
+                // Filtering the right side of asof join changes which row is the closest match
+                if matches!(
+                    join.join_type,
+                    JoinType::Left | JoinType::Asof | JoinType::LeftAsof
+                ) {
                     original_predicates.push(predicate);
                     continue;
                 }
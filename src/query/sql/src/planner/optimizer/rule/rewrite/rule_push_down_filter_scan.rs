@@ -153,6 +153,7 @@ impl RulePushDownFilterScan {
                             offset: ll.offset,
                             default: new_default,
                             return_type: ll.return_type.clone(),
+                            ignore_null: ll.ignore_null,
                         })
                     }
                     WindowFuncType::NthValue(func) => {
@@ -162,6 +163,7 @@ impl RulePushDownFilterScan {
                             n: func.n,
                             arg: Box::new(new_arg),
                             return_type: func.return_type.clone(),
+                            ignore_null: func.ignore_null,
                         })
                     }
                     func => func.clone(),
@@ -191,6 +191,7 @@ fn replace_column_binding(
                         offset: ll.offset,
                         default: new_default,
                         return_type: ll.return_type.clone(),
+                        ignore_null: ll.ignore_null,
                     })
                 }
                 WindowFuncType::NthValue(func) => {
@@ -199,6 +200,7 @@ fn replace_column_binding(
                         n: func.n,
                         arg: Box::new(new_arg),
                         return_type: func.return_type.clone(),
+                        ignore_null: func.ignore_null,
                     })
                 }
                 t => t,
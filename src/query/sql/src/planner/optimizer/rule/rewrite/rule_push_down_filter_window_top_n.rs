@@ -0,0 +1,167 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+
+use crate::optimizer::rule::constant::check_uint_range;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOp::Pattern;
+use crate::plans::ScalarExpr;
+use crate::plans::Window;
+use crate::plans::WindowFuncType;
+use crate::IndexType;
+
+/// Input:  Filter(row_number <= n)
+///           \
+///          Window(row_number)
+///             \
+///              *
+///
+/// Output: Filter(row_number <= n)
+///           \
+///          Window(row_number, limit = n)
+///             \
+///              *
+///
+/// Only the first n rows of each partition can pass the filter, so the window
+/// can drop the other rows before sorting instead of sorting all of them.
+pub struct RulePushDownFilterWindowTopN {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+}
+
+impl RulePushDownFilterWindowTopN {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::PushDownFilterWindowTopN,
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Filter,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Window,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan { plan_type: Pattern }.into(),
+                    ))),
+                )),
+            )],
+        }
+    }
+}
+
+impl Rule for RulePushDownFilterWindowTopN {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let filter: Filter = s_expr.plan().clone().try_into()?;
+        let window_expr = s_expr.child(0)?;
+        let mut window: Window = window_expr.plan().clone().try_into()?;
+
+        // Other ranking functions may assign the same number to more than n rows.
+        if !matches!(window.function, WindowFuncType::RowNumber) {
+            return Ok(());
+        }
+
+        let limit = match filter
+            .predicates
+            .iter()
+            .filter_map(|predicate| extract_top_n(predicate, window.index))
+            .min()
+        {
+            Some(limit) if limit > 0 => limit,
+            _ => return Ok(()),
+        };
+        if window.limit.map_or(false, |l| l <= limit) {
+            return Ok(());
+        }
+        window.limit = Some(limit);
+
+        let mut result = s_expr.replace_children(vec![Arc::new(
+            window_expr.replace_plan(Arc::new(window.into())),
+        )]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
+
+// Extract n from predicates like `row_number <= n`, `row_number < n` and `row_number = n`.
+fn extract_top_n(predicate: &ScalarExpr, index: IndexType) -> Option<usize> {
+    let (func_name, arguments) = match predicate {
+        ScalarExpr::FunctionCall(FunctionCall {
+            func_name,
+            arguments,
+            ..
+        }) => (func_name, arguments),
+        _ => return None,
+    };
+    if arguments.len() != 2 {
+        return None;
+    }
+    let (func_name, column, constant) = match (&arguments[0], &arguments[1]) {
+        (column, ScalarExpr::ConstantExpr(constant)) => (func_name.as_str(), column, constant),
+        (ScalarExpr::ConstantExpr(constant), column) => {
+            let func_name = match func_name.as_str() {
+                "gt" => "lt",
+                "gte" => "lte",
+                name => name,
+            };
+            (func_name, column, constant)
+        }
+        _ => return None,
+    };
+
+    let mut column = column;
+    while let ScalarExpr::CastExpr(cast) = column {
+        column = &cast.argument;
+    }
+    match column {
+        ScalarExpr::BoundColumnRef(column) if column.column.index == index => {}
+        _ => return None,
+    }
+
+    let (is_uint, value) = check_uint_range(u64::MAX, &constant.value);
+    if !is_uint {
+        return None;
+    }
+    let value = value as usize;
+    match func_name {
+        "lt" => Some(value.saturating_sub(1)),
+        "lte" | "eq" => Some(value),
+        _ => None,
+    }
+}
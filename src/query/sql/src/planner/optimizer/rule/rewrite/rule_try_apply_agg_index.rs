@@ -202,6 +202,10 @@ impl Rule for RuleTryApplyAggIndex {
         s_expr: &SExpr,
         state: &mut crate::optimizer::rule::TransformResult,
     ) -> Result<()> {
+        if Self::has_sample(s_expr) {
+            // Aggregating index is built from all rows of the table.
+            return Ok(());
+        }
         let (table_index, table_name) = self.get_table(s_expr);
         let metadata = self.metadata.read();
         let index_plans = metadata.get_agg_indexes(&table_name);
@@ -242,4 +246,11 @@ impl RuleTryApplyAggIndex {
             _ => self.get_table(s_expr.child(0).unwrap()),
         }
     }
+
+    fn has_sample(s_expr: &SExpr) -> bool {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => scan.sample.is_some(),
+            _ => Self::has_sample(s_expr.child(0).unwrap()),
+        }
+    }
 }
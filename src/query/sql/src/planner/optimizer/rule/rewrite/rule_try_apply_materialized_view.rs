@@ -0,0 +1,439 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use log::info;
+
+use super::agg_index;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::plans::UDFServerCall;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Rewrites the query to read from an up-to-date materialized view of the table.
+///
+/// The matching is the same as aggregating index, but the rows of materialized view
+/// are the final results of the query, so the query is rewritten to scan the view table
+/// directly instead of reading the index data of the source table.
+pub struct RuleTryApplyMaterializedView {
+    id: RuleID,
+    metadata: MetadataRef,
+
+    patterns: Vec<SExpr>,
+}
+
+impl RuleTryApplyMaterializedView {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyMaterializedView,
+            metadata,
+            patterns: vec![
+                // Expression
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
+                ),
+                // Expression
+                //     |
+                //   Filter
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Filter,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_leaf(Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Scan,
+                            }
+                            .into(),
+                        ))),
+                    )),
+                ),
+                // Expression
+                //     |
+                // Aggregation
+                //     |
+                // Expression
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Aggregate,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_unary(
+                            Arc::new(
+                                PatternPlan {
+                                    plan_type: RelOp::Aggregate,
+                                }
+                                .into(),
+                            ),
+                            Arc::new(SExpr::create_unary(
+                                Arc::new(
+                                    PatternPlan {
+                                        plan_type: RelOp::EvalScalar,
+                                    }
+                                    .into(),
+                                ),
+                                Arc::new(SExpr::create_leaf(Arc::new(
+                                    PatternPlan {
+                                        plan_type: RelOp::Scan,
+                                    }
+                                    .into(),
+                                ))),
+                            )),
+                        )),
+                    )),
+                ),
+                // Expression
+                //     |
+                // Aggregation
+                //     |
+                // Expression
+                //     |
+                //   Filter
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Aggregate,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_unary(
+                            Arc::new(
+                                PatternPlan {
+                                    plan_type: RelOp::Aggregate,
+                                }
+                                .into(),
+                            ),
+                            Arc::new(SExpr::create_unary(
+                                Arc::new(
+                                    PatternPlan {
+                                        plan_type: RelOp::EvalScalar,
+                                    }
+                                    .into(),
+                                ),
+                                Arc::new(SExpr::create_unary(
+                                    Arc::new(
+                                        PatternPlan {
+                                            plan_type: RelOp::Filter,
+                                        }
+                                        .into(),
+                                    ),
+                                    Arc::new(SExpr::create_leaf(Arc::new(
+                                        PatternPlan {
+                                            plan_type: RelOp::Scan,
+                                        }
+                                        .into(),
+                                    ))),
+                                )),
+                            )),
+                        )),
+                    )),
+                ),
+            ],
+        }
+    }
+}
+
+impl Rule for RuleTryApplyMaterializedView {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        if Self::has_sample(s_expr) {
+            // Materialized view is built from all rows of the table.
+            return Ok(());
+        }
+        let (table_index, table_name) = self.get_table(s_expr);
+        let metadata = self.metadata.read();
+        let views = match metadata.get_materialized_views(&table_name) {
+            Some(views) if !views.is_empty() => views,
+            _ => return Ok(()),
+        };
+
+        let base_columns = metadata.columns_by_table_index(table_index);
+        let view_plans = views.iter().map(|(_, _, plan, _)| plan).collect::<Vec<_>>();
+        let Some(matched) =
+            agg_index::try_match(table_index, &base_columns, s_expr, &view_plans, false)?
+        else {
+            return Ok(());
+        };
+
+        let (view_index, query, _, output_columns) = &views[matched.position];
+        let view_columns = metadata.columns_by_table_index(*view_index);
+        let view_table = metadata.table(*view_index);
+
+        // Map the outputs of the matched plan to the columns of view table.
+        let mut columns = HashMap::with_capacity(matched.outputs.len());
+        for output in matched.outputs.iter() {
+            let Some(position) = output_columns.iter().position(|i| *i == output.index) else {
+                continue;
+            };
+            let Some(field) = view_table.table().schema().fields().get(position).cloned() else {
+                return Ok(());
+            };
+            let Some(column) = view_columns.iter().find_map(|column| match column {
+                ColumnEntry::BaseTableColumn(column @ BaseTableColumn {
+                    column_name,
+                    path_indices: None,
+                    ..
+                }) if column_name == field.name() => Some(column),
+                _ => None,
+            }) else {
+                return Ok(());
+            };
+            let data_type = DataType::from(&column.data_type);
+            let mut scalar: ScalarExpr = BoundColumnRef {
+                span: None,
+                column: ColumnBindingBuilder::new(
+                    column.column_name.clone(),
+                    column.column_index,
+                    Box::new(data_type.clone()),
+                    Visibility::Visible,
+                )
+                .table_name(Some(view_table.name().to_string()))
+                .database_name(Some(view_table.database().to_string()))
+                .table_index(Some(*view_index))
+                .build(),
+            }
+            .into();
+            if data_type != output.data_type {
+                scalar = CastExpr {
+                    span: None,
+                    is_try: false,
+                    argument: Box::new(scalar),
+                    target_type: Box::new(output.data_type.clone()),
+                }
+                .into();
+            }
+            columns.insert(output.position, (column.column_index, scalar));
+        }
+
+        let mut used_columns = ColumnSet::new();
+        let mut selection = Vec::with_capacity(matched.selection.len());
+        for item in matched.selection.iter() {
+            let Some(scalar) = replace_index_column(&item.scalar, &columns, &mut used_columns)
+            else {
+                return Ok(());
+            };
+            selection.push(ScalarItem {
+                index: item.index,
+                scalar,
+            });
+        }
+        let mut predicates = Vec::with_capacity(matched.predicates.len());
+        for pred in matched.predicates.iter() {
+            let Some(pred) = replace_index_column(pred, &columns, &mut used_columns) else {
+                return Ok(());
+            };
+            predicates.push(pred);
+        }
+
+        // EvalScalar
+        //     |
+        //  [Filter]
+        //     |
+        //    Scan (materialized view)
+        let mut view_expr = SExpr::create_leaf(Arc::new(
+            Scan {
+                table_index: *view_index,
+                columns: used_columns,
+                ..Default::default()
+            }
+            .into(),
+        ));
+        if !predicates.is_empty() {
+            view_expr =
+                SExpr::create_unary(Arc::new(Filter { predicates }.into()), Arc::new(view_expr));
+        }
+        view_expr = SExpr::create_unary(
+            Arc::new(EvalScalar { items: selection }.into()),
+            Arc::new(view_expr),
+        );
+
+        let mut result = match s_expr.child(0)?.plan() {
+            RelOperator::Aggregate(_) if matched.is_agg => {
+                // The view has been aggregated with the same group items,
+                // its rows can be used as the output of the query's `Aggregate` operators.
+                s_expr.replace_children(vec![Arc::new(view_expr)])
+            }
+            RelOperator::Aggregate(_) => {
+                // Use the view as the input of the query's `Aggregate` operators.
+                let final_agg = s_expr.child(0)?;
+                let partial_agg = final_agg.child(0)?;
+                s_expr.replace_children(vec![Arc::new(final_agg.replace_children(vec![Arc::new(
+                    partial_agg.replace_children(vec![Arc::new(view_expr)]),
+                )]))])
+            }
+            _ => view_expr,
+        };
+
+        info!("Use materialized view: {query}");
+
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+
+        Ok(())
+    }
+}
+
+impl RuleTryApplyMaterializedView {
+    fn get_table(&self, s_expr: &SExpr) -> (IndexType, String) {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                let metadata = self.metadata.read();
+                let table = metadata.table(scan.table_index);
+                (
+                    scan.table_index,
+                    format!("{}.{}.{}", table.catalog(), table.database(), table.name()),
+                )
+            }
+            _ => self.get_table(s_expr.child(0).unwrap()),
+        }
+    }
+
+    fn has_sample(s_expr: &SExpr) -> bool {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => scan.sample.is_some(),
+            _ => Self::has_sample(s_expr.child(0).unwrap()),
+        }
+    }
+}
+
+/// Replaces the column refs, which are the output positions of the matched plan,
+/// with the columns of view table.
+///
+/// Returns [None] if an output is not stored in the view table.
+fn replace_index_column(
+    scalar: &ScalarExpr,
+    columns: &HashMap<usize, (IndexType, ScalarExpr)>,
+    used_columns: &mut ColumnSet,
+) -> Option<ScalarExpr> {
+    match scalar {
+        ScalarExpr::BoundColumnRef(col) => {
+            let (index, column) = columns.get(&col.column.index)?;
+            used_columns.insert(*index);
+            Some(column.clone())
+        }
+        ScalarExpr::ConstantExpr(_) => Some(scalar.clone()),
+        ScalarExpr::CastExpr(cast) => Some(
+            CastExpr {
+                span: cast.span,
+                is_try: cast.is_try,
+                argument: Box::new(replace_index_column(&cast.argument, columns, used_columns)?),
+                target_type: cast.target_type.clone(),
+            }
+            .into(),
+        ),
+        ScalarExpr::FunctionCall(func) => Some(
+            FunctionCall {
+                span: func.span,
+                func_name: func.func_name.clone(),
+                params: func.params.clone(),
+                arguments: func
+                    .arguments
+                    .iter()
+                    .map(|arg| replace_index_column(arg, columns, used_columns))
+                    .collect::<Option<Vec<_>>>()?,
+            }
+            .into(),
+        ),
+        ScalarExpr::UDFServerCall(udf) => Some(
+            UDFServerCall {
+                span: udf.span,
+                func_name: udf.func_name.clone(),
+                server_addr: udf.server_addr.clone(),
+                arg_types: udf.arg_types.clone(),
+                return_type: udf.return_type.clone(),
+                arguments: udf
+                    .arguments
+                    .iter()
+                    .map(|arg| replace_index_column(arg, columns, used_columns))
+                    .collect::<Option<Vec<_>>>()?,
+            }
+            .into(),
+        ),
+        _ => None,
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::VectorTopK;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::ArrayType;
+use common_expression::types::DataType;
+use common_expression::types::Float32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::ValueType;
+use common_expression::types::F32;
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_table_meta::table::get_vector_indexes;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Input:  Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///             Scan
+///
+/// Output:
+///         Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///             Scan(padding vector_top_k)
+///
+/// Applies to `ORDER BY cosine_distance(col, <const>) LIMIT n` (or `l2_distance`)
+/// if `col` has a vector index built for the distance. The scan then only
+/// reads the blocks holding the approximate nearest vectors, the sort still
+/// ranks the rows by the exact distance.
+pub struct RuleTryApplyVectorIndex {
+    id: RuleID,
+    metadata: MetadataRef,
+    patterns: Vec<SExpr>,
+}
+
+impl RuleTryApplyVectorIndex {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyVectorIndex,
+            metadata,
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Sort,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
+                )),
+            )],
+        }
+    }
+
+    /// Match the sort key against the vector indexes of the scanned table.
+    fn try_match(&self, sort: &Sort, eval_scalar: &EvalScalar, scan: &Scan) -> Option<VectorTopK> {
+        let limit = sort.limit?;
+        let item = sort.items.first()?;
+        if !item.asc {
+            return None;
+        }
+        let scalar = &eval_scalar
+            .items
+            .iter()
+            .find(|scalar_item| scalar_item.index == item.index)?
+            .scalar;
+        let ScalarExpr::FunctionCall(func) = scalar else {
+            return None;
+        };
+        if func.arguments.len() != 2 {
+            return None;
+        }
+        let (column, query) = match (&func.arguments[0], &func.arguments[1]) {
+            (ScalarExpr::BoundColumnRef(column), ScalarExpr::ConstantExpr(query))
+            | (ScalarExpr::ConstantExpr(query), ScalarExpr::BoundColumnRef(column)) => {
+                (column, query)
+            }
+            _ => return None,
+        };
+
+        let metadata = self.metadata.read();
+        let (column_name, nullable) = match metadata.column(column.column.index) {
+            ColumnEntry::BaseTableColumn(column)
+                if column.table_index == scan.table_index && column.path_indices.is_none() =>
+            {
+                (column.column_name.clone(), column.data_type.is_nullable())
+            }
+            _ => return None,
+        };
+        // Null distances come first, they are not in the index.
+        if item.nulls_first && nullable {
+            return None;
+        }
+
+        let table = metadata.table(scan.table_index).table();
+        let column_id = table.schema().column_id_of(&column_name).ok()?;
+        let indexes = get_vector_indexes(table.options()).ok()?;
+        let index = indexes.iter().find(|index| {
+            index.column_id == column_id && index.distance.function_name() == func.func_name
+        })?;
+
+        Some(VectorTopK {
+            index_id: index.id.clone(),
+            query: const_vector(&query.value)?,
+            limit,
+        })
+    }
+}
+
+/// Cast a constant to `Array(Float32)`.
+fn const_vector(value: &Scalar) -> Option<Vec<F32>> {
+    let expr = Expr::<String>::Constant {
+        span: None,
+        scalar: value.clone(),
+        data_type: value.as_ref().infer_data_type(),
+    };
+    let dest_type = DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)));
+    let expr = check_cast(None, false, expr, &dest_type, &BUILTIN_FUNCTIONS).ok()?;
+    let func_ctx = FunctionContext::default();
+    match ConstantFolder::fold(&expr, &func_ctx, &BUILTIN_FUNCTIONS).0 {
+        Expr::Constant { scalar, .. } => {
+            let vector = ArrayType::<Float32Type>::try_downcast_scalar(&scalar.as_ref())?;
+            Some(vector.iter().copied().collect())
+        }
+        _ => None,
+    }
+}
+
+impl Rule for RuleTryApplyVectorIndex {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut scan: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+        if scan.vector_top_k.is_some()
+            || scan.push_down_predicates.is_some()
+            || scan.prewhere.is_some()
+            || scan.sample.is_some()
+            || scan.agg_index.is_some()
+        {
+            return Ok(());
+        }
+        let Some(vector_top_k) = self.try_match(&sort, &eval_scalar, &scan) else {
+            return Ok(());
+        };
+        scan.vector_top_k = Some(vector_top_k);
+
+        let scan = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
+        let eval_scalar = eval_scalar_expr.replace_children(vec![Arc::new(scan)]);
+        let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
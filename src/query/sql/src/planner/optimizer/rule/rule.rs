@@ -51,6 +51,7 @@ pub enum RuleID {
     PushDownFilterScan,
     PushDownFilterSort,
     PushDownFilterProjectSet,
+    PushDownFilterWindowTopN,
     PushDownLimitUnion,
     PushDownLimitOuterJoin,
     PushDownLimitExpression,
@@ -66,6 +67,8 @@ pub enum RuleID {
     FoldCountAggregate,
     PushDownPrewhere,
     TryApplyAggIndex,
+    TryApplyMaterializedView,
+    TryApplyVectorIndex,
     CommuteJoin,
 
     // Exploration rules
@@ -83,6 +86,7 @@ impl Display for RuleID {
             RuleID::PushDownFilterScan => write!(f, "PushDownFilterScan"),
             RuleID::PushDownFilterSort => write!(f, "PushDownFilterSort"),
             RuleID::PushDownFilterProjectSet => write!(f, "PushDownFilterProjectSet"),
+            RuleID::PushDownFilterWindowTopN => write!(f, "PushDownFilterWindowTopN"),
             RuleID::PushDownLimitUnion => write!(f, "PushDownLimitUnion"),
             RuleID::PushDownLimitOuterJoin => write!(f, "PushDownLimitOuterJoin"),
             RuleID::PushDownLimitExpression => write!(f, "PushDownLimitExpression"),
@@ -107,6 +111,8 @@ impl Display for RuleID {
             RuleID::LeftExchangeJoin => write!(f, "LeftExchangeJoin"),
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
+            RuleID::TryApplyMaterializedView => write!(f, "TryApplyMaterializedView"),
+            RuleID::TryApplyVectorIndex => write!(f, "TryApplyVectorIndex"),
         }
     }
 }
@@ -22,6 +22,7 @@ use educe::Educe;
 use super::RelationalProperty;
 use crate::optimizer::rule::AppliedRules;
 use crate::optimizer::rule::RuleID;
+use crate::optimizer::RelExpr;
 use crate::optimizer::StatInfo;
 use crate::plans::Operator;
 use crate::plans::PatternPlan;
@@ -199,6 +200,27 @@ impl SExpr {
         true
     }
 
+    /// Check if contain a join whose right child refers to the columns of its left child,
+    /// which is bound from a `LATERAL` subquery or table function.
+    pub(crate) fn contain_lateral_join(&self) -> Result<bool> {
+        if let RelOperator::Join(_) = self.plan.as_ref() {
+            let left_prop = RelExpr::with_s_expr(self.child(0)?).derive_relational_prop()?;
+            let right_prop = RelExpr::with_s_expr(self.child(1)?).derive_relational_prop()?;
+            if !right_prop
+                .outer_columns
+                .is_disjoint(&left_prop.output_columns)
+            {
+                return Ok(true);
+            }
+        }
+        for child in self.children.iter() {
+            if child.contain_lateral_join()? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     // Add (table_index, column_index) into `Scan` node recursively.
     pub fn add_internal_column_index(
         expr: &SExpr,
@@ -173,6 +173,7 @@ impl Operator for ConstantTableScan {
                 ndv: ndv as f64,
                 null_count,
                 histogram,
+                most_common_values: vec![],
             };
             column_stats.insert(*index, column_stat);
         }
@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_ast::ast::TableIndexType;
+use common_expression::ColumnId;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::TableInfo;
 use common_meta_types::MetaId;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::table::VectorIndexDistance;
+use storages_common_table_meta::table::VectorIndexType;
 
 use crate::plans::Plan;
 
@@ -48,3 +53,33 @@ pub struct RefreshIndexPlan {
     pub user_defined_block_name: bool,
     pub segment_locs: Option<Vec<Location>>,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateVectorIndexPlan {
+    pub if_not_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+    pub column_id: ColumnId,
+    pub index_type: VectorIndexType,
+    pub distance: VectorIndexDistance,
+    pub options: BTreeMap<String, u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropVectorIndexPlan {
+    pub if_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshVectorIndexPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+}
@@ -17,8 +17,10 @@ mod catalog;
 mod database;
 mod file_format;
 mod index;
+mod sequence;
 mod stage;
 mod table;
+mod task;
 mod udf;
 mod view;
 mod virtual_column;
@@ -28,8 +30,10 @@ pub use catalog::*;
 pub use database::*;
 pub use file_format::*;
 pub use index::*;
+pub use sequence::*;
 pub use stage::*;
 pub use table::*;
+pub use task::*;
 pub use udf::*;
 pub use view::*;
 pub use virtual_column::*;
@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::Utc;
+use common_meta_app::schema::CreateSequenceReq;
+use common_meta_app::schema::DropSequenceReq;
+use common_meta_app::schema::SequenceNameIdent;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequencePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub sequence: String,
+    pub start: u64,
+    pub step: u64,
+    pub comment: Option<String>,
+}
+
+impl From<CreateSequencePlan> for CreateSequenceReq {
+    fn from(p: CreateSequencePlan) -> Self {
+        CreateSequenceReq {
+            if_not_exists: p.if_not_exists,
+            name_ident: SequenceNameIdent::new(p.tenant, p.sequence),
+            start: p.start,
+            step: p.step,
+            comment: p.comment,
+            create_on: Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropSequencePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub sequence: String,
+}
+
+impl From<DropSequencePlan> for DropSequenceReq {
+    fn from(p: DropSequencePlan) -> Self {
+        DropSequenceReq {
+            if_exists: p.if_exists,
+            name_ident: SequenceNameIdent::new(p.tenant, p.sequence),
+        }
+    }
+}
@@ -30,6 +30,7 @@ use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::storage::StorageParams;
 
+use crate::plans::CreateSequencePlan;
 use crate::plans::Plan;
 
 pub type TableOptions = BTreeMap<String, String>;
@@ -37,6 +38,7 @@ pub type TableOptions = BTreeMap<String, String>;
 #[derive(Clone, Debug)]
 pub struct CreateTablePlan {
     pub if_not_exists: bool,
+    pub temporary: bool,
     pub tenant: String,
     pub catalog: String,
     pub database: String,
@@ -49,6 +51,8 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    /// Sequences backing the AUTOINCREMENT columns, created along with the table.
+    pub auto_increment_sequences: Vec<CreateSequencePlan>,
     pub as_select: Option<Box<Plan>>,
 }
 
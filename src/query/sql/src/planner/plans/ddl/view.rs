@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::TableSchemaRef;
+use common_meta_app::schema::TableInfo;
+
+use crate::plans::TableOptions;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
     pub if_not_exists: bool,
@@ -41,3 +46,33 @@ pub struct DropViewPlan {
     pub database: String,
     pub view_name: String,
 }
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub schema: TableSchemaRef,
+    pub options: TableOptions,
+    pub subquery: String,
+    pub source_database: String,
+    pub source_table: String,
+    pub source_table_id: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct RefreshMaterializedViewPlan {
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub table_info: TableInfo,
+    pub source_table_id: u64,
+    /// The snapshot location of the source table at the last refresh.
+    pub refreshed_snapshot: Option<String>,
+    pub query: String,
+    /// The query merging new rows into an aggregating materialized view,
+    /// `None` if the materialized view is not aggregating.
+    pub merge_query: Option<String>,
+}
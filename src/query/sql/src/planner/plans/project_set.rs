@@ -56,6 +56,18 @@ impl Operator for ProjectSet {
         for srf in &self.srfs {
             child_prop.output_columns.insert(srf.index);
         }
+        // The arguments of set-returning functions may refer to outer columns,
+        // e.g. `SELECT * FROM t, LATERAL unnest(t.arr)`.
+        for srf in &self.srfs {
+            let used_columns = srf.scalar.used_columns();
+            child_prop.used_columns.extend(used_columns.iter().cloned());
+            child_prop.outer_columns.extend(
+                used_columns
+                    .difference(&child_prop.output_columns)
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            );
+        }
         Ok(Arc::new(child_prop))
     }
 
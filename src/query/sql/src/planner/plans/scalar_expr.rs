@@ -455,6 +455,8 @@ pub struct LagLeadFunction {
     pub offset: u64,
     pub default: Option<Box<ScalarExpr>>,
     pub return_type: Box<DataType>,
+    /// Skip null values when counting the offset (`IGNORE NULLS`).
+    pub ignore_null: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -467,6 +469,8 @@ pub struct NthValueFunction {
     pub n: Option<u64>,
     pub arg: Box<ScalarExpr>,
     pub return_type: Box<DataType>,
+    /// Skip null values in the window frame (`IGNORE NULLS`).
+    pub ignore_null: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
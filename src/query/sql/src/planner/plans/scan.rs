@@ -16,6 +16,8 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::plan::SampleInfo;
+use common_catalog::plan::VectorTopK;
 use common_catalog::statistics::BasicColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
@@ -94,6 +96,8 @@ pub struct Scan {
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
+    pub sample: Option<SampleInfo>,
+    pub vector_top_k: Option<VectorTopK>,
 
     pub statistics: Statistics,
 }
@@ -120,6 +124,8 @@ impl Scan {
             },
             prewhere,
             agg_index: self.agg_index.clone(),
+            sample: self.sample.clone(),
+            vector_top_k: self.vector_top_k.clone(),
         }
     }
 
@@ -144,6 +150,8 @@ impl PartialEq for Scan {
         self.table_index == other.table_index
             && self.columns == other.columns
             && self.push_down_predicates == other.push_down_predicates
+            && self.sample == other.sample
+            && self.vector_top_k == other.vector_top_k
     }
 }
 
@@ -156,6 +164,8 @@ impl std::hash::Hash for Scan {
             column.hash(state);
         }
         self.push_down_predicates.hash(state);
+        self.sample.hash(state);
+        self.vector_top_k.hash(state);
     }
 }
 
@@ -199,19 +209,23 @@ impl Operator for Scan {
                 let min = col_stat.min.unwrap();
                 let max = col_stat.max.unwrap();
                 let ndv = col_stat.ndv.unwrap();
-                let histogram = histogram_from_ndv(
-                    ndv,
-                    num_rows,
-                    Some((min.clone(), max.clone())),
-                    DEFAULT_HISTOGRAM_BUCKETS,
-                )
-                .ok();
+                // Prefer the histogram sampled by `ANALYZE TABLE` if there is one.
+                let histogram = col_stat.histogram.or_else(|| {
+                    histogram_from_ndv(
+                        ndv,
+                        num_rows,
+                        Some((min.clone(), max.clone())),
+                        DEFAULT_HISTOGRAM_BUCKETS,
+                    )
+                    .ok()
+                });
                 let column_stat = ColumnStat {
                     min,
                     max,
                     ndv: ndv as f64,
                     null_count: col_stat.null_count,
                     histogram,
+                    most_common_values: col_stat.most_common_values,
                 };
                 column_stats.insert(*k as IndexType, column_stat);
             }
@@ -245,8 +259,14 @@ impl Operator for Scan {
             (_, _) => 0.0,
         };
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        // Sampling keeps each row (or block) with the given probability.
+        let cardinality = match &self.sample {
+            Some(sample) => cardinality * sample.probability.0,
+            None => cardinality,
+        };
+
+        // If prewhere or sample is not none, we can't get precise cardinality
+        let precise_cardinality = if self.prewhere.is_none() && self.sample.is_none() {
             precise_cardinality
         } else {
             None
@@ -64,6 +64,9 @@ pub struct Window {
     pub order_by: Vec<WindowOrderByInfo>,
     // window frames
     pub frame: WindowFuncFrame,
+    // Only keep the top n rows of each partition, pushed down from
+    // a filter such as `row_number() <= n`.
+    pub limit: Option<usize>,
 }
 
 impl Window {
@@ -251,7 +254,11 @@ impl WindowFuncType {
             WindowFuncType::PercentRank => "percent_rank".to_string(),
             WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => "lag".to_string(),
             WindowFuncType::LagLead(_) => "lead".to_string(),
-            WindowFuncType::NthValue(_) => "nth_value".to_string(),
+            WindowFuncType::NthValue(func) => match func.n {
+                Some(1) => "first_value".to_string(),
+                Some(_) => "nth_value".to_string(),
+                None => "last_value".to_string(),
+            },
             WindowFuncType::Ntile(_) => "ntile".to_string(),
             WindowFuncType::CumeDist => "cume_dist".to_string(),
         }
@@ -31,11 +31,13 @@ impl VisitorMut for AggregateRewriter {
                 distinct,
                 name,
                 args,
+                filter,
                 window,
                 ..
             } if !*distinct
                 && args.len() == 1
                 && name.name.to_ascii_lowercase().to_lowercase() == "sum"
+                && filter.is_none()
                 && window.is_none() =>
             {
                 match &args[0] {
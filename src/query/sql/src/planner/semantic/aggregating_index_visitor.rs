@@ -18,12 +18,13 @@ use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
+use common_ast::ast::OrderByExpr;
 use common_ast::ast::Query;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
 use common_ast::ast::TableReference;
-use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::walk_expr;
 use common_ast::walk_select_target;
 use common_ast::walk_select_target_mut;
@@ -60,12 +61,16 @@ impl VisitorMut for AggregatingIndexRewriter {
                 distinct,
                 name,
                 args,
+                within_group,
+                filter,
                 window,
                 ..
             } if !*distinct
                 && args.len() == 1
                 && SUPPORTED_AGGREGATING_INDEX_FUNCTIONS
                     .contains(&&*name.name.to_ascii_lowercase().to_lowercase())
+                && within_group.is_empty()
+                && filter.is_none()
                 && window.is_none() =>
             {
                 self.has_agg_function = true;
@@ -83,6 +88,8 @@ impl VisitorMut for AggregatingIndexRewriter {
                     },
                     args: vec![],
                     params: vec![],
+                    within_group: vec![],
+                    filter: None,
                     window: None,
                     lambda: None,
                 };
@@ -168,13 +175,20 @@ impl<'ast> Visitor<'ast> for AggregatingIndexChecker {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        _over: &'ast Option<Window>,
+        within_group: &'ast [OrderByExpr],
+        filter: &'ast Option<Box<Expr>>,
+        _over: &'ast Option<WindowDesc>,
         _lambda: &'ast Option<Lambda>,
     ) {
         if self.not_support {
             return;
         }
 
+        if !within_group.is_empty() || filter.is_some() {
+            self.not_support = true;
+            return;
+        }
+
         // is agg func but not support now.
         if AggregateFunctionFactory::instance().contains(&name.name)
             && !SUPPORTED_AGGREGATING_INDEX_FUNCTIONS.contains(&&*name.name.to_lowercase())
@@ -197,7 +211,7 @@ impl<'ast> Visitor<'ast> for AggregatingIndexChecker {
         if self.not_support {
             return;
         }
-        if stmt.having.is_some() || stmt.window_list.is_some() {
+        if stmt.having.is_some() || stmt.qualify.is_some() || stmt.window_list.is_some() {
             self.not_support = true;
             return;
         }
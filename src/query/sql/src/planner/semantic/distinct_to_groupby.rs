@@ -32,6 +32,7 @@ impl VisitorMut for DistinctToGroupBy {
             selection,
             group_by,
             having,
+            qualify,
             window_list,
             ..
         } = stmt;
@@ -65,6 +66,7 @@ impl VisitorMut for DistinctToGroupBy {
                             selection: selection.clone(),
                             group_by: Some(GroupBy::Normal(args.clone())),
                             having: None,
+                            qualify: None,
                             window_list: None,
                         })),
                         order_by: vec![],
@@ -88,6 +90,8 @@ impl VisitorMut for DistinctToGroupBy {
                                 },
                                 args: vec![],
                                 params: vec![],
+                                within_group: vec![],
+                                filter: None,
                                 window: None,
                                 lambda: None,
                             }),
@@ -95,12 +99,14 @@ impl VisitorMut for DistinctToGroupBy {
                         }],
                         from: vec![TableReference::Subquery {
                             span: None,
+                            lateral: false,
                             subquery: Box::new(subquery),
                             alias: None,
                         }],
                         selection: None,
                         group_by: None,
                         having: having.clone(),
+                        qualify: qualify.clone(),
                         window_list: window_list.clone(),
                     };
 
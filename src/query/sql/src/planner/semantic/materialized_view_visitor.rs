@@ -0,0 +1,281 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::ColumnID;
+use common_ast::ast::Expr;
+use common_ast::ast::GroupBy;
+use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
+use common_ast::ast::Literal;
+use common_ast::ast::OrderByExpr;
+use common_ast::ast::Query;
+use common_ast::ast::SelectStmt;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::TableReference;
+use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
+use common_ast::walk_expr;
+use common_ast::Visitor;
+use common_exception::Span;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::planner::SUPPORTED_MATERIALIZED_VIEW_FUNCTIONS;
+
+/// Checks whether a query can be maintained incrementally as a materialized view,
+/// and collects the function used to merge each output column on refresh.
+///
+/// A supported query is a single `SELECT` over one table, optionally with `WHERE`
+/// and `GROUP BY`. If it aggregates, every output column must be either a group key
+/// or one of the decomposable aggregate functions `SUM`, `COUNT`, `MIN` and `MAX`.
+#[derive(Debug, Clone, Default)]
+pub struct MaterializedViewChecker {
+    not_support: bool,
+    agg_func_count: usize,
+    /// The merge function of each output column, `None` means the column is a group key.
+    pub merge_functions: Vec<Option<String>>,
+}
+
+impl MaterializedViewChecker {
+    pub fn is_supported(&self) -> bool {
+        !self.not_support
+    }
+
+    /// Whether the query of the materialized view contains aggregate functions.
+    pub fn is_aggregating(&self) -> bool {
+        self.merge_functions.iter().any(|f| f.is_some())
+    }
+
+    fn merge_function(expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::CountAll { window: None, .. } => Some("sum".to_string()),
+            Expr::FunctionCall {
+                distinct: false,
+                name,
+                args,
+                within_group,
+                filter: None,
+                window: None,
+                ..
+            } if args.len() == 1 && within_group.is_empty() => {
+                let name = name.name.to_lowercase();
+                match name.as_str() {
+                    "count" => Some("sum".to_string()),
+                    "sum" | "min" | "max" => Some(name),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn is_group_key(group_item: &Expr, keys: &[(&Expr, &Option<Identifier>)]) -> bool {
+        match group_item {
+            Expr::ColumnRef {
+                database: None,
+                table: None,
+                column: ColumnID::Name(column),
+                ..
+            } if keys.iter().any(|(_, alias)| {
+                alias
+                    .as_ref()
+                    .map_or(false, |alias| alias.name.eq_ignore_ascii_case(&column.name))
+            }) =>
+            {
+                true
+            }
+            _ => {
+                let item = group_item.to_string();
+                keys.iter().any(|(expr, _)| expr.to_string() == item)
+            }
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for MaterializedViewChecker {
+    fn visit_count_all(&mut self, _span: Span, window: &'ast Option<Window>) {
+        if window.is_some() {
+            self.not_support = true;
+        }
+        self.agg_func_count += 1;
+    }
+
+    fn visit_function_call(
+        &mut self,
+        _span: Span,
+        distinct: bool,
+        name: &'ast Identifier,
+        args: &'ast [Expr],
+        _params: &'ast [Literal],
+        within_group: &'ast [OrderByExpr],
+        filter: &'ast Option<Box<Expr>>,
+        over: &'ast Option<WindowDesc>,
+        _lambda: &'ast Option<Lambda>,
+    ) {
+        if self.not_support {
+            return;
+        }
+
+        if over.is_some() || !within_group.is_empty() || filter.is_some() {
+            self.not_support = true;
+            return;
+        }
+
+        if AggregateFunctionFactory::instance().contains(&name.name) {
+            if distinct
+                || !SUPPORTED_MATERIALIZED_VIEW_FUNCTIONS.contains(&&*name.name.to_lowercase())
+            {
+                self.not_support = true;
+                return;
+            }
+            self.agg_func_count += 1;
+        }
+
+        if BUILTIN_FUNCTIONS
+            .get_property(&name.name)
+            .map(|p| p.non_deterministic)
+            .unwrap_or(false)
+        {
+            self.not_support = true;
+            return;
+        }
+
+        for arg in args {
+            walk_expr(self, arg);
+        }
+    }
+
+    fn visit_select_stmt(&mut self, stmt: &'ast SelectStmt) {
+        if self.not_support {
+            return;
+        }
+        if stmt.distinct
+            || stmt.having.is_some()
+            || stmt.qualify.is_some()
+            || stmt.window_list.is_some()
+        {
+            self.not_support = true;
+            return;
+        }
+
+        match stmt.from.as_slice() {
+            [
+                TableReference::Table {
+                    travel_point: None,
+                    pivot: None,
+                    unpivot: None,
+                    sample: None,
+                    ..
+                },
+            ] => {}
+            _ => {
+                self.not_support = true;
+                return;
+            }
+        }
+
+        if let Some(selection) = &stmt.selection {
+            walk_expr(self, selection);
+            if self.agg_func_count > 0 {
+                self.not_support = true;
+                return;
+            }
+        }
+
+        let mut keys = Vec::with_capacity(stmt.select_list.len());
+        for target in &stmt.select_list {
+            let (expr, alias) = match target {
+                SelectTarget::AliasedExpr { expr, alias } => (expr.as_ref(), alias),
+                SelectTarget::QualifiedName { .. } => {
+                    self.not_support = true;
+                    return;
+                }
+            };
+            let agg_func_count = self.agg_func_count;
+            walk_expr(self, expr);
+            if self.not_support {
+                return;
+            }
+            if self.agg_func_count == agg_func_count {
+                keys.push((expr, alias));
+                self.merge_functions.push(None);
+                continue;
+            }
+            // An aggregate function must be the whole output column so that it can be merged.
+            match Self::merge_function(expr) {
+                Some(merge_function) if self.agg_func_count == agg_func_count + 1 => {
+                    self.merge_functions.push(Some(merge_function))
+                }
+                _ => {
+                    self.not_support = true;
+                    return;
+                }
+            }
+        }
+
+        match &stmt.group_by {
+            None => {}
+            Some(GroupBy::Normal(exprs)) => {
+                let agg_func_count = self.agg_func_count;
+                for expr in exprs {
+                    walk_expr(self, expr);
+                    // Every group key must be an output column, otherwise the groups
+                    // can not be identified when merging.
+                    let is_key = match expr {
+                        Expr::Literal {
+                            lit: Literal::UInt64(pos),
+                            ..
+                        } => (*pos as usize)
+                            .checked_sub(1)
+                            .and_then(|i| self.merge_functions.get(i))
+                            .map_or(false, |f| f.is_none()),
+                        _ => Self::is_group_key(expr, &keys),
+                    };
+                    if !is_key {
+                        self.not_support = true;
+                        return;
+                    }
+                }
+                if self.agg_func_count != agg_func_count {
+                    self.not_support = true;
+                }
+            }
+            Some(_) => {
+                self.not_support = true;
+            }
+        }
+    }
+
+    fn visit_query(&mut self, query: &'ast Query) {
+        if self.not_support {
+            return;
+        }
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            self.not_support = true;
+            return;
+        }
+
+        if !matches!(&query.body, SetExpr::Select(_)) {
+            self.not_support = true;
+            return;
+        }
+
+        self.visit_set_expr(&query.body);
+    }
+}
@@ -17,6 +17,7 @@ mod aggregating_index_visitor;
 mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
+mod materialized_view_visitor;
 mod name_resolution;
 mod type_check;
 mod view_rewriter;
@@ -28,6 +29,7 @@ pub use aggregating_index_visitor::AggregatingIndexRewriter;
 pub use distinct_to_groupby::DistinctToGroupBy;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
+pub use materialized_view_visitor::MaterializedViewChecker;
 pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
@@ -41,3 +43,5 @@ pub use window_check::WindowChecker;
 
 pub(crate) const SUPPORTED_AGGREGATING_INDEX_FUNCTIONS: [&str; 5] =
     ["sum", "min", "max", "avg", "approx_count_distinct"];
+
+pub(crate) const SUPPORTED_MATERIALIZED_VIEW_FUNCTIONS: [&str; 4] = ["sum", "count", "min", "max"];
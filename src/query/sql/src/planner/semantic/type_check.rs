@@ -24,6 +24,7 @@ use common_ast::ast::Identifier;
 use common_ast::ast::IntervalKind as ASTIntervalKind;
 use common_ast::ast::Literal;
 use common_ast::ast::MapAccessor;
+use common_ast::ast::OrderByExpr;
 use common_ast::ast::Query;
 use common_ast::ast::SubqueryModifier;
 use common_ast::ast::TrimWhere;
@@ -372,6 +373,8 @@ impl<'a> TypeChecker<'a> {
                             },
                             args: args.iter().copied().cloned().collect(),
                             params: vec![],
+                            within_group: vec![],
+                            filter: None,
                             window: None,
                             lambda: None,
                         })
@@ -616,6 +619,8 @@ impl<'a> TypeChecker<'a> {
                                 },
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
+                                within_group: vec![],
+                                filter: None,
                                 window: None,
                                 lambda: None,
                             };
@@ -661,6 +666,15 @@ impl<'a> TypeChecker<'a> {
                 Box::new((ConstantExpr { span: *span, value }.into(), data_type))
             }
 
+            Expr::FunctionCall {
+                within_group,
+                filter,
+                ..
+            } if !within_group.is_empty() || filter.is_some() => {
+                let new_expr = self.rewrite_aggregate_modifiers(expr)?;
+                self.resolve(&new_expr).await?
+            }
+
             Expr::FunctionCall {
                 span,
                 distinct,
@@ -669,6 +683,7 @@ impl<'a> TypeChecker<'a> {
                 params,
                 window,
                 lambda,
+                ..
             } => {
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
                 let func_name = func_name.as_str();
@@ -717,13 +732,10 @@ impl<'a> TypeChecker<'a> {
 
                 // check window function legal
                 if window.is_some() {
-                    let supported_window_funcs = AggregateFunctionFactory::instance()
-                        .registered_names()
-                        .into_iter()
-                        .chain(GENERAL_WINDOW_FUNCTIONS.iter().cloned().map(str::to_string))
-                        .collect::<Vec<String>>();
                     let name = func_name.to_lowercase();
-                    if !supported_window_funcs.contains(&name) {
+                    if !AggregateFunctionFactory::instance().contains(&name)
+                        && !GENERAL_WINDOW_FUNCTIONS.contains(&name.as_str())
+                    {
                         return Err(ErrorCode::SemanticError(
                             "only general and aggregate functions allowed in window syntax",
                         )
@@ -784,12 +796,12 @@ impl<'a> TypeChecker<'a> {
                             "window function {name} can only be used in window clause"
                         )));
                     }
+                    let window = window.as_ref().unwrap();
                     let func = self
-                        .resolve_general_window_function(*span, &name, &args)
+                        .resolve_general_window_function(*span, &name, &args, window.ignore_nulls)
                         .await?;
-                    let window = window.as_ref().unwrap();
                     let display_name = format!("{:#}", expr);
-                    self.resolve_window(*span, display_name, window, func)
+                    self.resolve_window(*span, display_name, &window.window, func)
                         .await?
                 } else if AggregateFunctionFactory::instance().contains(&name) {
                     if matches!(
@@ -812,9 +824,15 @@ impl<'a> TypeChecker<'a> {
                     self.in_aggregate_function = in_aggregate_function;
                     if let Some(window) = window {
                         // aggregate window function
+                        if window.ignore_nulls.is_some() {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "IGNORE NULLS or RESPECT NULLS is not supported by aggregate function {name}"
+                            ))
+                            .set_span(*span));
+                        }
                         let display_name = format!("{:#}", expr);
                         let func = WindowFuncType::Aggregate(new_agg_func);
-                        self.resolve_window(*span, display_name, window, func)
+                        self.resolve_window(*span, display_name, &window.window, func)
                             .await?
                     } else {
                         // aggregate function
@@ -1042,12 +1060,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit).await?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -1338,6 +1351,24 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Following(None),
                 });
             }
+            WindowFuncType::LagLead(lag_lead) if lag_lead.ignore_null && lag_lead.offset > 0 => {
+                // With `IGNORE NULLS` the offset only counts non-null rows,
+                // so the frame covers all rows before (lag) or after (lead) the current row.
+                let one = Some(Scalar::Number(NumberScalar::UInt64(1)));
+                return Ok(if lag_lead.is_lag {
+                    WindowFuncFrame {
+                        units: WindowFuncFrameUnits::Rows,
+                        start_bound: WindowFuncFrameBound::Preceding(None),
+                        end_bound: WindowFuncFrameBound::Preceding(one),
+                    }
+                } else {
+                    WindowFuncFrame {
+                        units: WindowFuncFrameUnits::Rows,
+                        start_bound: WindowFuncFrameBound::Following(one),
+                        end_bound: WindowFuncFrameBound::Following(None),
+                    }
+                });
+            }
             WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => {
                 return Ok(WindowFuncFrame {
                     units: WindowFuncFrameUnits::Rows,
@@ -1411,7 +1442,20 @@ impl<'a> TypeChecker<'a> {
         span: Span,
         func_name: &str,
         args: &[&Expr],
+        ignore_nulls: Option<bool>,
     ) -> Result<WindowFuncType> {
+        if ignore_nulls.is_some()
+            && !matches!(
+                func_name,
+                "lag" | "lead" | "first_value" | "first" | "last_value" | "last" | "nth_value"
+            )
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "IGNORE NULLS or RESPECT NULLS is not supported by window function {func_name}"
+            ))
+            .set_span(span));
+        }
+
         // try to resolve window function without arguments first
         if let Ok(window_func) = WindowFuncType::from_name(func_name) {
             return Ok(window_func);
@@ -1435,14 +1479,25 @@ impl<'a> TypeChecker<'a> {
         }
         self.in_window_function = false;
 
+        let ignore_null = ignore_nulls.unwrap_or(false);
         match func_name {
             "lag" | "lead" => {
-                self.resolve_lag_lead_window_function(func_name, &arguments, &arg_types)
-                    .await
+                self.resolve_lag_lead_window_function(
+                    func_name,
+                    &arguments,
+                    &arg_types,
+                    ignore_null,
+                )
+                .await
             }
             "first_value" | "first" | "last_value" | "last" | "nth_value" => {
-                self.resolve_nth_value_window_function(func_name, &arguments, &arg_types)
-                    .await
+                self.resolve_nth_value_window_function(
+                    func_name,
+                    &arguments,
+                    &arg_types,
+                    ignore_null,
+                )
+                .await
             }
             "ntile" => self.resolve_ntile_window_function(&arguments).await,
             _ => Err(ErrorCode::UnknownFunction(format!(
@@ -1457,6 +1512,7 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[ScalarExpr],
         arg_types: &[DataType],
+        ignore_null: bool,
     ) -> Result<WindowFuncType> {
         if args.is_empty() || args.len() > 3 {
             return Err(ErrorCode::InvalidArgument(format!(
@@ -1518,6 +1574,7 @@ impl<'a> TypeChecker<'a> {
             offset: offset.unsigned_abs(),
             default: cast_default,
             return_type: Box::new(return_type),
+            ignore_null,
         }))
     }
 
@@ -1527,6 +1584,7 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[ScalarExpr],
         arg_types: &[DataType],
+        ignore_null: bool,
     ) -> Result<WindowFuncType> {
         Ok(match func_name {
             "first_value" | "first" => {
@@ -1541,6 +1599,7 @@ impl<'a> TypeChecker<'a> {
                     n: Some(1),
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
+                    ignore_null,
                 })
             }
             "last_value" | "last" => {
@@ -1555,6 +1614,7 @@ impl<'a> TypeChecker<'a> {
                     n: None,
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
+                    ignore_null,
                 })
             }
             _ => {
@@ -1589,6 +1649,7 @@ impl<'a> TypeChecker<'a> {
                     n: Some(n),
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
+                    ignore_null,
                 })
             }
         })
@@ -1992,6 +2053,28 @@ impl<'a> TypeChecker<'a> {
             .await
     }
 
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    pub async fn resolve_interval(
+        &mut self,
+        span: Span,
+        arg: &Expr,
+        interval_kind: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        match interval_kind {
+            ASTIntervalKind::Doy | ASTIntervalKind::Dow => Err(ErrorCode::SemanticError(format!(
+                "Unsupported interval unit: {}",
+                interval_kind
+            ))
+            .set_span(span)),
+            _ => {
+                let func_name = format!("to_{}s", interval_kind.to_string().to_lowercase());
+                self.resolve_function(span, &func_name, vec![], &[arg])
+                    .await
+            }
+        }
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve_date_trunc(
@@ -2125,6 +2208,93 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((subquery_expr.into(), data_type)))
     }
 
+    /// Rewrite the `WITHIN GROUP (ORDER BY ...)` and `FILTER (WHERE ...)` of an aggregate function call:
+    /// - `percentile_cont(0.9) WITHIN GROUP (ORDER BY x)` to `quantile_cont(0.9)(x)`
+    /// - `sum(x) FILTER (WHERE cond)` to `sum_if(x, cond)`
+    fn rewrite_aggregate_modifiers(&self, expr: &Expr) -> Result<Expr> {
+        let mut new_expr = expr.clone();
+        if let Expr::FunctionCall {
+            span,
+            distinct,
+            name,
+            args,
+            params,
+            within_group,
+            filter,
+            ..
+        } = &mut new_expr
+        {
+            let mut func_name = normalize_identifier(name, self.name_resolution_ctx)
+                .name
+                .to_lowercase();
+
+            if !within_group.is_empty() {
+                func_name = match func_name.as_str() {
+                    "percentile_cont" => "quantile_cont".to_string(),
+                    "percentile_disc" => "quantile_disc".to_string(),
+                    "quantile" | "quantile_cont" | "quantile_disc" | "quantile_tdigest" => {
+                        func_name
+                    }
+                    _ => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "WITHIN GROUP is not supported by function {func_name}"
+                        ))
+                        .set_span(*span));
+                    }
+                };
+                if *distinct || !params.is_empty() || within_group.len() != 1 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "function {func_name} with WITHIN GROUP must have constant fractions and one ORDER BY expression"
+                    ))
+                    .set_span(*span));
+                }
+                let order_by = within_group.remove(0);
+                if order_by.asc == Some(false) {
+                    return Err(ErrorCode::SemanticError(
+                        "descending order in WITHIN GROUP is not supported".to_string(),
+                    )
+                    .set_span(*span));
+                }
+                *params = args
+                    .iter()
+                    .map(|arg| match arg {
+                        Expr::Literal { lit, .. } => Ok(lit.clone()),
+                        _ => Err(ErrorCode::SemanticError(format!(
+                            "the fraction of function {func_name} must be a constant"
+                        ))
+                        .set_span(arg.span())),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                *args = vec![order_by.expr];
+            }
+
+            if let Some(filter) = filter.take() {
+                if !AggregateFunctionFactory::instance().contains(&func_name) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "FILTER is only supported by aggregate functions, but got {func_name}"
+                    ))
+                    .set_span(*span));
+                }
+                if *distinct {
+                    return Err(ErrorCode::SemanticError(
+                        "DISTINCT aggregate functions with FILTER are not supported".to_string(),
+                    )
+                    .set_span(*span));
+                }
+                // The last argument of the `_if` combinator is the condition.
+                func_name = format!("{func_name}_if");
+                args.push(*filter);
+            }
+
+            *name = Identifier {
+                name: func_name,
+                quote: None,
+                span: name.span,
+            };
+        }
+        Ok(new_expr)
+    }
+
     pub fn all_rewritable_scalar_function() -> &'static [&'static str] {
         &[
             "database",
@@ -2259,6 +2429,8 @@ impl<'a> TypeChecker<'a> {
                         },
                         args: vec![arg_x.clone()],
                         params: vec![],
+                        within_group: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     })
@@ -2296,6 +2468,8 @@ impl<'a> TypeChecker<'a> {
                         },
                         args: vec![(*arg).clone()],
                         params: vec![],
+                        within_group: vec![],
+                        filter: None,
                         window: None,
                         lambda: None,
                     };
@@ -3423,6 +3597,8 @@ impl<'a> TypeChecker<'a> {
                     name,
                     args,
                     params,
+                    within_group,
+                    filter,
                     window,
                     lambda,
                 } => Ok(Expr::FunctionCall {
@@ -3434,6 +3610,25 @@ impl<'a> TypeChecker<'a> {
                         .map(|arg| self.clone_expr_with_replacement(arg, replacement_fn))
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
+                    within_group: within_group
+                        .iter()
+                        .map(|order_by| {
+                            Ok(OrderByExpr {
+                                expr: self
+                                    .clone_expr_with_replacement(&order_by.expr, replacement_fn)?,
+                                asc: order_by.asc,
+                                nulls_first: order_by.nulls_first,
+                            })
+                        })
+                        .collect::<Result<Vec<OrderByExpr>>>()?,
+                    filter: filter
+                        .as_ref()
+                        .map(|filter| {
+                            Ok(Box::new(
+                                self.clone_expr_with_replacement(filter, replacement_fn)?,
+                            ))
+                        })
+                        .transpose()?,
                     window: window.clone(),
                     lambda: lambda.clone(),
                 }),
@@ -3603,6 +3798,8 @@ pub fn resolve_type_name_inner(type_name: &TypeName) -> Result<TableDataType> {
         TypeName::String => TableDataType::String,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
+        TypeName::Interval => TableDataType::Interval,
+        TypeName::TimestampTz => TableDataType::TimestampTz,
         TypeName::Array(item_type) => {
             TableDataType::Array(Box::new(resolve_type_name_inner(item_type)?))
         }
@@ -3653,6 +3850,7 @@ pub fn resolve_type_name_inner(type_name: &TypeName) -> Result<TableDataType> {
             TableDataType::Nullable(Box::new(resolve_type_name_inner(inner_type)?))
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Geometry => TableDataType::Geometry,
     };
 
     Ok(data_type)
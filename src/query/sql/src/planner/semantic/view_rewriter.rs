@@ -34,6 +34,7 @@ impl VisitorMut for ViewRewriter {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 // Must rewrite view query when table_ref::database is none. If not:
                 // e.g.
@@ -51,6 +52,7 @@ impl VisitorMut for ViewRewriter {
                         travel_point: travel_point.clone(),
                         pivot: pivot.clone(),
                         unpivot: unpivot.clone(),
+                        sample: sample.clone(),
                     }
                 }
             }
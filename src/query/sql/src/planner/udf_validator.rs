@@ -19,7 +19,9 @@ use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
+use common_ast::ast::OrderByExpr;
 use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::walk_expr;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
@@ -89,7 +91,9 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        over: &'ast Option<Window>,
+        within_group: &'ast [OrderByExpr],
+        filter: &'ast Option<Box<Expr>>,
+        over: &'ast Option<WindowDesc>,
         lambda: &'ast Option<Lambda>,
     ) {
         let name = name.to_string();
@@ -101,9 +105,15 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         for arg in args {
             walk_expr(self, arg);
         }
+        for order_by in within_group {
+            walk_expr(self, &order_by.expr);
+        }
+        if let Some(filter) = filter {
+            walk_expr(self, filter);
+        }
 
         if let Some(over) = over {
-            match over {
+            match &over.window {
                 Window::WindowSpec(spec) => {
                     spec.partition_by
                         .iter()
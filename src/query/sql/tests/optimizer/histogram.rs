@@ -14,6 +14,8 @@
 
 use common_sql::optimizer::Histogram;
 use common_sql::optimizer::HistogramBucket;
+use common_storage::histogram_from_samples;
+use common_storage::most_common_values;
 use common_storage::Datum;
 
 #[test]
@@ -28,3 +30,40 @@ fn test_histogram() {
     assert_eq!(histogram.num_values(), 4.0);
     assert_eq!(histogram.num_distinct_values(), 2.0);
 }
+
+#[test]
+fn test_histogram_from_samples() {
+    // 1 appears 6 times, 2..=5 appear once.
+    let mut samples = vec![Datum::Int(1); 6];
+    samples.extend((2..=5).map(Datum::Int));
+
+    let histogram = histogram_from_samples(&samples, 100, 5, 2).unwrap();
+    assert!(histogram.accuracy);
+    assert_eq!(histogram.num_buckets(), 3);
+    assert_eq!(histogram.num_values(), 100.0);
+    assert_eq!(histogram.buckets[0].upper_bound(), &Datum::Int(1));
+    assert_eq!(histogram.buckets[0].num_values(), 60.0);
+    assert_eq!(histogram.buckets[1].upper_bound(), &Datum::Int(3));
+    assert_eq!(histogram.buckets[1].num_distinct(), 2.0);
+    assert_eq!(histogram.buckets[2].upper_bound(), &Datum::Int(5));
+
+    assert!(histogram_from_samples(&[Datum::Int(1); 3], 3, 1, 2).is_err());
+    assert!(histogram_from_samples(&[Datum::Bytes(vec![1])], 1, 1, 2).is_err());
+}
+
+#[test]
+fn test_most_common_values() {
+    let mut samples = vec![Datum::Int(1); 6];
+    samples.extend((2..=5).map(Datum::Int));
+    samples.extend(vec![Datum::Int(6); 3]);
+
+    // Total 16 sampled rows including 3 null values.
+    let mcv = most_common_values(&samples, 16, 10);
+    assert_eq!(mcv, vec![
+        (Datum::Int(1), 6.0 / 16.0),
+        (Datum::Int(6), 3.0 / 16.0)
+    ]);
+
+    let mcv = most_common_values(&samples, 16, 1);
+    assert_eq!(mcv, vec![(Datum::Int(1), 6.0 / 16.0)]);
+}
@@ -28,6 +28,7 @@ use storages_common_cache::InMemoryItemCacheHolder;
 use storages_common_cache::NamedCache;
 use storages_common_index::filters::Xor8Filter;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::SegmentVectorIndex;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
@@ -50,6 +51,10 @@ pub type BloomIndexFilterCache =
     NamedCache<InMemoryItemCacheHolder<Xor8Filter, DefaultHashBuilder, BloomIndexFilterMeter>>;
 /// In memory object cache of parquet FileMetaData of bloom index data
 pub type BloomIndexMetaCache = NamedCache<InMemoryItemCacheHolder<BloomIndexMeta>>;
+/// In memory object cache of the vector index files of segments
+pub type SegmentVectorIndexCache = NamedCache<
+    InMemoryItemCacheHolder<SegmentVectorIndex, DefaultHashBuilder, SegmentVectorIndexMeter>,
+>;
 /// In memory object cache of parquet FileMetaData of external parquet files
 pub type FileMetaDataCache = NamedCache<InMemoryItemCacheHolder<FileMetaData>>;
 
@@ -115,6 +120,15 @@ impl CachedObject<BloomIndexMeta> for BloomIndexMeta {
     }
 }
 
+impl CachedObject<SegmentVectorIndex, DefaultHashBuilder, SegmentVectorIndexMeter>
+    for SegmentVectorIndex
+{
+    type Cache = SegmentVectorIndexCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_segment_vector_index_cache()
+    }
+}
+
 impl CachedObject<(PartStatistics, Partitions)> for (PartStatistics, Partitions) {
     type Cache = PrunePartitionsCache;
     fn cache() -> Option<Self::Cache> {
@@ -165,3 +179,13 @@ impl Meter<String, Arc<Xor8Filter>> for BloomIndexFilterMeter {
         std::mem::size_of::<Xor8Filter>() + value.filter.finger_prints.len()
     }
 }
+
+pub struct SegmentVectorIndexMeter;
+
+impl Meter<String, Arc<SegmentVectorIndex>> for SegmentVectorIndexMeter {
+    type Measure = usize;
+
+    fn measure<Q: ?Sized>(&self, _: &Q, value: &Arc<SegmentVectorIndex>) -> Self::Measure {
+        std::mem::size_of::<SegmentVectorIndex>() + value.memory_size()
+    }
+}
@@ -23,6 +23,7 @@ pub trait Index {
             DataType::Number(_)
                 | DataType::Date
                 | DataType::Timestamp
+                | DataType::TimestampTz
                 | DataType::String
                 | DataType::Decimal(_)
         )
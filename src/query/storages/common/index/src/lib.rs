@@ -20,6 +20,7 @@ pub mod filters;
 mod index;
 mod page_index;
 mod range_index;
+mod vector_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
@@ -27,3 +28,5 @@ pub use bloom_index::FilterEvalResult;
 pub use index::Index;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
+pub use vector_index::SegmentVectorIndex;
+pub use vector_index::SegmentVectorIndexBuilder;
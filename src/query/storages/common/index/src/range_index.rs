@@ -26,6 +26,7 @@ use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
 use common_expression::types::ValueType;
 use common_expression::with_number_mapped_type;
 use common_expression::ColumnId;
@@ -125,7 +126,11 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
     }
     match data_type {
         DataType::Nullable(box inner_ty) => {
-            if stats.len() == 1 && (stats[0].min().is_null() || stats[0].max().is_null()) {
+            // Geometry columns keep a bounding box and leave min/max null.
+            if stats.len() == 1
+                && stats[0].bbox.is_none()
+                && (stats[0].min().is_null() || stats[0].max().is_null())
+            {
                 return Domain::Nullable(NullableDomain {
                     has_null: true,
                     value: None,
@@ -200,6 +205,14 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                     min: DateType::try_downcast_scalar(&stat.min().as_ref()).unwrap(),
                     max: DateType::try_downcast_scalar(&stat.max().as_ref()).unwrap(),
                 }),
+                DataType::TimestampTz => TimestampTzType::upcast_domain(SimpleDomain {
+                    min: TimestampTzType::try_downcast_scalar(&stat.min().as_ref()).unwrap(),
+                    max: TimestampTzType::try_downcast_scalar(&stat.max().as_ref()).unwrap(),
+                }),
+                DataType::Geometry => match stat.bbox {
+                    Some(bbox) => Domain::Geometry(bbox),
+                    None => Domain::full(data_type),
+                },
                 DataType::Decimal(dec) => match dec {
                     DecimalDataType::Decimal128(sz) => Domain::Decimal(DecimalDomain::Decimal128(
                         SimpleDomain {
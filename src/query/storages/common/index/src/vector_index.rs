@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberColumn;
+use common_expression::Column;
+use common_vector::VectorDistance;
+use common_vector::VectorIndex;
+use common_vector::VectorIndexParams;
+use storages_common_table_meta::table::VectorIndexDistance;
+use storages_common_table_meta::table::VectorIndexMeta;
+use storages_common_table_meta::table::VectorIndexType;
+
+/// The vector index file of a segment.
+///
+/// The vectors of the blocks are numbered consecutively in block order, so a
+/// search result is mapped back to its block by the vector counts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SegmentVectorIndex {
+    /// The location and the number of indexed (non-null) vectors of every block.
+    pub blocks: Vec<(String, u32)>,
+    pub index: VectorIndex,
+}
+
+impl SegmentVectorIndex {
+    pub fn params(meta: &VectorIndexMeta) -> VectorIndexParams {
+        let option = |name: &str| meta.options.get(name).map(|v| *v as usize);
+        match meta.index_type {
+            VectorIndexType::Hnsw => VectorIndexParams::Hnsw {
+                m: option("m"),
+                ef_construction: option("ef_construction"),
+            },
+            VectorIndexType::IvfPq => VectorIndexParams::IvfPq {
+                lists: option("lists"),
+                sub_vectors: option("sub_vectors"),
+            },
+        }
+    }
+
+    pub fn distance(meta: &VectorIndexMeta) -> VectorDistance {
+        match meta.distance {
+            VectorIndexDistance::Cosine => VectorDistance::Cosine,
+            VectorIndexDistance::L2 => VectorDistance::L2,
+        }
+    }
+
+    /// Check the build options of an index before any file is built.
+    pub fn validate(meta: &VectorIndexMeta) -> Result<()> {
+        let params = Self::params(meta);
+        let dim = match params {
+            VectorIndexParams::IvfPq {
+                sub_vectors: Some(m),
+                ..
+            } => m.max(1),
+            _ => 1,
+        };
+        VectorIndex::build(params, Self::distance(meta), dim, &[])?;
+        Ok(())
+    }
+
+    /// Search the approximate `k` nearest vectors of `query`, returns the
+    /// location of the block of every vector with its distance.
+    pub fn search(&self, query: &[f32], k: usize, effort: usize) -> Result<Vec<(&str, f32)>> {
+        let mut ends = Vec::with_capacity(self.blocks.len());
+        let mut end = 0u64;
+        for (_, count) in &self.blocks {
+            end += *count as u64;
+            ends.push(end);
+        }
+
+        Ok(self
+            .index
+            .search(query, k, effort)?
+            .into_iter()
+            .map(|(id, distance)| {
+                let block = ends.partition_point(|end| *end <= id as u64);
+                (self.blocks[block].0.as_str(), distance)
+            })
+            .collect())
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        buf.extend_from_slice(&(self.blocks.len() as u32).to_le_bytes());
+        for (location, count) in &self.blocks {
+            buf.extend_from_slice(&(location.len() as u32).to_le_bytes());
+            buf.extend_from_slice(location.as_bytes());
+            buf.extend_from_slice(&count.to_le_bytes());
+        }
+        buf.extend(self.index.to_bytes());
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut bytes = bytes;
+        let num_blocks = take_u32(&mut bytes)? as usize;
+        let mut blocks = Vec::with_capacity(num_blocks.min(1024));
+        let mut total = 0u64;
+        for _ in 0..num_blocks {
+            let len = take_u32(&mut bytes)? as usize;
+            let location = String::from_utf8(take(&mut bytes, len)?.to_vec())?;
+            let count = take_u32(&mut bytes)?;
+            total += count as u64;
+            blocks.push((location, count));
+        }
+        let index = VectorIndex::from_bytes(bytes)?;
+        if index.len() as u64 != total {
+            return Err(ErrorCode::StorageOther(
+                "corrupted vector index: vector count mismatch",
+            ));
+        }
+        Ok(SegmentVectorIndex { blocks, index })
+    }
+
+    /// The approximate memory size of the index, used to meter the cache.
+    pub fn memory_size(&self) -> usize {
+        let blocks: usize = self
+            .blocks
+            .iter()
+            .map(|(location, _)| location.len() + std::mem::size_of::<(String, u32)>())
+            .sum();
+        blocks + self.index.len() * self.index.dim() * std::mem::size_of::<f32>()
+    }
+}
+
+/// Collect the vectors of the blocks of a segment, in block order, and build
+/// the [`SegmentVectorIndex`] of the segment.
+#[derive(Default)]
+pub struct SegmentVectorIndexBuilder {
+    dim: Option<usize>,
+    vectors: Vec<f32>,
+    blocks: Vec<(String, u32)>,
+    // Set if a block is not indexable, the segment is then not indexed.
+    skipped: bool,
+}
+
+impl SegmentVectorIndexBuilder {
+    pub fn add_block(&mut self, location: &str, column: &Column) {
+        if self.skipped {
+            return;
+        }
+        match collect_vectors(column, &mut self.dim, &mut self.vectors) {
+            Some(count) => self.blocks.push((location.to_string(), count)),
+            None => self.skip(),
+        }
+    }
+
+    /// The vectors of a block are not available, the segment will not be indexed.
+    pub fn skip(&mut self) {
+        self.skipped = true;
+        self.vectors = vec![];
+        self.blocks = vec![];
+    }
+
+    pub fn num_vectors(&self) -> usize {
+        self.dim.map_or(0, |dim| self.vectors.len() / dim)
+    }
+
+    /// Returns `None` if there is no vector, or they are not all of the same dimension.
+    ///
+    /// Fails if the index can't be built with its options, e.g. the sub-vectors of
+    /// IVF_PQ don't divide the dimension.
+    pub fn finish(self, meta: &VectorIndexMeta) -> Result<Option<SegmentVectorIndex>> {
+        let Some(dim) = self.dim.filter(|_| !self.skipped) else {
+            return Ok(None);
+        };
+        let index = VectorIndex::build(
+            SegmentVectorIndex::params(meta),
+            SegmentVectorIndex::distance(meta),
+            dim,
+            &self.vectors,
+        )?;
+        Ok(Some(SegmentVectorIndex {
+            blocks: self.blocks,
+            index,
+        }))
+    }
+}
+
+fn take<'a>(bytes: &mut &'a [u8], n: usize) -> Result<&'a [u8]> {
+    if bytes.len() < n {
+        return Err(ErrorCode::StorageOther(
+            "corrupted vector index: unexpected end of file",
+        ));
+    }
+    let (head, tail) = bytes.split_at(n);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn take_u32(bytes: &mut &[u8]) -> Result<u32> {
+    Ok(u32::from_le_bytes(take(bytes, 4)?.try_into().unwrap()))
+}
+
+/// Collect the vectors of an `Array(Float32)` column, null rows are skipped.
+///
+/// Returns the number of vectors, or `None` if they are not all of the dimension
+/// `dim` (which is set by the first vector if unknown).
+fn collect_vectors(
+    column: &Column,
+    dim: &mut Option<usize>,
+    vectors: &mut Vec<f32>,
+) -> Option<u32> {
+    let (column, validity) = match column {
+        Column::Nullable(c) => (&c.column, Some(&c.validity)),
+        c => (c, None),
+    };
+    let Column::Array(array) = column else {
+        return None;
+    };
+    let Column::Number(NumberColumn::Float32(values)) = &array.values else {
+        return None;
+    };
+
+    let mut count = 0;
+    for (row, range) in array.offsets.windows(2).enumerate() {
+        if validity.is_some_and(|v| !v.get_bit(row)) {
+            continue;
+        }
+        let vector = &values[range[0] as usize..range[1] as usize];
+        if *dim.get_or_insert(vector.len()) != vector.len() || vector.is_empty() {
+            return None;
+        }
+        vectors.extend(vector.iter().map(|v| v.0));
+        count += 1;
+    }
+    Some(count)
+}
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use common_expression::converts::from_scalar;
+use common_expression::types::geometry::GeometryDomain;
 use common_expression::ColumnId;
 use common_expression::Scalar;
 use common_expression::TableDataType;
@@ -28,6 +29,10 @@ pub struct ColumnStatistics {
     pub null_count: u64,
     pub in_memory_size: u64,
     pub distinct_of_values: Option<u64>,
+
+    /// The bounding box of a geometry column, whose min/max are left null.
+    #[serde(default)]
+    pub bbox: Option<GeometryDomain>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -72,6 +77,7 @@ impl ColumnStatistics {
             null_count,
             in_memory_size,
             distinct_of_values,
+            bbox: None,
         }
     }
 
@@ -94,6 +100,7 @@ impl ColumnStatistics {
             null_count: v0.null_count,
             in_memory_size: v0.in_memory_size,
             distinct_of_values: None,
+            bbox: None,
         }
     }
 }
@@ -90,6 +90,7 @@ impl From<ColumnStatistics> for crate::meta::ColumnStatistics {
             null_count: value.null_count,
             in_memory_size: value.in_memory_size,
             distinct_of_values: value.distinct_of_values,
+            bbox: None,
         }
     }
 }
@@ -101,6 +102,7 @@ impl From<ColumnStatistics> for crate::meta::v5::ColumnStatistics {
             null_count: value.null_count,
             in_memory_size: value.in_memory_size,
             distinct_of_values: value.distinct_of_values,
+            bbox: None,
         }
     }
 }
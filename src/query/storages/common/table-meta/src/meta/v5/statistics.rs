@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 
+use common_expression::types::geometry::GeometryDomain;
 use common_expression::ColumnId;
 use common_expression::Scalar;
 
@@ -26,6 +27,10 @@ pub struct ColumnStatistics {
     pub null_count: u64,
     pub in_memory_size: u64,
     pub distinct_of_values: Option<u64>,
+
+    /// The bounding box of a geometry column, whose min/max are left null.
+    #[serde(default)]
+    pub bbox: Option<GeometryDomain>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -65,6 +70,7 @@ impl ColumnStatistics {
             null_count,
             in_memory_size,
             distinct_of_values,
+            bbox: None,
         }
     }
 
@@ -82,6 +88,7 @@ impl ColumnStatistics {
             null_count: v2.null_count,
             in_memory_size: v2.in_memory_size,
             distinct_of_values: v2.distinct_of_values,
+            bbox: v2.bbox,
         }
     }
 }
@@ -15,7 +15,9 @@
 mod table_compression;
 mod table_keys;
 mod table_prefix;
+mod vector_index;
 
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
+pub use vector_index::*;
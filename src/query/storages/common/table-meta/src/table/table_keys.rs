@@ -25,6 +25,18 @@ pub const OPT_KEY_EXTERNAL_LOCATION: &str = "external_location";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 
+/// The defining query of a materialized view
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+/// The id of the table that a materialized view is defined on
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID: &str = "materialized_view_source_table_id";
+/// The snapshot location of the source table that a materialized view is refreshed to
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT: &str =
+    "materialized_view_refreshed_snapshot";
+/// The comma separated ids of the materialized views defined on a table
+pub const OPT_KEY_MATERIALIZED_VIEW_IDS: &str = "materialized_view_ids";
+/// The vector indexes of a table, see `VectorIndexMeta`
+pub const OPT_KEY_VECTOR_INDEXES: &str = "vector_indexes";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -42,6 +54,11 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_IDS);
+    r.insert(OPT_KEY_VECTOR_INDEXES);
     r
 });
 
@@ -50,6 +67,11 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_IDS);
+    r.insert(OPT_KEY_VECTOR_INDEXES);
     r
 });
 
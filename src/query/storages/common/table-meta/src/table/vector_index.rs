@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+
+use crate::table::OPT_KEY_VECTOR_INDEXES;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorIndexType {
+    Hnsw,
+    IvfPq,
+}
+
+impl VectorIndexType {
+    /// The build options accepted by the index type.
+    pub fn option_names(&self) -> &'static [&'static str] {
+        match self {
+            VectorIndexType::Hnsw => &["m", "ef_construction"],
+            VectorIndexType::IvfPq => &["lists", "sub_vectors"],
+        }
+    }
+}
+
+impl Display for VectorIndexType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VectorIndexType::Hnsw => write!(f, "HNSW"),
+            VectorIndexType::IvfPq => write!(f, "IVF_PQ"),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorIndexDistance {
+    Cosine,
+    L2,
+}
+
+impl VectorIndexDistance {
+    /// The scalar function computing the distance.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            VectorIndexDistance::Cosine => "cosine_distance",
+            VectorIndexDistance::L2 => "l2_distance",
+        }
+    }
+}
+
+impl TryFrom<&str> for VectorIndexDistance {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "cosine" => Ok(VectorIndexDistance::Cosine),
+            "l2" => Ok(VectorIndexDistance::L2),
+            other => Err(ErrorCode::BadArguments(format!(
+                "unsupported vector index distance: {other}, expect 'cosine' or 'l2'"
+            ))),
+        }
+    }
+}
+
+impl Display for VectorIndexDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VectorIndexDistance::Cosine => write!(f, "cosine"),
+            VectorIndexDistance::L2 => write!(f, "l2"),
+        }
+    }
+}
+
+/// A vector index defined by `CREATE VECTOR INDEX`.
+///
+/// The indexes of a table are kept in the table option `vector_indexes`.
+/// Every segment written after the index is created gets an index file
+/// holding the vectors of its blocks.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexMeta {
+    /// Part of the index file locations, so that the files of a dropped
+    /// index are never read by a new index with the same name.
+    pub id: String,
+    pub name: String,
+    /// The indexed `Array(Float32)` column.
+    pub column_id: ColumnId,
+    pub index_type: VectorIndexType,
+    pub distance: VectorIndexDistance,
+    /// Build options, see [`VectorIndexType::option_names`].
+    pub options: BTreeMap<String, u64>,
+}
+
+/// Get the vector indexes of a table from its options.
+pub fn get_vector_indexes(options: &BTreeMap<String, String>) -> Result<Vec<VectorIndexMeta>> {
+    match options.get(OPT_KEY_VECTOR_INDEXES) {
+        None => Ok(vec![]),
+        Some(v) => serde_json::from_str(v).map_err(|e| {
+            ErrorCode::Internal(format!(
+                "invalid table option {OPT_KEY_VECTOR_INDEXES}: {e}"
+            ))
+        }),
+    }
+}
+
+/// Set the vector indexes into the table options, removing the option if
+/// there are none.
+pub fn set_vector_indexes(
+    options: &mut BTreeMap<String, String>,
+    indexes: &[VectorIndexMeta],
+) -> Result<()> {
+    if indexes.is_empty() {
+        options.remove(OPT_KEY_VECTOR_INDEXES);
+    } else {
+        let value = serde_json::to_string(indexes)?;
+        options.insert(OPT_KEY_VECTOR_INDEXES.to_string(), value);
+    }
+    Ok(())
+}
@@ -231,7 +231,7 @@ impl FuseTable {
     }
 
     pub fn table_snapshot_statistics_format_version(&self, location: &String) -> u64 {
-        TableMetaLocationGenerator::snapshot_version(location)
+        TableMetaLocationGenerator::snapshot_statistics_version(location)
     }
 
     #[minitrace::trace]
@@ -604,19 +604,11 @@ impl Table for FuseTable {
         let provider = if let Some(snapshot) = self.read_table_snapshot().await? {
             let stats = &snapshot.summary.col_stats;
             let table_statistics = self.read_table_snapshot_statistics(Some(&snapshot)).await?;
-            if let Some(table_statistics) = table_statistics {
-                FuseTableColumnStatisticsProvider::new(
-                    stats.clone(),
-                    Some(table_statistics.column_distinct_values.clone()),
-                    snapshot.summary.row_count,
-                )
-            } else {
-                FuseTableColumnStatisticsProvider::new(
-                    stats.clone(),
-                    None,
-                    snapshot.summary.row_count,
-                )
-            }
+            FuseTableColumnStatisticsProvider::new(
+                stats.clone(),
+                table_statistics.as_deref(),
+                snapshot.summary.row_count,
+            )
         } else {
             FuseTableColumnStatisticsProvider::default()
         };
@@ -767,20 +759,24 @@ struct FuseTableColumnStatisticsProvider {
 impl FuseTableColumnStatisticsProvider {
     fn new(
         column_stats: HashMap<ColumnId, FuseColumnStatistics>,
-        column_distinct_values: Option<HashMap<ColumnId, u64>>,
+        table_statistics: Option<&TableSnapshotStatistics>,
         row_count: u64,
     ) -> Self {
         let column_stats = column_stats
             .into_iter()
             .map(|(column_id, stat)| {
-                let ndv = column_distinct_values
-                    .as_ref()
-                    .map_or(row_count, |map| map.get(&column_id).map_or(0, |v| *v));
+                let ndv = table_statistics.map_or(row_count, |s| {
+                    s.column_distinct_values.get(&column_id).map_or(0, |v| *v)
+                });
                 let stat = BasicColumnStatistics {
                     min: Datum::from_scalar(stat.min().clone()),
                     max: Datum::from_scalar(stat.max().clone()),
                     ndv: Some(ndv),
                     null_count: stat.null_count,
+                    histogram: table_statistics.and_then(|s| s.histograms.get(&column_id).cloned()),
+                    most_common_values: table_statistics
+                        .and_then(|s| s.most_common_values.get(&column_id).cloned())
+                        .unwrap_or_default(),
                 };
                 (column_id, stat.get_useful_stat(row_count))
             })
@@ -31,6 +31,7 @@ use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -41,6 +42,8 @@ static SNAPSHOT_V4: SnapshotVersion = SnapshotVersion::V4(PhantomData);
 
 static SNAPSHOT_STATISTICS_V0: TableSnapshotStatisticsVersion =
     TableSnapshotStatisticsVersion::V0(PhantomData);
+static SNAPSHOT_STATISTICS_V2: TableSnapshotStatisticsVersion =
+    TableSnapshotStatisticsVersion::V2(PhantomData);
 
 #[derive(Clone)]
 pub struct TableMetaLocationGenerator {
@@ -135,8 +138,15 @@ impl TableMetaLocationGenerator {
         Ok(statistics_version.create(id, &self.prefix))
     }
 
-    pub fn snapshot_statistics_version(_location: impl AsRef<str>) -> u64 {
-        SNAPSHOT_STATISTICS_V0.version()
+    pub fn snapshot_statistics_version(location: impl AsRef<str>) -> u64 {
+        if location
+            .as_ref()
+            .ends_with(SNAPSHOT_STATISTICS_V2.suffix().as_str())
+        {
+            SNAPSHOT_STATISTICS_V2.version()
+        } else {
+            SNAPSHOT_STATISTICS_V0.version()
+        }
     }
 
     pub fn gen_last_snapshot_hint_location(&self) -> String {
@@ -154,6 +164,22 @@ impl TableMetaLocationGenerator {
         let block_name = splits[len - 1];
         format!("{prefix}/{FUSE_TBL_AGG_INDEX_PREFIX}/{index_id}/{block_name}")
     }
+
+    pub fn gen_vector_index_location_from_segment_location(loc: &str, index_id: &str) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let segment_name = splits[len - 1];
+        format!("{prefix}/{FUSE_TBL_VECTOR_INDEX_PREFIX}/{index_id}/{segment_name}")
+    }
+
+    /// The directory of all the files of a vector index.
+    pub fn vector_index_prefix(&self, index_id: &str) -> String {
+        format!(
+            "{}/{}/{}/",
+            &self.prefix, FUSE_TBL_VECTOR_INDEX_PREFIX, index_id
+        )
+    }
 }
 
 trait SnapshotLocationCreator {
@@ -198,6 +224,7 @@ impl SnapshotLocationCreator for TableSnapshotStatisticsVersion {
     fn suffix(&self) -> String {
         match self {
             TableSnapshotStatisticsVersion::V0(_) => "_ts_v0.json".to_string(),
+            TableSnapshotStatisticsVersion::V2(_) => "_ts_v2.json".to_string(),
         }
     }
 }
@@ -29,7 +29,9 @@ use storages_common_cache::LoadParams;
 use storages_common_cache::Loader;
 use storages_common_cache_manager::CacheManager;
 use storages_common_cache_manager::CompactSegmentInfoMeter;
+use storages_common_cache_manager::SegmentVectorIndexMeter;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::SegmentVectorIndex;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::SegmentInfoVersion;
 use storages_common_table_meta::meta::SnapshotVersion;
@@ -51,6 +53,13 @@ pub type CompactSegmentInfoReader = InMemoryItemCacheReader<
     CompactSegmentInfoMeter,
 >;
 
+pub type SegmentVectorIndexReader = InMemoryItemCacheReader<
+    SegmentVectorIndex,
+    LoaderWrapper<Operator>,
+    DefaultHashBuilder,
+    SegmentVectorIndexMeter,
+>;
+
 pub struct MetaReaders;
 
 impl MetaReaders {
@@ -81,6 +90,13 @@ impl MetaReaders {
             LoaderWrapper(dal),
         )
     }
+
+    pub fn segment_vector_index_reader(dal: Operator) -> SegmentVectorIndexReader {
+        SegmentVectorIndexReader::new(
+            CacheManager::instance().get_segment_vector_index_cache(),
+            LoaderWrapper(dal),
+        )
+    }
 }
 
 // workaround for the orphan rules
@@ -136,6 +152,15 @@ impl Loader<BloomIndexMeta> for LoaderWrapper<Operator> {
     }
 }
 
+#[async_trait::async_trait]
+impl Loader<SegmentVectorIndex> for LoaderWrapper<Operator> {
+    #[async_backtrace::framed]
+    async fn load(&self, params: &LoadParams) -> Result<SegmentVectorIndex> {
+        let data = self.0.read(&params.location).await?;
+        SegmentVectorIndex::from_bytes(&data)
+    }
+}
+
 async fn bytes_reader(op: &Operator, path: &str, len_hint: Option<u64>) -> Result<Reader> {
     let reader = if let Some(len) = len_hint {
         op.reader_with(path).range(0..len).await?
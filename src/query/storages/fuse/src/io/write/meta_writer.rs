@@ -167,11 +167,20 @@ mod tests {
 
     #[test]
     fn test_table_snapshot_statistics_format_version_validation() {
-        // since there is only one version for TableSnapshotStatistics,
-        // we omit the checking of invalid format versions, otherwise clippy will complain about empty_ranges
+        // old versions are not allowed (runtime panics)
+        for v in 0..TableSnapshotStatistics::VERSION {
+            let r = catch_unwind(|| {
+                let mut snapshot_stats =
+                    TableSnapshotStatistics::new(HashMap::new(), HashMap::new(), HashMap::new());
+                snapshot_stats.format_version = v;
+                let _ = snapshot_stats.marshal();
+            });
+            assert!(r.is_err())
+        }
 
         // current version allowed
-        let snapshot_stats = TableSnapshotStatistics::new(HashMap::new());
+        let snapshot_stats =
+            TableSnapshotStatistics::new(HashMap::new(), HashMap::new(), HashMap::new());
         snapshot_stats.marshal().unwrap();
     }
 }
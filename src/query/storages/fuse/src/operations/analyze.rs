@@ -16,20 +16,37 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::ComputedExpr;
+use common_expression::TableDataType;
+use common_storage::histogram_from_samples;
+use common_storage::most_common_values;
+use common_storage::Datum;
+use common_storage::Histogram;
+use common_storage::DEFAULT_HISTOGRAM_BUCKETS;
+use common_storage::DEFAULT_MOST_COMMON_VALUES;
 use log::warn;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
 use crate::FuseTable;
 
+/// Max number of blocks read to sample the column values.
+const ANALYZE_SAMPLE_BLOCKS: usize = 16;
+/// Max number of rows sampled to build the histograms and most common values.
+const ANALYZE_SAMPLE_ROWS: usize = 100_000;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -59,6 +76,10 @@ impl FuseTable {
             let mut read_segment_count = 0;
             let mut col_stats = HashMap::new();
             let mut cluster_stats = None;
+            let mut sample_blocks = Vec::new();
+            let mut block_index: usize = 0;
+            let sample_stride =
+                (snapshot.summary.block_count as usize / ANALYZE_SAMPLE_BLOCKS).max(1);
 
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
@@ -80,9 +101,14 @@ impl FuseTable {
                     stats_of_columns.push(segment.summary.col_stats.clone());
                     blocks_cluster_stats.push(segment.summary.cluster_stats.clone());
                     segment.blocks.iter().for_each(|block| {
-                        let block = block.as_ref();
                         let row_count = block.row_count;
                         if row_count != 0 {
+                            if block_index % sample_stride == 0
+                                && sample_blocks.len() < ANALYZE_SAMPLE_BLOCKS
+                            {
+                                sample_blocks.push(block.clone());
+                            }
+                            block_index += 1;
                             block_count_sum += 1;
                             row_count_sum += row_count;
                             for (i, col_stat) in block.col_stats.iter() {
@@ -127,8 +153,14 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Sample the column values to build histograms and most common values.
+            let (histograms, most_common_values) = self
+                .sample_column_distributions(ctx, &sample_blocks, row_count_sum, &ndv_map)
+                .await?;
+
+            // 4. Generate new table statistics
+            let table_statistics =
+                TableSnapshotStatistics::new(ndv_map, histograms, most_common_values);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -136,7 +168,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.summary.col_stats = col_stats;
             new_snapshot.summary.cluster_stats = cluster_stats;
@@ -155,4 +187,92 @@ impl FuseTable {
 
         Ok(())
     }
+
+    #[async_backtrace::framed]
+    async fn sample_column_distributions(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        blocks: &[Arc<BlockMeta>],
+        num_rows: u64,
+        ndv_map: &HashMap<ColumnId, u64>,
+    ) -> Result<(
+        HashMap<ColumnId, Histogram>,
+        HashMap<ColumnId, Vec<(Datum, f64)>>,
+    )> {
+        let mut histograms = HashMap::new();
+        let mut most_common_values_map = HashMap::new();
+
+        // Only the stored columns of the types which can be converted to datum are sampled.
+        let schema = self.table_info.schema();
+        let (field_indices, column_ids): (Vec<_>, Vec<_>) = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| {
+                !matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_)))
+                    && matches!(
+                        field.data_type().remove_nullable(),
+                        TableDataType::Boolean
+                            | TableDataType::String
+                            | TableDataType::Number(_)
+                            | TableDataType::Timestamp
+                            | TableDataType::Date
+                    )
+            })
+            .map(|(index, field)| (index, field.column_id()))
+            .unzip();
+        if blocks.is_empty() || field_indices.is_empty() {
+            return Ok((histograms, most_common_values_map));
+        }
+
+        let block_reader =
+            self.create_block_reader(Projection::Columns(field_indices), false, ctx.clone())?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let data_blocks = futures::future::try_join_all(blocks.iter().map(|block| {
+            block_reader.read_by_meta(&settings, block.as_ref(), &self.storage_format)
+        }))
+        .await?;
+
+        let rows_per_block = (ANALYZE_SAMPLE_ROWS / blocks.len()).max(1);
+        let mut num_samples = 0;
+        let mut samples = vec![Vec::new(); column_ids.len()];
+        for data_block in data_blocks {
+            let row_stride = (data_block.num_rows() / rows_per_block).max(1);
+            for row in (0..data_block.num_rows()).step_by(row_stride) {
+                num_samples += 1;
+                for (i, values) in samples.iter_mut().enumerate() {
+                    let value = data_block.get_by_offset(i).value.index(row);
+                    // Null values are not sampled, but counted in `num_samples`.
+                    if let Some(datum) = value.and_then(|v| Datum::from_scalar(v.to_owned())) {
+                        values.push(datum);
+                    }
+                }
+            }
+        }
+
+        for (column_id, mut values) in column_ids.into_iter().zip(samples.into_iter()) {
+            if values.is_empty() {
+                continue;
+            }
+            values.sort();
+
+            let mcv = most_common_values(&values, num_samples, DEFAULT_MOST_COMMON_VALUES);
+            if !mcv.is_empty() {
+                most_common_values_map.insert(column_id, mcv);
+            }
+
+            let ndv = ndv_map
+                .get(&column_id)
+                .cloned()
+                .unwrap_or(values.len() as u64);
+            let num_values = num_rows * values.len() as u64 / num_samples as u64;
+            if let Ok(histogram) =
+                histogram_from_samples(&values, num_values, ndv, DEFAULT_HISTOGRAM_BUCKETS)
+            {
+                histograms.insert(column_id, histogram);
+            }
+        }
+
+        Ok((histograms, most_common_values_map))
+    }
 }
@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use log::warn;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::Location;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::common::ConflictResolveContext;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Returns the segments appended to the table since the snapshot at `base_snapshot_loc`.
+    ///
+    /// `None` is returned if the table has been changed in any way other than appending
+    /// (e.g. delete, update, compaction or truncate), or if the base snapshot can not be
+    /// read any more, in which case the caller should fall back to a full scan.
+    #[async_backtrace::framed]
+    pub async fn appended_segments_since(
+        &self,
+        base_snapshot_loc: &str,
+    ) -> Result<Option<Vec<Location>>> {
+        let latest = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: base_snapshot_loc.to_string(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(base_snapshot_loc),
+            put_cache: false,
+        };
+        let base = match reader.read(&params).await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                warn!(
+                    "read base snapshot {} of table {} failed: {}",
+                    base_snapshot_loc, self.table_info.desc, e
+                );
+                return Ok(None);
+            }
+        };
+
+        Ok(
+            ConflictResolveContext::is_latest_snapshot_append_only(&base, &latest)
+                .map(|range| latest.segments[range].to_vec()),
+        )
+    }
+}
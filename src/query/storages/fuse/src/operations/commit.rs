@@ -23,10 +23,12 @@ use chrono::Utc;
 use common_catalog::table::Table;
 use common_catalog::table::TableExt;
 use common_catalog::table_context::TableContext;
+use common_catalog::temp_table::is_temp_table_id;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -219,7 +221,25 @@ impl FuseTable {
             deduplicated_label: ctx.get_settings().get_deduplicate_label()?,
         };
 
-        // 3. let's roll
+        // 3. inside an explicit transaction, buffer the new table meta and let COMMIT do the rest
+        let txn_mgr = ctx.txn_mgr();
+        {
+            let mut txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.update_table_meta(table_info, req, snapshot_location.clone());
+                TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+                return Ok(());
+            }
+        }
+
+        // 4. the meta of temporary tables is kept by the session, rather than the meta service
+        if is_temp_table_id(table_id) {
+            ctx.temp_tbl_mgr().lock().update_table_meta(req)?;
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+
+        // 5. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
@@ -494,4 +514,41 @@ impl FuseTable {
     pub fn remove_legacy_options(table_options: &mut BTreeMap<String, String>) {
         table_options.remove(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     }
+
+    /// Rebase the table meta generated by an explicit transaction onto the latest table meta.
+    ///
+    /// The transaction conflicts with others only if a new snapshot has been committed since
+    /// `base` (the table meta when the transaction mutated the table for the first time), the
+    /// other changes of the latest table meta, e.g. options, are kept.
+    pub fn rebase_txn_table_meta(
+        table_desc: &str,
+        base: &TableMeta,
+        latest: &TableMeta,
+        txn: &TableMeta,
+    ) -> Result<TableMeta> {
+        let snapshot_loc = |meta: &TableMeta| {
+            meta.options
+                .get(OPT_KEY_SNAPSHOT_LOCATION)
+                .or_else(|| meta.options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
+                .cloned()
+        };
+        if snapshot_loc(base) != snapshot_loc(latest) {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "table {} has been changed by another transaction, current snapshot {:?}",
+                table_desc,
+                snapshot_loc(latest)
+            )));
+        }
+
+        let mut new_table_meta = latest.clone();
+        if let Some(location) = snapshot_loc(txn) {
+            new_table_meta
+                .options
+                .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), location);
+        }
+        Self::remove_legacy_options(&mut new_table_meta.options);
+        new_table_meta.statistics = txn.statistics.clone();
+        new_table_meta.updated_on = txn.updated_on;
+        Ok(new_table_meta)
+    }
 }
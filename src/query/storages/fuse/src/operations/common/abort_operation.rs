@@ -28,6 +28,7 @@ pub struct AbortOperation {
     pub segments: Vec<String>,
     pub blocks: Vec<String>,
     pub bloom_filter_indexes: Vec<String>,
+    pub vector_indexes: Vec<String>,
 }
 
 impl AbortOperation {
@@ -35,6 +36,7 @@ impl AbortOperation {
         self.segments.extend(rhs.segments);
         self.blocks.extend(rhs.blocks);
         self.bloom_filter_indexes.extend(rhs.bloom_filter_indexes);
+        self.vector_indexes.extend(rhs.vector_indexes);
     }
 
     pub fn add_block(&mut self, block: &BlockMeta) {
@@ -49,6 +51,10 @@ impl AbortOperation {
         self.segments.push(segment);
     }
 
+    pub fn add_vector_index(&mut self, vector_index: String) {
+        self.vector_indexes.push(vector_index);
+    }
+
     #[async_backtrace::framed]
     pub async fn abort(self, ctx: Arc<dyn TableContext>, operator: Operator) -> Result<()> {
         // evict segment cache.
@@ -63,6 +69,7 @@ impl AbortOperation {
             .blocks
             .into_iter()
             .chain(self.bloom_filter_indexes.into_iter())
+            .chain(self.vector_indexes.into_iter())
             .chain(self.segments.into_iter());
         fuse_file.remove_file_in_batch(locations).await
     }
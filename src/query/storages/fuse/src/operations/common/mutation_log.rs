@@ -195,6 +195,12 @@ fn merge_commit_meta(
                 .into_iter()
                 .chain(r.abort_operation.bloom_filter_indexes.into_iter())
                 .collect(),
+            vector_indexes: l
+                .abort_operation
+                .vector_indexes
+                .into_iter()
+                .chain(r.abort_operation.vector_indexes.into_iter())
+                .collect(),
         },
     }
 }
@@ -325,7 +325,9 @@ where F: SnapshotGenerator + Send + 'static
                 .await
                 {
                     Ok(_) => {
-                        if self.transient {
+                        // inside an explicit transaction, the new snapshot is not committed yet
+                        let in_txn = self.ctx.txn_mgr().lock().is_active();
+                        if self.transient && !in_txn {
                             // Removes historical data, if table is transient
                             let latest = self.table.refresh(self.ctx.as_ref()).await?;
                             let tbl = FuseTable::try_from_table(latest.as_ref())?;
@@ -20,8 +20,10 @@ use common_base::base::ProgressValues;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::BlockEntry;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::DataBlock;
+use common_expression::FieldIndex;
 use common_pipeline_core::pipe::PipeItem;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
@@ -58,6 +60,7 @@ enum State {
     Serialized {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
+        vector_columns: Vec<BlockEntry>,
     },
 }
 
@@ -69,6 +72,9 @@ pub struct TransformSerializeBlock {
 
     block_builder: BlockBuilder,
     dal: Operator,
+    // The columns of the vector indexes, passed along with the metas of the appended
+    // blocks to build the vector indexes of the segments.
+    vector_index_fields: Vec<FieldIndex>,
 }
 
 impl TransformSerializeBlock {
@@ -91,6 +97,11 @@ impl TransformSerializeBlock {
             cluster_stats_gen,
             bloom_columns_map,
         };
+        let vector_index_fields = table
+            .vector_index_fields()?
+            .into_iter()
+            .map(|(_, field_index)| field_index)
+            .collect();
         Ok(TransformSerializeBlock {
             state: State::Consume,
             input,
@@ -98,6 +109,7 @@ impl TransformSerializeBlock {
             output_data: None,
             block_builder,
             dal: table.get_operator(),
+            vector_index_fields,
         })
     }
 
@@ -229,6 +241,14 @@ impl Processor for TransformSerializeBlock {
                 stats_type,
                 index,
             } => {
+                let vector_columns = if index.is_none() {
+                    self.vector_index_fields
+                        .iter()
+                        .map(|field_index| block.get_by_offset(*field_index).clone())
+                        .collect()
+                } else {
+                    vec![]
+                };
                 let serialized =
                     self.block_builder
                         .build(block, |block, generator| match &stats_type {
@@ -240,7 +260,11 @@ impl Processor for TransformSerializeBlock {
                             }
                         })?;
 
-                self.state = State::Serialized { serialized, index };
+                self.state = State::Serialized {
+                    serialized,
+                    index,
+                    vector_columns,
+                };
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
@@ -250,7 +274,11 @@ impl Processor for TransformSerializeBlock {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
-            State::Serialized { serialized, index } => {
+            State::Serialized {
+                serialized,
+                index,
+                vector_columns,
+            } => {
                 let start = Instant::now();
                 // write block data.
                 let raw_block_data = serialized.block_raw_data;
@@ -300,7 +328,12 @@ impl Processor for TransformSerializeBlock {
                         .get_write_progress()
                         .incr(&progress_values);
 
-                    DataBlock::empty_with_meta(Box::new(serialized.block_meta))
+                    let num_rows = serialized.block_meta.row_count as usize;
+                    DataBlock::new_with_meta(
+                        vector_columns,
+                        num_rows,
+                        Some(Box::new(serialized.block_meta)),
+                    )
                 };
                 self.output_data = Some(data_block);
             }
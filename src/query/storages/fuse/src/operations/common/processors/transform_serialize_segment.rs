@@ -28,10 +28,13 @@ use log::info;
 use opendal::Operator;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
+use storages_common_index::SegmentVectorIndexBuilder;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::VectorIndexMeta;
 
+use crate::io::write_data;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
 use crate::operations::common::MutationLogEntry;
@@ -52,10 +55,13 @@ enum State {
         data: Vec<u8>,
         location: String,
         segment: Arc<SegmentInfo>,
+        // The locations and data of the vector index files of the segment.
+        vector_indexes: Vec<(String, Vec<u8>)>,
     },
     PreCommitSegment {
         location: String,
         segment: Arc<SegmentInfo>,
+        vector_index_locations: Vec<String>,
     },
     Finished,
 }
@@ -73,6 +79,11 @@ pub struct TransformSerializeSegment {
 
     thresholds: BlockThresholds,
     default_cluster_key_id: Option<u32>,
+
+    // The vectors of the blocks are collected per vector index while the blocks of a
+    // segment are accumulated, their columns are passed along with the block metas.
+    vector_indexes: Vec<VectorIndexMeta>,
+    vector_index_builders: Vec<SegmentVectorIndexBuilder>,
 }
 
 impl TransformSerializeSegment {
@@ -84,6 +95,13 @@ impl TransformSerializeSegment {
         thresholds: BlockThresholds,
     ) -> Self {
         let default_cluster_key_id = table.cluster_key_id();
+        let vector_indexes: Vec<_> = table
+            .vector_index_fields()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        let vector_index_builders = Self::new_vector_index_builders(&vector_indexes);
         TransformSerializeSegment {
             ctx,
             input,
@@ -98,6 +116,35 @@ impl TransformSerializeSegment {
                 as u64,
             thresholds,
             default_cluster_key_id,
+            vector_indexes,
+            vector_index_builders,
+        }
+    }
+
+    fn new_vector_index_builders(indexes: &[VectorIndexMeta]) -> Vec<SegmentVectorIndexBuilder> {
+        indexes
+            .iter()
+            .map(|_| SegmentVectorIndexBuilder::default())
+            .collect()
+    }
+
+    fn add_block_vectors(&mut self, block_meta: &BlockMeta, data_block: &DataBlock) {
+        if data_block.num_columns() != self.vector_index_builders.len() {
+            // The block is not written by an append, its vectors are not available.
+            self.vector_index_builders
+                .iter_mut()
+                .for_each(|builder| builder.skip());
+            return;
+        }
+        for (builder, entry) in self
+            .vector_index_builders
+            .iter_mut()
+            .zip(data_block.columns())
+        {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, data_block.num_rows());
+            builder.add_block(&block_meta.location.0, &column);
         }
     }
 
@@ -159,10 +206,8 @@ impl Processor for TransformSerializeSegment {
         }
 
         if self.input.has_data() {
-            let input_meta = self
-                .input
-                .pull_data()
-                .unwrap()?
+            let input_data = self.input.pull_data().unwrap()?;
+            let input_meta = input_data
                 .get_meta()
                 .cloned()
                 .ok_or(ErrorCode::Internal("No block meta. It's a bug"))?;
@@ -170,6 +215,9 @@ impl Processor for TransformSerializeSegment {
                 .ok_or(ErrorCode::Internal("No commit meta. It's a bug"))?
                 .clone();
 
+            if !self.vector_index_builders.is_empty() {
+                self.add_block_vectors(&block_meta, &input_data);
+            }
             self.accumulator.add_with_block_meta(block_meta);
             if self.accumulator.summary_block_count >= self.block_per_seg {
                 self.state = State::GenerateSegment;
@@ -188,14 +236,33 @@ impl Processor for TransformSerializeSegment {
                 let summary = acc.summary(self.thresholds, self.default_cluster_key_id);
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, summary);
+                let location = self.meta_locations.gen_segment_info_location();
+
+                let builders = std::mem::replace(
+                    &mut self.vector_index_builders,
+                    Self::new_vector_index_builders(&self.vector_indexes),
+                );
+                let vector_indexes = self
+                    .vector_indexes
+                    .iter()
+                    .zip(builders)
+                    .filter_map(|(index, builder)| {
+                        FuseTable::build_segment_vector_index(index, &location, builder)
+                    })
+                    .collect();
 
                 self.state = State::SerializedSegment {
                     data: segment_info.to_bytes()?,
-                    location: self.meta_locations.gen_segment_info_location(),
+                    location,
                     segment: Arc::new(segment_info),
+                    vector_indexes,
                 }
             }
-            State::PreCommitSegment { location, segment } => {
+            State::PreCommitSegment {
+                location,
+                segment,
+                vector_index_locations,
+            } => {
                 if let Some(segment_cache) = SegmentInfo::cache() {
                     segment_cache.put(location.clone(), Arc::new(segment.as_ref().try_into()?));
                 }
@@ -205,6 +272,9 @@ impl Processor for TransformSerializeSegment {
                     abort_operation.add_block(block_meta);
                 }
                 abort_operation.add_segment(location.clone());
+                for vector_index_location in vector_index_locations {
+                    abort_operation.add_vector_index(vector_index_location);
+                }
 
                 let format_version = SegmentInfo::VERSION;
 
@@ -238,11 +308,22 @@ impl Processor for TransformSerializeSegment {
                 data,
                 location,
                 segment,
+                vector_indexes,
             } => {
                 self.data_accessor.write(&location, data).await?;
                 info!("fuse append wrote down segment {} ", location);
 
-                self.state = State::PreCommitSegment { location, segment };
+                let mut vector_index_locations = Vec::with_capacity(vector_indexes.len());
+                for (index_location, data) in vector_indexes {
+                    write_data(data, &self.data_accessor, &index_location).await?;
+                    vector_index_locations.push(index_location);
+                }
+
+                self.state = State::PreCommitSegment {
+                    location,
+                    segment,
+                    vector_index_locations,
+                };
             }
             _state => {
                 return Err(ErrorCode::Internal("Unknown state for fuse table sink."));
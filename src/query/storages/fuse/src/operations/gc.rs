@@ -18,6 +18,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use common_cache::CountableMeter;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -33,6 +34,7 @@ use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
+use storages_common_table_meta::table::get_vector_indexes;
 
 use crate::io::Files;
 use crate::io::MetaReaders;
@@ -52,6 +54,54 @@ impl FuseTable {
         limit: Option<usize>,
         keep_last_snapshot: bool,
         dry_run: bool,
+    ) -> Result<Option<Vec<String>>> {
+        let mut counter = PurgeCounter::new();
+        self.purge_with_counter(
+            ctx,
+            snapshot_files,
+            limit,
+            keep_last_snapshot,
+            dry_run,
+            &mut counter,
+        )
+        .await
+    }
+
+    /// Purge the snapshots older than `instant` and the files referenced only by them,
+    /// the last snapshot is kept. Returns the number and the total size of the files removed.
+    #[async_backtrace::framed]
+    pub async fn purge_and_count(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        instant: Option<NavigationPoint>,
+    ) -> Result<PurgeStats> {
+        let mut counter = PurgeCounter::new();
+        counter.count_bytes = true;
+        match self.navigate_for_purge(ctx, instant).await {
+            Ok((table, files)) => {
+                table
+                    .purge_with_counter(ctx, files, None, true, false, &mut counter)
+                    .await?;
+            }
+            Err(e) if e.code() == ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND => {
+                warn!("navigate failed: {:?}", e);
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(PurgeStats {
+            files: counter.files(),
+            bytes: counter.bytes,
+        })
+    }
+
+    async fn purge_with_counter(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_files: Vec<String>,
+        limit: Option<usize>,
+        keep_last_snapshot: bool,
+        dry_run: bool,
+        counter: &mut PurgeCounter,
     ) -> Result<Option<Vec<String>>> {
         // 1. Read the root snapshot.
         let root_snapshot_info_op = self.read_root_snapshot(ctx, keep_last_snapshot).await?;
@@ -76,7 +126,6 @@ impl FuseTable {
 
         let mut read_snapshot_count = 0;
         let mut remain_snapshots = Vec::<SnapshotLiteExtended>::new();
-        let mut counter = PurgeCounter::new();
         let mut dry_run_purge_files = vec![];
         let mut purged_snapshot_count = 0;
 
@@ -179,7 +228,7 @@ impl FuseTable {
                 } else {
                     self.partial_purge(
                         ctx,
-                        &mut counter,
+                        counter,
                         &root_snapshot_info.referenced_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
@@ -230,7 +279,7 @@ impl FuseTable {
             } else {
                 self.partial_purge(
                     ctx,
-                    &mut counter,
+                    counter,
                     &root_snapshot_info.referenced_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
@@ -249,7 +298,7 @@ impl FuseTable {
         if !keep_last_snapshot {
             self.purge_root_snapshot(
                 ctx,
-                &mut counter,
+                counter,
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
@@ -351,6 +400,7 @@ impl FuseTable {
                 purge_files.push(loc.to_string())
             }
 
+            purge_files.extend(self.vector_index_locations(chunk.iter().map(|loc| &loc.0))?);
             purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
@@ -500,6 +550,8 @@ impl FuseTable {
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
             counter.blocks += blocks_count;
+            self.count_purged_bytes(ctx, counter, &blocks_to_be_purged)
+                .await?;
             self.try_purge_location_files(ctx.clone(), blocks_to_be_purged)
                 .await?;
         }
@@ -507,6 +559,8 @@ impl FuseTable {
         let agg_index_count = agg_indexes_to_be_purged.len();
         if agg_index_count > 0 {
             counter.agg_indexes += agg_index_count;
+            self.count_purged_bytes(ctx, counter, &agg_indexes_to_be_purged)
+                .await?;
             self.try_purge_location_files(ctx.clone(), agg_indexes_to_be_purged)
                 .await?;
         }
@@ -515,6 +569,8 @@ impl FuseTable {
         let blooms_count = blooms_to_be_purged.len();
         if blooms_count > 0 {
             counter.blooms += blooms_count;
+            self.count_purged_bytes(ctx, counter, &blooms_to_be_purged)
+                .await?;
             self.try_purge_location_files_and_cache::<BloomIndexMeta, _, _>(
                 ctx.clone(),
                 blooms_to_be_purged,
@@ -522,10 +578,21 @@ impl FuseTable {
             .await?;
         }
 
-        // 3. Try to purge segment file chunks.
+        // 3. Try to purge segment file chunks, with the vector index files of the segments.
         let segments_count = segments_to_be_purged.len();
         if segments_count > 0 {
             counter.segments += segments_count;
+            self.count_purged_bytes(ctx, counter, &segments_to_be_purged)
+                .await?;
+            let vector_indexes_to_be_purged: HashSet<_> =
+                HashSet::from_iter(self.vector_index_locations(segments_to_be_purged.iter())?);
+            if !vector_indexes_to_be_purged.is_empty() {
+                counter.vector_indexes += vector_indexes_to_be_purged.len();
+                self.count_purged_bytes(ctx, counter, &vector_indexes_to_be_purged)
+                    .await?;
+                self.try_purge_location_files(ctx.clone(), vector_indexes_to_be_purged)
+                    .await?;
+            }
             self.try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
                 ctx.clone(),
                 segments_to_be_purged,
@@ -535,6 +602,28 @@ impl FuseTable {
         Ok(())
     }
 
+    fn vector_index_locations<'a>(
+        &self,
+        segments: impl Iterator<Item = &'a String>,
+    ) -> Result<Vec<String>> {
+        let index_ids: Vec<_> = get_vector_indexes(self.table_info.options())?
+            .into_iter()
+            .map(|index| index.id)
+            .collect();
+        if index_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(segments
+            .flat_map(|segment| {
+                index_ids.iter().map(move |id| {
+                    TableMetaLocationGenerator::gen_vector_index_location_from_segment_location(
+                        segment, id,
+                    )
+                })
+            })
+            .collect())
+    }
+
     async fn purge_ts_snapshots(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -546,6 +635,8 @@ impl FuseTable {
         let ts_count = ts_to_be_purged.len();
         if ts_count > 0 {
             counter.table_statistics += ts_count;
+            self.count_purged_bytes(ctx, counter, &ts_to_be_purged)
+                .await?;
             self.try_purge_location_files_and_cache::<TableSnapshotStatistics, _, _>(
                 ctx.clone(),
                 ts_to_be_purged,
@@ -557,6 +648,8 @@ impl FuseTable {
         let snapshots_count = snapshots_to_be_purged.len();
         if snapshots_count > 0 {
             counter.snapshots += snapshots_count;
+            self.count_purged_bytes(ctx, counter, &snapshots_to_be_purged)
+                .await?;
             self.try_purge_location_files_and_cache::<TableSnapshot, _, _>(
                 ctx.clone(),
                 snapshots_to_be_purged,
@@ -580,6 +673,45 @@ impl FuseTable {
         Ok(())
     }
 
+    async fn count_purged_bytes(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        counter: &mut PurgeCounter,
+        locations_to_be_purged: &HashSet<String>,
+    ) -> Result<()> {
+        if counter.count_bytes {
+            counter.bytes += self
+                .get_location_files_size(ctx.clone(), locations_to_be_purged)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Sum the sizes of the files at the given locations, the missing files are ignored.
+    #[async_backtrace::framed]
+    pub async fn get_location_files_size(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        locations: &HashSet<String>,
+    ) -> Result<u64> {
+        let locations = Vec::from_iter(locations.iter());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let mut size = 0;
+        for chunk in locations.chunks(chunk_size) {
+            let sizes = futures::future::try_join_all(chunk.iter().map(|loc| async move {
+                match self.operator.stat(loc).await {
+                    Ok(meta) => Ok(meta.content_length()),
+                    // concurrent gc: someone else has already collected this file
+                    Err(e) if e.kind() == opendal::ErrorKind::NotFound => Ok(0),
+                    Err(e) => Err(ErrorCode::from(e)),
+                }
+            }))
+            .await?;
+            size += sizes.into_iter().sum::<u64>();
+        }
+        Ok(size)
+    }
+
     // Purge file by location chunks.
     #[async_backtrace::framed]
     pub async fn try_purge_location_files(
@@ -696,12 +828,22 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     }
 }
 
+/// The files removed by a purge.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PurgeStats {
+    pub files: u64,
+    pub bytes: u64,
+}
+
 struct PurgeCounter {
     start: Instant,
+    count_bytes: bool,
+    bytes: u64,
     blocks: usize,
     agg_indexes: usize,
     blooms: usize,
     segments: usize,
+    vector_indexes: usize,
     table_statistics: usize,
     snapshots: usize,
 }
@@ -710,12 +852,25 @@ impl PurgeCounter {
     fn new() -> Self {
         Self {
             start: Instant::now(),
+            count_bytes: false,
+            bytes: 0,
             blocks: 0,
             agg_indexes: 0,
             blooms: 0,
             segments: 0,
+            vector_indexes: 0,
             table_statistics: 0,
             snapshots: 0,
         }
     }
+
+    fn files(&self) -> u64 {
+        (self.blocks
+            + self.agg_indexes
+            + self.blooms
+            + self.segments
+            + self.vector_indexes
+            + self.table_statistics
+            + self.snapshots) as u64
+    }
 }
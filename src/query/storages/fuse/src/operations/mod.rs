@@ -15,6 +15,7 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod changes;
 mod commit;
 pub mod common;
 mod compact;
@@ -32,14 +33,17 @@ pub mod replace;
 pub mod replace_into;
 mod revert;
 mod truncate;
+mod txn_abort;
 mod update;
 pub mod util;
+mod vector_index;
 pub use agg_index_sink::AggIndexSink;
 pub use common::BlockMetaIndex;
 pub use common::FillInternalColumnProcessor;
 pub use common::TransformSerializeBlock;
 pub use compact::CompactOptions;
 pub use delete::MutationBlockPruningContext;
+pub use gc::PurgeStats;
 pub use mutation::BlockCompactMutator;
 pub use mutation::CompactPartInfo;
 pub use mutation::DeletedSegmentInfo;
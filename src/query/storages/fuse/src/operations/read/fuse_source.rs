@@ -15,9 +15,13 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::InternalColumnMeta;
 use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::SampleInfo;
+use common_catalog::plan::SampleLevel;
 use common_catalog::plan::StealablePartitions;
 use common_catalog::plan::TopK;
 use common_catalog::table_context::TableContext;
@@ -290,6 +294,7 @@ pub(crate) fn fill_internal_column_meta(
     data_block: DataBlock,
     fuse_part: &FusePartInfo,
     offsets: Option<Vec<usize>>,
+    row_sample: Option<&SampleInfo>,
 ) -> Result<DataBlock> {
     // Fill `BlockMetaInfoPtr` if query internal columns
     let block_meta = fuse_part.block_meta_index().unwrap();
@@ -300,8 +305,32 @@ pub(crate) fn fill_internal_column_meta(
         segment_location: block_meta.segment_location.clone(),
         snapshot_location: block_meta.snapshot_location.clone(),
         offsets,
+        sample_block_seed: row_sample.map(|sample| sample.block_seed(&fuse_part.location)),
     };
 
     let meta: Option<BlockMetaInfoPtr> = Some(Box::new(internal_column_meta));
     data_block.add_meta(meta)
 }
+
+/// Row level sampling of the scan, block level sampling is done in pruning.
+pub(crate) fn row_sample_info(plan: &DataSourcePlan) -> Option<SampleInfo> {
+    plan.push_downs
+        .as_ref()
+        .and_then(|p| p.sample.clone())
+        .filter(|s| s.level == SampleLevel::Row)
+}
+
+/// Build the bitmap of rows kept by sampling.
+/// `offset` is the row offset of the first row in the block file.
+pub(crate) fn row_sample_bitmap(
+    sample: &SampleInfo,
+    location: &str,
+    offset: usize,
+    num_rows: usize,
+) -> Bitmap {
+    let block_seed = sample.block_seed(location);
+    let bitmap: MutableBitmap = (offset..offset + num_rows)
+        .map(|i| sample.keep_row(block_seed, i))
+        .collect();
+    bitmap.into()
+}
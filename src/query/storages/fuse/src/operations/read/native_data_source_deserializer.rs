@@ -19,6 +19,7 @@ use std::collections::VecDeque;
 use std::sync::Arc;
 
 use common_arrow::arrow::array::Array;
+use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::native::read::ArrayIter;
 use common_arrow::parquet::metadata::ColumnDescriptor;
@@ -27,6 +28,7 @@ use common_base::base::ProgressValues;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::SampleInfo;
 use common_catalog::plan::TopK;
 use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table_context::TableContext;
@@ -58,6 +60,8 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 
 use super::fuse_source::fill_internal_column_meta;
+use super::fuse_source::row_sample_bitmap;
+use super::fuse_source::row_sample_info;
 use super::native_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
@@ -104,6 +108,7 @@ pub struct NativeDeserializeDataTransform {
     // These columns need to fill in the default values.
     read_column_ids: HashSet<ColumnId>,
     top_k: Option<(TopK, TopKSorter, usize)>,
+    row_sample: Option<SampleInfo>,
     // Identifies whether the ArrayIter has been initialised.
     inited: bool,
     // The ArrayIter of each columns to read Pages in order.
@@ -236,6 +241,7 @@ impl NativeDeserializeDataTransform {
                 prewhere_virtual_columns,
                 skipped_page: 0,
                 top_k,
+                row_sample: row_sample_info(plan),
                 read_columns: vec![],
                 read_column_ids: HashSet::new(),
                 inited: false,
@@ -451,10 +457,11 @@ impl NativeDeserializeDataTransform {
                 data_block.add_column(column);
             }
         }
+        let (data_block, offsets) = self.sample_whole_block(data_block, fuse_part)?;
         let data_block = if !self.block_reader.query_internal_columns() {
             data_block
         } else {
-            fill_internal_column_meta(data_block, fuse_part, None)?
+            fill_internal_column_meta(data_block, fuse_part, offsets, self.row_sample.as_ref())?
         };
         let data_block = data_block.resort(&self.src_schema, &self.output_schema)?;
         self.add_block(data_block)?;
@@ -475,16 +482,43 @@ impl NativeDeserializeDataTransform {
 
         let num_rows = fuse_part.nums_rows;
         let data_block = DataBlock::new(vec![], num_rows);
+        let (data_block, offsets) = self.sample_whole_block(data_block, fuse_part)?;
         let data_block = if !self.block_reader.query_internal_columns() {
             data_block
         } else {
-            fill_internal_column_meta(data_block, fuse_part, None)?
+            fill_internal_column_meta(data_block, fuse_part, offsets, self.row_sample.as_ref())?
         };
 
         self.add_block(data_block)?;
         Ok(())
     }
 
+    /// Apply row level sampling to a block that contains all rows of the part,
+    /// returns the offsets of kept rows if internal columns are queried.
+    fn sample_whole_block(
+        &self,
+        data_block: DataBlock,
+        fuse_part: &FusePartInfo,
+    ) -> Result<(DataBlock, Option<Vec<usize>>)> {
+        match &self.row_sample {
+            Some(sample) => {
+                let num_rows = data_block.num_rows();
+                let bitmap = row_sample_bitmap(sample, &fuse_part.location, 0, num_rows);
+                let offsets = if self.block_reader.query_internal_columns() {
+                    Some(
+                        (0..num_rows)
+                            .filter(|i| unsafe { bitmap.get_bit_unchecked(*i) })
+                            .collect(),
+                    )
+                } else {
+                    None
+                };
+                Ok((data_block.filter_with_bitmap(&bitmap)?, offsets))
+            }
+            None => Ok((data_block, None)),
+        }
+    }
+
     /// Update the number of pages that can be skipped per column.
     fn finish_process_skip_page(&mut self) -> Result<()> {
         self.skipped_page += 1;
@@ -789,6 +823,30 @@ impl Processor for NativeDeserializeDataTransform {
             // Step 7: Add optional virtual columns
             self.add_virtual_columns(arrays, &self.src_schema, &self.virtual_columns, &mut block)?;
 
+            // Step 8: Apply row level sampling together with the prewhere filter.
+            let filter = match &self.row_sample {
+                Some(sample) => {
+                    let num_rows = block.num_rows();
+                    let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+                    let sampled = row_sample_bitmap(
+                        sample,
+                        &fuse_part.location,
+                        self.offset_in_part,
+                        num_rows,
+                    );
+                    let bitmap = match filter {
+                        Some(filter) => {
+                            let filter: Bitmap =
+                                FilterHelpers::filter_to_bitmap(filter, num_rows).into();
+                            (&filter) & (&sampled)
+                        }
+                        None => sampled,
+                    };
+                    Some(Value::Column(bitmap))
+                }
+                None => filter,
+            };
+
             let origin_num_rows = block.num_rows();
             let block = if let Some(filter) = &filter {
                 block.filter_boolean_value(filter)?
@@ -796,7 +854,7 @@ impl Processor for NativeDeserializeDataTransform {
                 block
             };
 
-            // Step 8: Fill `InternalColumnMeta` as `DataBlock.meta` if query internal columns,
+            // Step 9: Fill `InternalColumnMeta` as `DataBlock.meta` if query internal columns,
             // `FillInternalColumnProcessor` will generate internal columns using `InternalColumnMeta` in next pipeline.
             let mut block = block.resort(&self.src_schema, &self.output_schema)?;
             if self.block_reader.query_internal_columns() {
@@ -809,10 +867,15 @@ impl Processor for NativeDeserializeDataTransform {
                 };
 
                 let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
-                block = fill_internal_column_meta(block, fuse_part, Some(offsets))?;
+                block = fill_internal_column_meta(
+                    block,
+                    fuse_part,
+                    Some(offsets),
+                    self.row_sample.as_ref(),
+                )?;
             };
 
-            // Step 9: Add the block to output data
+            // Step 10: Add the block to output data
             self.offset_in_part += origin_num_rows;
             self.add_block(block)?;
         }
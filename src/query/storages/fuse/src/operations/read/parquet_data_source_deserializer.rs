@@ -20,6 +20,7 @@ use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::SampleInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::types::DataType;
@@ -34,6 +35,8 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 
 use super::fuse_source::fill_internal_column_meta;
+use super::fuse_source::row_sample_bitmap;
+use super::fuse_source::row_sample_info;
 use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
@@ -58,6 +61,7 @@ pub struct DeserializeDataTransform {
 
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
+    row_sample: Option<SampleInfo>,
 }
 
 unsafe impl Send for DeserializeDataTransform {}
@@ -105,6 +109,7 @@ impl DeserializeDataTransform {
             uncompressed_buffer: UncompressedBuffer::new(buffer_size),
             index_reader,
             virtual_reader,
+            row_sample: row_sample_info(plan),
         })))
     }
 }
@@ -226,10 +231,33 @@ impl Processor for DeserializeDataTransform {
 
                     let data_block = data_block.resort(&self.src_schema, &self.output_schema)?;
 
+                    // Row level sampling, the block is always read from its first row.
+                    let mut offsets = None;
+                    let data_block = match &self.row_sample {
+                        Some(sample) => {
+                            let num_rows = data_block.num_rows();
+                            let bitmap = row_sample_bitmap(sample, &part.location, 0, num_rows);
+                            if self.block_reader.query_internal_columns() {
+                                offsets = Some(
+                                    (0..num_rows)
+                                        .filter(|i| unsafe { bitmap.get_bit_unchecked(*i) })
+                                        .collect(),
+                                );
+                            }
+                            data_block.filter_with_bitmap(&bitmap)?
+                        }
+                        None => data_block,
+                    };
+
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `FillInternalColumnProcessor` will generate internal columns using `BlockMetaIndex` in next pipeline.
                     if self.block_reader.query_internal_columns() {
-                        let data_block = fill_internal_column_meta(data_block, part, None)?;
+                        let data_block = fill_internal_column_meta(
+                            data_block,
+                            part,
+                            offsets,
+                            self.row_sample.as_ref(),
+                        )?;
                         self.output_data = Some(data_block);
                     } else {
                         self.output_data = Some(data_block);
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
+use common_catalog::temp_table::is_temp_table_id;
 use common_exception::Result;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
@@ -68,27 +69,31 @@ impl FuseTable {
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
             let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            };
 
-            // commit table meta to meta server.
-            // `truncate_table` is not supposed to be retry-able, thus we use
-            // `update_data_table_meta` directly.
-            catalog
-                .update_table_meta(&self.table_info, UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                    copied_files: None,
-                    deduplicated_label: None,
-                })
-                .await?;
+            if is_temp_table_id(table_id) {
+                // temporary tables have no meta in meta server, nor copied files.
+                ctx.temp_tbl_mgr().lock().update_table_meta(req)?;
+            } else {
+                // commit table meta to meta server.
+                // `truncate_table` is not supposed to be retry-able, thus we use
+                // `update_data_table_meta` directly.
+                catalog.update_table_meta(&self.table_info, req).await?;
 
-            // best effort to remove the table's copied files.
-            catalog
-                .truncate_table(&self.table_info, TruncateTableReq {
-                    table_id,
-                    batch_size: None,
-                })
-                .await?;
+                // best effort to remove the table's copied files.
+                catalog
+                    .truncate_table(&self.table_info, TruncateTableReq {
+                        table_id,
+                        batch_size: None,
+                    })
+                    .await?;
+            }
 
             // try keep a hit file of last snapshot
             Self::write_last_snapshot_hint(
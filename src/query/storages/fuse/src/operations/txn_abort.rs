@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use log::info;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::LoadParams;
+use storages_common_cache_manager::CachedObject;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::Files;
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::common::AbortOperation;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Remove the files written by an aborted explicit transaction.
+    ///
+    /// `self` is the table before it is mutated by the transaction, the segments and blocks
+    /// it references are kept, everything else referenced by `uncommitted_snapshots` is removed.
+    #[async_backtrace::framed]
+    pub async fn abort_txn_mutation(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        uncommitted_snapshots: &[String],
+    ) -> Result<()> {
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+
+        // 1. collect the segments and blocks of the base snapshot
+        let base_segments: Vec<Location> = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            None => vec![],
+        };
+        let mut base_blocks = HashSet::new();
+        for segment in segments_io
+            .read_segments::<SegmentInfo>(&base_segments, false)
+            .await?
+        {
+            for block in segment?.blocks.iter() {
+                base_blocks.insert(block.location.0.clone());
+            }
+        }
+        let base_segments: HashSet<String> =
+            base_segments.into_iter().map(|(path, _)| path).collect();
+
+        // 2. collect the segments generated by the transaction
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut new_segments = vec![];
+        let mut visited = HashSet::new();
+        for location in uncommitted_snapshots {
+            let params = LoadParams {
+                location: location.clone(),
+                len_hint: None,
+                ver: TableMetaLocationGenerator::snapshot_version(location),
+                put_cache: false,
+            };
+            let snapshot = match reader.read(&params).await {
+                Ok(snapshot) => snapshot,
+                // the snapshot may not be written at all
+                Err(_) => continue,
+            };
+            for segment in snapshot.segments.iter() {
+                if !base_segments.contains(&segment.0) && visited.insert(segment.0.clone()) {
+                    new_segments.push(segment.clone());
+                }
+            }
+        }
+
+        // 3. the blocks in the new segments may come from the base snapshot (e.g. compaction)
+        let mut abort_operation = AbortOperation::default();
+        for (segment, location) in segments_io
+            .read_segments::<SegmentInfo>(&new_segments, false)
+            .await?
+            .into_iter()
+            .zip(new_segments.into_iter())
+        {
+            if let Ok(segment) = segment {
+                for block in segment.blocks.iter() {
+                    if !base_blocks.contains(&block.location.0) {
+                        abort_operation.add_block(block);
+                    }
+                }
+            }
+            abort_operation.add_segment(location.0);
+        }
+
+        info!(
+            "abort transaction mutation of table {}, removing {} segments, {} blocks and {} snapshots",
+            self.table_info.desc,
+            abort_operation.segments.len(),
+            abort_operation.blocks.len(),
+            uncommitted_snapshots.len()
+        );
+        abort_operation
+            .abort(ctx.clone(), self.operator.clone())
+            .await?;
+
+        // 4. remove the uncommitted snapshots
+        for location in uncommitted_snapshots {
+            TableSnapshot::cache().evict(location);
+        }
+        Files::create(ctx, self.operator.clone())
+            .remove_file_in_batch(uncommitted_snapshots)
+            .await
+    }
+}
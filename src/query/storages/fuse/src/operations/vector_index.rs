@@ -0,0 +1,199 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::FieldIndex;
+use log::info;
+use log::warn;
+use storages_common_index::SegmentVectorIndexBuilder;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::table::get_vector_indexes;
+use storages_common_table_meta::table::VectorIndexMeta;
+
+use crate::io::write_data;
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// The vector indexes of the table, with the offsets of their columns in the
+    /// written data blocks.
+    pub fn vector_index_fields(&self) -> Result<Vec<(VectorIndexMeta, FieldIndex)>> {
+        let schema = self.table_info.schema().remove_virtual_computed_fields();
+        Ok(get_vector_indexes(self.table_info.options())?
+            .into_iter()
+            .filter_map(|index| {
+                let field_index = schema
+                    .fields()
+                    .iter()
+                    .position(|field| field.column_id() == index.column_id)?;
+                Some((index, field_index))
+            })
+            .collect())
+    }
+
+    /// Build the vector index of a segment, returns the location and the data of its
+    /// file, or `None` if the segment is not indexed.
+    ///
+    /// A segment is not indexed if its vectors are not all of the same dimension, or the
+    /// index can't be built with its options, it is then fully scanned by the top-k queries.
+    pub fn build_segment_vector_index(
+        index: &VectorIndexMeta,
+        segment_location: &str,
+        builder: SegmentVectorIndexBuilder,
+    ) -> Option<(String, Vec<u8>)> {
+        let start = Instant::now();
+        let num_vectors = builder.num_vectors();
+        match builder.finish(index) {
+            Ok(Some(data)) => {
+                info!(
+                    "vector index {} built for segment {}, vectors: {}, cost: {:?}",
+                    index.name,
+                    segment_location,
+                    num_vectors,
+                    start.elapsed()
+                );
+                let location =
+                    TableMetaLocationGenerator::gen_vector_index_location_from_segment_location(
+                        segment_location,
+                        &index.id,
+                    );
+                Some((location, data.to_bytes()))
+            }
+            Ok(None) => {
+                info!(
+                    "vector index {} skipped segment {}: no vector or mixed dimensions",
+                    index.name, segment_location
+                );
+                None
+            }
+            Err(e) => {
+                warn!(
+                    "vector index {} skipped segment {}: {}",
+                    index.name, segment_location, e
+                );
+                None
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn refresh_segment_vector_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        segment_location: &str,
+        blocks: &[Arc<BlockMeta>],
+        index: &VectorIndexMeta,
+        field_index: FieldIndex,
+    ) -> Result<()> {
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let block_reader =
+            self.create_block_reader(Projection::Columns(vec![field_index]), false, ctx)?;
+
+        let mut builder = SegmentVectorIndexBuilder::default();
+        for chunk in blocks.chunks(chunk_size) {
+            let data_blocks = futures::future::try_join_all(chunk.iter().map(|block| {
+                block_reader.read_by_meta(&settings, block.as_ref(), &self.storage_format)
+            }))
+            .await?;
+            for (block, data_block) in chunk.iter().zip(data_blocks) {
+                let entry = data_block.get_by_offset(0);
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, data_block.num_rows());
+                builder.add_block(&block.location.0, &column);
+            }
+        }
+
+        if let Some((location, data)) =
+            Self::build_segment_vector_index(index, segment_location, builder)
+        {
+            write_data(data, &self.operator, &location).await?;
+        }
+        Ok(())
+    }
+
+    /// Build the files of a vector index for the segments of the current snapshot
+    /// which are not indexed yet.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_vector_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index: &VectorIndexMeta,
+    ) -> Result<()> {
+        let Some(field_index) = self
+            .table_info
+            .schema()
+            .fields()
+            .iter()
+            .position(|field| field.column_id() == index.column_id)
+        else {
+            return Ok(());
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        let mut segment_locations = vec![];
+        for location in &snapshot.segments {
+            let index_location =
+                TableMetaLocationGenerator::gen_vector_index_location_from_segment_location(
+                    &location.0,
+                    &index.id,
+                );
+            if !self.operator.is_exist(&index_location).await? {
+                segment_locations.push(location.clone());
+            }
+        }
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let number_segments = segment_locations.len();
+        let mut refreshed = 0;
+        for chunk in segment_locations.chunks(chunk_size) {
+            ctx.check_aborting()?;
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (location, segment) in chunk.iter().zip(segments) {
+                let segment = segment?;
+                self.refresh_segment_vector_index(
+                    ctx.clone(),
+                    &location.0,
+                    &segment.blocks,
+                    index,
+                    field_index,
+                )
+                .await?;
+
+                refreshed += 1;
+                let status = format!(
+                    "refresh vector index: built segment files:{}/{}",
+                    refreshed, number_segments
+                );
+                ctx.set_status_info(&status);
+            }
+        }
+        Ok(())
+    }
+}
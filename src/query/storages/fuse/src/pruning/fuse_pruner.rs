@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_base::base::tokio::sync::Semaphore;
 use common_base::runtime::Runtime;
 use common_base::runtime::TrySpawn;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::SampleLevel;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -29,6 +31,7 @@ use common_sql::field_default_value;
 use common_sql::BloomIndexColumns;
 use log::warn;
 use opendal::Operator;
+use storages_common_cache::LoadParams;
 use storages_common_index::RangeIndex;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_pruner::InternalColumnPruner;
@@ -44,6 +47,8 @@ use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
 use crate::operations::DeletedSegmentInfo;
 use crate::pruning::segment_pruner::SegmentPruner;
 use crate::pruning::BlockPruner;
@@ -261,7 +266,7 @@ impl FusePruner {
         self.pruning(segment_locs, true).await
     }
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> sample pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -366,7 +371,11 @@ impl FusePruner {
                     // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                     // will get here, we can prevent other mutations like update and so on.
                     // TopN pruner.
-                    self.topn_pruning(metas)
+                    let metas = self.topn_pruning(metas)?;
+                    // Block level sample pruner.
+                    let metas = self.sample_pruning(metas);
+                    // Vector index pruner.
+                    self.vector_pruning(metas).await
                 }
             }
         }
@@ -394,6 +403,111 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // sample pruner:
+    // if there is block level sampling, keep each block by the hash of its location
+    fn sample_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Vec<(BlockMetaIndex, Arc<BlockMeta>)> {
+        match self
+            .push_down
+            .as_ref()
+            .and_then(|p| p.sample.as_ref())
+            .filter(|s| s.level == SampleLevel::Block)
+        {
+            Some(sample) => metas
+                .into_iter()
+                .filter(|(_, meta)| sample.keep_block(&meta.location.0))
+                .collect(),
+            None => metas,
+        }
+    }
+
+    // vector pruner:
+    // if the scan only needs the rows of the nearest vectors, keep the blocks holding the
+    // approximate nearest vectors in the indexed segments, and all the blocks of the others.
+    #[async_backtrace::framed]
+    async fn vector_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let Some(top_k) = self.push_down.as_ref().and_then(|p| p.vector_top_k.as_ref()) else {
+            return Ok(metas);
+        };
+        if top_k.limit == 0 || metas.is_empty() {
+            return Ok(metas);
+        }
+        let query: Vec<f32> = top_k.query.iter().map(|v| v.0).collect();
+        let effort = self
+            .pruning_ctx
+            .ctx
+            .get_settings()
+            .get_vector_index_search_effort()? as usize;
+
+        let segment_locations: HashSet<&str> = metas
+            .iter()
+            .map(|(index, _)| index.segment_location.as_str())
+            .collect();
+        let reader = MetaReaders::segment_vector_index_reader(self.pruning_ctx.dal.clone());
+        let reader = &reader;
+        let indexes = futures::future::join_all(segment_locations.into_iter().map(|segment| {
+            let params = LoadParams {
+                location:
+                    TableMetaLocationGenerator::gen_vector_index_location_from_segment_location(
+                        segment,
+                        &top_k.index_id,
+                    ),
+                len_hint: None,
+                ver: 0,
+                put_cache: true,
+            };
+            async move { (segment, reader.read(&params).await) }
+        }))
+        .await;
+
+        let mut unindexed_segments = HashSet::new();
+        let mut candidates = vec![];
+        for (segment, index) in &indexes {
+            let result = match index {
+                Ok(index) if index.index.dim() == query.len() => {
+                    index.search(&query, top_k.limit, effort)
+                }
+                Ok(_) => {
+                    unindexed_segments.insert(*segment);
+                    continue;
+                }
+                Err(e) => Err(e.clone()),
+            };
+            match result {
+                Ok(result) => candidates.extend(result),
+                Err(e) => {
+                    if e.code() != ErrorCode::STORAGE_NOT_FOUND {
+                        warn!("vector index of segment {} is not used: {}", segment, e);
+                    }
+                    unindexed_segments.insert(*segment);
+                }
+            }
+        }
+        // Rows with null vectors are still returned if there are not enough vectors.
+        if candidates.len() < top_k.limit {
+            return Ok(metas);
+        }
+
+        candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let blocks: HashSet<&str> = candidates
+            .iter()
+            .take(top_k.limit)
+            .map(|(block, _)| *block)
+            .collect();
+        Ok(metas
+            .into_iter()
+            .filter(|(index, _)| {
+                unindexed_segments.contains(index.segment_location.as_str())
+                    || blocks.contains(index.block_location.as_str())
+            })
+            .collect())
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
@@ -15,10 +15,13 @@
 use std::collections::HashMap;
 
 use common_exception::Result;
+use common_expression::types::geometry::GeometryDomain;
+use common_expression::types::GeometryType;
 use common_expression::types::NumberType;
 use common_expression::types::ValueType;
 use common_expression::Column;
 use common_expression::DataBlock;
+use common_expression::Domain;
 use common_expression::FieldIndex;
 use common_expression::Scalar;
 use common_expression::TableSchemaRef;
@@ -34,6 +37,24 @@ pub fn calc_column_distinct_of_values(column: &Column, rows: usize) -> Result<u6
     Ok(col[0])
 }
 
+/// Geometry columns keep the bounding box of their geometries instead of
+/// min/max, so that spatial predicates can prune blocks. A box with
+/// non-finite coordinates prunes nothing and is widened to the full domain.
+fn geometry_bbox(column: &Column) -> GeometryDomain {
+    match column.remove_nullable().domain() {
+        Domain::Geometry(domain)
+            if domain.is_empty()
+                || (domain.min_x.0.is_finite()
+                    && domain.min_y.0.is_finite()
+                    && domain.max_x.0.is_finite()
+                    && domain.max_y.0.is_finite()) =>
+        {
+            domain
+        }
+        _ => GeometryType::full_domain(),
+    }
+}
+
 pub fn get_traverse_columns_dfs(data_block: &DataBlock) -> traverse::TraverseResult {
     traverse::traverse_columns_dfs(data_block.columns())
 }
@@ -50,41 +71,46 @@ pub fn gen_columns_statistics(
     let leaves = get_traverse_columns_dfs(&data_block)?;
     let leaf_column_ids = schema.to_leaf_column_ids();
     for ((col_idx, col, data_type), column_id) in leaves.iter().zip(leaf_column_ids) {
-        // Ignore the range index does not supported type.
-        if !RangeIndex::supported_type(data_type) {
-            continue;
-        }
+        let (min, max, bbox) = if data_type.remove_nullable().is_geometry() {
+            (Scalar::Null, Scalar::Null, Some(geometry_bbox(col)))
+        } else {
+            // Ignore the range index does not supported type.
+            if !RangeIndex::supported_type(data_type) {
+                continue;
+            }
 
-        // later, during the evaluation of expressions, name of field does not matter
-        let mut min = Scalar::Null;
-        let mut max = Scalar::Null;
+            // later, during the evaluation of expressions, name of field does not matter
+            let mut min = Scalar::Null;
+            let mut max = Scalar::Null;
 
-        let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
-        let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
+            let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
+            let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
 
-        if mins.len() > 0 {
-            min = if let Some(v) = mins.index(0) {
-                if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
-                    v
+            if mins.len() > 0 {
+                min = if let Some(v) = mins.index(0) {
+                    if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
-        }
 
-        if maxs.len() > 0 {
-            max = if let Some(v) = maxs.index(0) {
-                if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
-                    v
+            if maxs.len() > 0 {
+                max = if let Some(v) = maxs.index(0) {
+                    if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
-        }
+            (min, max, None)
+        };
 
         let (is_all_null, bitmap) = col.validity();
         let unset_bits = match (is_all_null, bitmap) {
@@ -111,13 +137,16 @@ pub fn gen_columns_statistics(
         };
 
         let in_memory_size = col.memory_size() as u64;
-        let col_stats = ColumnStatistics::new(
-            min,
-            max,
-            unset_bits as u64,
-            in_memory_size,
-            Some(distinct_of_values),
-        );
+        let col_stats = ColumnStatistics {
+            bbox,
+            ..ColumnStatistics::new(
+                min,
+                max,
+                unset_bits as u64,
+                in_memory_size,
+                Some(distinct_of_values),
+            )
+        };
 
         statistics.insert(column_id, col_stats);
     }
@@ -15,6 +15,9 @@
 use std::borrow::Borrow;
 use std::collections::HashMap;
 
+use common_expression::types::geometry::GeometryDomain;
+use common_expression::types::GeometryType;
+use common_expression::types::ValueType;
 use common_expression::BlockThresholds;
 use common_expression::ColumnId;
 use common_expression::Scalar;
@@ -74,14 +77,25 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 .max_by(|x, y| x.cmp(y))
                 .unwrap_or(Scalar::Null);
 
-            acc.insert(
-                *id,
-                ColumnStatistics::new(min, max, null_count, in_memory_size, None),
-            );
+            acc.insert(*id, ColumnStatistics {
+                bbox: reduce_geometry_bbox(stats),
+                ..ColumnStatistics::new(min, max, null_count, in_memory_size, None)
+            });
             acc
         })
 }
 
+/// Merges the bounding boxes of a geometry column. Statistics without a box,
+/// e.g. the default value of a new column, may hold any geometry.
+fn reduce_geometry_bbox(stats: &[&ColumnStatistics]) -> Option<GeometryDomain> {
+    if stats.iter().all(|s| s.bbox.is_none()) {
+        return None;
+    }
+    Some(stats.iter().fold(GeometryDomain::empty(), |acc, s| {
+        acc.merge(&s.bbox.unwrap_or_else(GeometryType::full_domain))
+    }))
+}
+
 pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     blocks_cluster_stats: &[T],
     default_cluster_key_id: Option<u32>,
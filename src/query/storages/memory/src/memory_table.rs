@@ -92,6 +92,11 @@ impl MemoryTable {
         Ok(Box::new(table))
     }
 
+    /// Release the data of a table that is gone, e.g. a temporary table of a closed session.
+    pub fn release(table_id: u64) {
+        IN_MEMORY_DATA.write().remove(&table_id);
+    }
+
     pub fn description() -> StorageDescription {
         StorageDescription {
             engine_name: "MEMORY".to_string(),
@@ -115,6 +115,7 @@ fn convert_page_index_boolean(
             null_count: null_count as u64,
             in_memory_size: 0, // not needed,
             distinct_of_values: None,
+            bbox: None,
         }),
         _ => None,
     }
@@ -162,6 +163,7 @@ fn convert_page_index_int32(
                 null_count: null_count as u64,
                 in_memory_size: 0, // not needed,
                 distinct_of_values: None,
+                bbox: None,
             })
         }
         _ => None,
@@ -190,6 +192,8 @@ fn convert_page_index_int64(
                     Scalar::Decimal(DecimalScalar::Decimal256(I256::from_i64(max), *size)),
                     Scalar::Decimal(DecimalScalar::Decimal256(I256::from_i64(min), *size)),
                 ),
+                // The time unit of the values is unknown here, TIMESTAMP_TZ pages are not pruned.
+                TableDataType::TimestampTz => return None,
                 _ => unreachable!(),
             };
             Some(ColumnStatistics {
@@ -198,6 +202,7 @@ fn convert_page_index_int64(
                 null_count: null_count as u64,
                 in_memory_size: 0, // not needed,
                 distinct_of_values: None,
+                bbox: None,
             })
         }
         _ => None,
@@ -216,6 +221,7 @@ fn convert_page_index_int96(
                 null_count: null_count as u64,
                 in_memory_size: 0, // not needed,
                 distinct_of_values: None,
+                bbox: None,
             })
         }
         _ => None,
@@ -234,6 +240,7 @@ fn convert_page_index_float(
                 null_count: null_count as u64,
                 in_memory_size: 0, // not needed,
                 distinct_of_values: None,
+                bbox: None,
             })
         }
         _ => None,
@@ -252,6 +259,7 @@ fn convert_page_index_double(
                 null_count: null_count as u64,
                 in_memory_size: 0, // not needed,
                 distinct_of_values: None,
+                bbox: None,
             })
         }
         _ => None,
@@ -270,6 +278,7 @@ fn convert_page_index_byte_array(
                 null_count: null_count as u64,
                 in_memory_size: 0, // not needed,
                 distinct_of_values: None,
+                bbox: None,
             })
         }
         _ => None,
@@ -299,6 +308,7 @@ fn convert_page_index_fixed_len_byte_array(
                 null_count: null_count as u64,
                 in_memory_size: 0, // not needed,
                 distinct_of_values: None,
+                bbox: None,
             })
         }
         _ => None,
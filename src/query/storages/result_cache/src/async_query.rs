@@ -0,0 +1,279 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::TableSchemaRef;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_store::MetaStore;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKV;
+use common_storage::DataOperator;
+use futures::TryStreamExt;
+use log::info;
+use opendal::EntryMode;
+use opendal::Metakey;
+use opendal::Operator;
+
+use crate::common::gen_async_query_meta_key;
+use crate::common::gen_async_query_prefix;
+use crate::common::gen_async_query_result_dir;
+use crate::common::AsyncQueryError;
+use crate::common::AsyncQueryState;
+use crate::common::AsyncQueryStatus;
+use crate::read::ResultCacheReader;
+use crate::write::ResultCacheWriter;
+
+/// Keeps the status and the spooled result of the queries submitted in async mode.
+///
+/// The status is stored in meta and the result is written to the object storage,
+/// both of them are kept for `retention` seconds after the query finished. The status
+/// of a running query is kept alive by the node executing it, and expires `retention`
+/// seconds after the last keep-alive if the node is gone.
+pub struct AsyncQueryManager {
+    tenant: String,
+    retention: u64,
+    kv_store: Arc<MetaStore>,
+    operator: Operator,
+}
+
+impl AsyncQueryManager {
+    pub fn create(tenant: &str, kv_store: Arc<MetaStore>, retention: u64) -> Self {
+        Self {
+            tenant: tenant.to_string(),
+            retention,
+            kv_store,
+            operator: DataOperator::instance().operator(),
+        }
+    }
+
+    /// The interval to call [`Self::keep_alive`] while a query is running.
+    pub fn keep_alive_interval(&self) -> Duration {
+        Duration::from_secs((self.retention / 2).max(1))
+    }
+
+    /// Record a newly submitted query of `user` as running.
+    #[async_backtrace::framed]
+    pub async fn submit(
+        &self,
+        query_id: &str,
+        sql: &str,
+        user: &str,
+        session_id: &str,
+    ) -> Result<SeqV<AsyncQueryStatus>> {
+        let status = AsyncQueryStatus {
+            query_id: query_id.to_string(),
+            user: user.to_string(),
+            session_id: session_id.to_string(),
+            sql: sql.to_string(),
+            state: AsyncQueryState::Running,
+            schema: None,
+            pages: vec![],
+            num_rows: 0,
+            error: None,
+            create_time: SeqV::<()>::now_ms() / 1000,
+            finish_time: None,
+            retention: self.retention,
+        };
+        match self.set(&status, MatchSeq::Exact(0)).await? {
+            Some(seq) => Ok(SeqV::new(seq, status)),
+            None => Err(ErrorCode::BadArguments(format!(
+                "async query {} already exists",
+                query_id
+            ))),
+        }
+    }
+
+    /// Extend the expiration of the status of a running query, returns the status with
+    /// its new seq, or `None` if the status was changed by others, e.g. the query was killed.
+    #[async_backtrace::framed]
+    pub async fn keep_alive(
+        &self,
+        status: &SeqV<AsyncQueryStatus>,
+    ) -> Result<Option<SeqV<AsyncQueryStatus>>> {
+        let seq = self.set(&status.data, MatchSeq::Exact(status.seq)).await?;
+        Ok(seq.map(|seq| SeqV::new(seq, status.data.clone())))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn succeed(
+        &self,
+        status: SeqV<AsyncQueryStatus>,
+        schema: DataSchema,
+        pages: Vec<String>,
+        num_rows: usize,
+    ) -> Result<()> {
+        let SeqV {
+            seq,
+            data: mut status,
+            ..
+        } = status;
+        status.state = AsyncQueryState::Succeeded;
+        status.schema = Some(schema);
+        status.pages = pages;
+        status.num_rows = num_rows;
+        status.finish_time = Some(SeqV::<()>::now_ms() / 1000);
+        self.finish(&status, seq).await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn fail(&self, status: SeqV<AsyncQueryStatus>, error: &ErrorCode) -> Result<()> {
+        let SeqV {
+            seq,
+            data: mut status,
+            ..
+        } = status;
+        status.state = AsyncQueryState::Failed;
+        status.error = Some(AsyncQueryError {
+            code: error.code(),
+            message: error.message(),
+        });
+        status.finish_time = Some(SeqV::<()>::now_ms() / 1000);
+        self.finish(&status, seq).await
+    }
+
+    /// Get the status of a query, the query of other users is treated as not found.
+    #[async_backtrace::framed]
+    pub async fn get_status(
+        &self,
+        query_id: &str,
+        user: &str,
+    ) -> Result<Option<SeqV<AsyncQueryStatus>>> {
+        let key = gen_async_query_meta_key(&self.tenant, query_id);
+        match self.kv_store.get_kv(&key).await? {
+            None => Ok(None),
+            Some(SeqV { seq, data, .. }) => {
+                let status: AsyncQueryStatus = serde_json::from_slice(&data)?;
+                Ok(Some(SeqV::new(seq, status)).filter(|status| status.data.user == user))
+            }
+        }
+    }
+
+    /// Remove the spooled result of the queries whose status is expired, returns the
+    /// number of queries removed.
+    ///
+    /// The status is recorded before any page is written and is kept alive while the
+    /// query is running, so the result of a running query is skipped.
+    #[async_backtrace::framed]
+    pub async fn vacuum_expired_results(&self) -> Result<usize> {
+        let prefix = gen_async_query_prefix(&self.tenant);
+        let mut lister = self
+            .operator
+            .lister_with(&prefix)
+            .metakey(Metakey::Mode)
+            .await?;
+        let mut expired = vec![];
+        while let Some(entry) = lister.try_next().await? {
+            if entry.metadata().mode() != EntryMode::DIR {
+                continue;
+            }
+            let query_id = entry.name().trim_end_matches('/');
+            let key = gen_async_query_meta_key(&self.tenant, query_id);
+            match self.kv_store.get_kv(&key).await? {
+                None => expired.push(entry.path().to_string()),
+                // still running or not expired yet.
+                Some(_) => continue,
+            }
+        }
+        for path in &expired {
+            self.operator.remove_all(path).await?;
+        }
+        Ok(expired.len())
+    }
+
+    /// Write one page of the result to the object storage and return its location.
+    #[async_backtrace::framed]
+    pub async fn write_page(
+        &self,
+        query_id: &str,
+        schema: TableSchemaRef,
+        blocks: Vec<DataBlock>,
+    ) -> Result<String> {
+        let location = gen_async_query_result_dir(&self.tenant, query_id);
+        let mut writer = ResultCacheWriter::create(schema, location, self.operator.clone(), 0);
+        for block in blocks {
+            writer.append_block(block);
+        }
+        writer.write_to_storage().await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_page(&self, location: &str) -> Result<Vec<DataBlock>> {
+        ResultCacheReader::read_blocks(&self.operator, location).await
+    }
+
+    /// Record the final status of a query, if it is not changed since the running
+    /// status with `seq` was read.
+    #[async_backtrace::framed]
+    async fn finish(&self, status: &AsyncQueryStatus, seq: u64) -> Result<()> {
+        if self.set(status, MatchSeq::Exact(seq)).await?.is_some() {
+            return Ok(());
+        }
+        let key = gen_async_query_meta_key(&self.tenant, &status.query_id);
+        match self.kv_store.get_kv(&key).await? {
+            Some(SeqV { data, .. })
+                if serde_json::from_slice::<AsyncQueryStatus>(&data)?.state
+                    != AsyncQueryState::Running =>
+            {
+                // e.g. the query was killed, keep the status recorded first.
+                info!(
+                    "async query {} is already finished, status {:?} is discarded",
+                    status.query_id, status.state
+                );
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal(format!(
+                "status of async query {} is expired",
+                status.query_id
+            ))),
+        }
+    }
+
+    /// Write the status if the seq matches, returns the new seq or `None` if the
+    /// seq doesn't match.
+    #[async_backtrace::framed]
+    async fn set(&self, status: &AsyncQueryStatus, seq: MatchSeq) -> Result<Option<u64>> {
+        let key = gen_async_query_meta_key(&self.tenant, &status.query_id);
+        // a running query is kept for the retention after the last keep-alive, in case
+        // the node crashed.
+        let expire_at = status
+            .finish_time
+            .unwrap_or_else(|| SeqV::<()>::now_ms() / 1000)
+            + self.retention;
+        let value = serde_json::to_vec(status)?;
+        let reply = self
+            .kv_store
+            .upsert_kv(UpsertKV {
+                key,
+                seq,
+                value: Operation::Update(value),
+                value_meta: Some(KVMeta {
+                    expire_at: Some(expire_at),
+                }),
+            })
+            .await?;
+        if !reply.is_changed() {
+            return Ok(None);
+        }
+        Ok(reply.result.map(|v| v.seq))
+    }
+}
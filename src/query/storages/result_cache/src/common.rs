@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::DataSchema;
 use sha2::Digest;
 use sha2::Sha256;
 
 const RESULT_CACHE_PREFIX: &str = "_result_cache";
+const ASYNC_QUERY_PREFIX: &str = "_async_query";
 
 #[inline(always)]
 pub fn gen_result_cache_key(raw: &str) -> String {
@@ -37,6 +39,21 @@ pub(crate) fn gen_result_cache_dir(key: &str) -> String {
     format!("{RESULT_CACHE_PREFIX}/{key}")
 }
 
+#[inline(always)]
+pub fn gen_async_query_meta_key(tenant: &str, query_id: &str) -> String {
+    format!("{ASYNC_QUERY_PREFIX}/{tenant}/{query_id}")
+}
+
+#[inline(always)]
+pub(crate) fn gen_async_query_prefix(tenant: &str) -> String {
+    format!("{ASYNC_QUERY_PREFIX}/{tenant}/")
+}
+
+#[inline(always)]
+pub(crate) fn gen_async_query_result_dir(tenant: &str, query_id: &str) -> String {
+    format!("{ASYNC_QUERY_PREFIX}/{tenant}/{query_id}")
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct ResultCacheValue {
     /// The original query SQL.
@@ -56,3 +73,45 @@ pub struct ResultCacheValue {
     /// The location of the result cache file.
     pub location: String,
 }
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AsyncQueryState {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct AsyncQueryError {
+    pub code: u16,
+    pub message: String,
+}
+
+/// The status of a query submitted in async mode, it is stored in meta so that
+/// the query could be polled from any node of the cluster.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct AsyncQueryStatus {
+    pub query_id: String,
+    /// The identity of the user who submitted the query, only the user can access it.
+    #[serde(default)]
+    pub user: String,
+    /// The id of the session which executes the query, used to kill the query.
+    #[serde(default)]
+    pub session_id: String,
+    /// The original query SQL.
+    pub sql: String,
+    pub state: AsyncQueryState,
+    /// The schema of the result, available once the query succeeded.
+    pub schema: Option<DataSchema>,
+    /// The locations of the result files, one file for each page.
+    pub pages: Vec<String>,
+    /// The number of rows in the result.
+    pub num_rows: usize,
+    pub error: Option<AsyncQueryError>,
+    /// The submit time of the query (seconds).
+    pub create_time: u64,
+    /// The finish time of the query (seconds).
+    pub finish_time: Option<u64>,
+    /// How long the status and the result are kept after the query finished (seconds).
+    pub retention: u64,
+}
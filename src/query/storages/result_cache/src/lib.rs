@@ -16,15 +16,21 @@
 #![deny(unused_crate_dependencies)]
 #![feature(impl_trait_in_assoc_type)]
 
+mod async_query;
 mod common;
 mod meta_manager;
 mod read;
 mod table_function;
 mod write;
 
+pub use async_query::AsyncQueryManager;
+pub use common::gen_async_query_meta_key;
 pub use common::gen_result_cache_key;
 pub use common::gen_result_cache_meta_key;
 pub use common::gen_result_cache_prefix;
+pub use common::AsyncQueryError;
+pub use common::AsyncQueryState;
+pub use common::AsyncQueryStatus;
 pub use meta_manager::ResultCacheMetaManager;
 pub use read::ResultCacheReader;
 pub use table_function::ResultScan;
@@ -108,7 +108,13 @@ impl ResultCacheReader {
 
     #[async_backtrace::framed]
     async fn read_result_from_cache(&self, location: &str) -> Result<Vec<DataBlock>> {
-        let data = self.operator.read(location).await?;
+        Self::read_blocks(&self.operator, location).await
+    }
+
+    /// Read the blocks of a parquet file written by `ResultCacheWriter`.
+    #[async_backtrace::framed]
+    pub async fn read_blocks(operator: &Operator, location: &str) -> Result<Vec<DataBlock>> {
+        let data = operator.read(location).await?;
         let mut reader = Cursor::new(data);
         let meta = read_metadata(&mut reader)?;
         let arrow_schema = infer_schema(&meta)?;
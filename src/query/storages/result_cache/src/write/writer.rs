@@ -20,7 +20,7 @@ use storages_common_blocks::blocks_to_parquet;
 use storages_common_table_meta::table::TableCompression;
 use uuid::Uuid;
 
-pub(super) struct ResultCacheWriter {
+pub(crate) struct ResultCacheWriter {
     operator: Operator,
     location: String,
 
@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::NaiveDateTime;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+fn datetime_str<S>(dt: &i64, s: S) -> Result<S::Ok, S::Error>
+where S: Serializer {
+    let t = NaiveDateTime::from_timestamp_opt(
+        dt / 1_000_000,
+        TryFrom::try_from((dt % 1_000_000) * 1000).unwrap_or(0),
+    )
+    .unwrap();
+    s.serialize_str(t.format("%Y-%m-%d %H:%M:%S%.6f").to_string().as_str())
+}
+
+/// An audit event of a change to the meta data, such as DDL, grants and users.
+#[derive(Clone, Serialize)]
+pub struct AuditLogElement {
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+
+    // Server.
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    pub query_id: String,
+
+    // Actor.
+    pub actor: String,
+    pub role: String,
+    pub client_address: String,
+
+    // Change.
+    pub action: String,
+    pub object: String,
+    pub before: String,
+    pub after: String,
+
+    // Exception.
+    pub error_code: i32,
+    pub error_text: String,
+}
+
+impl SystemLogElement for AuditLogElement {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            // Server.
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            // Actor.
+            TableField::new("actor", TableDataType::String),
+            TableField::new("role", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            // Change.
+            TableField::new("action", TableDataType::String),
+            TableField::new("object", TableDataType::String),
+            TableField::new("before", TableDataType::String),
+            TableField::new("after", TableDataType::String),
+            // Exception.
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_text", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+
+        let strings = [
+            &self.tenant_id,
+            &self.cluster_id,
+            &self.node_id,
+            &self.query_id,
+            &self.actor,
+            &self.role,
+            &self.client_address,
+            &self.action,
+            &self.object,
+            &self.before,
+            &self.after,
+        ];
+        for s in strings {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(s.as_bytes().to_vec()).as_ref());
+        }
+
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.error_code)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error_text.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditLogElement>;
+pub type AuditLogTable = SystemLogTable<AuditLogElement>;
@@ -62,6 +62,7 @@ impl SyncSystemTable for CachesTable {
         let segment_info_cache = cache_manager.get_table_segment_cache();
         let bloom_index_filter_cache = cache_manager.get_bloom_index_filter_cache();
         let bloom_index_meta_cache = cache_manager.get_bloom_index_meta_cache();
+        let segment_vector_index_cache = cache_manager.get_segment_vector_index_cache();
         let prune_partitions_cache = cache_manager.get_prune_partitions_cache();
         let file_meta_data_cache = cache_manager.get_file_meta_data_cache();
         let table_data_cache = cache_manager.get_table_data_cache();
@@ -101,6 +102,13 @@ impl SyncSystemTable for CachesTable {
             size.push(bloom_index_meta_cache.size());
         }
 
+        if let Some(segment_vector_index_cache) = segment_vector_index_cache {
+            nodes.push(local_node.clone().into_bytes());
+            names.push("segment_vector_index_cache");
+            num_items.push(segment_vector_index_cache.len() as u64);
+            size.push(segment_vector_index_cache.size());
+        }
+
         if let Some(prune_partitions_cache) = prune_partitions_cache {
             nodes.push(local_node.clone().into_bytes());
             names.push("prune_partitions_cache");
@@ -18,6 +18,7 @@
 
 extern crate core;
 
+mod audit_log_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -50,11 +51,15 @@ mod stages_table;
 mod table;
 mod table_functions_table;
 mod tables_table;
+mod task_history_table;
 mod temp_files_table;
 mod tracing_table;
 mod users_table;
 mod util;
 
+pub use audit_log_table::AuditLogElement;
+pub use audit_log_table::AuditLogQueue;
+pub use audit_log_table::AuditLogTable;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -97,6 +102,7 @@ pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use task_history_table::TaskHistoryTable;
 pub use temp_files_table::TempFilesTable;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use base64::engine::general_purpose;
+use base64::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use jwt_simple::algorithms::ECDSAP256PublicKeyLike;
 use jwt_simple::algorithms::ES256PublicKey;
+use jwt_simple::algorithms::Ed25519PublicKey;
+use jwt_simple::algorithms::EdDSAPublicKeyLike;
 use jwt_simple::algorithms::RS256PublicKey;
 use jwt_simple::algorithms::RSAPublicKeyLike;
 use jwt_simple::prelude::JWTClaims;
@@ -29,6 +33,37 @@ use super::jwk;
 pub enum PubKey {
     RSA256(RS256PublicKey),
     ES256(ES256PublicKey),
+    Ed25519(Ed25519PublicKey),
+}
+
+impl PubKey {
+    /// Parse the PEM encoded public key of a key pair user, RSA and Ed25519 are supported.
+    pub fn from_pem(pem: &str) -> Result<Self> {
+        if let Ok(k) = RS256PublicKey::from_pem(pem) {
+            return Ok(PubKey::RSA256(k));
+        }
+        if let Ok(k) = Ed25519PublicKey::from_pem(pem) {
+            return Ok(PubKey::Ed25519(k));
+        }
+        Err(ErrorCode::InvalidAuthInfo(
+            "public key should be a PEM encoded RSA or Ed25519 public key",
+        ))
+    }
+
+    fn verify_token(&self, token: &str) -> Result<JWTClaims<CustomClaims>> {
+        let r = match self {
+            PubKey::RSA256(pk) => pk.verify_token::<CustomClaims>(token, None),
+            PubKey::ES256(pk) => pk.verify_token::<CustomClaims>(token, None),
+            PubKey::Ed25519(pk) => pk.verify_token::<CustomClaims>(token, None),
+        };
+        let c = r.map_err(|err| ErrorCode::AuthenticateFailure(err.to_string()))?;
+        match c.subject {
+            None => Err(ErrorCode::AuthenticateFailure(
+                "missing field `subject` in jwt",
+            )),
+            Some(_) => Ok(c),
+        }
+    }
 }
 
 pub struct JwtAuthenticator {
@@ -100,17 +135,7 @@ impl JwtAuthenticator {
         let metadata = Token::decode_metadata(token);
         let key_id = metadata.map_or(None, |e| e.key_id().map(|s| s.to_string()));
         let pub_key = key_store.get_key(key_id).await?;
-        let r = match &pub_key {
-            PubKey::RSA256(pk) => pk.verify_token::<CustomClaims>(token, None),
-            PubKey::ES256(pk) => pk.verify_token::<CustomClaims>(token, None),
-        };
-        let c = r.map_err(|err| ErrorCode::AuthenticateFailure(err.to_string()))?;
-        match c.subject {
-            None => Err(ErrorCode::AuthenticateFailure(
-                "missing field `subject` in jwt",
-            )),
-            Some(_) => Ok(c),
-        }
+        pub_key.verify_token(token)
     }
     #[async_backtrace::framed]
     pub async fn parse_jwt_claims(&self, token: &str) -> Result<JWTClaims<CustomClaims>> {
@@ -133,4 +158,38 @@ impl JwtAuthenticator {
         }
         Err(combined_code)
     }
+
+    /// Decode the claims without verifying the signature.
+    ///
+    /// It is only used to find out which user the token claims to be, the token must be
+    /// verified before the claims are trusted.
+    pub fn decode_unverified_claims(token: &str) -> Result<JWTClaims<CustomClaims>> {
+        let payload = token.split('.').nth(1).ok_or_else(|| {
+            ErrorCode::AuthenticateFailure("jwt should contain header, payload and signature")
+        })?;
+        let payload = general_purpose::URL_SAFE_NO_PAD
+            .decode(payload.trim_end_matches('='))
+            .map_err(|e| ErrorCode::AuthenticateFailure(format!("invalid jwt payload: {}", e)))?;
+        serde_json::from_slice(&payload)
+            .map_err(|e| ErrorCode::AuthenticateFailure(format!("invalid jwt claims: {}", e)))
+    }
+
+    /// Verify a JWT signed by a key pair user against the public keys of the user,
+    /// any one of the keys(the old or the new one during rotation) is accepted.
+    pub fn parse_jwt_claims_with_public_keys(
+        token: &str,
+        public_keys: &[&str],
+    ) -> Result<JWTClaims<CustomClaims>> {
+        let mut combined_code =
+            ErrorCode::AuthenticateFailure("could not verify token with the user public keys. ");
+        for public_key in public_keys {
+            match PubKey::from_pem(public_key).and_then(|k| k.verify_token(token)) {
+                Ok(c) => return Ok(c),
+                Err(e) => {
+                    combined_code = combined_code.add_message(format!("message: {}, ", e));
+                }
+            }
+        }
+        Err(combined_code)
+    }
 }
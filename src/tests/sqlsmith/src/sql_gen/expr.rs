@@ -181,6 +181,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                     name: Identifier::from_name("to_date".to_string()),
                     args: vec![arg],
                     params: vec![],
+                    within_group: vec![],
+                    filter: None,
                     window: None,
                     lambda: None,
                 }
@@ -196,6 +198,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                     name: Identifier::from_name("to_timestamp".to_string()),
                     args: vec![arg],
                     params: vec![],
+                    within_group: vec![],
+                    filter: None,
                     window: None,
                     lambda: None,
                 }
@@ -251,6 +255,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                     name: Identifier::from_name("to_bitmap".to_string()),
                     args: vec![arg],
                     params: vec![],
+                    within_group: vec![],
+                    filter: None,
                     window: None,
                     lambda: None,
                 }
@@ -267,6 +273,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                     name: Identifier::from_name("parse_json".to_string()),
                     args: vec![arg],
                     params: vec![],
+                    within_group: vec![],
+                    filter: None,
                     window: None,
                     lambda: None,
                 }
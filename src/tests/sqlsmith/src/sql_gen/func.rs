@@ -18,6 +18,7 @@ use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::OrderByExpr;
 use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::ast::WindowFrame;
 use common_ast::ast::WindowFrameBound;
 use common_ast::ast::WindowFrameUnits;
@@ -696,7 +697,12 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             name,
             args,
             params,
-            window,
+            within_group: vec![],
+            filter: None,
+            window: window.map(|window| WindowDesc {
+                ignore_nulls: None,
+                window,
+            }),
             lambda,
         }
     }
@@ -121,6 +121,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             selection: None,
             group_by: None,
             having: None,
+            qualify: None,
             window_list: None,
         };
         let body = SetExpr::Select(Box::new(select));
@@ -312,6 +313,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             selection,
             group_by,
             having: self.gen_selection(),
+            qualify: None,
             window_list: self.gen_window_list(),
         }
     }
@@ -481,6 +483,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             pivot: None,
             // TODO
             unpivot: None,
+            sample: None,
         };
         (table_ref, schema)
     }
@@ -513,6 +516,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 self.bound_table(table);
                 TableReference::TableFunction {
                     span: None,
+                    lateral: false,
                     name: Identifier::from_name(name),
                     params: vec![Expr::Literal {
                         span: None,
@@ -537,6 +541,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                                 name: Identifier::from_name("to_date".to_string()),
                                 args: vec![arg],
                                 params: vec![],
+                                within_group: vec![],
+                                filter: None,
                                 window: None,
                                 lambda: None,
                             })
@@ -552,6 +558,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                                 name: Identifier::from_name("to_timestamp".to_string()),
                                 args: vec![arg],
                                 params: vec![],
+                                within_group: vec![],
+                                filter: None,
                                 window: None,
                                 lambda: None,
                             })
@@ -577,6 +585,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
 
                 TableReference::TableFunction {
                     span: None,
+                    lateral: false,
                     name: Identifier::from_name(name),
                     params: if self.rng.gen_bool(0.5) {
                         vec![param1, param2]
@@ -664,6 +673,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         let join = Join {
             op,
             condition,
+            match_condition: None,
             left: Box::new(left_table),
             right: Box::new(right_table),
         };
@@ -678,6 +688,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
 
         TableReference::Subquery {
             span: None,
+            lateral: false,
             subquery: Box::new(subquery),
             alias: Some(alias),
         }
@@ -0,0 +1,73 @@
+#!/bin/sh
+
+set -o errexit
+
+SCRIPT_PATH="$(cd "$(dirname "$0")" >/dev/null 2>&1 && pwd)"
+BUILD_PROFILE="${BUILD_PROFILE:-debug}"
+METACTL="./target/${BUILD_PROFILE}/databend-metactl --admin-api-address 127.0.0.1:28101 --raft-api-address 127.0.0.1:28103"
+
+rm -fr .databend/
+
+
+echo " ==="
+echo " === 1. Start 2 meta node cluster"
+echo " ==="
+
+nohup ./target/${BUILD_PROFILE}/databend-meta --config-file=./tests/metactl/config/databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 1
+
+nohup ./target/${BUILD_PROFILE}/databend-meta --config-file=./tests/metactl/config/databend-meta-node-2.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 28202
+
+sleep 3
+
+
+echo " ==="
+echo " === 2. Show the status"
+echo " ==="
+
+$METACTL status >status.out
+cat status.out
+grep '"id": 1' status.out
+grep '"name": "2"' status.out
+
+
+echo " ==="
+echo " === 3. Remove node-2 and add it back"
+echo " ==="
+
+$METACTL remove-node --node-id 2 | grep 'node-2 left'
+sleep 1
+
+$METACTL status >status.out
+if grep '"name": "2"' status.out; then
+    echo " === Error: node-2 is still in the cluster"
+    exit 1
+fi
+
+$METACTL add-node --node-id 2 --endpoint localhost:28203 --grpc-api-advertise-address 127.0.0.1:28202 | grep 'node-2 joined'
+sleep 3
+
+$METACTL status >status.out
+grep '"name": "2"' status.out
+
+
+echo " ==="
+echo " === 4. Invalid arguments"
+echo " ==="
+
+if $METACTL add-node --node-id 3 --endpoint localhost; then
+    echo " === Error: an endpoint without port is accepted"
+    exit 1
+fi
+
+if ./target/${BUILD_PROFILE}/databend-metactl status; then
+    echo " === Error: status without --admin-api-address is accepted"
+    exit 1
+fi
+
+rm status.out
+
+killall databend-meta
@@ -0,0 +1,87 @@
+#!/bin/sh
+
+set -o errexit
+
+SCRIPT_PATH="$(cd "$(dirname "$0")" >/dev/null 2>&1 && pwd)"
+BUILD_PROFILE="${BUILD_PROFILE:-debug}"
+METACTL="./target/${BUILD_PROFILE}/databend-metactl --grpc-api-address 127.0.0.1:9191"
+
+rm -fr .databend/
+
+
+echo " ==="
+echo " === 1. Start a single meta node"
+echo " ==="
+
+nohup ./target/${BUILD_PROFILE}/databend-meta --config-file=./tests/metactl/config/databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 1
+
+
+echo " ==="
+echo " === 2. Upsert, get and delete"
+echo " ==="
+
+$METACTL upsert --key metactl_test/a --value 1
+$METACTL upsert --key metactl_test/b --value 2
+$METACTL upsert --key metactl_test/c --value 3
+$METACTL upsert --key metactl_test/expire --value 4 --expire-after 3600
+
+echo " === get an existing key"
+$METACTL get --key metactl_test/a | grep 'metactl_test/a (seq: [0-9]*): 1'
+
+echo " === get a key with expire time"
+$METACTL get --key metactl_test/expire | grep 'metactl_test/expire (seq: [0-9]*, expire_at: [0-9]*): 4'
+
+echo " === get a missing key"
+$METACTL get --key metactl_test/missing | grep 'metactl_test/missing: <not found>'
+
+echo " === mget"
+$METACTL mget --keys metactl_test/a metactl_test/missing metactl_test/c >mget.out
+grep 'metactl_test/a (seq: [0-9]*): 1' mget.out
+grep 'metactl_test/missing: <not found>' mget.out
+grep 'metactl_test/c (seq: [0-9]*): 3' mget.out
+rm mget.out
+
+echo " === delete"
+$METACTL delete --key metactl_test/expire | grep 'deleted metactl_test/expire'
+$METACTL delete --key metactl_test/expire | grep 'metactl_test/expire does not exist'
+
+
+echo " ==="
+echo " === 3. List with prefix and limit"
+echo " ==="
+
+echo " === list all the keys with the prefix"
+$METACTL list --prefix metactl_test/ 2>list.err >list.out
+test "$(wc -l <list.out)" -eq 3
+grep '3 keys listed$' list.err
+
+echo " === list with limit, the keys are returned in key order"
+$METACTL list --prefix metactl_test/ --limit 2 2>list.err >list.out
+test "$(wc -l <list.out)" -eq 2
+grep 'metactl_test/a' list.out
+grep 'metactl_test/b' list.out
+grep '2 keys listed, there are more keys' list.err
+rm list.out list.err
+
+
+echo " ==="
+echo " === 4. Watch the changes of a prefix"
+echo " ==="
+
+$METACTL watch --prefix metactl_test/ >watch.out &
+WATCH_PID=$!
+sleep 1
+
+$METACTL upsert --key metactl_test/d --value 5
+$METACTL delete --key metactl_test/a
+sleep 1
+kill $WATCH_PID
+
+grep '\[update\] metactl_test/d (seq: [0-9]*): 5' watch.out
+grep '\[delete\] metactl_test/a' watch.out
+rm watch.out
+
+killall databend-meta
@@ -0,0 +1,66 @@
+#!/bin/sh
+
+set -o errexit
+
+SCRIPT_PATH="$(cd "$(dirname "$0")" >/dev/null 2>&1 && pwd)"
+BUILD_PROFILE="${BUILD_PROFILE:-debug}"
+METACTL="./target/${BUILD_PROFILE}/databend-metactl --grpc-api-address 127.0.0.1:9191"
+BACKUP="--backup-storage-type fs --backup-fs-root ./.databend/meta_backup"
+
+rm -fr .databend/
+
+
+echo " ==="
+echo " === 1. Start a single meta node with online backup"
+echo " ==="
+
+nohup ./target/${BUILD_PROFILE}/databend-meta --config-file=./tests/metactl/config/databend-meta-node-1.toml \
+    $BACKUP --backup-snapshot-interval 2 --backup-log-ship-interval 1 &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 1
+
+
+echo " ==="
+echo " === 2. Write data into a snapshot backup and a log backup"
+echo " ==="
+
+$METACTL upsert --key metactl_test/a --value 1
+
+# Wait for a snapshot that includes `a` to be uploaded.
+sleep 4
+
+$METACTL upsert --key metactl_test/b --value 2
+
+# Wait for the log of `b` to be shipped.
+sleep 3
+
+ls ./.databend/meta_backup/snapshots/
+ls ./.databend/meta_backup/logs/
+
+killall databend-meta
+sleep 1
+
+
+echo " ==="
+echo " === 3. Restore the raft dir from the backup"
+echo " ==="
+
+rm -fr ./.databend/meta1
+
+./target/${BUILD_PROFILE}/databend-metactl --raft-dir ./.databend/meta1 --id 1 restore $BACKUP
+
+
+echo " ==="
+echo " === 4. Start the restored node and check the data"
+echo " ==="
+
+nohup ./target/${BUILD_PROFILE}/databend-meta --config-file=./tests/metactl/config/databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 1
+
+$METACTL get --key metactl_test/a | grep 'metactl_test/a (seq: [0-9]*): 1'
+$METACTL get --key metactl_test/b | grep 'metactl_test/b (seq: [0-9]*): 2'
+
+killall databend-meta
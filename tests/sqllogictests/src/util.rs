@@ -51,6 +51,7 @@ pub struct HttpSessionConf {
     pub database: Option<String>,
     pub keep_server_session_secs: Option<u64>,
     pub settings: Option<BTreeMap<String, String>>,
+    pub session_id: Option<String>,
 }
 
 pub fn parser_rows(rows: &Value) -> Result<Vec<Vec<String>>> {